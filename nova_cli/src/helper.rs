@@ -73,6 +73,66 @@ pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcSco
         Ok(String::from_string(agent, file, gc.into_nogc()).into_value())
     }
 
+    // 'structuredClone' function
+    fn structured_clone<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let args = args.bind(gc.nogc());
+        let value = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1);
+        let mut transfer_list = Vec::new();
+        if let Ok(options) = Object::try_from(options) {
+            let property_key = PropertyKey::from_static_str(agent, "transfer", gc.nogc());
+            let transfer = options
+                .unbind()
+                .internal_get(
+                    agent,
+                    property_key.unbind(),
+                    options.into_value().unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+            match transfer {
+                Value::Undefined => {}
+                Value::Array(transfer) => {
+                    let transfer = transfer.scope(agent, gc.nogc());
+                    for index in 0..transfer.get(agent).len(agent) {
+                        let transfer = transfer.get(agent);
+                        let transferable = transfer
+                            .internal_get(
+                                agent,
+                                PropertyKey::from(index),
+                                transfer.into_value(),
+                                gc.reborrow(),
+                            )
+                            .unbind()?
+                            .bind(gc.nogc());
+                        transfer_list.push(transferable.scope(agent, gc.nogc()));
+                    }
+                }
+                _ => {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Expected transfer option to be an array",
+                        gc.into_nogc(),
+                    ));
+                }
+            }
+        }
+        let transfer_list = transfer_list
+            .iter()
+            .map(|transferable| transferable.get(agent))
+            .collect::<Vec<_>>();
+        let serialized = agent
+            .structured_serialize_with_transfer(value.get(agent), &transfer_list, gc.reborrow())
+            .unbind()?;
+        agent.structured_deserialize(&serialized, gc.into_nogc())
+    }
+
     let function = create_builtin_function(
         agent,
         Behaviour::Regular(print),
@@ -118,6 +178,29 @@ pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcSco
             gc.reborrow(),
         )
         .unwrap();
+
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Regular(structured_clone),
+        BuiltinFunctionArgs::new(1, "structuredClone"),
+        gc.nogc(),
+    );
+    let property_key = PropertyKey::from_static_str(agent, "structuredClone", gc.nogc());
+    global
+        .get(agent)
+        .internal_define_own_property(
+            agent,
+            property_key.unbind(),
+            PropertyDescriptor {
+                value: Some(function.into_value().unbind()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc.reborrow(),
+        )
+        .unwrap();
 }
 
 pub fn initialize_global_object_with_internals(agent: &mut Agent, global: Object, mut gc: GcScope) {
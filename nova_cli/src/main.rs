@@ -106,6 +106,11 @@ impl HostHooks for CliHostHooks {
     fn enqueue_promise_job(&self, job: Job) {
         self.promise_job_queue.borrow_mut().push_back(job);
     }
+
+    fn host_enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+        // Cleanup jobs run alongside promise jobs after the next script.
+        self.promise_job_queue.borrow_mut().push_back(job);
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -145,6 +150,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc: nogc,
                     print_internals: verbose,
+                    ..Default::default()
                 },
                 host_hooks,
             );
@@ -193,6 +199,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 exit_with_parse_errors(errors, &path, source_text)
                             }
                         };
+                        script.set_source_name(agent, path.as_str());
                         let result = script_evaluation(agent, script.unbind(), gc.reborrow());
 
                         fn run_microtask_queue<'gc>(
@@ -228,20 +235,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             }
                             Err(error) => {
-                                eprintln!(
-                                    "Uncaught exception: {}",
-                                    error
-                                        .value()
-                                        .unbind()
+                                let error = error.unbind();
+                                let error_value = error.value().scope(agent, gc.nogc());
+                                let message = match error.stack(agent, gc.reborrow()) {
+                                    Some(stack) => stack.unbind(),
+                                    None => error_value
+                                        .get(agent)
                                         .string_repr(agent, gc.reborrow())
-                                        .as_str(agent)
-                                );
+                                        .unbind(),
+                                };
+                                eprintln!("Uncaught exception: {}", message.as_str(agent));
                                 std::process::exit(1);
                             }
                         }
                         Ok(())
                     },
-                )?;
+                )??;
             }
             agent.remove_realm(realm);
         }
@@ -255,6 +264,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc,
                     print_internals,
+                    ..Default::default()
                 },
                 host_hooks,
             );
@@ -294,7 +304,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     continue;
                 }
                 placeholder = input.to_string();
-                agent.run_in_realm(&realm, |agent, mut gc| {
+                let result = agent.run_in_realm(&realm, |agent, mut gc| {
                     let realm = agent.current_realm(gc.nogc());
                     let source_text = JsString::from_string(agent, input, gc.nogc());
                     let script =
@@ -310,13 +320,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             println!("{:?}\n", result);
                         }
                         Err(error) => {
-                            eprintln!(
-                                "Uncaught exception: {}",
-                                error.value().unbind().string_repr(agent, gc).as_str(agent)
-                            );
+                            let error = error.unbind();
+                            let error_value = error.value().scope(agent, gc.nogc());
+                            let message = match error.stack(agent, gc.reborrow()) {
+                                Some(stack) => stack.unbind(),
+                                None => error_value
+                                    .get(agent)
+                                    .string_repr(agent, gc.reborrow())
+                                    .unbind(),
+                            };
+                            eprintln!("Uncaught exception: {}", message.as_str(agent));
                         }
                     }
                 });
+                if let Err(termination) = result {
+                    eprintln!("Uncaught exception: {termination}");
+                }
             }
         }
     }
@@ -246,6 +246,81 @@ pub(crate) fn get_iterator<'a>(
     get_iterator_from_method(agent, scoped_obj.get(agent), method.unbind(), gc)
 }
 
+/// Whether GetIteratorFlattenable accepts String primitives as iterables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    IterateStringPrimitives,
+    RejectPrimitives,
+}
+
+/// ### [7.4.5 GetIteratorFlattenable ( obj, primitiveHandling )](https://tc39.es/ecma262/#sec-getiteratorflattenable)
+///
+/// The abstract operation GetIteratorFlattenable takes arguments obj (an
+/// ECMAScript language value) and primitiveHandling (iterate-string-primitives
+/// or reject-primitives) and returns either a normal completion containing an
+/// Iterator Record or a throw completion.
+///
+/// Note: Different from the spec, this method returns None if the iterator
+/// object's next method isn't callable.
+pub(crate) fn get_iterator_flattenable<'a>(
+    agent: &mut Agent,
+    obj: Value,
+    primitive_handling: PrimitiveHandling,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<IteratorRecord<'a>>> {
+    let obj = obj.bind(gc.nogc());
+    // 1. If obj is not an Object, then
+    if !obj.is_object() {
+        // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+        // b. Assert: primitiveHandling is iterate-string-primitives.
+        // c. If obj is not a String, throw a TypeError exception.
+        if primitive_handling == PrimitiveHandling::RejectPrimitives || !obj.is_string() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Value is not an iterable object",
+                gc.into_nogc(),
+            ));
+        }
+    }
+    let scoped_obj = obj.scope(agent, gc.nogc());
+    // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+    let method = get_method(
+        agent,
+        obj.unbind(),
+        PropertyKey::Symbol(WellKnownSymbolIndexes::Iterator.into()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. If method is undefined, then
+    let iterator = if let Some(method) = method {
+        // 4. Else,
+        // a. Let iterator be ? Call(method, obj).
+        call_function(
+            agent,
+            method.unbind(),
+            scoped_obj.get(agent),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc())
+    } else {
+        // a. Let iterator be obj.
+        scoped_obj.get(agent).bind(gc.nogc())
+    };
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let Ok(iterator) = Object::try_from(iterator) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Iterator is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(agent, iterator.unbind(), gc)
+}
+
 /// ### [7.4.6 IteratorNext ( iteratorRecord [ , value ] )](https://tc39.es/ecma262/#sec-iteratornext)
 ///
 /// The abstract operation IteratorNext takes argument iteratorRecord (an
@@ -291,7 +366,7 @@ pub(crate) fn iterator_next<'a>(
 /// The abstract operation IteratorComplete takes argument iterResult (an
 /// Object) and returns either a normal completion containing a Boolean or a
 /// throw completion.
-fn iterator_complete<'a>(
+pub(crate) fn iterator_complete<'a>(
     agent: &mut Agent,
     iter_result: Object,
     gc: GcScope<'a, '_>,
@@ -10,7 +10,7 @@ use super::{
     operations_on_iterator_objects::{
         IteratorRecord, get_iterator, if_abrupt_close_iterator, iterator_close_with_error,
     },
-    testing_and_comparison::{is_callable, require_object_coercible, same_value},
+    testing_and_comparison::{is_callable, is_constructor, require_object_coercible, same_value},
     type_conversion::{
         to_length, to_object, to_property_key, to_property_key_simple, try_to_length,
     },
@@ -22,6 +22,7 @@ use crate::{
         builtins::{
             ArgumentsList, Array, BuiltinConstructorFunction, array_create,
             keyed_collections::map_objects::map_prototype::canonicalize_keyed_collection_key,
+            ordinary::ordinary_object_create_with_intrinsics,
             proxy::abstract_operations::{
                 try_validate_non_revoked_proxy, validate_non_revoked_proxy,
             },
@@ -38,7 +39,7 @@ use crate::{
         },
     },
     engine::{Vm, instanceof_operator, rootable::Scopable},
-    heap::{Heap, ObjectEntry},
+    heap::{Heap, ObjectEntry, WellKnownSymbolIndexes},
 };
 use crate::{
     ecmascript::types::scope_property_keys,
@@ -773,6 +774,36 @@ pub(crate) fn throw_not_callable<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -
     agent.throw_exception_with_static_message(ExceptionType::TypeError, "Not a callable object", gc)
 }
 
+/// ### [GetOptionsObject ( options )](https://tc39.es/ecma402/#sec-getoptionsobject)
+///
+/// The abstract operation GetOptionsObject takes argument options (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing an Object or a throw completion.
+pub(crate) fn get_options_object<'a>(
+    agent: &mut Agent,
+    options: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(ordinary_object_create_with_intrinsics(
+            agent, None, None, gc,
+        ));
+    }
+    // 2. If options is an Object, then
+    if let Ok(options) = Object::try_from(options) {
+        // a. Return options.
+        return Ok(options.bind(gc));
+    }
+    // 3. Throw a TypeError exception.
+    Err(agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "Options must be an object",
+        gc,
+    ))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum IntegrityLevel {
     Sealed,
@@ -1449,6 +1480,68 @@ pub(crate) fn is_prototype_of_loop<'a>(
     }
 }
 
+/// ### [7.3.22 SpeciesConstructor ( O, defaultConstructor )](https://tc39.es/ecma262/#sec-speciesconstructor)
+///
+/// The abstract operation SpeciesConstructor takes arguments O (an Object) and
+/// defaultConstructor (a constructor) and returns either a normal completion
+/// containing a constructor or a throw completion. It is used to retrieve the
+/// constructor that should be used to create new objects that are derived
+/// from O. defaultConstructor is the constructor to use if a constructor
+/// %Symbol.species% property cannot be found starting from O.
+pub(crate) fn species_constructor<'a>(
+    agent: &mut Agent,
+    o: Object,
+    default_constructor: Function,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Function<'a>> {
+    let o = o.bind(gc.nogc());
+    let default_constructor = default_constructor.scope(agent, gc.nogc());
+    // 1. Let C be ? Get(O, "constructor").
+    let c = get(
+        agent,
+        o.unbind(),
+        BUILTIN_STRING_MEMORY.constructor.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If C is undefined, return defaultConstructor.
+    if c.is_undefined() {
+        return Ok(default_constructor.get(agent).bind(gc.into_nogc()));
+    }
+    // 3. If C is not an Object, throw a TypeError exception.
+    let Ok(c) = Object::try_from(c) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "constructor property is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    // 4. Let S be ? Get(C, %Symbol.species%).
+    let s = get(
+        agent,
+        c.unbind(),
+        WellKnownSymbolIndexes::Species.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 5. If S is either undefined or null, return defaultConstructor.
+    if s.is_undefined() || s.is_null() {
+        return Ok(default_constructor.get(agent).bind(gc.into_nogc()));
+    }
+    // 6. If IsConstructor(S) is true, return S.
+    if let Some(s) = is_constructor(agent, s) {
+        return Ok(s.unbind().bind(gc.into_nogc()));
+    }
+    // 7. Throw a TypeError exception.
+    Err(agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "species is not a constructor",
+        gc.into_nogc(),
+    ))
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum EnumPropKind {
     Key,
@@ -1244,11 +1244,13 @@ pub(crate) fn to_property_key_simple<'a, 'gc>(
     match argument {
         Value::String(_) | Value::SmallString(_) => {
             let (str, string_key) = match &argument {
-                Value::String(x) => (agent[*x].as_str(), PropertyKey::String(*x)),
-                Value::SmallString(x) => (x.as_str(), PropertyKey::SmallString(*x)),
+                // NOTE: Strings containing lone surrogates are never integer
+                // keys.
+                Value::String(x) => (agent[*x].as_wtf8().as_str(), PropertyKey::String(*x)),
+                Value::SmallString(x) => (Some(x.as_str()), PropertyKey::SmallString(*x)),
                 _ => unreachable!(),
             };
-            if let Some(key) = parse_string_to_integer_property_key(str) {
+            if let Some(key) = str.and_then(parse_string_to_integer_property_key) {
                 TryResult::Continue(key)
             } else {
                 TryResult::Continue(string_key)
@@ -546,6 +546,7 @@ impl
             realm: self.realm,
             initial_name: Some(self.name.0),
             behaviour: self.behaviour.0,
+            closure: None,
         };
 
         let slot = self
@@ -623,6 +624,7 @@ impl
             realm,
             initial_name: Some(name.0),
             behaviour: behaviour.0,
+            closure: None,
         };
 
         let slot = agent
@@ -693,6 +695,7 @@ impl
             realm,
             initial_name: Some(name.0),
             behaviour: behaviour.0,
+            closure: None,
         };
 
         let slot = agent
@@ -21,12 +21,14 @@ pub(crate) mod data_view;
 #[cfg(feature = "date")]
 pub mod date;
 mod ecmascript_function;
-pub(crate) mod embedder_object;
+pub mod embedder_object;
 pub mod error;
 pub(crate) mod finalization_registry;
 pub(crate) mod fundamental_objects;
 pub(crate) mod global_object;
 pub(crate) mod indexed_collections;
+#[cfg(feature = "intl")]
+pub(crate) mod intl;
 pub(crate) mod keyed_collections;
 pub(crate) mod managing_memory;
 pub(crate) mod map;
@@ -44,6 +46,8 @@ pub(crate) mod set;
 #[cfg(feature = "shared-array-buffer")]
 pub(crate) mod shared_array_buffer;
 pub(crate) mod structured_data;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) mod temporal;
 pub(crate) mod text_processing;
 #[cfg(feature = "array-buffer")]
 pub(crate) mod typed_array;
@@ -66,9 +70,11 @@ pub use builtin_constructor::BuiltinConstructorFunction;
 pub(crate) use builtin_constructor::{BuiltinConstructorArgs, create_builtin_constructor};
 pub use builtin_function::{
     ArgumentsList, Behaviour, Builtin, BuiltinFunction, BuiltinFunctionArgs, BuiltinGetter,
-    ConstructorFn, RegularFn as JsFunction, RegularFn, ScopedArgumentsList,
+    ConstructorClosure, ConstructorFn, RegularClosure, RegularFn as JsFunction, RegularFn,
+    ScopedArgumentsList, create_builtin_closure, create_builtin_constructor_closure,
     create_builtin_function,
 };
-pub(crate) use builtin_function::{BuiltinIntrinsic, BuiltinIntrinsicConstructor};
+pub(crate) use builtin_function::{BuiltinClosure, BuiltinIntrinsic, BuiltinIntrinsicConstructor};
 pub use control_abstraction_objects::*;
 pub(crate) use ecmascript_function::*;
+pub use embedder_object::{EmbedderClassBuilder, EmbedderObject, data::EmbedderData};
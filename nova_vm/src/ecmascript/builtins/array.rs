@@ -390,6 +390,10 @@ impl<'a> InternalMethods<'a> for Array<'a> {
                 if !length_writable {
                     return TryResult::Continue(false);
                 }
+                let additional = elements.reserve_byte_size(index + 1);
+                if additional > 0 && agent.exceeds_heap_limit(additional) {
+                    return TryResult::Break(());
+                }
                 let Heap {
                     elements, arrays, ..
                 } = &mut agent.heap;
@@ -448,6 +452,18 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.length) {
             array_set_length(agent, self, property_descriptor.unbind(), gc)
         } else {
+            if let PropertyKey::Integer(index) = property_key {
+                let index = index.into_i64();
+                let elements = agent[self].elements;
+                if ARRAY_INDEX_RANGE.contains(&index) && elements.len_writable {
+                    // NOTE: Growing the elements of the Array must not grow
+                    // the heap beyond the heap limit.
+                    let additional = elements.reserve_byte_size(index as u32 + 1);
+                    if additional > 0 {
+                        agent.check_heap_limit(additional, gc.nogc()).unbind()?;
+                    }
+                }
+            }
             Ok(unwrap_try(self.try_define_own_property(
                 agent,
                 property_key.unbind(),
@@ -17,7 +17,7 @@ use crate::{
         execution::{Agent, JsResult, agent::ExceptionType},
         types::{BUILTIN_STRING_MEMORY, IntoObject, Number, Object, PropertyDescriptor, Value},
     },
-    heap::{Heap, WellKnownSymbolIndexes, indexes::ArrayIndex},
+    heap::{Heap, WellKnownSymbolIndexes, element_array::ElementArrayKey, indexes::ArrayIndex},
 };
 
 use super::{Array, ArrayHeapData, data::SealableElementsVector};
@@ -77,6 +77,19 @@ pub(crate) fn array_create<'a>(
     Ok(Array(ArrayIndex::last(&agent.heap.arrays)))
 }
 
+/// Check that the elements of a new Array of the given length fit within the
+/// heap limit. Lengths that ArrayCreate rejects are left for it to throw on.
+pub(crate) fn check_array_elements_heap_limit<'a>(
+    agent: &mut Agent,
+    length: usize,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    match u32::try_from(length) {
+        Ok(length) => agent.check_heap_limit(ElementArrayKey::from(length).byte_size(), gc),
+        Err(_) => Ok(()),
+    }
+}
+
 /// ### [10.4.2.3 ArraySpeciesCreate ( originalArray, length )](https://tc39.es/ecma262/#sec-arrayspeciescreate)
 ///
 /// The abstract operation ArraySpeciesCreate takes arguments originalArray (an
@@ -104,7 +117,9 @@ pub(crate) fn array_species_create<'a>(
     let original_is_array = is_array(agent, original_array, nogc).unbind()?;
     // 2. If isArray is false, return ? ArrayCreate(length).
     if !original_is_array {
-        let new_array = array_create(agent, length, length, None, gc.into_nogc())?;
+        let gc = gc.into_nogc();
+        check_array_elements_heap_limit(agent, length, gc)?;
+        let new_array = array_create(agent, length, length, None, gc)?;
         return Ok(new_array.into_object());
     }
     // 3. Let C be ? Get(originalArray, "constructor").
@@ -154,7 +169,9 @@ pub(crate) fn array_species_create<'a>(
     }
     // 6. If C is undefined, return ? ArrayCreate(length).
     if c.is_undefined() {
-        let new_array = array_create(agent, length, length, None, gc.into_nogc())?;
+        let gc = gc.into_nogc();
+        check_array_elements_heap_limit(agent, length, gc)?;
+        let new_array = array_create(agent, length, length, None, gc)?;
         return Ok(new_array.into_object());
     }
     // 7. If IsConstructor(C) is false, throw a TypeError exception.
@@ -244,6 +261,12 @@ pub(crate) fn array_set_length<'a>(
     }
     let gc = gc.into_nogc();
     let a = a.get(agent).bind(gc);
+    // NOTE: Growing the elements of the Array must not grow the heap beyond
+    // the heap limit.
+    let additional = agent[a].elements.reserve_byte_size(new_len);
+    if additional > 0 && agent[a].elements.len_writable {
+        agent.check_heap_limit(additional, gc)?;
+    }
     // 6. Set newLenDesc.[[Value]] to newLen.
     // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
     let Heap {
@@ -348,6 +371,12 @@ pub(crate) fn array_try_set_length(
     if !Number::same_value_zero(agent, number_len, new_len.into()) {
         return TryResult::Break(());
     }
+    // NOTE: Growing the elements of the Array must not grow the heap beyond
+    // the heap limit.
+    let additional = agent[a].elements.reserve_byte_size(new_len);
+    if additional > 0 && agent.exceeds_heap_limit(additional) {
+        return TryResult::Break(());
+    }
     // 6. Set newLenDesc.[[Value]] to newLen.
     // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
     let Heap {
@@ -27,6 +27,13 @@ impl<'a> SealableElementsVector<'a> {
         self.cap.cap()
     }
 
+    /// Returns the number of bytes that reserving room for `new_len`
+    /// elements would allocate.
+    pub(crate) fn reserve_byte_size(&self, new_len: u32) -> usize {
+        let elements_vector: ElementsVector = (*self).into();
+        elements_vector.reserve_byte_size(new_len)
+    }
+
     pub(crate) fn len(&self) -> u32 {
         self.len
     }
@@ -123,12 +123,14 @@ impl ArrayBuffer<'_> {
         &mut *agent[self].get_data_block_mut()
     }
 
-    /// Copy data from `source` ArrayBuffer to this ArrayBuffer.
+    /// Copy `count` bytes of data from `source` ArrayBuffer starting at byte
+    /// index `first` to this ArrayBuffer starting at byte index `to_index`.
     ///
     /// `self` and `source` must be different ArrayBuffers.
     pub(crate) fn copy_array_buffer_data(
         self,
         agent: &mut Agent,
+        to_index: usize,
         source: ArrayBuffer,
         first: usize,
         count: usize,
@@ -150,7 +152,7 @@ impl ArrayBuffer<'_> {
         };
         let source_data = source_data.buffer.get_data_block();
         let target_data = target_data.buffer.get_data_block_mut();
-        target_data.copy_data_block_bytes(0, source_data, first, count);
+        target_data.copy_data_block_bytes(to_index, source_data, first, count);
     }
 
     pub(crate) const fn _def() -> Self {
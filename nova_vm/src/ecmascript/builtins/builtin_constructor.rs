@@ -381,6 +381,7 @@ fn builtin_call_or_construct<'a>(
     let f = f.bind(gc.nogc());
     let arguments_list = arguments_list.bind(gc.nogc());
     let new_target = new_target.bind(gc.nogc());
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
     // 1. Let callerContext be the running execution context.
     let caller_context = agent.running_execution_context();
     // 2. If callerContext is not already suspended, suspend callerContext.
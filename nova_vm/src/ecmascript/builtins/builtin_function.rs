@@ -6,7 +6,7 @@ use core::{
     marker::PhantomData,
     ops::{Deref, Index, IndexMut},
 };
-use std::hint::unreachable_unchecked;
+use std::{hint::unreachable_unchecked, rc::Rc};
 
 use crate::{
     ecmascript::{
@@ -324,6 +324,47 @@ pub type ConstructorFn = for<'gc> fn(
     GcScope<'gc, '_>,
 ) -> JsResult<'gc, Value<'gc>>;
 
+/// Rust closure implementing the behaviour of a built-in function.
+///
+/// Unlike [`RegularFn`], a closure can carry Rust state bound to the function.
+/// It must not hold on to any JavaScript Values, as those are not traced by
+/// the garbage collector.
+pub type RegularClosure = dyn for<'gc> Fn(
+    &mut Agent,
+    Value,
+    ArgumentsList,
+    GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>>;
+
+/// Rust closure implementing the behaviour of a built-in constructor
+/// function. See [`RegularClosure`].
+pub type ConstructorClosure = dyn for<'gc> Fn(
+    &mut Agent,
+    Value,
+    ArgumentsList,
+    Option<Object>,
+    GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>>;
+
+/// Reference counted closure stored in the heap data of a built-in function.
+#[derive(Clone)]
+pub(crate) enum BuiltinClosure {
+    Regular(Rc<RegularClosure>),
+    Constructor(Rc<ConstructorClosure>),
+}
+
+// SAFETY: Built-in closures are only called on the thread that owns the
+// Agent. The garbage collector takes the closures of collected functions out
+// of the heap before sweeping the heap on multiple threads, and drops them on
+// the thread that owns the Agent.
+unsafe impl Send for BuiltinClosure {}
+
+impl core::fmt::Debug for BuiltinClosure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("BuiltinClosure")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Behaviour {
     Regular(RegularFn),
@@ -706,6 +747,7 @@ pub(crate) fn builtin_call_or_construct<'gc>(
     let this_argument = this_argument.bind(gc.nogc());
     let arguments_list = arguments_list.bind(gc.nogc());
     let new_target = new_target.bind(gc.nogc());
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
     // 1. Let callerContext be the running execution context.
     let caller_context = agent.running_execution_context();
     // 2. If callerContext is not already suspended, suspend callerContext.
@@ -737,24 +779,34 @@ pub(crate) fn builtin_call_or_construct<'gc>(
     // the specification of F. If thisArgument is uninitialized, the this value is uninitialized; otherwise,
     // thisArgument provides the this value. argumentsList provides the named parameters. newTarget provides the NewTarget value.
     let func = heap_data.behaviour;
-    let result = match func {
-        Behaviour::Regular(func) => {
-            if new_target.is_some() {
-                Err(agent.throw_exception_with_static_message(
-                    ExceptionType::TypeError,
-                    "Not a constructor",
-                    gc.into_nogc(),
-                ))
-            } else {
-                func(
-                    agent,
-                    this_argument.unwrap_or(Value::Undefined).unbind(),
-                    arguments_list.unbind(),
-                    gc,
-                )
-            }
-        }
-        Behaviour::Constructor(func) => func(
+    let closure = heap_data.closure.clone();
+    let result = match (func, closure) {
+        (Behaviour::Regular(_), _) if new_target.is_some() => Err(agent
+            .throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Not a constructor",
+                gc.into_nogc(),
+            )),
+        (_, Some(BuiltinClosure::Regular(closure))) => closure(
+            agent,
+            this_argument.unwrap_or(Value::Undefined).unbind(),
+            arguments_list.unbind(),
+            gc,
+        ),
+        (_, Some(BuiltinClosure::Constructor(closure))) => closure(
+            agent,
+            this_argument.unwrap_or(Value::Undefined).unbind(),
+            arguments_list.unbind(),
+            new_target.map(|target| target.into_object().unbind()),
+            gc,
+        ),
+        (Behaviour::Regular(func), None) => func(
+            agent,
+            this_argument.unwrap_or(Value::Undefined).unbind(),
+            arguments_list.unbind(),
+            gc,
+        ),
+        (Behaviour::Constructor(func), None) => func(
             agent,
             this_argument.unwrap_or(Value::Undefined).unbind(),
             arguments_list.unbind(),
@@ -800,7 +852,7 @@ pub fn create_builtin_function<'a>(
     let initial_name = if let Some(prefix) = args.prefix {
         // 12. Else,
         // a. Perform SetFunctionName(func, name, prefix).
-        String::from_string(agent, format!("{} {}", args.name, prefix), gc)
+        String::from_string(agent, format!("{} {}", prefix, args.name), gc)
     } else {
         // 11. If prefix is not present, then
         // a. Perform SetFunctionName(func, name).
@@ -877,10 +929,89 @@ pub fn create_builtin_function<'a>(
             // 8. Set func.[[Realm]] to realm.
             realm,
             object_index,
+            closure: None,
         })
         .bind(gc)
 }
 
+/// Create a built-in function whose behaviour is implemented by a Rust
+/// closure. This allows binding Rust state to the function, for example to
+/// implement per-instance native methods.
+///
+/// The closure must not hold on to any JavaScript Values, as those are not
+/// traced by the garbage collector. The closure is dropped when the function
+/// is garbage collected.
+pub fn create_builtin_closure<'a>(
+    agent: &mut Agent,
+    closure: impl for<'gc> Fn(
+        &mut Agent,
+        Value,
+        ArgumentsList,
+        GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>>
+    + 'static,
+    args: BuiltinFunctionArgs,
+    gc: NoGcScope<'a, '_>,
+) -> BuiltinFunction<'a> {
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Regular(builtin_closure_behaviour),
+        args,
+        gc,
+    );
+    agent[function].closure = Some(BuiltinClosure::Regular(Rc::new(closure)));
+    function
+}
+
+/// Create a built-in constructor function whose behaviour is implemented by a
+/// Rust closure. See [`create_builtin_closure`].
+pub fn create_builtin_constructor_closure<'a>(
+    agent: &mut Agent,
+    closure: impl for<'gc> Fn(
+        &mut Agent,
+        Value,
+        ArgumentsList,
+        Option<Object>,
+        GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>>
+    + 'static,
+    args: BuiltinFunctionArgs,
+    gc: NoGcScope<'a, '_>,
+) -> BuiltinFunction<'a> {
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Constructor(builtin_closure_constructor_behaviour),
+        args,
+        gc,
+    );
+    agent[function].closure = Some(BuiltinClosure::Constructor(Rc::new(closure)));
+    function
+}
+
+/// Placeholder behaviour of built-in functions created using
+/// [`create_builtin_closure`]: BuiltinCallOrConstruct calls the closure of
+/// the function instead.
+fn builtin_closure_behaviour<'gc>(
+    _agent: &mut Agent,
+    _this_value: Value,
+    _arguments: ArgumentsList,
+    _gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    unreachable!("built-in closure called without its closure")
+}
+
+/// Placeholder behaviour of built-in functions created using
+/// [`create_builtin_constructor_closure`].
+fn builtin_closure_constructor_behaviour<'gc>(
+    _agent: &mut Agent,
+    _this_value: Value,
+    _arguments: ArgumentsList,
+    _new_target: Option<Object>,
+    _gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    unreachable!("built-in closure called without its closure")
+}
+
 impl<'a> CreateHeapData<BuiltinFunctionHeapData<'a>, BuiltinFunction<'a>> for Heap {
     fn create(&mut self, data: BuiltinFunctionHeapData<'a>) -> BuiltinFunction<'a> {
         self.builtin_functions.push(Some(data.unbind()));
@@ -948,6 +1079,7 @@ impl HeapMarkAndSweep for BuiltinFunctionHeapData<'static> {
             realm,
             initial_name,
             behaviour: _,
+            closure: _,
         } = self;
         realm.mark_values(queues);
         initial_name.mark_values(queues);
@@ -961,6 +1093,7 @@ impl HeapMarkAndSweep for BuiltinFunctionHeapData<'static> {
             realm,
             initial_name,
             behaviour: _,
+            closure: _,
         } = self;
         realm.sweep_values(compactions);
         initial_name.sweep_values(compactions);
@@ -5,4 +5,8 @@
 pub(crate) mod async_from_sync_iterator_prototype;
 pub(crate) mod async_iterator_prototype;
 pub(crate) mod iterator_constructor;
+pub(crate) mod iterator_helper;
+pub(crate) mod iterator_helper_prototype;
 pub(crate) mod iterator_prototype;
+pub(crate) mod wrap_for_valid_iterator;
+pub(crate) mod wrap_for_valid_iterator_prototype;
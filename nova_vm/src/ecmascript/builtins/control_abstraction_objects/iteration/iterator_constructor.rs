@@ -4,15 +4,25 @@
 
 use crate::{
     ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                IteratorRecord, PrimitiveHandling, get_iterator_flattenable,
+            },
+            operations_on_objects::{ordinary_has_instance, throw_not_callable},
+        },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
         builtins::{
             ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            control_abstraction_objects::iteration::wrap_for_valid_iterator::WrapForValidIterator,
             ordinary::ordinary_create_from_constructor,
         },
         execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, Object, String, Value},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
     },
-    engine::context::{Bindable, GcScope},
     heap::IntrinsicConstructorIndexes,
 };
 
@@ -26,6 +36,13 @@ impl BuiltinIntrinsicConstructor for IteratorConstructor {
     const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Iterator;
 }
 
+struct IteratorFrom;
+impl Builtin for IteratorFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorConstructor::from);
+}
+
 impl IteratorConstructor {
     fn constructor<'gc>(
         agent: &mut Agent,
@@ -66,15 +83,63 @@ impl IteratorConstructor {
         .map(Into::into)
     }
 
+    /// ### [27.1.3.2.1 Iterator.from ( O )](https://tc39.es/ecma262/#sec-iterator.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let o = arguments.get(0).bind(gc.nogc());
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let Some(iterator_record) = get_iterator_flattenable(
+            agent,
+            o.unbind(),
+            PrimitiveHandling::IterateStringPrimitives,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc()) else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+        let iterator = iterator_record.iterator.scope(agent, gc.nogc());
+        let next_method = iterator_record.next_method.scope(agent, gc.nogc());
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let iterator_constructor = agent.current_realm_record().intrinsics().iterator();
+        let has_instance = ordinary_has_instance(
+            agent,
+            iterator_constructor,
+            iterator_record.iterator.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator.get(agent).into_value().bind(gc));
+        }
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        let iterator_record = IteratorRecord {
+            iterator: iterator.get(agent).bind(gc),
+            next_method: next_method.get(agent).bind(gc),
+        };
+        let wrapper = WrapForValidIterator::create(agent, iterator_record);
+        // 6. Return wrapper.
+        Ok(wrapper.into_value().bind(gc))
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let iterator_prototype = intrinsics.iterator_prototype();
         let function_prototype = intrinsics.function_prototype();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<IteratorConstructor>(agent, realm)
-            .with_property_capacity(1)
+            .with_property_capacity(2)
             .with_prototype(function_prototype.into_object())
             .with_prototype_property(iterator_prototype.into_object())
+            .with_builtin_function_property::<IteratorFrom>()
             .build();
     }
 }
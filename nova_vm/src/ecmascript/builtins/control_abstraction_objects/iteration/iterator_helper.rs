@@ -0,0 +1,903 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [27.1.2 Iterator Helper Objects](https://tc39.es/ecma262/#sec-iterator-helper-objects)
+//!
+//! An Iterator Helper object is an ordinary object that represents a lazy
+//! transformation of some specific source iterator object. The abstract
+//! closures of the Iterator.prototype helper methods are represented as an
+//! explicit state machine stored in the heap data.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                IteratorRecord, PrimitiveHandling, create_iter_result_object,
+                get_iterator_flattenable, if_abrupt_close_iterator, iterator_close_with_error,
+                iterator_close_with_value, iterator_step, iterator_step_value,
+            },
+            operations_on_objects::{call_function, throw_not_callable},
+            type_conversion::to_boolean,
+        },
+        builtins::ArgumentsList,
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{
+            Function, InternalMethods, InternalSlots, IntoObject, IntoValue, Number, Object,
+            OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, Scopable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::IteratorHelperIndex,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IteratorHelper<'a>(IteratorHelperIndex<'a>);
+
+impl IteratorHelper<'_> {
+    /// # Do not use this
+    /// This is only for Value discriminant creation.
+    pub(crate) const fn _def() -> Self {
+        Self(IteratorHelperIndex::from_u32_index(0))
+    }
+
+    pub(crate) fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Create an Iterator Helper over the \[\[UnderlyingIterator]] `iterator`.
+    ///
+    /// `next_method` is None if the next method of the iterator is not
+    /// callable: the TypeError is then thrown when the Iterator Helper first
+    /// steps the iterator.
+    pub(crate) fn create(
+        agent: &mut Agent,
+        iterator: Object,
+        next_method: Option<Function>,
+        closure: IteratorHelperClosure,
+    ) -> Self {
+        agent.heap.create(IteratorHelperHeapData {
+            object_index: None,
+            underlying_iterator: Some(iterator.unbind()),
+            underlying_next_method: next_method.unbind(),
+            generator_state: IteratorHelperState::SuspendedStart,
+            closure: Some(closure.unbind()),
+        })
+    }
+
+    /// Returns the \[\[Iterator]] of the \[\[UnderlyingIterator]].
+    fn underlying_iterator<'gc>(self, agent: &Agent, gc: NoGcScope<'gc, '_>) -> Object<'gc> {
+        agent[self]
+            .underlying_iterator
+            .expect("Iterator Helper has no underlying iterator")
+            .bind(gc)
+    }
+
+    /// Returns the \[\[UnderlyingIterator]] Iterator Record for stepping the
+    /// iterator, or a TypeError if its next method is not callable.
+    fn iterated<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, IteratorRecord<'gc>> {
+        let iterator = self.underlying_iterator(agent, gc);
+        let Some(next_method) = agent[self].underlying_next_method else {
+            return Err(throw_not_callable(agent, gc));
+        };
+        Ok(IteratorRecord {
+            iterator,
+            next_method: next_method.bind(gc),
+        })
+    }
+
+    fn closure<'gc>(self, agent: &Agent, gc: NoGcScope<'gc, '_>) -> IteratorHelperClosure<'gc> {
+        agent[self]
+            .closure
+            .expect("Iterator Helper has no closure")
+            .bind(gc)
+    }
+
+    /// Move the Iterator Helper into the completed state. Once a generator
+    /// enters the completed state it never leaves it and its associated
+    /// execution context is never resumed, so the underlying iterator and
+    /// closure state can be discarded at this point.
+    fn complete(self, agent: &mut Agent) {
+        let data = &mut agent[self];
+        data.generator_state = IteratorHelperState::Completed;
+        data.underlying_iterator = None;
+        data.underlying_next_method = None;
+        data.closure = None;
+    }
+
+    /// ### [27.5.3.3 GeneratorResume ( generator, value, generatorBrand )](https://tc39.es/ecma262/#sec-generatorresume)
+    ///
+    /// Resumes the abstract closure of the Iterator Helper with generatorBrand
+    /// "Iterator Helper".
+    pub(crate) fn resume<'gc>(
+        self,
+        agent: &mut Agent,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Object<'gc>> {
+        let generator = self.bind(gc.nogc());
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        match agent[generator].generator_state {
+            IteratorHelperState::SuspendedStart | IteratorHelperState::SuspendedYield => {
+                // 3. Assert: state is either suspended-start or suspended-yield.
+            }
+            IteratorHelperState::Executing => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "The iterator helper is currently running",
+                    gc.into_nogc(),
+                ));
+            }
+            IteratorHelperState::Completed => {
+                // 2. If state is completed, return CreateIteratorResultObject(undefined, true).
+                return Ok(create_iter_result_object(
+                    agent,
+                    Value::Undefined,
+                    true,
+                    gc.into_nogc(),
+                ));
+            }
+        }
+        // 7. Set generator.[[GeneratorState]] to executing.
+        agent[generator].generator_state = IteratorHelperState::Executing;
+        let scoped_generator = generator.scope(agent, gc.nogc());
+        // 8. Push genContext onto the execution context stack; genContext is
+        //    now the running execution context.
+        // 9. Resume the suspended evaluation of genContext using
+        //    NormalCompletion(value) as the result of the operation that
+        //    suspended it. Let result be the value returned by the resumed
+        //    computation.
+        let result = run_closure(agent, &scoped_generator, gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        // 10. Assert: When we return here, genContext has already been removed
+        //     from the execution context stack and methodContext is the
+        //     currently running execution context.
+        // 11. Return ? result.
+        match result {
+            Ok(Some(value)) => {
+                // GeneratorYield: Set generator.[[GeneratorState]] to suspended-yield.
+                agent[generator].generator_state = IteratorHelperState::SuspendedYield;
+                Ok(create_iter_result_object(
+                    agent,
+                    value.unbind(),
+                    false,
+                    gc.into_nogc(),
+                ))
+            }
+            Ok(None) => {
+                generator.complete(agent);
+                Ok(create_iter_result_object(
+                    agent,
+                    Value::Undefined,
+                    true,
+                    gc.into_nogc(),
+                ))
+            }
+            Err(err) => {
+                generator.complete(agent);
+                Err(err.unbind().bind(gc.into_nogc()))
+            }
+        }
+    }
+
+    /// ### [27.1.2.1.2 %IteratorHelperPrototype%.return ( )](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return)
+    ///
+    /// Steps 4 onwards, including GeneratorResumeAbrupt with a return
+    /// completion. The abrupt completion is forwarded to the abstract closure,
+    /// which closes the underlying iterator (and any inner iterator of
+    /// flatMap).
+    pub(crate) fn resume_return<'gc>(
+        self,
+        agent: &mut Agent,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Object<'gc>> {
+        let generator = self.bind(gc.nogc());
+        match agent[generator].generator_state {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            IteratorHelperState::SuspendedStart => {
+                let iterator = generator.underlying_iterator(agent, gc.nogc());
+                // a. Set O.[[GeneratorState]] to completed.
+                // b. NOTE: Once a generator enters the completed state it
+                //    never leaves it and its associated execution context is
+                //    never resumed. Any execution state associated with O can
+                //    be discarded at this point.
+                generator.complete(agent);
+                // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                iterator_close_with_value(
+                    agent,
+                    iterator.unbind(),
+                    Value::Undefined,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // d. Return CreateIteratorResultObject(undefined, true).
+                return Ok(create_iter_result_object(
+                    agent,
+                    Value::Undefined,
+                    true,
+                    gc.into_nogc(),
+                ));
+            }
+            IteratorHelperState::SuspendedYield => {}
+            IteratorHelperState::Executing => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "The iterator helper is currently running",
+                    gc.into_nogc(),
+                ));
+            }
+            IteratorHelperState::Completed => {
+                // GeneratorResumeAbrupt
+                // 2. If state is completed, then
+                // a. If abruptCompletion is a return completion, then
+                // i. Return CreateIteratorResultObject(abruptCompletion.[[Value]], true).
+                return Ok(create_iter_result_object(
+                    agent,
+                    Value::Undefined,
+                    true,
+                    gc.into_nogc(),
+                ));
+            }
+        }
+        // 5. Let C be ReturnCompletion(undefined).
+        // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+        agent[generator].generator_state = IteratorHelperState::Executing;
+        let iterator = generator.underlying_iterator(agent, gc.nogc());
+        let inner_iterator = match generator.closure(agent, gc.nogc()) {
+            IteratorHelperClosure::FlatMap { inner_iterator, .. } => inner_iterator,
+            _ => None,
+        };
+        let scoped_generator = generator.scope(agent, gc.nogc());
+        let scoped_iterator = iterator.scope(agent, gc.nogc());
+        // The abstract closure was suspended at a Yield; resuming it with a
+        // return completion makes it perform the IfAbruptCloseIterator steps.
+        let result = if let Some(inner_iterator) = inner_iterator {
+            // flatMap 6.b.viii.4.b. If completion is an abrupt completion, then
+            // i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            let backup_completion = iterator_close_with_value(
+                agent,
+                inner_iterator.iterator.unbind(),
+                Value::Undefined,
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+            match backup_completion {
+                // ii. IfAbruptCloseIterator(backupCompletion, iterated).
+                Err(err) => Err(iterator_close_with_error(
+                    agent,
+                    scoped_iterator.get(agent),
+                    err.unbind(),
+                    gc.reborrow(),
+                )),
+                // iii. Return ? IteratorClose(iterated, completion).
+                Ok(_) => iterator_close_with_value(
+                    agent,
+                    scoped_iterator.get(agent),
+                    Value::Undefined,
+                    gc.reborrow(),
+                ),
+            }
+        } else {
+            // IfAbruptCloseIterator(completion, iterated).
+            iterator_close_with_value(
+                agent,
+                scoped_iterator.get(agent),
+                Value::Undefined,
+                gc.reborrow(),
+            )
+        }
+        .unbind()
+        .bind(gc.nogc());
+        scoped_generator.get(agent).complete(agent);
+        result.unbind()?;
+        Ok(create_iter_result_object(
+            agent,
+            Value::Undefined,
+            true,
+            gc.into_nogc(),
+        ))
+    }
+}
+
+/// Runs the abstract closure of an Iterator Helper until it either yields a
+/// value (`Some`) or returns (`None`).
+///
+/// The closure state is kept in the heap and is re-read after every call into
+/// user code, as the Iterator Helper may have moved during garbage collection.
+fn run_closure<'gc>(
+    agent: &mut Agent,
+    generator: &Scoped<IteratorHelper<'static>>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Value<'gc>>> {
+    match generator.get(agent).closure(agent, gc.nogc()) {
+        IteratorHelperClosure::Map { .. } => run_map(agent, generator, gc),
+        IteratorHelperClosure::Filter { .. } => run_filter(agent, generator, gc),
+        IteratorHelperClosure::Take { remaining } => {
+            // b. Repeat,
+            // i. If remaining = 0, then
+            if remaining == Some(0) {
+                // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                let iterator = generator.get(agent).underlying_iterator(agent, gc.nogc());
+                iterator_close_with_value(
+                    agent,
+                    iterator.unbind(),
+                    Value::Undefined,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                return Ok(None);
+            }
+            // ii. If remaining ≠ +∞, then
+            if let Some(remaining) = remaining {
+                // 1. Set remaining to remaining - 1.
+                let generator = generator.get(agent);
+                agent[generator].closure = Some(IteratorHelperClosure::Take {
+                    remaining: Some(remaining - 1),
+                });
+            }
+            // iii. Let value be ? IteratorStepValue(iterated).
+            // iv. If value is done, return ReturnCompletion(undefined).
+            // v. Let completion be Completion(Yield(value)).
+            let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+            iterator_step_value(agent, iterated, gc)
+        }
+        IteratorHelperClosure::Drop { .. } => {
+            // b. Repeat, while remaining > 0,
+            loop {
+                let IteratorHelperClosure::Drop { remaining } =
+                    generator.get(agent).closure(agent, gc.nogc())
+                else {
+                    unreachable!()
+                };
+                if remaining == Some(0) {
+                    break;
+                }
+                // i. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    let generator = generator.get(agent);
+                    agent[generator].closure = Some(IteratorHelperClosure::Drop {
+                        remaining: Some(remaining - 1),
+                    });
+                }
+                // ii. Let next be ? IteratorStep(iterated).
+                let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+                let next = iterator_step(agent, iterated, gc.reborrow()).unbind()?;
+                // iii. If next is done, return ReturnCompletion(undefined).
+                if next.is_none() {
+                    return Ok(None);
+                }
+            }
+            // c. Repeat,
+            // i. Let value be ? IteratorStepValue(iterated).
+            // ii. If value is done, return ReturnCompletion(undefined).
+            // iii. Let completion be Completion(Yield(value)).
+            let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+            iterator_step_value(agent, iterated, gc)
+        }
+        IteratorHelperClosure::FlatMap { .. } => run_flat_map(agent, generator, gc),
+    }
+}
+
+/// ### [27.1.4.8 Iterator.prototype.map ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.map)
+///
+/// Step 6 closure.
+fn run_map<'gc>(
+    agent: &mut Agent,
+    generator: &Scoped<IteratorHelper<'static>>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Value<'gc>>> {
+    // b. Repeat,
+    // i. Let value be ? IteratorStepValue(iterated).
+    let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+    let Some(value) = iterator_step_value(agent, iterated, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc())
+    else {
+        // ii. If value is done, return ReturnCompletion(undefined).
+        return Ok(None);
+    };
+    let IteratorHelperClosure::Map { mapper, counter } =
+        generator.get(agent).closure(agent, gc.nogc())
+    else {
+        unreachable!()
+    };
+    // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+    let mapped = call_function(
+        agent,
+        mapper.unbind(),
+        Value::Undefined,
+        Some(ArgumentsList::from_mut_slice(&mut [
+            value.unbind(),
+            Number::try_from(counter).unwrap().into_value(),
+        ])),
+        gc.reborrow(),
+    )
+    .unbind()
+    .bind(gc.nogc());
+    // iv. IfAbruptCloseIterator(mapped, iterated).
+    let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+    let mapped = if_abrupt_close_iterator!(agent, mapped, iterated, gc);
+    // vii. Set counter to counter + 1.
+    let generator = generator.get(agent);
+    if let Some(IteratorHelperClosure::Map { counter, .. }) = &mut agent[generator].closure {
+        *counter += 1;
+    }
+    // v. Let completion be Completion(Yield(mapped)).
+    // vi. IfAbruptCloseIterator(completion, iterated).
+    Ok(Some(mapped.unbind().bind(gc.into_nogc())))
+}
+
+/// ### [27.1.4.4 Iterator.prototype.filter ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.filter)
+///
+/// Step 6 closure.
+fn run_filter<'gc>(
+    agent: &mut Agent,
+    generator: &Scoped<IteratorHelper<'static>>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Value<'gc>>> {
+    let mut scoped_value: Scoped<Value> = Value::Undefined.scope_static(gc.nogc());
+    // b. Repeat,
+    loop {
+        // i. Let value be ? IteratorStepValue(iterated).
+        let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+        let Some(value) = iterator_step_value(agent, iterated, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+        else {
+            // ii. If value is done, return ReturnCompletion(undefined).
+            return Ok(None);
+        };
+        // SAFETY: scoped_value is not shared.
+        unsafe { scoped_value.replace(agent, value.unbind()) };
+        let IteratorHelperClosure::Filter { predicate, counter } =
+            generator.get(agent).closure(agent, gc.nogc())
+        else {
+            unreachable!()
+        };
+        // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+        let selected = call_function(
+            agent,
+            predicate.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [
+                value.unbind(),
+                Number::try_from(counter).unwrap().into_value(),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // iv. IfAbruptCloseIterator(selected, iterated).
+        let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+        let selected = if_abrupt_close_iterator!(agent, selected, iterated, gc);
+        // vi. Set counter to counter + 1.
+        let helper = generator.get(agent);
+        if let Some(IteratorHelperClosure::Filter { counter, .. }) = &mut agent[helper].closure {
+            *counter += 1;
+        }
+        // v. If ToBoolean(selected) is true, then
+        if to_boolean(agent, selected) {
+            // 1. Let completion be Completion(Yield(value)).
+            // 2. IfAbruptCloseIterator(completion, iterated).
+            return Ok(Some(scoped_value.get(agent).bind(gc.into_nogc())));
+        }
+    }
+}
+
+/// ### [27.1.4.6 Iterator.prototype.flatMap ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.flatmap)
+///
+/// Step 6 closure.
+fn run_flat_map<'gc>(
+    agent: &mut Agent,
+    generator: &Scoped<IteratorHelper<'static>>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Value<'gc>>> {
+    // b. Repeat,
+    loop {
+        let IteratorHelperClosure::FlatMap { inner_iterator, .. } =
+            generator.get(agent).closure(agent, gc.nogc())
+        else {
+            unreachable!()
+        };
+        // viii. Repeat, while innerAlive is true,
+        if let Some(inner_iterator) = inner_iterator {
+            // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+            let inner_value = iterator_step_value(agent, inner_iterator.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            // 2. IfAbruptCloseIterator(innerValue, iterated).
+            let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+            let inner_value = if_abrupt_close_iterator!(agent, inner_value, iterated, gc);
+            if let Some(inner_value) = inner_value {
+                // 4. Else,
+                // a. Let completion be Completion(Yield(innerValue)).
+                return Ok(Some(inner_value.unbind().bind(gc.into_nogc())));
+            }
+            // 3. If innerValue is done, then
+            // a. Set innerAlive to false.
+            // ix. Set counter to counter + 1.
+            let helper = generator.get(agent);
+            if let Some(IteratorHelperClosure::FlatMap {
+                counter,
+                inner_iterator,
+                ..
+            }) = &mut agent[helper].closure
+            {
+                *inner_iterator = None;
+                *counter += 1;
+            }
+            continue;
+        }
+        // i. Let value be ? IteratorStepValue(iterated).
+        let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+        let Some(value) = iterator_step_value(agent, iterated, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+        else {
+            // ii. If value is done, return ReturnCompletion(undefined).
+            return Ok(None);
+        };
+        let IteratorHelperClosure::FlatMap {
+            mapper, counter, ..
+        } = generator.get(agent).closure(agent, gc.nogc())
+        else {
+            unreachable!()
+        };
+        // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+        let mapped = call_function(
+            agent,
+            mapper.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [
+                value.unbind(),
+                Number::try_from(counter).unwrap().into_value(),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // iv. IfAbruptCloseIterator(mapped, iterated).
+        let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+        let mapped = if_abrupt_close_iterator!(agent, mapped, iterated, gc);
+        // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+        let inner_iterator = get_iterator_flattenable(
+            agent,
+            mapped.unbind(),
+            PrimitiveHandling::RejectPrimitives,
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let inner_iterator = match inner_iterator {
+            Ok(Some(inner_iterator)) => Ok(inner_iterator),
+            Ok(None) => Err(throw_not_callable(agent, gc.nogc())),
+            Err(err) => Err(err),
+        };
+        // vi. IfAbruptCloseIterator(innerIterator, iterated).
+        let iterated = generator.get(agent).iterated(agent, gc.nogc()).unbind()?;
+        let inner_iterator = if_abrupt_close_iterator!(agent, inner_iterator, iterated, gc);
+        // vii. Let innerAlive be true.
+        let helper = generator.get(agent);
+        if let Some(IteratorHelperClosure::FlatMap {
+            inner_iterator: slot,
+            ..
+        }) = &mut agent[helper].closure
+        {
+            *slot = Some(inner_iterator.unbind());
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for IteratorHelper<'_> {
+    type Of<'a> = IteratorHelper<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for IteratorHelper<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> IntoObject<'a> for IteratorHelper<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<IteratorHelper<'a>> for Object<'a> {
+    fn from(value: IteratorHelper) -> Self {
+        Self::IteratorHelper(value.unbind())
+    }
+}
+
+impl<'a> From<IteratorHelper<'a>> for Value<'a> {
+    fn from(value: IteratorHelper<'a>) -> Self {
+        Self::IteratorHelper(value)
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for IteratorHelper<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::IteratorHelper(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for IteratorHelper<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::IteratorHelper(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for IteratorHelper<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::IteratorHelper;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            agent[self]
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for IteratorHelper<'a> {}
+
+impl Index<IteratorHelper<'_>> for Agent {
+    type Output = IteratorHelperHeapData<'static>;
+
+    fn index(&self, index: IteratorHelper) -> &Self::Output {
+        &self.heap.iterator_helpers[index]
+    }
+}
+
+impl IndexMut<IteratorHelper<'_>> for Agent {
+    fn index_mut(&mut self, index: IteratorHelper) -> &mut Self::Output {
+        &mut self.heap.iterator_helpers[index]
+    }
+}
+
+impl Index<IteratorHelper<'_>> for Vec<Option<IteratorHelperHeapData<'static>>> {
+    type Output = IteratorHelperHeapData<'static>;
+
+    fn index(&self, index: IteratorHelper) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("IteratorHelper out of bounds")
+            .as_ref()
+            .expect("IteratorHelper slot empty")
+    }
+}
+
+impl IndexMut<IteratorHelper<'_>> for Vec<Option<IteratorHelperHeapData<'static>>> {
+    fn index_mut(&mut self, index: IteratorHelper) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("IteratorHelper out of bounds")
+            .as_mut()
+            .expect("IteratorHelper slot empty")
+    }
+}
+
+impl TryFrom<HeapRootData> for IteratorHelper<'_> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
+        if let HeapRootData::IteratorHelper(value) = value {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<'a> CreateHeapData<IteratorHelperHeapData<'a>, IteratorHelper<'a>> for Heap {
+    fn create(&mut self, data: IteratorHelperHeapData<'a>) -> IteratorHelper<'a> {
+        self.iterator_helpers.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<IteratorHelperHeapData<'static>>>();
+        }
+        IteratorHelper(IteratorHelperIndex::last(&self.iterator_helpers))
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelper<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.iterator_helpers.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.iterator_helpers.shift_index(&mut self.0);
+    }
+}
+
+/// The \[\[GeneratorState]] of an Iterator Helper.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum IteratorHelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    #[default]
+    Completed,
+}
+
+/// The abstract closure of an Iterator Helper, along with the values it has
+/// captured and the state it carries between yields.
+///
+/// A `remaining` count of `None` represents +∞.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum IteratorHelperClosure<'a> {
+    Map {
+        mapper: Function<'a>,
+        counter: u64,
+    },
+    Filter {
+        predicate: Function<'a>,
+        counter: u64,
+    },
+    Take {
+        remaining: Option<u64>,
+    },
+    Drop {
+        remaining: Option<u64>,
+    },
+    FlatMap {
+        mapper: Function<'a>,
+        counter: u64,
+        inner_iterator: Option<IteratorRecord<'a>>,
+    },
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for IteratorHelperClosure<'_> {
+    type Of<'a> = IteratorHelperClosure<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelperClosure<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Map { mapper, counter: _ } => mapper.mark_values(queues),
+            Self::Filter {
+                predicate,
+                counter: _,
+            } => predicate.mark_values(queues),
+            Self::Take { remaining: _ } | Self::Drop { remaining: _ } => {}
+            Self::FlatMap {
+                mapper,
+                counter: _,
+                inner_iterator,
+            } => {
+                mapper.mark_values(queues);
+                inner_iterator.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Map { mapper, counter: _ } => mapper.sweep_values(compactions),
+            Self::Filter {
+                predicate,
+                counter: _,
+            } => predicate.sweep_values(compactions),
+            Self::Take { remaining: _ } | Self::Drop { remaining: _ } => {}
+            Self::FlatMap {
+                mapper,
+                counter: _,
+                inner_iterator,
+            } => {
+                mapper.sweep_values(compactions);
+                inner_iterator.sweep_values(compactions);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IteratorHelperHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// The \[\[Iterator]] of the \[\[UnderlyingIterator]] Iterator Record.
+    pub(crate) underlying_iterator: Option<Object<'a>>,
+    /// The \[\[NextMethod]] of the \[\[UnderlyingIterator]] Iterator Record,
+    /// or None if it is not callable.
+    pub(crate) underlying_next_method: Option<Function<'a>>,
+    pub(crate) generator_state: IteratorHelperState,
+    pub(crate) closure: Option<IteratorHelperClosure<'a>>,
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for IteratorHelperHeapData<'_> {
+    type Of<'a> = IteratorHelperHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelperHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            underlying_iterator,
+            underlying_next_method,
+            generator_state: _,
+            closure,
+        } = self;
+        object_index.mark_values(queues);
+        underlying_iterator.mark_values(queues);
+        underlying_next_method.mark_values(queues);
+        closure.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            underlying_iterator,
+            underlying_next_method,
+            generator_state: _,
+            closure,
+        } = self;
+        object_index.sweep_values(compactions);
+        underlying_iterator.sweep_values(compactions);
+        underlying_next_method.sweep_values(compactions);
+        closure.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{ArgumentsList, Behaviour, Builtin},
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
+    },
+    engine::context::{Bindable, GcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+/// ### [27.1.2.1 The %IteratorHelperPrototype% Object](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object)
+pub(crate) struct IteratorHelperPrototype;
+
+struct IteratorHelperPrototypeNext;
+impl Builtin for IteratorHelperPrototypeNext {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.next;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorHelperPrototype::next);
+}
+
+struct IteratorHelperPrototypeReturn;
+impl Builtin for IteratorHelperPrototypeReturn {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#return;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorHelperPrototype::r#return);
+}
+
+impl IteratorHelperPrototype {
+    /// ### [27.1.2.1.1 %IteratorHelperPrototype%.next ( )](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next)
+    fn next<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let Value::IteratorHelper(generator) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator Helper expected",
+                gc.into_nogc(),
+            ));
+        };
+        generator
+            .unbind()
+            .resume(agent, gc)
+            .map(|result| result.into_value())
+    }
+
+    /// ### [27.1.2.1.2 %IteratorHelperPrototype%.return ( )](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return)
+    fn r#return<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        let Value::IteratorHelper(o) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator Helper expected",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        o.unbind()
+            .resume_return(agent, gc)
+            .map(|result| result.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.iterator_helper_prototype();
+        let iterator_prototype = intrinsics.iterator_prototype();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(3)
+            .with_prototype(iterator_prototype)
+            .with_builtin_function_property::<IteratorHelperPrototypeNext>()
+            .with_builtin_function_property::<IteratorHelperPrototypeReturn>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Iterator_Helper.into_value())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
@@ -8,8 +8,13 @@ use crate::ecmascript::abstract_operations::operations_on_iterator_objects::{
 };
 use crate::ecmascript::abstract_operations::operations_on_objects::{call, throw_not_callable};
 use crate::ecmascript::abstract_operations::testing_and_comparison::is_callable;
-use crate::ecmascript::abstract_operations::type_conversion::to_boolean;
+use crate::ecmascript::abstract_operations::type_conversion::{
+    to_boolean, to_integer_or_infinity_number, to_number,
+};
 use crate::ecmascript::builtins::Array;
+use crate::ecmascript::builtins::control_abstraction_objects::iteration::iterator_helper::{
+    IteratorHelper, IteratorHelperClosure,
+};
 use crate::ecmascript::execution::agent::ExceptionType;
 use crate::ecmascript::types::{IntoValue, Object};
 use crate::engine::Scoped;
@@ -36,6 +41,14 @@ impl Builtin for IteratorPrototypeIterator {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::iterator);
 }
 
+struct IteratorPrototypeDrop;
+impl Builtin for IteratorPrototypeDrop {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.drop;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::drop);
+}
+
 struct IteratorPrototypeEvery;
 impl Builtin for IteratorPrototypeEvery {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.every;
@@ -44,6 +57,14 @@ impl Builtin for IteratorPrototypeEvery {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::every);
 }
 
+struct IteratorPrototypeFilter;
+impl Builtin for IteratorPrototypeFilter {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.filter;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::filter);
+}
+
 struct IteratorPrototypeFind;
 impl Builtin for IteratorPrototypeFind {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.find;
@@ -52,6 +73,14 @@ impl Builtin for IteratorPrototypeFind {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::find);
 }
 
+struct IteratorPrototypeFlatMap;
+impl Builtin for IteratorPrototypeFlatMap {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.flatMap;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::flat_map);
+}
+
 struct IteratorPrototypeForEach;
 impl Builtin for IteratorPrototypeForEach {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.forEach;
@@ -60,6 +89,14 @@ impl Builtin for IteratorPrototypeForEach {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::for_each);
 }
 
+struct IteratorPrototypeMap;
+impl Builtin for IteratorPrototypeMap {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.map;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::map);
+}
+
 struct IteratorPrototypeSome;
 impl Builtin for IteratorPrototypeSome {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.some;
@@ -76,6 +113,14 @@ impl Builtin for IteratorPrototypeReduce {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::reduce);
 }
 
+struct IteratorPrototypeTake;
+impl Builtin for IteratorPrototypeTake {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.take;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::take);
+}
+
 struct IteratorPrototypeToArray;
 impl Builtin for IteratorPrototypeToArray {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.toArray;
@@ -94,6 +139,56 @@ impl IteratorPrototype {
         Ok(this_value.unbind())
     }
 
+    /// ### [27.1.4.2 Iterator.prototype.drop ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.drop)
+    fn drop<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let limit = arguments.get(0).bind(nogc);
+
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        let o = o.scope(agent, nogc);
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // ...
+        // 8. If integerLimit < 0, then
+        let integer_limit = to_iterator_limit(agent, &o, limit.unbind(), gc.reborrow()).unbind()?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        // Note: If the next method is not callable, the TypeError is thrown
+        // when the Iterator Helper first steps the iterator.
+        let next_method = get_iterator_direct(agent, o.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|iterated| iterated.next_method)
+            .bind(gc.nogc());
+
+        // 10. Let closure be a new Abstract Closure with no parameters that
+        //     captures iterated and integerLimit and performs the following
+        //     steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        let closure = IteratorHelperClosure::Drop {
+            remaining: integer_limit,
+        };
+        let result = IteratorHelper::create(agent, o.get(agent), next_method, closure);
+
+        // 13. Return result.
+        Ok(result.into_value().bind(gc.into_nogc()))
+    }
+
     /// ### [27.1.4.3 Iterator.prototype.every ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.every)
     fn every<'gc>(
         agent: &mut Agent,
@@ -204,6 +299,70 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.4 Iterator.prototype.filter ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.filter)
+    fn filter<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let predicate = arguments.get(0).bind(nogc);
+
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        let Some(predicate) = is_callable(predicate, nogc).unbind().bind(nogc) else {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'predicate' is not callable",
+                nogc,
+            );
+            // b. Return ? IteratorClose(iterated, error).
+            return Err(iterator_close_with_error(
+                agent,
+                o.unbind(),
+                error.unbind(),
+                gc,
+            ));
+        };
+        let predicate = predicate.scope(agent, nogc);
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let o = o.scope(agent, gc.nogc());
+        // Note: If the next method is not callable, the TypeError is thrown
+        // when the Iterator Helper first steps the iterator.
+        let next_method = get_iterator_direct(agent, o.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|iterated| iterated.next_method)
+            .bind(gc.nogc());
+
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and predicate and performs the following steps
+        //    when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        let closure = IteratorHelperClosure::Filter {
+            predicate: predicate.get(agent),
+            counter: 0,
+        };
+        let result = IteratorHelper::create(agent, o.get(agent), next_method, closure);
+
+        // 9. Return result.
+        Ok(result.into_value().bind(gc.into_nogc()))
+    }
+
     /// ### [27.1.4.5 Iterator.prototype.find ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.find)
     fn find<'gc>(
         agent: &mut Agent,
@@ -318,6 +477,71 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.6 Iterator.prototype.flatMap ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.flatmap)
+    fn flat_map<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let mapper = arguments.get(0).bind(nogc);
+
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        let Some(mapper) = is_callable(mapper, nogc).unbind().bind(nogc) else {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'mapper' is not callable",
+                nogc,
+            );
+            // b. Return ? IteratorClose(iterated, error).
+            return Err(iterator_close_with_error(
+                agent,
+                o.unbind(),
+                error.unbind(),
+                gc,
+            ));
+        };
+        let mapper = mapper.scope(agent, nogc);
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let o = o.scope(agent, gc.nogc());
+        // Note: If the next method is not callable, the TypeError is thrown
+        // when the Iterator Helper first steps the iterator.
+        let next_method = get_iterator_direct(agent, o.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|iterated| iterated.next_method)
+            .bind(gc.nogc());
+
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and mapper and performs the following steps
+        //    when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        let closure = IteratorHelperClosure::FlatMap {
+            mapper: mapper.get(agent),
+            counter: 0,
+            inner_iterator: None,
+        };
+        let result = IteratorHelper::create(agent, o.get(agent), next_method, closure);
+
+        // 9. Return result.
+        Ok(result.into_value().bind(gc.into_nogc()))
+    }
+
     /// ### [27.1.4.7 Iterator.prototype.forEach ( procedure )](https://tc39.es/ecma262/#sec-iterator.prototype.foreach)
     fn for_each<'gc>(
         agent: &mut Agent,
@@ -417,6 +641,70 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.8 Iterator.prototype.map ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.map)
+    fn map<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let mapper = arguments.get(0).bind(nogc);
+
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        let Some(mapper) = is_callable(mapper, nogc).unbind().bind(nogc) else {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'mapper' is not callable",
+                nogc,
+            );
+            // b. Return ? IteratorClose(iterated, error).
+            return Err(iterator_close_with_error(
+                agent,
+                o.unbind(),
+                error.unbind(),
+                gc,
+            ));
+        };
+        let mapper = mapper.scope(agent, nogc);
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let o = o.scope(agent, gc.nogc());
+        // Note: If the next method is not callable, the TypeError is thrown
+        // when the Iterator Helper first steps the iterator.
+        let next_method = get_iterator_direct(agent, o.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|iterated| iterated.next_method)
+            .bind(gc.nogc());
+
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and mapper and performs the following steps
+        //    when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        let closure = IteratorHelperClosure::Map {
+            mapper: mapper.get(agent),
+            counter: 0,
+        };
+        let result = IteratorHelper::create(agent, o.get(agent), next_method, closure);
+
+        // 9. Return result.
+        Ok(result.into_value().bind(gc.into_nogc()))
+    }
+
     /// ### [27.1.4.9 Iterator.prototype.reduce ( reducer \[ , initialValue \] )](https://tc39.es/ecma262/#sec-iterator.prototype.reduce)
     fn reduce<'gc>(
         agent: &mut Agent,
@@ -661,6 +949,56 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.11 Iterator.prototype.take ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.take)
+    fn take<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let limit = arguments.get(0).bind(nogc);
+
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        let o = o.scope(agent, nogc);
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // ...
+        // 8. If integerLimit < 0, then
+        let integer_limit = to_iterator_limit(agent, &o, limit.unbind(), gc.reborrow()).unbind()?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        // Note: If the next method is not callable, the TypeError is thrown
+        // when the Iterator Helper first steps the iterator.
+        let next_method = get_iterator_direct(agent, o.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|iterated| iterated.next_method)
+            .bind(gc.nogc());
+
+        // 10. Let closure be a new Abstract Closure with no parameters that
+        //     captures iterated and integerLimit and performs the following
+        //     steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        let closure = IteratorHelperClosure::Take {
+            remaining: integer_limit,
+        };
+        let result = IteratorHelper::create(agent, o.get(agent), next_method, closure);
+
+        // 13. Return result.
+        Ok(result.into_value().bind(gc.into_nogc()))
+    }
+
     /// 27.1.4.12 Iterator.prototype.toArray ( )
     fn to_array<'gc>(
         agent: &mut Agent,
@@ -733,15 +1071,88 @@ impl IteratorPrototype {
         let this = intrinsics.iterator_prototype();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(7)
+            .with_property_capacity(12)
             .with_prototype(object_prototype)
             .with_builtin_function_property::<IteratorPrototypeIterator>()
+            .with_builtin_function_property::<IteratorPrototypeDrop>()
             .with_builtin_function_property::<IteratorPrototypeEvery>()
+            .with_builtin_function_property::<IteratorPrototypeFilter>()
             .with_builtin_function_property::<IteratorPrototypeFind>()
+            .with_builtin_function_property::<IteratorPrototypeFlatMap>()
             .with_builtin_function_property::<IteratorPrototypeForEach>()
+            .with_builtin_function_property::<IteratorPrototypeMap>()
             .with_builtin_function_property::<IteratorPrototypeSome>()
             .with_builtin_function_property::<IteratorPrototypeReduce>()
+            .with_builtin_function_property::<IteratorPrototypeTake>()
             .with_builtin_function_property::<IteratorPrototypeToArray>()
             .build();
     }
 }
+
+/// Steps 4 to 8 of Iterator.prototype.drop and Iterator.prototype.take.
+///
+/// Returns the integer limit, with `None` representing +∞. The iterator is
+/// closed if the limit is invalid.
+fn to_iterator_limit<'gc>(
+    agent: &mut Agent,
+    o: &Scoped<Object>,
+    limit: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u64>> {
+    let limit = limit.bind(gc.nogc());
+    // 4. Let numLimit be Completion(ToNumber(limit)).
+    let num_limit = to_number(agent, limit.unbind(), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 5. IfAbruptCloseIterator(numLimit, iterated).
+    let num_limit = match num_limit {
+        Ok(num_limit) => num_limit,
+        Err(err) => {
+            return Err(iterator_close_with_error(
+                agent,
+                o.get(agent),
+                err.unbind(),
+                gc,
+            ));
+        }
+    };
+    // 6. If numLimit is NaN, then
+    if num_limit.is_nan(agent) {
+        // a. Let error be ThrowCompletion(a newly created RangeError object).
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Iterator limit cannot be NaN",
+            gc.nogc(),
+        );
+        // b. Return ? IteratorClose(iterated, error).
+        return Err(iterator_close_with_error(
+            agent,
+            o.get(agent),
+            error.unbind(),
+            gc,
+        ));
+    }
+    // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+    let integer_limit = to_integer_or_infinity_number(agent, num_limit);
+    // 8. If integerLimit < 0, then
+    if integer_limit.is_negative() {
+        // a. Let error be ThrowCompletion(a newly created RangeError object).
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Iterator limit cannot be negative",
+            gc.nogc(),
+        );
+        // b. Return ? IteratorClose(iterated, error).
+        return Err(iterator_close_with_error(
+            agent,
+            o.get(agent),
+            error.unbind(),
+            gc,
+        ));
+    }
+    if integer_limit.is_pos_infinity() {
+        Ok(None)
+    } else {
+        Ok(Some(integer_limit.into_i64() as u64))
+    }
+}
@@ -0,0 +1,240 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_iterator_objects::IteratorRecord,
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, NoGcScope},
+        rootable::HeapRootData,
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::WrapForValidIteratorIndex,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WrapForValidIterator<'a>(WrapForValidIteratorIndex<'a>);
+
+impl WrapForValidIterator<'_> {
+    /// # Do not use this
+    /// This is only for Value discriminant creation.
+    pub(crate) const fn _def() -> Self {
+        Self(WrapForValidIteratorIndex::from_u32_index(0))
+    }
+
+    pub(crate) fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    pub(crate) fn create(agent: &mut Agent, iterated: IteratorRecord) -> Self {
+        agent.heap.create(WrapForValidIteratorHeapData {
+            object_index: None,
+            iterated: Some(iterated.unbind()),
+        })
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for WrapForValidIterator<'_> {
+    type Of<'a> = WrapForValidIterator<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for WrapForValidIterator<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> IntoObject<'a> for WrapForValidIterator<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<WrapForValidIterator<'a>> for Object<'a> {
+    fn from(value: WrapForValidIterator) -> Self {
+        Self::WrapForValidIterator(value.unbind())
+    }
+}
+
+impl<'a> From<WrapForValidIterator<'a>> for Value<'a> {
+    fn from(value: WrapForValidIterator<'a>) -> Self {
+        Self::WrapForValidIterator(value)
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for WrapForValidIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::WrapForValidIterator(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for WrapForValidIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::WrapForValidIterator(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for WrapForValidIterator<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::WrapForValidIterator;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            agent[self]
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for WrapForValidIterator<'a> {}
+
+impl Index<WrapForValidIterator<'_>> for Agent {
+    type Output = WrapForValidIteratorHeapData<'static>;
+
+    fn index(&self, index: WrapForValidIterator) -> &Self::Output {
+        &self.heap.wrap_for_valid_iterators[index]
+    }
+}
+
+impl IndexMut<WrapForValidIterator<'_>> for Agent {
+    fn index_mut(&mut self, index: WrapForValidIterator) -> &mut Self::Output {
+        &mut self.heap.wrap_for_valid_iterators[index]
+    }
+}
+
+impl Index<WrapForValidIterator<'_>> for Vec<Option<WrapForValidIteratorHeapData<'static>>> {
+    type Output = WrapForValidIteratorHeapData<'static>;
+
+    fn index(&self, index: WrapForValidIterator) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("WrapForValidIterator out of bounds")
+            .as_ref()
+            .expect("WrapForValidIterator slot empty")
+    }
+}
+
+impl IndexMut<WrapForValidIterator<'_>> for Vec<Option<WrapForValidIteratorHeapData<'static>>> {
+    fn index_mut(&mut self, index: WrapForValidIterator) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("WrapForValidIterator out of bounds")
+            .as_mut()
+            .expect("WrapForValidIterator slot empty")
+    }
+}
+
+impl TryFrom<HeapRootData> for WrapForValidIterator<'_> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
+        if let HeapRootData::WrapForValidIterator(value) = value {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<'a> CreateHeapData<WrapForValidIteratorHeapData<'a>, WrapForValidIterator<'a>> for Heap {
+    fn create(&mut self, data: WrapForValidIteratorHeapData<'a>) -> WrapForValidIterator<'a> {
+        self.wrap_for_valid_iterators.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter +=
+                core::mem::size_of::<Option<WrapForValidIteratorHeapData<'static>>>();
+        }
+        WrapForValidIterator(WrapForValidIteratorIndex::last(
+            &self.wrap_for_valid_iterators,
+        ))
+    }
+}
+
+impl HeapMarkAndSweep for WrapForValidIterator<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.wrap_for_valid_iterators.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .wrap_for_valid_iterators
+            .shift_index(&mut self.0);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WrapForValidIteratorHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    pub(crate) iterated: Option<IteratorRecord<'a>>,
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for WrapForValidIteratorHeapData<'_> {
+    type Of<'a> = WrapForValidIteratorHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for WrapForValidIteratorHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            iterated,
+        } = self;
+        object_index.mark_values(queues);
+        iterated.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            iterated,
+        } = self;
+        object_index.sweep_values(compactions);
+        iterated.sweep_values(compactions);
+    }
+}
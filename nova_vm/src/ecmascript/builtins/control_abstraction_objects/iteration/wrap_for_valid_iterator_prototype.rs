@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::create_iter_result_object,
+            operations_on_objects::{call_function, get_method},
+        },
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{ArgumentsList, Behaviour, Builtin},
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+};
+
+/// ### [27.1.3.2.1.1 The %WrapForValidIteratorPrototype% Object](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object)
+pub(crate) struct WrapForValidIteratorPrototype;
+
+struct WrapForValidIteratorPrototypeNext;
+impl Builtin for WrapForValidIteratorPrototypeNext {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.next;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WrapForValidIteratorPrototype::next);
+}
+
+struct WrapForValidIteratorPrototypeReturn;
+impl Builtin for WrapForValidIteratorPrototypeReturn {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#return;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WrapForValidIteratorPrototype::r#return);
+}
+
+impl WrapForValidIteratorPrototype {
+    /// ### [27.1.3.2.1.1.1 %WrapForValidIteratorPrototype%.next ( )](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next)
+    fn next<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        let Value::WrapForValidIterator(o) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "WrapForValidIterator expected",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterator_record = agent[o].iterated.unwrap().bind(gc.nogc());
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        call_function(
+            agent,
+            iterator_record.next_method.unbind(),
+            iterator_record.iterator.into_value().unbind(),
+            None,
+            gc,
+        )
+    }
+
+    /// ### [27.1.3.2.1.1.2 %WrapForValidIteratorPrototype%.return ( )](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return)
+    fn r#return<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        let Value::WrapForValidIterator(o) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "WrapForValidIterator expected",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = agent[o].iterated.unwrap().iterator.bind(gc.nogc());
+        let scoped_iterator = iterator.scope(agent, gc.nogc());
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let return_method = get_method(
+            agent,
+            iterator.into_value().unbind(),
+            BUILTIN_STRING_MEMORY.r#return.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 6. If returnMethod is undefined, then
+        let Some(return_method) = return_method else {
+            // a. Return CreateIteratorResultObject(undefined, true).
+            return Ok(
+                create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                    .into_value(),
+            );
+        };
+        // 7. Return ? Call(returnMethod, iterator).
+        call_function(
+            agent,
+            return_method.unbind(),
+            scoped_iterator.get(agent).into_value(),
+            None,
+            gc,
+        )
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.wrap_for_valid_iterator_prototype();
+        let iterator_prototype = intrinsics.iterator_prototype();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(2)
+            .with_prototype(iterator_prototype)
+            .with_builtin_function_property::<WrapForValidIteratorPrototypeNext>()
+            .with_builtin_function_property::<WrapForValidIteratorPrototypeReturn>()
+            .build();
+    }
+}
@@ -188,6 +188,12 @@ impl PromiseReactionJob {
                 );
                 return Ok(());
             }
+            PromiseReactionHandler::ArrayFromAsync(array_from_async) => {
+                assert!(agent[reaction].capability.is_none());
+                let reaction_type = agent[reaction].reaction_type;
+                array_from_async.resume(agent, reaction_type, argument.unbind(), gc.reborrow());
+                return Ok(());
+            }
         };
 
         // f. If promiseCapability is undefined, then
@@ -241,6 +247,11 @@ pub(crate) fn new_promise_reaction_job(
                 .unwrap()
                 .realm,
         ),
+        // Array.fromAsync is specified as an async closure started in the
+        // realm of the Array.fromAsync function.
+        PromiseReactionHandler::ArrayFromAsync(array_from_async) => {
+            Some(agent[array_from_async].realm)
+        }
         // 2. Let handlerRealm be null.
         PromiseReactionHandler::AsyncGenerator(_) | PromiseReactionHandler::Empty => None,
     };
@@ -9,6 +9,7 @@ use crate::{
         builtins::{
             async_generator_objects::AsyncGenerator,
             control_abstraction_objects::async_function_objects::await_reaction::AwaitReactionIdentifier,
+            indexed_collections::array_objects::array_from_async::ArrayFromAsync,
         },
         execution::Agent,
         types::Function,
@@ -49,6 +50,7 @@ pub(crate) enum PromiseReactionHandler<'a> {
     JobCallback(Function<'a>),
     Await(AwaitReactionIdentifier<'a>),
     AsyncGenerator(AsyncGenerator<'a>),
+    ArrayFromAsync(ArrayFromAsync<'a>),
     Empty,
 }
 
@@ -58,6 +60,7 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
             Self::JobCallback(function) => function.mark_values(queues),
             Self::Await(await_reaction_identifier) => await_reaction_identifier.mark_values(queues),
             Self::AsyncGenerator(async_generator) => async_generator.mark_values(queues),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.mark_values(queues),
             Self::Empty => {}
         }
     }
@@ -69,6 +72,7 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 await_reaction_identifier.sweep_values(compactions)
             }
             Self::AsyncGenerator(async_generator) => async_generator.sweep_values(compactions),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.sweep_values(compactions),
             Self::Empty => {}
         }
     }
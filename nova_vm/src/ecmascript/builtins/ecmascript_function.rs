@@ -500,6 +500,7 @@ impl<'a> InternalMethods<'a> for ECMAScriptFunction<'a> {
     ) -> JsResult<'gc, Value<'gc>> {
         let f = self.bind(gc.nogc());
         let arguments_list = arguments_list.bind(gc.nogc());
+        agent.check_call_stack_limit(gc.nogc()).unbind()?;
 
         // 1. Let callerContext be the running execution context.
         let _ = agent.running_execution_context();
@@ -559,6 +560,7 @@ impl<'a> InternalMethods<'a> for ECMAScriptFunction<'a> {
         let mut self_fn = self.bind(gc.nogc());
         let mut new_target = new_target.bind(gc.nogc());
         let mut arguments_list = arguments.bind(gc.nogc());
+        agent.check_call_stack_limit(gc.nogc()).unbind()?;
         // 2. Let kind be F.[[ConstructorKind]].
         let is_base = !agent[self_fn]
             .ecmascript_function
@@ -1034,7 +1036,7 @@ pub(crate) fn make_constructor<'a>(
         Function::BuiltinConstructorFunction(_) => unreachable!(),
         Function::BuiltinPromiseResolvingFunction(_) => todo!(),
         Function::BuiltinPromiseCollectorFunction => todo!(),
-        Function::BuiltinProxyRevokerFunction => todo!(),
+        Function::BuiltinProxyRevokerFunction(_) => todo!(),
     }
     // 5. If prototype is not present, then
     let prototype = prototype.unwrap_or_else(|| {
@@ -1154,7 +1156,7 @@ pub(crate) fn set_function_name<'a>(
         Function::BuiltinConstructorFunction(_) => unreachable!(),
         Function::BuiltinPromiseResolvingFunction(_) => todo!(),
         Function::BuiltinPromiseCollectorFunction => todo!(),
-        Function::BuiltinProxyRevokerFunction => todo!(),
+        Function::BuiltinProxyRevokerFunction(_) => todo!(),
     }
 }
 
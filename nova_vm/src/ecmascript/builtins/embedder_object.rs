@@ -2,7 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::ops::{Index, IndexMut};
+use core::{
+    any::TypeId,
+    ops::{Index, IndexMut},
+};
 
 use crate::{
     ecmascript::{
@@ -16,20 +19,23 @@ use crate::{
         rootable::HeapRootData,
     },
     heap::{
-        HeapMarkAndSweep,
+        CreateHeapData, Heap, HeapMarkAndSweep,
         indexes::{BaseIndex, EmbedderObjectIndex},
     },
 };
 
-use self::data::EmbedderObjectHeapData;
+use self::data::{EmbedderData, EmbedderObjectHeapData};
 
+mod class_builder;
 pub mod data;
 
+pub use class_builder::EmbedderClassBuilder;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct EmbedderObject<'a>(pub(crate) EmbedderObjectIndex<'a>);
 
-impl EmbedderObject<'_> {
+impl<'a> EmbedderObject<'a> {
     pub(crate) const fn _def() -> Self {
         Self(BaseIndex::from_u32_index(0))
     }
@@ -37,6 +43,52 @@ impl EmbedderObject<'_> {
     pub(crate) const fn get_index(self) -> usize {
         self.0.into_index()
     }
+
+    /// Create an embedder object that carries the given host-defined data.
+    ///
+    /// If `prototype` is not given, the object's prototype is
+    /// %Object.prototype% of the current realm.
+    pub fn new(
+        agent: &mut Agent,
+        prototype: Option<Object>,
+        data: impl EmbedderData,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let object_index = prototype.map(|prototype| {
+            agent
+                .heap
+                .create_object_with_prototype(prototype.unbind(), &[])
+        });
+        agent
+            .heap
+            .create(EmbedderObjectHeapData {
+                object_index,
+                data: Box::new(data),
+            })
+            .bind(gc)
+    }
+
+    /// Get a reference to the host-defined data of the object if it is of
+    /// type `T`.
+    pub fn data<T: EmbedderData>(self, agent: &Agent) -> Option<&T> {
+        let data = &*agent[self].data;
+        if (*data).type_id() != TypeId::of::<T>() {
+            return None;
+        }
+        // SAFETY: The data was checked to be of type T.
+        Some(unsafe { &*(data as *const dyn EmbedderData as *const T) })
+    }
+
+    /// Get a mutable reference to the host-defined data of the object if it
+    /// is of type `T`.
+    pub fn data_mut<T: EmbedderData>(self, agent: &mut Agent) -> Option<&mut T> {
+        let data = &mut *agent[self].data;
+        if (*data).type_id() != TypeId::of::<T>() {
+            return None;
+        }
+        // SAFETY: The data was checked to be of type T.
+        Some(unsafe { &mut *(data as *mut dyn EmbedderData as *mut T) })
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -78,40 +130,48 @@ impl<'a> From<EmbedderObject<'a>> for Object<'a> {
     }
 }
 
-impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
-    #[inline(always)]
-    fn get_backing_object(self, _agent: &Agent) -> Option<OrdinaryObject<'static>> {
-        todo!();
-    }
+impl<'a> TryFrom<Value<'a>> for EmbedderObject<'a> {
+    type Error = ();
 
-    fn set_backing_object(self, _agent: &mut Agent, _backing_object: OrdinaryObject<'static>) {
-        todo!();
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::EmbedderObject(idx) => Ok(idx),
+            _ => Err(()),
+        }
     }
+}
 
-    fn create_backing_object(self, _agent: &mut Agent) -> OrdinaryObject<'static> {
-        todo!();
-    }
-    fn internal_extensible(self, _agent: &Agent) -> bool {
-        todo!();
-    }
+impl<'a> TryFrom<Object<'a>> for EmbedderObject<'a> {
+    type Error = ();
 
-    fn internal_set_extensible(self, _agent: &mut Agent, _value: bool) {
-        todo!();
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::EmbedderObject(idx) => Ok(idx),
+            _ => Err(()),
+        }
     }
+}
 
-    fn internal_prototype(self, _agent: &Agent) -> Option<Object<'static>> {
-        todo!();
+impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
     }
 
-    fn internal_set_prototype(self, _agent: &mut Agent, _prototype: Option<Object>) {
-        todo!();
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            agent[self]
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
     }
 }
 
 impl<'a> InternalMethods<'a> for EmbedderObject<'a> {}
 
 impl Index<EmbedderObject<'_>> for Agent {
-    type Output = EmbedderObjectHeapData;
+    type Output = EmbedderObjectHeapData<'static>;
 
     fn index(&self, index: EmbedderObject) -> &Self::Output {
         &self.heap.embedder_objects[index]
@@ -124,8 +184,8 @@ impl IndexMut<EmbedderObject<'_>> for Agent {
     }
 }
 
-impl Index<EmbedderObject<'_>> for Vec<Option<EmbedderObjectHeapData>> {
-    type Output = EmbedderObjectHeapData;
+impl Index<EmbedderObject<'_>> for Vec<Option<EmbedderObjectHeapData<'static>>> {
+    type Output = EmbedderObjectHeapData<'static>;
 
     fn index(&self, index: EmbedderObject) -> &Self::Output {
         self.get(index.get_index())
@@ -135,7 +195,7 @@ impl Index<EmbedderObject<'_>> for Vec<Option<EmbedderObjectHeapData>> {
     }
 }
 
-impl IndexMut<EmbedderObject<'_>> for Vec<Option<EmbedderObjectHeapData>> {
+impl IndexMut<EmbedderObject<'_>> for Vec<Option<EmbedderObjectHeapData<'static>>> {
     fn index_mut(&mut self, index: EmbedderObject) -> &mut Self::Output {
         self.get_mut(index.get_index())
             .expect("EmbedderObject out of bounds")
@@ -157,6 +217,17 @@ impl TryFrom<HeapRootData> for EmbedderObject<'_> {
     }
 }
 
+impl<'a> CreateHeapData<EmbedderObjectHeapData<'a>, EmbedderObject<'a>> for Heap {
+    fn create(&mut self, data: EmbedderObjectHeapData<'a>) -> EmbedderObject<'a> {
+        self.embedder_objects.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<EmbedderObjectHeapData<'static>>>();
+        }
+        EmbedderObject(EmbedderObjectIndex::last(&self.embedder_objects))
+    }
+}
+
 impl HeapMarkAndSweep for EmbedderObject<'static> {
     fn mark_values(&self, queues: &mut crate::heap::WorkQueues) {
         queues.embedder_objects.push(*self);
@@ -0,0 +1,306 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            ArgumentsList, BuiltinFunction, BuiltinFunctionArgs, create_builtin_closure,
+            create_builtin_constructor_closure, ordinary::get_prototype_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, InternalMethods, InternalSlots, IntoFunction,
+            IntoValue, OrdinaryObject, PropertyDescriptor, PropertyKey, String, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+        unwrap_try,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+use super::{EmbedderObject, data::EmbedderData};
+
+/// Builder for a JavaScript class whose instances are
+/// [`EmbedderObject`]s carrying host-defined data.
+///
+/// The class constructor calls the host-defined constructor closure with the
+/// arguments of the `new` expression to create the data of the instance.
+/// Methods and accessors defined with the builder are placed on the class
+/// prototype; they receive the `this` value as-is and can access the data of
+/// the instance through [`EmbedderObject::data`].
+///
+/// ```ignore
+/// let class = EmbedderClassBuilder::new(
+///     agent,
+///     "Counter",
+///     0,
+///     |_, _, _| Ok(Counter(0)),
+///     gc.nogc(),
+/// )
+/// .with_method("increment", 0, |agent, this, _, gc| {
+///     let counter = EmbedderObject::try_from(this).unwrap();
+///     counter.data_mut::<Counter>(agent).unwrap().0 += 1;
+///     Ok(Value::Undefined)
+/// })
+/// .build();
+/// ```
+pub struct EmbedderClassBuilder<'agent, 'gc, 'scope> {
+    agent: &'agent mut Agent,
+    name: &'static str,
+    constructor: BuiltinFunction<'gc>,
+    prototype: OrdinaryObject<'gc>,
+    gc: NoGcScope<'gc, 'scope>,
+}
+
+impl<'agent, 'gc, 'scope> EmbedderClassBuilder<'agent, 'gc, 'scope> {
+    /// Start building a class called `name` whose constructor takes `length`
+    /// arguments and creates the host-defined data of its instances using
+    /// `constructor`.
+    ///
+    /// Calling the class constructor without `new` throws a TypeError.
+    #[must_use]
+    pub fn new<T: EmbedderData>(
+        agent: &'agent mut Agent,
+        name: &'static str,
+        length: u32,
+        constructor: impl for<'a> Fn(&mut Agent, ArgumentsList, GcScope<'a, '_>) -> JsResult<'a, T>
+        + 'static,
+        gc: NoGcScope<'gc, 'scope>,
+    ) -> Self {
+        let prototype = OrdinaryObject::create_empty_object(agent, gc);
+        let constructor = create_builtin_constructor_closure(
+            agent,
+            move |agent, _this, arguments, new_target, mut gc| {
+                let Some(new_target) = new_target.bind(gc.nogc()) else {
+                    return Err(agent.throw_exception(
+                        ExceptionType::TypeError,
+                        format!("Constructor {name} requires 'new'"),
+                        gc.into_nogc(),
+                    ));
+                };
+                let new_target = Function::try_from(new_target)
+                    .unwrap()
+                    .scope(agent, gc.nogc());
+                let data = match constructor(agent, arguments.unbind(), gc.reborrow()) {
+                    Ok(data) => data,
+                    Err(err) => return Err(err.unbind().bind(gc.into_nogc())),
+                };
+                // Note: The object is created before its prototype is
+                // resolved so that any JavaScript Values held by the data are
+                // traced by the garbage collector.
+                let object =
+                    EmbedderObject::new(agent, None, data, gc.nogc()).scope(agent, gc.nogc());
+                let prototype = get_prototype_from_constructor(
+                    agent,
+                    new_target.get(agent),
+                    ProtoIntrinsics::Object,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                let gc = gc.into_nogc();
+                let object = object.get(agent).bind(gc);
+                if prototype.is_some() {
+                    object.internal_set_prototype(agent, prototype);
+                }
+                Ok(object.into_value())
+            },
+            BuiltinFunctionArgs::new(length, name),
+            gc,
+        );
+        Self {
+            agent,
+            name,
+            constructor,
+            prototype,
+            gc,
+        }
+    }
+
+    /// Define a method called `name` on the class prototype.
+    #[must_use]
+    pub fn with_method(
+        self,
+        name: &'static str,
+        length: u32,
+        method: impl for<'a> Fn(
+            &mut Agent,
+            Value,
+            ArgumentsList,
+            GcScope<'a, '_>,
+        ) -> JsResult<'a, Value<'a>>
+        + 'static,
+    ) -> Self {
+        let function = create_builtin_closure(
+            self.agent,
+            method,
+            BuiltinFunctionArgs::new(length, name),
+            self.gc,
+        );
+        self.with_prototype_property(
+            name,
+            PropertyDescriptor {
+                value: Some(function.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Define a read-only accessor property called `name` on the class
+    /// prototype.
+    #[must_use]
+    pub fn with_getter(
+        self,
+        name: &'static str,
+        getter: impl for<'a> Fn(
+            &mut Agent,
+            Value,
+            ArgumentsList,
+            GcScope<'a, '_>,
+        ) -> JsResult<'a, Value<'a>>
+        + 'static,
+    ) -> Self {
+        let getter = create_builtin_closure(
+            self.agent,
+            getter,
+            BuiltinFunctionArgs {
+                prefix: Some("get"),
+                ..BuiltinFunctionArgs::new(0, name)
+            },
+            self.gc,
+        );
+        self.with_prototype_property(
+            name,
+            PropertyDescriptor {
+                get: Some(getter.into_function()),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Define an accessor property called `name` with a getter and a setter
+    /// on the class prototype.
+    #[must_use]
+    pub fn with_accessor(
+        self,
+        name: &'static str,
+        getter: impl for<'a> Fn(
+            &mut Agent,
+            Value,
+            ArgumentsList,
+            GcScope<'a, '_>,
+        ) -> JsResult<'a, Value<'a>>
+        + 'static,
+        setter: impl for<'a> Fn(
+            &mut Agent,
+            Value,
+            ArgumentsList,
+            GcScope<'a, '_>,
+        ) -> JsResult<'a, Value<'a>>
+        + 'static,
+    ) -> Self {
+        let getter = create_builtin_closure(
+            self.agent,
+            getter,
+            BuiltinFunctionArgs {
+                prefix: Some("get"),
+                ..BuiltinFunctionArgs::new(0, name)
+            },
+            self.gc,
+        );
+        let setter = create_builtin_closure(
+            self.agent,
+            setter,
+            BuiltinFunctionArgs {
+                prefix: Some("set"),
+                ..BuiltinFunctionArgs::new(1, name)
+            },
+            self.gc,
+        );
+        self.with_prototype_property(
+            name,
+            PropertyDescriptor {
+                get: Some(getter.into_function()),
+                set: Some(setter.into_function()),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn with_prototype_property(
+        self,
+        name: &'static str,
+        descriptor: PropertyDescriptor<'gc>,
+    ) -> Self {
+        let key = PropertyKey::from_static_str(self.agent, name, self.gc);
+        let success = unwrap_try(
+            self.prototype
+                .try_define_own_property(self.agent, key, descriptor, self.gc),
+        );
+        assert!(success, "Failed to define {name} on the class prototype");
+        self
+    }
+
+    /// Finish the class and return its constructor.
+    ///
+    /// The constructor gets a non-writable `prototype` property and the
+    /// prototype gets a `constructor` property and a `@@toStringTag` property
+    /// with the name of the class.
+    pub fn build(self) -> BuiltinFunction<'gc> {
+        let Self {
+            agent,
+            name,
+            constructor,
+            prototype,
+            gc,
+        } = self;
+        let name = String::from_static_str(agent, name, gc);
+        unwrap_try(constructor.try_define_own_property(
+            agent,
+            BUILTIN_STRING_MEMORY.prototype.to_property_key(),
+            PropertyDescriptor {
+                value: Some(prototype.into_value()),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(false),
+                ..Default::default()
+            },
+            gc,
+        ));
+        unwrap_try(prototype.try_define_own_property(
+            agent,
+            BUILTIN_STRING_MEMORY.constructor.to_property_key(),
+            PropertyDescriptor {
+                value: Some(constructor.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc,
+        ));
+        unwrap_try(prototype.try_define_own_property(
+            agent,
+            WellKnownSymbolIndexes::ToStringTag.to_property_key(),
+            PropertyDescriptor {
+                value: Some(name.into_value()),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc,
+        ));
+        constructor
+    }
+}
@@ -2,17 +2,76 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::heap::{CompactionLists, HeapMarkAndSweep, WorkQueues};
+use core::any::Any;
 
-#[derive(Debug, Clone)]
-pub struct EmbedderObjectHeapData {}
+use crate::{
+    ecmascript::types::{OrdinaryObject, Value},
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
 
-impl HeapMarkAndSweep for EmbedderObjectHeapData {
-    fn mark_values(&self, _queues: &mut WorkQueues) {
-        let Self {} = self;
+/// Host-defined data carried by an
+/// [`EmbedderObject`](super::EmbedderObject).
+///
+/// The data is dropped on the Agent's thread when the embedder object is
+/// garbage collected; implement [`Drop`] to finalize it.
+pub trait EmbedderData: Any {
+    /// Visit the JavaScript Values held by the data to keep them alive during
+    /// garbage collection.
+    ///
+    /// Data that holds JavaScript Values must implement both this method and
+    /// [`EmbedderData::sweep_values`].
+    fn mark_values(&self, _mark: &mut dyn FnMut(Value<'static>)) {}
+
+    /// Visit the JavaScript Values held by the data to update them after the
+    /// heap has been compacted by garbage collection.
+    fn sweep_values(&mut self, _sweep: &mut dyn FnMut(&mut Value<'static>)) {}
+}
+
+pub struct EmbedderObjectHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// The host-defined data of the object.
+    pub(crate) data: Box<dyn EmbedderData>,
+}
+
+// SAFETY: The host-defined data is only accessed on the thread that owns the
+// Agent: the garbage collector marks and sweeps embedder objects on that
+// thread.
+unsafe impl Send for EmbedderObjectHeapData<'_> {}
+
+impl core::fmt::Debug for EmbedderObjectHeapData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EmbedderObjectHeapData")
+            .field("object_index", &self.object_index)
+            .finish_non_exhaustive()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for EmbedderObjectHeapData<'_> {
+    type Of<'a> = EmbedderObjectHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for EmbedderObjectHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self { object_index, data } = self;
+        object_index.mark_values(queues);
+        data.mark_values(&mut |value| value.mark_values(queues));
     }
 
-    fn sweep_values(&mut self, _compactions: &CompactionLists) {
-        let Self {} = self;
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self { object_index, data } = self;
+        object_index.sweep_values(compactions);
+        data.sweep_values(&mut |value| value.sweep_values(compactions));
     }
 }
@@ -3,10 +3,12 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod data;
+mod stack_trace;
 
 use core::ops::{Index, IndexMut};
 
 pub(crate) use data::ErrorHeapData;
+pub(crate) use stack_trace::capture_stack_trace;
 
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::{HeapRootData, HeapRootRef, Rootable};
@@ -17,7 +17,9 @@ pub struct ErrorHeapData<'a> {
     pub(crate) kind: ExceptionType,
     pub(crate) message: Option<String<'a>>,
     pub(crate) cause: Option<Value<'a>>,
-    // TODO: stack? name?
+    /// Call sites captured when the Error was created, formatted as the lines
+    /// following the header line of the `stack` string.
+    pub(crate) stack: Option<String<'a>>,
 }
 
 impl<'a> ErrorHeapData<'a> {
@@ -31,6 +33,7 @@ impl<'a> ErrorHeapData<'a> {
             kind,
             message,
             cause,
+            stack: None,
         }
     }
 }
@@ -57,11 +60,13 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
 
         object_index.mark_values(queues);
         message.mark_values(queues);
         cause.mark_values(queues);
+        stack.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -70,9 +75,11 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
         object_index.sweep_values(compactions);
         message.sweep_values(compactions);
         cause.sweep_values(compactions);
+        stack.sweep_values(compactions);
     }
 }
@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        execution::Agent,
+        types::{Function, String},
+    },
+    engine::context::NoGcScope,
+};
+
+/// Maximum number of call sites captured into an Error's stack trace. This
+/// matches V8's default `Error.stackTraceLimit`.
+const STACK_TRACE_LIMIT: usize = 10;
+
+/// Capture the call sites of the execution context stack, formatted as the
+/// V8-compatible `    at name (file:line:column)` lines that follow the
+/// header line of an Error's `stack` string.
+///
+/// The topmost `skip` execution contexts are left out of the stack trace:
+/// Error constructors use this to leave themselves out.
+pub(crate) fn capture_stack_trace<'a>(
+    agent: &mut Agent,
+    skip: usize,
+    gc: NoGcScope<'a, '_>,
+) -> Option<String<'a>> {
+    let call_sites = agent
+        .execution_context_stack
+        .iter()
+        .enumerate()
+        .rev()
+        .skip(skip)
+        .take(STACK_TRACE_LIMIT)
+        .map(|(index, context)| {
+            let function_name = context
+                .function
+                .and_then(|function| match function {
+                    Function::BoundFunction(f) => agent[f].name,
+                    Function::BuiltinFunction(f) => agent[f].initial_name,
+                    Function::ECMAScriptFunction(f) => agent[f].name,
+                    Function::BuiltinGeneratorFunction
+                    | Function::BuiltinConstructorFunction(_)
+                    | Function::BuiltinPromiseResolvingFunction(_)
+                    | Function::BuiltinPromiseCollectorFunction
+                    | Function::BuiltinProxyRevokerFunction(_) => None,
+                })
+                .filter(|name| !name.is_empty_string());
+            let location = if let Some(code) = context.ecmascript_code {
+                let source_code = code.source_code;
+                let source_name = source_code.get_name(agent).unwrap_or("<anonymous>");
+                // Find the VM executing in this execution context, and the
+                // source position of the instruction it is executing.
+                let position = agent
+                    .vm_frames
+                    .iter()
+                    .rev()
+                    .find(|frame| frame.execution_context_index == index)
+                    .and_then(|frame| frame.executable.get_source_position(agent, frame.ip()));
+                if let Some(position) = position {
+                    let (line, column) = source_code.get_line_and_column(agent, position);
+                    format!("{source_name}:{line}:{column}")
+                } else {
+                    source_name.to_string()
+                }
+            } else {
+                // Builtin functions have no source code.
+                "<anonymous>".to_string()
+            };
+            match function_name {
+                Some(function_name) => format!(
+                    "    at {} ({location})",
+                    function_name.as_wtf8(agent).to_string_lossy()
+                ),
+                None => format!("    at {location}"),
+            }
+        })
+        .collect::<Vec<_>>();
+    if call_sites.is_empty() {
+        return None;
+    }
+    Some(String::from_string(agent, call_sites.join("\n"), gc))
+}
@@ -6,14 +6,19 @@ use core::ops::{Index, IndexMut};
 
 use crate::{
     ecmascript::{
-        execution::{Agent, ProtoIntrinsics},
+        abstract_operations::operations_on_objects::call_function,
+        builtins::ArgumentsList,
+        execution::{
+            Agent, ExecutionContext, JsResult, ProtoIntrinsics,
+            agent::{InnerJob, Job},
+        },
         types::{
             InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
         },
     },
     engine::{
-        context::{Bindable, NoGcScope},
-        rootable::{HeapRootData, HeapRootRef, Rootable},
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
     },
     heap::{
         CreateHeapData, Heap, HeapMarkAndSweep,
@@ -78,6 +83,17 @@ impl<'a> From<FinalizationRegistry<'a>> for Object<'a> {
     }
 }
 
+impl<'a> TryFrom<Object<'a>> for FinalizationRegistry<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::FinalizationRegistry(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for FinalizationRegistry<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::FinalizationRegistry;
 
@@ -173,3 +189,111 @@ impl HeapMarkAndSweep for FinalizationRegistry<'static> {
         compactions.finalization_registrys.shift_index(&mut self.0);
     }
 }
+
+/// A host job that cleans up every FinalizationRegistry with cells whose
+/// targets have been collected.
+///
+/// The job does not refer to any particular registry, so it does not root
+/// anything and dropping it without running it leaks nothing.
+pub(crate) struct FinalizationRegistryCleanupJob;
+
+impl FinalizationRegistryCleanupJob {
+    pub(crate) fn run<'a>(self, agent: &mut Agent, mut gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let mut index = 0;
+        while index < agent.heap.finalization_registrys.len() {
+            let Some(data) = agent.heap.finalization_registrys[index].as_ref() else {
+                index += 1;
+                continue;
+            };
+            if !data.has_cleared_cells() {
+                index += 1;
+                continue;
+            }
+            let realm = data.realm;
+            let finalization_registry =
+                FinalizationRegistry(FinalizationRegistryIndex::from_index(index));
+            index += 1;
+            if let Some(realm) = realm {
+                agent.execution_context_stack.push(ExecutionContext {
+                    ecmascript_code: None,
+                    function: None,
+                    realm,
+                    script_or_module: None,
+                });
+            }
+            // Note: If a cleanup callback triggers garbage collection, the
+            // registries may get compacted and some of them skipped here.
+            // Those are cleaned up by the job enqueued after that collection.
+            let result =
+                cleanup_finalization_registry(agent, finalization_registry, gc.reborrow()).unbind();
+            if realm.is_some() {
+                agent.execution_context_stack.pop();
+            }
+            result?;
+        }
+        Ok(())
+    }
+}
+
+/// ### [9.12 CleanupFinalizationRegistry ( finalizationRegistry )](https://tc39.es/ecma262/#sec-cleanup-finalization-registry)
+///
+/// The abstract operation CleanupFinalizationRegistry takes argument
+/// finalizationRegistry (a FinalizationRegistry) and returns either a
+/// normal completion containing UNUSED or a throw completion.
+fn cleanup_finalization_registry<'a>(
+    agent: &mut Agent,
+    finalization_registry: FinalizationRegistry,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let finalization_registry = finalization_registry.bind(gc.nogc());
+    // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+    // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+    let callback = agent[finalization_registry].cleanup_callback.unwrap();
+    let callback = callback.scope(agent, gc.nogc());
+    let finalization_registry = finalization_registry.scope(agent, gc.nogc());
+    // 3. While finalizationRegistry.[[Cells]] contains a Record cell such
+    //    that cell.[[WeakRefTarget]] is EMPTY, an implementation may
+    //    perform the following steps:
+    loop {
+        // a. Choose any such cell.
+        // b. Remove cell from finalizationRegistry.[[Cells]].
+        let finalization_registry = finalization_registry.get(agent);
+        let Some(held_value) = agent[finalization_registry].take_cleared_cell() else {
+            break;
+        };
+        // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+        call_function(
+            agent,
+            callback.get(agent),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [held_value])),
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    // 4. Return UNUSED.
+    Ok(())
+}
+
+/// ### [9.9.4.1 HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )](https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry)
+///
+/// Enqueue a cleanup job if any FinalizationRegistry has cells whose targets
+/// have been collected. This is called after garbage collection has finished.
+pub(crate) fn enqueue_finalization_registry_cleanup_jobs(agent: &mut Agent) {
+    if !agent
+        .heap
+        .finalization_registrys
+        .iter()
+        .flatten()
+        .any(|data| data.has_cleared_cells())
+    {
+        return;
+    }
+    let job = Job {
+        realm: None,
+        inner: InnerJob::FinalizationRegistryCleanup(FinalizationRegistryCleanupJob),
+    };
+    agent
+        .host_hooks
+        .host_enqueue_finalization_registry_cleanup_job(job);
+}
@@ -3,14 +3,97 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::{
+        execution::Realm,
+        types::{Function, OrdinaryObject, Value},
+    },
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
 
+/// A Record in a FinalizationRegistry's \[\[Cells]] List.
+#[derive(Debug, Clone)]
+pub(crate) struct FinalizationRegistryCell<'a> {
+    /// \[\[WeakRefTarget]]
+    ///
+    /// This is held weakly, and is set to None (EMPTY) when the target is
+    /// garbage collected.
+    pub(crate) target: Option<Value<'a>>,
+    /// \[\[HeldValue]]
+    pub(crate) held_value: Value<'a>,
+    /// \[\[UnregisterToken]]
+    ///
+    /// This is held weakly, and is set to None (EMPTY) when the token is
+    /// garbage collected.
+    pub(crate) unregister_token: Option<Value<'a>>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct FinalizationRegistryHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Realm]]
+    pub(crate) realm: Option<Realm<'a>>,
+    /// \[\[CleanupCallback]]
+    pub(crate) cleanup_callback: Option<Function<'a>>,
+    /// \[\[Cells]]
+    pub(crate) cells: Vec<FinalizationRegistryCell<'a>>,
+}
+
+impl FinalizationRegistryHeapData<'_> {
+    pub(crate) fn register(
+        &mut self,
+        target: Value,
+        held_value: Value,
+        unregister_token: Option<Value>,
+    ) {
+        self.cells.push(FinalizationRegistryCell {
+            target: Some(target.unbind()),
+            held_value: held_value.unbind(),
+            unregister_token: unregister_token.map(|token| token.unbind()),
+        });
+    }
+
+    /// Remove all cells registered with the given unregister token. Returns
+    /// true if any cells were removed.
+    pub(crate) fn unregister(&mut self, unregister_token: Value) -> bool {
+        let unregister_token = unregister_token.unbind();
+        let len = self.cells.len();
+        self.cells.retain(|cell| {
+            cell.unregister_token.map(|token| token.unbind()) != Some(unregister_token)
+        });
+        self.cells.len() != len
+    }
+
+    /// Returns true if the registry has cells whose targets have been
+    /// collected.
+    pub(crate) fn has_cleared_cells(&self) -> bool {
+        self.cells.iter().any(|cell| cell.target.is_none())
+    }
+
+    /// Remove the first cell whose target has been collected, and return its
+    /// held value.
+    pub(crate) fn take_cleared_cell(&mut self) -> Option<Value<'static>> {
+        let index = self.cells.iter().position(|cell| cell.target.is_none())?;
+        Some(self.cells.remove(index).held_value.unbind())
+    }
+}
+
+impl FinalizationRegistryHeapData<'static> {
+    /// Clear the targets and unregister tokens of all cells that were not
+    /// marked.
+    pub(crate) fn clear_dead_cells(&mut self, bits: &HeapBits) {
+        for cell in self.cells.iter_mut() {
+            if cell.target.is_some_and(|target| !bits.is_marked(target)) {
+                cell.target = None;
+            }
+            if cell
+                .unregister_token
+                .is_some_and(|token| !bits.is_marked(token))
+            {
+                cell.unregister_token = None;
+            }
+        }
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -30,12 +113,40 @@ unsafe impl Bindable for FinalizationRegistryHeapData<'_> {
 
 impl HeapMarkAndSweep for FinalizationRegistryHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { object_index } = self;
+        let Self {
+            object_index,
+            realm,
+            cleanup_callback,
+            cells,
+        } = self;
         object_index.mark_values(queues);
+        realm.mark_values(queues);
+        cleanup_callback.mark_values(queues);
+        for cell in cells {
+            // Note: Targets and unregister tokens are held weakly.
+            cell.held_value.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { object_index } = self;
+        let Self {
+            object_index,
+            realm,
+            cleanup_callback,
+            cells,
+        } = self;
         object_index.sweep_values(compactions);
+        realm.sweep_values(compactions);
+        cleanup_callback.sweep_values(compactions);
+        for cell in cells {
+            let FinalizationRegistryCell {
+                target,
+                held_value,
+                unregister_token,
+            } = cell;
+            target.sweep_values(compactions);
+            held_value.sweep_values(compactions);
+            unregister_token.sweep_values(compactions);
+        }
     }
 }
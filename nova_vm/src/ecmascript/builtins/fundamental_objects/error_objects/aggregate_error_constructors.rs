@@ -16,7 +16,8 @@ use crate::{
         },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
         builtins::{
-            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor, error::Error,
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            error::{Error, capture_stack_trace},
             ordinary::ordinary_create_from_constructor,
         },
         execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
@@ -86,10 +87,13 @@ impl AggregateErrorConstructor {
             .bind(gc.nogc());
         // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
         let message = message.map(|message| message.get(agent).bind(gc.nogc()));
+        // Note: The Error constructor itself is left out of the stack trace.
+        let stack = capture_stack_trace(agent, 1, gc.nogc());
         let heap_data = &mut agent[o];
         heap_data.kind = ExceptionType::Error;
         heap_data.message = message.unbind();
         heap_data.cause = cause.unbind();
+        heap_data.stack = stack.unbind();
         // 5. Let errorsList be ? IteratorToList(? GetIterator(errors, sync)).
         let Some(iterator_record) = get_iterator(agent, errors.get(agent), false, gc.reborrow())
             .unbind()?
@@ -11,6 +11,7 @@ use crate::ecmascript::builtins::Behaviour;
 use crate::ecmascript::builtins::Builtin;
 use crate::ecmascript::builtins::BuiltinIntrinsicConstructor;
 use crate::ecmascript::builtins::error::Error;
+use crate::ecmascript::builtins::error::capture_stack_trace;
 use crate::ecmascript::builtins::ordinary::ordinary_create_from_constructor;
 use crate::ecmascript::execution::Agent;
 use crate::ecmascript::execution::JsResult;
@@ -112,16 +113,19 @@ impl ErrorConstructor {
             ProtoIntrinsics::Error,
             gc.reborrow(),
         )
-        .unbind()?
-        .bind(gc.into_nogc());
-        let o = Error::try_from(o).unwrap();
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let o = Error::try_from(o.bind(gc)).unwrap();
         // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
         let message = message.map(|message| message.get(agent));
         let cause = cause.map(|c| c.get(agent));
+        // Note: The Error constructor itself is left out of the stack trace.
+        let stack = capture_stack_trace(agent, 1, gc);
         let heap_data = &mut agent[o];
         heap_data.kind = ExceptionType::Error;
         heap_data.message = message;
         heap_data.cause = cause;
+        heap_data.stack = stack.unbind();
         // 5. Return O.
         Ok(o.into_value())
     }
@@ -4,11 +4,19 @@
 
 use crate::{
     ecmascript::{
-        abstract_operations::{operations_on_objects::get, type_conversion::to_string},
-        builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
+        abstract_operations::{
+            operations_on_objects::{create_data_property_or_throw, get},
+            type_conversion::to_string,
+        },
+        builders::{
+            builtin_function_builder::BuiltinFunctionBuilder,
+            ordinary_object_builder::OrdinaryObjectBuilder,
+        },
+        builtins::{ArgumentsList, Behaviour, Builtin, error::Error},
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, IntoValue, Object, PropertyKey, String, Value},
+        types::{
+            BUILTIN_STRING_MEMORY, IntoFunction, IntoValue, Object, PropertyKey, String, Value,
+        },
     },
     engine::{
         context::{Bindable, GcScope},
@@ -28,6 +36,26 @@ impl Builtin for ErrorPrototypeToString {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::to_string);
 }
 
+struct ErrorPrototypeGetStack;
+
+impl Builtin for ErrorPrototypeGetStack {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_stack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::get_stack);
+}
+
+struct ErrorPrototypeSetStack;
+
+impl Builtin for ErrorPrototypeSetStack {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.set_stack;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::set_stack);
+}
+
 impl ErrorPrototype {
     /// ### [20.5.3.4 Error.prototype.toString ( )](https://tc39.es/ecma262/#sec-error.prototype.tostring)
     fn to_string<'gc>(
@@ -104,6 +132,72 @@ impl ErrorPrototype {
         }
     }
 
+    /// ### get Error.prototype.stack
+    ///
+    /// Non-standard accessor compatible with V8's `stack` property. Returns
+    /// the result of Error.prototype.toString followed by the call sites that
+    /// were captured when the Error was created, one per line.
+    fn get_stack<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let Ok(error) = Error::try_from(this_value) else {
+            return Ok(Value::Undefined);
+        };
+        let call_sites = agent[error].stack;
+        let call_sites = call_sites.map(|call_sites| call_sites.scope(agent, gc.nogc()));
+        let header = Self::to_string(
+            agent,
+            error.into_value().unbind(),
+            ArgumentsList::default(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let header = String::try_from(header.bind(gc)).unwrap();
+        let Some(call_sites) = call_sites else {
+            return Ok(header.into_value());
+        };
+        let result = format!(
+            "{}\n{}",
+            header.as_str(agent),
+            call_sites.get(agent).as_str(agent)
+        );
+        Ok(String::from_string(agent, result, gc).into_value())
+    }
+
+    /// ### set Error.prototype.stack
+    ///
+    /// Defines `stack` as an own data property of the this value, shadowing
+    /// the accessor.
+    fn set_stack<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let value = arguments.get(0).bind(gc.nogc());
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        create_data_property_or_throw(
+            agent,
+            o.unbind(),
+            BUILTIN_STRING_MEMORY.stack.into(),
+            value.unbind(),
+            gc,
+        )?;
+        Ok(Value::Undefined)
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let object_prototype = intrinsics.object_prototype();
@@ -111,7 +205,7 @@ impl ErrorPrototype {
         let error_constructor = intrinsics.error();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(4)
+            .with_property_capacity(5)
             .with_prototype(object_prototype)
             .with_constructor_property(error_constructor)
             .with_property(|builder| {
@@ -128,6 +222,23 @@ impl ErrorPrototype {
                     .with_value(BUILTIN_STRING_MEMORY.Error.into())
                     .build()
             })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.stack.into())
+                    .with_getter_and_setter(|agent| {
+                        (
+                            BuiltinFunctionBuilder::new::<ErrorPrototypeGetStack>(agent, realm)
+                                .build()
+                                .into_function(),
+                            BuiltinFunctionBuilder::new::<ErrorPrototypeSetStack>(agent, realm)
+                                .build()
+                                .into_function(),
+                        )
+                    })
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             .with_builtin_function_property::<ErrorPrototypeToString>()
             .build();
     }
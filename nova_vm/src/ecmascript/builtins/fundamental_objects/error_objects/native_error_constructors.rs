@@ -9,7 +9,8 @@ use crate::{
         abstract_operations::type_conversion::to_string,
         builders::builtin_function_builder::BuiltinFunctionBuilder,
         builtins::{
-            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor, error::Error,
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            error::{Error, capture_stack_trace},
             ordinary::ordinary_create_from_constructor,
         },
         execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
@@ -150,10 +151,13 @@ impl NativeErrorConstructors {
         let o = Error::try_from(o.get(agent).bind(gc)).unwrap();
         // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
         let msg = msg.map(|msg| msg.get(agent).bind(gc));
+        // Note: The Error constructor itself is left out of the stack trace.
+        let stack = capture_stack_trace(agent, 1, gc);
         let heap_data = &mut agent[o];
         heap_data.kind = error_kind;
         heap_data.message = msg.unbind();
         heap_data.cause = cause.unbind();
+        heap_data.stack = stack.unbind();
         Ok(o.into_value())
     }
 
@@ -349,6 +349,19 @@ impl FunctionPrototype {
         let this_value = this_value.bind(gc.nogc());
         // Let func be the this value.
         let Ok(func) = Function::try_from(this_value) else {
+            // 4. If func is an Object and IsCallable(func) is true, return an
+            // implementation-defined String source code representation of func.
+            // The representation must have the syntax of a NativeFunction.
+            if let Value::Proxy(proxy) = this_value {
+                if proxy.is_callable(agent, gc.nogc()) {
+                    return Ok(Value::from_static_str(
+                        agent,
+                        "function () { [ native code ] }",
+                        gc.nogc(),
+                    )
+                    .unbind());
+                }
+            }
             // 5. Throw a TypeError exception.
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
@@ -357,60 +370,77 @@ impl FunctionPrototype {
             ));
         };
 
-        match func {
-            // 2. If func is an Object, func has a [[SourceText]] internal slot,
-            // func.[[SourceText]] is a sequence of Unicode code points, and
-            // HostHasSourceTextAvailable(func) is true, then
+        // 2. If func is an Object, func has a [[SourceText]] internal slot,
+        // func.[[SourceText]] is a sequence of Unicode code points, and
+        // HostHasSourceTextAvailable(func) is true, then
+        let source_text = match func {
             Function::ECMAScriptFunction(idx) => {
-                // a. Return CodePointsToString(func.[[SourceText]]).
                 let data = &agent[idx].ecmascript_function;
-                let span = data.source_text;
-                let source_text = data.source_code.get_source_text(agent)
-                    [(span.start as usize)..(span.end as usize)]
-                    .to_string();
-                Ok(Value::from_string(agent, source_text, gc.nogc()).unbind())
+                Some((data.source_code, data.source_text))
             }
-            // 4. If func is an Object and IsCallable(func) is true, return an
-            // implementation-defined String source code representation of func.
-            // The representation must have the syntax of a NativeFunction.
-            Function::BoundFunction(_) => todo!(),
-            // 3. If func is a built-in function object, return an
-            // implementation-defined String source code representation of func.
-            // The representation must have the syntax of a NativeFunction.
-            // Additionally, if func has an [[InitialName]] internal slot and
-            // func.[[InitialName]] is a String, the portion of the returned
-            // String that would be matched by NativeFunctionAccessor_opt
-            // PropertyName must be the value of func.[[InitialName]].
-            Function::BuiltinFunction(idx) => {
+            // Note: Class default constructors are built-in constructor
+            // functions that have the [[SourceText]] of their class.
+            Function::BuiltinConstructorFunction(idx) => {
                 let data = &agent[idx];
-                let initial_name = data.initial_name.map_or_else(
-                    || "function () {{ [ native code ] }}".into(),
-                    |initial_name| match initial_name {
-                        crate::ecmascript::types::String::String(idx) => {
-                            format!("function {}() {{ [ native code ] }}", agent[idx].as_str())
-                        }
-                        crate::ecmascript::types::String::SmallString(string) => {
-                            format!("function {}() {{ [ native code ] }}", string.as_str())
-                        }
-                    },
-                );
-                Ok(Value::from_string(agent, initial_name, gc.nogc()).unbind())
-            }
-            Function::BuiltinGeneratorFunction => todo!(),
-            Function::BuiltinConstructorFunction(_) => {
-                Ok(Value::from_static_str(agent, "class { [ native code ] }", gc.nogc()).unbind())
+                Some((data.source_code, data.source_text))
             }
-            Function::BuiltinPromiseResolvingFunction(_) => {
-                // Promise resolving functions have no initial name.
-                Ok(
-                    Value::from_static_str(agent, "function () { [ native code ] }", gc.nogc())
-                        .unbind(),
+            Function::BoundFunction(_)
+            | Function::BuiltinFunction(_)
+            | Function::BuiltinGeneratorFunction
+            | Function::BuiltinPromiseResolvingFunction(_)
+            | Function::BuiltinPromiseCollectorFunction
+            | Function::BuiltinProxyRevokerFunction(_) => None,
+        };
+        if let Some((source_code, span)) = source_text {
+            if agent.host_hooks.host_has_source_text_available(func) {
+                // a. Return CodePointsToString(func.[[SourceText]]).
+                let source_text = skip_leading_trivia(
+                    &source_code.get_source_text(agent)[(span.start as usize)..(span.end as usize)],
                 )
+                .to_string();
+                return Ok(Value::from_string(agent, source_text, gc.nogc()).unbind());
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
         }
 
+        // 3. If func is a built-in function object, return an
+        // implementation-defined String source code representation of func.
+        // The representation must have the syntax of a NativeFunction.
+        // Additionally, if func has an [[InitialName]] internal slot and
+        // func.[[InitialName]] is a String, the portion of the returned
+        // String that would be matched by NativeFunctionAccessor_opt
+        // PropertyName must be the value of func.[[InitialName]].
+        // 4. If func is an Object and IsCallable(func) is true, return an
+        // implementation-defined String source code representation of func.
+        // The representation must have the syntax of a NativeFunction.
+        let initial_name = match func {
+            Function::BuiltinFunction(idx) => agent[idx].initial_name,
+            // Note: Bound functions, built-in functions without an initial
+            // name, and functions whose source text is hidden by the host are
+            // represented as anonymous native functions.
+            Function::BoundFunction(_)
+            | Function::ECMAScriptFunction(_)
+            | Function::BuiltinGeneratorFunction
+            | Function::BuiltinConstructorFunction(_)
+            | Function::BuiltinPromiseResolvingFunction(_)
+            | Function::BuiltinPromiseCollectorFunction
+            | Function::BuiltinProxyRevokerFunction(_) => None,
+        };
+        let source_text = match initial_name {
+            Some(initial_name) => format!(
+                "function {}() {{ [ native code ] }}",
+                initial_name.as_wtf8(agent).to_string_lossy()
+            ),
+            None => {
+                return Ok(Value::from_static_str(
+                    agent,
+                    "function () { [ native code ] }",
+                    gc.nogc(),
+                )
+                .unbind());
+            }
+        };
+        Ok(Value::from_string(agent, source_text, gc.nogc()).unbind())
+
         // NOTE: NativeFunction means the following string:
         // `function <?:"get"/"set"> <?:name> (<?:parameters>) { [ native code ] }``
         // <?:...> is an optional template part.
@@ -543,3 +573,23 @@ fn create_throw_type_error_backing_object(
         values,
     })
 }
+
+/// Skips the whitespace and comments at the start of a source text slice.
+///
+/// The recorded source text of a static class method starts right after the
+/// `static` keyword, so it may begin with trivia that is not part of the
+/// MethodDefinition.
+fn skip_leading_trivia(mut source_text: &str) -> &str {
+    loop {
+        let trimmed = source_text.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("//") {
+            source_text = rest
+                .find(['\n', '\r', '\u{2028}', '\u{2029}'])
+                .map_or("", |i| &rest[i..]);
+        } else if let Some(rest) = trimmed.strip_prefix("/*") {
+            source_text = rest.find("*/").map_or("", |i| &rest[i + 2..]);
+        } else {
+            return trimmed;
+        }
+    }
+}
@@ -2,5 +2,6 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+pub(crate) mod global_symbol_registry;
 pub(crate) mod symbol_constructor;
 pub(crate) mod symbol_prototype;
@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use ahash::RandomState;
+use hashbrown::HashTable;
+use wtf8::{Wtf8, Wtf8Buf};
+
+use crate::{
+    ecmascript::{
+        execution::Agent,
+        types::{String, Symbol},
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [GlobalSymbolRegistry](https://tc39.es/ecma262/#table-globalsymbolregistry-record-fields)
+///
+/// The GlobalSymbolRegistry is an append-only List that is globally
+/// available. It is shared by all realms. Prior to the evaluation of any
+/// ECMAScript code, it is initialized as a new empty List.
+///
+/// The registry is owned by the Agent and its symbols are held strongly: A
+/// registered symbol can always be recreated using `Symbol.for`, so it must
+/// not be garbage collected.
+#[derive(Debug, Default)]
+pub(crate) struct GlobalSymbolRegistry {
+    /// The \[\[Key]] and \[\[Symbol]] of each registered Symbol. Keys are
+    /// stored as WTF-8 so that strings with lone surrogates can be registered.
+    symbols: HashTable<(Wtf8Buf, Symbol<'static>)>,
+    hasher: RandomState,
+}
+
+impl GlobalSymbolRegistry {
+    /// Find the registered Symbol for the given key string.
+    pub(crate) fn get<'a>(&self, key: &Wtf8, gc: NoGcScope<'a, '_>) -> Option<Symbol<'a>> {
+        self.find(key).map(|symbol| symbol.bind(gc))
+    }
+
+    /// Register a new Symbol with the given key string.
+    pub(crate) fn insert(&mut self, key: Wtf8Buf, symbol: Symbol) {
+        debug_assert!(self.find(&key).is_none());
+        let hasher = &self.hasher;
+        self.symbols.insert_unique(
+            hasher.hash_one(&*key),
+            (key, symbol.unbind()),
+            |(key, _)| hasher.hash_one(&**key),
+        );
+    }
+
+    fn find(&self, key: &Wtf8) -> Option<Symbol<'static>> {
+        self.symbols
+            .find(self.hasher.hash_one(key), |(entry_key, _)| {
+                **entry_key == *key
+            })
+            .map(|(_, symbol)| *symbol)
+    }
+}
+
+/// ### [20.4.5.1 KeyForSymbol ( sym )](https://tc39.es/ecma262/#sec-keyforsymbol)
+///
+/// The abstract operation KeyForSymbol takes argument sym (a Symbol) and
+/// returns a String or undefined. If sym is in the GlobalSymbolRegistry the
+/// String used to register sym will be returned.
+pub(crate) fn key_for_symbol<'a>(agent: &Agent, sym: Symbol<'a>) -> Option<String<'a>> {
+    // Note: A registered symbol's [[Description]] is always its [[Key]].
+    let description = agent[sym].descriptor?;
+    // 1. For each element e of the GlobalSymbolRegistry List, do
+    // a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
+    let registered = agent
+        .global_symbol_registry
+        .find(description.as_wtf8(agent))?;
+    if registered == sym.unbind() {
+        Some(description)
+    } else {
+        // 2. Assert: GlobalSymbolRegistry does not currently contain an
+        //    entry for sym.
+        // 3. Return undefined.
+        None
+    }
+}
+
+impl HeapMarkAndSweep for GlobalSymbolRegistry {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        for (_, symbol) in self.symbols.iter() {
+            symbol.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        for (_, symbol) in self.symbols.iter_mut() {
+            symbol.sweep_values(compactions);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::ecmascript::builtins::ArgumentsList;
 use crate::ecmascript::builtins::Behaviour;
 use crate::ecmascript::builtins::Builtin;
 use crate::ecmascript::builtins::BuiltinIntrinsicConstructor;
+use crate::ecmascript::builtins::fundamental_objects::symbol_objects::global_symbol_registry::key_for_symbol;
 use crate::ecmascript::execution::Agent;
 use crate::ecmascript::execution::JsResult;
 use crate::ecmascript::execution::Realm;
@@ -24,6 +25,7 @@ use crate::engine::context::{Bindable, GcScope};
 use crate::heap::CreateHeapData;
 use crate::heap::IntrinsicConstructorIndexes;
 use crate::heap::WellKnownSymbolIndexes;
+use wtf8::Wtf8Buf;
 
 pub(crate) struct SymbolConstructor;
 
@@ -88,22 +90,64 @@ impl SymbolConstructor {
             .into_value())
     }
 
+    /// ### [20.4.2.2 Symbol.for ( key )](https://tc39.es/ecma262/#sec-symbol.for)
     fn r#for<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
         arguments: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Ok(arguments.get(0).unbind())
+        let key = arguments.get(0).bind(gc.nogc());
+        // 1. Let stringKey be ? ToString(key).
+        let string_key = to_string(agent, key.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let string_key = string_key.bind(gc);
+        // 2. For each element e of the GlobalSymbolRegistry List, do
+        // a. If SameValue(e.[[Key]], stringKey) is true, return e.[[Symbol]].
+        if let Some(symbol) = agent
+            .global_symbol_registry
+            .get(string_key.as_wtf8(agent), gc)
+        {
+            return Ok(symbol.into_value());
+        }
+        // 3. Assert: The GlobalSymbolRegistry List does not currently contain
+        //    an entry for stringKey.
+        // 4. Let newSymbol be a new Symbol whose [[Description]] is stringKey.
+        let new_symbol = agent
+            .heap
+            .create(SymbolHeapData {
+                descriptor: Some(string_key.unbind()),
+            })
+            .bind(gc);
+        // 5. Append the GlobalSymbolRegistry Record { [[Key]]: stringKey,
+        //    [[Symbol]]: newSymbol } to the GlobalSymbolRegistry List.
+        let key = string_key.as_wtf8(agent);
+        let mut buf = Wtf8Buf::with_capacity(key.len());
+        buf.push_wtf8(key);
+        agent.global_symbol_registry.insert(buf, new_symbol);
+        // 6. Return newSymbol.
+        Ok(new_symbol.into_value())
     }
 
+    /// ### [20.4.2.6 Symbol.keyFor ( sym )](https://tc39.es/ecma262/#sec-symbol.keyfor)
     fn key_for<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
         arguments: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Ok(arguments.get(0).unbind())
+        let gc = gc.into_nogc();
+        let sym = arguments.get(0).bind(gc);
+        // 1. If sym is not a Symbol, throw a TypeError exception.
+        let Value::Symbol(sym) = sym else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Symbol.keyFor argument is not a symbol",
+                gc,
+            ));
+        };
+        // 2. Return KeyForSymbol(sym).
+        Ok(key_for_symbol(agent, sym).map_or(Value::Undefined, |key| key.into_value()))
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
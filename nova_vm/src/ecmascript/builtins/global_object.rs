@@ -2,16 +2,19 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use core::fmt::Write;
+
 use ahash::AHashSet;
 use oxc_ast::ast::{BindingIdentifier, Program, VariableDeclarationKind};
 use oxc_ecmascript::BoundNames;
 use oxc_span::SourceType;
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
 
 use crate::ecmascript::abstract_operations::type_conversion::{
     is_trimmable_whitespace, to_int32, to_int32_number, to_number_primitive, to_string,
 };
 use crate::ecmascript::types::Primitive;
-use crate::engine::context::{Bindable, GcScope};
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
@@ -770,6 +773,212 @@ pub fn eval_declaration_instantiation<'a>(
     Ok(())
 }
 
+/// ### [19.2.6.5 Encode ( string, extraUnescaped )](https://tc39.es/ecma262/#sec-encode)
+///
+/// The abstract operation Encode takes arguments string (a String) and
+/// extraUnescaped (a String) and returns either a normal completion containing
+/// a String or a throw completion. It performs URI encoding and escaping,
+/// interpreting string as a sequence of UTF-16 encoded code points as
+/// described in 6.1.4. If a character is identified as unreserved in RFC 2396
+/// or appears in extraUnescaped, it is not escaped.
+pub(crate) fn encode<'gc>(
+    agent: &mut Agent,
+    string: String,
+    extra_unescaped: &str,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, String<'gc>> {
+    let string = string.bind(gc);
+    // 1. Let len be the length of string.
+    // 2. Let R be the empty String.
+    let mut r = std::string::String::with_capacity(string.len(agent));
+    // 3. Let alwaysUnescaped be the string-concatenation of the ASCII word
+    //    characters and "-.!~*'()".
+    // 4. Let unescapedSet be the string-concatenation of alwaysUnescaped and
+    //    extraUnescaped.
+    let is_unescaped = |c: char| {
+        c.is_ascii_alphanumeric() || "_-.!~*'()".contains(c) || extra_unescaped.contains(c)
+    };
+    let mut is_malformed = false;
+    // 5. Let k be 0.
+    // 6. Repeat, while k < len,
+    for cp in string.as_wtf8(agent).code_points() {
+        // a. Let C be the code unit at index k within string.
+        // c. Else,
+        // i. Let cp be CodePointAt(string, k).
+        // ii. If cp.[[IsUnpairedSurrogate]] is true, throw a URIError
+        //     exception.
+        let Some(c) = cp.to_char() else {
+            is_malformed = true;
+            break;
+        };
+        // b. If unescapedSet contains C, then
+        if is_unescaped(c) {
+            // i. Set k to k + 1.
+            // ii. Set R to the string-concatenation of R and C.
+            r.push(c);
+        } else {
+            // iii. Set k to k + cp.[[CodeUnitCount]].
+            // iv. Let Octets be the List of octets resulting by applying the
+            //     UTF-8 transformation to cp.[[CodePoint]].
+            let mut octets = [0u8; 4];
+            // v. For each element octet of Octets, do
+            for octet in c.encode_utf8(&mut octets).as_bytes() {
+                // 1. Let hex be the String representation of octet, formatted
+                //    as an uppercase hexadecimal number.
+                // 2. Set R to the string-concatenation of R, "%", and
+                //    StringPad(hex, 2, "0", start).
+                write!(r, "%{:02X}", octet).unwrap();
+            }
+        }
+    }
+    if is_malformed {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::UriError,
+            "URI malformed",
+            gc,
+        ));
+    }
+    // 7. Return R.
+    Ok(String::from_string(agent, r, gc))
+}
+
+/// ### [19.2.6.6 Decode ( string, preserveEscapeSet )](https://tc39.es/ecma262/#sec-decode)
+///
+/// The abstract operation Decode takes arguments string (a String) and
+/// preserveEscapeSet (a String) and returns either a normal completion
+/// containing a String or a throw completion. It performs URI unescaping and
+/// decoding, preserving any escape sequences that correspond to Basic Latin
+/// characters in preserveEscapeSet.
+pub(crate) fn decode<'gc>(
+    agent: &mut Agent,
+    string: String,
+    preserve_escape_set: &str,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, String<'gc>> {
+    let string = string.bind(gc);
+    let Some(r) = decode_wtf8(string.as_wtf8(agent), preserve_escape_set) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::UriError,
+            "URI malformed",
+            gc,
+        ));
+    };
+    Ok(String::from_wtf8_buf(agent, r, gc))
+}
+
+fn decode_wtf8(string: &Wtf8, preserve_escape_set: &str) -> Option<Wtf8Buf> {
+    // 1. Let len be the length of string.
+    let len = string.len();
+    // 2. Let R be the empty String.
+    let mut r = Wtf8Buf::with_capacity(len);
+    // 3. Let k be 0.
+    let mut k = 0;
+    // 4. Repeat, while k < len,
+    while k < len {
+        // a. Let C be the code unit at index k within string.
+        // b. Let S be C.
+        // c. If C is the code unit 0x0025 (PERCENT SIGN), then
+        if string.ascii_byte_at(k) != b'%' {
+            let c = string.slice_from(k).code_points().next().unwrap();
+            // d. Set R to the string-concatenation of R and S.
+            r.push(c);
+            // e. Set k to k + 1.
+            // NOTE: Lone surrogates take three bytes in WTF-8.
+            k += c.to_char().map_or(3, char::len_utf8);
+            continue;
+        }
+        // i. If k + 3 > len, throw a URIError exception.
+        // ii. Let escape be the substring of string from k to k + 3.
+        // iii. Let B be ParseHexOctet(string, k + 1).
+        // iv. If B is not an integer, throw a URIError exception.
+        let b = parse_hex_octet(string, k + 1)?;
+        // v. Set k to k + 2.
+        k += 2;
+        // vi. Let n be the number of leading 1 bits in B.
+        let n = b.leading_ones() as usize;
+        // vii. If n = 0, then
+        if n == 0 {
+            // 1. Let asciiChar be the code unit whose numeric value is B.
+            let ascii_char = b as char;
+            // 2. If preserveEscapeSet contains asciiChar, let S be escape.
+            //    Otherwise, let S be asciiChar.
+            if preserve_escape_set.contains(ascii_char) {
+                r.push_wtf8(string.slice(k - 2, k + 1));
+            } else {
+                r.push_char(ascii_char);
+            }
+        } else {
+            // viii. Else,
+            // 1. If n = 1 or n > 4, throw a URIError exception.
+            if n == 1 || n > 4 {
+                return None;
+            }
+            // 2. Let Octets be « B ».
+            let mut octets = [b, 0, 0, 0];
+            // 3. Let j be 1.
+            // 4. Repeat, while j < n,
+            for octet in octets.iter_mut().take(n).skip(1) {
+                // a. Set k to k + 1.
+                k += 1;
+                // b. If k + 3 > len, throw a URIError exception.
+                // c. If the code unit at index k within string is not the
+                //    code unit 0x0025 (PERCENT SIGN), throw a URIError
+                //    exception.
+                if k >= len || string.ascii_byte_at(k) != b'%' {
+                    return None;
+                }
+                // d. Let continuationByte be ParseHexOctet(string, k + 1).
+                // e. If continuationByte is not an integer, throw a URIError
+                //    exception.
+                // f. Append continuationByte to Octets.
+                *octet = parse_hex_octet(string, k + 1)?;
+                // g. Set k to k + 2.
+                k += 2;
+                // h. Set j to j + 1.
+            }
+            // 5. Assert: The length of Octets is n.
+            // 6. If Octets does not contain a valid UTF-8 encoding of a
+            //    Unicode code point, throw a URIError exception.
+            // 7. Let V be the code point obtained by applying the UTF-8
+            //    transformation to Octets, that is, from a List of octets
+            //    into a 21-bit value.
+            // 8. Let S be UTF16EncodeCodePoint(V).
+            r.push_str(core::str::from_utf8(&octets[..n]).ok()?);
+        }
+        // e. Set k to k + 1.
+        k += 1;
+    }
+    // 5. Return R.
+    Some(r)
+}
+
+/// ### [19.2.6.7 ParseHexOctet ( string, position )](https://tc39.es/ecma262/#sec-parsehexoctet)
+///
+/// The abstract operation ParseHexOctet takes arguments string (a String) and
+/// position (a non-negative integer) and returns either a non-negative
+/// integer or a non-empty List of SyntaxError objects. It parses a sequence of
+/// two hexadecimal characters at the specified position in string into an
+/// unsigned 8-bit integer.
+fn parse_hex_octet(string: &Wtf8, position: usize) -> Option<u8> {
+    // 1. Let len be the length of string.
+    // 2. Assert: position + 2 ≤ len.
+    if position + 2 > string.len() {
+        return None;
+    }
+    // 3. Let hexDigits be the substring of string from position to position + 2.
+    let hex_digits = [
+        string.ascii_byte_at(position),
+        string.ascii_byte_at(position + 1),
+    ];
+    // 4. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+    // 5. If parseResult is not a Parse Node, return parseResult.
+    // 6. Let n be the MV of parseResult.
+    // 7. Assert: n is in the inclusive interval from 0 to 255.
+    // 8. Return n.
+    let digit = |d: u8| (d as char).to_digit(16).map(|d| d as u8);
+    Some((digit(hex_digits[0])? << 4) | digit(hex_digits[1])?)
+}
+
 impl GlobalObject {
     /// ### [19.2.1 eval ( x )](https://tc39.es/ecma262/#sec-eval-x)
     ///
@@ -1085,53 +1294,238 @@ impl GlobalObject {
         }
     }
 
+    /// ### [19.2.6.1 decodeURI ( encodedURI )](https://tc39.es/ecma262/#sec-decodeuri-encodeduri)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by the
+    /// encodeURI function is replaced with the UTF-16 encoding of the code
+    /// point that it represents. Escape sequences that could not have been
+    /// introduced by encodeURI are not replaced.
     fn decode_uri<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let encoded_uri = arguments.get(0).bind(gc.nogc());
+        // 1. Let uriString be ? ToString(encodedURI).
+        let uri_string = to_string(agent, encoded_uri.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let uri_string = uri_string.bind(gc);
+        // 2. Let preserveEscapeSet be ";/?:@&=+$,#".
+        // 3. Return ? Decode(uriString, preserveEscapeSet).
+        decode(agent, uri_string, ";/?:@&=+$,#", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.2 decodeURIComponent ( encodedURIComponent )](https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by the
+    /// encodeURIComponent function is replaced with the UTF-16 encoding of the
+    /// code point that it represents.
     fn decode_uri_component<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let encoded_uri_component = arguments.get(0).bind(gc.nogc());
+        // 1. Let componentString be ? ToString(encodedURIComponent).
+        let component_string =
+            to_string(agent, encoded_uri_component.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let component_string = component_string.bind(gc);
+        // 2. Let preserveEscapeSet be the empty String.
+        // 3. Return ? Decode(componentString, preserveEscapeSet).
+        decode(agent, component_string, "", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.3 encodeURI ( uri )](https://tc39.es/ecma262/#sec-encodeuri-uri)
+    ///
+    /// This function computes a new version of a UTF-16 encoded (6.1.4) URI
+    /// in which each instance of certain code points is replaced by one, two,
+    /// three, or four escape sequences representing the UTF-8 encoding of the
+    /// code point.
     fn encode_uri<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let uri = arguments.get(0).bind(gc.nogc());
+        // 1. Let uriString be ? ToString(uri).
+        let uri_string = to_string(agent, uri.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let uri_string = uri_string.bind(gc);
+        // 2. Let extraUnescaped be ";/?:@&=+$,#".
+        // 3. Return ? Encode(uriString, extraUnescaped).
+        encode(agent, uri_string, ";/?:@&=+$,#", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.4 encodeURIComponent ( uriComponent )](https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent)
+    ///
+    /// This function computes a new version of a UTF-16 encoded (6.1.4) URI
+    /// in which each instance of certain code points is replaced by one, two,
+    /// three, or four escape sequences representing the UTF-8 encoding of the
+    /// code point.
     fn encode_uri_component<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let uri_component = arguments.get(0).bind(gc.nogc());
+        // 1. Let componentString be ? ToString(uriComponent).
+        let component_string = to_string(agent, uri_component.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let component_string = component_string.bind(gc);
+        // 2. Let extraUnescaped be the empty String.
+        // 3. Return ? Encode(componentString, extraUnescaped).
+        encode(agent, component_string, "", gc).map(|s| s.into_value())
     }
+
+    /// ### [B.2.1.1 escape ( string )](https://tc39.es/ecma262/#sec-escape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which certain code units have been
+    /// replaced by a hexadecimal escape sequence.
     fn escape<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let string = string.bind(gc);
+        // 2. Let len be the length of string.
+        // 3. Let R be the empty String.
+        let mut r = std::string::String::with_capacity(string.len(agent));
+        // 4. Let unescapedSet be the string-concatenation of the ASCII word
+        //    characters and "@*+-./".
+        let is_unescaped = |c: u16| {
+            u8::try_from(c).is_ok_and(|c| c.is_ascii_alphanumeric() || b"_@*+-./".contains(&c))
+        };
+        let mut buf = [0u16; 2];
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for cp in string.as_wtf8(agent).code_points() {
+            let code_units = match cp.to_char() {
+                Some(c) => &*c.encode_utf16(&mut buf),
+                // Lone surrogates are a single code unit.
+                None => {
+                    buf[0] = cp.to_u32() as u16;
+                    &buf[..1]
+                }
+            };
+            // a. Let C be the code unit at index k within string.
+            for &c in code_units {
+                // b. If unescapedSet contains C, then
+                if is_unescaped(c) {
+                    // i. Let S be C.
+                    r.push(c as u8 as char);
+                } else if c < 256 {
+                    // c. Else,
+                    // i. Let n be the numeric value of C.
+                    // ii. If n < 256, then
+                    // 1. Let hex be the String representation of n,
+                    //    formatted as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%" and
+                    //    StringPad(hex, 2, "0", start).
+                    write!(r, "%{:02X}", c).unwrap();
+                } else {
+                    // iii. Else,
+                    // 1. Let hex be the String representation of n,
+                    //    formatted as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%u" and
+                    //    StringPad(hex, 4, "0", start).
+                    write!(r, "%u{:04X}", c).unwrap();
+                }
+                // d. Set R to the string-concatenation of R and S.
+                // e. Set k to k + 1.
+            }
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc).into_value())
     }
+
+    /// ### [B.2.1.2 unescape ( string )](https://tc39.es/ecma262/#sec-unescape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which each escape sequence of the sort
+    /// that might be introduced by the escape function is replaced with the
+    /// code unit that it represents.
     fn unescape<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let string = string.bind(gc);
+        let code_points = string.as_wtf8(agent).code_points().collect::<Vec<_>>();
+        // 2. Let len be the length of string.
+        let len = code_points.len();
+        // 3. Let R be the empty String.
+        let mut r = Wtf8Buf::with_capacity(len);
+        // 4. Let k be 0.
+        let mut k = 0;
+        // 5. Repeat, while k < len,
+        while k < len {
+            // a. Let C be the code unit at index k within string.
+            let c = code_points[k];
+            // b. If C is the code unit 0x0025 (PERCENT SIGN), then
+            if c.to_u32() == u32::from(b'%') {
+                // i. Let hexDigits be the empty String.
+                // ii. Let optionalAdvance be 0.
+                // iii. If k + 5 < len and the code unit at index k + 1
+                //      within string is the code unit 0x0075 (LATIN SMALL
+                //      LETTER U), then
+                let (hex_digits, optional_advance) = if code_points
+                    .get(k + 1)
+                    .is_some_and(|c| c.to_u32() == u32::from(b'u'))
+                {
+                    // 1. Set hexDigits to the substring of string from k + 2
+                    //    to k + 6.
+                    // 2. Set optionalAdvance to 5.
+                    (code_points.get(k + 2..k + 6), 5)
+                } else {
+                    // iv. Else if k + 3 ≤ len, then
+                    // 1. Set hexDigits to the substring of string from k + 1
+                    //    to k + 3.
+                    // 2. Set optionalAdvance to 2.
+                    (code_points.get(k + 1..k + 3), 2)
+                };
+                // v. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+                let parse_result = hex_digits.and_then(|digits| {
+                    digits.iter().try_fold(0u32, |n, digit| {
+                        Some((n << 4) | digit.to_char()?.to_digit(16)?)
+                    })
+                });
+                // vi. If parseResult is a Parse Node, then
+                if let Some(n) = parse_result {
+                    // 1. Let n be the MV of parseResult.
+                    // 2. Set C to the code unit whose numeric value is n.
+                    // c. Set R to the string-concatenation of R and C.
+                    r.push(CodePoint::from_u32(n).unwrap());
+                    // 3. Set k to k + optionalAdvance.
+                    // d. Set k to k + 1.
+                    k += optional_advance + 1;
+                    continue;
+                }
+            }
+            // c. Set R to the string-concatenation of R and C.
+            r.push(c);
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 6. Return R.
+        Ok(String::from_wtf8_buf(agent, r, gc).into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
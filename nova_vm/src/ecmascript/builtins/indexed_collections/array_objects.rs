@@ -3,5 +3,6 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 pub(crate) mod array_constructor;
+pub(crate) mod array_from_async;
 pub(crate) mod array_iterator_objects;
 pub(crate) mod array_prototype;
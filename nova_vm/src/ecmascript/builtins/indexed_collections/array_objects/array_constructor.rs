@@ -31,6 +31,9 @@ use crate::ecmascript::builtins::Builtin;
 use crate::ecmascript::builtins::BuiltinGetter;
 use crate::ecmascript::builtins::BuiltinIntrinsicConstructor;
 use crate::ecmascript::builtins::array_create;
+use crate::ecmascript::builtins::check_array_elements_heap_limit;
+use crate::ecmascript::builtins::indexed_collections::array_objects::array_from_async::array_from_async;
+use crate::ecmascript::builtins::control_abstraction_objects::promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability;
 use crate::ecmascript::builtins::ordinary::get_prototype_from_constructor;
 use crate::ecmascript::execution::Agent;
 use crate::ecmascript::execution::JsResult;
@@ -77,6 +80,12 @@ impl Builtin for ArrayFrom {
     const LENGTH: u8 = 1;
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
 }
+struct ArrayFromAsync;
+impl Builtin for ArrayFromAsync {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::from_async);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromAsync;
+}
 struct ArrayIsArray;
 impl Builtin for ArrayIsArray {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::is_array);
@@ -172,6 +181,9 @@ impl ArrayConstructor {
                             gc,
                         ));
                     }
+                    // NOTE: The Array's elements must fit within the heap
+                    // limit.
+                    check_array_elements_heap_limit(agent, int_len as usize, gc)?;
                     let array = array_create(
                         agent,
                         int_len as usize,
@@ -535,6 +547,47 @@ impl ArrayConstructor {
         Ok(a.get(agent).into_value())
     }
 
+    /// ### [2.1.1.1 Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+    fn from_async<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let async_items = arguments.get(0).bind(gc.nogc());
+        let mapfn = arguments.get(1).bind(gc.nogc());
+        let this_arg = arguments.get(2).bind(gc.nogc());
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(agent, gc.nogc());
+        let promise = promise_capability.promise().scope(agent, gc.nogc());
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters
+        //    that captures C, mapfn, and thisArg and performs the following
+        //    steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        let result = array_from_async(
+            agent,
+            this_value.unbind(),
+            async_items.unbind(),
+            mapfn.unbind(),
+            this_arg.unbind(),
+            promise.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        if let Err(err) = result {
+            PromiseCapability::from_promise(promise.get(agent), true).reject(
+                agent,
+                err.value(),
+                gc.nogc(),
+            );
+        }
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(promise.get(agent).into_value().bind(gc.into_nogc()))
+    }
+
     /// ### [23.1.2.2 Array.isArray ( arg )](https://tc39.es/ecma262/#sec-array.isarray)
     fn is_array<'gc>(
         agent: &mut Agent,
@@ -624,9 +677,10 @@ impl ArrayConstructor {
         let array_prototype = intrinsics.array_prototype();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<ArrayConstructor>(agent, realm)
-            .with_property_capacity(5)
+            .with_property_capacity(6)
             .with_prototype(function_prototype)
             .with_builtin_function_property::<ArrayFrom>()
+            .with_builtin_function_property::<ArrayFromAsync>()
             .with_builtin_function_property::<ArrayIsArray>()
             .with_builtin_function_property::<ArrayOf>()
             .with_prototype_property(array_prototype.into_object())
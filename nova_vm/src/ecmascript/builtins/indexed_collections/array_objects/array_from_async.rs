@@ -0,0 +1,902 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [2.1.1.1 Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+//!
+//! The spec describes Array.fromAsync as an abstract closure that is run
+//! using AsyncFunctionStart. We have no way to suspend a builtin function in
+//! the middle of its steps, so instead the state of the closure is kept in an
+//! [`ArrayFromAsyncRecord`] on the heap and the closure is resumed as a
+//! promise reaction each time an awaited value settles.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    SmallInteger,
+    ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                IteratorRecord, get_iterator_from_method, iterator_close_with_error,
+                iterator_complete, iterator_step_value, iterator_value,
+            },
+            operations_on_objects::{
+                call_function, construct, create_data_property_or_throw, get, get_method,
+                length_of_array_like, set, throw_not_callable,
+            },
+            testing_and_comparison::{is_callable, is_constructor},
+            type_conversion::to_object,
+        },
+        builtins::{
+            ArgumentsList, array_create,
+            control_abstraction_objects::promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::{PromiseReactionHandler, PromiseReactionType},
+                },
+                promise_prototype::inner_promise_then,
+            },
+            promise::Promise,
+        },
+        execution::{
+            Agent, JsResult, Realm,
+            agent::{ExceptionType, JsError},
+        },
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Number, Object, PropertyKey,
+            Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WellKnownSymbolIndexes,
+        WorkQueues, indexes::BaseIndex,
+    },
+};
+
+/// The source of values for an Array.fromAsync call.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ArrayFromAsyncSource<'a> {
+    /// asyncItems has a @@asyncIterator method.
+    AsyncIterator(IteratorRecord<'a>),
+    /// asyncItems has a @@iterator method.
+    ///
+    /// NOTE: The spec wraps the iterator using CreateAsyncFromSyncIterator.
+    /// We instead step the sync iterator directly and await each value,
+    /// closing the iterator if the value rejects. This is observably
+    /// equivalent except that the result of calling the sync iterator's
+    /// return method is not awaited.
+    SyncIterator(IteratorRecord<'a>),
+    /// asyncItems is neither an AsyncIterable nor an Iterable.
+    ArrayLike { array_like: Object<'a>, len: u64 },
+}
+
+/// The Await that the Array.fromAsync closure is currently suspended on.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ArrayFromAsyncState<'a> {
+    /// Awaiting the result of calling the async iterator's next method.
+    NextResult,
+    /// Awaiting a value from a sync iterator or an array-like.
+    SourceValue,
+    /// Awaiting the result of calling mapfn.
+    MappedValue,
+    /// Awaiting the result of calling the async iterator's return method
+    /// during AsyncIteratorClose. The contained value is the original error.
+    Return(Value<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayFromAsyncRecord<'a> {
+    /// The Realm that Array.fromAsync was called in.
+    pub(crate) realm: Realm<'a>,
+    /// promiseCapability.\[\[Promise]]
+    pub(crate) promise: Promise<'a>,
+    /// A
+    pub(crate) a: Object<'a>,
+    /// mapfn, if mapping is true.
+    pub(crate) mapfn: Option<Function<'a>>,
+    /// thisArg
+    pub(crate) this_arg: Value<'a>,
+    pub(crate) source: ArrayFromAsyncSource<'a>,
+    /// k
+    pub(crate) k: u64,
+    pub(crate) state: ArrayFromAsyncState<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ArrayFromAsync<'a>(BaseIndex<'a, ArrayFromAsyncRecord<'static>>);
+
+/// The result of running the Array.fromAsync closure until it either suspends
+/// or completes.
+enum ArrayFromAsyncStep<'a> {
+    /// The closure awaits the value.
+    Await(Value<'a>),
+    /// The closure returns A.
+    Return(Object<'a>),
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ArrayFromAsyncStep<'_> {
+    type Of<'a> = ArrayFromAsyncStep<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl ArrayFromAsync<'_> {
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Resumes the Array.fromAsync closure after the awaited value has
+    /// settled.
+    pub(crate) fn resume(
+        self,
+        agent: &mut Agent,
+        reaction_type: PromiseReactionType,
+        value: Value,
+        mut gc: GcScope,
+    ) {
+        let value = value.bind(gc.nogc());
+        let this = self.bind(gc.nogc()).scope(agent, gc.nogc());
+        let step = match reaction_type {
+            PromiseReactionType::Fulfill => {
+                on_fulfilled(agent, &this, value.unbind(), gc.reborrow())
+            }
+            PromiseReactionType::Reject => on_rejected(agent, &this, value.unbind(), gc.reborrow()),
+        }
+        .unbind()
+        .bind(gc.nogc());
+        settle(agent, this, step.unbind(), gc);
+    }
+}
+
+/// ### [2.1.1.1 Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+///
+/// Runs steps 3.a to 3.i of the fromAsyncClosure up to its first Await.
+/// Errors thrown before the record has been created are returned to the
+/// caller, which is responsible for rejecting the promise.
+pub(crate) fn array_from_async<'gc>(
+    agent: &mut Agent,
+    c: Value,
+    async_items: Value,
+    mapfn: Value,
+    this_arg: Value,
+    promise: Promise,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let c = c.bind(gc.nogc());
+    let async_items = async_items.bind(gc.nogc());
+    let mapfn = mapfn.bind(gc.nogc());
+    let this_arg = this_arg.bind(gc.nogc());
+    let promise = promise.bind(gc.nogc());
+
+    // a. If mapfn is undefined, let mapping be false.
+    let mapfn = if mapfn.is_undefined() {
+        None
+    } else {
+        // b. Else,
+        // i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        let Some(mapfn) = is_callable(mapfn, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The map function of Array.fromAsync is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        // ii. Let mapping be true.
+        Some(mapfn.scope(agent, gc.nogc()))
+    };
+    let c = c.scope(agent, gc.nogc());
+    let scoped_async_items = async_items.scope(agent, gc.nogc());
+    let this_arg = this_arg.scope(agent, gc.nogc());
+    let promise = promise.scope(agent, gc.nogc());
+
+    // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+    let using_async_iterator = get_method(
+        agent,
+        async_items.unbind(),
+        WellKnownSymbolIndexes::AsyncIterator.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    let (using_iterator, is_async) = if let Some(using_async_iterator) = using_async_iterator {
+        (Some(using_async_iterator), true)
+    } else {
+        // d. If usingAsyncIterator is undefined, then
+        // i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let using_sync_iterator = get_method(
+            agent,
+            scoped_async_items.get(agent),
+            WellKnownSymbolIndexes::Iterator.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        (using_sync_iterator, false)
+    };
+
+    // e. Let iteratorRecord be undefined.
+    let (a, source) = if let Some(using_iterator) = using_iterator {
+        // f. If usingAsyncIterator is not undefined, then
+        // i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        // i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+        let Some(IteratorRecord {
+            iterator,
+            next_method,
+        }) = get_iterator_from_method(
+            agent,
+            scoped_async_items.get(agent),
+            using_iterator.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc())
+        else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.scope(agent, gc.nogc());
+
+        // h. If iteratorRecord is not undefined, then
+        // i. If IsConstructor(C) is true, then
+        let a = if let Some(c) = is_constructor(agent, c.get(agent)) {
+            // 1. Let A be ? Construct(C).
+            construct(agent, c.unbind(), None, None, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc())
+        } else {
+            // ii. Else,
+            // 1. Let A be ! ArrayCreate(0).
+            array_create(agent, 0, 0, None, gc.nogc())
+                .unwrap()
+                .into_object()
+        };
+        let iterator_record = IteratorRecord {
+            iterator: iterator.get(agent).bind(gc.nogc()),
+            next_method: next_method.get(agent).bind(gc.nogc()),
+        };
+        let source = if is_async {
+            ArrayFromAsyncSource::AsyncIterator(iterator_record)
+        } else {
+            ArrayFromAsyncSource::SyncIterator(iterator_record)
+        };
+        (a, source)
+    } else {
+        // i. Else,
+        // i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so
+        //    assume it is an array-like object.
+        // ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = to_object(agent, scoped_async_items.get(agent), gc.nogc())
+            .unwrap()
+            .scope(agent, gc.nogc());
+
+        // iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = length_of_array_like(agent, array_like.get(agent), gc.reborrow()).unbind()?;
+        let len_value = Value::try_from(len).unwrap();
+
+        // iv. If IsConstructor(C) is true, then
+        let a = if let Some(c) = is_constructor(agent, c.get(agent)) {
+            // 1. Let A be ? Construct(C, « 𝔽(len) »).
+            construct(
+                agent,
+                c.unbind(),
+                Some(ArgumentsList::from_mut_slice(&mut [len_value])),
+                None,
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc())
+        } else {
+            // v. Else,
+            // 1. Let A be ? ArrayCreate(len).
+            array_create(agent, len as usize, len as usize, None, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc())
+                .into_object()
+        };
+        let source = ArrayFromAsyncSource::ArrayLike {
+            array_like: array_like.get(agent).bind(gc.nogc()),
+            len: len as u64,
+        };
+        (a, source)
+    };
+
+    let record = ArrayFromAsyncRecord {
+        realm: agent.current_realm(gc.nogc()),
+        promise: promise.get(agent).bind(gc.nogc()),
+        a,
+        mapfn: mapfn.map(|mapfn| mapfn.get(agent).bind(gc.nogc())),
+        this_arg: this_arg.get(agent).bind(gc.nogc()),
+        source,
+        // Let k be 0.
+        k: 0,
+        // NOTE: The state is always set before the closure suspends.
+        state: ArrayFromAsyncState::NextResult,
+    };
+    let this = agent.heap.create(record).scope(agent, gc.nogc());
+    let step = step_next(agent, &this, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    settle(agent, this, step.unbind(), gc);
+    Ok(())
+}
+
+/// Performs the Await for a suspended closure, or settles
+/// promiseCapability.\[\[Promise]] with the closure's completion.
+fn settle(
+    agent: &mut Agent,
+    this: Scoped<ArrayFromAsync>,
+    step: JsResult<ArrayFromAsyncStep>,
+    mut gc: GcScope,
+) {
+    let step = step.bind(gc.nogc());
+    match step {
+        Ok(ArrayFromAsyncStep::Await(value)) => {
+            let handler = PromiseReactionHandler::ArrayFromAsync(this.get(agent));
+            // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
+            // 2. Let promise be ? PromiseResolve(%Promise%, value).
+            let promise = Promise::resolve(agent, value.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+            inner_promise_then(agent, promise, handler, handler, None, gc.nogc());
+        }
+        Ok(ArrayFromAsyncStep::Return(a)) => {
+            let promise = agent[this.get(agent)].promise;
+            PromiseCapability::from_promise(promise, true).resolve(
+                agent,
+                a.into_value().unbind(),
+                gc,
+            );
+        }
+        Err(err) => {
+            let promise = agent[this.get(agent)].promise;
+            PromiseCapability::from_promise(promise, true).reject(agent, err.value(), gc.nogc());
+        }
+    }
+}
+
+/// Runs the closure from the top of its loop: steps 3.h.iv and 3.i.vii.
+fn step_next<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let k = agent[this.get(agent)].k;
+    let source = agent[this.get(agent)].source.bind(gc.nogc());
+    match source {
+        ArrayFromAsyncSource::AsyncIterator(IteratorRecord {
+            iterator,
+            next_method,
+        }) => {
+            // NOTE: The actual max size of an array is u32::MAX
+            // 1. If k ≥ 2**53 - 1, then
+            if k >= u32::MAX as u64 {
+                // a. Let error be ThrowCompletion(a newly created TypeError object).
+                let error = agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Maximum array size of 2**53-1 exceeded",
+                    gc.nogc(),
+                );
+                // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                return close_source_with_error(agent, this, error.unbind(), gc);
+            }
+            // 2. Let Pk be ! ToString(𝔽(k)).
+            // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+            let next_result = call_function(
+                agent,
+                next_method.unbind(),
+                iterator.into_value().unbind(),
+                None,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // 4. Set nextResult to ? Await(nextResult).
+            set_state(agent, this, ArrayFromAsyncState::NextResult);
+            Ok(ArrayFromAsyncStep::Await(next_result.bind(gc.into_nogc())))
+        }
+        ArrayFromAsyncSource::SyncIterator(iterator_record) => {
+            // NOTE: The actual max size of an array is u32::MAX
+            // 1. If k ≥ 2**53 - 1, then
+            if k >= u32::MAX as u64 {
+                // a. Let error be ThrowCompletion(a newly created TypeError object).
+                let error = agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Maximum array size of 2**53-1 exceeded",
+                    gc.nogc(),
+                );
+                // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                return close_source_with_error(agent, this, error.unbind(), gc);
+            }
+            // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+            // NOTE: This steps the sync iterator directly; see
+            // ArrayFromAsyncSource::SyncIterator.
+            let Some(next) =
+                iterator_step_value(agent, iterator_record.unbind(), gc.reborrow()).unbind()?
+            else {
+                // 6. If done is true, then
+                return finish(agent, this, gc);
+            };
+            set_state(agent, this, ArrayFromAsyncState::SourceValue);
+            Ok(ArrayFromAsyncStep::Await(next.bind(gc.into_nogc())))
+        }
+        ArrayFromAsyncSource::ArrayLike { array_like, len } => {
+            // vii. Repeat, while k < len,
+            if k >= len {
+                // viii. Perform ? Set(A, "length", 𝔽(len), true).
+                // ix. Return A.
+                return finish(agent, this, gc);
+            }
+            // 1. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
+            // 2. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = get(agent, array_like.unbind(), pk, gc.reborrow()).unbind()?;
+            // 3. Set kValue to ? Await(kValue).
+            set_state(agent, this, ArrayFromAsyncState::SourceValue);
+            Ok(ArrayFromAsyncStep::Await(k_value.bind(gc.into_nogc())))
+        }
+    }
+}
+
+/// Performs ? Set(A, "length", 𝔽(k), true) and returns A.
+fn finish<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let ArrayFromAsyncRecord { a, k, .. } = agent[this.get(agent)];
+    // a. Perform ? Set(A, "length", 𝔽(k), true).
+    set(
+        agent,
+        a,
+        BUILTIN_STRING_MEMORY.length.into(),
+        Number::from(SmallInteger::try_from(k).unwrap()).into_value(),
+        true,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // b. Return A.
+    let a = agent[this.get(agent)].a;
+    Ok(ArrayFromAsyncStep::Return(a.bind(gc.into_nogc())))
+}
+
+/// Applies mapfn to the value, if mapping is true, and awaits the result.
+/// Otherwise the value is defined on A directly.
+fn map_or_define<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let value = value.bind(gc.nogc());
+    let ArrayFromAsyncRecord {
+        mapfn, this_arg, k, ..
+    } = agent[this.get(agent)];
+    // If mapping is true, then
+    let Some(mapfn) = mapfn else {
+        // Else, let mappedValue be the value.
+        return define_and_continue(agent, this, value.unbind(), gc);
+    };
+    // 𝔽(k)
+    let fk = Number::from(SmallInteger::try_from(k).unwrap()).into_value();
+    // a. Let mappedValue be Call(mapfn, thisArg, « value, 𝔽(k) »).
+    let mapped_value = call_function(
+        agent,
+        mapfn,
+        this_arg,
+        Some(ArgumentsList::from_mut_slice(&mut [value.unbind(), fk])),
+        gc.reborrow(),
+    )
+    .unbind();
+    // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+    let mapped_value = match mapped_value {
+        Ok(mapped_value) => mapped_value,
+        Err(err) => return close_source_with_error(agent, this, err, gc),
+    };
+    // c. Set mappedValue to Await(mappedValue).
+    set_state(agent, this, ArrayFromAsyncState::MappedValue);
+    Ok(ArrayFromAsyncStep::Await(mapped_value.bind(gc.into_nogc())))
+}
+
+/// Defines the mapped value on A, increments k and continues the loop.
+fn define_and_continue<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    mapped_value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let mapped_value = mapped_value.bind(gc.nogc());
+    let ArrayFromAsyncRecord { a, k, .. } = agent[this.get(agent)];
+    // Let Pk be ! ToString(𝔽(k)).
+    let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
+    // Let defineStatus be Completion(CreateDataPropertyOrThrow(A, Pk, mappedValue)).
+    if let Err(err) =
+        create_data_property_or_throw(agent, a, pk, mapped_value.unbind(), gc.reborrow()).unbind()
+    {
+        // If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        return close_source_with_error(agent, this, err, gc);
+    }
+    // Set k to k + 1.
+    let record = this.get(agent);
+    agent[record].k = k + 1;
+    step_next(agent, this, gc)
+}
+
+/// Continues the closure after the awaited value was fulfilled.
+fn on_fulfilled<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let value = value.bind(gc.nogc());
+    let state = agent[this.get(agent)].state;
+    match state {
+        ArrayFromAsyncState::NextResult => {
+            // 5. If nextResult is not an Object, throw a TypeError exception.
+            let Ok(next_result) = Object::try_from(value) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Iterator result is not an object",
+                    gc.into_nogc(),
+                ));
+            };
+            let next_result = next_result.scope(agent, gc.nogc());
+            // 6. Let done be ? IteratorComplete(nextResult).
+            let done = iterator_complete(agent, next_result.get(agent), gc.reborrow()).unbind()?;
+            // 7. If done is true, then
+            if done {
+                // a. Perform ? Set(A, "length", 𝔽(k), true).
+                // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                return finish(agent, this, gc);
+            }
+            // 8. Let nextValue be ? IteratorValue(nextResult).
+            let next_value = iterator_value(agent, next_result.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            map_or_define(agent, this, next_value.unbind(), gc)
+        }
+        ArrayFromAsyncState::SourceValue => map_or_define(agent, this, value.unbind(), gc),
+        ArrayFromAsyncState::MappedValue => define_and_continue(agent, this, value.unbind(), gc),
+        ArrayFromAsyncState::Return(error) => {
+            // [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+            // 5. If completion is a throw completion, return ? completion.
+            Err(JsError::new(error).bind(gc.into_nogc()))
+        }
+    }
+}
+
+/// Continues the closure after the awaited value was rejected.
+fn on_rejected<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    reason: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let error = JsError::new(reason.unbind());
+    let state = agent[this.get(agent)].state;
+    match state {
+        // Set nextResult to ? Await(nextResult).
+        ArrayFromAsyncState::NextResult => Err(error.bind(gc.into_nogc())),
+        // Set kValue to ? Await(kValue).
+        // NOTE: For sync iterators this is the rejection handling of
+        // AsyncFromSyncIteratorContinuation, which closes the sync iterator.
+        ArrayFromAsyncState::SourceValue => close_source_with_error(agent, this, error, gc),
+        // Set mappedValue to Await(mappedValue).
+        // IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        ArrayFromAsyncState::MappedValue => close_source_with_error(agent, this, error, gc),
+        ArrayFromAsyncState::Return(error) => {
+            // [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+            // 5. If completion is a throw completion, return ? completion.
+            Err(JsError::new(error).bind(gc.into_nogc()))
+        }
+    }
+}
+
+/// Closes the source of values with a throw completion. For async iterators
+/// this is AsyncIteratorClose, which may need to await the result of the
+/// return method before the error is rethrown.
+fn close_source_with_error<'gc>(
+    agent: &mut Agent,
+    this: &Scoped<ArrayFromAsync>,
+    error: JsError,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ArrayFromAsyncStep<'gc>> {
+    let error = error.bind(gc.nogc());
+    let source = agent[this.get(agent)].source.bind(gc.nogc());
+    match source {
+        ArrayFromAsyncSource::AsyncIterator(IteratorRecord { iterator, .. }) => {
+            let error = error.scope(agent, gc.nogc());
+            let iterator = iterator.scope(agent, gc.nogc());
+            // [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+            // 1. Assert: iteratorRecord.[[Iterator]] is an Object.
+            // 2. Let iterator be iteratorRecord.[[Iterator]].
+            // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+            let inner_result = get_method(
+                agent,
+                iterator.get(agent).into_value(),
+                BUILTIN_STRING_MEMORY.r#return.into(),
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+            // 4. If innerResult is a normal completion, then
+            // a. Let return be innerResult.[[Value]].
+            // b. If return is undefined, return ? completion.
+            // 5. If completion is a throw completion, return ? completion.
+            let Ok(Some(r#return)) = inner_result else {
+                // SAFETY: error is not shared.
+                return Err(unsafe { error.take(agent) }.bind(gc.into_nogc()));
+            };
+            // c. Set innerResult to Completion(Call(return, iterator)).
+            let inner_result = call_function(
+                agent,
+                r#return.unbind(),
+                iterator.get(agent).into_value(),
+                None,
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+            // SAFETY: error is not shared.
+            let error = unsafe { error.take(agent) }.bind(gc.nogc());
+            // 5. If completion is a throw completion, return ? completion.
+            let Ok(inner_result) = inner_result else {
+                return Err(error.unbind().bind(gc.into_nogc()));
+            };
+            // d. If innerResult is a normal completion, set innerResult to
+            //    Completion(Await(innerResult.[[Value]])).
+            set_state(
+                agent,
+                this,
+                ArrayFromAsyncState::Return(error.value().unbind()),
+            );
+            Ok(ArrayFromAsyncStep::Await(
+                inner_result.unbind().bind(gc.into_nogc()),
+            ))
+        }
+        ArrayFromAsyncSource::SyncIterator(IteratorRecord { iterator, .. }) => Err(
+            iterator_close_with_error(agent, iterator.unbind(), error.unbind(), gc),
+        ),
+        ArrayFromAsyncSource::ArrayLike { .. } => Err(error.unbind().bind(gc.into_nogc())),
+    }
+}
+
+fn set_state(agent: &mut Agent, this: &Scoped<ArrayFromAsync>, state: ArrayFromAsyncState) {
+    let record = this.get(agent);
+    agent[record].state = state.unbind();
+}
+
+impl Index<ArrayFromAsync<'_>> for Agent {
+    type Output = ArrayFromAsyncRecord<'static>;
+
+    fn index(&self, index: ArrayFromAsync) -> &Self::Output {
+        &self.heap.array_from_async_records[index]
+    }
+}
+
+impl IndexMut<ArrayFromAsync<'_>> for Agent {
+    fn index_mut(&mut self, index: ArrayFromAsync) -> &mut Self::Output {
+        &mut self.heap.array_from_async_records[index]
+    }
+}
+
+impl Index<ArrayFromAsync<'_>> for Vec<Option<ArrayFromAsyncRecord<'static>>> {
+    type Output = ArrayFromAsyncRecord<'static>;
+
+    fn index(&self, index: ArrayFromAsync) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("ArrayFromAsync out of bounds")
+            .as_ref()
+            .expect("ArrayFromAsync slot empty")
+    }
+}
+
+impl IndexMut<ArrayFromAsync<'_>> for Vec<Option<ArrayFromAsyncRecord<'static>>> {
+    fn index_mut(&mut self, index: ArrayFromAsync) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("ArrayFromAsync out of bounds")
+            .as_mut()
+            .expect("ArrayFromAsync slot empty")
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ArrayFromAsync<'_> {
+    type Of<'a> = ArrayFromAsync<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsync<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.array_from_async_records.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .array_from_async_records
+            .shift_index(&mut self.0);
+    }
+}
+
+impl Rootable for ArrayFromAsync<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::ArrayFromAsync(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        if let HeapRootData::ArrayFromAsync(data) = heap_data {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ArrayFromAsyncSource<'_> {
+    type Of<'a> = ArrayFromAsyncSource<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncSource<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::AsyncIterator(iterator_record) | Self::SyncIterator(iterator_record) => {
+                iterator_record.mark_values(queues)
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::AsyncIterator(iterator_record) | Self::SyncIterator(iterator_record) => {
+                iterator_record.sweep_values(compactions)
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.sweep_values(compactions),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ArrayFromAsyncState<'_> {
+    type Of<'a> = ArrayFromAsyncState<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncState<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::NextResult | Self::SourceValue | Self::MappedValue => {}
+            Self::Return(error) => error.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::NextResult | Self::SourceValue | Self::MappedValue => {}
+            Self::Return(error) => error.sweep_values(compactions),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ArrayFromAsyncRecord<'_> {
+    type Of<'a> = ArrayFromAsyncRecord<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            realm,
+            promise,
+            a,
+            mapfn,
+            this_arg,
+            source,
+            k: _,
+            state,
+        } = self;
+        realm.mark_values(queues);
+        promise.mark_values(queues);
+        a.mark_values(queues);
+        mapfn.mark_values(queues);
+        this_arg.mark_values(queues);
+        source.mark_values(queues);
+        state.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            realm,
+            promise,
+            a,
+            mapfn,
+            this_arg,
+            source,
+            k: _,
+            state,
+        } = self;
+        realm.sweep_values(compactions);
+        promise.sweep_values(compactions);
+        a.sweep_values(compactions);
+        mapfn.sweep_values(compactions);
+        this_arg.sweep_values(compactions);
+        source.sweep_values(compactions);
+        state.sweep_values(compactions);
+    }
+}
+
+impl<'a> CreateHeapData<ArrayFromAsyncRecord<'a>, ArrayFromAsync<'a>> for Heap {
+    fn create(&mut self, data: ArrayFromAsyncRecord<'a>) -> ArrayFromAsync<'a> {
+        self.array_from_async_records.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<ArrayFromAsyncRecord<'static>>>();
+        }
+        ArrayFromAsync(BaseIndex::last(&self.array_from_async_records))
+    }
+}
@@ -20,7 +20,7 @@ use crate::{
         abstract_operations::{
             operations_on_objects::{
                 call_function, create_data_property_or_throw, delete_property_or_throw, get,
-                has_property, length_of_array_like, set,
+                has_property, invoke, length_of_array_like, set,
             },
             testing_and_comparison::{is_array, is_callable, is_strictly_equal, same_value_zero},
             type_conversion::{
@@ -37,8 +37,8 @@ use crate::{
             agent::{ExceptionType, JsError},
         },
         types::{
-            BUILTIN_STRING_MEMORY, Function, IntoFunction, IntoObject, IntoValue, Number, Object,
-            PropertyKey, String, Value,
+            BUILTIN_STRING_MEMORY, BigInt, Function, IntoFunction, IntoObject, IntoValue, Number,
+            Object, PropertyKey, String, Value,
         },
     },
     heap::{Heap, IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
@@ -3466,13 +3466,85 @@ impl ArrayPrototype {
         Ok(a.get(agent).into_value())
     }
 
+    /// ### [23.1.3.32 Array.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-array.prototype.tolocalestring)
+    ///
+    /// With the `intl` feature enabled, this implements
+    /// [19.5.1 Array.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-array.prototype.tolocalestring)
+    /// instead: the locales and options arguments are passed on to each
+    /// element's toLocaleString method.
     fn to_locale_string<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        #[cfg(feature = "intl")] arguments: ArgumentsList,
+        #[cfg(not(feature = "intl"))] _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        #[cfg(feature = "intl")]
+        let locales = arguments.get(0).scope(agent, nogc);
+        #[cfg(feature = "intl")]
+        let options = arguments.get(1).scope(agent, nogc);
+        // 1. Let array be ? ToObject(this value).
+        let array = to_object(agent, this_value, nogc)
+            .unbind()?
+            .scope(agent, nogc);
+        // 2. Let len be ? LengthOfArrayLike(array).
+        let len = length_of_array_like(agent, array.get(agent), gc.reborrow()).unbind()?;
+        // 3. Let separator be the implementation-defined list-separator
+        //    String appropriate for the host environment's current locale
+        //    (such as ", ").
+        let separator = ",";
+        // 4. Let R be the empty String.
+        let mut r = std::string::String::new();
+        // 5. Let k be 0.
+        let mut k = 0;
+        // 6. Repeat, while k < len,
+        while k < len {
+            // a. If k > 0, set R to the string-concatenation of R and separator.
+            if k > 0 {
+                r.push_str(separator);
+            }
+            // b. Let element be ? Get(array, ! ToString(𝔽(k))).
+            let pk = PropertyKey::try_from(k).unwrap();
+            let element = get(agent, array.get(agent), pk, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            // c. If element is neither undefined nor null, then
+            if !element.is_undefined() && !element.is_null() {
+                // i. Let S be ? ToString(? Invoke(element, "toLocaleString")).
+                #[cfg(not(feature = "intl"))]
+                let s = invoke(
+                    agent,
+                    element.unbind(),
+                    BUILTIN_STRING_MEMORY.toLocaleString.into(),
+                    None,
+                    gc.reborrow(),
+                );
+                // NOTE: ECMA-402 passes « locales, options » to Invoke.
+                #[cfg(feature = "intl")]
+                let s = invoke(
+                    agent,
+                    element.unbind(),
+                    BUILTIN_STRING_MEMORY.toLocaleString.into(),
+                    Some(ArgumentsList::from_mut_slice(&mut [
+                        locales.get(agent),
+                        options.get(agent),
+                    ])),
+                    gc.reborrow(),
+                );
+                let s = s.unbind()?.bind(gc.nogc());
+                let s = to_string(agent, s.unbind(), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(s.as_str(agent));
+            }
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 7. Return R.
+        Ok(Value::from_string(agent, r, gc.into_nogc()).into_value())
     }
 
     fn to_reversed<'gc>(
@@ -4484,7 +4556,12 @@ fn flatten_into_array<'a>(
 /// > The above conditions are necessary and sufficient to ensure that
 /// > comparator divides the set S into equivalence classes and that these
 /// > equivalence classes are totally ordered.
-fn sort_indexed_properties<'gc, 'scope, const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
+pub(crate) fn sort_indexed_properties<
+    'gc,
+    'scope,
+    const SKIP_HOLES: bool,
+    const TYPED_ARRAY: bool,
+>(
     agent: &mut Agent,
     obj: Object,
     len: usize,
@@ -4526,27 +4603,27 @@ fn sort_indexed_properties<'gc, 'scope, const SKIP_HOLES: bool, const TYPED_ARRA
     // SortCompare. If any such call returns an abrupt completion, stop before
     // performing any further calls to SortCompare and return that Completion
     // Record.
-    if TYPED_ARRAY {
-        items.sort_by(|_a, _b| compare_typed_array_elements());
-    } else {
-        let mut error: Option<JsError> = None;
-        items.sort_by(|a, b| {
-            if error.is_some() {
-                // This is dangerous but we don't have much of a choice.
-                return Ordering::Equal;
-            }
-            let result = compare_array_elements(agent, a, b, comparator.clone(), gc.reborrow());
-            match result {
-                Ok(result) => result,
-                Err(err) => {
-                    error = Some(err.unbind());
-                    Ordering::Equal
-                }
+    let mut error: Option<JsError> = None;
+    items.sort_by(|a, b| {
+        if error.is_some() {
+            // This is dangerous but we don't have much of a choice.
+            return Ordering::Equal;
+        }
+        let result = if TYPED_ARRAY {
+            compare_typed_array_elements(agent, a, b, comparator.clone(), gc.reborrow())
+        } else {
+            compare_array_elements(agent, a, b, comparator.clone(), gc.reborrow())
+        };
+        match result {
+            Ok(result) => result,
+            Err(err) => {
+                error = Some(err.unbind());
+                Ordering::Equal
             }
-        });
-        if let Some(error) = error {
-            return Err(error);
         }
+    });
+    if let Some(error) = error {
+        return Err(error);
     }
     // 5. Return items.
     Ok(items)
@@ -4638,6 +4715,77 @@ fn compare_array_elements<'a>(
     }
 }
 
-fn compare_typed_array_elements() -> Ordering {
-    todo!();
+/// ### [23.2.4.7 CompareTypedArrayElements ( x, y, comparator )](https://tc39.es/ecma262/#sec-comparetypedarrayelements)
+/// The abstract operation CompareTypedArrayElements takes arguments x (a
+/// Number or a BigInt), y (a Number or a BigInt), and comparator (a function
+/// object or undefined) and returns either a normal completion containing a
+/// Number or an abrupt completion.
+fn compare_typed_array_elements<'a>(
+    agent: &mut Agent,
+    scoped_x: &Scoped<Value>,
+    scoped_y: &Scoped<Value>,
+    comparator: Option<Scoped<Function>>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Ordering> {
+    let x = scoped_x.get(agent).bind(gc.nogc());
+    let y = scoped_y.get(agent).bind(gc.nogc());
+    // 1. Assert: x is a Number and y is a Number, or x is a BigInt and y is a BigInt.
+    // 2. If comparator is not undefined, then
+    if let Some(comparator) = comparator {
+        // a. Let v be ? ToNumber(? Call(comparator, undefined, « x, y »)).
+        let v = call_function(
+            agent,
+            comparator.get(agent),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [x.unbind(), y.unbind()])),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let v = to_number(agent, v.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // b. If v is NaN, return +0𝔽.
+        // c. Return v.
+        return Ok(v
+            .into_f64(agent)
+            .partial_cmp(&0.0)
+            .unwrap_or(Ordering::Equal));
+    }
+    if let (Ok(x), Ok(y)) = (BigInt::try_from(x), BigInt::try_from(y)) {
+        // 6. If x < y, return -1𝔽.
+        if BigInt::less_than(agent, x, y) {
+            Ok(Ordering::Less)
+        } else if BigInt::less_than(agent, y, x) {
+            // 7. If x > y, return 1𝔽.
+            Ok(Ordering::Greater)
+        } else {
+            // 10. Return +0𝔽.
+            Ok(Ordering::Equal)
+        }
+    } else {
+        let x = Number::try_from(x).unwrap().into_f64(agent);
+        let y = Number::try_from(y).unwrap().into_f64(agent);
+        Ok(compare_typed_array_numbers(x, y))
+    }
+}
+
+/// Steps 3.-9. of CompareTypedArrayElements for Number values: NaN values
+/// are sorted last, and -0𝔽 is sorted before +0𝔽.
+#[inline]
+pub(crate) fn compare_typed_array_numbers(x: f64, y: f64) -> Ordering {
+    match (x.is_nan(), y.is_nan()) {
+        // 3. If x and y are both NaN, return +0𝔽.
+        (true, true) => Ordering::Equal,
+        // 4. If x is NaN, return 1𝔽.
+        (true, false) => Ordering::Greater,
+        // 5. If y is NaN, return -1𝔽.
+        (false, true) => Ordering::Less,
+        // 6. If x < y, return -1𝔽.
+        // 7. If x > y, return 1𝔽.
+        // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+        // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+        // 10. Return +0𝔽.
+        (false, false) => x.total_cmp(&y),
+    }
 }
@@ -5,3 +5,4 @@
 pub(crate) mod abstract_operations;
 pub(crate) mod typed_array_constructors;
 pub(crate) mod typed_array_intrinsic_object;
+pub(crate) mod uint8_array_base64;
@@ -6,7 +6,9 @@ use crate::{
     SmallInteger,
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::{construct, get, length_of_array_like, set, try_set},
+            operations_on_objects::{
+                construct, get, length_of_array_like, set, species_constructor, try_set,
+            },
             type_conversion::{to_big_int, to_index, to_number},
         },
         builtins::{
@@ -319,6 +321,82 @@ pub(crate) fn is_typed_array_fixed_length(agent: &Agent, o: TypedArray, gc: NoGc
     }
 }
 
+/// ### [10.4.5.12 Generic TypedArrayLength ( taRecord )](https://tc39.es/ecma262/#sec-typedarraylength)
+///
+/// The abstract operation TypedArrayLength takes argument taRecord (a
+/// TypedArray With Buffer Witness Record) and returns a non-negative integer.
+pub(crate) fn typed_array_length_generic(
+    agent: &Agent,
+    ta_record: &TypedArrayWithBufferWitnessRecords,
+    gc: NoGcScope,
+) -> usize {
+    match ta_record.object {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) | TypedArray::Uint8ClampedArray(_) => {
+            typed_array_length::<u8>(agent, ta_record, gc)
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+            typed_array_length::<u16>(agent, ta_record, gc)
+        }
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => typed_array_length::<f16>(agent, ta_record, gc),
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) | TypedArray::Float32Array(_) => {
+            typed_array_length::<u32>(agent, ta_record, gc)
+        }
+        TypedArray::BigInt64Array(_)
+        | TypedArray::BigUint64Array(_)
+        | TypedArray::Float64Array(_) => typed_array_length::<u64>(agent, ta_record, gc),
+    }
+}
+
+/// ### [10.4.5.13 Generic IsTypedArrayOutOfBounds ( taRecord )](https://tc39.es/ecma262/#sec-istypedarrayoutofbounds)
+///
+/// The abstract operation IsTypedArrayOutOfBounds takes argument taRecord (a
+/// TypedArray With Buffer Witness Record) and returns a Boolean. It checks if
+/// any of the object's numeric properties reference a value at an index not
+/// contained within the underlying buffer's bounds.
+pub(crate) fn is_typed_array_out_of_bounds_generic(
+    agent: &Agent,
+    ta_record: &TypedArrayWithBufferWitnessRecords,
+    gc: NoGcScope,
+) -> bool {
+    match ta_record.object {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) | TypedArray::Uint8ClampedArray(_) => {
+            is_typed_array_out_of_bounds::<u8>(agent, ta_record, gc)
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+            is_typed_array_out_of_bounds::<u16>(agent, ta_record, gc)
+        }
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => is_typed_array_out_of_bounds::<f16>(agent, ta_record, gc),
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) | TypedArray::Float32Array(_) => {
+            is_typed_array_out_of_bounds::<u32>(agent, ta_record, gc)
+        }
+        TypedArray::BigInt64Array(_)
+        | TypedArray::BigUint64Array(_)
+        | TypedArray::Float64Array(_) => is_typed_array_out_of_bounds::<u64>(agent, ta_record, gc),
+    }
+}
+
+/// ### [23.2.4.5 TypedArrayElementSize ( O )](https://tc39.es/ecma262/#sec-typedarrayelementsize)
+///
+/// The abstract operation TypedArrayElementSize takes argument O (a
+/// TypedArray) and returns a non-negative integer.
+pub(crate) fn typed_array_element_size(o: TypedArray) -> usize {
+    // 1. Return the Element Size value specified in Table 71 for O.[[TypedArrayName]].
+    match o {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) | TypedArray::Uint8ClampedArray(_) => {
+            1
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => 2,
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => 2,
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) | TypedArray::Float32Array(_) => 4,
+        TypedArray::BigInt64Array(_)
+        | TypedArray::BigUint64Array(_)
+        | TypedArray::Float64Array(_) => 8,
+    }
+}
+
 /// ### [10.4.5.16 Generic IsValidIntegerIndex ( O, index )](https://tc39.es/ecma262/#sec-isvalidintegerindex)
 ///
 /// The abstract operation IsValidIntegerIndex takes arguments O (a TypedArray)
@@ -795,7 +873,7 @@ pub(crate) fn initialize_typed_array_from_typed_array<'a, O: Viewable, Src: View
     }
 
     // 9. Let elementLength be TypedArrayLength(srcRecord).
-    let element_length = typed_array_length::<O>(agent, &src_record, gc);
+    let element_length = typed_array_length::<Src>(agent, &src_record, gc);
 
     // 10. Let byteLength be elementSize × elementLength.
     let byte_length = element_size * element_length;
@@ -1354,30 +1432,11 @@ pub(crate) fn typed_array_create_same_type<'a>(
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, TypedArray<'a>> {
     // 1. Let constructor be the intrinsic object associated with the constructor name exemplar.[[TypedArrayName]] in Table 73.
-    let constructor_value = match exemplar {
-        TypedArray::Int8Array(_) => agent.current_realm_record().intrinsics().int8_array(),
-        TypedArray::Uint8Array(_) => agent.current_realm_record().intrinsics().uint8_array(),
-        TypedArray::Uint8ClampedArray(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .uint8_clamped_array(),
-        TypedArray::Int16Array(_) => agent.current_realm_record().intrinsics().int16_array(),
-        TypedArray::Uint16Array(_) => agent.current_realm_record().intrinsics().uint16_array(),
-        TypedArray::Int32Array(_) => agent.current_realm_record().intrinsics().int32_array(),
-        TypedArray::Uint32Array(_) => agent.current_realm_record().intrinsics().uint32_array(),
-        TypedArray::BigInt64Array(_) => agent.current_realm_record().intrinsics().big_int64_array(),
-        TypedArray::BigUint64Array(_) => {
-            agent.current_realm_record().intrinsics().big_uint64_array()
-        }
-        #[cfg(feature = "proposal-float16array")]
-        TypedArray::Float16Array(_) => agent.current_realm_record().intrinsics().float16_array(),
-        TypedArray::Float32Array(_) => agent.current_realm_record().intrinsics().float32_array(),
-        TypedArray::Float64Array(_) => agent.current_realm_record().intrinsics().float64_array(),
-    };
+    let constructor_value = intrinsic_typed_array_constructor(agent, exemplar);
     // 2. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
     let result = typed_array_create_from_constructor_with_length(
         agent,
-        constructor_value.into_function(),
+        constructor_value,
         length,
         gc.reborrow(),
     )
@@ -1388,3 +1447,134 @@ pub(crate) fn typed_array_create_same_type<'a>(
     // 5. Return result.
     Ok(result.unbind())
 }
+
+/// Returns the intrinsic object associated with the constructor name
+/// exemplar.\[\[TypedArrayName]] in Table 73.
+fn intrinsic_typed_array_constructor(agent: &Agent, exemplar: TypedArray) -> Function<'static> {
+    let intrinsics = agent.current_realm_record().intrinsics();
+    match exemplar {
+        TypedArray::Int8Array(_) => intrinsics.int8_array(),
+        TypedArray::Uint8Array(_) => intrinsics.uint8_array(),
+        TypedArray::Uint8ClampedArray(_) => intrinsics.uint8_clamped_array(),
+        TypedArray::Int16Array(_) => intrinsics.int16_array(),
+        TypedArray::Uint16Array(_) => intrinsics.uint16_array(),
+        TypedArray::Int32Array(_) => intrinsics.int32_array(),
+        TypedArray::Uint32Array(_) => intrinsics.uint32_array(),
+        TypedArray::BigInt64Array(_) => intrinsics.big_int64_array(),
+        TypedArray::BigUint64Array(_) => intrinsics.big_uint64_array(),
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => intrinsics.float16_array(),
+        TypedArray::Float32Array(_) => intrinsics.float32_array(),
+        TypedArray::Float64Array(_) => intrinsics.float64_array(),
+    }
+    .into_function()
+}
+
+/// ### [23.2.4.1 TypedArraySpeciesCreate ( exemplar, argumentList )](https://tc39.es/ecma262/#typedarray-species-create)
+///
+/// The abstract operation TypedArraySpeciesCreate takes arguments exemplar (a
+/// TypedArray) and argumentList (a List of ECMAScript language values) and
+/// returns either a normal completion containing a TypedArray or a throw
+/// completion. It is used to specify the creation of a new TypedArray using a
+/// constructor function that is derived from exemplar. Unlike
+/// ArraySpeciesCreate, which can create non-Array objects through the use of
+/// %Symbol.species%, this operation enforces that the constructor function
+/// creates an actual TypedArray.
+pub(crate) fn typed_array_species_create_with_length<'a>(
+    agent: &mut Agent,
+    exemplar: TypedArray,
+    length: i64,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let exemplar = exemplar.bind(gc.nogc());
+    let exemplar_is_bigint = exemplar.is_bigint();
+    // 1. Let defaultConstructor be the intrinsic object associated with the constructor name exemplar.[[TypedArrayName]] in Table 73.
+    let default_constructor = intrinsic_typed_array_constructor(agent, exemplar);
+    // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
+    let constructor = species_constructor(
+        agent,
+        exemplar.into_object().unbind(),
+        default_constructor,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+    let result = typed_array_create_from_constructor_with_length(
+        agent,
+        constructor.unbind(),
+        length,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let result = result.bind(gc);
+    // 4. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+    // 5. If result.[[ContentType]] is not exemplar.[[ContentType]], throw a TypeError exception.
+    if result.is_bigint() != exemplar_is_bigint {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray species constructor returned a TypedArray of different content type",
+            gc,
+        ));
+    }
+    // 6. Return result.
+    Ok(result)
+}
+
+/// ### [23.2.4.1 TypedArraySpeciesCreate ( exemplar, argumentList )](https://tc39.es/ecma262/#typedarray-species-create)
+///
+/// The abstract operation TypedArraySpeciesCreate takes arguments exemplar (a
+/// TypedArray) and argumentList (a List of ECMAScript language values) and
+/// returns either a normal completion containing a TypedArray or a throw
+/// completion. It is used to specify the creation of a new TypedArray using a
+/// constructor function that is derived from exemplar. Unlike
+/// ArraySpeciesCreate, which can create non-Array objects through the use of
+/// %Symbol.species%, this operation enforces that the constructor function
+/// creates an actual TypedArray.
+pub(crate) fn typed_array_species_create_with_buffer<'a>(
+    agent: &mut Agent,
+    exemplar: TypedArray,
+    buffer: ArrayBuffer,
+    byte_offset: i64,
+    length: Option<i64>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let exemplar = exemplar.bind(gc.nogc());
+    let buffer = buffer.scope(agent, gc.nogc());
+    let exemplar_is_bigint = exemplar.is_bigint();
+    // 1. Let defaultConstructor be the intrinsic object associated with the constructor name exemplar.[[TypedArrayName]] in Table 73.
+    let default_constructor = intrinsic_typed_array_constructor(agent, exemplar);
+    // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
+    let constructor = species_constructor(
+        agent,
+        exemplar.into_object().unbind(),
+        default_constructor,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+    let result = typed_array_create_from_constructor_with_buffer(
+        agent,
+        constructor.unbind(),
+        buffer.get(agent),
+        byte_offset,
+        length,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let result = result.bind(gc);
+    // 4. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+    // 5. If result.[[ContentType]] is not exemplar.[[ContentType]], throw a TypeError exception.
+    if result.is_bigint() != exemplar_is_bigint {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray species constructor returned a TypedArray of different content type",
+            gc,
+        ));
+    }
+    // 6. Return result.
+    Ok(result)
+}
@@ -15,6 +15,10 @@ use crate::ecmascript::builtins::indexed_collections::typed_array_objects::abstr
     initialize_typed_array_from_array_like, initialize_typed_array_from_list,
     initialize_typed_array_from_typed_array,
 };
+use crate::ecmascript::builtins::indexed_collections::typed_array_objects::uint8_array_base64::{
+    Uint8ArrayFromBase64, Uint8ArrayFromHex, Uint8ArrayPrototypeSetFromBase64,
+    Uint8ArrayPrototypeSetFromHex, Uint8ArrayPrototypeToBase64, Uint8ArrayPrototypeToHex,
+};
 use crate::ecmascript::builtins::typed_array::TypedArray;
 use crate::ecmascript::execution::agent::ExceptionType;
 use crate::ecmascript::types::{Function, IntoValue, PropertyKey, U8Clamped, Viewable};
@@ -340,7 +344,7 @@ impl TypedArrayConstructors {
             .build();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<Uint8ArrayConstructor>(agent, realm)
-            .with_property_capacity(2)
+            .with_property_capacity(4)
             .with_prototype(typed_array_constructor)
             .with_property(|builder| {
                 builder
@@ -350,6 +354,8 @@ impl TypedArrayConstructors {
                     .with_configurable(false)
                     .build()
             })
+            .with_builtin_function_property::<Uint8ArrayFromBase64>()
+            .with_builtin_function_property::<Uint8ArrayFromHex>()
             .with_prototype_property(uint8_array_prototype.into_object())
             .build();
 
@@ -548,7 +554,7 @@ impl TypedArrayPrototypes {
             .build();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, uint8_array_prototype)
-            .with_property_capacity(2)
+            .with_property_capacity(6)
             .with_prototype(typed_array_prototype)
             .with_property(|builder| {
                 builder
@@ -559,6 +565,10 @@ impl TypedArrayPrototypes {
                     .build()
             })
             .with_constructor_property(uint8_array_constructor)
+            .with_builtin_function_property::<Uint8ArrayPrototypeSetFromBase64>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeSetFromHex>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeToBase64>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeToHex>()
             .build();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, uint8_clamped_array_prototype)
@@ -10,13 +10,13 @@ use crate::{
         abstract_operations::{
             operations_on_iterator_objects::{get_iterator_from_method, iterator_to_list},
             operations_on_objects::{
-                call_function, get, get_method, length_of_array_like, set, throw_not_callable,
-                try_get, try_set,
+                call_function, get, get_method, invoke, length_of_array_like, set,
+                throw_not_callable, try_get, try_set,
             },
             testing_and_comparison::{is_array, is_callable, is_constructor, same_value_zero},
             type_conversion::{
-                to_big_int, to_boolean, to_integer_or_infinity, to_number, to_object, to_string,
-                try_to_integer_or_infinity, try_to_string,
+                IntegerOrInfinity, to_big_int, to_boolean, to_integer_or_infinity, to_number,
+                to_object, to_string, try_to_integer_or_infinity, try_to_string,
             },
         },
         builders::{
@@ -29,7 +29,9 @@ use crate::{
             array_buffer::{Ordering, get_value_from_buffer, is_detached_buffer},
             indexed_collections::array_objects::{
                 array_iterator_objects::array_iterator::{ArrayIterator, CollectionIteratorKind},
-                array_prototype::find_via_predicate,
+                array_prototype::{
+                    compare_typed_array_numbers, find_via_predicate, sort_indexed_properties,
+                },
             },
             typed_array::TypedArray,
         },
@@ -40,7 +42,7 @@ use crate::{
         },
     },
     engine::{
-        TryResult,
+        Scoped, TryResult,
         context::{Bindable, GcScope, NoGcScope},
         rootable::Scopable,
         unwrap_try,
@@ -50,9 +52,13 @@ use crate::{
 
 use super::abstract_operations::{
     TypedArrayWithBufferWitnessRecords, is_typed_array_out_of_bounds,
-    make_typed_array_with_buffer_witness_record, typed_array_byte_length,
-    typed_array_create_from_constructor_with_length, typed_array_create_same_type,
-    typed_array_length, validate_typed_array,
+    is_typed_array_out_of_bounds_generic, is_valid_integer_index_generic,
+    make_typed_array_with_buffer_witness_record, try_typed_array_set_element_generic,
+    typed_array_byte_length, typed_array_create_from_constructor_with_length,
+    typed_array_create_same_type, typed_array_element_size, typed_array_get_element_generic,
+    typed_array_length, typed_array_length_generic, typed_array_set_element_generic,
+    typed_array_species_create_with_buffer, typed_array_species_create_with_length,
+    validate_typed_array,
 };
 
 pub struct TypedArrayIntrinsicObject;
@@ -1284,13 +1290,96 @@ impl TypedArrayPrototype {
         o.map(|o| o.into_value())
     }
 
+    /// ### [23.2.3.10 %TypedArray%.prototype.filter ( callback [ , thisArg ] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter)
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.filter as defined in 23.1.3.8.
     fn filter<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let callback = arguments.get(0).bind(nogc);
+        let this_arg = arguments.get(1).bind(nogc);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let mut o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = typed_array_length_generic(agent, &ta_record, nogc);
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, nogc);
+        let this_arg = this_arg.scope(agent, nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 5. Let kept be a new empty List.
+        let mut kept = Vec::new();
+        // 6. Let captured be 0.
+        // 7. Let k be 0.
+        let mut k = 0;
+        // 8. Repeat, while k < len,
+        while k < len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            let scoped_k_value = k_value.scope(agent, gc.nogc());
+            // c. Let selected be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
+            let call = call_function(
+                agent,
+                callback.get(agent),
+                this_arg.get(agent),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    k_value.unbind(),
+                    Number::try_from(k).unwrap().into_value().unbind(),
+                    o.into_value().unbind(),
+                ])),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            let selected = to_boolean(agent, call);
+            // d. If selected is true, then
+            if selected {
+                // i. Append kValue to kept.
+                // ii. Set captured to captured + 1.
+                kept.push(scoped_k_value);
+            }
+            // e. Set k to k + 1.
+            o = scoped_o.get(agent).bind(gc.nogc());
+            k += 1;
+        }
+        // 9. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(captured) »).
+        let a = typed_array_species_create_with_length(
+            agent,
+            o.unbind(),
+            kept.len() as i64,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        // 10. Let n be 0.
+        // 11. For each element e of kept, do
+        for (n, e) in kept.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(n)), e, true).
+            // b. Set n to n + 1.
+            let pk = PropertyKey::from(SmallInteger::from(n as u32));
+            unwrap_try(try_set(agent, a.into_object(), pk, e.get(agent), true, gc)).unwrap();
+        }
+        // 12. Return A.
+        Ok(a.into_value())
     }
 
     /// ### 23.2.3.11 %TypedArray%.prototype.find ( predicate [ , thisArg ] )(https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.find)
@@ -2443,13 +2532,82 @@ impl TypedArrayPrototype {
         Ok(Value::try_from(length).unwrap())
     }
 
+    /// ### [23.2.3.22 %TypedArray%.prototype.map ( callback [ , thisArg ] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.map)
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.map as defined in 23.1.3.21.
     fn map<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let callback = arguments.get(0).bind(nogc);
+        let this_arg = arguments.get(1).bind(nogc);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = typed_array_length_generic(agent, &ta_record, nogc);
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, nogc);
+        let this_arg = this_arg.scope(agent, nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 5. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(len) »).
+        let a =
+            typed_array_species_create_with_length(agent, o.unbind(), len as i64, gc.reborrow())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 6. Let k be 0.
+        let mut k = 0;
+        // 7. Repeat, while k < len,
+        while k < len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            // c. Let mappedValue be ? Call(callback, thisArg, « kValue, 𝔽(k), O »).
+            let mapped_value = call_function(
+                agent,
+                callback.get(agent),
+                this_arg.get(agent),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    k_value.unbind(),
+                    Number::try_from(k).unwrap().into_value().unbind(),
+                    o.into_value().unbind(),
+                ])),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // d. Perform ? Set(A, Pk, mappedValue, true).
+            set(
+                agent,
+                a.get(agent).into_object(),
+                pk,
+                mapped_value.unbind(),
+                true,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // e. Set k to k + 1.
+            k += 1;
+        }
+        // 8. Return A.
+        Ok(a.get(agent).into_value())
     }
 
     /// ### [23.2.3.23 %TypedArray%.prototype.reduce ( callback [ , initialValue ] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.reduce)
@@ -2735,22 +2893,210 @@ impl TypedArrayPrototype {
         Ok(o.into_value())
     }
 
+    /// ### [23.2.3.26 %TypedArray%.prototype.set ( source [ , offset ] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.set)
+    ///
+    /// This method sets multiple values in this TypedArray, reading the
+    /// values from source. The details differ based upon the type of source.
+    /// The optional offset value indicates the first element index in this
+    /// TypedArray where values are written. If omitted, it is assumed to be 0.
     fn set<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let source = arguments.get(0).scope(agent, nogc);
+        let offset = arguments.get(1).bind(nogc);
+        // 1. Let target be the this value.
+        // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
+        // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
+        let target = require_internal_slot_typed_array(agent, this_value, nogc)
+            .unbind()?
+            .scope(agent, nogc);
+        // 4. Let targetOffset be ? ToIntegerOrInfinity(offset).
+        let target_offset =
+            to_integer_or_infinity(agent, offset.unbind(), gc.reborrow()).unbind()?;
+        // 5. If targetOffset < 0, throw a RangeError exception.
+        if target_offset.is_negative() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Offset must be a non-negative integer",
+                gc.into_nogc(),
+            ));
+        }
+        // 6. If source is an Object that has a [[TypedArrayName]] internal slot, then
+        if let Ok(source) = TypedArray::try_from(source.get(agent)) {
+            // a. Perform ? SetTypedArrayFromTypedArray(target, targetOffset, source).
+            let gc = gc.into_nogc();
+            set_typed_array_from_typed_array(
+                agent,
+                target.get(agent).bind(gc),
+                target_offset,
+                source.bind(gc),
+                gc,
+            )?;
+        } else {
+            // 7. Else,
+            // a. Perform ? SetTypedArrayFromArrayLike(target, targetOffset, source).
+            set_typed_array_from_array_like(agent, target, target_offset, source, gc.reborrow())
+                .unbind()?;
+        }
+        // 8. Return undefined.
+        Ok(Value::Undefined)
     }
 
+    /// ### [23.2.3.27 %TypedArray%.prototype.slice ( start, end )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice)
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.slice as defined in 23.1.3.28.
     fn slice<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let start = arguments.get(0).scope(agent, nogc);
+        let end = arguments.get(1).scope(agent, nogc);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let o = ta_record.object;
+        // 3. Let srcArrayLength be TypedArrayLength(taRecord).
+        let src_array_length = typed_array_length_generic(agent, &ta_record, nogc) as i64;
+        let scoped_o = o.scope(agent, nogc);
+        // 4. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start =
+            to_integer_or_infinity(agent, start.get(agent), gc.reborrow()).unbind()?;
+        let start_index = if relative_start.is_neg_infinity() {
+            // 5. If relativeStart = -∞, let startIndex be 0.
+            0
+        } else if relative_start.is_negative() {
+            // 6. Else if relativeStart < 0, let startIndex be max(srcArrayLength + relativeStart, 0).
+            (src_array_length + relative_start.into_i64()).max(0)
+        } else {
+            // 7. Else, let startIndex be min(relativeStart, srcArrayLength).
+            relative_start.into_i64().min(src_array_length)
+        };
+        // 8. If end is undefined, let relativeEnd be srcArrayLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = end.get(agent).bind(gc.nogc());
+        let end_index = if end.is_undefined() {
+            src_array_length
+        } else {
+            let relative_end =
+                to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+            if relative_end.is_neg_infinity() {
+                // 9. If relativeEnd = -∞, let endIndex be 0.
+                0
+            } else if relative_end.is_negative() {
+                // 10. Else if relativeEnd < 0, let endIndex be max(srcArrayLength + relativeEnd, 0).
+                (src_array_length + relative_end.into_i64()).max(0)
+            } else {
+                // 11. Else, let endIndex be min(relativeEnd, srcArrayLength).
+                relative_end.into_i64().min(src_array_length)
+            }
+        };
+        // 12. Let countBytes be max(endIndex - startIndex, 0).
+        let count_bytes = (end_index - start_index).max(0);
+        // 13. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(countBytes) »).
+        let a = typed_array_species_create_with_length(
+            agent,
+            scoped_o.get(agent),
+            count_bytes,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        let o = scoped_o.get(agent).bind(gc);
+        // 14. If countBytes > 0, then
+        if count_bytes > 0 {
+            // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            let ta_record =
+                make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst, gc);
+            // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if is_typed_array_out_of_bounds_generic(agent, &ta_record, gc) {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "TypedArray out of bounds",
+                    gc,
+                ));
+            }
+            // c. Set endIndex to min(endIndex, TypedArrayLength(taRecord)).
+            let end_index = end_index.min(typed_array_length_generic(agent, &ta_record, gc) as i64);
+            // d. Set countBytes to max(endIndex - startIndex, 0).
+            let count_bytes = (end_index - start_index).max(0) as usize;
+            // e. Let srcType be TypedArrayElementType(O).
+            // f. Let targetType be TypedArrayElementType(A).
+            // g. If srcType is targetType, then
+            if core::mem::discriminant(&o) == core::mem::discriminant(&a) {
+                // i. NOTE: The transfer must be performed in a manner that
+                //    preserves the bit-level encoding of the source data.
+                // ii. Let srcBuffer be O.[[ViewedArrayBuffer]].
+                let src_buffer = o.get_viewed_array_buffer(agent, gc);
+                // iii. Let targetBuffer be A.[[ViewedArrayBuffer]].
+                let target_buffer = a.get_viewed_array_buffer(agent, gc);
+                // iv. Let elementSize be TypedArrayElementSize(O).
+                let element_size = typed_array_element_size(o);
+                // v. Let srcByteOffset be O.[[ByteOffset]].
+                let src_byte_offset = o.byte_offset(agent);
+                // vi. Let srcByteIndex be (startIndex × elementSize) + srcByteOffset.
+                let src_byte_index = start_index as usize * element_size + src_byte_offset;
+                // vii. Let targetByteIndex be A.[[ByteOffset]].
+                let target_byte_index = a.byte_offset(agent);
+                // viii. Let endByteIndex be targetByteIndex + (countBytes × elementSize).
+                let byte_count = count_bytes * element_size;
+                // ix. Repeat, while targetByteIndex < endByteIndex,
+                //     1. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, uint8, true, unordered).
+                //     2. Perform SetValueInBuffer(targetBuffer, targetByteIndex, uint8, value, true, unordered).
+                //     3. Set srcByteIndex to srcByteIndex + 1.
+                //     4. Set targetByteIndex to targetByteIndex + 1.
+                if src_buffer == target_buffer {
+                    // Note: The species constructor may have returned a view
+                    // into our own buffer; copy byte-by-byte as the
+                    // specification does.
+                    let data = target_buffer.as_mut_slice(agent);
+                    for i in 0..byte_count {
+                        data[target_byte_index + i] = data[src_byte_index + i];
+                    }
+                } else {
+                    target_buffer.copy_array_buffer_data(
+                        agent,
+                        target_byte_index,
+                        src_buffer,
+                        src_byte_index,
+                        byte_count,
+                    );
+                }
+            } else {
+                // h. Else,
+                // i. Let n be 0.
+                let mut n: u32 = 0;
+                // ii. Let k be startIndex.
+                let mut k = start_index;
+                // iii. Repeat, while k < endIndex,
+                while k < end_index {
+                    // 1. Let Pk be ! ToString(𝔽(k)).
+                    let pk = PropertyKey::try_from(k).unwrap();
+                    // 2. Let kValue be ! Get(O, Pk).
+                    let k_value = unwrap_try(try_get(agent, o, pk, gc));
+                    // 3. Perform ! Set(A, ! ToString(𝔽(n)), kValue, true).
+                    let pn = PropertyKey::from(n);
+                    unwrap_try(try_set(agent, a.into_object(), pn, k_value, true, gc)).unwrap();
+                    // 4. Set k to k + 1.
+                    k += 1;
+                    // 5. Set n to n + 1.
+                    n += 1;
+                }
+            }
+        }
+        // 15. Return A.
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.28 get %TypedArray%.prototype.some](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.some)
@@ -2834,31 +3180,270 @@ impl TypedArrayPrototype {
         Ok(false.into())
     }
 
+    /// ### [23.2.3.29 %TypedArray%.prototype.sort ( comparator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort)
+    ///
+    /// This is a distinct method that, except as described below, implements
+    /// the same requirements as those of Array.prototype.sort as defined in
+    /// 23.1.3.30. The implementation of this method may be optimized with the
+    /// knowledge that the this value is an object that has a fixed length and
+    /// whose integer-indexed properties are not sparse.
     fn sort<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let comparator = arguments.get(0).bind(nogc);
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        let comparator = if comparator.is_undefined() {
+            None
+        } else if let Some(comparator) = is_callable(comparator, nogc) {
+            Some(comparator)
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. Let obj be the this value.
+        let obj = this_value;
+        // 3. Let taRecord be ? ValidateTypedArray(obj, seq-cst).
+        let ta_record = validate_typed_array(agent, obj, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let obj = ta_record.object;
+        // 4. Let len be TypedArrayLength(taRecord).
+        let len = typed_array_length_generic(agent, &ta_record, nogc);
+        let Some(comparator) = comparator else {
+            // Fast path: Without a comparator no JavaScript code can be
+            // called, and the elements can be sorted in place.
+            let obj = obj.unbind();
+            let gc = gc.into_nogc();
+            let obj = obj.bind(gc);
+            sort_typed_array_generic(agent, obj, len, gc)?;
+            return Ok(obj.into_value());
+        };
+        let comparator = comparator.scope(agent, nogc);
+        let scoped_obj = obj.scope(agent, nogc);
+        // 5. NOTE: The following closure performs a numeric comparison rather
+        //    than the string comparison used in 23.1.3.30.
+        // 6. Let SortCompare be a new Abstract Closure with parameters (x, y)
+        //    that captures comparator and performs the following steps when
+        //    called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 7. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        let sorted_list = sort_indexed_properties::<false, true>(
+            agent,
+            obj.into_object().unbind(),
+            len,
+            Some(comparator),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let obj = scoped_obj.get(agent).bind(gc);
+        // 8. Let j be 0.
+        // 9. Repeat, while j < len,
+        for (j, value) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            // b. Set j to j + 1.
+            let pk = PropertyKey::try_from(j).unwrap();
+            unwrap_try(try_set(
+                agent,
+                obj.into_object(),
+                pk,
+                value.get(agent),
+                true,
+                gc,
+            ))
+            .unwrap();
+        }
+        // 10. Return obj.
+        Ok(obj.into_value())
     }
 
+    /// ### [23.2.3.30 %TypedArray%.prototype.subarray ( start, end )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray)
+    ///
+    /// This method returns a new TypedArray whose element type is the element
+    /// type of this TypedArray and whose ArrayBuffer is the ArrayBuffer of
+    /// this TypedArray, referencing the elements in the interval from start
+    /// (inclusive) to end (exclusive). If either start or end is negative, it
+    /// refers to an index from the end of the array, as opposed to from the
+    /// beginning.
     fn subarray<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let start = arguments.get(0).scope(agent, nogc);
+        let end = arguments.get(1).scope(agent, nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        let o = require_internal_slot_typed_array(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 4. Let buffer be O.[[ViewedArrayBuffer]].
+        let buffer = o.get_viewed_array_buffer(agent, nogc).scope(agent, nogc);
+        // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        let src_record =
+            make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst, nogc);
+        let src_length = if is_typed_array_out_of_bounds_generic(agent, &src_record, nogc) {
+            // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+            // a. Let srcLength be 0.
+            0
+        } else {
+            // 7. Else,
+            // a. Let srcLength be TypedArrayLength(srcRecord).
+            typed_array_length_generic(agent, &src_record, nogc) as i64
+        };
+        let scoped_o = o.scope(agent, nogc);
+        // 8. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start =
+            to_integer_or_infinity(agent, start.get(agent), gc.reborrow()).unbind()?;
+        let start_index = if relative_start.is_neg_infinity() {
+            // 9. If relativeStart = -∞, let startIndex be 0.
+            0
+        } else if relative_start.is_negative() {
+            // 10. Else if relativeStart < 0, let startIndex be max(srcLength + relativeStart, 0).
+            (src_length + relative_start.into_i64()).max(0)
+        } else {
+            // 11. Else, let startIndex be min(relativeStart, srcLength).
+            relative_start.into_i64().min(src_length)
+        };
+        let o = scoped_o.get(agent).bind(gc.nogc());
+        // 12. Let elementSize be TypedArrayElementSize(O).
+        let element_size = typed_array_element_size(o) as i64;
+        // 13. Let srcByteOffset be O.[[ByteOffset]].
+        let src_byte_offset = o.byte_offset(agent) as i64;
+        // 14. Let beginByteOffset be srcByteOffset + (startIndex × elementSize).
+        let begin_byte_offset = src_byte_offset + start_index * element_size;
+        let end = end.get(agent).bind(gc.nogc());
+        let new_length = if o.array_length(agent).is_none() && end.is_undefined() {
+            // 15. If O.[[ArrayLength]] is auto and end is undefined, then
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+            None
+        } else {
+            // 16. Else,
+            // a. If end is undefined, let relativeEnd be srcLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+            let end_index = if end.is_undefined() {
+                src_length
+            } else {
+                let relative_end =
+                    to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+                if relative_end.is_neg_infinity() {
+                    // b. If relativeEnd = -∞, let endIndex be 0.
+                    0
+                } else if relative_end.is_negative() {
+                    // c. Else if relativeEnd < 0, let endIndex be max(srcLength + relativeEnd, 0).
+                    (src_length + relative_end.into_i64()).max(0)
+                } else {
+                    // d. Else, let endIndex be min(relativeEnd, srcLength).
+                    relative_end.into_i64().min(src_length)
+                }
+            };
+            // e. Let newLength be max(endIndex - startIndex, 0).
+            // f. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+            Some((end_index - start_index).max(0))
+        };
+        // 17. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        typed_array_species_create_with_buffer(
+            agent,
+            scoped_o.get(agent),
+            buffer.get(agent),
+            begin_byte_offset,
+            new_length,
+            gc,
+        )
+        .map(|a| a.into_value())
     }
 
+    /// ### [23.2.3.31 %TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring)
+    ///
+    /// This is a distinct method that implements the same algorithm as
+    /// Array.prototype.toLocaleString as defined in 23.1.3.32 except that
+    /// TypedArrayLength is called in place of performing a \[\[Get]] of
+    /// "length".
     fn to_locale_string<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        #[cfg(feature = "intl")] arguments: ArgumentsList,
+        #[cfg(not(feature = "intl"))] _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        #[cfg(feature = "intl")]
+        let locales = arguments.get(0).scope(agent, nogc);
+        #[cfg(feature = "intl")]
+        let options = arguments.get(1).scope(agent, nogc);
+        // This method first validates the TypedArray.
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 1. Let array be ? ToObject(this value).
+        let array = ta_record.object;
+        // 2. Let len be ? LengthOfArrayLike(array).
+        let len = typed_array_length_generic(agent, &ta_record, nogc);
+        let array = array.scope(agent, nogc);
+        // 3. Let separator be the implementation-defined list-separator
+        //    String appropriate for the host environment's current locale
+        //    (such as ", ").
+        let separator = ",";
+        // 4. Let R be the empty String.
+        let mut r = std::string::String::new();
+        // 5. Let k be 0.
+        let mut k = 0;
+        // 6. Repeat, while k < len,
+        while k < len {
+            // a. If k > 0, set R to the string-concatenation of R and separator.
+            if k > 0 {
+                r.push_str(separator);
+            }
+            // b. Let element be ? Get(array, ! ToString(𝔽(k))).
+            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            let element = unwrap_try(try_get(agent, array.get(agent), pk, gc.nogc()));
+            // c. If element is neither undefined nor null, then
+            if !element.is_undefined() && !element.is_null() {
+                // i. Let S be ? ToString(? Invoke(element, "toLocaleString")).
+                #[cfg(not(feature = "intl"))]
+                let s = invoke(
+                    agent,
+                    element.unbind(),
+                    BUILTIN_STRING_MEMORY.toLocaleString.into(),
+                    None,
+                    gc.reborrow(),
+                );
+                // NOTE: ECMA-402 passes « locales, options » to Invoke.
+                #[cfg(feature = "intl")]
+                let s = invoke(
+                    agent,
+                    element.unbind(),
+                    BUILTIN_STRING_MEMORY.toLocaleString.into(),
+                    Some(ArgumentsList::from_mut_slice(&mut [
+                        locales.get(agent),
+                        options.get(agent),
+                    ])),
+                    gc.reborrow(),
+                );
+                let s = s.unbind()?.bind(gc.nogc());
+                let s = to_string(agent, s.unbind(), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(s.as_str(agent));
+            }
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc.into_nogc()).into_value())
     }
 
     /// ### [23.2.3.32 %TypedArray%.prototype.toReversed ( )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tospliced)
@@ -2933,13 +3518,100 @@ impl TypedArrayPrototype {
         Ok(scope_a.get(agent).into_value())
     }
 
+    /// ### [23.2.3.34 %TypedArray%.prototype.toSorted ( comparator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted)
     fn to_sorted<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let comparator = arguments.get(0).bind(nogc);
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        let comparator = if comparator.is_undefined() {
+            None
+        } else if let Some(comparator) = is_callable(comparator, nogc) {
+            Some(comparator.scope(agent, nogc))
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. Let O be the this value.
+        let o = this_value;
+        // 3. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let o = ta_record.object;
+        // 4. Let len be TypedArrayLength(taRecord).
+        let len = typed_array_length_generic(agent, &ta_record, nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 5. Let A be ? TypedArrayCreateSameType(O, len).
+        let a = typed_array_create_same_type(agent, o.unbind(), len as i64, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let Some(comparator) = comparator else {
+            // Fast path: Without a comparator no JavaScript code can be
+            // called; copy the elements over and sort them in place.
+            let a = a.unbind();
+            let gc = gc.into_nogc();
+            let a = a.bind(gc);
+            let o = scoped_o.get(agent).bind(gc);
+            let byte_count = len * typed_array_element_size(o);
+            let src_buffer = o.get_viewed_array_buffer(agent, gc);
+            let src_byte_offset = o.byte_offset(agent);
+            let target_buffer = a.get_viewed_array_buffer(agent, gc);
+            let target_byte_offset = a.byte_offset(agent);
+            target_buffer.copy_array_buffer_data(
+                agent,
+                target_byte_offset,
+                src_buffer,
+                src_byte_offset,
+                byte_count,
+            );
+            sort_typed_array_generic(agent, a, len, gc)?;
+            return Ok(a.into_value());
+        };
+        let a = a.scope(agent, gc.nogc());
+        // 6. NOTE: The following closure performs a numeric comparison rather
+        //    than the string comparison used in 23.1.3.34.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y)
+        //    that captures comparator and performs the following steps when
+        //    called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted_list = sort_indexed_properties::<false, true>(
+            agent,
+            scoped_o.get(agent).into_object(),
+            len,
+            Some(comparator),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let a = a.get(agent).bind(gc);
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, value) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+            // b. Set j to j + 1.
+            let pk = PropertyKey::try_from(j).unwrap();
+            unwrap_try(try_set(
+                agent,
+                a.into_object(),
+                pk,
+                value.get(agent),
+                true,
+                gc,
+            ))
+            .unwrap();
+        }
+        // 11. Return A.
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.35 %TypedArray%.prototype.values ( )](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-%symbol.tostringtag%)
@@ -2960,13 +3632,90 @@ impl TypedArrayPrototype {
         )
     }
 
+    /// ### [23.2.3.36 %TypedArray%.prototype.with ( index, value )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.with)
     fn with<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!();
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let index = arguments.get(0).scope(agent, nogc);
+        let value = arguments.get(1).scope(agent, nogc);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = typed_array_length_generic(agent, &ta_record, nogc) as i64;
+        let is_bigint = o.is_bigint();
+        let scoped_o = o.scope(agent, nogc);
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = to_integer_or_infinity(agent, index.get(agent), gc.reborrow())
+            .unbind()?
+            .into_i64();
+        // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        let actual_index = if relative_index >= 0 {
+            relative_index
+        } else {
+            // 6. Else, let actualIndex be len + relativeIndex.
+            len + relative_index
+        };
+        let numeric_value = if is_bigint {
+            // 7. If O.[[ContentType]] is bigint, let numericValue be ? ToBigInt(value).
+            to_big_int(agent, value.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc())
+                .into_value()
+        } else {
+            // 8. Else, let numericValue be ? ToNumber(value).
+            to_number(agent, value.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc())
+                .into_value()
+        };
+        // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        if is_valid_integer_index_generic(agent, scoped_o.get(agent), actual_index, gc.nogc())
+            .is_none()
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "invalid or out-of-range index",
+                gc.into_nogc(),
+            ));
+        }
+        let numeric_value = numeric_value.scope(agent, gc.nogc());
+        // 10. Let A be ? TypedArrayCreateSameType(O, len).
+        let a = typed_array_create_same_type(agent, scoped_o.get(agent), len, gc.reborrow())
+            .unbind()?;
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        let o = scoped_o.get(agent).bind(gc);
+        let numeric_value = numeric_value.get(agent).bind(gc);
+        // 11. Let k be 0.
+        let mut k = 0;
+        // 12. Repeat, while k < len,
+        while k < len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::try_from(k).unwrap();
+            let from_value = if k == actual_index {
+                // b. If k is actualIndex, let fromValue be numericValue.
+                numeric_value
+            } else {
+                // c. Else, let fromValue be ! Get(O, Pk).
+                unwrap_try(try_get(agent, o, pk, gc))
+            };
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            unwrap_try(try_set(agent, a.into_object(), pk, from_value, true, gc)).unwrap();
+            // e. Set k to k + 1.
+            k += 1;
+        }
+        // 13. Return A.
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.38 get %TypedArray%.prototype \[ %Symbol.toStringTag% \]](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-%symbol.tostringtag%)
@@ -3374,3 +4123,313 @@ fn fill_typed_array<'a, T: Viewable>(
     // 20. Return O.
     Ok(ta)
 }
+
+/// ### [23.2.3.26.1 SetTypedArrayFromTypedArray ( target, targetOffset, source )](https://tc39.es/ecma262/#sec-settypedarrayfromtypedarray)
+///
+/// The abstract operation SetTypedArrayFromTypedArray takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (a
+/// TypedArray) and returns either a normal completion containing unused or a
+/// throw completion. It sets multiple values in target, starting at index
+/// targetOffset, reading the values from source.
+fn set_typed_array_from_typed_array<'a>(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: IntegerOrInfinity,
+    source: TypedArray,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let target = target.bind(gc);
+    let source = source.bind(gc);
+    // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
+    let target_buffer = target.get_viewed_array_buffer(agent, gc);
+    // 2. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    let target_record =
+        make_typed_array_with_buffer_witness_record(agent, target, Ordering::SeqCst, gc);
+    // 3. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds_generic(agent, &target_record, gc) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 4. Let targetLength be TypedArrayLength(targetRecord).
+    let target_length = typed_array_length_generic(agent, &target_record, gc);
+    // 5. Let srcBuffer be source.[[ViewedArrayBuffer]].
+    let src_buffer = source.get_viewed_array_buffer(agent, gc);
+    // 6. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(source, seq-cst).
+    let src_record =
+        make_typed_array_with_buffer_witness_record(agent, source, Ordering::SeqCst, gc);
+    // 7. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds_generic(agent, &src_record, gc) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 8. Let srcLength be TypedArrayLength(srcRecord).
+    let src_length = typed_array_length_generic(agent, &src_record, gc);
+    // 9. Let targetType be TypedArrayElementType(target).
+    // 10. Let targetElementSize be TypedArrayElementSize(target).
+    let target_element_size = typed_array_element_size(target);
+    // 11. Let targetByteOffset be target.[[ByteOffset]].
+    let target_byte_offset = target.byte_offset(agent);
+    // 12. Let srcType be TypedArrayElementType(source).
+    // 13. Let srcElementSize be TypedArrayElementSize(source).
+    let src_element_size = typed_array_element_size(source);
+    // 14. Let srcByteOffset be source.[[ByteOffset]].
+    let src_byte_offset = source.byte_offset(agent);
+    // 15. If targetOffset = +∞, throw a RangeError exception.
+    if target_offset.is_pos_infinity() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Offset out of bounds",
+            gc,
+        ));
+    }
+    let target_offset = target_offset.into_i64() as usize;
+    // 16. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if src_length + target_offset > target_length {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc,
+        ));
+    }
+    // 17. If target.[[ContentType]] is not source.[[ContentType]], throw a TypeError exception.
+    if target.is_bigint() != source.is_bigint() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray content types do not match",
+            gc,
+        ));
+    }
+    // 18. If IsSharedArrayBuffer(srcBuffer) is true, IsSharedArrayBuffer(targetBuffer) is true, and srcBuffer.[[ArrayBufferData]] is targetBuffer.[[ArrayBufferData]], let sameSharedArrayBuffer be true; otherwise, let sameSharedArrayBuffer be false.
+    // 19. If SameValue(srcBuffer, targetBuffer) is true or sameSharedArrayBuffer is true, then
+    //     a. Let srcByteLength be TypedArrayByteLength(srcRecord).
+    //     b. Set srcBuffer to ? CloneArrayBuffer(srcBuffer, srcByteOffset, srcByteLength).
+    //     c. Let srcByteIndex be 0.
+    // 20. Else,
+    //     a. Let srcByteIndex be srcByteOffset.
+    // 21. Let targetByteIndex be (targetOffset × targetElementSize) + targetByteOffset.
+    let target_byte_index = target_offset * target_element_size + target_byte_offset;
+    // 22. Let limit be targetByteIndex + (targetElementSize × srcLength).
+    // 23. If srcType is targetType, then
+    if core::mem::discriminant(&target) == core::mem::discriminant(&source) {
+        // a. NOTE: The transfer must be performed in a manner that preserves
+        //    the bit-level encoding of the source data.
+        // b. Repeat, while targetByteIndex < limit,
+        let byte_count = src_element_size * src_length;
+        if src_buffer == target_buffer {
+            // Note: copy_within behaves as if the source data was first
+            // cloned, so we do not need to perform CloneArrayBuffer.
+            target_buffer.as_mut_slice(agent).copy_within(
+                src_byte_offset..src_byte_offset + byte_count,
+                target_byte_index,
+            );
+        } else {
+            target_buffer.copy_array_buffer_data(
+                agent,
+                target_byte_index,
+                src_buffer,
+                src_byte_offset,
+                byte_count,
+            );
+        }
+    } else {
+        // 24. Else,
+        // Note: Reading all values out before writing any has the same effect
+        // as cloning the source buffer would.
+        let values = (0..src_length as i64)
+            .map(|index| typed_array_get_element_generic(agent, source, index, gc).unwrap())
+            .collect::<Vec<_>>();
+        // a. Repeat, while targetByteIndex < limit,
+        for (k, value) in values.into_iter().enumerate() {
+            // i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, srcType, true, unordered).
+            // ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, unordered).
+            // iii. Set srcByteIndex to srcByteIndex + srcElementSize.
+            // iv. Set targetByteIndex to targetByteIndex + targetElementSize.
+            unwrap_try(try_typed_array_set_element_generic(
+                agent,
+                target,
+                (target_offset + k) as i64,
+                value.into_value(),
+                gc,
+            ));
+        }
+    }
+    // 25. Return unused.
+    Ok(())
+}
+
+/// ### [23.2.3.26.2 SetTypedArrayFromArrayLike ( target, targetOffset, source )](https://tc39.es/ecma262/#sec-settypedarrayfromarraylike)
+///
+/// The abstract operation SetTypedArrayFromArrayLike takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (an
+/// ECMAScript language value, but not a TypedArray) and returns either a
+/// normal completion containing unused or a throw completion. It sets
+/// multiple values in target, starting at index targetOffset, reading the
+/// values from source.
+fn set_typed_array_from_array_like<'a>(
+    agent: &mut Agent,
+    target: Scoped<TypedArray>,
+    target_offset: IntegerOrInfinity,
+    source: Scoped<Value>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    let target_record = make_typed_array_with_buffer_witness_record(
+        agent,
+        target.get(agent),
+        Ordering::SeqCst,
+        gc.nogc(),
+    );
+    // 2. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds_generic(agent, &target_record, gc.nogc()) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Let targetLength be TypedArrayLength(targetRecord).
+    let target_length = typed_array_length_generic(agent, &target_record, gc.nogc()) as i64;
+    // 4. Let src be ? ToObject(source).
+    let src = to_object(agent, source.get(agent), gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 5. Let srcLength be ? LengthOfArrayLike(src).
+    let src_length = length_of_array_like(agent, src.get(agent), gc.reborrow()).unbind()?;
+    // 6. If targetOffset = +∞, throw a RangeError exception.
+    if target_offset.is_pos_infinity() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Offset out of bounds",
+            gc.into_nogc(),
+        ));
+    }
+    let target_offset = target_offset.into_i64();
+    // 7. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if src_length + target_offset > target_length {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc.into_nogc(),
+        ));
+    }
+    // 8. Let k be 0.
+    let mut k = 0;
+    // 9. Repeat, while k < srcLength,
+    while k < src_length {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::try_from(k).unwrap();
+        // b. Let value be ? Get(src, Pk).
+        let value = get(agent, src.get(agent), pk, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // c. Let targetIndex be 𝔽(targetOffset + k).
+        let target_index = target_offset + k;
+        // d. Perform ? TypedArraySetElement(target, targetIndex, value).
+        typed_array_set_element_generic(
+            agent,
+            target.get(agent),
+            target_index,
+            value.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Set k to k + 1.
+        k += 1;
+    }
+    // 10. Return unused.
+    Ok(())
+}
+
+/// Sort the elements of a TypedArray in place according to the default
+/// ordering of CompareTypedArrayElements.
+fn sort_typed_array_generic<'a>(
+    agent: &mut Agent,
+    ta: TypedArray,
+    len: usize,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    match ta {
+        TypedArray::Int8Array(_) => sort_typed_array::<i8>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::Uint8Array(_) => sort_typed_array::<u8>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::Uint8ClampedArray(_) => {
+            sort_typed_array::<U8Clamped>(agent, ta, len, |a, b| a.cmp(b), gc)
+        }
+        TypedArray::Int16Array(_) => sort_typed_array::<i16>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::Uint16Array(_) => sort_typed_array::<u16>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::Int32Array(_) => sort_typed_array::<i32>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::Uint32Array(_) => sort_typed_array::<u32>(agent, ta, len, |a, b| a.cmp(b), gc),
+        TypedArray::BigInt64Array(_) => {
+            sort_typed_array::<i64>(agent, ta, len, |a, b| a.cmp(b), gc)
+        }
+        TypedArray::BigUint64Array(_) => {
+            sort_typed_array::<u64>(agent, ta, len, |a, b| a.cmp(b), gc)
+        }
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => sort_typed_array::<f16>(
+            agent,
+            ta,
+            len,
+            |a, b| compare_typed_array_numbers(*a as f64, *b as f64),
+            gc,
+        ),
+        TypedArray::Float32Array(_) => sort_typed_array::<f32>(
+            agent,
+            ta,
+            len,
+            |a, b| compare_typed_array_numbers(*a as f64, *b as f64),
+            gc,
+        ),
+        TypedArray::Float64Array(_) => sort_typed_array::<f64>(
+            agent,
+            ta,
+            len,
+            |a, b| compare_typed_array_numbers(*a, *b),
+            gc,
+        ),
+    }
+}
+
+fn sort_typed_array<'a, T: Viewable>(
+    agent: &mut Agent,
+    ta: TypedArray,
+    len: usize,
+    compare: impl FnMut(&T, &T) -> core::cmp::Ordering,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let array_buffer = ta.get_viewed_array_buffer(agent, gc);
+    let byte_offset = ta.byte_offset(agent);
+    let byte_length = ta.byte_length(agent);
+    let byte_slice = array_buffer.as_mut_slice(agent);
+    if byte_slice.is_empty() {
+        return Ok(());
+    }
+    let byte_slice = if let Some(byte_length) = byte_length {
+        let end_index = byte_offset + byte_length;
+        if end_index > byte_slice.len() {
+            return Ok(());
+        }
+        &mut byte_slice[byte_offset..end_index]
+    } else {
+        &mut byte_slice[byte_offset..]
+    };
+    // SAFETY: All bytes in byte_slice are initialized, and all bitwise
+    // combinations of T are valid values. Alignment of T's is
+    // guaranteed by align_to_mut itself.
+    let (head, slice, _) = unsafe { byte_slice.align_to_mut::<T>() };
+    if !head.is_empty() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray is not properly aligned",
+            gc,
+        ));
+    }
+    let len = len.min(slice.len());
+    slice[..len].sort_by(compare);
+    Ok(())
+}
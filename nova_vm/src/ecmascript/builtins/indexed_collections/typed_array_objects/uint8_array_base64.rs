@@ -0,0 +1,1109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [Uint8Array to/from base64](https://tc39.es/proposal-arraybuffer-base64/spec/)
+//!
+//! The base64 and hex conversion methods of %Uint8Array% and
+//! %Uint8Array.prototype%.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{get, get_options_object},
+            type_conversion::to_boolean,
+        },
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, array_buffer::Ordering,
+            ordinary::ordinary_object_create_with_intrinsics, typed_array::TypedArray,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, IntoFunction, IntoValue, Object, PropertyDescriptor, String,
+            Value,
+        },
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+    },
+};
+
+use super::abstract_operations::{
+    allocate_typed_array, is_typed_array_out_of_bounds,
+    make_typed_array_with_buffer_witness_record, typed_array_length,
+};
+
+pub(crate) struct Uint8ArrayFromBase64;
+impl Builtin for Uint8ArrayFromBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromBase64;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::from_base64);
+}
+pub(crate) struct Uint8ArrayFromHex;
+impl Builtin for Uint8ArrayFromHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromHex;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::from_hex);
+}
+pub(crate) struct Uint8ArrayPrototypeSetFromBase64;
+impl Builtin for Uint8ArrayPrototypeSetFromBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.setFromBase64;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::set_from_base64);
+}
+pub(crate) struct Uint8ArrayPrototypeSetFromHex;
+impl Builtin for Uint8ArrayPrototypeSetFromHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.setFromHex;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::set_from_hex);
+}
+pub(crate) struct Uint8ArrayPrototypeToBase64;
+impl Builtin for Uint8ArrayPrototypeToBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toBase64;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::to_base64);
+}
+pub(crate) struct Uint8ArrayPrototypeToHex;
+impl Builtin for Uint8ArrayPrototypeToHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toHex;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(Uint8ArrayBase64::to_hex);
+}
+
+struct Uint8ArrayBase64;
+impl Uint8ArrayBase64 {
+    /// ### [Uint8Array.fromBase64 ( string \[ , options \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64)
+    fn from_base64<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let string = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Uint8Array.fromBase64 requires a string",
+                gc.into_nogc(),
+            ));
+        };
+        let string = string.scope(agent, gc.nogc());
+        // 2. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to
+        //    "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or
+        //    "stop-before-partial", throw a TypeError exception.
+        let (alphabet, last_chunk_handling) =
+            get_base64_decode_options(agent, opts.unbind(), gc.reborrow()).unbind()?;
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        let result = from_base64(
+            &string_to_ascii(agent, string.get(agent)),
+            alphabet,
+            last_chunk_handling,
+            usize::MAX,
+        );
+        // 10. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc.into_nogc(),
+            ));
+        }
+        // 11. Let resultLength be the length of result.[[Bytes]].
+        // 12. Let ta be ? AllocateTypedArray(Uint8Array, %Uint8Array%,
+        //     %Uint8Array.prototype%, resultLength).
+        // 13. Set the value at each index of
+        //     ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the
+        //     corresponding index of result.[[Bytes]].
+        // 14. Return ta.
+        create_uint8_array_from_bytes(agent, &result.bytes, gc).map(TypedArray::into_value)
+    }
+
+    /// ### [Uint8Array.fromHex ( string )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex)
+    fn from_hex<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Uint8Array.fromHex requires a string",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. Let result be FromHex(string).
+        let result = from_hex(
+            &string_to_ascii(agent, string),
+            string.utf16_len(agent),
+            usize::MAX,
+        );
+        // 3. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc.into_nogc(),
+            ));
+        }
+        // 4. Let resultLength be the length of result.[[Bytes]].
+        // 5. Let ta be ? AllocateTypedArray(Uint8Array, %Uint8Array%,
+        //    %Uint8Array.prototype%, resultLength).
+        // 6. Set the value at each index of
+        //    ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the
+        //    corresponding index of result.[[Bytes]].
+        // 7. Return ta.
+        create_uint8_array_from_bytes(agent, &result.bytes, gc).map(TypedArray::into_value)
+    }
+
+    /// ### [Uint8Array.prototype.setFromBase64 ( string \[ , options \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64)
+    fn set_from_base64<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Uint8Array.prototype.setFromBase64 requires a string",
+                gc.into_nogc(),
+            ));
+        };
+        let into = into.scope(agent, gc.nogc());
+        let string = string.scope(agent, gc.nogc());
+        // 4. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to
+        //    "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or
+        //     "stop-before-partial", throw a TypeError exception.
+        let (alphabet, last_chunk_handling) =
+            get_base64_decode_options(agent, opts.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let into = into.get(agent).bind(gc);
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+        //     exception.
+        // 13. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = uint8_array_length(agent, into, gc)?;
+        // 14. Let result be FromBase64(string, alphabet, lastChunkHandling,
+        //     byteLength).
+        let result = from_base64(
+            &string_to_ascii(agent, string.get(agent)),
+            alphabet,
+            last_chunk_handling,
+            byte_length,
+        );
+        // 15. Let bytes be result.[[Bytes]].
+        // 16. Let written be the length of bytes.
+        // 17. NOTE: FromBase64 does not invoke any user code, so the
+        //     ArrayBuffer backing into cannot have been detached or shrunk.
+        // 18. Assert: written ≤ byteLength.
+        debug_assert!(result.bytes.len() <= byte_length);
+        // 19. Perform SetUint8ArrayBytes(into, bytes).
+        set_uint8_array_bytes(agent, into, &result.bytes);
+        // 20. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc,
+            ));
+        }
+        // 21. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 22. Perform ! CreateDataPropertyOrThrow(resultObject, "read",
+        //     𝔽(result.[[Read]])).
+        // 23. Perform ! CreateDataPropertyOrThrow(resultObject, "written",
+        //     𝔽(written)).
+        // 24. Return resultObject.
+        Ok(create_read_written_object(agent, result.read, result.bytes.len(), gc).into_value())
+    }
+
+    /// ### [Uint8Array.prototype.setFromHex ( string )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex)
+    fn set_from_hex<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let string = arguments.get(0).bind(gc);
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(agent, this_value, gc)?;
+        // 3. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Uint8Array.prototype.setFromHex requires a string",
+                gc,
+            ));
+        };
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+        //    exception.
+        // 6. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = uint8_array_length(agent, into, gc)?;
+        // 7. Let result be FromHex(string, byteLength).
+        let result = from_hex(
+            &string_to_ascii(agent, string),
+            string.utf16_len(agent),
+            byte_length,
+        );
+        // 8. Let bytes be result.[[Bytes]].
+        // 9. Let written be the length of bytes.
+        // 10. NOTE: FromHex does not invoke any user code, so the ArrayBuffer
+        //     backing into cannot have been detached or shrunk.
+        // 11. Assert: written ≤ byteLength.
+        debug_assert!(result.bytes.len() <= byte_length);
+        // 12. Perform SetUint8ArrayBytes(into, bytes).
+        set_uint8_array_bytes(agent, into, &result.bytes);
+        // 13. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc,
+            ));
+        }
+        // 14. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "read",
+        //     𝔽(result.[[Read]])).
+        // 16. Perform ! CreateDataPropertyOrThrow(resultObject, "written",
+        //     𝔽(written)).
+        // 17. Return resultObject.
+        Ok(create_read_written_object(agent, result.read, result.bytes.len(), gc).into_value())
+    }
+
+    /// ### [Uint8Array.prototype.toBase64 ( \[ options \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64)
+    fn to_base64<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let options = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        let alphabet = get_alphabet_option(agent, opts.get(agent), gc.reborrow()).unbind()?;
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding = get(
+            agent,
+            opts.get(agent),
+            BUILTIN_STRING_MEMORY.omitPadding.into(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let omit_padding = to_boolean(agent, omit_padding);
+        let gc = gc.into_nogc();
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = get_uint8_array_bytes(agent, o.get(agent), gc)?;
+        // 9. If alphabet is "base64", then
+        //    a. Let outAscii be the sequence of code points which results from
+        //       encoding toEncode according to the base64 encoding specified
+        //       in section 4 of RFC 4648. Padding is included if and only if
+        //       omitPadding is false.
+        // 10. Else,
+        //    a. Assert: alphabet is "base64url".
+        //    b. Let outAscii be the sequence of code points which results from
+        //       encoding toEncode according to the base64url encoding
+        //       specified in section 5 of RFC 4648. Padding is included if and
+        //       only if omitPadding is false.
+        let out_ascii = encode_base64(to_encode, alphabet, omit_padding);
+        // 11. Return CodePointsToString(outAscii).
+        Ok(String::from_string(agent, out_ascii, gc).into_value())
+    }
+
+    /// ### [Uint8Array.prototype.toHex ( )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex)
+    fn to_hex<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(agent, this_value, gc)?;
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = get_uint8_array_bytes(agent, o, gc)?;
+        // 4. Let out be the empty String.
+        let mut out = std::string::String::with_capacity(to_encode.len() * 2);
+        // 5. For each byte byte of toEncode, do
+        for byte in to_encode {
+            // a. Let hex be Number::toString(𝔽(byte), 16).
+            // b. Set hex to StringPad(hex, 2, "0", start).
+            // c. Set out to the string-concatenation of out and hex.
+            out.push(HEX_DIGITS[usize::from(byte >> 4)] as char);
+            out.push(HEX_DIGITS[usize::from(byte & 0xF)] as char);
+        }
+        // 6. Return out.
+        Ok(String::from_string(agent, out, gc).into_value())
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    Base64,
+    Base64Url,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LastChunkHandling {
+    Loose,
+    Strict,
+    StopBeforePartial,
+}
+
+trivially_bindable!(Alphabet);
+trivially_bindable!((Alphabet, LastChunkHandling));
+
+/// The Record returned by FromBase64 and FromHex.
+///
+/// The \[\[Error]] field is always a SyntaxError; we store only its message.
+struct DecodeResult {
+    /// \[\[Read]]
+    read: usize,
+    /// \[\[Bytes]]
+    bytes: Vec<u8>,
+    /// \[\[Error]]
+    error: Option<&'static str>,
+}
+
+/// ### [ValidateUint8Array ( ta )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-validateuint8array)
+///
+/// The abstract operation ValidateUint8Array takes argument ta (an ECMAScript
+/// language value) and returns either a normal completion containing unused
+/// or a throw completion.
+fn validate_uint8_array<'a>(
+    agent: &mut Agent,
+    ta: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+    // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError
+    //    exception.
+    if let Value::Uint8Array(ta) = ta {
+        Ok(TypedArray::Uint8Array(ta.bind(gc)))
+    } else {
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected this to be Uint8Array",
+            gc,
+        ))
+    }
+}
+
+/// Performs steps 1 to 4 of GetUint8ArrayBytes, returning the length of the
+/// Uint8Array or throwing a TypeError if it is out of bounds.
+fn uint8_array_length<'a>(
+    agent: &mut Agent,
+    ta: TypedArray,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, usize> {
+    // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+    // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+    let ta_record = make_typed_array_with_buffer_witness_record(agent, ta, Ordering::SeqCst, gc);
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+    //    exception.
+    if is_typed_array_out_of_bounds::<u8>(agent, &ta_record, gc) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 4. Let len be TypedArrayLength(taRecord).
+    Ok(typed_array_length::<u8>(agent, &ta_record, gc))
+}
+
+/// ### [GetUint8ArrayBytes ( ta )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-getuint8arraybytes)
+///
+/// The abstract operation GetUint8ArrayBytes takes argument ta (a Uint8Array)
+/// and returns either a normal completion containing a List of byte values or
+/// a throw completion.
+fn get_uint8_array_bytes<'agent, 'a>(
+    agent: &'agent mut Agent,
+    ta: TypedArray,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, &'agent [u8]> {
+    let len = uint8_array_length(agent, ta, gc)?;
+    // 5. Let byteOffset be ta.[[ByteOffset]].
+    let byte_offset = ta.byte_offset(agent);
+    // 6. Let bytes be a new empty List.
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    //    a. Let byteIndex be byteOffset + index.
+    //    b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true,
+    //       unordered)).
+    //    c. Append byte to bytes.
+    //    d. Set index to index + 1.
+    // 9. Return bytes.
+    let buffer = ta.get_viewed_array_buffer(agent, gc);
+    Ok(&buffer.as_slice(agent)[byte_offset..byte_offset + len])
+}
+
+/// ### [SetUint8ArrayBytes ( into, bytes )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-setuint8arraybytes)
+///
+/// The abstract operation SetUint8ArrayBytes takes arguments into (a
+/// Uint8Array) and bytes (a List of byte values) and returns unused.
+fn set_uint8_array_bytes(agent: &mut Agent, into: TypedArray, bytes: &[u8]) {
+    // 1. Let offset be into.[[ByteOffset]].
+    let offset = into.byte_offset(agent);
+    // 2. Let len be the length of bytes.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < len,
+    //    a. Let byte be bytes[index].
+    //    b. Let byteIndexInBuffer be index + offset.
+    //    c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]],
+    //       byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+    //    d. Set index to index + 1.
+    let buffer = agent[into].viewed_array_buffer;
+    buffer.as_mut_slice(agent)[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+/// Performs AllocateTypedArray(Uint8Array, %Uint8Array%,
+/// %Uint8Array.prototype%, resultLength) and sets the bytes of the new
+/// Uint8Array to the given bytes.
+fn create_uint8_array_from_bytes<'gc>(
+    agent: &mut Agent,
+    bytes: &[u8],
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TypedArray<'gc>> {
+    let uint8_array_constructor = agent
+        .current_realm_record()
+        .intrinsics()
+        .uint8_array()
+        .into_function();
+    let ta = allocate_typed_array::<u8>(
+        agent,
+        uint8_array_constructor,
+        ProtoIntrinsics::Uint8Array,
+        Some(bytes.len()),
+        gc,
+    )?;
+    set_uint8_array_bytes(agent, ta, bytes);
+    Ok(ta)
+}
+
+/// Creates the { read, written } result object of setFromBase64 and
+/// setFromHex.
+fn create_read_written_object<'a>(
+    agent: &mut Agent,
+    read: usize,
+    written: usize,
+    gc: NoGcScope<'a, '_>,
+) -> Object<'a> {
+    let result_object =
+        ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None, gc);
+    result_object.property_storage().set(
+        agent,
+        BUILTIN_STRING_MEMORY.read.to_property_key(),
+        PropertyDescriptor::new_data_descriptor(Value::try_from(read as i64).unwrap()),
+    );
+    result_object.property_storage().set(
+        agent,
+        BUILTIN_STRING_MEMORY.written.to_property_key(),
+        PropertyDescriptor::new_data_descriptor(Value::try_from(written as i64).unwrap()),
+    );
+    result_object
+}
+
+/// Gets the "alphabet" option.
+fn get_alphabet_option<'gc>(
+    agent: &mut Agent,
+    opts: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Alphabet> {
+    // Let alphabet be ? Get(opts, "alphabet").
+    let alphabet = get(
+        agent,
+        opts,
+        BUILTIN_STRING_MEMORY.alphabet.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // If alphabet is undefined, set alphabet to "base64".
+    if alphabet.is_undefined() {
+        return Ok(Alphabet::Base64);
+    }
+    // If alphabet is neither "base64" nor "base64url", throw a TypeError
+    // exception.
+    let alphabet = match String::try_from(alphabet) {
+        Ok(alphabet) => match alphabet.as_wtf8(agent).as_str() {
+            Some("base64") => Some(Alphabet::Base64),
+            Some("base64url") => Some(Alphabet::Base64Url),
+            _ => None,
+        },
+        Err(_) => None,
+    };
+    alphabet.ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "alphabet must be \"base64\" or \"base64url\"",
+            gc.into_nogc(),
+        )
+    })
+}
+
+/// Gets the "alphabet" and "lastChunkHandling" options.
+fn get_base64_decode_options<'gc>(
+    agent: &mut Agent,
+    opts: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (Alphabet, LastChunkHandling)> {
+    let opts = opts.scope(agent, gc.nogc());
+    let alphabet = get_alphabet_option(agent, opts.get(agent), gc.reborrow()).unbind()?;
+    // Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+    let last_chunk_handling = get(
+        agent,
+        opts.get(agent),
+        BUILTIN_STRING_MEMORY.lastChunkHandling.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+    if last_chunk_handling.is_undefined() {
+        return Ok((alphabet, LastChunkHandling::Loose));
+    }
+    // If lastChunkHandling is not one of "loose", "strict", or
+    // "stop-before-partial", throw a TypeError exception.
+    let last_chunk_handling = match String::try_from(last_chunk_handling) {
+        Ok(last_chunk_handling) => match last_chunk_handling.as_wtf8(agent).as_str() {
+            Some("loose") => Some(LastChunkHandling::Loose),
+            Some("strict") => Some(LastChunkHandling::Strict),
+            Some("stop-before-partial") => Some(LastChunkHandling::StopBeforePartial),
+            _ => None,
+        },
+        Err(_) => None,
+    };
+    let Some(last_chunk_handling) = last_chunk_handling else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "lastChunkHandling must be \"loose\", \"strict\" or \"stop-before-partial\"",
+            gc.into_nogc(),
+        ));
+    };
+    Ok((alphabet, last_chunk_handling))
+}
+
+/// Returns the code points of the string with every non-ASCII code point
+/// replaced by 0xFF.
+///
+/// Neither decoding algorithm accepts non-ASCII input, and they stop at the
+/// first non-ASCII code point. Every index they report therefore lies in the
+/// ASCII prefix of the string, where code point indexes and code unit indexes
+/// coincide.
+fn string_to_ascii(agent: &Agent, string: String) -> Vec<u8> {
+    string
+        .as_wtf8(agent)
+        .code_points()
+        .map(|cp| {
+            u8::try_from(cp.to_u32())
+                .ok()
+                .filter(u8::is_ascii)
+                .unwrap_or(0xFF)
+        })
+        .collect()
+}
+
+/// ### [SkipAsciiWhitespace ( string, index )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-skipasciiwhitespace)
+///
+/// The abstract operation SkipAsciiWhitespace takes arguments string (a
+/// String) and index (a non-negative integer) and returns a non-negative
+/// integer.
+fn skip_ascii_whitespace(string: &[u8], mut index: usize) -> usize {
+    // 1. Let length be the length of string.
+    // 2. Repeat, while index < length,
+    while let Some(&char) = string.get(index) {
+        // a. Let char be the code unit at index index of string.
+        // b. If char is neither 0x0009 (TAB), 0x000A (LF), 0x000C (FF),
+        //    0x000D (CR), nor 0x0020 (SPACE), then
+        if !matches!(char, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ') {
+            // i. Return index.
+            return index;
+        }
+        // c. Set index to index + 1.
+        index += 1;
+    }
+    // 3. Return index.
+    index
+}
+
+/// ### [DecodeBase64Chunk ( chunk \[ , throwOnExtraBits \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodebase64chunk)
+///
+/// The abstract operation DecodeBase64Chunk takes argument chunk (a String)
+/// and optional argument throwOnExtraBits (a Boolean) and returns either a
+/// normal completion containing a List of byte values, or a throw completion.
+///
+/// The chunk is given as a list of 6-bit values and the decoded bytes are
+/// appended to `bytes`.
+fn decode_base64_chunk(
+    chunk: &[u8],
+    throw_on_extra_bits: bool,
+    bytes: &mut Vec<u8>,
+) -> Result<(), &'static str> {
+    // 1. Let chunkLength be the length of chunk.
+    let chunk_length = chunk.len();
+    // 2. If chunkLength = 2, then
+    //    a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength = 3, then
+    //    a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //    a. Assert: chunkLength is 4.
+    debug_assert!((2..=4).contains(&chunk_length));
+    let mut sextets = [0u8; 4];
+    sextets[..chunk_length].copy_from_slice(chunk);
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from
+    //    decoding chunk as base64 (such that applying the base64 encoding
+    //    specified in section 4 of RFC 4648 to byteSequence would result in
+    //    chunk).
+    let bits = (u32::from(sextets[0]) << 18)
+        | (u32::from(sextets[1]) << 12)
+        | (u32::from(sextets[2]) << 6)
+        | u32::from(sextets[3]);
+    let byte_sequence = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+    match chunk_length {
+        // 6. If chunkLength = 2, then
+        2 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and byteSequence[1] ≠ 0, then
+            if throw_on_extra_bits && byte_sequence[1] != 0 {
+                // i. Throw a SyntaxError exception.
+                return Err("Base64 string has non-zero padding bits");
+            }
+            // c. Return « byteSequence[0] ».
+            bytes.push(byte_sequence[0]);
+        }
+        // 7. Else if chunkLength = 3, then
+        3 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and byteSequence[2] ≠ 0, then
+            if throw_on_extra_bits && byte_sequence[2] != 0 {
+                // i. Throw a SyntaxError exception.
+                return Err("Base64 string has non-zero padding bits");
+            }
+            // c. Return « byteSequence[0], byteSequence[1] ».
+            bytes.extend_from_slice(&byte_sequence[..2]);
+        }
+        // 8. Else,
+        _ => {
+            // a. Return byteSequence.
+            bytes.extend_from_slice(&byte_sequence);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the 6-bit value of a character of the standard base64 alphabet.
+fn standard_base64_value(char: u8) -> Option<u8> {
+    match char {
+        b'A'..=b'Z' => Some(char - b'A'),
+        b'a'..=b'z' => Some(char - b'a' + 26),
+        b'0'..=b'9' => Some(char - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// ### [FromBase64 ( string, alphabet, lastChunkHandling \[ , maxLength \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64)
+///
+/// The abstract operation FromBase64 takes arguments string (a String),
+/// alphabet ("base64" or "base64url"), and lastChunkHandling ("loose",
+/// "strict", or "stop-before-partial") and optional argument maxLength (a
+/// non-negative integer) and returns a Record with fields \[\[Read]] (a
+/// non-negative integer), \[\[Bytes]] (a List of byte values), and
+/// \[\[Error]] (either none or a SyntaxError object).
+fn from_base64(
+    string: &[u8],
+    alphabet: Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    // 1. If maxLength is not present, then
+    //    a. Let maxLength be 2**53 - 1.
+    max_length: usize,
+) -> DecodeResult {
+    // 2. NOTE: Because the input is a string, the length of strings is
+    //    limited to 2**53 - 1 characters, and the output requires no more
+    //    bytes than the input has characters, this limit can never be
+    //    reached. However, it is editorially convenient to use a finite
+    //    value here.
+    // 3. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return DecodeResult {
+            read: 0,
+            bytes: Vec::new(),
+            error: None,
+        };
+    }
+    // 4. Let read be 0.
+    let mut read = 0;
+    // 5. Let bytes be « ».
+    let mut bytes = Vec::with_capacity((string.len() / 4 * 3 + 2).min(max_length));
+    // 6. Let chunk be the empty String.
+    let mut chunk = [0u8; 4];
+    // 7. Let chunkLength be 0.
+    let mut chunk_length = 0;
+    // 8. Let index be 0.
+    let mut index = 0;
+    // 9. Let length be the length of string.
+    let length = string.len();
+    let syntax_error = |read, bytes| DecodeResult {
+        read,
+        bytes,
+        error: Some("Invalid base64 string"),
+    };
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if chunk_length > 0 {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    LastChunkHandling::StopBeforePartial => {
+                        // a. Return the Record { [[Read]]: read, [[Bytes]]:
+                        //    bytes, [[Error]]: none }.
+                        return DecodeResult {
+                            read,
+                            bytes,
+                            error: None,
+                        };
+                    }
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk_length == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read,
+                            //     [[Bytes]]: bytes, [[Error]]: error }.
+                            return syntax_error(read, bytes);
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and
+                        //    ! DecodeBase64Chunk(chunk, false).
+                        decode_base64_chunk(&chunk[..chunk_length], false, &mut bytes).unwrap();
+                    }
+                    // 3. Else,
+                    LastChunkHandling::Strict => {
+                        // a. Assert: lastChunkHandling is "strict".
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]:
+                        //    bytes, [[Error]]: error }.
+                        return syntax_error(read, bytes);
+                    }
+                }
+            }
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes,
+            //     [[Error]]: none }.
+            return DecodeResult {
+                read: length,
+                bytes,
+                error: None,
+            };
+        }
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string[index];
+        // d. Set index to index + 1.
+        index += 1;
+        // e. If char is "=", then
+        if char == b'=' {
+            // i. If chunkLength < 2, then
+            if chunk_length < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+                //    [[Error]]: error }.
+                return syntax_error(read, bytes);
+            }
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+            // iii. If chunkLength = 2, then
+            if chunk_length == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]:
+                        //    bytes, [[Error]]: none }.
+                        return DecodeResult {
+                            read,
+                            bytes,
+                            error: None,
+                        };
+                    }
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]:
+                    //    bytes, [[Error]]: error }.
+                    return syntax_error(read, bytes);
+                }
+                // 2. Set char to the substring of string from index to
+                //    index + 1.
+                char = string[index];
+                // 3. If char is "=", then
+                if char == b'=' {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+                //    [[Error]]: error }.
+                return syntax_error(read, bytes);
+            }
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be
+            //    true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk,
+            //      throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            if let Err(error) =
+                decode_base64_chunk(&chunk[..chunk_length], throw_on_extra_bits, &mut bytes)
+            {
+                // 1. Let error be decodeResult.[[Value]].
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+                //    [[Error]]: error }.
+                return DecodeResult {
+                    read,
+                    bytes,
+                    error: Some(error),
+                };
+            }
+            // ix. Set bytes to the list-concatenation of bytes and
+            //     ! decodeResult.
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes,
+            //    [[Error]]: none }.
+            return DecodeResult {
+                read: length,
+                bytes,
+                error: None,
+            };
+        }
+        // f. If alphabet is "base64url", then
+        if alphabet == Alphabet::Base64Url {
+            // i. If char is either "+" or "/", then
+            if char == b'+' || char == b'/' {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+                //    [[Error]]: error }.
+                return syntax_error(read, bytes);
+            } else if char == b'-' {
+                // ii. Else if char is "-", then
+                // 1. Set char to "+".
+                char = b'+';
+            } else if char == b'_' {
+                // iii. Else if char is "_", then
+                // 1. Set char to "/".
+                char = b'/';
+            }
+        }
+        // g. If the sole code unit of char is not an element of the standard
+        //    base64 alphabet, then
+        let Some(sextet) = standard_base64_value(char) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+            //     [[Error]]: error }.
+            return syntax_error(read, bytes);
+        };
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - bytes.len();
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and
+        //    chunkLength = 3, then
+        if (remaining == 1 && chunk_length == 2) || (remaining == 2 && chunk_length == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+            //    [[Error]]: none }.
+            return DecodeResult {
+                read,
+                bytes,
+                error: None,
+            };
+        }
+        // j. Set chunk to the string-concatenation of chunk and char.
+        chunk[chunk_length] = sextet;
+        // k. Set chunkLength to the length of chunk.
+        chunk_length += 1;
+        // l. If chunkLength = 4, then
+        if chunk_length == 4 {
+            // i. Set bytes to the list-concatenation of bytes and
+            //    ! DecodeBase64Chunk(chunk).
+            decode_base64_chunk(&chunk, false, &mut bytes).unwrap();
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk_length = 0;
+            // iv. Set read to index.
+            read = index;
+            // v. If the length of bytes = maxLength, then
+            if bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+                //    [[Error]]: none }.
+                return DecodeResult {
+                    read,
+                    bytes,
+                    error: None,
+                };
+            }
+        }
+    }
+}
+
+/// ### [FromHex ( string \[ , maxLength \] )](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex)
+///
+/// The abstract operation FromHex takes argument string (a String) and
+/// optional argument maxLength (a non-negative integer) and returns a Record
+/// with fields \[\[Read]] (a non-negative integer), \[\[Bytes]] (a List of
+/// byte values), and \[\[Error]] (either none or a SyntaxError object).
+///
+/// The length of the string is given separately as its UTF-16 length.
+fn from_hex(
+    string: &[u8],
+    // 2. Let length be the length of string.
+    length: usize,
+    // 1. If maxLength is not present, let maxLength be 2**53 - 1.
+    max_length: usize,
+) -> DecodeResult {
+    // 3. Let bytes be « ».
+    let mut bytes = Vec::with_capacity((length / 2).min(max_length));
+    // 4. Let read be 0.
+    let mut read = 0;
+    let syntax_error = |read, bytes| DecodeResult {
+        read,
+        bytes,
+        error: Some("Invalid hex string"),
+    };
+    // 5. If length modulo 2 is not 0, then
+    if length % 2 != 0 {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+        //    [[Error]]: error }.
+        return syntax_error(read, bytes);
+    }
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while read < length && bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in
+        //    "0123456789abcdefABCDEF", then
+        let (Some(high), Some(low)) = (
+            string.get(read).and_then(|&c| (c as char).to_digit(16)),
+            string.get(read + 1).and_then(|&c| (c as char).to_digit(16)),
+        ) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes,
+            //     [[Error]]: error }.
+            return syntax_error(read, bytes);
+        };
+        // c. Set read to read + 2.
+        read += 2;
+        // d. Let byte be the integer value represented by hexits in base-16
+        //    notation, using the letters A-F and a-f for digits with values
+        //    10 through 15.
+        // e. Append byte to bytes.
+        bytes.push(((high << 4) | low) as u8);
+    }
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]:
+    //    none }.
+    DecodeResult {
+        read,
+        bytes,
+        error: None,
+    }
+}
+
+/// Encodes the bytes according to the base64 or base64url encoding specified
+/// in sections 4 and 5 of RFC 4648.
+fn encode_base64(bytes: &[u8], alphabet: Alphabet, omit_padding: bool) -> std::string::String {
+    let table = match alphabet {
+        Alphabet::Base64 => BASE64_ALPHABET,
+        Alphabet::Base64Url => BASE64URL_ALPHABET,
+    };
+    let mut out = std::string::String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let bits = (u32::from(chunk[0]) << 16)
+            | (u32::from(chunk.get(1).copied().unwrap_or(0)) << 8)
+            | u32::from(chunk.get(2).copied().unwrap_or(0));
+        // A chunk of n bytes is encoded as n + 1 characters.
+        for i in 0..=chunk.len() {
+            let sextet = (bits >> (18 - 6 * i)) & 0x3F;
+            out.push(table[sextet as usize] as char);
+        }
+        if !omit_padding {
+            for _ in chunk.len()..3 {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,21 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [ECMAScript Internationalization API](https://tc39.es/ecma402/)
+//!
+//! A minimal subset of ECMA-402 with bundled locale data for the root and
+//! `en` locales.
+
+pub(crate) mod abstract_operations;
+pub mod collator;
+pub mod collator_objects;
+pub mod date_time_format;
+pub mod date_time_format_objects;
+pub mod intl_object;
+pub(crate) mod locale;
+pub(crate) mod locale_data;
+pub mod number_format;
+pub mod number_format_objects;
+pub mod plural_rules;
+pub mod plural_rules_objects;
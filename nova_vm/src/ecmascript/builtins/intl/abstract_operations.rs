@@ -0,0 +1,788 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [9 Locale and Parameter Negotiation](https://tc39.es/ecma402/#locale-and-parameter-negotiation)
+//!
+//! The abstract operations shared by the Intl service constructors.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{
+                create_array_from_list, get, has_property, length_of_array_like,
+            },
+            type_conversion::{
+                PreferredType, string_to_number, to_boolean, to_number, to_object, to_primitive,
+                to_string,
+            },
+        },
+        builtins::{
+            Array,
+            intl::{
+                locale::{LanguageTag, lookup_supported_locales},
+                number_format_objects::numeric_formatting::{
+                    Decimal, DigitOptions, IntlMathematicalValue, Notation, RoundingMode,
+                    RoundingPriority, RoundingType, TrailingZeroDisplay,
+                },
+            },
+            ordinary::ordinary_object_create_with_intrinsics,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, BigInt, IntoValue, Number, Object, PropertyDescriptor,
+            PropertyKey, String, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+};
+
+/// ### [9.2.1 CanonicalizeLocaleList ( locales )](https://tc39.es/ecma402/#sec-canonicalizelocalelist)
+pub(crate) fn canonicalize_locale_list<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Vec<LanguageTag>> {
+    let locales = locales.bind(gc.nogc());
+    // 1. If locales is undefined, then
+    if locales.is_undefined() {
+        // a. Return a new empty List.
+        return Ok(Vec::new());
+    }
+    // 2. Let seen be a new empty List.
+    let mut seen: Vec<LanguageTag> = Vec::new();
+    // 3. If locales is a String or locales has an [[InitializedLocale]]
+    //    internal slot, then
+    if let Ok(locales) = String::try_from(locales) {
+        // a. Let O be CreateArrayFromList(« locales »).
+        // NOTE: The single element is handled directly.
+        let tag = locales.as_wtf8(agent).to_string_lossy().into_owned();
+        seen.push(canonicalize_language_tag(agent, &tag, gc.into_nogc())?);
+        return Ok(seen);
+    }
+    // 4. Else,
+    // a. Let O be ? ToObject(locales).
+    let o = to_object(agent, locales, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 5. Let len be ? LengthOfArrayLike(O).
+    let len = length_of_array_like(agent, o.get(agent), gc.reborrow()).unbind()?;
+    // 6. Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::Integer(k.try_into().unwrap());
+        // b. Let kPresent be ? HasProperty(O, Pk).
+        let k_present = has_property(agent, o.get(agent), pk, gc.reborrow()).unbind()?;
+        // c. If kPresent is true, then
+        if !k_present {
+            continue;
+        }
+        // i. Let kValue be ? Get(O, Pk).
+        let k_value = get(agent, o.get(agent), pk, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // ii. If kValue is not a String and kValue is not an Object, throw a
+        //     TypeError exception.
+        if !k_value.is_string() && !k_value.is_object() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Locale must be a string or an object",
+                gc.into_nogc(),
+            ));
+        }
+        // iii. If kValue is an Object and kValue has an
+        //      [[InitializedLocale]] internal slot, then
+        // 1. Let tag be kValue.[[Locale]].
+        // iv. Else,
+        // 1. Let tag be ? ToString(kValue).
+        let tag = to_string(agent, k_value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let tag = tag.as_wtf8(agent).to_string_lossy().into_owned();
+        // v. If IsStructurallyValidLanguageTag(tag) is false, throw a
+        //    RangeError exception.
+        // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
+        let canonicalized_tag = canonicalize_language_tag(agent, &tag, gc.nogc()).unbind()?;
+        // vii. If seen does not contain canonicalizedTag, append
+        //      canonicalizedTag to the end of seen.
+        if !seen.contains(&canonicalized_tag) {
+            seen.push(canonicalized_tag);
+        }
+        // d. Set k to k + 1.
+    }
+    // 8. Return seen.
+    Ok(seen)
+}
+
+/// Parse and canonicalize a language tag, throwing a RangeError if it is not
+/// structurally valid.
+fn canonicalize_language_tag<'a>(
+    agent: &mut Agent,
+    tag: &str,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, LanguageTag> {
+    LanguageTag::parse(tag)
+        .map(LanguageTag::canonicalize)
+        .ok_or_else(|| {
+            agent.throw_exception(
+                ExceptionType::RangeError,
+                format!("Incorrect locale information provided: {tag}"),
+                gc,
+            )
+        })
+}
+
+/// ### [9.2.13 SupportedLocales ( availableLocales, requestedLocales, options )](https://tc39.es/ecma402/#sec-supportedlocales)
+pub(crate) fn supported_locales<'gc>(
+    agent: &mut Agent,
+    requested_locales: &[LanguageTag],
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Array<'gc>> {
+    // 1. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    get_string_option(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.localeMatcher.into(),
+        &["lookup", "best fit"],
+        Some("best fit"),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 3. If matcher is "best fit", then
+    // a. Let supportedLocales be BestFitSupportedLocales(availableLocales,
+    //    requestedLocales).
+    // 4. Else,
+    // a. Let supportedLocales be LookupSupportedLocales(availableLocales,
+    //    requestedLocales).
+    let supported_locales = lookup_supported_locales(requested_locales);
+    // 5. Return CreateArrayFromList(supportedLocales).
+    let gc = gc.into_nogc();
+    let supported_locales = supported_locales
+        .into_iter()
+        .map(|locale| Value::from_string(agent, locale, gc))
+        .collect::<Vec<_>>();
+    Ok(create_array_from_list(agent, &supported_locales, gc))
+}
+
+/// ### [9.2.11 CoerceOptionsToObject ( options )](https://tc39.es/ecma402/#sec-coerceoptionstoobject)
+pub(crate) fn coerce_options_to_object<'a>(
+    agent: &mut Agent,
+    options: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(ordinary_object_create_with_intrinsics(
+            agent, None, None, gc,
+        ));
+    }
+    // 2. Return ? ToObject(options).
+    to_object(agent, options, gc)
+}
+
+/// ### [9.2.12 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type string and a non-empty list of values. A default of
+/// None means that the default is undefined.
+pub(crate) fn get_string_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    values: &'static [&'static str],
+    default: Option<&'static str>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<&'static str>> {
+    debug_assert!(!values.is_empty());
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // a. If default is required, throw a RangeError exception.
+        // b. Return default.
+        return Ok(default);
+    }
+    // 3. If type is boolean, then
+    // a. Set value to ToBoolean(value).
+    // 4. If type is string, then
+    // a. Set value to ? ToString(value).
+    let value = to_string(agent, value.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 5. If values is not empty and values does not contain value, throw a
+    //    RangeError exception.
+    let Some(value) = values
+        .iter()
+        .find(|v| value.as_wtf8(agent).as_str() == Some(**v))
+    else {
+        let value = value.as_wtf8(agent).to_string_lossy().into_owned();
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!("Value {value} out of range for option"),
+            gc.into_nogc(),
+        ));
+    };
+    // 6. Return value.
+    Ok(Some(value))
+}
+
+/// ### [9.2.12 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type string, an empty list of values and a default of
+/// undefined.
+pub(crate) fn get_free_string_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<std::string::String>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // b. Return default.
+        return Ok(None);
+    }
+    // 4. If type is string, then
+    // a. Set value to ? ToString(value).
+    let value = to_string(agent, value.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 6. Return value.
+    Ok(Some(value.as_wtf8(agent).to_string_lossy().into_owned()))
+}
+
+/// ### [9.2.12 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type boolean, an empty list of values and a default of
+/// undefined.
+pub(crate) fn get_boolean_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<bool>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // b. Return default.
+        return Ok(None);
+    }
+    // 3. If type is boolean, then
+    // a. Set value to ToBoolean(value).
+    // 6. Return value.
+    Ok(Some(to_boolean(agent, value)))
+}
+
+/// ### [9.2.15 DefaultNumberOption ( value, minimum, maximum, fallback )](https://tc39.es/ecma402/#sec-defaultnumberoption)
+pub(crate) fn default_number_option<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    minimum: u32,
+    maximum: u32,
+    fallback: Option<u32>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u32>> {
+    // 1. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(fallback);
+    }
+    // 2. Set value to ? ToNumber(value).
+    let value = to_number(agent, value, gc.reborrow())
+        .unbind()?
+        .to_real(agent);
+    // 3. If value is not finite or ℝ(value) < minimum or ℝ(value) > maximum,
+    //    throw a RangeError exception.
+    if !value.is_finite() || value < minimum as f64 || value > maximum as f64 {
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!("Value {value} out of range, must be between {minimum} and {maximum}"),
+            gc.into_nogc(),
+        ));
+    }
+    // 4. Return floor(ℝ(value)).
+    Ok(Some(value.floor() as u32))
+}
+
+/// ### [9.2.16 GetNumberOption ( options, property, minimum, maximum, fallback )](https://tc39.es/ecma402/#sec-getnumberoption)
+pub(crate) fn get_number_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    minimum: u32,
+    maximum: u32,
+    fallback: Option<u32>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u32>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. Return ? DefaultNumberOption(value, minimum, maximum, fallback).
+    default_number_option(agent, value.unbind(), minimum, maximum, fallback, gc)
+}
+
+/// ### [15.1.3 SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )](https://tc39.es/ecma402/#sec-setnfdigitoptions)
+pub(crate) fn set_number_format_digit_options<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mnfd_default: u8,
+    mut mxfd_default: u8,
+    notation: Notation,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DigitOptions> {
+    let options = options.scope(agent, gc.nogc());
+    let mut intl_obj = DigitOptions::default();
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits", 1, 21, 1).
+    let mnid = get_number_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minimumIntegerDigits.into(),
+        1,
+        21,
+        Some(1),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    let mnfd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minimumFractionDigits.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    let mxfd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.maximumFractionDigits.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+    let mnsd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minimumSignificantDigits.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+    let mxsd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.maximumSignificantDigits.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+    intl_obj.minimum_integer_digits = mnid as u8;
+    // 7. Let roundingIncrement be ? GetNumberOption(options,
+    //    "roundingIncrement", 1, 5000, 1).
+    let rounding_increment = get_number_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.roundingIncrement.into(),
+        1,
+        5000,
+        Some(1),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 8. If roundingIncrement is not in « 1, 2, 5, 10, 20, 25, 50, 100, 200,
+    //    250, 500, 1000, 2000, 2500, 5000 », throw a RangeError exception.
+    if ![
+        1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500, 5000,
+    ]
+    .contains(&rounding_increment)
+    {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Invalid roundingIncrement",
+            gc.into_nogc(),
+        ));
+    }
+    // 9. Let roundingMode be ? GetOption(options, "roundingMode", string,
+    //    « "ceil", "floor", "expand", "trunc", "halfCeil", "halfFloor",
+    //    "halfExpand", "halfTrunc", "halfEven" », "halfExpand").
+    let rounding_mode = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.roundingMode.into(),
+        RoundingMode::VALUES,
+        Some(RoundingMode::HalfExpand.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 10. Let roundingPriority be ? GetOption(options, "roundingPriority",
+    //     string, « "auto", "morePrecision", "lessPrecision" », "auto").
+    let rounding_priority = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.roundingPriority.into(),
+        RoundingPriority::VALUES,
+        Some(RoundingPriority::Auto.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    let rounding_priority = RoundingPriority::from_option(rounding_priority);
+    // 11. Let trailingZeroDisplay be ? GetOption(options,
+    //     "trailingZeroDisplay", string, « "auto", "stripIfInteger" », "auto").
+    let trailing_zero_display = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.trailingZeroDisplay.into(),
+        TrailingZeroDisplay::VALUES,
+        Some(TrailingZeroDisplay::Auto.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 12. NOTE: All fields required by SetNumberFormatDigitOptions have now
+    //     been read from options. The remainder of this AO interprets the
+    //     options and may throw exceptions.
+    // 13. If roundingIncrement is not 1, set mxfdDefault to mnfdDefault.
+    if rounding_increment != 1 {
+        mxfd_default = mnfd_default;
+    }
+    // 14. Set intlObj.[[RoundingIncrement]] to roundingIncrement.
+    intl_obj.rounding_increment = rounding_increment as u16;
+    // 15. Set intlObj.[[RoundingMode]] to roundingMode.
+    intl_obj.rounding_mode = RoundingMode::from_option(rounding_mode);
+    // 16. Set intlObj.[[TrailingZeroDisplay]] to trailingZeroDisplay.
+    intl_obj.trailing_zero_display = TrailingZeroDisplay::from_option(trailing_zero_display);
+    // 17. If mnsd is undefined and mxsd is undefined, let hasSd be false.
+    //     Otherwise, let hasSd be true.
+    let has_sd = !mnsd.get(agent).is_undefined() || !mxsd.get(agent).is_undefined();
+    // 18. If mnfd is undefined and mxfd is undefined, let hasFd be false.
+    //     Otherwise, let hasFd be true.
+    let has_fd = !mnfd.get(agent).is_undefined() || !mxfd.get(agent).is_undefined();
+    // 19. Let needSd be true.
+    let mut need_sd = true;
+    // 20. Let needFd be true.
+    let mut need_fd = true;
+    // 21. If roundingPriority is "auto", then
+    if rounding_priority == RoundingPriority::Auto {
+        // a. Set needSd to hasSd.
+        need_sd = has_sd;
+        // b. If needSd is true, or hasFd is false and notation is "compact",
+        //    then
+        if need_sd || (!has_fd && notation == Notation::Compact) {
+            // i. Set needFd to false.
+            need_fd = false;
+        }
+    }
+    // 22. If needSd is true, then
+    if need_sd {
+        // a. If hasSd is true, then
+        if has_sd {
+            // i. Set intlObj.[[MinimumSignificantDigits]] to ?
+            //    DefaultNumberOption(mnsd, 1, 21, 1).
+            let minimum_significant_digits =
+                default_number_option(agent, mnsd.get(agent), 1, 21, Some(1), gc.reborrow())
+                    .unbind()?
+                    .unwrap();
+            // ii. Set intlObj.[[MaximumSignificantDigits]] to ?
+            //     DefaultNumberOption(mxsd, intlObj.[[MinimumSignificantDigits]],
+            //     21, 21).
+            let maximum_significant_digits = default_number_option(
+                agent,
+                mxsd.get(agent),
+                minimum_significant_digits,
+                21,
+                Some(21),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .unwrap();
+            intl_obj.minimum_significant_digits = minimum_significant_digits as u8;
+            intl_obj.maximum_significant_digits = maximum_significant_digits as u8;
+        } else {
+            // b. Else,
+            // i. Set intlObj.[[MinimumSignificantDigits]] to 1.
+            intl_obj.minimum_significant_digits = 1;
+            // ii. Set intlObj.[[MaximumSignificantDigits]] to 21.
+            intl_obj.maximum_significant_digits = 21;
+        }
+    }
+    // 23. If needFd is true, then
+    if need_fd {
+        // a. If hasFd is true, then
+        if has_fd {
+            // i. Set mnfd to ? DefaultNumberOption(mnfd, 0, 100, undefined).
+            let mnfd = default_number_option(agent, mnfd.get(agent), 0, 100, None, gc.reborrow())
+                .unbind()?;
+            // ii. Set mxfd to ? DefaultNumberOption(mxfd, 0, 100, undefined).
+            let mxfd = default_number_option(agent, mxfd.get(agent), 0, 100, None, gc.reborrow())
+                .unbind()?;
+            let (mnfd, mxfd) = match (mnfd, mxfd) {
+                // iii. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+                (None, Some(mxfd)) => ((mnfd_default as u32).min(mxfd), mxfd),
+                // iv. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+                (Some(mnfd), None) => (mnfd, (mxfd_default as u32).max(mnfd)),
+                // v. Else if mnfd is greater than mxfd, throw a RangeError
+                //    exception.
+                (Some(mnfd), Some(mxfd)) if mnfd > mxfd => {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::RangeError,
+                        "minimumFractionDigits is greater than maximumFractionDigits",
+                        gc.into_nogc(),
+                    ));
+                }
+                (Some(mnfd), Some(mxfd)) => (mnfd, mxfd),
+                (None, None) => unreachable!(),
+            };
+            // vi. Set intlObj.[[MinimumFractionDigits]] to mnfd.
+            intl_obj.minimum_fraction_digits = mnfd as u8;
+            // vii. Set intlObj.[[MaximumFractionDigits]] to mxfd.
+            intl_obj.maximum_fraction_digits = mxfd as u8;
+        } else {
+            // b. Else,
+            // i. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+            intl_obj.minimum_fraction_digits = mnfd_default;
+            // ii. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+            intl_obj.maximum_fraction_digits = mxfd_default;
+        }
+    }
+    if !need_sd && !need_fd {
+        // 24. If needSd is false and needFd is false, then
+        // a. Set intlObj.[[MinimumFractionDigits]] to 0.
+        intl_obj.minimum_fraction_digits = 0;
+        // b. Set intlObj.[[MaximumFractionDigits]] to 0.
+        intl_obj.maximum_fraction_digits = 0;
+        // c. Set intlObj.[[MinimumSignificantDigits]] to 1.
+        intl_obj.minimum_significant_digits = 1;
+        // d. Set intlObj.[[MaximumSignificantDigits]] to 2.
+        intl_obj.maximum_significant_digits = 2;
+        // e. Set intlObj.[[RoundingType]] to more-precision.
+        intl_obj.rounding_type = RoundingType::MorePrecision;
+        // f. Set intlObj.[[ComputedRoundingPriority]] to "morePrecision".
+        intl_obj.computed_rounding_priority = RoundingPriority::MorePrecision;
+    } else {
+        match rounding_priority {
+            // 25. Else if roundingPriority is "auto", then
+            RoundingPriority::Auto => {
+                // a. If needSd is true, then
+                // i. Set intlObj.[[RoundingType]] to significant-digits.
+                // b. Else,
+                // i. Set intlObj.[[RoundingType]] to fraction-digits.
+                intl_obj.rounding_type = if need_sd {
+                    RoundingType::SignificantDigits
+                } else {
+                    RoundingType::FractionDigits
+                };
+                // c. Set intlObj.[[ComputedRoundingPriority]] to "auto".
+                intl_obj.computed_rounding_priority = RoundingPriority::Auto;
+            }
+            // 26. Else if roundingPriority is "morePrecision", then
+            RoundingPriority::MorePrecision => {
+                // a. Set intlObj.[[RoundingType]] to more-precision.
+                intl_obj.rounding_type = RoundingType::MorePrecision;
+                // b. Set intlObj.[[ComputedRoundingPriority]] to "morePrecision".
+                intl_obj.computed_rounding_priority = RoundingPriority::MorePrecision;
+            }
+            // 27. Else,
+            RoundingPriority::LessPrecision => {
+                // a. Assert: roundingPriority is "lessPrecision".
+                // b. Set intlObj.[[RoundingType]] to less-precision.
+                intl_obj.rounding_type = RoundingType::LessPrecision;
+                // c. Set intlObj.[[ComputedRoundingPriority]] to "lessPrecision".
+                intl_obj.computed_rounding_priority = RoundingPriority::LessPrecision;
+            }
+        }
+    }
+    // 28. If roundingIncrement is not 1, then
+    if rounding_increment != 1 {
+        // a. If intlObj.[[RoundingType]] is not fraction-digits, throw a
+        //    TypeError exception.
+        if intl_obj.rounding_type != RoundingType::FractionDigits {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "roundingIncrement requires fraction digit rounding",
+                gc.into_nogc(),
+            ));
+        }
+        // b. If intlObj.[[MaximumFractionDigits]] is not equal to
+        //    intlObj.[[MinimumFractionDigits]], throw a RangeError exception.
+        if intl_obj.maximum_fraction_digits != intl_obj.minimum_fraction_digits {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "roundingIncrement requires equal minimum and maximum fraction digits",
+                gc.into_nogc(),
+            ));
+        }
+    }
+    Ok(intl_obj)
+}
+
+/// ### [15.5.16 ToIntlMathematicalValue ( value )](https://tc39.es/ecma402/#sec-tointlmathematicalvalue)
+pub(crate) fn to_intl_mathematical_value<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IntlMathematicalValue> {
+    let value = value.bind(gc.nogc());
+    // 1. If value is a Number, return ℝ(value).
+    if let Ok(value) = Number::try_from(value) {
+        return Ok(number_to_intl_mathematical_value(value.to_real(agent)));
+    }
+    // 2. Let primValue be ? ToPrimitive(value, number).
+    let prim_value = to_primitive(
+        agent,
+        value.unbind(),
+        Some(PreferredType::Number),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. If Type(primValue) is BigInt, return ℝ(primValue).
+    if let Ok(prim_value) = BigInt::try_from(prim_value) {
+        let string = BigInt::to_string_radix_10(agent, prim_value, gc.nogc());
+        let decimal = Decimal::parse(string.as_wtf8(agent).as_str().unwrap()).unwrap();
+        return Ok(IntlMathematicalValue::Finite(decimal));
+    }
+    // 4. If Type(primValue) is String, then
+    // a. Let str be primValue.
+    let Ok(str) = String::try_from(prim_value) else {
+        // 5. Else,
+        // a. Let x be ? ToNumber(primValue).
+        // b. If x is -0𝔽, return negative-zero.
+        // c. Let str be Number::toString(x, 10).
+        let x = to_number(agent, prim_value.unbind(), gc).unbind()?;
+        return Ok(number_to_intl_mathematical_value(x.to_real(agent)));
+    };
+    // 6. Let text be StringToCodePoints(str).
+    // 7. Let literal be ParseText(text, StringIntlMathematicalValue).
+    // 8. If literal is a List of errors, return not-a-number.
+    // 9. Let intlMV be the StringIntlMV of literal.
+    // 10. If intlMV is a mathematical value, then
+    // a. Let rounded be RoundMVResult(abs(intlMV)).
+    // b. If rounded is +∞𝔽 and intlMV < 0, return negative-infinity.
+    // c. If rounded is +∞𝔽, return positive-infinity.
+    // d. If rounded is +0𝔽 and intlMV < 0, return negative-zero.
+    // e. If rounded is +0𝔽, return 0.
+    // 11. Return intlMV.
+    let text = str.as_wtf8(agent).to_string_lossy();
+    let text = text.trim_matches(|c: char| c.is_whitespace() || c == '\u{FEFF}');
+    let intl_mv = match text {
+        "" => IntlMathematicalValue::Finite(Decimal::zero()),
+        "Infinity" | "+Infinity" => IntlMathematicalValue::Infinity { negative: false },
+        "-Infinity" => IntlMathematicalValue::Infinity { negative: true },
+        _ if text.len() > 2
+            && text.as_bytes()[0] == b'0'
+            && matches!(text.as_bytes()[1], b'x' | b'X' | b'o' | b'O' | b'b' | b'B') =>
+        {
+            // NonDecimalIntegerLiteral: these are integers, which the Number
+            // conversion handles.
+            let x = string_to_number(agent, str, gc.nogc());
+            number_to_intl_mathematical_value(x.to_real(agent))
+        }
+        _ => match Decimal::parse(text) {
+            Some(decimal) => IntlMathematicalValue::Finite(decimal),
+            None => IntlMathematicalValue::NaN,
+        },
+    };
+    Ok(intl_mv)
+}
+
+/// Convert a Number value into an Intl mathematical value.
+pub(crate) fn number_to_intl_mathematical_value(x: f64) -> IntlMathematicalValue {
+    if x.is_nan() {
+        IntlMathematicalValue::NaN
+    } else if x.is_infinite() {
+        IntlMathematicalValue::Infinity {
+            negative: x.is_sign_negative(),
+        }
+    } else {
+        // The shortest round-trip representation gives the exact decimal
+        // value that Number::toString would show.
+        IntlMathematicalValue::Finite(Decimal::parse(&format!("{x:e}")).unwrap())
+    }
+}
+
+/// Create an Array of `{ type, value }` part objects, as used by the
+/// formatToParts methods.
+pub(crate) fn create_parts_array<'a, 'p>(
+    agent: &mut Agent,
+    parts: impl IntoIterator<Item = (&'static str, &'p str)>,
+    gc: NoGcScope<'a, '_>,
+) -> Array<'a> {
+    let parts = parts
+        .into_iter()
+        .map(|(r#type, value)| {
+            let r#type = Value::from_static_str(agent, r#type, gc);
+            let value = Value::from_str(agent, value, gc);
+            let o = ordinary_object_create_with_intrinsics(
+                agent,
+                Some(ProtoIntrinsics::Object),
+                None,
+                gc,
+            );
+            o.property_storage().set(
+                agent,
+                BUILTIN_STRING_MEMORY.r#type.to_property_key(),
+                PropertyDescriptor::new_data_descriptor(r#type),
+            );
+            o.property_storage().set(
+                agent,
+                BUILTIN_STRING_MEMORY.value.to_property_key(),
+                PropertyDescriptor::new_data_descriptor(value),
+            );
+            o.into_value()
+        })
+        .collect::<Vec<_>>();
+    create_array_from_list(agent, &parts, gc)
+}
+
+/// Create the object returned by the resolvedOptions methods from a list of
+/// properties. Properties with a value of undefined are skipped.
+pub(crate) fn create_resolved_options_object<'a>(
+    agent: &mut Agent,
+    properties: &[(String<'static>, Value<'a>)],
+    gc: NoGcScope<'a, '_>,
+) -> Object<'a> {
+    // 1. Let options be OrdinaryObjectCreate(%Object.prototype%).
+    let options =
+        ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None, gc);
+    // 2. For each row of the table, except the header row, in table order, do
+    for (p, v) in properties {
+        // a. Let p be the Property value of the current row.
+        // b. Let v be the value of the internal slot of the current row.
+        // c. If v is not undefined, then
+        if !v.is_undefined() {
+            // i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+            options.property_storage().set(
+                agent,
+                p.to_property_key(),
+                PropertyDescriptor::new_data_descriptor(*v),
+            );
+        }
+    }
+    options
+}
@@ -0,0 +1,202 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::CollatorIndex,
+    },
+};
+
+use self::data::CollatorHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Collator<'a>(pub(crate) CollatorIndex<'a>);
+
+impl Collator<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, Collator<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(CollatorIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for Collator<'_> {
+    type Of<'a> = Collator<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for Collator<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Collator<'a>> for Value<'a> {
+    fn from(value: Collator<'a>) -> Self {
+        Value::Collator(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for Collator<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Collator<'a>> for Object<'a> {
+    fn from(value: Collator) -> Self {
+        Object::Collator(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for Collator<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::Collator(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for Collator<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::Collator(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for Collator<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Collator;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for Collator<'a> {}
+
+impl Index<Collator<'_>> for Agent {
+    type Output = CollatorHeapData<'static>;
+
+    fn index(&self, index: Collator) -> &Self::Output {
+        &self.heap.collators[index]
+    }
+}
+
+impl IndexMut<Collator<'_>> for Agent {
+    fn index_mut(&mut self, index: Collator) -> &mut Self::Output {
+        &mut self.heap.collators[index]
+    }
+}
+
+impl Index<Collator<'_>> for Vec<Option<CollatorHeapData<'static>>> {
+    type Output = CollatorHeapData<'static>;
+
+    fn index(&self, index: Collator) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("Collator out of bounds")
+            .as_ref()
+            .expect("Collator slot empty")
+    }
+}
+
+impl IndexMut<Collator<'_>> for Vec<Option<CollatorHeapData<'static>>> {
+    fn index_mut(&mut self, index: Collator) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("Collator out of bounds")
+            .as_mut()
+            .expect("Collator slot empty")
+    }
+}
+
+impl Rootable for Collator<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::Collator(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::Collator(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for Collator<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.collators.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.collators.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<CollatorHeapData<'a>, Collator<'a>> for Heap {
+    fn create(&mut self, data: CollatorHeapData<'a>) -> Collator<'a> {
+        self.collators.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<CollatorHeapData<'static>>>();
+        }
+        Collator(CollatorIndex::last(&self.collators))
+    }
+}
@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            bound_function::BoundFunction,
+            intl::{
+                collator_objects::collation::{CollationOptions, CollatorUsage},
+                locale::DEFAULT_LOCALE,
+            },
+        },
+        types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [10.4 Properties of Intl.Collator Instances](https://tc39.es/ecma402/#sec-properties-of-intl-collator-instances)
+#[derive(Debug, Clone)]
+pub struct CollatorHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Locale]]
+    pub(crate) locale: Box<str>,
+    /// \[\[Usage]]
+    pub(crate) usage: CollatorUsage,
+    /// \[\[Sensitivity]], \[\[IgnorePunctuation]], \[\[Numeric]] and
+    /// \[\[CaseFirst]]
+    pub(crate) options: CollationOptions,
+    /// \[\[BoundCompare]]
+    pub(crate) bound_compare: Option<BoundFunction<'a>>,
+}
+
+impl Default for CollatorHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            locale: DEFAULT_LOCALE.into(),
+            usage: CollatorUsage::Sort,
+            options: CollationOptions::default(),
+            bound_compare: None,
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for CollatorHeapData<'_> {
+    type Of<'a> = CollatorHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for CollatorHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            locale: _,
+            usage: _,
+            options: _,
+            bound_compare,
+        } = self;
+        object_index.mark_values(queues);
+        bound_compare.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            locale: _,
+            usage: _,
+            options: _,
+            bound_compare,
+        } = self;
+        object_index.sweep_values(compactions);
+        bound_compare.sweep_values(compactions);
+    }
+}
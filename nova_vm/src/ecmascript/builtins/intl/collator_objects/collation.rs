@@ -0,0 +1,184 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [10.3.3.1 CompareStrings ( collator, x, y )](https://tc39.es/ecma402/#sec-collator-comparestrings)
+//!
+//! A small multi-level collation for the root and `en` locales. Strings are
+//! compared by their canonical decomposition: the primary level compares base
+//! characters, the secondary level compares accents and the tertiary level
+//! compares case. Whitespace and punctuation sort before digits, which sort
+//! before letters.
+
+use core::cmp::Ordering;
+
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+use crate::ecmascript::builtins::intl::number_format_objects::numeric_formatting::option_enum;
+
+option_enum!(
+    /// \[\[Usage]]
+    CollatorUsage {
+        Sort => "sort",
+        Search => "search",
+    }
+);
+
+option_enum!(
+    /// \[\[Sensitivity]]
+    Sensitivity {
+        Base => "base",
+        Accent => "accent",
+        Case => "case",
+        Variant => "variant",
+    }
+);
+
+option_enum!(
+    /// \[\[CaseFirst]]
+    CaseFirst {
+        Upper => "upper",
+        Lower => "lower",
+        False => "false",
+    }
+);
+
+/// The resolved options of an Intl.Collator object that are used in
+/// comparisons.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CollationOptions {
+    pub(crate) sensitivity: Sensitivity,
+    pub(crate) case_first: CaseFirst,
+    pub(crate) numeric: bool,
+    pub(crate) ignore_punctuation: bool,
+}
+
+impl Default for CollationOptions {
+    fn default() -> Self {
+        Self {
+            sensitivity: Sensitivity::Variant,
+            case_first: CaseFirst::False,
+            numeric: false,
+            ignore_punctuation: false,
+        }
+    }
+}
+
+/// The primary weight of a collation element. The variant order is the
+/// order of the script groups.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Primary {
+    /// Whitespace, punctuation and symbols.
+    Punctuation(char),
+    /// A digit or, with numeric collation, a run of digits. The number is
+    /// compared by its count of significant digits and then its digits.
+    Number(usize, Vec<u8>),
+    /// A letter, case folded.
+    Letter(char),
+    /// Any other character.
+    Other(char),
+}
+
+#[derive(Debug)]
+struct CollationElement {
+    primary: Primary,
+    /// Combining marks applied to the character.
+    secondary: Vec<char>,
+    /// True if the character is upper case.
+    upper_case: bool,
+}
+
+fn collation_elements(string: &str, options: &CollationOptions) -> Vec<CollationElement> {
+    let mut elements: Vec<CollationElement> = Vec::new();
+    let mut chars = string.nfd().peekable();
+    while let Some(c) = chars.next() {
+        if is_combining_mark(c) {
+            if let Some(previous) = elements.last_mut() {
+                previous.secondary.push(c);
+                continue;
+            }
+        }
+        let primary = if c.is_ascii_digit() {
+            let mut digits = vec![c as u8 - b'0'];
+            if options.numeric {
+                while let Some(next) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(next as u8 - b'0');
+                }
+                let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+                digits.drain(..leading_zeros);
+            }
+            Primary::Number(digits.len(), digits)
+        } else if c.is_alphabetic() {
+            Primary::Letter(c.to_lowercase().next().unwrap_or(c))
+        } else if c.is_whitespace() || c.is_ascii_punctuation() || is_general_punctuation(c) {
+            if options.ignore_punctuation {
+                continue;
+            }
+            Primary::Punctuation(c)
+        } else if c.is_control() {
+            // Control characters are completely ignorable.
+            continue;
+        } else {
+            Primary::Other(c)
+        };
+        elements.push(CollationElement {
+            primary,
+            secondary: Vec::new(),
+            upper_case: c.is_uppercase(),
+        });
+    }
+    elements
+}
+
+/// Returns true for the punctuation characters outside of ASCII that the
+/// root collation orders with the ASCII punctuation.
+fn is_general_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '\u{a0}'..='\u{bf}' | '\u{d7}' | '\u{f7}' | '\u{2000}'..='\u{206f}' | '\u{3000}'..='\u{303f}'
+    )
+}
+
+/// Compare two strings according to the collation options.
+pub(crate) fn compare_strings(options: &CollationOptions, x: &str, y: &str) -> Ordering {
+    let x_elements = collation_elements(x, options);
+    let y_elements = collation_elements(y, options);
+    // Primary level: base characters.
+    let primary = x_elements
+        .iter()
+        .map(|e| &e.primary)
+        .cmp(y_elements.iter().map(|e| &e.primary));
+    if primary != Ordering::Equal {
+        return primary;
+    }
+    // Secondary level: accents.
+    if matches!(
+        options.sensitivity,
+        Sensitivity::Accent | Sensitivity::Variant
+    ) {
+        let secondary = x_elements
+            .iter()
+            .map(|e| &e.secondary)
+            .cmp(y_elements.iter().map(|e| &e.secondary));
+        if secondary != Ordering::Equal {
+            return secondary;
+        }
+    }
+    // Tertiary level: case.
+    if matches!(
+        options.sensitivity,
+        Sensitivity::Case | Sensitivity::Variant
+    ) {
+        let tertiary = x_elements
+            .iter()
+            .map(|e| e.upper_case)
+            .cmp(y_elements.iter().map(|e| e.upper_case));
+        if tertiary != Ordering::Equal {
+            return match options.case_first {
+                CaseFirst::Upper => tertiary.reverse(),
+                CaseFirst::Lower | CaseFirst::False => tertiary,
+            };
+        }
+    }
+    Ordering::Equal
+}
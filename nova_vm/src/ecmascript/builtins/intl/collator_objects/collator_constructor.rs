@@ -0,0 +1,285 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            intl::{
+                abstract_operations::{
+                    canonicalize_locale_list, coerce_options_to_object, get_boolean_option,
+                    get_free_string_option, get_string_option, supported_locales,
+                },
+                collator::Collator,
+                collator_objects::collation::{
+                    CaseFirst, CollationOptions, CollatorUsage, Sensitivity,
+                },
+                locale::{ExtensionKeyData, is_unicode_locale_type, resolve_locale},
+            },
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct CollatorConstructor;
+impl Builtin for CollatorConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Collator;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for CollatorConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Collator;
+}
+
+struct CollatorSupportedLocalesOf;
+impl Builtin for CollatorSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(CollatorConstructor::supported_locales_of);
+}
+
+/// ### [10.2.3 Internal slots](https://tc39.es/ecma402/#sec-intl-collator-internal-slots)
+///
+/// The \[\[RelevantExtensionKeys]] of %Intl.Collator% with the sort locale
+/// data of the available locales: « "co", "kf", "kn" ». Only the default
+/// collation is supported.
+const RELEVANT_EXTENSION_KEYS: [ExtensionKeyData; 3] = [
+    ExtensionKeyData {
+        key: "co",
+        values: &[],
+        default_is_null: true,
+    },
+    ExtensionKeyData {
+        key: "kf",
+        values: &["false", "lower", "upper"],
+        default_is_null: false,
+    },
+    ExtensionKeyData {
+        key: "kn",
+        values: &["false", "true"],
+        default_is_null: false,
+    },
+];
+
+impl CollatorConstructor {
+    /// ### [10.1.1 Intl.Collator ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.collator)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).scope(agent, gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object, else let newTarget be NewTarget.
+        let new_target = new_target.map_or_else(
+            || agent.active_function_object(gc.nogc()),
+            |new_target| Function::try_from(new_target).unwrap(),
+        );
+        // 2. Let internalSlotsList be « [[InitializedCollator]], [[Locale]],
+        //    [[Usage]], [[Collation]], [[Numeric]], [[CaseFirst]],
+        //    [[Sensitivity]], [[IgnorePunctuation]], [[BoundCompare]] ».
+        // 3. Let collator be ? OrdinaryCreateFromConstructor(newTarget,
+        //    "%Intl.Collator.prototype%", internalSlotsList).
+        let collator = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::Collator,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let collator = Collator::try_from(collator)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 4. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.get(agent), gc.reborrow()).unbind()?;
+        // 5. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 6. Let usage be ? GetOption(options, "usage", string, « "sort",
+        //    "search" », "sort").
+        let usage = get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.usage.into(),
+            CollatorUsage::VALUES,
+            Some(CollatorUsage::Sort.as_str()),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap();
+        // 7. Set collator.[[Usage]] to usage.
+        let usage = CollatorUsage::from_option(usage);
+        // 8. If usage is "sort", then
+        // a. Let localeData be %Intl.Collator%.[[SortLocaleData]].
+        // 9. Else,
+        // a. Let localeData be %Intl.Collator%.[[SearchLocaleData]].
+        // 10. Let opt be a new Record.
+        // 11. Let matcher be ? GetOption(options, "localeMatcher", string,
+        //     « "lookup", "best fit" », "best fit").
+        // 12. Set opt.[[localeMatcher]] to matcher.
+        get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.localeMatcher.into(),
+            &["lookup", "best fit"],
+            Some("best fit"),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 13. Let collation be ? GetOption(options, "collation", string,
+        //     empty, undefined).
+        let collation = get_free_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.collation.into(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 14. If collation is not undefined, then
+        if let Some(collation) = &collation {
+            // a. If collation cannot be matched by the type Unicode locale
+            //    nonterminal, throw a RangeError exception.
+            if !is_unicode_locale_type(collation) {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::RangeError,
+                    "Invalid collation",
+                    gc.into_nogc(),
+                ));
+            }
+        }
+        // 15. Set opt.[[co]] to collation.
+        // 16. Let numeric be ? GetOption(options, "numeric", boolean, empty,
+        //     undefined).
+        let numeric = get_boolean_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.numeric.into(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 17. If numeric is not undefined, then
+        // a. Set numeric to ! ToString(numeric).
+        let numeric = numeric.map(|numeric| numeric.to_string());
+        // 18. Set opt.[[kn]] to numeric.
+        // 19. Let caseFirst be ? GetOption(options, "caseFirst", string,
+        //     « "upper", "lower", "false" », undefined).
+        let case_first = get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.caseFirst.into(),
+            CaseFirst::VALUES,
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 20. Set opt.[[kf]] to caseFirst.
+        // 21. Let relevantExtensionKeys be %Intl.Collator%.[[RelevantExtensionKeys]].
+        // 22. Let r be ResolveLocale(%Intl.Collator%.[[AvailableLocales]],
+        //     requestedLocales, opt, relevantExtensionKeys, localeData).
+        let r = resolve_locale(
+            &requested_locales,
+            &[collation, case_first.map(str::to_string), numeric],
+            &RELEVANT_EXTENSION_KEYS,
+        );
+        // 23. Set collator.[[Locale]] to r.[[Locale]].
+        // 24. Let collation be r.[[co]].
+        // 25. If collation is null, set collation to "default".
+        // 26. Set collator.[[Collation]] to collation.
+        // 27. If relevantExtensionKeys contains "kn", then
+        // a. Set collator.[[Numeric]] to SameValue(r.[[kn]], "true").
+        let numeric = r.values[2].as_deref() == Some("true");
+        // 28. If relevantExtensionKeys contains "kf", then
+        // a. Set collator.[[CaseFirst]] to r.[[kf]].
+        let case_first = CaseFirst::from_option(r.values[1].as_deref().unwrap_or("false"));
+        // 29. Let resolvedLocaleData be r.[[LocaleData]].
+        // 30. If usage is "sort", let defaultSensitivity be "variant".
+        //     Otherwise, let defaultSensitivity be
+        //     resolvedLocaleData.[[sensitivity]].
+        // 31. Set collator.[[Sensitivity]] to ? GetOption(options,
+        //     "sensitivity", string, « "base", "accent", "case", "variant" »,
+        //     defaultSensitivity).
+        let sensitivity = get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.sensitivity.into(),
+            Sensitivity::VALUES,
+            Some(Sensitivity::Variant.as_str()),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap();
+        let sensitivity = Sensitivity::from_option(sensitivity);
+        // 32. Let defaultIgnorePunctuation be resolvedLocaleData.[[ignorePunctuation]].
+        // 33. Set collator.[[IgnorePunctuation]] to ? GetOption(options,
+        //     "ignorePunctuation", boolean, empty, defaultIgnorePunctuation).
+        let ignore_punctuation = get_boolean_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.ignorePunctuation.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap_or(false);
+        let gc = gc.into_nogc();
+        let collator = collator.get(agent).bind(gc);
+        let data = &mut agent[collator];
+        data.locale = r.locale.into_boxed_str();
+        data.usage = usage;
+        data.options = CollationOptions {
+            sensitivity,
+            case_first,
+            numeric,
+            ignore_punctuation,
+        };
+        // 34. Return collator.
+        Ok(collator.into_value())
+    }
+
+    /// ### [10.2.2 Intl.Collator.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.collator.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let availableLocales be %Intl.Collator%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(agent, &requested_locales, options.get(agent), gc)
+            .map(|locales| locales.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let collator_prototype = intrinsics.collator_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<CollatorConstructor>(agent, realm)
+            .with_property_capacity(2)
+            .with_prototype_property(collator_prototype.into_object())
+            .with_builtin_function_property::<CollatorSupportedLocalesOf>()
+            .build();
+    }
+}
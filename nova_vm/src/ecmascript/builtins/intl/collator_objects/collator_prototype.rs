@@ -0,0 +1,218 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::cmp::Ordering;
+
+use crate::{
+    ecmascript::{
+        abstract_operations::type_conversion::to_string,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinFunctionArgs, BuiltinGetter,
+            bound_function::bound_function_create,
+            create_builtin_function,
+            intl::{
+                abstract_operations::create_resolved_options_object, collator::Collator,
+                collator_objects::collation::compare_strings,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoFunction, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct CollatorPrototype;
+
+struct CollatorPrototypeGetCompare;
+impl Builtin for CollatorPrototypeGetCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_compare;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.compare.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(CollatorPrototype::get_compare);
+}
+impl BuiltinGetter for CollatorPrototypeGetCompare {}
+struct CollatorPrototypeResolvedOptions;
+impl Builtin for CollatorPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(CollatorPrototype::resolved_options);
+}
+
+impl CollatorPrototype {
+    /// ### [10.3.3 get Intl.Collator.prototype.compare](https://tc39.es/ecma402/#sec-intl.collator.prototype.compare)
+    fn get_compare<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = require_collator(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If collator.[[BoundCompare]] is undefined, then
+        if let Some(bound_compare) = agent[collator].bound_compare {
+            // 4. Return collator.[[BoundCompare]].
+            return Ok(bound_compare.into_value().bind(gc.into_nogc()));
+        }
+        // a. Let F be a new built-in function object as defined in 10.3.3.1.
+        let f = create_builtin_function(
+            agent,
+            Behaviour::Regular(Self::collator_compare_function),
+            BuiltinFunctionArgs::new(2, ""),
+            gc.nogc(),
+        );
+        // b. Set F.[[Collator]] to collator.
+        let scoped_collator = collator.scope(agent, gc.nogc());
+        let f = bound_function_create(
+            agent,
+            f.into_function().unbind(),
+            collator.into_value().unbind(),
+            &[],
+            gc,
+        )?;
+        agent[f].length = 2;
+        // c. Set collator.[[BoundCompare]] to F.
+        let collator = scoped_collator.get(agent);
+        agent[collator].bound_compare = Some(f.unbind());
+        // 4. Return collator.[[BoundCompare]].
+        Ok(f.into_value())
+    }
+
+    /// ### [10.3.3.1 Collator Compare Functions](https://tc39.es/ecma402/#sec-collator-compare-functions)
+    ///
+    /// The collator is the this value of the bound compare function.
+    fn collator_compare_function<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let collator be F.[[Collator]].
+        // 2. Assert: collator is an Object and collator has an
+        //    [[InitializedCollator]] internal slot.
+        let collator = Collator::try_from(this_value)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 3. If x is not provided, let x be undefined.
+        // 4. If y is not provided, let y be undefined.
+        let y = arguments.get(1).scope(agent, gc.nogc());
+        // 5. Let X be ? ToString(x).
+        let x = to_string(agent, arguments.get(0), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 6. Let Y be ? ToString(y).
+        let y = to_string(agent, y.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 7. Return CompareStrings(collator, X, Y).
+        let result = compare_strings(
+            &agent[collator.get(agent)].options,
+            &x.get(agent).as_wtf8(agent).to_string_lossy(),
+            &y.as_wtf8(agent).to_string_lossy(),
+        );
+        Ok(Value::from(match result {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }))
+    }
+
+    /// ### [10.3.4 Intl.Collator.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.collator.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = require_collator(agent, this_value, gc)?;
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 4, except the header row, in table order, do
+        let data = &agent[collator];
+        let locale = data.locale.to_string();
+        let usage = data.usage.as_str();
+        let options = data.options;
+        let properties = [
+            (
+                BUILTIN_STRING_MEMORY.locale,
+                Value::from_string(agent, locale, gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.usage,
+                Value::from_static_str(agent, usage, gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.sensitivity,
+                Value::from_static_str(agent, options.sensitivity.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.ignorePunctuation,
+                options.ignore_punctuation.into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.collation,
+                BUILTIN_STRING_MEMORY.default.into_value(),
+            ),
+            (BUILTIN_STRING_MEMORY.numeric, options.numeric.into()),
+            (
+                BUILTIN_STRING_MEMORY.caseFirst,
+                Value::from_static_str(agent, options.case_first.as_str(), gc),
+            ),
+        ];
+        // 5. Return options.
+        Ok(create_resolved_options_object(agent, &properties, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.collator_prototype();
+        let collator_constructor = intrinsics.collator();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            .with_builtin_function_getter_property::<CollatorPrototypeGetCompare>()
+            .with_constructor_property(collator_constructor)
+            .with_builtin_function_property::<CollatorPrototypeResolvedOptions>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_Collator.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_collator<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Collator<'a>> {
+    Collator::try_from(value).map(|c| c.bind(gc)).map_err(|_| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "this is not an Intl.Collator object",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::DateTimeFormatIndex,
+    },
+};
+
+use self::data::DateTimeFormatHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct DateTimeFormat<'a>(pub(crate) DateTimeFormatIndex<'a>);
+
+impl DateTimeFormat<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, DateTimeFormat<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(DateTimeFormatIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DateTimeFormat<'_> {
+    type Of<'a> = DateTimeFormat<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for DateTimeFormat<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<DateTimeFormat<'a>> for Value<'a> {
+    fn from(value: DateTimeFormat<'a>) -> Self {
+        Value::DateTimeFormat(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for DateTimeFormat<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<DateTimeFormat<'a>> for Object<'a> {
+    fn from(value: DateTimeFormat) -> Self {
+        Object::DateTimeFormat(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for DateTimeFormat<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::DateTimeFormat(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for DateTimeFormat<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::DateTimeFormat(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for DateTimeFormat<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::DateTimeFormat;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for DateTimeFormat<'a> {}
+
+impl Index<DateTimeFormat<'_>> for Agent {
+    type Output = DateTimeFormatHeapData<'static>;
+
+    fn index(&self, index: DateTimeFormat) -> &Self::Output {
+        &self.heap.date_time_formats[index]
+    }
+}
+
+impl IndexMut<DateTimeFormat<'_>> for Agent {
+    fn index_mut(&mut self, index: DateTimeFormat) -> &mut Self::Output {
+        &mut self.heap.date_time_formats[index]
+    }
+}
+
+impl Index<DateTimeFormat<'_>> for Vec<Option<DateTimeFormatHeapData<'static>>> {
+    type Output = DateTimeFormatHeapData<'static>;
+
+    fn index(&self, index: DateTimeFormat) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("DateTimeFormat out of bounds")
+            .as_ref()
+            .expect("DateTimeFormat slot empty")
+    }
+}
+
+impl IndexMut<DateTimeFormat<'_>> for Vec<Option<DateTimeFormatHeapData<'static>>> {
+    fn index_mut(&mut self, index: DateTimeFormat) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("DateTimeFormat out of bounds")
+            .as_mut()
+            .expect("DateTimeFormat slot empty")
+    }
+}
+
+impl Rootable for DateTimeFormat<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::DateTimeFormat(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::DateTimeFormat(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for DateTimeFormat<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.date_time_formats.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.date_time_formats.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<DateTimeFormatHeapData<'a>, DateTimeFormat<'a>> for Heap {
+    fn create(&mut self, data: DateTimeFormatHeapData<'a>) -> DateTimeFormat<'a> {
+        self.date_time_formats.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<DateTimeFormatHeapData<'static>>>();
+        }
+        DateTimeFormat(DateTimeFormatIndex::last(&self.date_time_formats))
+    }
+}
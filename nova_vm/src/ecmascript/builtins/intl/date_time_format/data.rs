@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            bound_function::BoundFunction,
+            intl::{
+                date_time_format_objects::date_time_formatting::{
+                    DateTimeFields, DateTimeStyle, HourCycle,
+                },
+                locale::DEFAULT_LOCALE,
+            },
+            numbers_and_dates::date_objects::time_zone::TimeZoneRules,
+        },
+        types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [11.4 Properties of Intl.DateTimeFormat Instances](https://tc39.es/ecma402/#sec-properties-of-intl-datetimeformat-instances)
+#[derive(Debug)]
+pub struct DateTimeFormatHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Locale]]
+    pub(crate) locale: Box<str>,
+    /// \[\[TimeZone]]
+    pub(crate) time_zone: Box<str>,
+    /// The rules of a named time zone other than the system time zone.
+    pub(crate) time_zone_rules: Option<TimeZoneRules>,
+    /// \[\[HourCycle]]
+    pub(crate) hour_cycle: Option<HourCycle>,
+    /// \[\[DateTimeFormat]]: the fields of the selected pattern.
+    pub(crate) fields: DateTimeFields,
+    /// \[\[DateStyle]]
+    pub(crate) date_style: Option<DateTimeStyle>,
+    /// \[\[TimeStyle]]
+    pub(crate) time_style: Option<DateTimeStyle>,
+    /// \[\[BoundFormat]]
+    pub(crate) bound_format: Option<BoundFunction<'a>>,
+}
+
+impl Default for DateTimeFormatHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            locale: DEFAULT_LOCALE.into(),
+            time_zone: "UTC".into(),
+            time_zone_rules: None,
+            hour_cycle: None,
+            fields: DateTimeFields::default(),
+            date_style: None,
+            time_style: None,
+            bound_format: None,
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DateTimeFormatHeapData<'_> {
+    type Of<'a> = DateTimeFormatHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for DateTimeFormatHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            locale: _,
+            time_zone: _,
+            time_zone_rules: _,
+            hour_cycle: _,
+            fields: _,
+            date_style: _,
+            time_style: _,
+            bound_format,
+        } = self;
+        object_index.mark_values(queues);
+        bound_format.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            locale: _,
+            time_zone: _,
+            time_zone_rules: _,
+            hour_cycle: _,
+            fields: _,
+            date_style: _,
+            time_style: _,
+            bound_format,
+        } = self;
+        object_index.sweep_values(compactions);
+        bound_format.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,732 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{operations_on_objects::get, type_conversion::to_string},
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            intl::{
+                abstract_operations::{
+                    canonicalize_locale_list, coerce_options_to_object, get_boolean_option,
+                    get_free_string_option, get_number_option, get_string_option,
+                    supported_locales,
+                },
+                date_time_format::DateTimeFormat,
+                date_time_format_objects::date_time_formatting::{
+                    DateTimeFields, DateTimeStyle, HourCycle, MonthStyle, NumericStyle, TextStyle,
+                    TimeZoneNameStyle,
+                },
+                locale::{ExtensionKeyData, is_unicode_locale_type, resolve_locale},
+            },
+            numbers_and_dates::date_objects::{
+                date_prototype::{
+                    is_time_zone_offset_string, parse_time_zone_offset_string,
+                    system_time_zone_identifier,
+                },
+                time_zone::{TimeZoneRules, is_utc_identifier},
+            },
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoFunction, IntoObject, IntoValue, Object,
+            PropertyKey, String, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct DateTimeFormatConstructor;
+impl Builtin for DateTimeFormatConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.DateTimeFormat;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for DateTimeFormatConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::DateTimeFormat;
+}
+
+struct DateTimeFormatSupportedLocalesOf;
+impl Builtin for DateTimeFormatSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DateTimeFormatConstructor::supported_locales_of);
+}
+
+/// ### [11.2.3 Internal slots](https://tc39.es/ecma402/#sec-intl.datetimeformat-internal-slots)
+///
+/// The \[\[RelevantExtensionKeys]] of %Intl.DateTimeFormat%: « "ca", "hc",
+/// "nu" ». Only the Gregorian calendar and the Latin numbering system are
+/// supported.
+const RELEVANT_EXTENSION_KEYS: [ExtensionKeyData; 3] = [
+    ExtensionKeyData {
+        key: "ca",
+        values: &["gregory"],
+        default_is_null: false,
+    },
+    ExtensionKeyData {
+        key: "hc",
+        values: &["h11", "h12", "h23", "h24"],
+        default_is_null: true,
+    },
+    ExtensionKeyData {
+        key: "nu",
+        values: &["latn"],
+        default_is_null: false,
+    },
+];
+
+/// The \[\[hourCycle]], \[\[hourCycle12]] and \[\[hourCycle24]] locale data
+/// of the available locales.
+const HOUR_CYCLE: HourCycle = HourCycle::H12;
+const HOUR_CYCLE_12: HourCycle = HourCycle::H12;
+const HOUR_CYCLE_24: HourCycle = HourCycle::H23;
+
+/// The `required` parameter of CreateDateTimeFormat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeFormatRequired {
+    Date,
+    Time,
+    Any,
+}
+
+/// The `defaults` parameter of CreateDateTimeFormat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeFormatDefaults {
+    Date,
+    Time,
+    All,
+}
+
+impl DateTimeFormatConstructor {
+    /// ### [11.1.1 Intl.DateTimeFormat ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.datetimeformat)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object, else let newTarget be NewTarget.
+        let new_target = new_target.map_or_else(
+            || agent.active_function_object(gc.nogc()),
+            |new_target| Function::try_from(new_target).unwrap(),
+        );
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales,
+        //    options, any, date).
+        // 3. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        // a. Let this be the this value.
+        // b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // 4. Return dateTimeFormat.
+        create_date_time_format(
+            agent,
+            new_target.unbind(),
+            locales.unbind(),
+            options.unbind(),
+            DateTimeFormatRequired::Any,
+            DateTimeFormatDefaults::Date,
+            gc,
+        )
+        .map(|date_time_format| date_time_format.into_value())
+    }
+
+    /// ### [11.2.2 Intl.DateTimeFormat.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let availableLocales be %Intl.DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(agent, &requested_locales, options.get(agent), gc)
+            .map(|locales| locales.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let date_time_format_prototype = intrinsics.date_time_format_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<DateTimeFormatConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(2)
+        .with_prototype_property(date_time_format_prototype.into_object())
+        .with_builtin_function_property::<DateTimeFormatSupportedLocalesOf>()
+        .build();
+    }
+}
+
+/// Create a new Intl.DateTimeFormat object as if by
+/// `CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, required,
+/// defaults)`.
+pub(crate) fn construct_date_time_format<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    options: Value,
+    required: DateTimeFormatRequired,
+    defaults: DateTimeFormatDefaults,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DateTimeFormat<'gc>> {
+    let constructor = agent
+        .current_realm_record()
+        .intrinsics()
+        .date_time_format()
+        .into_function();
+    create_date_time_format(agent, constructor, locales, options, required, defaults, gc)
+}
+
+/// ### [11.1.2 CreateDateTimeFormat ( newTarget, locales, options, required, defaults )](https://tc39.es/ecma402/#sec-createdatetimeformat)
+fn create_date_time_format<'gc>(
+    agent: &mut Agent,
+    new_target: Function,
+    locales: Value,
+    options: Value,
+    required: DateTimeFormatRequired,
+    defaults: DateTimeFormatDefaults,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DateTimeFormat<'gc>> {
+    let locales = locales.scope(agent, gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let dateTimeFormat be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Intl.DateTimeFormat.prototype%", « [[InitializedDateTimeFormat]],
+    //    [[Locale]], [[Calendar]], [[NumberingSystem]], [[TimeZone]],
+    //    [[HourCycle]], [[DateStyle]], [[TimeStyle]], [[DateTimeFormat]],
+    //    [[BoundFormat]] »).
+    let date_time_format = ordinary_create_from_constructor(
+        agent,
+        new_target,
+        ProtoIntrinsics::DateTimeFormat,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    let date_time_format = DateTimeFormat::try_from(date_time_format)
+        .unwrap()
+        .scope(agent, gc.nogc());
+    // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+    let requested_locales =
+        canonicalize_locale_list(agent, locales.get(agent), gc.reborrow()).unbind()?;
+    // 3. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 4. Let opt be a new Record.
+    // 5. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    // 6. Set opt.[[localeMatcher]] to matcher.
+    get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.localeMatcher.into(),
+        &["lookup", "best fit"],
+        Some("best fit"),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 7. Let calendar be ? GetOption(options, "calendar", string, empty,
+    //    undefined).
+    let calendar = get_free_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.calendar.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 8. If calendar is not undefined, then
+    if let Some(calendar) = &calendar {
+        // a. If calendar cannot be matched by the type Unicode locale
+        //    nonterminal, throw a RangeError exception.
+        if !is_unicode_locale_type(calendar) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid calendar",
+                gc.into_nogc(),
+            ));
+        }
+    }
+    // 9. Set opt.[[ca]] to calendar.
+    // 10. Let numberingSystem be ? GetOption(options, "numberingSystem",
+    //     string, empty, undefined).
+    let numbering_system = get_free_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.numberingSystem.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 11. If numberingSystem is not undefined, then
+    if let Some(numbering_system) = &numbering_system {
+        // a. If numberingSystem cannot be matched by the type Unicode locale
+        //    nonterminal, throw a RangeError exception.
+        if !is_unicode_locale_type(numbering_system) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid numberingSystem",
+                gc.into_nogc(),
+            ));
+        }
+    }
+    // 12. Set opt.[[nu]] to numberingSystem.
+    // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty,
+    //     undefined).
+    let hour12 = get_boolean_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hour12.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11",
+    //     "h12", "h23", "h24" », undefined).
+    let mut hour_cycle = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hourCycle.into(),
+        HourCycle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 15. If hour12 is not undefined, then
+    if hour12.is_some() {
+        // a. Set hourCycle to null.
+        hour_cycle = None;
+    }
+    // 16. Set opt.[[hc]] to hourCycle.
+    // 17. Let r be ResolveLocale(%Intl.DateTimeFormat%.[[AvailableLocales]],
+    //     requestedLocales, opt,
+    //     %Intl.DateTimeFormat%.[[RelevantExtensionKeys]],
+    //     %Intl.DateTimeFormat%.[[LocaleData]]).
+    let r = resolve_locale(
+        &requested_locales,
+        &[calendar, hour_cycle.map(str::to_string), numbering_system],
+        &RELEVANT_EXTENSION_KEYS,
+    );
+    // 18. Set dateTimeFormat.[[Locale]] to r.[[Locale]].
+    // 19. Let resolvedCalendar be r.[[ca]].
+    // 20. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+    // 21. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+    // 22. Let resolvedLocaleData be r.[[LocaleData]].
+    let hc = match hour12 {
+        // 23. If hour12 is true, then
+        // a. Let hc be resolvedLocaleData.[[hourCycle12]].
+        Some(true) => HOUR_CYCLE_12,
+        // 24. Else if hour12 is false, then
+        // a. Let hc be resolvedLocaleData.[[hourCycle24]].
+        Some(false) => HOUR_CYCLE_24,
+        // 25. Else,
+        // a. Assert: hour12 is undefined.
+        // b. Let hc be r.[[hc]].
+        // c. If hc is null, set hc to resolvedLocaleData.[[hourCycle]].
+        None => r.values[1]
+            .as_deref()
+            .map_or(HOUR_CYCLE, HourCycle::from_option),
+    };
+    // 26. Let timeZone be ? Get(options, "timeZone").
+    let time_zone = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeZone.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 27. If timeZone is undefined, then
+    let time_zone = if time_zone.is_undefined() {
+        // a. Set timeZone to SystemTimeZoneIdentifier().
+        system_time_zone_identifier(agent).to_string()
+    } else {
+        // 28. Else,
+        // a. Set timeZone to ? ToString(timeZone).
+        let time_zone = to_string(agent, time_zone.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        time_zone.as_wtf8(agent).to_string_lossy().into_owned()
+    };
+    let (time_zone, time_zone_rules) = match resolve_time_zone(agent, time_zone) {
+        Some(resolved) => resolved,
+        None => {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid time zone",
+                gc.into_nogc(),
+            ));
+        }
+    };
+    // 31. Set dateTimeFormat.[[TimeZone]] to timeZone.
+    // 32. Let formatOptions be a new Record.
+    // 33. Set formatOptions.[[hourCycle]] to hc.
+    // 34. Let hasExplicitFormatComponents be false.
+    // 35. For each row of Table 16, except the header row, in table order, do
+    // a. Let prop be the name given in the Property column of the current
+    //    row.
+    // b. If prop is "fractionalSecondDigits", then
+    // i. Let value be ? GetNumberOption(options, "fractionalSecondDigits",
+    //    1, 3, undefined).
+    // c. Else,
+    // i. Let values be a List whose elements are the strings given in the
+    //    Values column of the current row.
+    // ii. Let value be ? GetOption(options, prop, string, values,
+    //     undefined).
+    // d. Set formatOptions.[[<prop>]] to value.
+    let weekday = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.weekday.into(),
+        TextStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(TextStyle::from_option);
+    let era = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.era.into(),
+        TextStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(TextStyle::from_option);
+    let year = get_numeric_style_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.year.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let month = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.month.into(),
+        MonthStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(MonthStyle::from_option);
+    let day = get_numeric_style_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.day.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let day_period = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.dayPeriod.into(),
+        TextStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(TextStyle::from_option);
+    let hour = get_numeric_style_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hour.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let minute = get_numeric_style_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minute.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let second = get_numeric_style_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.second.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let fractional_second_digits = get_number_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.fractionalSecondDigits.into(),
+        1,
+        3,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(|digits| digits as u8);
+    let time_zone_name = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeZoneName.into(),
+        TimeZoneNameStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(TimeZoneNameStyle::from_option);
+    let mut format_options = DateTimeFields {
+        weekday,
+        era,
+        year,
+        month,
+        day,
+        day_period,
+        hour,
+        minute,
+        second,
+        fractional_second_digits,
+        time_zone_name,
+    };
+    // e. If value is not undefined, then
+    // i. Set hasExplicitFormatComponents to true.
+    let has_explicit_format_components = format_options.has_date_fields()
+        || format_options.has_time_fields()
+        || format_options.time_zone_name.is_some();
+    // 36. Let formatMatcher be ? GetOption(options, "formatMatcher", string,
+    //     « "basic", "best fit" », "best fit").
+    get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.formatMatcher.into(),
+        &["basic", "best fit"],
+        Some("best fit"),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 37. Let dateStyle be ? GetOption(options, "dateStyle", string,
+    //     « "full", "long", "medium", "short" », undefined).
+    // 38. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+    let date_style = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.dateStyle.into(),
+        DateTimeStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(DateTimeStyle::from_option);
+    // 39. Let timeStyle be ? GetOption(options, "timeStyle", string,
+    //     « "full", "long", "medium", "short" », undefined).
+    // 40. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+    let time_style = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeStyle.into(),
+        DateTimeStyle::VALUES,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .map(DateTimeStyle::from_option);
+    let gc = gc.into_nogc();
+    // 41. If dateStyle is not undefined or timeStyle is not undefined, then
+    let best_format = if date_style.is_some() || time_style.is_some() {
+        // a. If hasExplicitFormatComponents is true, then
+        if has_explicit_format_components {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "dateStyle and timeStyle cannot be combined with explicit date and time fields",
+                gc,
+            ));
+        }
+        // b. If required is date and timeStyle is not undefined, then
+        if required == DateTimeFormatRequired::Date && time_style.is_some() {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "timeStyle cannot be used when formatting only a date",
+                gc,
+            ));
+        }
+        // c. If required is time and dateStyle is not undefined, then
+        if required == DateTimeFormatRequired::Time && date_style.is_some() {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "dateStyle cannot be used when formatting only a time",
+                gc,
+            ));
+        }
+        // d. Let styles be resolvedLocaleData.[[styles]].[[<resolvedCalendar>]].
+        // e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle,
+        //    styles).
+        DateTimeFields::from_styles(date_style, time_style)
+    } else {
+        // 42. Else,
+        // a. Let needDefaults be true.
+        let mut need_defaults = true;
+        // b. If required is date or any, then
+        // i. For each property name prop of « "weekday", "year", "month",
+        //    "day" », do
+        // 1. Let value be formatOptions.[[<prop>]].
+        // 2. If value is not undefined, set needDefaults to false.
+        if matches!(
+            required,
+            DateTimeFormatRequired::Date | DateTimeFormatRequired::Any
+        ) && (format_options.weekday.is_some()
+            || format_options.year.is_some()
+            || format_options.month.is_some()
+            || format_options.day.is_some())
+        {
+            need_defaults = false;
+        }
+        // c. If required is time or any, then
+        // i. For each property name prop of « "dayPeriod", "hour", "minute",
+        //    "second", "fractionalSecondDigits" », do
+        // 1. Let value be formatOptions.[[<prop>]].
+        // 2. If value is not undefined, set needDefaults to false.
+        if matches!(
+            required,
+            DateTimeFormatRequired::Time | DateTimeFormatRequired::Any
+        ) && format_options.has_time_fields()
+        {
+            need_defaults = false;
+        }
+        // d. If needDefaults is true and defaults is either date or all, then
+        if need_defaults
+            && matches!(
+                defaults,
+                DateTimeFormatDefaults::Date | DateTimeFormatDefaults::All
+            )
+        {
+            // i. For each property name prop of « "year", "month", "day" », do
+            // 1. Set formatOptions.[[<prop>]] to "numeric".
+            format_options.year = Some(NumericStyle::Numeric);
+            format_options.month = Some(MonthStyle::Numeric);
+            format_options.day = Some(NumericStyle::Numeric);
+        }
+        // e. If needDefaults is true and defaults is either time or all, then
+        if need_defaults
+            && matches!(
+                defaults,
+                DateTimeFormatDefaults::Time | DateTimeFormatDefaults::All
+            )
+        {
+            // i. For each property name prop of « "hour", "minute", "second" »,
+            //    do
+            // 1. Set formatOptions.[[<prop>]] to "numeric".
+            format_options.hour = Some(NumericStyle::Numeric);
+            format_options.minute = Some(NumericStyle::Numeric);
+            format_options.second = Some(NumericStyle::Numeric);
+        }
+        // f. Let formats be resolvedLocaleData.[[formats]].[[<resolvedCalendar>]].
+        // g. If formatMatcher is "basic", then
+        // i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
+        // h. Else,
+        // i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+        // NOTE: The pattern is assembled directly from the requested fields,
+        // so every combination of fields is an available format.
+        format_options
+    };
+    // 43. Set dateTimeFormat.[[DateTimeFormat]] to bestFormat.
+    let date_time_format = date_time_format.get(agent).bind(gc);
+    let data = &mut agent[date_time_format];
+    data.locale = r.locale.into_boxed_str();
+    data.time_zone = time_zone.into_boxed_str();
+    data.time_zone_rules = time_zone_rules;
+    data.fields = best_format;
+    data.date_style = date_style;
+    data.time_style = time_style;
+    // 44. If bestFormat has a field [[hour]], then
+    // a. Set dateTimeFormat.[[HourCycle]] to hc.
+    data.hour_cycle = best_format.hour.map(|_| hc);
+    // 45. Return dateTimeFormat.
+    Ok(date_time_format)
+}
+
+/// GetOption with the values « "2-digit", "numeric" » and a default of
+/// undefined.
+fn get_numeric_style_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<NumericStyle>> {
+    get_string_option(agent, options, property, NumericStyle::VALUES, None, gc)
+        .map(|value| value.map(NumericStyle::from_option))
+}
+
+/// ### [11.1.2 CreateDateTimeFormat ( newTarget, locales, options, required, defaults )](https://tc39.es/ecma402/#sec-createdatetimeformat)
+///
+/// Steps 29 and 30: Resolve the time zone to its primary identifier,
+/// together with the rules of a named time zone other than the system time
+/// zone. Returns None if the time zone is invalid.
+fn resolve_time_zone(
+    agent: &Agent,
+    time_zone: std::string::String,
+) -> Option<(std::string::String, Option<TimeZoneRules>)> {
+    // 29. If IsTimeZoneOffsetString(timeZone) is true, then
+    if is_time_zone_offset_string(&time_zone) {
+        // a. Let parseResult be ParseText(StringToCodePoints(timeZone),
+        //    UTCOffset).
+        // b. Assert: parseResult is a Parse Node.
+        // c. If parseResult contains more than one MinuteSecond Parse Node,
+        //    throw a RangeError exception.
+        let max_length = if time_zone.contains(':') { 6 } else { 5 };
+        if time_zone.len() > max_length {
+            return None;
+        }
+        // d. Let offsetNanoseconds be ParseTimeZoneOffsetString(timeZone).
+        let offset_nanoseconds = parse_time_zone_offset_string(&time_zone);
+        // e. Let offsetMinutes be offsetNanoseconds / (6 × 10**10).
+        // f. Assert: offsetMinutes is an integer.
+        let offset_minutes = (offset_nanoseconds / 6e10) as i32;
+        // g. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        return Some((format_offset_time_zone_identifier(offset_minutes), None));
+    }
+    // 30. Else,
+    // a. Let timeZoneIdentifierRecord be
+    //    GetAvailableNamedTimeZoneIdentifier(timeZone).
+    // b. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
+    // c. Set timeZone to timeZoneIdentifierRecord.[[PrimaryIdentifier]].
+    if is_utc_identifier(&time_zone) || time_zone.eq_ignore_ascii_case("utc") {
+        return Some(("UTC".to_string(), None));
+    }
+    if time_zone == system_time_zone_identifier(agent) {
+        // The rules of the system time zone are already loaded.
+        return Some((time_zone, None));
+    }
+    let rules = TimeZoneRules::from_identifier(&time_zone)?;
+    Some((time_zone, Some(rules)))
+}
+
+/// ### [21.4.1.33.3 FormatOffsetTimeZoneIdentifier ( offsetMinutes \[ , style \] )](https://tc39.es/ecma262/#sec-formatoffsettimezoneidentifier)
+fn format_offset_time_zone_identifier(offset_minutes: i32) -> std::string::String {
+    // 1. If offsetMinutes ≥ 0, let sign be the code unit 0x002B (PLUS SIGN);
+    //    otherwise, let sign be the code unit 0x002D (HYPHEN-MINUS).
+    let sign = if offset_minutes >= 0 { '+' } else { '-' };
+    // 2. Let absoluteMinutes be abs(offsetMinutes).
+    let absolute_minutes = offset_minutes.unsigned_abs();
+    // 3. Let hours be floor(absoluteMinutes / 60).
+    let hours = absolute_minutes / 60;
+    // 4. Let minutes be absoluteMinutes modulo 60.
+    let minutes = absolute_minutes % 60;
+    // 5. Let timeString be FormatTimeString(hours, minutes, 0, 0, separated).
+    // 6. Return the string-concatenation of sign and timeString.
+    format!("{sign}{hours:02}:{minutes:02}")
+}
@@ -0,0 +1,456 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::type_conversion::to_number,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinFunctionArgs, BuiltinGetter,
+            bound_function::bound_function_create,
+            create_builtin_function,
+            date::data::{DateValue, time_clip},
+            intl::{
+                abstract_operations::{create_parts_array, create_resolved_options_object},
+                date_time_format::DateTimeFormat,
+                date_time_format_objects::date_time_formatting::{
+                    DateTimePart, HourCycle, LocalDateTime, format_date_time_pattern,
+                },
+            },
+            numbers_and_dates::date_objects::date_prototype::{
+                date_from_time, hour_from_time, is_time_zone_offset_string, min_from_time,
+                month_from_time, ms_from_time, parse_time_zone_offset_string, sec_from_time,
+                week_day, year_from_time,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoFunction, IntoValue, PropertyKey, String, Value},
+    },
+    engine::context::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct DateTimeFormatPrototype;
+
+struct DateTimeFormatPrototypeGetFormat;
+impl Builtin for DateTimeFormatPrototypeGetFormat {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_format;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.format.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DateTimeFormatPrototype::get_format);
+}
+impl BuiltinGetter for DateTimeFormatPrototypeGetFormat {}
+struct DateTimeFormatPrototypeFormatToParts;
+impl Builtin for DateTimeFormatPrototypeFormatToParts {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.formatToParts;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DateTimeFormatPrototype::format_to_parts);
+}
+struct DateTimeFormatPrototypeResolvedOptions;
+impl Builtin for DateTimeFormatPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DateTimeFormatPrototype::resolved_options);
+}
+
+impl DateTimeFormatPrototype {
+    /// ### [11.3.3 get Intl.DateTimeFormat.prototype.format](https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format)
+    fn get_format<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        // a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = require_date_time_format(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        if let Some(bound_format) = agent[dtf].bound_format {
+            // 5. Return dtf.[[BoundFormat]].
+            return Ok(bound_format.into_value().bind(gc.into_nogc()));
+        }
+        // a. Let F be a new built-in function object as defined in DateTime
+        //    Format Functions (11.5.4).
+        let f = create_builtin_function(
+            agent,
+            Behaviour::Regular(Self::date_time_format_function),
+            BuiltinFunctionArgs::new(1, ""),
+            gc.nogc(),
+        );
+        // b. Set F.[[DateTimeFormat]] to dtf.
+        let scoped_dtf = dtf.scope(agent, gc.nogc());
+        let f = bound_function_create(
+            agent,
+            f.into_function().unbind(),
+            dtf.into_value().unbind(),
+            &[],
+            gc,
+        )?;
+        agent[f].length = 1;
+        // c. Set dtf.[[BoundFormat]] to F.
+        let dtf = scoped_dtf.get(agent);
+        agent[dtf].bound_format = Some(f.unbind());
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(f.into_value())
+    }
+
+    /// ### [11.5.4 DateTime Format Functions](https://tc39.es/ecma402/#sec-datetime-format-functions)
+    ///
+    /// The date time format is the this value of the bound format function.
+    fn date_time_format_function<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let dtf be F.[[DateTimeFormat]].
+        // 2. Assert: dtf is an Object and dtf has an
+        //    [[InitializedDateTimeFormat]] internal slot.
+        let dtf = DateTimeFormat::try_from(this_value)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 3. If date is not provided or is undefined, then
+        // a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        // a. Let x be ? ToNumber(date).
+        let x = to_date_time_format_value(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 5. Return ? FormatDateTime(dtf, x).
+        let result = format_date_time(agent, dtf.get(agent), x, gc)?;
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [11.3.5 Intl.DateTimeFormat.prototype.formatToParts ( date )](https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatToParts)
+    fn format_to_parts<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = require_date_time_format(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. If date is undefined, then
+        // a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        // a. Let x be ? ToNumber(date).
+        let x = to_date_time_format_value(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        // ### [11.5.9 FormatDateTimeToParts ( dateTimeFormat, x )](https://tc39.es/ecma402/#sec-formatdatetimetoparts)
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = partition_date_time_pattern(agent, dtf.get(agent), x, gc)?;
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part of parts, do
+        // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        // d. Perform ! CreateDataProperty(result, ! ToString(𝔽(n)), O).
+        // e. Increment n by 1.
+        // 5. Return result.
+        Ok(create_parts_array(
+            agent,
+            parts
+                .iter()
+                .map(|part| (part.r#type.as_str(), part.value.as_str())),
+            gc,
+        )
+        .into_value())
+    }
+
+    /// ### [11.3.7 Intl.DateTimeFormat.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        // a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = require_date_time_format(agent, this_value, gc)?;
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 15, except the header row, in table order,
+        //    do
+        let data = &agent[dtf];
+        let locale = data.locale.to_string();
+        let time_zone = data.time_zone.to_string();
+        let hour_cycle = data.hour_cycle;
+        let fields = data.fields;
+        let date_style = data.date_style;
+        let time_style = data.time_style;
+        // NOTE: The fields of a date or time style are not reported.
+        let has_style = date_style.is_some() || time_style.is_some();
+        let mut properties = vec![
+            (
+                BUILTIN_STRING_MEMORY.locale,
+                Value::from_string(agent, locale, gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.calendar,
+                Value::from_static_str(agent, "gregory", gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.numberingSystem,
+                Value::from_static_str(agent, "latn", gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.timeZone,
+                Value::from_string(agent, time_zone, gc),
+            ),
+            // b. If p is "hour12", then
+            // i. Let hc be dtf.[[HourCycle]].
+            // ii. If hc is "h11" or "h12", let v be true.
+            // iii. Else if hc is "h23" or "h24", let v be false.
+            // iv. Else, let v be undefined.
+            (
+                BUILTIN_STRING_MEMORY.hourCycle,
+                hour_cycle
+                    .map(|hc| Value::from_static_str(agent, hc.as_str(), gc))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.hour12,
+                hour_cycle
+                    .map(|hc| Value::from(matches!(hc, HourCycle::H11 | HourCycle::H12)))
+                    .into(),
+            ),
+        ];
+        if !has_style {
+            // c. Else,
+            // i. Let v be the value of dtf's internal slot whose name is the
+            //    Internal Slot value of the current row.
+            // d. If the Internal Slot value of the current row is an Internal
+            //    Slot value in Table 16, then
+            // i. If dtf.[[DateStyle]] is not undefined or dtf.[[TimeStyle]]
+            //    is not undefined, then
+            // 1. Let v be undefined.
+            let components = [
+                (
+                    BUILTIN_STRING_MEMORY.weekday,
+                    fields.weekday.map(|v| v.as_str()),
+                ),
+                (BUILTIN_STRING_MEMORY.era, fields.era.map(|v| v.as_str())),
+                (BUILTIN_STRING_MEMORY.year, fields.year.map(|v| v.as_str())),
+                (
+                    BUILTIN_STRING_MEMORY.month,
+                    fields.month.map(|v| v.as_str()),
+                ),
+                (BUILTIN_STRING_MEMORY.day, fields.day.map(|v| v.as_str())),
+                (
+                    BUILTIN_STRING_MEMORY.dayPeriod,
+                    fields.day_period.map(|v| v.as_str()),
+                ),
+                (BUILTIN_STRING_MEMORY.hour, fields.hour.map(|v| v.as_str())),
+                (
+                    BUILTIN_STRING_MEMORY.minute,
+                    fields.minute.map(|v| v.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.second,
+                    fields.second.map(|v| v.as_str()),
+                ),
+            ];
+            for (p, v) in components {
+                let v = v.map(|v| Value::from_static_str(agent, v, gc)).into();
+                properties.push((p, v));
+            }
+            properties.push((
+                BUILTIN_STRING_MEMORY.fractionalSecondDigits,
+                fields.fractional_second_digits.map(Value::from).into(),
+            ));
+            properties.push((
+                BUILTIN_STRING_MEMORY.timeZoneName,
+                fields
+                    .time_zone_name
+                    .map(|v| Value::from_static_str(agent, v.as_str(), gc))
+                    .into(),
+            ));
+        }
+        properties.push((
+            BUILTIN_STRING_MEMORY.dateStyle,
+            date_style
+                .map(|v| Value::from_static_str(agent, v.as_str(), gc))
+                .into(),
+        ));
+        properties.push((
+            BUILTIN_STRING_MEMORY.timeStyle,
+            time_style
+                .map(|v| Value::from_static_str(agent, v.as_str(), gc))
+                .into(),
+        ));
+        // e. If v is not undefined, then
+        // i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        // 6. Return options.
+        Ok(create_resolved_options_object(agent, &properties, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.date_time_format_prototype();
+        let date_time_format_constructor = intrinsics.date_time_format();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(5)
+            .with_prototype(object_prototype)
+            .with_constructor_property(date_time_format_constructor)
+            .with_builtin_function_getter_property::<DateTimeFormatPrototypeGetFormat>()
+            .with_builtin_function_property::<DateTimeFormatPrototypeFormatToParts>()
+            .with_builtin_function_property::<DateTimeFormatPrototypeResolvedOptions>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_DateTimeFormat.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Steps 3 and 4 of the DateTime Format Functions and of
+/// Intl.DateTimeFormat.prototype.formatToParts: if date is undefined, let x
+/// be the current time value, else let x be ? ToNumber(date).
+fn to_date_time_format_value<'gc>(
+    agent: &mut Agent,
+    date: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, f64> {
+    if date.is_undefined() {
+        return Ok(DateValue::now().get_f64().unwrap());
+    }
+    let x = to_number(agent, date, gc)?;
+    Ok(x.to_real(agent))
+}
+
+/// ### [11.5.6 PartitionDateTimePattern ( dateTimeFormat, x )](https://tc39.es/ecma402/#sec-partitiondatetimepattern)
+pub(crate) fn partition_date_time_pattern<'a>(
+    agent: &mut Agent,
+    date_time_format: DateTimeFormat,
+    x: f64,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Vec<DateTimePart>> {
+    // 1. Let x be TimeClip(x).
+    // 2. If x is NaN, throw a RangeError exception.
+    let Some(x) = time_clip(x).get_f64() else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Invalid time value",
+            gc,
+        ));
+    };
+    // 3. Let tz be dateTimeFormat.[[TimeZone]].
+    // 4. Let epochNs be ℤ(ℝ(x) × 10**6).
+    // 5. Let timeZoneOffset be GetOffsetNanosecondsFor(tz, epochNs).
+    // 6. Let tm be ToLocalTime(epochNs, dateTimeFormat.[[Calendar]], tz).
+    let tm = to_local_time(agent, date_time_format, x);
+    // 7. Let format be dateTimeFormat.[[DateTimeFormat]].
+    // 8. Let result be FormatDateTimePattern(dateTimeFormat, format, tm).
+    // 9. Return result.
+    let data = &agent[date_time_format];
+    Ok(format_date_time_pattern(
+        &data.fields,
+        data.date_style,
+        data.hour_cycle.unwrap_or(HourCycle::H12),
+        &data.time_zone,
+        &tm,
+    ))
+}
+
+/// ### [11.5.7 FormatDateTime ( dateTimeFormat, x )](https://tc39.es/ecma402/#sec-formatdatetime)
+pub(crate) fn format_date_time<'a>(
+    agent: &mut Agent,
+    date_time_format: DateTimeFormat,
+    x: f64,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, std::string::String> {
+    // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+    let parts = partition_date_time_pattern(agent, date_time_format, x, gc)?;
+    // 2. Let result be the empty String.
+    // 3. For each Record { [[Type]], [[Value]] } part of parts, do
+    // a. Set result to the string-concatenation of result and part.[[Value]].
+    // 4. Return result.
+    Ok(parts.into_iter().map(|part| part.value).collect())
+}
+
+/// ### [11.5.13 ToLocalTime ( epochNs, calendar, timeZoneIdentifier )](https://tc39.es/ecma402/#sec-tolocaltime)
+///
+/// Only the Gregorian calendar is supported.
+fn to_local_time(agent: &Agent, date_time_format: DateTimeFormat, t: f64) -> LocalDateTime {
+    let data = &agent[date_time_format];
+    let epoch_seconds = (t / 1000.0).floor() as i64;
+    // 1. If IsTimeZoneOffsetString(timeZoneIdentifier) is true, then
+    // a. Let offsetNs be ParseTimeZoneOffsetString(timeZoneIdentifier).
+    // 2. Else,
+    // a. Assert: GetAvailableNamedTimeZoneIdentifier(timeZoneIdentifier)
+    //    does not return empty.
+    // b. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(
+    //    timeZoneIdentifier, epochNs).
+    let offset_seconds = if let Some(rules) = &data.time_zone_rules {
+        rules.offset_at(epoch_seconds)
+    } else if is_time_zone_offset_string(&data.time_zone) {
+        (parse_time_zone_offset_string(&data.time_zone) / 1e9) as i32
+    } else if &*data.time_zone == "UTC" {
+        0
+    } else {
+        // The time zone is the system time zone.
+        agent
+            .system_time_zone()
+            .rules()
+            .map_or(0, |rules| rules.offset_at(epoch_seconds))
+    };
+    // 3. Let tz be ℝ(epochNs) + offsetNs.
+    let tz = t + f64::from(offset_seconds) * 1000.0;
+    // 4. If calendar is "gregory", then
+    // a. Return a ToLocalTime Record with fields calculated from tz
+    //    according to Table 19.
+    LocalDateTime {
+        year: i64::from(year_from_time(tz)),
+        month: month_from_time(tz),
+        day: date_from_time(tz),
+        weekday: week_day(tz),
+        hour: hour_from_time(tz),
+        minute: min_from_time(tz),
+        second: sec_from_time(tz),
+        millisecond: ms_from_time(tz),
+        offset_minutes: offset_seconds / 60,
+    }
+}
+
+#[inline(always)]
+fn require_date_time_format<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DateTimeFormat<'a>> {
+    DateTimeFormat::try_from(value)
+        .map(|dtf| dtf.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not an Intl.DateTimeFormat object",
+                gc,
+            )
+        })
+}
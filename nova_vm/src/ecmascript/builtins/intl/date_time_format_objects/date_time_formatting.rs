@@ -0,0 +1,516 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [11.5.5 FormatDateTimePattern ( dateTimeFormat, patternParts, x, rangeFormatOptions )](https://tc39.es/ecma402/#sec-formatdatetimepattern)
+//!
+//! Formatting of local date and time fields with the `en` patterns. Instead
+//! of matching the requested fields against a table of CLDR skeletons, the
+//! pattern is assembled directly from the requested fields in the order and
+//! with the separators that the `en` locale data would select.
+
+use crate::ecmascript::builtins::intl::{
+    locale_data, number_format_objects::numeric_formatting::option_enum,
+};
+
+option_enum!(
+    /// The \[\[Weekday]] and \[\[Era]] field styles.
+    TextStyle {
+        Narrow => "narrow",
+        Short => "short",
+        Long => "long",
+    }
+);
+
+option_enum!(
+    /// The \[\[Year]], \[\[Day]], \[\[Hour]], \[\[Minute]] and \[\[Second]]
+    /// field styles.
+    NumericStyle {
+        TwoDigit => "2-digit",
+        Numeric => "numeric",
+    }
+);
+
+option_enum!(
+    /// \[\[Month]]
+    MonthStyle {
+        TwoDigit => "2-digit",
+        Numeric => "numeric",
+        Narrow => "narrow",
+        Short => "short",
+        Long => "long",
+    }
+);
+
+option_enum!(
+    /// \[\[TimeZoneName]]
+    TimeZoneNameStyle {
+        Short => "short",
+        Long => "long",
+        ShortOffset => "shortOffset",
+        LongOffset => "longOffset",
+        ShortGeneric => "shortGeneric",
+        LongGeneric => "longGeneric",
+    }
+);
+
+option_enum!(
+    /// \[\[HourCycle]]
+    HourCycle {
+        H11 => "h11",
+        H12 => "h12",
+        H23 => "h23",
+        H24 => "h24",
+    }
+);
+
+option_enum!(
+    /// \[\[DateStyle]] and \[\[TimeStyle]]
+    DateTimeStyle {
+        Full => "full",
+        Long => "long",
+        Medium => "medium",
+        Short => "short",
+    }
+);
+
+/// The date and time fields that an Intl.DateTimeFormat object formats.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DateTimeFields {
+    pub(crate) weekday: Option<TextStyle>,
+    pub(crate) era: Option<TextStyle>,
+    pub(crate) year: Option<NumericStyle>,
+    pub(crate) month: Option<MonthStyle>,
+    pub(crate) day: Option<NumericStyle>,
+    pub(crate) day_period: Option<TextStyle>,
+    pub(crate) hour: Option<NumericStyle>,
+    pub(crate) minute: Option<NumericStyle>,
+    pub(crate) second: Option<NumericStyle>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+impl DateTimeFields {
+    /// Get the fields that the `en` date and time style patterns use.
+    pub(crate) fn from_styles(
+        date_style: Option<DateTimeStyle>,
+        time_style: Option<DateTimeStyle>,
+    ) -> Self {
+        let mut fields = Self::default();
+        if let Some(date_style) = date_style {
+            fields.year = Some(match date_style {
+                DateTimeStyle::Short => NumericStyle::TwoDigit,
+                _ => NumericStyle::Numeric,
+            });
+            fields.month = Some(match date_style {
+                DateTimeStyle::Full | DateTimeStyle::Long => MonthStyle::Long,
+                DateTimeStyle::Medium => MonthStyle::Short,
+                DateTimeStyle::Short => MonthStyle::Numeric,
+            });
+            fields.day = Some(NumericStyle::Numeric);
+            if date_style == DateTimeStyle::Full {
+                fields.weekday = Some(TextStyle::Long);
+            }
+        }
+        if let Some(time_style) = time_style {
+            fields.hour = Some(NumericStyle::Numeric);
+            fields.minute = Some(NumericStyle::TwoDigit);
+            if time_style != DateTimeStyle::Short {
+                fields.second = Some(NumericStyle::TwoDigit);
+            }
+            fields.time_zone_name = match time_style {
+                DateTimeStyle::Full => Some(TimeZoneNameStyle::Long),
+                DateTimeStyle::Long => Some(TimeZoneNameStyle::Short),
+                DateTimeStyle::Medium | DateTimeStyle::Short => None,
+            };
+        }
+        fields
+    }
+
+    pub(crate) fn has_date_fields(&self) -> bool {
+        self.weekday.is_some()
+            || self.era.is_some()
+            || self.year.is_some()
+            || self.month.is_some()
+            || self.day.is_some()
+    }
+
+    pub(crate) fn has_time_fields(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+}
+
+/// The local date and time fields of a time value, as produced by
+/// ToLocalTime.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LocalDateTime {
+    /// The proleptic Gregorian year, with year 0 being 1 BC.
+    pub(crate) year: i64,
+    /// The month, from 0 to 11.
+    pub(crate) month: u8,
+    /// The day of the month, from 1 to 31.
+    pub(crate) day: u8,
+    /// The day of the week, from 0 (Sunday) to 6.
+    pub(crate) weekday: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+    /// The offset from UTC of the time zone in minutes.
+    pub(crate) offset_minutes: i32,
+}
+
+/// The types of the parts produced by FormatDateTimePattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimePartType {
+    Day,
+    DayPeriod,
+    Era,
+    FractionalSecond,
+    Hour,
+    Literal,
+    Minute,
+    Month,
+    Second,
+    TimeZoneName,
+    Weekday,
+    Year,
+}
+
+impl DateTimePartType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::DayPeriod => "dayPeriod",
+            Self::Era => "era",
+            Self::FractionalSecond => "fractionalSecond",
+            Self::Hour => "hour",
+            Self::Literal => "literal",
+            Self::Minute => "minute",
+            Self::Month => "month",
+            Self::Second => "second",
+            Self::TimeZoneName => "timeZoneName",
+            Self::Weekday => "weekday",
+            Self::Year => "year",
+        }
+    }
+}
+
+/// A part of a formatted date: a Record with fields \[\[Type]] and \[\[Value]].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DateTimePart {
+    pub(crate) r#type: DateTimePartType,
+    pub(crate) value: String,
+}
+
+struct PartList(Vec<DateTimePart>);
+
+impl PartList {
+    fn push(&mut self, r#type: DateTimePartType, value: impl Into<String>) {
+        self.0.push(DateTimePart {
+            r#type,
+            value: value.into(),
+        });
+    }
+
+    fn literal(&mut self, value: &str) {
+        // Adjacent literals are merged into one part.
+        if let Some(last) = self.0.last_mut() {
+            if last.r#type == DateTimePartType::Literal {
+                last.value.push_str(value);
+                return;
+            }
+        }
+        self.push(DateTimePartType::Literal, value);
+    }
+}
+
+fn numeric(value: i64, style: NumericStyle) -> String {
+    match style {
+        NumericStyle::Numeric => value.to_string(),
+        NumericStyle::TwoDigit => format!("{:02}", value.rem_euclid(100)),
+    }
+}
+
+fn text(names: [&'static [&'static str]; 3], index: usize, style: TextStyle) -> &'static str {
+    let [narrow, short, long] = names;
+    match style {
+        TextStyle::Narrow => narrow[index],
+        TextStyle::Short => short[index],
+        TextStyle::Long => long[index],
+    }
+}
+
+/// Format the offset of a time zone as a localized GMT format.
+fn gmt_offset(offset_minutes: i32, long: bool) -> String {
+    if offset_minutes == 0 {
+        return "GMT".to_string();
+    }
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let hours = offset_minutes.unsigned_abs() / 60;
+    let minutes = offset_minutes.unsigned_abs() % 60;
+    if long {
+        format!("GMT{sign}{hours:02}:{minutes:02}")
+    } else if minutes == 0 {
+        format!("GMT{sign}{hours}")
+    } else {
+        format!("GMT{sign}{hours}:{minutes:02}")
+    }
+}
+
+/// Get the display name of a time zone.
+fn time_zone_name(time_zone: &str, offset_minutes: i32, style: TimeZoneNameStyle) -> String {
+    let is_utc = time_zone == "UTC";
+    match style {
+        TimeZoneNameStyle::Short | TimeZoneNameStyle::ShortGeneric if is_utc => "UTC".to_string(),
+        TimeZoneNameStyle::Long | TimeZoneNameStyle::LongGeneric if is_utc => {
+            locale_data::UTC_LONG_NAME.to_string()
+        }
+        TimeZoneNameStyle::Short
+        | TimeZoneNameStyle::ShortOffset
+        | TimeZoneNameStyle::ShortGeneric => gmt_offset(offset_minutes, false),
+        TimeZoneNameStyle::Long
+        | TimeZoneNameStyle::LongOffset
+        | TimeZoneNameStyle::LongGeneric => gmt_offset(offset_minutes, true),
+    }
+}
+
+/// Format the date fields in the `en` pattern that matches them.
+fn format_date(parts: &mut PartList, fields: &DateTimeFields, date: &LocalDateTime) {
+    // The era year: 1 BC is year 0.
+    let (era, year) = if date.year <= 0 {
+        (0, 1 - date.year)
+    } else {
+        (1, date.year)
+    };
+    let weekday = fields.weekday.map(|style| {
+        text(
+            [
+                &locale_data::WEEKDAYS_NARROW,
+                &locale_data::WEEKDAYS_SHORT,
+                &locale_data::WEEKDAYS_LONG,
+            ],
+            usize::from(date.weekday),
+            style,
+        )
+    });
+    let era = fields.era.map(|style| {
+        text(
+            [
+                &locale_data::ERAS_NARROW,
+                &locale_data::ERAS_SHORT,
+                &locale_data::ERAS_LONG,
+            ],
+            era,
+            style,
+        )
+    });
+    let textual_month = match fields.month {
+        Some(MonthStyle::Narrow) => Some(locale_data::MONTHS_NARROW[usize::from(date.month)]),
+        Some(MonthStyle::Short) => Some(locale_data::MONTHS_SHORT[usize::from(date.month)]),
+        Some(MonthStyle::Long) => Some(locale_data::MONTHS_LONG[usize::from(date.month)]),
+        Some(MonthStyle::Numeric | MonthStyle::TwoDigit) | None => None,
+    };
+    if let Some(weekday) = weekday {
+        parts.push(DateTimePartType::Weekday, weekday);
+        if fields.year.is_some() || fields.month.is_some() || fields.day.is_some() {
+            parts.literal(", ");
+        }
+    }
+    if let Some(month) = textual_month {
+        // "MMMM d, y", "MMMM y" or "MMMM d".
+        parts.push(DateTimePartType::Month, month);
+        if let Some(day) = fields.day {
+            parts.literal(" ");
+            parts.push(DateTimePartType::Day, numeric(i64::from(date.day), day));
+            if fields.year.is_some() {
+                parts.literal(", ");
+            }
+        } else if fields.year.is_some() {
+            parts.literal(" ");
+        }
+    } else {
+        // "M/d/y" with any of the fields left out.
+        if let Some(month) = fields.month {
+            let style = match month {
+                MonthStyle::TwoDigit => NumericStyle::TwoDigit,
+                _ => NumericStyle::Numeric,
+            };
+            parts.push(
+                DateTimePartType::Month,
+                numeric(i64::from(date.month) + 1, style),
+            );
+            if fields.day.is_some() || fields.year.is_some() {
+                parts.literal("/");
+            }
+        }
+        if let Some(day) = fields.day {
+            parts.push(DateTimePartType::Day, numeric(i64::from(date.day), day));
+            if fields.year.is_some() {
+                parts.literal("/");
+            }
+        }
+    }
+    if let Some(year_style) = fields.year {
+        parts.push(DateTimePartType::Year, numeric(year, year_style));
+    }
+    if let Some(era) = era {
+        if !parts.0.is_empty() {
+            parts.literal(" ");
+        }
+        parts.push(DateTimePartType::Era, era);
+    }
+}
+
+/// Format the time fields in the `en` pattern that matches them.
+fn format_time(
+    parts: &mut PartList,
+    fields: &DateTimeFields,
+    hour_cycle: HourCycle,
+    date: &LocalDateTime,
+) {
+    let twelve_hour = matches!(hour_cycle, HourCycle::H11 | HourCycle::H12);
+    if let Some(hour_style) = fields.hour {
+        let hour = match hour_cycle {
+            HourCycle::H11 => date.hour % 12,
+            HourCycle::H12 => match date.hour % 12 {
+                0 => 12,
+                hour => hour,
+            },
+            HourCycle::H23 => date.hour,
+            HourCycle::H24 => match date.hour {
+                0 => 24,
+                hour => hour,
+            },
+        };
+        // The 24-hour patterns of `en` always use two digits.
+        let hour_style = if twelve_hour {
+            hour_style
+        } else {
+            NumericStyle::TwoDigit
+        };
+        parts.push(DateTimePartType::Hour, numeric(i64::from(hour), hour_style));
+    }
+    if fields.minute.is_some() {
+        if fields.hour.is_some() {
+            parts.literal(":");
+        }
+        let style = if fields.hour.is_some() || fields.second.is_some() {
+            NumericStyle::TwoDigit
+        } else {
+            fields.minute.unwrap()
+        };
+        parts.push(
+            DateTimePartType::Minute,
+            numeric(i64::from(date.minute), style),
+        );
+    }
+    if fields.second.is_some() || fields.fractional_second_digits.is_some() {
+        if fields.hour.is_some() || fields.minute.is_some() {
+            parts.literal(":");
+        }
+        let style = if fields.hour.is_some() || fields.minute.is_some() {
+            NumericStyle::TwoDigit
+        } else {
+            fields.second.unwrap_or(NumericStyle::Numeric)
+        };
+        if fields.second.is_some() {
+            parts.push(
+                DateTimePartType::Second,
+                numeric(i64::from(date.second), style),
+            );
+        }
+        if let Some(digits) = fields.fractional_second_digits {
+            if fields.second.is_some() {
+                parts.literal(".");
+            }
+            let fraction = format!("{:03}", date.millisecond);
+            parts.push(
+                DateTimePartType::FractionalSecond,
+                &fraction[..usize::from(digits)],
+            );
+        }
+    }
+    if fields.hour.is_some() && twelve_hour {
+        match fields.day_period {
+            // Flexible day periods replace the AM/PM marker.
+            Some(style) => {
+                let (long, narrow) = locale_data::flexible_day_period(date.hour, date.minute);
+                parts.literal(" ");
+                parts.push(
+                    DateTimePartType::DayPeriod,
+                    if style == TextStyle::Narrow {
+                        narrow
+                    } else {
+                        long
+                    },
+                );
+            }
+            None => {
+                parts.literal(locale_data::TIME_PERIOD_SEPARATOR);
+                parts.push(
+                    DateTimePartType::DayPeriod,
+                    locale_data::DAY_PERIODS[usize::from(date.hour >= 12)],
+                );
+            }
+        }
+    } else if let Some(style) = fields.day_period {
+        if fields.hour.is_none() {
+            if !parts.0.is_empty() {
+                parts.literal(" ");
+            }
+            let (long, narrow) = locale_data::flexible_day_period(date.hour, date.minute);
+            parts.push(
+                DateTimePartType::DayPeriod,
+                if style == TextStyle::Narrow {
+                    narrow
+                } else {
+                    long
+                },
+            );
+        }
+    }
+}
+
+/// Format local date and time fields into parts.
+///
+/// The `date_style` selects the separator between the date and the time.
+pub(crate) fn format_date_time_pattern(
+    fields: &DateTimeFields,
+    date_style: Option<DateTimeStyle>,
+    hour_cycle: HourCycle,
+    time_zone: &str,
+    date: &LocalDateTime,
+) -> Vec<DateTimePart> {
+    let mut parts = PartList(Vec::new());
+    if fields.has_date_fields() {
+        format_date(&mut parts, fields, date);
+    }
+    if fields.has_time_fields() {
+        if !parts.0.is_empty() {
+            match date_style {
+                Some(DateTimeStyle::Full | DateTimeStyle::Long) => parts.literal(" at "),
+                _ => parts.literal(", "),
+            }
+        }
+        format_time(&mut parts, fields, hour_cycle, date);
+    }
+    if let Some(style) = fields.time_zone_name {
+        if !parts.0.is_empty() {
+            if fields.has_time_fields() {
+                parts.literal(" ");
+            } else {
+                parts.literal(", ");
+            }
+        }
+        parts.push(
+            DateTimePartType::TimeZoneName,
+            time_zone_name(time_zone, date.offset_minutes, style),
+        );
+    }
+    parts.0
+}
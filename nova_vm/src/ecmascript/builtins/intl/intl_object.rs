@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::create_array_from_list,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, intl::abstract_operations::canonicalize_locale_list,
+        },
+        execution::{Agent, JsResult, Realm},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
+    },
+    engine::context::{Bindable, GcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct IntlObject;
+
+struct IntlObjectGetCanonicalLocales;
+impl Builtin for IntlObjectGetCanonicalLocales {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getCanonicalLocales;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IntlObject::get_canonical_locales);
+}
+
+impl IntlObject {
+    /// ### [8.3.1 Intl.getCanonicalLocales ( locales )](https://tc39.es/ecma402/#sec-intl.getcanonicallocales)
+    fn get_canonical_locales<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        // 1. Let ll be ? CanonicalizeLocaleList(locales).
+        let ll = canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 2. Return CreateArrayFromList(ll).
+        let ll = ll
+            .into_iter()
+            .map(|locale| Value::from_string(agent, locale.to_string(), gc))
+            .collect::<Vec<_>>();
+        Ok(create_array_from_list(agent, &ll, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.intl();
+        let collator = intrinsics.collator();
+        let date_time_format = intrinsics.date_time_format();
+        let number_format = intrinsics.number_format();
+        let plural_rules = intrinsics.plural_rules();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(6)
+            .with_prototype(object_prototype)
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.Collator.into())
+                    .with_value(collator.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.DateTimeFormat.into())
+                    .with_value(date_time_format.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_builtin_function_property::<IntlObjectGetCanonicalLocales>()
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.NumberFormat.into())
+                    .with_value(number_format.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PluralRules.into())
+                    .with_value(plural_rules.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
@@ -0,0 +1,663 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [6.2 Language Tags](https://tc39.es/ecma402/#sec-language-tags)
+//!
+//! Parsing and canonicalization of Unicode BCP 47 locale identifiers as
+//! defined by [Unicode Technical Standard #35](https://unicode.org/reports/tr35/#Unicode_locale_identifier).
+
+use crate::engine::context::{Bindable, NoGcScope};
+
+/// A structurally valid Unicode BCP 47 locale identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LanguageTag {
+    /// The unicode_language_subtag, in lowercase.
+    pub(crate) language: String,
+    /// The unicode_script_subtag, in titlecase.
+    pub(crate) script: Option<String>,
+    /// The unicode_region_subtag, in uppercase.
+    pub(crate) region: Option<String>,
+    /// The unicode_variant_subtags, in lowercase.
+    pub(crate) variants: Vec<String>,
+    /// The unicode_locale_extensions component.
+    pub(crate) unicode_extension: Option<UnicodeExtension>,
+    /// All other extensions, including transformed_extensions, keyed by
+    /// their lowercase singleton.
+    pub(crate) other_extensions: Vec<(u8, Vec<String>)>,
+    /// The subtags of the pu_extensions component, in lowercase.
+    pub(crate) private_use: Vec<String>,
+}
+
+// SAFETY: Trivially safe.
+unsafe impl Bindable for LanguageTag {
+    type Of<'a> = LanguageTag;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        self
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        self
+    }
+}
+
+/// The unicode_locale_extensions component of a locale identifier, ie. the
+/// `-u-` extension.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct UnicodeExtension {
+    pub(crate) attributes: Vec<String>,
+    /// Keywords as key and type pairs. A keyword without a type has an empty
+    /// type.
+    pub(crate) keywords: Vec<(String, String)>,
+}
+
+impl UnicodeExtension {
+    /// Returns the type of the keyword with the given key.
+    pub(crate) fn keyword(&self, key: &str) -> Option<&str> {
+        self.keywords
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn is_alpha(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_digit(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_alphanum(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// unicode_language_subtag = alpha{2,3} | alpha{5,8}
+fn is_language_subtag(subtag: &str) -> bool {
+    matches!(subtag.len(), 2 | 3 | 5..=8) && is_alpha(subtag)
+}
+
+/// unicode_script_subtag = alpha{4}
+fn is_script_subtag(subtag: &str) -> bool {
+    subtag.len() == 4 && is_alpha(subtag)
+}
+
+/// unicode_region_subtag = (alpha{2} | digit{3})
+fn is_region_subtag(subtag: &str) -> bool {
+    (subtag.len() == 2 && is_alpha(subtag)) || (subtag.len() == 3 && is_digit(subtag))
+}
+
+/// unicode_variant_subtag = (alphanum{5,8} | digit alphanum{3})
+fn is_variant_subtag(subtag: &str) -> bool {
+    match subtag.len() {
+        5..=8 => is_alphanum(subtag),
+        4 => subtag.as_bytes()[0].is_ascii_digit() && is_alphanum(subtag),
+        _ => false,
+    }
+}
+
+/// Returns true if the subtag matches `alphanum{min,max}`.
+fn is_alphanum_of_length(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && is_alphanum(subtag)
+}
+
+/// key = alphanum alpha
+fn is_unicode_key(subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphanumeric() && bytes[1].is_ascii_alphabetic()
+}
+
+/// tkey = alpha digit
+fn is_transformed_key(subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_digit()
+}
+
+fn to_titlecase(subtag: &str) -> String {
+    let mut result = subtag.to_ascii_lowercase();
+    result[..1].make_ascii_uppercase();
+    result
+}
+
+/// Deprecated and legacy language subtags and their preferred values, from
+/// the CLDR language alias data.
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("aju", "jrb"),
+    ("als", "sq"),
+    ("arb", "ar"),
+    ("ayr", "ay"),
+    ("azj", "az"),
+    ("cmn", "zh"),
+    ("ekk", "et"),
+    ("in", "id"),
+    ("iw", "he"),
+    ("ji", "yi"),
+    ("jw", "jv"),
+    ("mo", "ro"),
+    ("tl", "fil"),
+    ("zsm", "ms"),
+];
+
+impl LanguageTag {
+    /// Parse a structurally valid language tag.
+    ///
+    /// This implements IsStructurallyValidLanguageTag: None is returned if
+    /// the tag cannot be generated from the EBNF grammar for
+    /// unicode_locale_id, uses backwards compatibility syntax, contains
+    /// duplicate variant subtags, or contains duplicate singleton subtags.
+    pub(crate) fn parse(tag: &str) -> Option<Self> {
+        let lowercase = tag.to_ascii_lowercase();
+        let subtags = lowercase.split('-').collect::<Vec<_>>();
+        if subtags
+            .iter()
+            .any(|subtag| subtag.is_empty() || subtag.len() > 8 || !is_alphanum(subtag))
+        {
+            return None;
+        }
+        let mut subtags = subtags.into_iter().peekable();
+
+        let language = subtags.next()?;
+        if !is_language_subtag(language) {
+            return None;
+        }
+        let script = subtags.next_if(|subtag| is_script_subtag(subtag));
+        let region = subtags.next_if(|subtag| is_region_subtag(subtag));
+        let mut variants = Vec::new();
+        while let Some(variant) = subtags.next_if(|subtag| is_variant_subtag(subtag)) {
+            if variants.iter().any(|v| v == variant) {
+                return None;
+            }
+            variants.push(variant.to_string());
+        }
+
+        let mut unicode_extension = None;
+        let mut other_extensions: Vec<(u8, Vec<String>)> = Vec::new();
+        let mut private_use = Vec::new();
+        let mut seen_singletons = Vec::new();
+        while let Some(singleton) = subtags.next() {
+            if singleton.len() != 1 {
+                return None;
+            }
+            let singleton = singleton.as_bytes()[0];
+            if seen_singletons.contains(&singleton) {
+                return None;
+            }
+            seen_singletons.push(singleton);
+            match singleton {
+                b'x' => {
+                    // pu_extensions = sep [xX] (sep alphanum{1,8})+
+                    private_use.extend(subtags.by_ref().map(str::to_string));
+                    if private_use.is_empty() {
+                        return None;
+                    }
+                }
+                b'u' => {
+                    // unicode_locale_extensions = sep [uU]
+                    //     ((sep keyword)+ | (sep attribute)+ (sep keyword)*)
+                    let mut extension = UnicodeExtension::default();
+                    while let Some(attribute) =
+                        subtags.next_if(|subtag| is_alphanum_of_length(subtag, 3, 8))
+                    {
+                        extension.attributes.push(attribute.to_string());
+                    }
+                    while let Some(key) = subtags.next_if(|subtag| is_unicode_key(subtag)) {
+                        let mut types = Vec::new();
+                        while let Some(r#type) =
+                            subtags.next_if(|subtag| is_alphanum_of_length(subtag, 3, 8))
+                        {
+                            types.push(r#type);
+                        }
+                        extension.keywords.push((key.to_string(), types.join("-")));
+                    }
+                    if extension.attributes.is_empty() && extension.keywords.is_empty() {
+                        return None;
+                    }
+                    unicode_extension = Some(extension);
+                }
+                b't' => {
+                    // transformed_extensions = sep [tT]
+                    //     ((sep tlang (sep tfield)*) | (sep tfield)+)
+                    let mut extension = Vec::new();
+                    if let Some(language) = subtags.next_if(|subtag| is_language_subtag(subtag)) {
+                        extension.push(language.to_string());
+                        if let Some(script) = subtags.next_if(|subtag| is_script_subtag(subtag)) {
+                            extension.push(script.to_string());
+                        }
+                        if let Some(region) = subtags.next_if(|subtag| is_region_subtag(subtag)) {
+                            extension.push(region.to_string());
+                        }
+                        while let Some(variant) =
+                            subtags.next_if(|subtag| is_variant_subtag(subtag))
+                        {
+                            extension.push(variant.to_string());
+                        }
+                    }
+                    while let Some(key) = subtags.next_if(|subtag| is_transformed_key(subtag)) {
+                        extension.push(key.to_string());
+                        let mut has_value = false;
+                        while let Some(value) =
+                            subtags.next_if(|subtag| is_alphanum_of_length(subtag, 3, 8))
+                        {
+                            extension.push(value.to_string());
+                            has_value = true;
+                        }
+                        if !has_value {
+                            return None;
+                        }
+                    }
+                    if extension.is_empty() {
+                        return None;
+                    }
+                    other_extensions.push((singleton, extension));
+                }
+                _ => {
+                    // other_extensions = sep [alphanum-[tTuUxX]] (sep alphanum{2,8})+
+                    let mut extension = Vec::new();
+                    while let Some(subtag) =
+                        subtags.next_if(|subtag| is_alphanum_of_length(subtag, 2, 8))
+                    {
+                        extension.push(subtag.to_string());
+                    }
+                    if extension.is_empty() {
+                        return None;
+                    }
+                    other_extensions.push((singleton, extension));
+                }
+            }
+        }
+
+        Some(Self {
+            language: language.to_string(),
+            script: script.map(to_titlecase),
+            region: region.map(str::to_ascii_uppercase),
+            variants,
+            unicode_extension,
+            other_extensions,
+            private_use,
+        })
+    }
+
+    /// ### [6.2.2 CanonicalizeUnicodeLocaleId ( locale )](https://tc39.es/ecma402/#sec-canonicalizeunicodelocaleid)
+    ///
+    /// Transforms the language tag into the canonical form specified by
+    /// Unicode Technical Standard #35, Annex C, LocaleId Canonicalization.
+    pub(crate) fn canonicalize(mut self) -> Self {
+        if let Some((_, preferred)) = LANGUAGE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == self.language)
+        {
+            self.language = preferred.to_string();
+        }
+        self.variants.sort();
+        if let Some(extension) = &mut self.unicode_extension {
+            extension.attributes.sort();
+            extension.attributes.dedup();
+            // Keep only the first keyword of each key.
+            let mut keywords: Vec<(String, String)> = Vec::with_capacity(extension.keywords.len());
+            for (key, value) in extension.keywords.drain(..) {
+                if !keywords.iter().any(|(k, _)| *k == key) {
+                    // A type of "true" is the same as an empty type.
+                    let value = if value == "true" {
+                        String::new()
+                    } else {
+                        value
+                    };
+                    keywords.push((key, value));
+                }
+            }
+            keywords.sort_by(|(a, _), (b, _)| a.cmp(b));
+            extension.keywords = keywords;
+        }
+        for (singleton, extension) in &mut self.other_extensions {
+            if *singleton == b't' {
+                canonicalize_transformed_extension(extension);
+            }
+        }
+        self.other_extensions
+            .sort_by_key(|(singleton, _)| *singleton);
+        self
+    }
+
+    /// Returns the locale without any Unicode locale extension sequences.
+    pub(crate) fn without_unicode_extension(&self) -> Self {
+        Self {
+            unicode_extension: None,
+            ..self.clone()
+        }
+    }
+
+    /// The unicode_language_id of the locale, ie. the locale without any
+    /// extensions.
+    pub(crate) fn base_name(&self) -> String {
+        let mut result = self.language.clone();
+        for subtag in self
+            .script
+            .iter()
+            .chain(self.region.iter())
+            .chain(self.variants.iter())
+        {
+            result.push('-');
+            result.push_str(subtag);
+        }
+        result
+    }
+}
+
+/// Sort the fields of a transformed extension by their keys, leaving the
+/// tlang component in place.
+fn canonicalize_transformed_extension(extension: &mut Vec<String>) {
+    let tfields_start = extension
+        .iter()
+        .position(|subtag| is_transformed_key(subtag))
+        .unwrap_or(extension.len());
+    let mut tfields: Vec<Vec<String>> = Vec::new();
+    for subtag in extension.drain(tfields_start..) {
+        if is_transformed_key(&subtag) {
+            tfields.push(vec![subtag]);
+        } else {
+            tfields.last_mut().unwrap().push(subtag);
+        }
+    }
+    tfields.sort_by(|a, b| a[0].cmp(&b[0]));
+    extension.extend(tfields.into_iter().flatten());
+}
+
+impl core::fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.base_name())?;
+        // Extensions are ordered by their singletons, and the private use
+        // extension comes last.
+        let mut unicode_extension = self.unicode_extension.as_ref();
+        for (singleton, subtags) in &self.other_extensions {
+            if *singleton > b'u' {
+                if let Some(extension) = unicode_extension.take() {
+                    write_unicode_extension(f, extension)?;
+                }
+            }
+            write!(f, "-{}", *singleton as char)?;
+            for subtag in subtags {
+                write!(f, "-{subtag}")?;
+            }
+        }
+        if let Some(extension) = unicode_extension {
+            write_unicode_extension(f, extension)?;
+        }
+        if !self.private_use.is_empty() {
+            f.write_str("-x")?;
+            for subtag in &self.private_use {
+                write!(f, "-{subtag}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_unicode_extension(
+    f: &mut core::fmt::Formatter<'_>,
+    extension: &UnicodeExtension,
+) -> core::fmt::Result {
+    f.write_str("-u")?;
+    for attribute in &extension.attributes {
+        write!(f, "-{attribute}")?;
+    }
+    for (key, value) in &extension.keywords {
+        write!(f, "-{key}")?;
+        if !value.is_empty() {
+            write!(f, "-{value}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if the value is a well-formed Unicode locale identifier type,
+/// ie. `(3*8alphanum) *("-" (3*8alphanum))`.
+pub(crate) fn is_unicode_locale_type(value: &str) -> bool {
+    value
+        .split('-')
+        .all(|subtag| is_alphanum_of_length(subtag, 3, 8))
+}
+
+/// Relevant data of a Unicode locale extension key for locale resolution.
+pub(crate) struct ExtensionKeyData {
+    pub(crate) key: &'static str,
+    /// The supported values of the key. The first value is the default. A
+    /// key whose default is null has no supported values that are used
+    /// unless requested.
+    pub(crate) values: &'static [&'static str],
+    pub(crate) default_is_null: bool,
+}
+
+/// The record returned by ResolveLocale.
+#[derive(Debug)]
+pub(crate) struct ResolvedLocale {
+    /// The resolved locale, including the supported Unicode extension
+    /// keywords.
+    pub(crate) locale: String,
+    /// The locale whose data is used.
+    pub(crate) data_locale: &'static str,
+    /// The resolved values of the relevant extension keys, in the same order
+    /// as the keys were given. A null value is None.
+    pub(crate) values: Vec<Option<String>>,
+}
+
+/// The locales for which locale data is bundled, and the locale used when
+/// no requested locale is available.
+pub(crate) const AVAILABLE_LOCALES: &[&str] = &["en", "en-US"];
+pub(crate) const DEFAULT_LOCALE: &str = "en-US";
+
+/// ### [9.2.2 BestAvailableLocale ( availableLocales, locale )](https://tc39.es/ecma402/#sec-bestavailablelocale)
+pub(crate) fn best_available_locale(locale: &str) -> Option<&'static str> {
+    // 1. Let candidate be locale.
+    let mut candidate = locale;
+    // 2. Repeat,
+    loop {
+        // a. If availableLocales contains candidate, return candidate.
+        if let Some(available) = AVAILABLE_LOCALES
+            .iter()
+            .find(|available| available.eq_ignore_ascii_case(candidate))
+        {
+            return Some(available);
+        }
+        // b. Let pos be the character index of the last occurrence of "-"
+        //    (U+002D) within candidate. If that character does not occur,
+        //    return undefined.
+        let mut pos = candidate.rfind('-')?;
+        // c. If pos ≥ 2 and the character "-" occurs at index pos - 2 of
+        //    candidate, decrease pos by 2.
+        if pos >= 2 && candidate.as_bytes()[pos - 2] == b'-' {
+            pos -= 2;
+        }
+        // d. Let candidate be the substring of candidate from 0 to pos.
+        candidate = &candidate[..pos];
+    }
+}
+
+/// ### [9.2.3 LookupMatchingLocaleByPrefix ( availableLocales, requestedLocales )](https://tc39.es/ecma402/#sec-lookupmatchinglocalebyprefix)
+///
+/// Returns the matched available locale and the requested locale it was
+/// matched from.
+fn lookup_matching_locale_by_prefix(
+    requested_locales: &[LanguageTag],
+) -> Option<(&'static str, &LanguageTag)> {
+    // 1. For each element locale of requestedLocales, do
+    for locale in requested_locales {
+        // a. Let extension be empty.
+        // b. If locale contains a Unicode locale extension sequence, then
+        // i. Set extension to the Unicode locale extension sequence of locale.
+        // ii. Set locale to the String value that is locale with any Unicode
+        //     locale extension sequences removed.
+        // c. Let prefix be LookupMatchingLocaleByPrefix(availableLocales, locale).
+        let no_extensions_locale = locale.without_unicode_extension().to_string();
+        // d. If prefix is not undefined, then
+        if let Some(available_locale) = best_available_locale(&no_extensions_locale) {
+            // i. Return the Record { [[locale]]: prefix, [[extension]]: extension }.
+            return Some((available_locale, locale));
+        }
+    }
+    // 2. Return undefined.
+    None
+}
+
+/// ### [9.2.7 ResolveLocale ( availableLocales, requestedLocales, options, relevantExtensionKeys, localeData )](https://tc39.es/ecma402/#sec-resolvelocale)
+///
+/// The requested locales must be canonicalized. The options give the values
+/// of the relevant extension keys given through the options bag; a value of
+/// "true" is used for a boolean option that was true.
+///
+/// Both the "lookup" and "best fit" matchers use the lookup algorithm.
+pub(crate) fn resolve_locale(
+    requested_locales: &[LanguageTag],
+    options: &[Option<String>],
+    relevant_extension_keys: &[ExtensionKeyData],
+) -> ResolvedLocale {
+    debug_assert_eq!(options.len(), relevant_extension_keys.len());
+    // 1. Let matcher be options.[[localeMatcher]].
+    // 2. If matcher is "lookup", then
+    // a. Let r be LookupMatchingLocaleByPrefix(availableLocales, requestedLocales).
+    // 3. Else,
+    // a. Let r be LookupMatchingLocaleByBestFit(availableLocales, requestedLocales).
+    let r = lookup_matching_locale_by_prefix(requested_locales);
+    // 4. If r is undefined, set r to the Record { [[locale]]:
+    //    DefaultLocale(), [[extension]]: empty }.
+    // 5. Let foundLocale be r.[[locale]].
+    let (found_locale, extension) = match r {
+        Some((found_locale, requested)) => (found_locale, requested.unicode_extension.as_ref()),
+        None => (DEFAULT_LOCALE, None),
+    };
+    // 6. Let result be a new Record.
+    // 7. Set result.[[dataLocale]] to foundLocale.
+    let mut values = Vec::with_capacity(relevant_extension_keys.len());
+    // 8. If r.[[extension]] is not empty, then
+    // a. Let components be UnicodeExtensionComponents(r.[[extension]]).
+    // b. Let keywords be components.[[Keywords]].
+    // 9. Else,
+    // a. Let keywords be a new empty List.
+    // 10. Let supportedKeywords be a new empty List.
+    let mut supported_keywords: Vec<(String, String)> = Vec::new();
+    // 11. For each element key of relevantExtensionKeys, do
+    for (key_data, options_value) in relevant_extension_keys.iter().zip(options) {
+        // a. Let foundLocaleData be localeData.[[<foundLocale>]].
+        // b. Assert: foundLocaleData is a Record.
+        // c. Let keyLocaleData be foundLocaleData.[[<key>]].
+        // d. Assert: keyLocaleData is a List.
+        let key_locale_data = key_data.values;
+        // e. Let value be keyLocaleData[0].
+        // f. Assert: value is a String or value is null.
+        let mut value = if key_data.default_is_null {
+            None
+        } else {
+            Some(key_locale_data[0].to_string())
+        };
+        // g. Let supportedKeyword be empty.
+        let mut supported_keyword = None;
+        // h. If keywords contains an element whose [[Key]] is key, then
+        if let Some(requested_value) =
+            extension.and_then(|extension| extension.keyword(key_data.key))
+        {
+            // i. Let entry be the element of keywords whose [[Key]] is key.
+            // ii. Let requestedValue be entry.[[Value]].
+            // iii. If requestedValue is not the empty String, then
+            if !requested_value.is_empty() {
+                // 1. If keyLocaleData contains requestedValue, then
+                if key_locale_data.contains(&requested_value) {
+                    // a. Set value to requestedValue.
+                    value = Some(requested_value.to_string());
+                    // b. Set supportedKeyword to the Record { [[Key]]: key,
+                    //    [[Value]]: value }.
+                    supported_keyword =
+                        Some((key_data.key.to_string(), requested_value.to_string()));
+                }
+            }
+            // iv. Else if keyLocaleData contains "true", then
+            else if key_locale_data.contains(&"true") {
+                // 1. Set value to "true".
+                value = Some("true".to_string());
+                // 2. Set supportedKeyword to the Record { [[Key]]: key,
+                //    [[Value]]: "" }.
+                supported_keyword = Some((key_data.key.to_string(), String::new()));
+            }
+        }
+        // i. Assert: options has a field [[<key>]].
+        // j. Let optionsValue be options.[[<key>]].
+        // k. Assert: optionsValue is a String, or optionsValue is either
+        //    undefined or null.
+        // l. If optionsValue is a String, then
+        // i. Let optionsUValue be the ASCII-lowercase of optionsValue.
+        // ii. Set optionsValue to CanonicalizeUValue(key, optionsUValue).
+        // iii. If optionsValue is the empty String, then
+        // 1. Set optionsValue to "true".
+        let options_value = options_value.as_ref().map(|options_value| {
+            let options_value = options_value.to_ascii_lowercase();
+            if options_value.is_empty() {
+                "true".to_string()
+            } else {
+                options_value
+            }
+        });
+        // m. If SameValue(optionsValue, value) is false and keyLocaleData
+        //    contains optionsValue, then
+        if let Some(options_value) = options_value {
+            if value.as_deref() != Some(options_value.as_str())
+                && key_locale_data.contains(&options_value.as_str())
+            {
+                // i. Set value to optionsValue.
+                value = Some(options_value);
+                // ii. Set supportedKeyword to empty.
+                supported_keyword = None;
+            }
+        }
+        // n. If supportedKeyword is not empty, append supportedKeyword to
+        //    supportedKeywords.
+        if let Some(supported_keyword) = supported_keyword {
+            supported_keywords.push(supported_keyword);
+        }
+        // o. Set result.[[<key>]] to value.
+        values.push(value);
+    }
+    // 12. If supportedKeywords is not empty, then
+    let locale = if !supported_keywords.is_empty() {
+        // a. Let supportedAttributes be a new empty List.
+        // b. Set foundLocale to InsertUnicodeExtensionAndCanonicalize(
+        //    foundLocale, supportedAttributes, supportedKeywords).
+        let mut locale = LanguageTag::parse(found_locale).unwrap();
+        locale.unicode_extension = Some(UnicodeExtension {
+            attributes: Vec::new(),
+            keywords: supported_keywords,
+        });
+        locale.canonicalize().to_string()
+    } else {
+        found_locale.to_string()
+    };
+    // 13. Set result.[[Locale]] to foundLocale.
+    // 14. Return result.
+    ResolvedLocale {
+        locale,
+        data_locale: found_locale,
+        values,
+    }
+}
+
+/// ### [9.2.10 LookupSupportedLocales ( availableLocales, requestedLocales )](https://tc39.es/ecma402/#sec-lookupsupportedlocales)
+///
+/// Returns the subset of the requested locales that are supported, in their
+/// original form.
+pub(crate) fn lookup_supported_locales(requested_locales: &[LanguageTag]) -> Vec<String> {
+    // 1. Let subset be a new empty List.
+    // 2. For each element locale of requestedLocales, do
+    // a. Let noExtensionsLocale be the String value that is locale with any
+    //    Unicode locale extension sequences removed.
+    // b. Let availableLocale be BestAvailableLocale(availableLocales, noExtensionsLocale).
+    // c. If availableLocale is not undefined, append locale to the end of subset.
+    // 3. Return subset.
+    requested_locales
+        .iter()
+        .filter(|locale| {
+            best_available_locale(&locale.without_unicode_extension().to_string()).is_some()
+        })
+        .map(|locale| locale.to_string())
+        .collect()
+}
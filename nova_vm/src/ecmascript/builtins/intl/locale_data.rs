@@ -0,0 +1,613 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Bundled locale data.
+//!
+//! The data is taken from the [Unicode Common Locale Data Repository](https://cldr.unicode.org/)
+//! for the root and `en` locales. All available locales use the `en` data;
+//! data that the root locale defines differently from `en`, such as the
+//! plural rules, is not used.
+
+/// ### Decimal format symbols of the `latn` numbering system
+pub(crate) const DECIMAL_SEPARATOR: &str = ".";
+pub(crate) const GROUP_SEPARATOR: &str = ",";
+pub(crate) const PERCENT_SIGN: &str = "%";
+pub(crate) const PLUS_SIGN: &str = "+";
+pub(crate) const MINUS_SIGN: &str = "-";
+pub(crate) const EXPONENT_SEPARATOR: &str = "E";
+pub(crate) const INFINITY: &str = "∞";
+pub(crate) const NAN: &str = "NaN";
+
+/// Number of integer digits in the primary and secondary groups.
+pub(crate) const GROUPING_SIZE: usize = 3;
+/// The minimum number of digits in the leading group for grouping to be
+/// used with the "auto" grouping strategy.
+pub(crate) const MINIMUM_GROUPING_DIGITS: usize = 1;
+
+/// Data of a currency in the `en` locale.
+pub(crate) struct CurrencyData {
+    pub(crate) code: &'static str,
+    pub(crate) symbol: &'static str,
+    pub(crate) narrow_symbol: &'static str,
+    /// Display names for the plural categories "one" and "other".
+    pub(crate) name_one: &'static str,
+    pub(crate) name_other: &'static str,
+}
+
+pub(crate) const CURRENCIES: &[CurrencyData] = &[
+    currency("AUD", "A$", "$", "Australian dollar", "Australian dollars"),
+    currency("BRL", "R$", "R$", "Brazilian real", "Brazilian reals"),
+    currency("CAD", "CA$", "$", "Canadian dollar", "Canadian dollars"),
+    currency("CHF", "CHF", "CHF", "Swiss franc", "Swiss francs"),
+    currency("CNY", "CN¥", "¥", "Chinese yuan", "Chinese yuan"),
+    currency("DKK", "DKK", "kr", "Danish krone", "Danish kroner"),
+    currency("EUR", "€", "€", "euro", "euros"),
+    currency("GBP", "£", "£", "British pound", "British pounds"),
+    currency("HKD", "HK$", "$", "Hong Kong dollar", "Hong Kong dollars"),
+    currency("ILS", "₪", "₪", "Israeli new shekel", "Israeli new shekels"),
+    currency("INR", "₹", "₹", "Indian rupee", "Indian rupees"),
+    currency("JPY", "¥", "¥", "Japanese yen", "Japanese yen"),
+    currency("KRW", "₩", "₩", "South Korean won", "South Korean won"),
+    currency("MXN", "MX$", "$", "Mexican peso", "Mexican pesos"),
+    currency("NOK", "NOK", "kr", "Norwegian krone", "Norwegian kroner"),
+    currency(
+        "NZD",
+        "NZ$",
+        "$",
+        "New Zealand dollar",
+        "New Zealand dollars",
+    ),
+    currency("PLN", "PLN", "zł", "Polish zloty", "Polish zlotys"),
+    currency("RUB", "RUB", "₽", "Russian ruble", "Russian rubles"),
+    currency("SEK", "SEK", "kr", "Swedish krona", "Swedish kronor"),
+    currency("SGD", "SGD", "$", "Singapore dollar", "Singapore dollars"),
+    currency("TRY", "TRY", "₺", "Turkish lira", "Turkish Lira"),
+    currency("TWD", "NT$", "$", "New Taiwan dollar", "New Taiwan dollars"),
+    currency("USD", "$", "$", "US dollar", "US dollars"),
+    currency("VND", "₫", "₫", "Vietnamese dong", "Vietnamese dong"),
+    currency(
+        "ZAR",
+        "ZAR",
+        "R",
+        "South African rand",
+        "South African rand",
+    ),
+];
+
+const fn currency(
+    code: &'static str,
+    symbol: &'static str,
+    narrow_symbol: &'static str,
+    name_one: &'static str,
+    name_other: &'static str,
+) -> CurrencyData {
+    CurrencyData {
+        code,
+        symbol,
+        narrow_symbol,
+        name_one,
+        name_other,
+    }
+}
+
+/// Returns the data of a currency given its upper case ISO 4217 code.
+pub(crate) fn currency_data(code: &str) -> Option<&'static CurrencyData> {
+    CURRENCIES.iter().find(|data| data.code == code)
+}
+
+/// ### [15.5.1 CurrencyDigits ( currency )](https://tc39.es/ecma402/#sec-currencydigits)
+///
+/// Returns the number of minor unit digits of an upper case ISO 4217
+/// currency code.
+pub(crate) fn currency_digits(code: &str) -> u8 {
+    match code {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// Data of a measurement unit in the `en` locale. Each pattern contains a
+/// `{0}` placeholder for the number.
+pub(crate) struct UnitData {
+    pub(crate) unit: &'static str,
+    pub(crate) long_one: &'static str,
+    pub(crate) long_other: &'static str,
+    pub(crate) short_one: &'static str,
+    pub(crate) short_other: &'static str,
+    pub(crate) narrow: &'static str,
+}
+
+/// ### [6.6.2 IsSanctionedSingleUnitIdentifier ( unitIdentifier )](https://tc39.es/ecma402/#sec-issanctionedsingleunitidentifier)
+///
+/// The units of Table 2: Single units sanctioned for use in ECMAScript.
+pub(crate) const SANCTIONED_UNITS: &[UnitData] = &[
+    unit("acre", "{0} acre", "{0} acres", "{0} ac", "{0} ac", "{0}ac"),
+    unit("bit", "{0} bit", "{0} bits", "{0} bit", "{0} bit", "{0}bit"),
+    unit(
+        "byte",
+        "{0} byte",
+        "{0} bytes",
+        "{0} byte",
+        "{0} byte",
+        "{0}B",
+    ),
+    unit(
+        "celsius",
+        "{0} degree Celsius",
+        "{0} degrees Celsius",
+        "{0}°C",
+        "{0}°C",
+        "{0}°C",
+    ),
+    unit(
+        "centimeter",
+        "{0} centimeter",
+        "{0} centimeters",
+        "{0} cm",
+        "{0} cm",
+        "{0}cm",
+    ),
+    unit("day", "{0} day", "{0} days", "{0} day", "{0} days", "{0}d"),
+    unit(
+        "degree",
+        "{0} degree",
+        "{0} degrees",
+        "{0} deg",
+        "{0} deg",
+        "{0}°",
+    ),
+    unit(
+        "fahrenheit",
+        "{0} degree Fahrenheit",
+        "{0} degrees Fahrenheit",
+        "{0}°F",
+        "{0}°F",
+        "{0}°",
+    ),
+    unit(
+        "fluid-ounce",
+        "{0} fluid ounce",
+        "{0} fluid ounces",
+        "{0} fl oz",
+        "{0} fl oz",
+        "{0}fl oz",
+    ),
+    unit("foot", "{0} foot", "{0} feet", "{0} ft", "{0} ft", "{0}′"),
+    unit(
+        "gallon",
+        "{0} gallon",
+        "{0} gallons",
+        "{0} gal",
+        "{0} gal",
+        "{0}gal",
+    ),
+    unit(
+        "gigabit",
+        "{0} gigabit",
+        "{0} gigabits",
+        "{0} Gb",
+        "{0} Gb",
+        "{0}Gb",
+    ),
+    unit(
+        "gigabyte",
+        "{0} gigabyte",
+        "{0} gigabytes",
+        "{0} GB",
+        "{0} GB",
+        "{0}GB",
+    ),
+    unit("gram", "{0} gram", "{0} grams", "{0} g", "{0} g", "{0}g"),
+    unit(
+        "hectare",
+        "{0} hectare",
+        "{0} hectares",
+        "{0} ha",
+        "{0} ha",
+        "{0}ha",
+    ),
+    unit("hour", "{0} hour", "{0} hours", "{0} hr", "{0} hr", "{0}h"),
+    unit("inch", "{0} inch", "{0} inches", "{0} in", "{0} in", "{0}″"),
+    unit(
+        "kilobit",
+        "{0} kilobit",
+        "{0} kilobits",
+        "{0} kb",
+        "{0} kb",
+        "{0}kb",
+    ),
+    unit(
+        "kilobyte",
+        "{0} kilobyte",
+        "{0} kilobytes",
+        "{0} kB",
+        "{0} kB",
+        "{0}kB",
+    ),
+    unit(
+        "kilogram",
+        "{0} kilogram",
+        "{0} kilograms",
+        "{0} kg",
+        "{0} kg",
+        "{0}kg",
+    ),
+    unit(
+        "kilometer",
+        "{0} kilometer",
+        "{0} kilometers",
+        "{0} km",
+        "{0} km",
+        "{0}km",
+    ),
+    unit("liter", "{0} liter", "{0} liters", "{0} L", "{0} L", "{0}L"),
+    unit(
+        "megabit",
+        "{0} megabit",
+        "{0} megabits",
+        "{0} Mb",
+        "{0} Mb",
+        "{0}Mb",
+    ),
+    unit(
+        "megabyte",
+        "{0} megabyte",
+        "{0} megabytes",
+        "{0} MB",
+        "{0} MB",
+        "{0}MB",
+    ),
+    unit("meter", "{0} meter", "{0} meters", "{0} m", "{0} m", "{0}m"),
+    unit(
+        "microsecond",
+        "{0} microsecond",
+        "{0} microseconds",
+        "{0} μs",
+        "{0} μs",
+        "{0}μs",
+    ),
+    unit("mile", "{0} mile", "{0} miles", "{0} mi", "{0} mi", "{0}mi"),
+    unit(
+        "mile-scandinavian",
+        "{0} mile-scandinavian",
+        "{0} miles-scandinavian",
+        "{0} smi",
+        "{0} smi",
+        "{0}smi",
+    ),
+    unit(
+        "milliliter",
+        "{0} milliliter",
+        "{0} milliliters",
+        "{0} mL",
+        "{0} mL",
+        "{0}mL",
+    ),
+    unit(
+        "millimeter",
+        "{0} millimeter",
+        "{0} millimeters",
+        "{0} mm",
+        "{0} mm",
+        "{0}mm",
+    ),
+    unit(
+        "millisecond",
+        "{0} millisecond",
+        "{0} milliseconds",
+        "{0} ms",
+        "{0} ms",
+        "{0}ms",
+    ),
+    unit(
+        "minute",
+        "{0} minute",
+        "{0} minutes",
+        "{0} min",
+        "{0} min",
+        "{0}m",
+    ),
+    unit(
+        "month",
+        "{0} month",
+        "{0} months",
+        "{0} mth",
+        "{0} mths",
+        "{0}m",
+    ),
+    unit(
+        "nanosecond",
+        "{0} nanosecond",
+        "{0} nanoseconds",
+        "{0} ns",
+        "{0} ns",
+        "{0}ns",
+    ),
+    unit(
+        "ounce",
+        "{0} ounce",
+        "{0} ounces",
+        "{0} oz",
+        "{0} oz",
+        "{0}oz",
+    ),
+    unit(
+        "percent",
+        "{0} percent",
+        "{0} percent",
+        "{0}%",
+        "{0}%",
+        "{0}%",
+    ),
+    unit(
+        "petabyte",
+        "{0} petabyte",
+        "{0} petabytes",
+        "{0} PB",
+        "{0} PB",
+        "{0}PB",
+    ),
+    unit(
+        "pound",
+        "{0} pound",
+        "{0} pounds",
+        "{0} lb",
+        "{0} lb",
+        "{0}#",
+    ),
+    unit(
+        "second",
+        "{0} second",
+        "{0} seconds",
+        "{0} sec",
+        "{0} sec",
+        "{0}s",
+    ),
+    unit(
+        "stone",
+        "{0} stone",
+        "{0} stones",
+        "{0} st",
+        "{0} st",
+        "{0}st",
+    ),
+    unit(
+        "terabit",
+        "{0} terabit",
+        "{0} terabits",
+        "{0} Tb",
+        "{0} Tb",
+        "{0}Tb",
+    ),
+    unit(
+        "terabyte",
+        "{0} terabyte",
+        "{0} terabytes",
+        "{0} TB",
+        "{0} TB",
+        "{0}TB",
+    ),
+    unit("week", "{0} week", "{0} weeks", "{0} wk", "{0} wks", "{0}w"),
+    unit("yard", "{0} yard", "{0} yards", "{0} yd", "{0} yd", "{0}yd"),
+    unit("year", "{0} year", "{0} years", "{0} yr", "{0} yrs", "{0}y"),
+];
+
+/// Compound units with dedicated patterns.
+pub(crate) const COMPOUND_UNITS: &[UnitData] = &[
+    unit(
+        "kilometer-per-hour",
+        "{0} kilometer per hour",
+        "{0} kilometers per hour",
+        "{0} km/h",
+        "{0} km/h",
+        "{0}km/h",
+    ),
+    unit(
+        "liter-per-kilometer",
+        "{0} liter per kilometer",
+        "{0} liters per kilometer",
+        "{0} L/km",
+        "{0} L/km",
+        "{0}L/km",
+    ),
+    unit(
+        "meter-per-second",
+        "{0} meter per second",
+        "{0} meters per second",
+        "{0} m/s",
+        "{0} m/s",
+        "{0}m/s",
+    ),
+    unit(
+        "mile-per-gallon",
+        "{0} mile per gallon",
+        "{0} miles per gallon",
+        "{0} mpg",
+        "{0} mpg",
+        "{0}mpg",
+    ),
+    unit(
+        "mile-per-hour",
+        "{0} mile per hour",
+        "{0} miles per hour",
+        "{0} mph",
+        "{0} mph",
+        "{0}mph",
+    ),
+];
+
+const fn unit(
+    unit: &'static str,
+    long_one: &'static str,
+    long_other: &'static str,
+    short_one: &'static str,
+    short_other: &'static str,
+    narrow: &'static str,
+) -> UnitData {
+    UnitData {
+        unit,
+        long_one,
+        long_other,
+        short_one,
+        short_other,
+        narrow,
+    }
+}
+
+pub(crate) fn sanctioned_unit_data(unit: &str) -> Option<&'static UnitData> {
+    SANCTIONED_UNITS.iter().find(|data| data.unit == unit)
+}
+
+pub(crate) fn compound_unit_data(unit: &str) -> Option<&'static UnitData> {
+    COMPOUND_UNITS.iter().find(|data| data.unit == unit)
+}
+
+/// Decimal compact patterns: the compact affix used for numbers with the
+/// given exponent, in the short and long forms.
+pub(crate) const COMPACT_PATTERNS: &[(i32, &str, &str)] = &[
+    (3, "K", "thousand"),
+    (6, "M", "million"),
+    (9, "B", "billion"),
+    (12, "T", "trillion"),
+];
+
+/// ### Plural rules
+///
+/// The `en` cardinal and ordinal plural rules. The root locale only has the
+/// "other" category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+pub(crate) const CARDINAL_PLURAL_CATEGORIES: &[PluralCategory] =
+    &[PluralCategory::One, PluralCategory::Other];
+pub(crate) const ORDINAL_PLURAL_CATEGORIES: &[PluralCategory] = &[
+    PluralCategory::Few,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Other,
+];
+
+/// Select the cardinal plural category of a formatted decimal number such
+/// as "1.50", using the plural operands of [UTS #35](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+pub(crate) fn cardinal_plural_category(formatted: &str) -> PluralCategory {
+    let (integer, fraction) = split_operands(formatted);
+    // one: i = 1 and v = 0
+    if integer == "1" && fraction.is_empty() {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// Select the ordinal plural category of a formatted decimal number.
+pub(crate) fn ordinal_plural_category(formatted: &str) -> PluralCategory {
+    let (integer, _) = split_operands(formatted);
+    // The rules only consider the last two digits of n.
+    let last_two = integer[integer.len().saturating_sub(2)..]
+        .parse::<u8>()
+        .unwrap_or(0);
+    match (last_two % 10, last_two) {
+        // one: n % 10 = 1 and n % 100 != 11
+        (1, n) if n != 11 => PluralCategory::One,
+        // two: n % 10 = 2 and n % 100 != 12
+        (2, n) if n != 12 => PluralCategory::Two,
+        // few: n % 10 = 3 and n % 100 != 13
+        (3, n) if n != 13 => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Split a formatted decimal number into the integer digits and the visible
+/// fraction digits.
+fn split_operands(formatted: &str) -> (&str, &str) {
+    let formatted = formatted.trim_start_matches('-');
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((formatted, ""));
+    let integer = integer.trim_start_matches('0');
+    (if integer.is_empty() { "0" } else { integer }, fraction)
+}
+
+/// ### Gregorian calendar data
+pub(crate) const MONTHS_LONG: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+pub(crate) const MONTHS_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+pub(crate) const MONTHS_NARROW: [&str; 12] =
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+
+/// Weekdays starting from Sunday.
+pub(crate) const WEEKDAYS_LONG: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+pub(crate) const WEEKDAYS_SHORT: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+pub(crate) const WEEKDAYS_NARROW: [&str; 7] = ["S", "M", "T", "W", "T", "F", "S"];
+
+/// Eras before and after the epoch of the Gregorian calendar.
+pub(crate) const ERAS_LONG: [&str; 2] = ["Before Christ", "Anno Domini"];
+pub(crate) const ERAS_SHORT: [&str; 2] = ["BC", "AD"];
+pub(crate) const ERAS_NARROW: [&str; 2] = ["B", "A"];
+
+/// AM and PM markers.
+pub(crate) const DAY_PERIODS: [&str; 2] = ["AM", "PM"];
+pub(crate) const DAY_PERIODS_NARROW: [&str; 2] = ["a", "p"];
+
+/// Returns the flexible day period of the given hour and minute in the
+/// short and long forms, and in the narrow form.
+pub(crate) fn flexible_day_period(hour: u8, minute: u8) -> (&'static str, &'static str) {
+    match (hour, minute) {
+        (12, 0) => ("noon", "n"),
+        (6..=11, _) => ("in the morning", "in the morning"),
+        (12..=17, _) => ("in the afternoon", "in the afternoon"),
+        (18..=20, _) => ("in the evening", "in the evening"),
+        _ => ("at night", "at night"),
+    }
+}
+
+/// The separator between a time and an AM/PM marker: U+202F NARROW NO-BREAK
+/// SPACE.
+pub(crate) const TIME_PERIOD_SEPARATOR: &str = "\u{202F}";
+
+/// Long name of the UTC time zone.
+pub(crate) const UTC_LONG_NAME: &str = "Coordinated Universal Time";
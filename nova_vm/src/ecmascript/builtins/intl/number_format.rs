@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::NumberFormatIndex,
+    },
+};
+
+use self::data::NumberFormatHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct NumberFormat<'a>(pub(crate) NumberFormatIndex<'a>);
+
+impl NumberFormat<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, NumberFormat<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(NumberFormatIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for NumberFormat<'_> {
+    type Of<'a> = NumberFormat<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for NumberFormat<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<NumberFormat<'a>> for Value<'a> {
+    fn from(value: NumberFormat<'a>) -> Self {
+        Value::NumberFormat(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for NumberFormat<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<NumberFormat<'a>> for Object<'a> {
+    fn from(value: NumberFormat) -> Self {
+        Object::NumberFormat(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for NumberFormat<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::NumberFormat(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for NumberFormat<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::NumberFormat(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for NumberFormat<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::NumberFormat;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for NumberFormat<'a> {}
+
+impl Index<NumberFormat<'_>> for Agent {
+    type Output = NumberFormatHeapData<'static>;
+
+    fn index(&self, index: NumberFormat) -> &Self::Output {
+        &self.heap.number_formats[index]
+    }
+}
+
+impl IndexMut<NumberFormat<'_>> for Agent {
+    fn index_mut(&mut self, index: NumberFormat) -> &mut Self::Output {
+        &mut self.heap.number_formats[index]
+    }
+}
+
+impl Index<NumberFormat<'_>> for Vec<Option<NumberFormatHeapData<'static>>> {
+    type Output = NumberFormatHeapData<'static>;
+
+    fn index(&self, index: NumberFormat) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("NumberFormat out of bounds")
+            .as_ref()
+            .expect("NumberFormat slot empty")
+    }
+}
+
+impl IndexMut<NumberFormat<'_>> for Vec<Option<NumberFormatHeapData<'static>>> {
+    fn index_mut(&mut self, index: NumberFormat) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("NumberFormat out of bounds")
+            .as_mut()
+            .expect("NumberFormat slot empty")
+    }
+}
+
+impl Rootable for NumberFormat<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::NumberFormat(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::NumberFormat(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for NumberFormat<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.number_formats.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.number_formats.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<NumberFormatHeapData<'a>, NumberFormat<'a>> for Heap {
+    fn create(&mut self, data: NumberFormatHeapData<'a>) -> NumberFormat<'a> {
+        self.number_formats.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<NumberFormatHeapData<'static>>>();
+        }
+        NumberFormat(NumberFormatIndex::last(&self.number_formats))
+    }
+}
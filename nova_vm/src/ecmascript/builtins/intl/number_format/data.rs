@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            bound_function::BoundFunction,
+            intl::{
+                locale::DEFAULT_LOCALE,
+                number_format_objects::numeric_formatting::NumberFormatRecord,
+            },
+        },
+        types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [15.4 Properties of Intl.NumberFormat Instances](https://tc39.es/ecma402/#sec-properties-of-intl-numberformat-instances)
+#[derive(Debug, Clone)]
+pub struct NumberFormatHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Locale]]
+    pub(crate) locale: Box<str>,
+    /// The formatting options, from \[\[Style]] to \[\[TrailingZeroDisplay]].
+    pub(crate) record: NumberFormatRecord,
+    /// \[\[BoundFormat]]
+    pub(crate) bound_format: Option<BoundFunction<'a>>,
+}
+
+impl Default for NumberFormatHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            locale: DEFAULT_LOCALE.into(),
+            record: NumberFormatRecord::default(),
+            bound_format: None,
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for NumberFormatHeapData<'_> {
+    type Of<'a> = NumberFormatHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for NumberFormatHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            locale: _,
+            record: _,
+            bound_format,
+        } = self;
+        object_index.mark_values(queues);
+        bound_format.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            locale: _,
+            record: _,
+            bound_format,
+        } = self;
+        object_index.sweep_values(compactions);
+        bound_format.sweep_values(compactions);
+    }
+}
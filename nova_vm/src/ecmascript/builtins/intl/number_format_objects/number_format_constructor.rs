@@ -0,0 +1,607 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::get,
+            type_conversion::{to_boolean, to_string},
+        },
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            intl::{
+                abstract_operations::{
+                    canonicalize_locale_list, coerce_options_to_object, get_free_string_option,
+                    get_string_option, set_number_format_digit_options, supported_locales,
+                },
+                locale::{ExtensionKeyData, is_unicode_locale_type, resolve_locale},
+                locale_data::{currency_digits, sanctioned_unit_data},
+                number_format::NumberFormat,
+                number_format_objects::numeric_formatting::{
+                    CompactDisplay, CurrencyDisplay, CurrencySign, Notation, NumberFormatRecord,
+                    NumberFormatStyle, SignDisplay, UnitDisplay, UseGrouping,
+                },
+            },
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoFunction, IntoObject, IntoValue, Object,
+            PropertyKey, String, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct NumberFormatConstructor;
+impl Builtin for NumberFormatConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.NumberFormat;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for NumberFormatConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::NumberFormat;
+}
+
+struct NumberFormatSupportedLocalesOf;
+impl Builtin for NumberFormatSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatConstructor::supported_locales_of);
+}
+
+/// ### [15.2.3 Internal slots](https://tc39.es/ecma402/#sec-intl.numberformat-internal-slots)
+///
+/// The \[\[RelevantExtensionKeys]] of %Intl.NumberFormat%: « "nu" ». Only
+/// the Latin numbering system is supported.
+const RELEVANT_EXTENSION_KEYS: [ExtensionKeyData; 1] = [ExtensionKeyData {
+    key: "nu",
+    values: &["latn"],
+    default_is_null: false,
+}];
+
+impl NumberFormatConstructor {
+    /// ### [15.1.1 Intl.NumberFormat ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.numberformat)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object, else let newTarget be NewTarget.
+        let new_target = new_target.map_or_else(
+            || agent.active_function_object(gc.nogc()),
+            |new_target| Function::try_from(new_target).unwrap(),
+        );
+        create_number_format(
+            agent,
+            new_target.unbind(),
+            locales.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(|number_format| number_format.into_value())
+    }
+
+    /// ### [15.2.2 Intl.NumberFormat.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let availableLocales be %Intl.NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(agent, &requested_locales, options.get(agent), gc)
+            .map(|locales| locales.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let number_format_prototype = intrinsics.number_format_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<NumberFormatConstructor>(agent, realm)
+            .with_property_capacity(2)
+            .with_prototype_property(number_format_prototype.into_object())
+            .with_builtin_function_property::<NumberFormatSupportedLocalesOf>()
+            .build();
+    }
+}
+
+/// Create a new Intl.NumberFormat object as if by
+/// `Construct(%Intl.NumberFormat%, « locales, options »)`.
+pub(crate) fn construct_number_format<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    options: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, NumberFormat<'gc>> {
+    let constructor = agent
+        .current_realm_record()
+        .intrinsics()
+        .number_format()
+        .into_function();
+    create_number_format(agent, constructor, locales, options, gc)
+}
+
+/// ### [15.1.1 Intl.NumberFormat ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.numberformat)
+///
+/// Steps 2 to 5 of the constructor.
+fn create_number_format<'gc>(
+    agent: &mut Agent,
+    new_target: Function,
+    locales: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, NumberFormat<'gc>> {
+    let locales = locales.scope(agent, gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Intl.NumberFormat.prototype%", « [[InitializedNumberFormat]],
+    //    [[Locale]], [[LocaleData]], [[NumberingSystem]], [[Style]],
+    //    [[Unit]], [[UnitDisplay]], [[Currency]], [[CurrencyDisplay]],
+    //    [[CurrencySign]], [[MinimumIntegerDigits]],
+    //    [[MinimumFractionDigits]], [[MaximumFractionDigits]],
+    //    [[MinimumSignificantDigits]], [[MaximumSignificantDigits]],
+    //    [[RoundingType]], [[Notation]], [[CompactDisplay]], [[UseGrouping]],
+    //    [[SignDisplay]], [[RoundingIncrement]], [[RoundingMode]],
+    //    [[ComputedRoundingPriority]], [[TrailingZeroDisplay]],
+    //    [[BoundFormat]] »).
+    let number_format = ordinary_create_from_constructor(
+        agent,
+        new_target,
+        ProtoIntrinsics::NumberFormat,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    let number_format = NumberFormat::try_from(number_format)
+        .unwrap()
+        .scope(agent, gc.nogc());
+    // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+    initialize_number_format(
+        agent,
+        number_format.clone(),
+        locales.get(agent),
+        options.get(agent),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let number_format = number_format.get(agent).bind(gc.into_nogc());
+    // 4. If the implementation supports the normative optional constructor
+    //    mode of 4.3 Note 1, then
+    // a. Let this be the this value.
+    // b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
+    // 5. Return numberFormat.
+    Ok(number_format)
+}
+
+/// ### [15.1.2 InitializeNumberFormat ( numberFormat, locales, options )](https://tc39.es/ecma402/#sec-initializenumberformat)
+///
+fn initialize_number_format<'gc>(
+    agent: &mut Agent,
+    number_format: Scoped<NumberFormat>,
+    locales: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+    let requested_locales = canonicalize_locale_list(agent, locales, gc.reborrow()).unbind()?;
+    // 2. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 3. Let opt be a new Record.
+    // 4. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    // 5. Set opt.[[localeMatcher]] to matcher.
+    get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.localeMatcher.into(),
+        &["lookup", "best fit"],
+        Some("best fit"),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 6. Let numberingSystem be ? GetOption(options, "numberingSystem",
+    //    string, empty, undefined).
+    let numbering_system = get_free_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.numberingSystem.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 7. If numberingSystem is not undefined, then
+    if let Some(numbering_system) = &numbering_system {
+        // a. If numberingSystem cannot be matched by the type Unicode locale
+        //    nonterminal, throw a RangeError exception.
+        if !is_unicode_locale_type(numbering_system) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid numberingSystem",
+                gc.into_nogc(),
+            ));
+        }
+    }
+    // 8. Set opt.[[nu]] to numberingSystem.
+    // 9. Let r be ResolveLocale(%Intl.NumberFormat%.[[AvailableLocales]],
+    //    requestedLocales, opt, %Intl.NumberFormat%.[[RelevantExtensionKeys]],
+    //    %Intl.NumberFormat%.[[LocaleData]]).
+    let r = resolve_locale(
+        &requested_locales,
+        &[numbering_system],
+        &RELEVANT_EXTENSION_KEYS,
+    );
+    // 10. Set numberFormat.[[Locale]] to r.[[Locale]].
+    // 11. Set numberFormat.[[LocaleData]] to r.[[LocaleData]].
+    // 12. Set numberFormat.[[NumberingSystem]] to r.[[nu]].
+    let mut record = NumberFormatRecord::default();
+    // 13. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+    set_number_format_unit_options(agent, &mut record, options.get(agent), gc.reborrow())
+        .unbind()?;
+    // 14. Let style be numberFormat.[[Style]].
+    let style = record.style;
+    let (mnfd_default, mxfd_default) = if style == NumberFormatStyle::Currency {
+        // 15. If style is "currency", then
+        // a. Let currency be numberFormat.[[Currency]].
+        // b. Let cDigits be CurrencyDigits(currency).
+        let c_digits = currency_digits(record.currency.as_deref().unwrap());
+        // c. Let mnfdDefault be cDigits.
+        // d. Let mxfdDefault be cDigits.
+        (c_digits, c_digits)
+    } else {
+        // 16. Else,
+        // a. Let mnfdDefault be 0.
+        // b. If style is "percent", then
+        // i. Let mxfdDefault be 0.
+        // c. Else,
+        // i. Let mxfdDefault be 3.
+        (
+            0,
+            if style == NumberFormatStyle::Percent {
+                0
+            } else {
+                3
+            },
+        )
+    };
+    // 17. Let notation be ? GetOption(options, "notation", string,
+    //     « "standard", "scientific", "engineering", "compact" »,
+    //     "standard").
+    let notation = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.notation.into(),
+        Notation::VALUES,
+        Some(Notation::Standard.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 18. Set numberFormat.[[Notation]] to notation.
+    record.notation = Notation::from_option(notation);
+    // 19. Perform ? SetNumberFormatDigitOptions(numberFormat, options,
+    //     mnfdDefault, mxfdDefault, notation).
+    record.digit_options = set_number_format_digit_options(
+        agent,
+        options.get(agent),
+        mnfd_default,
+        mxfd_default,
+        record.notation,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 20. Let compactDisplay be ? GetOption(options, "compactDisplay",
+    //     string, « "short", "long" », "short").
+    let compact_display = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.compactDisplay.into(),
+        CompactDisplay::VALUES,
+        Some(CompactDisplay::Short.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 21. Let defaultUseGrouping be "auto".
+    let mut default_use_grouping = UseGrouping::Auto;
+    // 22. If notation is "compact", then
+    if record.notation == Notation::Compact {
+        // a. Set numberFormat.[[CompactDisplay]] to compactDisplay.
+        record.compact_display = CompactDisplay::from_option(compact_display);
+        // b. Set defaultUseGrouping to "min2".
+        default_use_grouping = UseGrouping::Min2;
+    }
+    // 23. NOTE: For historical reasons, the strings "true" and "false" are
+    //     accepted and replaced with the default value.
+    // 24. Let useGrouping be ? GetBooleanOrStringNumberFormatOption(options,
+    //     "useGrouping", « "min2", "auto", "always", "true", "false" »,
+    //     defaultUseGrouping).
+    // 25. If useGrouping is "true" or useGrouping is "false", set
+    //     useGrouping to defaultUseGrouping.
+    // 26. If useGrouping is true, set useGrouping to "always".
+    // 27. Set numberFormat.[[UseGrouping]] to useGrouping.
+    record.use_grouping = get_use_grouping_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.useGrouping.into(),
+        default_use_grouping,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 28. Let signDisplay be ? GetOption(options, "signDisplay", string,
+    //     « "auto", "never", "always", "exceptZero", "negative" », "auto").
+    let sign_display = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.signDisplay.into(),
+        SignDisplay::VALUES,
+        Some(SignDisplay::Auto.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 29. Set numberFormat.[[SignDisplay]] to signDisplay.
+    record.sign_display = SignDisplay::from_option(sign_display);
+    // 30. If the implementation supports the normative optional constructor
+    //     mode of 4.3 Note 1, then
+    let number_format = number_format.get(agent);
+    let data = &mut agent[number_format];
+    data.locale = r.locale.into_boxed_str();
+    data.record = record;
+    // 31. Return numberFormat.
+    Ok(())
+}
+
+/// ### [15.1.3 GetBooleanOrStringNumberFormatOption ( options, property, stringValues, fallback )](https://tc39.es/ecma402/#sec-getbooleanorstringnumberformatoption)
+///
+/// Specialised for the "useGrouping" option: the string values "true" and
+/// "false" give the fallback.
+fn get_use_grouping_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    fallback: UseGrouping,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, UseGrouping> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(fallback);
+    }
+    // 3. If value is true, return true.
+    if value == Value::Boolean(true) {
+        return Ok(UseGrouping::Always);
+    }
+    // 4. If ToBoolean(value) is false, return false.
+    if !to_boolean(agent, value) {
+        return Ok(UseGrouping::False);
+    }
+    // 5. Set value to ? ToString(value).
+    let value = to_string(agent, value.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 6. If stringValues does not contain value, throw a RangeError exception.
+    // 7. Return value.
+    match value.as_wtf8(agent).as_str() {
+        Some("min2") => Ok(UseGrouping::Min2),
+        Some("auto") => Ok(UseGrouping::Auto),
+        Some("always") => Ok(UseGrouping::Always),
+        Some("true" | "false") => Ok(fallback),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Invalid useGrouping value",
+            gc.into_nogc(),
+        )),
+    }
+}
+
+/// ### [15.1.4 SetNumberFormatUnitOptions ( intlObj, options )](https://tc39.es/ecma402/#sec-setnumberformatunitoptions)
+fn set_number_format_unit_options<'gc>(
+    agent: &mut Agent,
+    intl_obj: &mut NumberFormatRecord,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let style be ? GetOption(options, "style", string, « "decimal",
+    //    "percent", "currency", "unit" », "decimal").
+    let style = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.style.into(),
+        NumberFormatStyle::VALUES,
+        Some(NumberFormatStyle::Decimal.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 2. Set intlObj.[[Style]] to style.
+    let style = NumberFormatStyle::from_option(style);
+    intl_obj.style = style;
+    // 3. Let currency be ? GetOption(options, "currency", string, empty,
+    //    undefined).
+    let currency = get_free_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.currency.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    match &currency {
+        // 4. If currency is undefined, then
+        None => {
+            // a. If style is "currency", throw a TypeError exception.
+            if style == NumberFormatStyle::Currency {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Currency code is required with currency style",
+                    gc.into_nogc(),
+                ));
+            }
+        }
+        // 5. Else,
+        Some(currency) => {
+            // a. If IsWellFormedCurrencyCode(currency) is false, throw a
+            //    RangeError exception.
+            if !is_well_formed_currency_code(currency) {
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    format!("Invalid currency code: {currency}"),
+                    gc.into_nogc(),
+                ));
+            }
+        }
+    }
+    // 6. Let currencyDisplay be ? GetOption(options, "currencyDisplay",
+    //    string, « "code", "symbol", "narrowSymbol", "name" », "symbol").
+    let currency_display = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.currencyDisplay.into(),
+        CurrencyDisplay::VALUES,
+        Some(CurrencyDisplay::Symbol.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 7. Let currencySign be ? GetOption(options, "currencySign", string,
+    //    « "standard", "accounting" », "standard").
+    let currency_sign = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.currencySign.into(),
+        CurrencySign::VALUES,
+        Some(CurrencySign::Standard.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 8. Let unit be ? GetOption(options, "unit", string, empty, undefined).
+    let unit = get_free_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.unit.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    match &unit {
+        // 9. If unit is undefined, then
+        None => {
+            // a. If style is "unit", throw a TypeError exception.
+            if style == NumberFormatStyle::Unit {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Unit is required with unit style",
+                    gc.into_nogc(),
+                ));
+            }
+        }
+        // 10. Else,
+        Some(unit) => {
+            // a. If IsWellFormedUnitIdentifier(unit) is false, throw a
+            //    RangeError exception.
+            if !is_well_formed_unit_identifier(unit) {
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    format!("Invalid unit argument: {unit}"),
+                    gc.into_nogc(),
+                ));
+            }
+        }
+    }
+    // 11. Let unitDisplay be ? GetOption(options, "unitDisplay", string,
+    //     « "short", "narrow", "long" », "short").
+    let unit_display = get_string_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.unitDisplay.into(),
+        UnitDisplay::VALUES,
+        Some(UnitDisplay::Short.as_str()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap();
+    // 12. If style is "currency", then
+    if style == NumberFormatStyle::Currency {
+        // a. Set intlObj.[[Currency]] to the ASCII-uppercase of currency.
+        intl_obj.currency = currency.map(|currency| currency.to_ascii_uppercase().into());
+        // b. Set intlObj.[[CurrencyDisplay]] to currencyDisplay.
+        intl_obj.currency_display = CurrencyDisplay::from_option(currency_display);
+        // c. Set intlObj.[[CurrencySign]] to currencySign.
+        intl_obj.currency_sign = CurrencySign::from_option(currency_sign);
+    }
+    // 13. If style is "unit", then
+    if style == NumberFormatStyle::Unit {
+        // a. Set intlObj.[[Unit]] to unit.
+        intl_obj.unit = unit.map(|unit| unit.into());
+        // b. Set intlObj.[[UnitDisplay]] to unitDisplay.
+        intl_obj.unit_display = UnitDisplay::from_option(unit_display);
+    }
+    // 14. Return unused.
+    Ok(())
+}
+
+/// ### [6.3.1 IsWellFormedCurrencyCode ( currency )](https://tc39.es/ecma402/#sec-iswellformedcurrencycode)
+fn is_well_formed_currency_code(currency: &str) -> bool {
+    // 1. If the length of currency is not 3, return false.
+    // 2. Let normalized be the ASCII-uppercase of currency.
+    // 3. If normalized contains any code unit outside of 0x0041 through
+    //    0x005A (corresponding to Unicode characters LATIN CAPITAL LETTER A
+    //    through LATIN CAPITAL LETTER Z), return false.
+    // 4. Return true.
+    currency.len() == 3 && currency.bytes().all(|c| c.is_ascii_alphabetic())
+}
+
+/// ### [6.6.3 IsWellFormedUnitIdentifier ( unitIdentifier )](https://tc39.es/ecma402/#sec-iswellformedunitidentifier)
+fn is_well_formed_unit_identifier(unit_identifier: &str) -> bool {
+    // 1. If IsSanctionedSingleUnitIdentifier(unitIdentifier) is true, then
+    if sanctioned_unit_data(unit_identifier).is_some() {
+        // a. Return true.
+        return true;
+    }
+    // 2. Let i be StringIndexOf(unitIdentifier, "-per-", 0).
+    // 3. If i is not-found or StringIndexOf(unitIdentifier, "-per-", i + 1)
+    //    is not not-found, then
+    // a. Return false.
+    // 4. Assert: The five-character substring "-per-" occurs exactly once in
+    //    unitIdentifier, at index i.
+    // 5. Let numerator be the substring of unitIdentifier from 0 to i.
+    // 6. Let denominator be the substring of unitIdentifier from i + 5.
+    let mut parts = unit_identifier.split("-per-");
+    let (Some(numerator), Some(denominator), None) = (parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    // 7. If IsSanctionedSingleUnitIdentifier(numerator) and
+    //    IsSanctionedSingleUnitIdentifier(denominator) are both true, return
+    //    true.
+    // 8. Return false.
+    sanctioned_unit_data(numerator).is_some() && sanctioned_unit_data(denominator).is_some()
+}
@@ -0,0 +1,367 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinFunctionArgs, BuiltinGetter,
+            bound_function::bound_function_create,
+            create_builtin_function,
+            intl::{
+                abstract_operations::{
+                    create_parts_array, create_resolved_options_object, to_intl_mathematical_value,
+                },
+                number_format::NumberFormat,
+                number_format_objects::numeric_formatting::{
+                    IntlMathematicalValue, Notation, NumberFormatStyle, RoundingType, UseGrouping,
+                    partition_number_pattern, parts_to_string,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoFunction, IntoValue, PropertyKey, String, Value},
+    },
+    engine::context::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct NumberFormatPrototype;
+
+struct NumberFormatPrototypeGetFormat;
+impl Builtin for NumberFormatPrototypeGetFormat {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_format;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.format.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatPrototype::get_format);
+}
+impl BuiltinGetter for NumberFormatPrototypeGetFormat {}
+struct NumberFormatPrototypeFormatToParts;
+impl Builtin for NumberFormatPrototypeFormatToParts {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.formatToParts;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatPrototype::format_to_parts);
+}
+struct NumberFormatPrototypeResolvedOptions;
+impl Builtin for NumberFormatPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatPrototype::resolved_options);
+}
+
+impl NumberFormatPrototype {
+    /// ### [15.3.3 get Intl.NumberFormat.prototype.format](https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format)
+    fn get_format<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        // a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = require_number_format(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        if let Some(bound_format) = agent[nf].bound_format {
+            // 5. Return nf.[[BoundFormat]].
+            return Ok(bound_format.into_value().bind(gc.into_nogc()));
+        }
+        // a. Let F be a new built-in function object as defined in Number
+        //    Format Functions (15.5.2).
+        let f = create_builtin_function(
+            agent,
+            Behaviour::Regular(Self::number_format_function),
+            BuiltinFunctionArgs::new(1, ""),
+            gc.nogc(),
+        );
+        // b. Set F.[[NumberFormat]] to nf.
+        let scoped_nf = nf.scope(agent, gc.nogc());
+        let f = bound_function_create(
+            agent,
+            f.into_function().unbind(),
+            nf.into_value().unbind(),
+            &[],
+            gc,
+        )?;
+        agent[f].length = 1;
+        // c. Set nf.[[BoundFormat]] to F.
+        let nf = scoped_nf.get(agent);
+        agent[nf].bound_format = Some(f.unbind());
+        // 5. Return nf.[[BoundFormat]].
+        Ok(f.into_value())
+    }
+
+    /// ### [15.5.2 Number Format Functions](https://tc39.es/ecma402/#sec-number-format-functions)
+    ///
+    /// The number format is the this value of the bound format function.
+    fn number_format_function<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let nf be F.[[NumberFormat]].
+        // 2. Assert: nf is an Object and nf has an [[InitializedNumberFormat]]
+        //    internal slot.
+        let nf = NumberFormat::try_from(this_value)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 3. If value is not provided, let value be undefined.
+        // 4. Let x be ? ToIntlMathematicalValue(value).
+        let x = to_intl_mathematical_value(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 5. Return FormatNumeric(nf, x).
+        let result = format_numeric(agent, nf.get(agent), &x);
+        Ok(Value::from_string(agent, result, gc.into_nogc()))
+    }
+
+    /// ### [15.3.4 Intl.NumberFormat.prototype.formatToParts ( value )](https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts)
+    fn format_to_parts<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = require_number_format(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let x = to_intl_mathematical_value(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 4. Return FormatNumericToParts(nf, x).
+        let parts = partition_number_pattern(&agent[nf.get(agent)].record, &x);
+        // ### [15.5.8 FormatNumericToParts ( numberFormat, x )](https://tc39.es/ecma402/#sec-formatnumbertoparts)
+        // 1. Let parts be PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part of parts, do
+        // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+        // e. Increment n by 1.
+        // 5. Return result.
+        Ok(create_parts_array(
+            agent,
+            parts
+                .iter()
+                .map(|part| (part.r#type.as_str(), part.value.as_str())),
+            gc.into_nogc(),
+        )
+        .into_value())
+    }
+
+    /// ### [15.3.5 Intl.NumberFormat.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        // a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = require_number_format(agent, this_value, gc)?;
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 21, except the header row, in table
+        //    order, do
+        let data = &agent[nf];
+        let locale = data.locale.to_string();
+        let record = data.record.clone();
+        let digit_options = record.digit_options;
+        let has_fraction_digits = digit_options.rounding_type != RoundingType::SignificantDigits;
+        let has_significant_digits = digit_options.rounding_type != RoundingType::FractionDigits;
+        let string = |agent: &mut Agent, value: Option<&str>| match value {
+            Some(value) => Value::from_str(agent, value, gc),
+            None => Value::Undefined,
+        };
+        let properties = [
+            (
+                BUILTIN_STRING_MEMORY.locale,
+                Value::from_string(agent, locale, gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.numberingSystem,
+                Value::from_static_str(agent, "latn", gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.style,
+                Value::from_static_str(agent, record.style.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.currency,
+                string(agent, record.currency.as_deref()),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.currencyDisplay,
+                string(
+                    agent,
+                    (record.style == NumberFormatStyle::Currency)
+                        .then(|| record.currency_display.as_str()),
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.currencySign,
+                string(
+                    agent,
+                    (record.style == NumberFormatStyle::Currency)
+                        .then(|| record.currency_sign.as_str()),
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.unit,
+                string(agent, record.unit.as_deref()),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.unitDisplay,
+                string(
+                    agent,
+                    (record.style == NumberFormatStyle::Unit).then(|| record.unit_display.as_str()),
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumIntegerDigits,
+                Value::from(digit_options.minimum_integer_digits),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumFractionDigits,
+                has_fraction_digits
+                    .then(|| Value::from(digit_options.minimum_fraction_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.maximumFractionDigits,
+                has_fraction_digits
+                    .then(|| Value::from(digit_options.maximum_fraction_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumSignificantDigits,
+                has_significant_digits
+                    .then(|| Value::from(digit_options.minimum_significant_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.maximumSignificantDigits,
+                has_significant_digits
+                    .then(|| Value::from(digit_options.maximum_significant_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.useGrouping,
+                match record.use_grouping {
+                    UseGrouping::False => false.into(),
+                    use_grouping => Value::from_static_str(agent, use_grouping.as_str(), gc),
+                },
+            ),
+            (
+                BUILTIN_STRING_MEMORY.notation,
+                Value::from_static_str(agent, record.notation.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.compactDisplay,
+                string(
+                    agent,
+                    (record.notation == Notation::Compact).then(|| record.compact_display.as_str()),
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.signDisplay,
+                Value::from_static_str(agent, record.sign_display.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingIncrement,
+                Value::from(digit_options.rounding_increment),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingMode,
+                Value::from_static_str(agent, digit_options.rounding_mode.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingPriority,
+                Value::from_static_str(
+                    agent,
+                    digit_options.computed_rounding_priority.as_str(),
+                    gc,
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.trailingZeroDisplay,
+                Value::from_static_str(agent, digit_options.trailing_zero_display.as_str(), gc),
+            ),
+        ];
+        // 6. Return options.
+        Ok(create_resolved_options_object(agent, &properties, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.number_format_prototype();
+        let number_format_constructor = intrinsics.number_format();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(5)
+            .with_prototype(object_prototype)
+            .with_constructor_property(number_format_constructor)
+            .with_builtin_function_getter_property::<NumberFormatPrototypeGetFormat>()
+            .with_builtin_function_property::<NumberFormatPrototypeFormatToParts>()
+            .with_builtin_function_property::<NumberFormatPrototypeResolvedOptions>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_NumberFormat.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [15.5.6 FormatNumeric ( numberFormat, x )](https://tc39.es/ecma402/#sec-formatnumber)
+pub(crate) fn format_numeric(
+    agent: &Agent,
+    number_format: NumberFormat,
+    x: &IntlMathematicalValue,
+) -> std::string::String {
+    // 1. Let parts be PartitionNumberPattern(numberFormat, x).
+    let parts = partition_number_pattern(&agent[number_format].record, x);
+    // 2. Let result be the empty String.
+    // 3. For each Record { [[Type]], [[Value]] } part of parts, do
+    // a. Set result to the string-concatenation of result and part.[[Value]].
+    // 4. Return result.
+    parts_to_string(&parts)
+}
+
+#[inline(always)]
+fn require_number_format<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, NumberFormat<'a>> {
+    NumberFormat::try_from(value)
+        .map(|nf| nf.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not an Intl.NumberFormat object",
+                gc,
+            )
+        })
+}
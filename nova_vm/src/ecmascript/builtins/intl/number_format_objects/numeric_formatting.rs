@@ -0,0 +1,1348 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [15.5 Abstract Operations for NumberFormat Objects](https://tc39.es/ecma402/#sec-numberformat-abstracts)
+//!
+//! Locale-sensitive formatting of mathematical values. Numbers are formatted
+//! from an exact decimal representation so that rounding is performed on the
+//! mathematical value as the specification requires.
+
+use crate::{
+    ecmascript::builtins::intl::locale_data::{
+        self, CurrencyData, PluralCategory, UnitData, cardinal_plural_category,
+    },
+    engine::context::{Bindable, NoGcScope},
+};
+
+macro_rules! option_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub(crate) enum $name {
+            $($variant,)+
+        }
+
+        impl $name {
+            /// The valid option values, in the order of the variants.
+            pub(crate) const VALUES: &'static [&'static str] = &[$($value,)+];
+
+            pub(crate) fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+
+            /// Get the variant of a valid option value.
+            pub(crate) fn from_option(value: &str) -> Self {
+                const VARIANTS: &[$name] = &[$($name::$variant,)+];
+                let index = Self::VALUES.iter().position(|v| *v == value).unwrap();
+                VARIANTS[index]
+            }
+        }
+
+        // SAFETY: Trivially safe.
+        unsafe impl crate::engine::context::Bindable for $name {
+            type Of<'a> = $name;
+
+            #[inline(always)]
+            fn unbind(self) -> Self::Of<'static> {
+                self
+            }
+
+            #[inline(always)]
+            fn bind<'a>(self, _gc: crate::engine::context::NoGcScope<'a, '_>) -> Self::Of<'a> {
+                self
+            }
+        }
+    };
+}
+pub(crate) use option_enum;
+
+option_enum!(
+    /// \[\[Style]]
+    NumberFormatStyle {
+        Decimal => "decimal",
+        Percent => "percent",
+        Currency => "currency",
+        Unit => "unit",
+    }
+);
+
+option_enum!(
+    /// \[\[CurrencyDisplay]]
+    CurrencyDisplay {
+        Code => "code",
+        Symbol => "symbol",
+        NarrowSymbol => "narrowSymbol",
+        Name => "name",
+    }
+);
+
+option_enum!(
+    /// \[\[CurrencySign]]
+    CurrencySign {
+        Standard => "standard",
+        Accounting => "accounting",
+    }
+);
+
+option_enum!(
+    /// \[\[UnitDisplay]]
+    UnitDisplay {
+        Short => "short",
+        Narrow => "narrow",
+        Long => "long",
+    }
+);
+
+option_enum!(
+    /// \[\[Notation]]
+    Notation {
+        Standard => "standard",
+        Scientific => "scientific",
+        Engineering => "engineering",
+        Compact => "compact",
+    }
+);
+
+option_enum!(
+    /// \[\[CompactDisplay]]
+    CompactDisplay {
+        Short => "short",
+        Long => "long",
+    }
+);
+
+option_enum!(
+    /// \[\[UseGrouping]]
+    UseGrouping {
+        Always => "always",
+        Auto => "auto",
+        Min2 => "min2",
+        False => "false",
+    }
+);
+
+option_enum!(
+    /// \[\[SignDisplay]]
+    SignDisplay {
+        Auto => "auto",
+        Never => "never",
+        Always => "always",
+        ExceptZero => "exceptZero",
+        Negative => "negative",
+    }
+);
+
+option_enum!(
+    /// \[\[RoundingMode]]
+    RoundingMode {
+        Ceil => "ceil",
+        Floor => "floor",
+        Expand => "expand",
+        Trunc => "trunc",
+        HalfCeil => "halfCeil",
+        HalfFloor => "halfFloor",
+        HalfExpand => "halfExpand",
+        HalfTrunc => "halfTrunc",
+        HalfEven => "halfEven",
+    }
+);
+
+option_enum!(
+    /// \[\[ComputedRoundingPriority]]
+    RoundingPriority {
+        Auto => "auto",
+        MorePrecision => "morePrecision",
+        LessPrecision => "lessPrecision",
+    }
+);
+
+option_enum!(
+    /// \[\[TrailingZeroDisplay]]
+    TrailingZeroDisplay {
+        Auto => "auto",
+        StripIfInteger => "stripIfInteger",
+    }
+);
+
+/// \[\[RoundingType]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingType {
+    FractionDigits,
+    SignificantDigits,
+    MorePrecision,
+    LessPrecision,
+}
+
+/// The rounding settings of an object that formats numbers, as set by
+/// SetNumberFormatDigitOptions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DigitOptions {
+    pub(crate) minimum_integer_digits: u8,
+    pub(crate) minimum_fraction_digits: u8,
+    pub(crate) maximum_fraction_digits: u8,
+    pub(crate) minimum_significant_digits: u8,
+    pub(crate) maximum_significant_digits: u8,
+    pub(crate) rounding_type: RoundingType,
+    pub(crate) computed_rounding_priority: RoundingPriority,
+    pub(crate) rounding_increment: u16,
+    pub(crate) rounding_mode: RoundingMode,
+    pub(crate) trailing_zero_display: TrailingZeroDisplay,
+}
+
+// SAFETY: Trivially safe.
+unsafe impl Bindable for DigitOptions {
+    type Of<'a> = DigitOptions;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        self
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        self
+    }
+}
+
+impl Default for DigitOptions {
+    fn default() -> Self {
+        Self {
+            minimum_integer_digits: 1,
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 3,
+            minimum_significant_digits: 1,
+            maximum_significant_digits: 21,
+            rounding_type: RoundingType::FractionDigits,
+            computed_rounding_priority: RoundingPriority::Auto,
+            rounding_increment: 1,
+            rounding_mode: RoundingMode::HalfExpand,
+            trailing_zero_display: TrailingZeroDisplay::Auto,
+        }
+    }
+}
+
+/// The resolved options of an Intl.NumberFormat object that are used in
+/// formatting.
+#[derive(Debug, Clone)]
+pub(crate) struct NumberFormatRecord {
+    pub(crate) style: NumberFormatStyle,
+    /// Upper case ISO 4217 currency code.
+    pub(crate) currency: Option<Box<str>>,
+    pub(crate) currency_display: CurrencyDisplay,
+    pub(crate) currency_sign: CurrencySign,
+    /// Lower case unit identifier.
+    pub(crate) unit: Option<Box<str>>,
+    pub(crate) unit_display: UnitDisplay,
+    pub(crate) notation: Notation,
+    pub(crate) compact_display: CompactDisplay,
+    pub(crate) use_grouping: UseGrouping,
+    pub(crate) sign_display: SignDisplay,
+    pub(crate) digit_options: DigitOptions,
+}
+
+impl Default for NumberFormatRecord {
+    fn default() -> Self {
+        Self {
+            style: NumberFormatStyle::Decimal,
+            currency: None,
+            currency_display: CurrencyDisplay::Symbol,
+            currency_sign: CurrencySign::Standard,
+            unit: None,
+            unit_display: UnitDisplay::Short,
+            notation: Notation::Standard,
+            compact_display: CompactDisplay::Short,
+            use_grouping: UseGrouping::Auto,
+            sign_display: SignDisplay::Auto,
+            digit_options: DigitOptions::default(),
+        }
+    }
+}
+
+/// A finite decimal number `0.d₁d₂…dₙ × 10^exponent`.
+///
+/// The digits have no leading or trailing zeros; zero has no digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    pub(crate) negative: bool,
+    digits: Vec<u8>,
+    exponent: i32,
+}
+
+/// An Intl mathematical value: a decimal number, negative zero, or one of
+/// the special Number values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum IntlMathematicalValue {
+    NaN,
+    Infinity {
+        negative: bool,
+    },
+    /// Negative zero is a Decimal zero with the negative flag set.
+    Finite(Decimal),
+}
+
+// SAFETY: Trivially safe.
+unsafe impl Bindable for IntlMathematicalValue {
+    type Of<'a> = IntlMathematicalValue;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        self
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        self
+    }
+}
+
+impl Decimal {
+    pub(crate) fn zero() -> Self {
+        Self {
+            negative: false,
+            digits: Vec::new(),
+            exponent: 0,
+        }
+    }
+
+    fn new(negative: bool, mut digits: Vec<u8>, mut exponent: i32) -> Self {
+        let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+        digits.drain(..leading_zeros);
+        exponent -= leading_zeros as i32;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            exponent = 0;
+        }
+        Self {
+            negative,
+            digits,
+            exponent,
+        }
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Returns true if the number has no fractional part.
+    pub(crate) fn is_integer(&self) -> bool {
+        self.digits.len() as i32 <= self.exponent || self.is_zero()
+    }
+
+    /// The exponent of the most significant digit, ie. floor(log10(|x|)).
+    fn magnitude(&self) -> i32 {
+        debug_assert!(!self.is_zero());
+        self.exponent - 1
+    }
+
+    /// Multiply the number by 10^power.
+    pub(crate) fn scale(&self, power: i32) -> Self {
+        let mut result = self.clone();
+        if !result.is_zero() {
+            result.exponent += power;
+        }
+        result
+    }
+
+    /// Parse a StrDecimalLiteral with an optional sign, such as "-1.5e-7" or
+    /// "123". Returns None for any other syntax.
+    pub(crate) fn parse(literal: &str) -> Option<Self> {
+        let (negative, literal) = match literal.as_bytes().first() {
+            Some(b'-') => (true, &literal[1..]),
+            Some(b'+') => (false, &literal[1..]),
+            _ => (false, literal),
+        };
+        let (mantissa, exponent) = match literal.find(['e', 'E']) {
+            Some(index) => {
+                let exponent = &literal[index + 1..];
+                let exponent_digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if exponent_digits.is_empty()
+                    || !exponent_digits.bytes().all(|b| b.is_ascii_digit())
+                {
+                    return None;
+                }
+                // Saturate huge exponents; they are not representable anyway.
+                let exponent = exponent
+                    .parse::<i64>()
+                    .unwrap_or(if exponent.starts_with('-') {
+                        i64::MIN
+                    } else {
+                        i64::MAX
+                    });
+                (&literal[..index], exponent.clamp(-100_000, 100_000) as i32)
+            }
+            None => (literal, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let digits = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .map(|b| b - b'0')
+            .collect::<Vec<_>>();
+        Some(Self::new(negative, digits, integer.len() as i32 + exponent))
+    }
+
+    /// Round the number to an integer multiple of increment × 10^magnitude.
+    fn round(&self, magnitude: i32, increment: u32, mode: UnsignedRoundingMode) -> Self {
+        // The number of digits at or above the rounding magnitude.
+        let kept = self.exponent as i64 - magnitude as i64;
+        let mut quotient: Vec<u8> = if kept <= 0 {
+            Vec::new()
+        } else {
+            let kept = kept as usize;
+            let mut quotient = self.digits[..kept.min(self.digits.len())].to_vec();
+            quotient.resize(kept, 0);
+            quotient
+        };
+        // The remaining digits form the fraction of a unit of 10^magnitude.
+        let remainder = if kept < 0 {
+            // The fraction is less than 0.1, and thus less than half.
+            if self.is_zero() {
+                Fraction::Zero
+            } else {
+                Fraction::LessThanHalf
+            }
+        } else {
+            Fraction::of(&self.digits[(kept as usize).min(self.digits.len())..])
+        };
+        let modulo = digits_modulo(&quotient, increment);
+        if modulo == 0 && remainder == Fraction::Zero {
+            let length = quotient.len() as i32;
+            return Self::new(self.negative, quotient, magnitude + length);
+        }
+        // Compare the distance from the lower candidate with half of the
+        // increment, ie. 2 × (modulo + fraction) with increment.
+        let doubled = 2 * modulo;
+        let half = if doubled > increment {
+            core::cmp::Ordering::Greater
+        } else if doubled == increment {
+            if remainder == Fraction::Zero {
+                core::cmp::Ordering::Equal
+            } else {
+                core::cmp::Ordering::Greater
+            }
+        } else if doubled + 1 == increment {
+            match remainder {
+                Fraction::Zero | Fraction::LessThanHalf => core::cmp::Ordering::Less,
+                Fraction::Half => core::cmp::Ordering::Equal,
+                Fraction::MoreThanHalf => core::cmp::Ordering::Greater,
+            }
+        } else {
+            core::cmp::Ordering::Less
+        };
+        let lower_is_even = digits_modulo(&quotient, 2 * increment) < increment;
+        let round_up = match mode {
+            UnsignedRoundingMode::Infinity => true,
+            UnsignedRoundingMode::Zero => false,
+            UnsignedRoundingMode::HalfInfinity => half != core::cmp::Ordering::Less,
+            UnsignedRoundingMode::HalfZero => half == core::cmp::Ordering::Greater,
+            UnsignedRoundingMode::HalfEven => match half {
+                core::cmp::Ordering::Less => false,
+                core::cmp::Ordering::Equal => !lower_is_even,
+                core::cmp::Ordering::Greater => true,
+            },
+        };
+        digits_sub_small(&mut quotient, modulo);
+        if round_up {
+            digits_add_small(&mut quotient, increment);
+        }
+        let length = quotient.len() as i32;
+        Self::new(self.negative, quotient, magnitude + length)
+    }
+
+    /// The number of digits in the integer part, at least 1.
+    fn integer_digits_count(&self) -> usize {
+        self.exponent.max(1) as usize
+    }
+
+    /// Format the absolute value of the number in positional notation with at
+    /// least the given number of fraction digits.
+    fn to_positional_string(&self, minimum_fraction_digits: usize) -> String {
+        let mut result = String::new();
+        if self.exponent <= 0 {
+            result.push('0');
+        } else {
+            for i in 0..self.exponent as usize {
+                result.push((b'0' + self.digits.get(i).copied().unwrap_or(0)) as char);
+            }
+        }
+        let fraction_length = (self.digits.len() as i32 - self.exponent).max(0) as usize;
+        let fraction_length = fraction_length.max(minimum_fraction_digits);
+        if fraction_length > 0 {
+            result.push('.');
+            for i in 0..fraction_length as i32 {
+                let index = self.exponent + i;
+                let digit = if index < 0 {
+                    0
+                } else {
+                    self.digits.get(index as usize).copied().unwrap_or(0)
+                };
+                result.push((b'0' + digit) as char);
+            }
+        }
+        result
+    }
+}
+
+/// The fraction of a rounding unit that is discarded in rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fraction {
+    Zero,
+    LessThanHalf,
+    Half,
+    MoreThanHalf,
+}
+
+impl Fraction {
+    fn of(digits: &[u8]) -> Self {
+        match digits.first() {
+            None => Self::Zero,
+            Some(&d) if d < 5 => Self::LessThanHalf,
+            Some(&5) if digits.len() == 1 => Self::Half,
+            Some(_) => Self::MoreThanHalf,
+        }
+    }
+}
+
+fn digits_modulo(digits: &[u8], divisor: u32) -> u32 {
+    digits
+        .iter()
+        .fold(0, |acc, &d| (acc * 10 + u32::from(d)) % divisor)
+}
+
+fn digits_sub_small(digits: &mut [u8], mut value: u32) {
+    for digit in digits.iter_mut().rev() {
+        if value == 0 {
+            break;
+        }
+        let subtrahend = value % 10;
+        value /= 10;
+        if u32::from(*digit) >= subtrahend {
+            *digit -= subtrahend as u8;
+        } else {
+            *digit = (u32::from(*digit) + 10 - subtrahend) as u8;
+            value += 1;
+        }
+    }
+}
+
+fn digits_add_small(digits: &mut Vec<u8>, mut value: u32) {
+    for digit in digits.iter_mut().rev() {
+        if value == 0 {
+            return;
+        }
+        let sum = u32::from(*digit) + value % 10;
+        value /= 10;
+        *digit = (sum % 10) as u8;
+        value += sum / 10;
+    }
+    while value > 0 {
+        digits.insert(0, (value % 10) as u8);
+        value /= 10;
+    }
+}
+
+/// The unsigned rounding modes of
+/// [Table 22](https://tc39.es/ecma402/#table-intl-unsigned-rounding-modes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnsignedRoundingMode {
+    Infinity,
+    Zero,
+    HalfInfinity,
+    HalfZero,
+    HalfEven,
+}
+
+/// ### [15.5.18 GetUnsignedRoundingMode ( roundingMode, sign )](https://tc39.es/ecma402/#sec-getunsignedroundingmode)
+fn get_unsigned_rounding_mode(rounding_mode: RoundingMode, negative: bool) -> UnsignedRoundingMode {
+    match (rounding_mode, negative) {
+        (RoundingMode::Ceil, false) | (RoundingMode::Floor, true) | (RoundingMode::Expand, _) => {
+            UnsignedRoundingMode::Infinity
+        }
+        (RoundingMode::Ceil, true) | (RoundingMode::Floor, false) | (RoundingMode::Trunc, _) => {
+            UnsignedRoundingMode::Zero
+        }
+        (RoundingMode::HalfCeil, false)
+        | (RoundingMode::HalfFloor, true)
+        | (RoundingMode::HalfExpand, _) => UnsignedRoundingMode::HalfInfinity,
+        (RoundingMode::HalfCeil, true)
+        | (RoundingMode::HalfFloor, false)
+        | (RoundingMode::HalfTrunc, _) => UnsignedRoundingMode::HalfZero,
+        (RoundingMode::HalfEven, _) => UnsignedRoundingMode::HalfEven,
+    }
+}
+
+/// The Record returned by ToRawPrecision and ToRawFixed.
+struct RawFormatResult {
+    formatted_string: String,
+    rounded_number: Decimal,
+    rounding_magnitude: i32,
+}
+
+/// ### [15.5.8 ToRawPrecision ( x, minPrecision, maxPrecision, unsignedRoundingMode )](https://tc39.es/ecma402/#sec-torawprecision)
+fn to_raw_precision(
+    x: &Decimal,
+    min_precision: u8,
+    max_precision: u8,
+    unsigned_rounding_mode: UnsignedRoundingMode,
+) -> RawFormatResult {
+    let min_precision = i32::from(min_precision);
+    let max_precision = i32::from(max_precision);
+    // 1. Let p be maxPrecision.
+    // 2. If x = 0, then
+    if x.is_zero() {
+        // a. Let m be the String value consisting of p occurrences of the
+        //    code unit 0x0030 (DIGIT ZERO).
+        // b. Let e be 0.
+        // c. Let xFinal be 0.
+        // 11. If m contains the code unit 0x002E (FULL STOP) and
+        //     maxPrecision > minPrecision, then cut trailing zeroes.
+        return RawFormatResult {
+            formatted_string: x.to_positional_string((min_precision - 1) as usize),
+            rounded_number: x.clone(),
+            rounding_magnitude: 1 - max_precision,
+        };
+    }
+    // 3. Else,
+    // a. Let n1 and e1 each be an integer and r1 a mathematical value, with
+    //    r1 = ToRawPrecisionFn(n1, e1, p), such that r1 ≤ x and r1 is
+    //    maximized.
+    // b. Let n2 and e2 each be an integer and r2 a mathematical value, with
+    //    r2 = ToRawPrecisionFn(n2, e2, p), such that r2 ≥ x and r2 is
+    //    minimized.
+    // c. Let r be ApplyUnsignedRoundingMode(x, r1, r2, unsignedRoundingMode).
+    let rounded = x.round(x.magnitude() - max_precision + 1, 1, unsigned_rounding_mode);
+    // d. If r is r2, then let e be e2, else let e be e1.
+    let e = rounded.magnitude();
+    // 4-10. Format m as positional notation with e + 1 integer digits.
+    // 11. If m contains the code unit 0x002E (FULL STOP) and maxPrecision >
+    //     minPrecision, then
+    // a. Let cut be maxPrecision – minPrecision.
+    // b. Repeat, while cut > 0 and the last code unit of m is 0x0030 (DIGIT ZERO),
+    // i. Remove the last code unit from m.
+    // ii. Decrease cut by 1.
+    // c. If the last code unit of m is 0x002E (FULL STOP), then
+    // i. Remove the last code unit from m.
+    let minimum_fraction_digits = (min_precision - 1 - e).max(0) as usize;
+    // 12. Return the Record { [[FormattedString]]: m,
+    //     [[RoundedNumber]]: xFinal, [[IntegerDigitsCount]]: int,
+    //     [[RoundingMagnitude]]: e–p+1 }.
+    RawFormatResult {
+        formatted_string: rounded.to_positional_string(minimum_fraction_digits),
+        rounding_magnitude: e - max_precision + 1,
+        rounded_number: rounded,
+    }
+}
+
+/// ### [15.5.10 ToRawFixed ( x, minFraction, maxFraction, roundingIncrement, unsignedRoundingMode )](https://tc39.es/ecma402/#sec-torawfixed)
+fn to_raw_fixed(
+    x: &Decimal,
+    min_fraction: u8,
+    max_fraction: u8,
+    rounding_increment: u16,
+    unsigned_rounding_mode: UnsignedRoundingMode,
+) -> RawFormatResult {
+    // 1. Let f be maxFraction.
+    let f = i32::from(max_fraction);
+    // 2. Let n1 be an integer and r1 a mathematical value, with r1 =
+    //    ToRawFixedFn(n1, f), such that n1 modulo roundingIncrement = 0,
+    //    r1 ≤ x, and r1 is maximized.
+    // 3. Let n2 be an integer and r2 a mathematical value, with r2 =
+    //    ToRawFixedFn(n2, f), such that n2 modulo roundingIncrement = 0,
+    //    r2 ≥ x, and r2 is minimized.
+    // 4. Let r be ApplyUnsignedRoundingMode(x, r1, r2, unsignedRoundingMode).
+    let rounded = x.round(-f, u32::from(rounding_increment), unsigned_rounding_mode);
+    // 5-10. Let m be the String consisting of the digits of the decimal
+    //       representation of n, with a decimal point inserted f digits from
+    //       the end.
+    // 11. Let cut be maxFraction – minFraction.
+    // 12. Repeat, while cut > 0 and the last code unit of m is 0x0030 (DIGIT ZERO),
+    // a. Remove the last code unit from m.
+    // b. Decrease cut by 1.
+    // 13. If the last code unit of m is 0x002E (FULL STOP), then
+    // a. Remove the last code unit from m.
+    // 14. Return the Record { [[FormattedString]]: m,
+    //     [[RoundedNumber]]: xFinal, [[IntegerDigitsCount]]: int,
+    //     [[RoundingMagnitude]]: –f }.
+    RawFormatResult {
+        formatted_string: rounded.to_positional_string(usize::from(min_fraction)),
+        rounded_number: rounded,
+        rounding_magnitude: -f,
+    }
+}
+
+/// The Record returned by FormatNumericToString.
+pub(crate) struct FormatNumericResult {
+    /// The rounded number, with the sign of the input.
+    pub(crate) rounded_number: Decimal,
+    /// The formatted absolute value of the rounded number.
+    pub(crate) formatted_string: String,
+}
+
+/// ### [15.5.3 FormatNumericToString ( intlObject, x )](https://tc39.es/ecma402/#sec-formatnumberstring)
+///
+/// The sign of the input is not included in the formatted string.
+pub(crate) fn format_numeric_to_string(options: &DigitOptions, x: &Decimal) -> FormatNumericResult {
+    // 1. Assert: x is a mathematical value or negative-zero.
+    // 2. Let isNegative be false.
+    // 3. If x is negative-zero, then
+    // a. Set isNegative to true.
+    // b. Set x to 0.
+    // 4. Else,
+    // a. Assert: x is a mathematical value.
+    // b. If x < 0, let isNegative be true; else let isNegative be false.
+    // c. If isNegative is true, then
+    // i. Set x to -x.
+    let is_negative = x.negative;
+    // 5. Let unsignedRoundingMode be GetUnsignedRoundingMode(intlObject.[[RoundingMode]], isNegative).
+    let unsigned_rounding_mode = get_unsigned_rounding_mode(options.rounding_mode, is_negative);
+    let significant = || {
+        to_raw_precision(
+            x,
+            options.minimum_significant_digits,
+            options.maximum_significant_digits,
+            unsigned_rounding_mode,
+        )
+    };
+    let fixed = || {
+        to_raw_fixed(
+            x,
+            options.minimum_fraction_digits,
+            options.maximum_fraction_digits,
+            options.rounding_increment,
+            unsigned_rounding_mode,
+        )
+    };
+    let result = match options.rounding_type {
+        // 6. If intlObject.[[RoundingType]] is significantDigits, then
+        // a. Let result be ToRawPrecision(x, intlObject.[[MinimumSignificantDigits]],
+        //    intlObject.[[MaximumSignificantDigits]], unsignedRoundingMode).
+        RoundingType::SignificantDigits => significant(),
+        // 7. Else if intlObject.[[RoundingType]] is fractionDigits, then
+        // a. Let result be ToRawFixed(x, intlObject.[[MinimumFractionDigits]],
+        //    intlObject.[[MaximumFractionDigits]], intlObject.[[RoundingIncrement]],
+        //    unsignedRoundingMode).
+        RoundingType::FractionDigits => fixed(),
+        // 8. Else,
+        // a. Let sResult be ToRawPrecision(x, intlObject.[[MinimumSignificantDigits]],
+        //    intlObject.[[MaximumSignificantDigits]], unsignedRoundingMode).
+        // b. Let fResult be ToRawFixed(x, intlObject.[[MinimumFractionDigits]],
+        //    intlObject.[[MaximumFractionDigits]], intlObject.[[RoundingIncrement]],
+        //    unsignedRoundingMode).
+        RoundingType::MorePrecision | RoundingType::LessPrecision => {
+            let s_result = significant();
+            let f_result = fixed();
+            let s_is_more_precise = s_result.rounding_magnitude <= f_result.rounding_magnitude;
+            // c. If intlObject.[[RoundingType]] is morePrecision, then
+            // i. If sResult.[[RoundingMagnitude]] ≤ fResult.[[RoundingMagnitude]], then
+            // 1. Let fixedIsMorePrecise be false.
+            // d. Else,
+            // i. Assert: intlObject.[[RoundingType]] is lessPrecision.
+            // ii. If sResult.[[RoundingMagnitude]] ≤ fResult.[[RoundingMagnitude]], then
+            // 1. Let fixedIsMorePrecise be true.
+            if s_is_more_precise == (options.rounding_type == RoundingType::MorePrecision) {
+                s_result
+            } else {
+                f_result
+            }
+        }
+    };
+    // 9. Set x to result.[[RoundedNumber]].
+    let mut x = result.rounded_number;
+    // 10. Let string be result.[[FormattedString]].
+    let mut string = result.formatted_string;
+    // 11. If intlObject.[[TrailingZeroDisplay]] is "stripIfInteger" and x
+    //     modulo 1 = 0, then
+    if options.trailing_zero_display == TrailingZeroDisplay::StripIfInteger && x.is_integer() {
+        // a. Let i be StringIndexOf(string, ".", 0).
+        // b. If i ≠ not-found, set string to the substring of string from 0 to i.
+        if let Some(i) = string.find('.') {
+            string.truncate(i);
+        }
+    }
+    // 12. Let int be result.[[IntegerDigitsCount]].
+    let int = x.integer_digits_count();
+    // 13. Let minInteger be intlObject.[[MinimumIntegerDigits]].
+    let min_integer = usize::from(options.minimum_integer_digits);
+    // 14. If int < minInteger, then
+    if int < min_integer {
+        // a. Let forwardZeros be the String consisting of minInteger - int
+        //    occurrences of the code unit 0x0030 (DIGIT ZERO).
+        // b. Set string to the string-concatenation of forwardZeros and string.
+        let forward_zeros = "0".repeat(min_integer - int);
+        string.insert_str(0, &forward_zeros);
+    }
+    // 15. If isNegative is true, then
+    // a. If x is 0, set x to negative-zero. Otherwise, set x to -x.
+    x.negative = is_negative;
+    // 16. Return the Record { [[RoundedNumber]]: x, [[FormattedString]]: string }.
+    FormatNumericResult {
+        rounded_number: x,
+        formatted_string: string,
+    }
+}
+
+/// The types of the parts produced by PartitionNumberPattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumberPartType {
+    Compact,
+    Currency,
+    Decimal,
+    ExponentInteger,
+    ExponentMinusSign,
+    ExponentSeparator,
+    Fraction,
+    Group,
+    Infinity,
+    Integer,
+    Literal,
+    MinusSign,
+    Nan,
+    PercentSign,
+    PlusSign,
+    Unit,
+}
+
+impl NumberPartType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Compact => "compact",
+            Self::Currency => "currency",
+            Self::Decimal => "decimal",
+            Self::ExponentInteger => "exponentInteger",
+            Self::ExponentMinusSign => "exponentMinusSign",
+            Self::ExponentSeparator => "exponentSeparator",
+            Self::Fraction => "fraction",
+            Self::Group => "group",
+            Self::Infinity => "infinity",
+            Self::Integer => "integer",
+            Self::Literal => "literal",
+            Self::MinusSign => "minusSign",
+            Self::Nan => "nan",
+            Self::PercentSign => "percentSign",
+            Self::PlusSign => "plusSign",
+            Self::Unit => "unit",
+        }
+    }
+}
+
+/// A part of a formatted number: a Record with fields \[\[Type]] and \[\[Value]].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NumberPart {
+    pub(crate) r#type: NumberPartType,
+    pub(crate) value: String,
+}
+
+fn part(r#type: NumberPartType, value: &str) -> NumberPart {
+    NumberPart {
+        r#type,
+        value: value.to_string(),
+    }
+}
+
+/// Concatenate the values of formatted parts.
+pub(crate) fn parts_to_string(parts: &[NumberPart]) -> String {
+    parts.iter().map(|part| part.value.as_str()).collect()
+}
+
+/// ### [15.5.5 ComputeExponent ( numberFormat, x )](https://tc39.es/ecma402/#sec-computeexponent)
+fn compute_exponent(record: &NumberFormatRecord, x: &Decimal) -> i32 {
+    // 1. If x = 0, then
+    if x.is_zero() {
+        // a. Return 0.
+        return 0;
+    }
+    // 2. If x < 0, then
+    // a. Let x = -x.
+    // 3. Let magnitude be the base 10 logarithm of x rounded down to the
+    //    nearest integer.
+    let magnitude = x.magnitude();
+    // 4. Let exponent be ComputeExponentForMagnitude(numberFormat, magnitude).
+    let exponent = compute_exponent_for_magnitude(record, magnitude);
+    // 5. Let x be x × 10^(-exponent).
+    let x = x.scale(-exponent);
+    // 6. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+    let format_number_result = format_numeric_to_string(&record.digit_options, &x);
+    // 7. If formatNumberResult.[[RoundedNumber]] = 0, then
+    if format_number_result.rounded_number.is_zero() {
+        // a. Return exponent.
+        return exponent;
+    }
+    // 8. Let newMagnitude be the base 10 logarithm of
+    //    formatNumberResult.[[RoundedNumber]] rounded down to the nearest
+    //    integer.
+    let new_magnitude = format_number_result.rounded_number.magnitude();
+    // 9. If newMagnitude is magnitude – exponent, then
+    if new_magnitude == magnitude - exponent {
+        // a. Return exponent.
+        return exponent;
+    }
+    // 10. Return ComputeExponentForMagnitude(numberFormat, magnitude + 1).
+    compute_exponent_for_magnitude(record, magnitude + 1)
+}
+
+/// ### [15.5.6 ComputeExponentForMagnitude ( numberFormat, magnitude )](https://tc39.es/ecma402/#sec-computeexponentformagnitude)
+fn compute_exponent_for_magnitude(record: &NumberFormatRecord, magnitude: i32) -> i32 {
+    // 1. Let notation be numberFormat.[[Notation]].
+    match record.notation {
+        // 2. If notation is "scientific", then
+        // a. Return magnitude.
+        Notation::Scientific => magnitude,
+        // 3. Else if notation is "engineering", then
+        // a. Let thousands be the greatest integer that is not greater than
+        //    magnitude / 3.
+        // b. Return thousands × 3.
+        Notation::Engineering => magnitude.div_euclid(3) * 3,
+        // 4. Else if notation is "compact", then
+        // a. Let exponent be an implementation- and locale-dependent (ILD)
+        //    integer by which to scale a number of the given magnitude in
+        //    compact notation for the current locale.
+        // b. Return exponent.
+        Notation::Compact => locale_data::COMPACT_PATTERNS
+            .iter()
+            .rev()
+            .map(|(exponent, _, _)| *exponent)
+            .find(|exponent| *exponent <= magnitude)
+            .unwrap_or(0),
+        // 5. Else,
+        // a. Assert: notation is "standard".
+        // b. Return 0.
+        Notation::Standard => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    None,
+    Plus,
+    Minus,
+}
+
+/// ### [15.5.11 GetNumberFormatPattern ( intlObject, x )](https://tc39.es/ecma402/#sec-getnumberformatpattern)
+///
+/// Returns the sign that the pattern for the rounded number uses.
+fn get_sign(sign_display: SignDisplay, x: &IntlMathematicalValue) -> Sign {
+    let (is_nan, is_zero, is_negative) = match x {
+        IntlMathematicalValue::NaN => (true, false, false),
+        IntlMathematicalValue::Infinity { negative } => (false, false, *negative),
+        IntlMathematicalValue::Finite(x) => (false, x.is_zero(), x.negative),
+    };
+    match sign_display {
+        // 9. If signDisplay is "never", then
+        // a. Let pattern be patterns.[[zeroPattern]].
+        SignDisplay::Never => Sign::None,
+        // 10. Else if signDisplay is "auto", then
+        // a. If x is 0 or x > 0 or x is NaN, then
+        // i. Let pattern be patterns.[[zeroPattern]].
+        // b. Else,
+        // i. Let pattern be patterns.[[negativePattern]].
+        SignDisplay::Auto => {
+            if is_negative {
+                Sign::Minus
+            } else {
+                Sign::None
+            }
+        }
+        // 11. Else if signDisplay is "always", then
+        // a. If x is 0 or x > 0 or x is NaN, then
+        // i. Let pattern be patterns.[[positivePattern]].
+        // b. Else,
+        // i. Let pattern be patterns.[[negativePattern]].
+        SignDisplay::Always => {
+            if is_negative {
+                Sign::Minus
+            } else {
+                Sign::Plus
+            }
+        }
+        // 12. Else if signDisplay is "exceptZero", then
+        // a. If x is NaN or x is 0, then
+        // i. Let pattern be patterns.[[zeroPattern]].
+        // b. Else if x > 0, then
+        // i. Let pattern be patterns.[[positivePattern]].
+        // c. Else,
+        // i. Let pattern be patterns.[[negativePattern]].
+        SignDisplay::ExceptZero => {
+            if is_nan || is_zero {
+                Sign::None
+            } else if is_negative {
+                Sign::Minus
+            } else {
+                Sign::Plus
+            }
+        }
+        // 13. Else,
+        // a. Assert: signDisplay is "negative".
+        // b. If x is 0 or x is negative-zero or x > 0 or x is NaN, then
+        // i. Let pattern be patterns.[[zeroPattern]].
+        // c. Else,
+        // i. Let pattern be patterns.[[negativePattern]].
+        SignDisplay::Negative => {
+            if is_negative && !is_zero {
+                Sign::Minus
+            } else {
+                Sign::None
+            }
+        }
+    }
+}
+
+/// Returns true if the integer digits should be grouped.
+fn use_grouping(strategy: UseGrouping, integer_digits: usize) -> bool {
+    match strategy {
+        UseGrouping::False => false,
+        UseGrouping::Always => integer_digits > locale_data::GROUPING_SIZE,
+        UseGrouping::Auto => {
+            integer_digits >= locale_data::GROUPING_SIZE + locale_data::MINIMUM_GROUPING_DIGITS
+        }
+        UseGrouping::Min2 => integer_digits >= locale_data::GROUPING_SIZE + 2,
+    }
+}
+
+/// The currency display name or symbol of a currency code.
+fn currency_display_string(
+    currency: &str,
+    display: CurrencyDisplay,
+    plural_category: PluralCategory,
+) -> &str {
+    let data: Option<&'static CurrencyData> = locale_data::currency_data(currency);
+    match (display, data) {
+        (CurrencyDisplay::Code, _) | (_, None) => currency,
+        (CurrencyDisplay::Symbol, Some(data)) => data.symbol,
+        (CurrencyDisplay::NarrowSymbol, Some(data)) => data.narrow_symbol,
+        (CurrencyDisplay::Name, Some(data)) => {
+            if plural_category == PluralCategory::One {
+                data.name_one
+            } else {
+                data.name_other
+            }
+        }
+    }
+}
+
+/// Returns the unit pattern with a `{0}` placeholder for a unit identifier.
+fn unit_pattern(unit: &str, display: UnitDisplay, plural_category: PluralCategory) -> String {
+    let select = |data: &UnitData| -> &'static str {
+        match (display, plural_category) {
+            (UnitDisplay::Long, PluralCategory::One) => data.long_one,
+            (UnitDisplay::Long, _) => data.long_other,
+            (UnitDisplay::Short, PluralCategory::One) => data.short_one,
+            (UnitDisplay::Short, _) => data.short_other,
+            (UnitDisplay::Narrow, _) => data.narrow,
+        }
+    };
+    if let Some(data) =
+        locale_data::sanctioned_unit_data(unit).or_else(|| locale_data::compound_unit_data(unit))
+    {
+        return select(data).to_string();
+    }
+    if let Some((numerator, denominator)) = unit.split_once("-per-") {
+        if let (Some(numerator), Some(denominator)) = (
+            locale_data::sanctioned_unit_data(numerator),
+            locale_data::sanctioned_unit_data(denominator),
+        ) {
+            let denominator_name = match display {
+                UnitDisplay::Long => denominator.long_one,
+                UnitDisplay::Short => denominator.short_one,
+                UnitDisplay::Narrow => denominator.narrow,
+            }
+            .replace("{0}", "");
+            let denominator_name = denominator_name.trim();
+            let numerator = select(numerator);
+            return match display {
+                UnitDisplay::Long => format!("{numerator} per {denominator_name}"),
+                UnitDisplay::Short | UnitDisplay::Narrow => {
+                    format!("{numerator}/{denominator_name}")
+                }
+            };
+        }
+    }
+    format!("{{0}} {unit}")
+}
+
+/// ### [15.5.4 PartitionNumberPattern ( numberFormat, x )](https://tc39.es/ecma402/#sec-partitionnumberpattern)
+pub(crate) fn partition_number_pattern(
+    record: &NumberFormatRecord,
+    x: &IntlMathematicalValue,
+) -> Vec<NumberPart> {
+    // 1. Let exponent be 0.
+    let mut exponent = 0;
+    // 2. If x is not-a-number, then
+    // a. Let n be an ILD String value indicating the NaN value.
+    // 3. Else if x is positive-infinity, then
+    // a. Let n be an ILD String value indicating positive infinity.
+    // 4. Else if x is negative-infinity, then
+    // a. Let n be an ILD String value indicating negative infinity.
+    // 5. Else,
+    let (x, n) = match x {
+        IntlMathematicalValue::NaN | IntlMathematicalValue::Infinity { .. } => (x.clone(), None),
+        IntlMathematicalValue::Finite(x) => {
+            let mut x = x.clone();
+            // a. If x is not negative-zero, then
+            if !x.is_zero() {
+                // i. Assert: x is a mathematical value.
+                // ii. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+                if record.style == NumberFormatStyle::Percent {
+                    x = x.scale(2);
+                }
+                // iii. Let exponent be ComputeExponent(numberFormat, x).
+                exponent = compute_exponent(record, &x);
+                // iv. Let x be x × 10^-exponent.
+                x = x.scale(-exponent);
+            }
+            // b. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+            let format_number_result = format_numeric_to_string(&record.digit_options, &x);
+            // c. Let n be formatNumberResult.[[FormattedString]].
+            // d. Let x be formatNumberResult.[[RoundedNumber]].
+            (
+                IntlMathematicalValue::Finite(format_number_result.rounded_number),
+                Some(format_number_result.formatted_string),
+            )
+        }
+    };
+    // 6. Let pattern be GetNumberFormatPattern(numberFormat, x).
+    let sign = get_sign(record.sign_display, &x);
+    // 7. Let result be a new empty List.
+    let mut result = Vec::new();
+    let accounting = record.style == NumberFormatStyle::Currency
+        && record.currency_sign == CurrencySign::Accounting
+        && sign == Sign::Minus;
+    let plural_category = n
+        .as_deref()
+        .map_or(PluralCategory::Other, cardinal_plural_category);
+    // 8. Let patternParts be PartitionPattern(pattern).
+    // 9. For each Record { [[Type]], [[Value]] } patternPart of patternParts, do
+    if accounting {
+        result.push(part(NumberPartType::Literal, "("));
+    } else {
+        match sign {
+            // f. Else if p is equal to "plusSign", then
+            // i. Let plusSignSymbol be the ILND String representing the plus sign.
+            // ii. Append the Record { [[Type]]: "plusSign", [[Value]]: plusSignSymbol } to result.
+            Sign::Plus => result.push(part(NumberPartType::PlusSign, locale_data::PLUS_SIGN)),
+            // g. Else if p is equal to "minusSign", then
+            // i. Let minusSignSymbol be the ILND String representing the minus sign.
+            // ii. Append the Record { [[Type]]: "minusSign", [[Value]]: minusSignSymbol } to result.
+            Sign::Minus => result.push(part(NumberPartType::MinusSign, locale_data::MINUS_SIGN)),
+            Sign::None => {}
+        }
+    }
+    let currency = record.currency.as_deref().unwrap_or("");
+    // j. Else if p is equal to "currencyCode" and numberFormat.[[Style]] is
+    //    "currency", then
+    // l. Else if p is equal to "currencyPrefix" and numberFormat.[[Style]]
+    //    is "currency", then
+    if record.style == NumberFormatStyle::Currency
+        && record.currency_display != CurrencyDisplay::Name
+    {
+        let symbol = currency_display_string(currency, record.currency_display, plural_category);
+        result.push(part(NumberPartType::Currency, symbol));
+        // Note: Alphabetic currency symbols are separated from the number.
+        if symbol.chars().last().is_some_and(char::is_alphabetic) {
+            result.push(part(NumberPartType::Literal, "\u{a0}"));
+        }
+    }
+    // a. Let p be patternPart.[[Type]].
+    // b. If p is "literal", then
+    // c. Else if p is equal to "number", then
+    // i. Let notationSubParts be PartitionNotationSubPattern(numberFormat, x, n, exponent).
+    // ii. Set result to the list-concatenation of result and notationSubParts.
+    result.extend(partition_notation_sub_pattern(
+        record,
+        &x,
+        n.as_deref(),
+        exponent,
+    ));
+    match record.style {
+        // h. Else if p is equal to "percentSign" and numberFormat.[[Style]] is
+        //    "percent", then
+        NumberFormatStyle::Percent => {
+            result.push(part(NumberPartType::PercentSign, locale_data::PERCENT_SIGN))
+        }
+        // i. Else if p is equal to "unitPrefix" and numberFormat.[[Style]] is
+        //    "unit", then
+        // j. Else if p is equal to "unitSuffix" and numberFormat.[[Style]] is
+        //    "unit", then
+        NumberFormatStyle::Unit => {
+            let unit = record.unit.as_deref().unwrap_or("");
+            let pattern = unit_pattern(unit, record.unit_display, plural_category);
+            let (prefix, suffix) = pattern.split_once("{0}").unwrap_or(("", ""));
+            // The number is already part of the result.
+            debug_assert!(prefix.is_empty());
+            let unit_name = suffix.trim_start();
+            if unit_name.len() != suffix.len() {
+                result.push(part(
+                    NumberPartType::Literal,
+                    &suffix[..suffix.len() - unit_name.len()],
+                ));
+            }
+            if !unit_name.is_empty() {
+                result.push(part(NumberPartType::Unit, unit_name));
+            }
+        }
+        // k. Else if p is equal to "currencyCode" and numberFormat.[[Style]]
+        //    is "currency", then
+        NumberFormatStyle::Currency => {
+            if record.currency_display == CurrencyDisplay::Name {
+                result.push(part(NumberPartType::Literal, " "));
+                result.push(part(
+                    NumberPartType::Currency,
+                    currency_display_string(currency, record.currency_display, plural_category),
+                ));
+            }
+        }
+        NumberFormatStyle::Decimal => {}
+    }
+    if accounting {
+        result.push(part(NumberPartType::Literal, ")"));
+    }
+    // 10. Return result.
+    result
+}
+
+/// ### [15.5.4.1 PartitionNotationSubPattern ( numberFormat, x, n, exponent )](https://tc39.es/ecma402/#sec-partitionnotationsubpattern)
+fn partition_notation_sub_pattern(
+    record: &NumberFormatRecord,
+    x: &IntlMathematicalValue,
+    n: Option<&str>,
+    exponent: i32,
+) -> Vec<NumberPart> {
+    // 1. Let result be a new empty List.
+    let mut result = Vec::new();
+    let n = match (x, n) {
+        // 2. If x is not-a-number, then
+        // a. Append the Record { [[Type]]: "nan", [[Value]]: n } to result.
+        (IntlMathematicalValue::NaN, _) => {
+            result.push(part(NumberPartType::Nan, locale_data::NAN));
+            return result;
+        }
+        // 3. Else if x is positive-infinity or negative-infinity, then
+        // a. Append the Record { [[Type]]: "infinity", [[Value]]: n } to result.
+        (IntlMathematicalValue::Infinity { .. }, _) => {
+            result.push(part(NumberPartType::Infinity, locale_data::INFINITY));
+            return result;
+        }
+        (IntlMathematicalValue::Finite(_), Some(n)) => n,
+        (IntlMathematicalValue::Finite(_), None) => unreachable!(),
+    };
+    // 4. Else,
+    // a. Let notationSubPattern be GetNotationSubPattern(numberFormat, exponent).
+    // b. Let patternParts be PartitionPattern(notationSubPattern).
+    // c. For each Record { [[Type]], [[Value]] } patternPart of patternParts, do
+    // iii. Else if p is equal to "number", then
+    // 1. If the numberFormat.[[NumberingSystem]] matches one of the values
+    //    in the Numbering System column of Table 24 below, then
+    // a. Let digits be a List whose elements are the code points specified
+    //    in the Digits column of the matching row in Table 24.
+    // 3. Let decimalSepIndex be StringIndexOf(n, ".", 0).
+    // 4. If decimalSepIndex is not-found, then
+    // a. Let integer be n.
+    // b. Let fraction be undefined.
+    // 5. Else,
+    // a. Let integer be the substring of n from 0 to decimalSepIndex.
+    // b. Let fraction be the substring of n from decimalSepIndex + 1.
+    let (integer, fraction) = match n.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (n, None),
+    };
+    // 6. If the numberFormat.[[UseGrouping]] is false, then
+    // a. Append the Record { [[Type]]: "integer", [[Value]]: integer } to result.
+    // 7. Else,
+    if use_grouping(record.use_grouping, integer.len()) {
+        // a. Let groupSepSymbol be the ILND String representing the grouping separator.
+        // b. Let groups be a List whose elements are, in left to right order,
+        //    the substrings defined by ILND set of locations within the integer,
+        //    which may depend on the value of numberFormat.[[UseGrouping]].
+        let first_group = match integer.len() % locale_data::GROUPING_SIZE {
+            0 => locale_data::GROUPING_SIZE,
+            length => length,
+        };
+        // c. Assert: The number of elements in groups List is greater than 0.
+        // d. Repeat, while groups List is not empty,
+        // i. Remove the first element from groups and let integerGroup be the
+        //    value of that element.
+        // ii. Append the Record { [[Type]]: "integer", [[Value]]: integerGroup } to result.
+        // iii. If groups List is not empty, then
+        // i. Append the Record { [[Type]]: "group", [[Value]]: groupSepSymbol } to result.
+        result.push(part(NumberPartType::Integer, &integer[..first_group]));
+        let mut start = first_group;
+        while start < integer.len() {
+            result.push(part(NumberPartType::Group, locale_data::GROUP_SEPARATOR));
+            result.push(part(
+                NumberPartType::Integer,
+                &integer[start..start + locale_data::GROUPING_SIZE],
+            ));
+            start += locale_data::GROUPING_SIZE;
+        }
+    } else {
+        result.push(part(NumberPartType::Integer, integer));
+    }
+    // 8. If fraction is not undefined, then
+    if let Some(fraction) = fraction {
+        // a. Let decimalSepSymbol be the ILND String representing the decimal separator.
+        // b. Append the Record { [[Type]]: "decimal", [[Value]]: decimalSepSymbol } to result.
+        result.push(part(
+            NumberPartType::Decimal,
+            locale_data::DECIMAL_SEPARATOR,
+        ));
+        // c. Append the Record { [[Type]]: "fraction", [[Value]]: fraction } to result.
+        result.push(part(NumberPartType::Fraction, fraction));
+    }
+    match record.notation {
+        // iv. Else if p is equal to "compactSymbol", then
+        // v. Else if p is equal to "compactName", then
+        Notation::Compact => {
+            if let Some((_, short, long)) = locale_data::COMPACT_PATTERNS
+                .iter()
+                .find(|(pattern_exponent, _, _)| *pattern_exponent == exponent)
+            {
+                match record.compact_display {
+                    CompactDisplay::Short => result.push(part(NumberPartType::Compact, short)),
+                    CompactDisplay::Long => {
+                        result.push(part(NumberPartType::Literal, " "));
+                        result.push(part(NumberPartType::Compact, long));
+                    }
+                }
+            }
+        }
+        // vi. Else if p is equal to "scientificSeparator", then
+        // vii. Else if p is equal to "scientificExponent", then
+        Notation::Scientific | Notation::Engineering => {
+            result.push(part(
+                NumberPartType::ExponentSeparator,
+                locale_data::EXPONENT_SEPARATOR,
+            ));
+            // 1. If exponent < 0, then
+            if exponent < 0 {
+                // a. Let minusSignSymbol be the ILND String representing the minus sign.
+                // b. Append the Record { [[Type]]: "exponentMinusSign",
+                //    [[Value]]: minusSignSymbol } to result.
+                result.push(part(
+                    NumberPartType::ExponentMinusSign,
+                    locale_data::MINUS_SIGN,
+                ));
+            }
+            // 2. Let exponent be -exponent.
+            // 3. Let exponentResult be ToRawFixed(exponent, 0, 0, 1, undefined).
+            // 4. Append the Record { [[Type]]: "exponentInteger", [[Value]]:
+            //    exponentResult.[[FormattedString]] } to result.
+            result.push(part(
+                NumberPartType::ExponentInteger,
+                &exponent.unsigned_abs().to_string(),
+            ));
+        }
+        Notation::Standard => {}
+    }
+    // 5. Return result.
+    result
+}
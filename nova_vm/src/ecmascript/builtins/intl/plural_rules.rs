@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::PluralRulesIndex,
+    },
+};
+
+use self::data::PluralRulesHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PluralRules<'a>(pub(crate) PluralRulesIndex<'a>);
+
+impl PluralRules<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, PluralRules<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(PluralRulesIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PluralRules<'_> {
+    type Of<'a> = PluralRules<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for PluralRules<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PluralRules<'a>> for Value<'a> {
+    fn from(value: PluralRules<'a>) -> Self {
+        Value::PluralRules(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for PluralRules<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PluralRules<'a>> for Object<'a> {
+    fn from(value: PluralRules) -> Self {
+        Object::PluralRules(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for PluralRules<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::PluralRules(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for PluralRules<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::PluralRules(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for PluralRules<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::PluralRules;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for PluralRules<'a> {}
+
+impl Index<PluralRules<'_>> for Agent {
+    type Output = PluralRulesHeapData<'static>;
+
+    fn index(&self, index: PluralRules) -> &Self::Output {
+        &self.heap.plural_rules[index]
+    }
+}
+
+impl IndexMut<PluralRules<'_>> for Agent {
+    fn index_mut(&mut self, index: PluralRules) -> &mut Self::Output {
+        &mut self.heap.plural_rules[index]
+    }
+}
+
+impl Index<PluralRules<'_>> for Vec<Option<PluralRulesHeapData<'static>>> {
+    type Output = PluralRulesHeapData<'static>;
+
+    fn index(&self, index: PluralRules) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("PluralRules out of bounds")
+            .as_ref()
+            .expect("PluralRules slot empty")
+    }
+}
+
+impl IndexMut<PluralRules<'_>> for Vec<Option<PluralRulesHeapData<'static>>> {
+    fn index_mut(&mut self, index: PluralRules) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("PluralRules out of bounds")
+            .as_mut()
+            .expect("PluralRules slot empty")
+    }
+}
+
+impl Rootable for PluralRules<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::PluralRules(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::PluralRules(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for PluralRules<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plural_rules.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plural_rules.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<PluralRulesHeapData<'a>, PluralRules<'a>> for Heap {
+    fn create(&mut self, data: PluralRulesHeapData<'a>) -> PluralRules<'a> {
+        self.plural_rules.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<PluralRulesHeapData<'static>>>();
+        }
+        PluralRules(PluralRulesIndex::last(&self.plural_rules))
+    }
+}
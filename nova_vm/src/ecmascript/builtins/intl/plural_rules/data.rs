@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::intl::{
+            locale::DEFAULT_LOCALE,
+            number_format_objects::numeric_formatting::{DigitOptions, option_enum},
+        },
+        types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+option_enum!(
+    /// \[\[Type]]
+    PluralRuleType {
+        Cardinal => "cardinal",
+        Ordinal => "ordinal",
+    }
+);
+
+/// ### [16.4 Properties of Intl.PluralRules Instances](https://tc39.es/ecma402/#sec-properties-of-intl-pluralrules-instances)
+#[derive(Debug, Clone)]
+pub struct PluralRulesHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Locale]]
+    pub(crate) locale: Box<str>,
+    /// \[\[Type]]
+    pub(crate) r#type: PluralRuleType,
+    /// The rounding options, from \[\[MinimumIntegerDigits]] to
+    /// \[\[TrailingZeroDisplay]].
+    pub(crate) digit_options: DigitOptions,
+}
+
+impl Default for PluralRulesHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            locale: DEFAULT_LOCALE.into(),
+            r#type: PluralRuleType::Cardinal,
+            digit_options: DigitOptions::default(),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PluralRulesHeapData<'_> {
+    type Of<'a> = PluralRulesHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PluralRulesHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            locale: _,
+            r#type: _,
+            digit_options: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            locale: _,
+            r#type: _,
+            digit_options: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,179 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            intl::{
+                abstract_operations::{
+                    canonicalize_locale_list, coerce_options_to_object, get_string_option,
+                    set_number_format_digit_options, supported_locales,
+                },
+                locale::resolve_locale,
+                number_format_objects::numeric_formatting::Notation,
+                plural_rules::{PluralRules, data::PluralRuleType},
+            },
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct PluralRulesConstructor;
+impl Builtin for PluralRulesConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PluralRules;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for PluralRulesConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::PluralRules;
+}
+
+struct PluralRulesSupportedLocalesOf;
+impl Builtin for PluralRulesSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PluralRulesConstructor::supported_locales_of);
+}
+
+impl PluralRulesConstructor {
+    /// ### [16.1.1 Intl.PluralRules ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.pluralrules)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).scope(agent, gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Intl.PluralRules requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // 2. Let pluralRules be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%Intl.PluralRules.prototype%", « [[InitializedPluralRules]],
+        //    [[Locale]], [[Type]], [[MinimumIntegerDigits]],
+        //    [[MinimumFractionDigits]], [[MaximumFractionDigits]],
+        //    [[MinimumSignificantDigits]], [[MaximumSignificantDigits]],
+        //    [[RoundingType]], [[RoundingIncrement]], [[RoundingMode]],
+        //    [[ComputedRoundingPriority]], [[TrailingZeroDisplay]] »).
+        let plural_rules = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::PluralRules,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let plural_rules = PluralRules::try_from(plural_rules)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.get(agent), gc.reborrow()).unbind()?;
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string,
+        //    « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[localeMatcher]] to matcher.
+        get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.localeMatcher.into(),
+            &["lookup", "best fit"],
+            Some("best fit"),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 8. Let t be ? GetOption(options, "type", string, « "cardinal",
+        //    "ordinal" », "cardinal").
+        let t = get_string_option(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.r#type.into(),
+            PluralRuleType::VALUES,
+            Some(PluralRuleType::Cardinal.as_str()),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap();
+        // 9. Set pluralRules.[[Type]] to t.
+        let t = PluralRuleType::from_option(t);
+        // 10. Perform ? SetNumberFormatDigitOptions(pluralRules, options, 0,
+        //     3, "standard").
+        let digit_options = set_number_format_digit_options(
+            agent,
+            options.get(agent),
+            0,
+            3,
+            Notation::Standard,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 11. Let r be ResolveLocale(%Intl.PluralRules%.[[AvailableLocales]],
+        //     requestedLocales, opt,
+        //     %Intl.PluralRules%.[[RelevantExtensionKeys]],
+        //     %Intl.PluralRules%.[[LocaleData]]).
+        let r = resolve_locale(&requested_locales, &[], &[]);
+        // 12. Set pluralRules.[[Locale]] to r.[[locale]].
+        let gc = gc.into_nogc();
+        let plural_rules = plural_rules.get(agent).bind(gc);
+        let data = &mut agent[plural_rules];
+        data.locale = r.locale.into_boxed_str();
+        data.r#type = t;
+        data.digit_options = digit_options;
+        // 13. Return pluralRules.
+        Ok(plural_rules.into_value())
+    }
+
+    /// ### [16.2.2 Intl.PluralRules.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let availableLocales be %Intl.PluralRules%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales =
+            canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(agent, &requested_locales, options.get(agent), gc)
+            .map(|locales| locales.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plural_rules_prototype = intrinsics.plural_rules_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<PluralRulesConstructor>(agent, realm)
+            .with_property_capacity(2)
+            .with_prototype_property(plural_rules_prototype.into_object())
+            .with_builtin_function_property::<PluralRulesSupportedLocalesOf>()
+            .build();
+    }
+}
@@ -0,0 +1,246 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::create_array_from_list, type_conversion::to_number,
+        },
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin,
+            intl::{
+                abstract_operations::{
+                    create_resolved_options_object, number_to_intl_mathematical_value,
+                },
+                locale_data::{
+                    CARDINAL_PLURAL_CATEGORIES, ORDINAL_PLURAL_CATEGORIES, PluralCategory,
+                    cardinal_plural_category, ordinal_plural_category,
+                },
+                number_format_objects::numeric_formatting::{
+                    IntlMathematicalValue, RoundingType, format_numeric_to_string,
+                },
+                plural_rules::{PluralRules, data::PluralRuleType},
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
+    },
+    engine::context::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct PluralRulesPrototype;
+
+struct PluralRulesPrototypeResolvedOptions;
+impl Builtin for PluralRulesPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PluralRulesPrototype::resolved_options);
+}
+struct PluralRulesPrototypeSelect;
+impl Builtin for PluralRulesPrototypeSelect {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.select;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PluralRulesPrototype::select);
+}
+
+impl PluralRulesPrototype {
+    /// ### [16.3.3 Intl.PluralRules.prototype.select ( value )](https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select)
+    fn select<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = require_plural_rules(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let n be ? ToNumber(value).
+        let n = to_number(agent, arguments.get(0), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let n = n.to_real(agent);
+        // 4. Return ResolvePlural(pr, n).[[PluralCategory]].
+        let category = resolve_plural(agent, pr.get(agent), n);
+        Ok(Value::from_static_str(
+            agent,
+            category.as_str(),
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [16.3.4 Intl.PluralRules.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = require_plural_rules(agent, this_value, gc)?;
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. Let pluralCategories be a List of Strings containing all
+        //    possible results of PluralRuleSelect for the selected locale
+        //    pr.[[Locale]], sorted according to the following order: "zero",
+        //    "one", "two", "few", "many", "other".
+        let data = &agent[pr];
+        let locale = data.locale.to_string();
+        let r#type = data.r#type;
+        let digit_options = data.digit_options;
+        let categories = match r#type {
+            PluralRuleType::Cardinal => CARDINAL_PLURAL_CATEGORIES,
+            PluralRuleType::Ordinal => ORDINAL_PLURAL_CATEGORIES,
+        };
+        let mut categories = categories.to_vec();
+        categories.sort_by_key(|category| *category as u8);
+        let plural_categories = categories
+            .iter()
+            .map(|category| Value::from_static_str(agent, category.as_str(), gc))
+            .collect::<Vec<_>>();
+        let plural_categories = create_array_from_list(agent, &plural_categories, gc);
+        let has_fraction_digits = digit_options.rounding_type != RoundingType::SignificantDigits;
+        let has_significant_digits = digit_options.rounding_type != RoundingType::FractionDigits;
+        // 5. For each row of Table 25, except the header row, in table order,
+        //    do
+        let properties = [
+            (
+                BUILTIN_STRING_MEMORY.locale,
+                Value::from_string(agent, locale, gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.r#type,
+                Value::from_static_str(agent, r#type.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumIntegerDigits,
+                Value::from(digit_options.minimum_integer_digits),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumFractionDigits,
+                has_fraction_digits
+                    .then(|| Value::from(digit_options.minimum_fraction_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.maximumFractionDigits,
+                has_fraction_digits
+                    .then(|| Value::from(digit_options.maximum_fraction_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.minimumSignificantDigits,
+                has_significant_digits
+                    .then(|| Value::from(digit_options.minimum_significant_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.maximumSignificantDigits,
+                has_significant_digits
+                    .then(|| Value::from(digit_options.maximum_significant_digits))
+                    .into(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.pluralCategories,
+                plural_categories.into_value(),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingIncrement,
+                Value::from(digit_options.rounding_increment),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingMode,
+                Value::from_static_str(agent, digit_options.rounding_mode.as_str(), gc),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.roundingPriority,
+                Value::from_static_str(
+                    agent,
+                    digit_options.computed_rounding_priority.as_str(),
+                    gc,
+                ),
+            ),
+            (
+                BUILTIN_STRING_MEMORY.trailingZeroDisplay,
+                Value::from_static_str(agent, digit_options.trailing_zero_display.as_str(), gc),
+            ),
+        ];
+        // 6. Return options.
+        Ok(create_resolved_options_object(agent, &properties, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.plural_rules_prototype();
+        let plural_rules_constructor = intrinsics.plural_rules();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plural_rules_constructor)
+            .with_builtin_function_property::<PluralRulesPrototypeResolvedOptions>()
+            .with_builtin_function_property::<PluralRulesPrototypeSelect>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_PluralRules.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [16.5.3 ResolvePlural ( pluralRules, n )](https://tc39.es/ecma402/#sec-resolveplural)
+///
+/// Returns the \[\[PluralCategory]] of the resolution record.
+fn resolve_plural(agent: &Agent, plural_rules: PluralRules, n: f64) -> PluralCategory {
+    // 1. If n is not a finite Number, then
+    // a. Let s be ! ToString(n).
+    // b. Return the Record { [[PluralCategory]]: "other",
+    //    [[FormattedString]]: s }.
+    let IntlMathematicalValue::Finite(x) = number_to_intl_mathematical_value(n) else {
+        return PluralCategory::Other;
+    };
+    let data = &agent[plural_rules];
+    // 2. Let res be FormatNumericToString(pluralRules, ℝ(n)).
+    let res = format_numeric_to_string(&data.digit_options, &x);
+    // 3. Let s be res.[[FormattedString]].
+    let s = res.formatted_string;
+    // 4. Let locale be pluralRules.[[Locale]].
+    // 5. Let type be pluralRules.[[Type]].
+    // 6. Let p be PluralRuleSelect(locale, type, s).
+    // 7. Return the Record { [[PluralCategory]]: p, [[FormattedString]]: s }.
+    match data.r#type {
+        PluralRuleType::Cardinal => cardinal_plural_category(&s),
+        PluralRuleType::Ordinal => ordinal_plural_category(&s),
+    }
+}
+
+#[inline(always)]
+fn require_plural_rules<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, PluralRules<'a>> {
+    PluralRules::try_from(value)
+        .map(|pr| pr.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not an Intl.PluralRules object",
+                gc,
+            )
+        })
+}
@@ -204,6 +204,7 @@ impl MapConstructor {
             bigints,
             numbers,
             strings,
+            external_bytes,
             ..
         } = &mut agent.heap;
         let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
@@ -244,6 +245,7 @@ impl MapConstructor {
                     vacant.insert(index);
                     keys.push(Some(key.unbind()));
                     values.push(Some(elements.into_value().unbind()));
+                    *external_bytes += MapData::ENTRY_BYTES;
                 }
             }
         }
@@ -322,6 +324,7 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
                         numbers,
                         strings,
                         maps,
+                        external_bytes,
                         ..
                     } = &mut agent.heap;
                     let array_heap = ArrayHeap::new(elements, arrays);
@@ -397,6 +400,7 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
                                     vacant.insert(next_index);
                                     keys.push(Some(key.unbind()));
                                     values.push(Some(value.unbind()));
+                                    *external_bytes += MapData::ENTRY_BYTES;
                                 }
                             }
                         }
@@ -410,13 +414,14 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
         }
     }
 
-    add_entries_from_iterable(
+    Ok(Map::try_from(add_entries_from_iterable(
         agent,
-        target.unbind(),
+        target.into_object().unbind(),
         iterable.unbind(),
         adder.unbind(),
         gc,
-    )
+    )?)
+    .unwrap())
 }
 
 /// ### [24.1.1.2 AddEntriesFromIterable ( target, iterable, adder )](https://tc39.es/ecma262/#sec-add-entries-from-iterable)
@@ -434,11 +439,11 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
 /// > key.
 pub(crate) fn add_entries_from_iterable<'a>(
     agent: &mut Agent,
-    target: Map,
+    target: Object,
     iterable: Value,
     adder: Function,
     mut gc: GcScope<'a, '_>,
-) -> JsResult<'a, Map<'a>> {
+) -> JsResult<'a, Object<'a>> {
     let nogc = gc.nogc();
     let target = target.scope(agent, nogc);
     let iterable = iterable.bind(nogc);
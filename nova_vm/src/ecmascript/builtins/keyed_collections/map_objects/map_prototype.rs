@@ -420,59 +420,14 @@ impl MapPrototype {
         // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
         let m = require_map_data_internal_slot(agent, this_value, gc)?;
 
-        let Heap {
-            bigints,
-            numbers,
-            strings,
-            maps,
-            ..
-        } = &mut agent.heap;
-        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
-
-        let MapData {
-            keys,
-            values,
-            map_data,
-            ..
-        } = &mut maps[m].borrow_mut(&primitive_heap);
-        let map_data = map_data.get_mut();
-
-        let hasher = |value: Value| {
-            let mut hasher = AHasher::default();
-            value.hash(&primitive_heap, &mut hasher);
-            hasher.finish()
-        };
-
         // 3. Set key to CanonicalizeKeyedCollectionKey(key).
-        let key = canonicalize_keyed_collection_key(numbers, key);
-        let key_hash = hasher(key);
         // 4. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
         // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, then
-        let entry = map_data.entry(
-            key_hash,
-            |hash_equal_index| {
-                let found_key = keys[*hash_equal_index as usize].unwrap();
-                // Quick check: Equal keys have the same value.
-                found_key == key || same_value(&primitive_heap, found_key, key)
-            },
-            |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
-        );
-        match entry {
-            hashbrown::hash_table::Entry::Occupied(occupied) => {
-                let index = *occupied.get();
-                // i. Set p.[[Value]] to value.
-                values[index as usize] = Some(value.unbind());
-                // ii. Return M.
-            }
-            hashbrown::hash_table::Entry::Vacant(vacant) => {
-                // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
-                // 6. Append p to M.[[MapData]].
-                let index = u32::try_from(values.len()).unwrap();
-                vacant.insert(index);
-                keys.push(Some(key.unbind()));
-                values.push(Some(value.unbind()));
-            }
-        }
+        // i. Set p.[[Value]] to value.
+        // ii. Return M.
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[MapData]].
+        map_data_set(agent, m, key, value);
         // 7. Return M.
         Ok(m.into_value())
     }
@@ -562,6 +517,60 @@ fn require_map_data_internal_slot<'a>(
     }
 }
 
+/// Sets the value of the canonicalized key in the \[\[MapData]] of `m`,
+/// appending a new entry if the key is not already present.
+pub(crate) fn map_data_set(agent: &mut Agent, m: Map, key: Value, value: Value) {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        maps,
+        external_bytes,
+        ..
+    } = &mut agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+
+    let key = canonicalize_keyed_collection_key(numbers, key);
+
+    let MapData {
+        keys,
+        values,
+        map_data,
+        ..
+    } = &mut maps[m].borrow_mut(&primitive_heap);
+    let map_data = map_data.get_mut();
+
+    let hasher = |value: Value| {
+        let mut hasher = AHasher::default();
+        value.hash(&primitive_heap, &mut hasher);
+        hasher.finish()
+    };
+
+    let key_hash = hasher(key);
+    let entry = map_data.entry(
+        key_hash,
+        |hash_equal_index| {
+            let found_key = keys[*hash_equal_index as usize].unwrap();
+            // Quick check: Equal keys have the same value.
+            found_key == key || same_value(&primitive_heap, found_key, key)
+        },
+        |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
+    );
+    match entry {
+        hashbrown::hash_table::Entry::Occupied(occupied) => {
+            let index = *occupied.get();
+            values[index as usize] = Some(value.unbind());
+        }
+        hashbrown::hash_table::Entry::Vacant(vacant) => {
+            let index = u32::try_from(values.len()).unwrap();
+            vacant.insert(index);
+            keys.push(Some(key.unbind()));
+            values.push(Some(value.unbind()));
+            *external_bytes += MapData::ENTRY_BYTES;
+        }
+    }
+}
+
 #[inline(always)]
 /// ### [24.5.1 CanonicalizeKeyedCollectionKey ( key )](https://tc39.es/ecma262/#sec-canonicalizekeyedcollectionkey)
 /// The abstract operation CanonicalizeKeyedCollectionKey takes argument key
@@ -156,6 +156,7 @@ impl SetConstructor {
                     numbers,
                     strings,
                     sets,
+                    external_bytes,
                     ..
                 } = &mut agent.heap;
                 let array_heap = ArrayHeap::new(elements, arrays);
@@ -197,6 +198,7 @@ impl SetConstructor {
                         hashbrown::hash_table::Entry::Vacant(vacant) => {
                             vacant.insert(next_index);
                             values.push(Some(value.unbind()));
+                            *external_bytes += SetData::ENTRY_BYTES;
                         }
                     }
                 });
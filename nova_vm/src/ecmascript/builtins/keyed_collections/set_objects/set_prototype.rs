@@ -11,8 +11,15 @@ use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::call_function,
+            operations_on_iterator_objects::{
+                IteratorRecord, get_iterator_from_method, iterator_close_with_value,
+                iterator_step_value,
+            },
+            operations_on_objects::{call_function, get, throw_not_callable},
             testing_and_comparison::{is_callable, same_value},
+            type_conversion::{
+                IntegerOrInfinity, to_boolean, to_integer_or_infinity_number, to_number,
+            },
         },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
         builtins::{
@@ -26,9 +33,13 @@ use crate::{
             },
         },
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, IntoValue, Number, PropertyKey, String, Value},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, InternalMethods, IntoFunction, IntoValue, Number,
+            Object, PropertyKey, String, Value,
+        },
     },
-    heap::{Heap, IntrinsicFunctionIndexes, PrimitiveHeap, WellKnownSymbolIndexes},
+    engine::TryResult,
+    heap::{CreateHeapData, Heap, IntrinsicFunctionIndexes, PrimitiveHeap, WellKnownSymbolIndexes},
 };
 
 pub(crate) struct SetPrototype;
@@ -51,6 +62,12 @@ impl Builtin for SetPrototypeDelete {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::delete);
 }
+struct SetPrototypeDifference;
+impl Builtin for SetPrototypeDifference {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.difference;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::difference);
+}
 struct SetPrototypeEntries;
 impl Builtin for SetPrototypeEntries {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.entries;
@@ -69,6 +86,33 @@ impl Builtin for SetPrototypeHas {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::has);
 }
+impl BuiltinIntrinsic for SetPrototypeHas {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::SetPrototypeHas;
+}
+struct SetPrototypeIntersection;
+impl Builtin for SetPrototypeIntersection {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.intersection;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::intersection);
+}
+struct SetPrototypeIsDisjointFrom;
+impl Builtin for SetPrototypeIsDisjointFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isDisjointFrom;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_disjoint_from);
+}
+struct SetPrototypeIsSubsetOf;
+impl Builtin for SetPrototypeIsSubsetOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isSubsetOf;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_subset_of);
+}
+struct SetPrototypeIsSupersetOf;
+impl Builtin for SetPrototypeIsSupersetOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isSupersetOf;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_superset_of);
+}
 struct SetPrototypeGetSize;
 impl Builtin for SetPrototypeGetSize {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_size;
@@ -77,6 +121,18 @@ impl Builtin for SetPrototypeGetSize {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::get_size);
 }
 impl BuiltinGetter for SetPrototypeGetSize {}
+struct SetPrototypeSymmetricDifference;
+impl Builtin for SetPrototypeSymmetricDifference {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.symmetricDifference;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::symmetric_difference);
+}
+struct SetPrototypeUnion;
+impl Builtin for SetPrototypeUnion {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.union;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::union);
+}
 struct SetPrototypeValues;
 impl Builtin for SetPrototypeValues {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.values;
@@ -102,46 +158,11 @@ impl SetPrototype {
         // 2. Perform ? RequireInternalSlot(S, [[SetData]]).
         let s = require_set_data_internal_slot(agent, this_value, gc)?;
 
-        let Heap {
-            bigints,
-            numbers,
-            strings,
-            sets,
-            ..
-        } = &mut agent.heap;
-        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
-
         // 3. Set value to CanonicalizeKeyedCollectionKey(value).
-        let value = canonicalize_keyed_collection_key(numbers, value);
-
-        let SetData {
-            values, set_data, ..
-        } = &mut sets[s].borrow_mut(&primitive_heap);
-        let set_data = set_data.get_mut();
-        let hasher = |value: Value| {
-            let mut hasher = AHasher::default();
-            value.hash(&primitive_heap, &mut hasher);
-            hasher.finish()
-        };
-
-        let value_hash = hasher(value);
-
         // 4. For each element e of S.[[SetData]], do
         // a. If e is not empty and SameValue(e, value) is true, then
-        if let hashbrown::hash_table::Entry::Vacant(entry) = set_data.entry(
-            value_hash,
-            |hash_equal_index| {
-                let found_value = values[*hash_equal_index as usize].unwrap();
-                // Quick check: Equal values have the same value.
-                found_value == value || same_value(&primitive_heap, found_value, value)
-            },
-            |index_to_hash| hasher(values[*index_to_hash as usize].unwrap()),
-        ) {
-            // 5. Append value to S.[[SetData]].
-            let index = u32::try_from(values.len()).unwrap();
-            entry.insert(index);
-            values.push(Some(value.unbind()));
-        }
+        // 5. Append value to S.[[SetData]].
+        set_data_append(agent, s, value);
         // i. Return S.
         // 6. Return S.
         Ok(s.into_value())
@@ -192,44 +213,141 @@ impl SetPrototype {
         // 2. Perform ? RequireInternalSlot(S, [[SetData]]).
         let s = require_set_data_internal_slot(agent, this_value, gc)?;
 
-        let Heap {
-            bigints,
-            numbers,
-            strings,
-            sets,
-            ..
-        } = &mut agent.heap;
-        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
-
         // 3. Set value to CanonicalizeKeyedCollectionKey(value).
-        let value = canonicalize_keyed_collection_key(numbers, value);
-        let mut hasher = AHasher::default();
-        let value_hash = {
-            value.hash(&primitive_heap, &mut hasher);
-            hasher.finish()
-        };
-        let SetData {
-            values, set_data, ..
-        } = &mut sets[s].borrow_mut(&primitive_heap);
-        let set_data = set_data.get_mut();
         // 4. For each element e of S.[[SetData]], do
-        if let Ok(entry) = set_data.find_entry(value_hash, |hash_equal_index| {
-            let found_value = values[*hash_equal_index as usize].unwrap();
-            // Quick check: Equal keys have the same value.
-            found_value == value || same_value(&primitive_heap, found_value, value)
-        }) {
-            // a. If e is not EMPTY and SameValue(e, value) is true, then
-            let index = *entry.get() as usize;
-            // i. Replace the element of S.[[SetData]] whose value is e with
-            // an element whose value is EMPTY.
-            values[index] = None;
-            let _ = entry.remove();
-            // ii. Return true.
-            Ok(true.into())
+        // a. If e is not EMPTY and SameValue(e, value) is true, then
+        // i. Replace the element of S.[[SetData]] whose value is e with an
+        // element whose value is EMPTY.
+        // ii. Return true.
+        // 5. Return false.
+        Ok(set_data_remove(agent, s, value).into())
+    }
+
+    /// ### [24.2.4.5 Set.prototype.difference ( other )](https://tc39.es/ecma262/#sec-set.prototype.difference)
+    fn difference<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if i64::from(agent[o.get(agent)].size()) <= other_rec.size.into_i64() {
+            let set_object = other_rec.set_object.scope(agent, gc.nogc());
+            let has = other_rec.has.scope(agent, gc.nogc());
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            let this_size = agent[result.get(agent)].values(gc.nogc()).len();
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            while index < this_size {
+                // i. Let e be resultSetData[index].
+                let e = agent[result.get(agent)].values(gc.nogc())[index];
+                // ii. If e is not EMPTY, then
+                if let Some(e) = e {
+                    // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                    let in_other = set_record_has(
+                        agent,
+                        set_object.get(agent),
+                        has.get(agent),
+                        e.unbind(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    // 2. If inOther is true, then
+                    if in_other {
+                        // a. Set resultSetData[index] to EMPTY.
+                        // Note: resultSetData is not reachable from user code,
+                        // so e is still found at index after the call.
+                        let result = result.get(agent);
+                        let e = agent[result].values(gc.nogc())[index].unwrap();
+                        set_data_remove(agent, result, e);
+                    }
+                }
+                // iii. Set index to index + 1.
+                index += 1;
+            }
+        } else if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let result = result.get(agent);
+            for next in set_data_values(agent, other, gc.nogc()) {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not NOT-FOUND, then
+                // a. Set resultSetData[valueIndex] to EMPTY.
+                set_data_remove(agent, result, next);
+            }
+        } else if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let o = o.get(agent);
+            let result = result.get(agent);
+            for next in set_data_values(agent, other, gc.nogc()) {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                // 3. If inThis is true, then
+                if set_data_has(agent, o, next) {
+                    // b. If SetDataHas(resultSetData, next) is false, then
+                    // i. Append next to resultSetData.
+                    set_data_append(agent, result, next);
+                }
+            }
         } else {
-            // 5. Return false.
-            Ok(false.into())
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+            let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not NOT-FOUND, then
+                // a. Set resultSetData[valueIndex] to EMPTY.
+                set_data_remove(agent, result.get(agent), next);
+            }
         }
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(result.get(agent).into_value())
     }
 
     fn entries<'gc>(
@@ -362,37 +480,365 @@ impl SetPrototype {
         // 2. Perform ? RequireInternalSlot(S, [[SetData]]).
         let s = require_set_data_internal_slot(agent, this_value, gc)?;
 
-        let Heap {
-            bigints,
-            numbers,
-            strings,
-            sets,
-            ..
-        } = &agent.heap;
-        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
-        let SetData {
-            values, set_data, ..
-        } = &sets[s].borrow(&primitive_heap);
-        let set_data = set_data.borrow();
-
         // 3. Set value to CanonicalizeKeyedCollectionKey(value).
-        let value = canonicalize_keyed_collection_key(&primitive_heap, value);
-        let mut hasher = AHasher::default();
-        let value_hash = {
-            value.hash(&primitive_heap, &mut hasher);
-            hasher.finish()
-        };
         // 4. For each element e of S.[[SetData]], do
         // a. If e is not EMPTY and SameValue(e, value) is true, return true.
-        let found = set_data
-            .find(value_hash, |hash_equal_index| {
-                let found_value = values[*hash_equal_index as usize].unwrap();
-                // Quick check: Equal values have the same value.
-                found_value == value || same_value(&primitive_heap, found_value, value)
-            })
-            .is_some();
         // 5. Return false.
-        Ok(found.into())
+        Ok(set_data_has(agent, s, value).into())
+    }
+
+    /// ### [24.2.4.9 Set.prototype.intersection ( other )](https://tc39.es/ecma262/#sec-set.prototype.intersection)
+    fn intersection<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let resultSetData be a new empty List.
+        let result = agent
+            .heap
+            .create(SetHeapData::default())
+            .scope(agent, gc.nogc());
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if i64::from(agent[o.get(agent)].size()) <= other_rec.size.into_i64() {
+            let set_object = other_rec.set_object.scope(agent, gc.nogc());
+            let has = other_rec.has.scope(agent, gc.nogc());
+            let mut scoped_e = Value::Undefined.scope_static(gc.nogc());
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            // 4. Set thisSize to the number of elements in O.[[SetData]].
+            while let Some(&e) = agent[o.get(agent)].values(gc.nogc()).get(index) {
+                // i. Let e be O.[[SetData]][index].
+                // ii. Set index to index + 1.
+                index += 1;
+                // iii. If e is not EMPTY, then
+                let Some(e) = e else {
+                    continue;
+                };
+                // SAFETY: scoped_e never escapes this call.
+                unsafe { scoped_e.replace(agent, e.unbind()) };
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                let in_other = set_record_has(
+                    agent,
+                    set_object.get(agent),
+                    has.get(agent),
+                    e.unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 2. If inOther is true, then
+                if in_other {
+                    // a. NOTE: It is possible for earlier calls to
+                    //    otherRec.[[Has]] to remove and re-add an element of
+                    //    O.[[SetData]], which can cause elements to be visited
+                    //    more than once during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    // i. Append e to resultSetData.
+                    let e = scoped_e.get(agent);
+                    set_data_append(agent, result.get(agent), e);
+                }
+                // 3. NOTE: The number of elements in O.[[SetData]] may have
+                //    increased during execution of otherRec.[[Has]].
+            }
+        } else {
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+            let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                // 3. If inThis is true, then
+                if set_data_has(agent, o.get(agent), next) {
+                    // a. NOTE: Because other is an arbitrary object, it is
+                    //    possible for its "keys" iterator to produce the same
+                    //    value more than once.
+                    // b. If SetDataHas(resultSetData, next) is false, then
+                    // i. Append next to resultSetData.
+                    set_data_append(agent, result.get(agent), next);
+                }
+            }
+        }
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(result.get(agent).into_value())
+    }
+
+    /// ### [24.2.4.10 Set.prototype.isDisjointFrom ( other )](https://tc39.es/ecma262/#sec-set.prototype.isdisjointfrom)
+    fn is_disjoint_from<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if i64::from(agent[o.get(agent)].size()) <= other_rec.size.into_i64() {
+            let set_object = other_rec.set_object.scope(agent, gc.nogc());
+            let has = other_rec.has.scope(agent, gc.nogc());
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            // 4. Set thisSize to the number of elements in O.[[SetData]].
+            while let Some(&e) = agent[o.get(agent)].values(gc.nogc()).get(index) {
+                // i. Let e be O.[[SetData]][index].
+                // ii. Set index to index + 1.
+                index += 1;
+                // iii. If e is not EMPTY, then
+                let Some(e) = e else {
+                    continue;
+                };
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                let in_other = set_record_has(
+                    agent,
+                    set_object.get(agent),
+                    has.get(agent),
+                    e.unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 2. If inOther is true, return false.
+                if in_other {
+                    return Ok(false.into());
+                }
+                // 3. NOTE: The number of elements in O.[[SetData]] may have
+                //    increased during execution of otherRec.[[Has]].
+            }
+        } else if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // 5. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let o = o.get(agent);
+            // 1. If SetDataHas(O.[[SetData]], next) is true, then
+            // b. Return false.
+            let disjoint = agent[other]
+                .values(gc.nogc())
+                .iter()
+                .flatten()
+                .all(|&next| !set_data_has(agent, o, next));
+            return Ok(disjoint.into());
+        } else {
+            // 5. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+            let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. If SetDataHas(O.[[SetData]], next) is true, then
+                if set_data_has(agent, o.get(agent), next) {
+                    // a. Perform ? IteratorClose(keysIter, NormalCompletion(UNUSED)).
+                    // b. Return false.
+                    return iterator_close_with_value(
+                        agent,
+                        iterator.get(agent),
+                        false.into_value(),
+                        gc,
+                    );
+                }
+            }
+        }
+        // 6. Return true.
+        Ok(true.into())
+    }
+
+    /// ### [24.2.4.11 Set.prototype.isSubsetOf ( other )](https://tc39.es/ecma262/#sec-set.prototype.issubsetof)
+    fn is_subset_of<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if i64::from(agent[o.get(agent)].size()) > other_rec.size.into_i64() {
+            return Ok(false.into());
+        }
+        let set_object = other_rec.set_object.scope(agent, gc.nogc());
+        let has = other_rec.has.scope(agent, gc.nogc());
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        let mut index = 0;
+        // 7. Repeat, while index < thisSize,
+        // iv. Set thisSize to the number of elements in O.[[SetData]].
+        while let Some(&e) = agent[o.get(agent)].values(gc.nogc()).get(index) {
+            // a. Let e be O.[[SetData]][index].
+            // b. Set index to index + 1.
+            index += 1;
+            // c. If e is not EMPTY, then
+            let Some(e) = e else {
+                continue;
+            };
+            // i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            let in_other = set_record_has(
+                agent,
+                set_object.get(agent),
+                has.get(agent),
+                e.unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // ii. If inOther is false, return false.
+            if !in_other {
+                return Ok(false.into());
+            }
+            // iii. NOTE: The number of elements in O.[[SetData]] may have
+            //      increased during execution of otherRec.[[Has]].
+        }
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// ### [24.2.4.12 Set.prototype.isSupersetOf ( other )](https://tc39.es/ecma262/#sec-set.prototype.issupersetof)
+    fn is_superset_of<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if i64::from(agent[o.get(agent)].size()) < other_rec.size.into_i64() {
+            return Ok(false.into());
+        }
+        // 5. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let o = o.get(agent);
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            // 2. Return false.
+            // 8. Return true.
+            let superset = agent[other]
+                .values(gc.nogc())
+                .iter()
+                .flatten()
+                .all(|&next| set_data_has(agent, o, next));
+            return Ok(superset.into());
+        }
+        let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+        let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !set_data_has(agent, o.get(agent), next) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(UNUSED)).
+                // 2. Return false.
+                return iterator_close_with_value(
+                    agent,
+                    iterator.get(agent),
+                    false.into_value(),
+                    gc,
+                );
+            }
+        }
+        // 8. Return true.
+        Ok(true.into())
     }
 
     /// ### [24.2.4.14 get Set.prototype.size](https://tc39.es/ecma262/#sec-get-set.prototype.size)
@@ -416,6 +862,166 @@ impl SetPrototype {
         Ok(Number::from(size).into_value())
     }
 
+    /// ### [24.2.4.15 Set.prototype.symmetricDifference ( other )](https://tc39.es/ecma262/#sec-set.prototype.symmetricdifference)
+    fn symmetric_difference<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let other = other.unbind();
+            let gc = gc.into_nogc();
+            let other = other.bind(gc);
+            let o = o.get(agent).bind(gc);
+            // 5. Let resultSetData be a copy of O.[[SetData]].
+            let result = copy_set(agent, o, gc);
+            for next in set_data_values(agent, other, gc) {
+                // iv. If SetDataHas(O.[[SetData]], next) is true, then
+                if set_data_has(agent, o, next) {
+                    // 1. If alreadyInResult is true, set resultSetData[resultIndex] to EMPTY.
+                    set_data_remove(agent, result, next);
+                } else {
+                    // v. Else,
+                    // 1. If alreadyInResult is false, append next to resultSetData.
+                    set_data_append(agent, result, next);
+                }
+            }
+            // 10. Return result.
+            return Ok(result.into_value());
+        }
+        let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+        let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            // ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            // iii. If resultIndex is NOT-FOUND, let alreadyInResult be false.
+            //      Otherwise let alreadyInResult be true.
+            // iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if set_data_has(agent, o.get(agent), next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to EMPTY.
+                set_data_remove(agent, result.get(agent), next);
+            } else {
+                // v. Else,
+                // 1. If alreadyInResult is false, append next to resultSetData.
+                set_data_append(agent, result.get(agent), next);
+            }
+        }
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(result.get(agent).into_value())
+    }
+
+    /// ### [24.2.4.16 Set.prototype.union ( other )](https://tc39.es/ecma262/#sec-set.prototype.union)
+    fn union<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let other = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        if let Some(other) =
+            intrinsic_set_keys(agent, other_rec.set_object, other_rec.keys, gc.nogc())
+        {
+            // Note: keysIter iterates the [[SetData]] of other unobservably.
+            let other = other.unbind();
+            let gc = gc.into_nogc();
+            let other = other.bind(gc);
+            // 5. Let resultSetData be a copy of O.[[SetData]].
+            let result = copy_set(agent, o.get(agent), gc);
+            for next in set_data_values(agent, other, gc) {
+                // ii. If SetDataHas(resultSetData, next) is false, then
+                // 1. Append next to resultSetData.
+                set_data_append(agent, result, next);
+            }
+            // 10. Return result.
+            return Ok(result.into_value());
+        }
+        let keys_iter = get_keys_iterator(agent, other_rec.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = keys_iter.iterator.scope(agent, gc.nogc());
+        let next_method = keys_iter.next_method.scope(agent, gc.nogc());
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            // ii. If SetDataHas(resultSetData, next) is false, then
+            // 1. Append next to resultSetData.
+            set_data_append(agent, result.get(agent), next);
+        }
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(result.get(agent).into_value())
+    }
+
     fn values<'gc>(
         agent: &mut Agent,
         this_value: Value,
@@ -441,15 +1047,20 @@ impl SetPrototype {
         let set_prototype_values = intrinsics.set_prototype_values();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(12)
+            .with_property_capacity(19)
             .with_prototype(object_prototype)
             .with_builtin_function_property::<SetPrototypeAdd>()
             .with_builtin_function_property::<SetPrototypeClear>()
             .with_constructor_property(set_constructor)
             .with_builtin_function_property::<SetPrototypeDelete>()
+            .with_builtin_function_property::<SetPrototypeDifference>()
             .with_builtin_function_property::<SetPrototypeEntries>()
             .with_builtin_function_property::<SetPrototypeForEach>()
-            .with_builtin_function_property::<SetPrototypeHas>()
+            .with_builtin_intrinsic_function_property::<SetPrototypeHas>()
+            .with_builtin_function_property::<SetPrototypeIntersection>()
+            .with_builtin_function_property::<SetPrototypeIsDisjointFrom>()
+            .with_builtin_function_property::<SetPrototypeIsSubsetOf>()
+            .with_builtin_function_property::<SetPrototypeIsSupersetOf>()
             .with_property(|builder| {
                 builder
                     .with_key(BUILTIN_STRING_MEMORY.keys.to_property_key())
@@ -459,6 +1070,8 @@ impl SetPrototype {
                     .build()
             })
             .with_builtin_function_getter_property::<SetPrototypeGetSize>()
+            .with_builtin_function_property::<SetPrototypeSymmetricDifference>()
+            .with_builtin_function_property::<SetPrototypeUnion>()
             .with_builtin_intrinsic_function_property::<SetPrototypeValues>()
             .with_property(|builder| {
                 builder
@@ -508,3 +1121,367 @@ fn set_data_size(set_data: &SetHeapData) -> u32 {
     // 3. Return count.
     set_data.size()
 }
+
+/// ### [24.2.1.1 Set Records](https://tc39.es/ecma262/#sec-set-records)
+///
+/// A Set Record is a Record value used to encapsulate the interface of a Set
+/// or similar object.
+#[derive(Debug, Clone, Copy)]
+struct SetRecord<'a> {
+    /// \[\[SetObject]]: The Set or similar object.
+    set_object: Object<'a>,
+    /// \[\[Size]]: The reported size of the object.
+    size: IntegerOrInfinity,
+    /// \[\[Has]]: The has method of the object.
+    has: Function<'a>,
+    /// \[\[Keys]]: The keys method of the object.
+    keys: Function<'a>,
+}
+
+// SAFETY: Properly implemented as recursive binding.
+unsafe impl Bindable for SetRecord<'_> {
+    type Of<'a> = SetRecord<'a>;
+
+    fn unbind(self) -> Self::Of<'static> {
+        Self::Of {
+            set_object: self.set_object.unbind(),
+            size: self.size,
+            has: self.has.unbind(),
+            keys: self.keys.unbind(),
+        }
+    }
+
+    fn bind<'a>(self, gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        Self::Of {
+            set_object: self.set_object.bind(gc),
+            size: self.size,
+            has: self.has.bind(gc),
+            keys: self.keys.bind(gc),
+        }
+    }
+}
+
+/// ### [24.2.1.2 GetSetRecord ( obj )](https://tc39.es/ecma262/#sec-getsetrecord)
+///
+/// The abstract operation GetSetRecord takes argument obj (an ECMAScript
+/// language value) and returns either a normal completion containing a Set
+/// Record or a throw completion.
+fn get_set_record<'gc>(
+    agent: &mut Agent,
+    obj: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, SetRecord<'gc>> {
+    let obj = obj.bind(gc.nogc());
+    // 1. If obj is not an Object, throw a TypeError exception.
+    let Ok(obj) = Object::try_from(obj) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Argument is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    let scoped_obj = obj.scope(agent, gc.nogc());
+    // 2. Let rawSize be ? Get(obj, "size").
+    let raw_size = get(
+        agent,
+        obj.unbind(),
+        BUILTIN_STRING_MEMORY.size.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. Let numSize be ? ToNumber(rawSize).
+    // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+    let num_size = to_number(agent, raw_size.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 5. If numSize is NaN, throw a TypeError exception.
+    if num_size.is_nan(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Set-like object size is NaN",
+            gc.into_nogc(),
+        ));
+    }
+    // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+    let int_size = to_integer_or_infinity_number(agent, num_size);
+    // 7. If intSize < 0, throw a RangeError exception.
+    if int_size.is_negative() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Set-like object size is negative",
+            gc.into_nogc(),
+        ));
+    }
+    // 8. Let has be ? Get(obj, "has").
+    let has = get(
+        agent,
+        scoped_obj.get(agent),
+        BUILTIN_STRING_MEMORY.has.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 9. If IsCallable(has) is false, throw a TypeError exception.
+    let Some(has) = is_callable(has, gc.nogc()) else {
+        return Err(throw_not_callable(agent, gc.into_nogc()));
+    };
+    let has = has.scope(agent, gc.nogc());
+    // 10. Let keys be ? Get(obj, "keys").
+    let keys = get(
+        agent,
+        scoped_obj.get(agent),
+        BUILTIN_STRING_MEMORY.keys.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 11. If IsCallable(keys) is false, throw a TypeError exception.
+    let Some(keys) = is_callable(keys, gc.nogc()) else {
+        return Err(throw_not_callable(agent, gc.into_nogc()));
+    };
+    let keys = keys.unbind();
+    let gc = gc.into_nogc();
+    // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize,
+    //     [[Has]]: has, [[Keys]]: keys }.
+    // SAFETY: Neither scoped value is shared.
+    let (set_object, has) = unsafe { (scoped_obj.take(agent), has.take(agent)) };
+    Ok(SetRecord {
+        set_object: set_object.bind(gc),
+        size: int_size,
+        has: has.bind(gc),
+        keys: keys.bind(gc),
+    })
+}
+
+/// Performs GetIteratorFromMethod(otherRec.\[\[SetObject]], otherRec.\[\[Keys]])
+/// for a Set Record.
+fn get_keys_iterator<'gc>(
+    agent: &mut Agent,
+    other_rec: SetRecord,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IteratorRecord<'gc>> {
+    let SetRecord {
+        set_object, keys, ..
+    } = other_rec;
+    let Some(keys_iter) = get_iterator_from_method(
+        agent,
+        set_object.into_value().unbind(),
+        keys.unbind(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    else {
+        return Err(throw_not_callable(agent, gc.into_nogc()));
+    };
+    Ok(keys_iter.bind(gc.into_nogc()))
+}
+
+/// Performs ToBoolean(? Call(otherRec.\[\[Has]], otherRec.\[\[SetObject]], « value »)).
+///
+/// If the \[\[SetObject]] is a Set and \[\[Has]] is the current Realm's
+/// %Set.prototype.has%, the call is unobservable and the lookup is performed
+/// directly on the Set's \[\[SetData]].
+fn set_record_has<'gc>(
+    agent: &mut Agent,
+    set_object: Object,
+    has: Function,
+    value: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, bool> {
+    if let Some(set) = intrinsic_set(agent, set_object, has) {
+        return Ok(set_data_has(agent, set, value));
+    }
+    let result = call_function(
+        agent,
+        has.unbind(),
+        set_object.into_value().unbind(),
+        Some(ArgumentsList::from_mut_slice(&mut [value.unbind()])),
+        gc,
+    )?;
+    Ok(to_boolean(agent, result))
+}
+
+/// Returns the Set if the Set Record's \[\[SetObject]] is a Set whose
+/// \[\[Has]] is the current Realm's %Set.prototype.has%.
+fn intrinsic_set<'a>(agent: &Agent, set_object: Object<'a>, has: Function) -> Option<Set<'a>> {
+    let Object::Set(set) = set_object else {
+        return None;
+    };
+    let intrinsic_has = agent
+        .current_realm_record()
+        .intrinsics()
+        .set_prototype_has()
+        .into_function();
+    (has == intrinsic_has).then_some(set)
+}
+
+/// Returns the Set if the Set Record's \[\[SetObject]] is a Set whose
+/// \[\[Keys]] is the current Realm's %Set.prototype.values% and the Realm's
+/// %SetIteratorPrototype%.next is unmodified.
+///
+/// GetIteratorFromMethod(otherRec.\[\[SetObject]], otherRec.\[\[Keys]]) and
+/// stepping the resulting iterator are then unobservable, and the Set's
+/// \[\[SetData]] can be iterated directly as long as no user code runs in
+/// between.
+fn intrinsic_set_keys<'a>(
+    agent: &mut Agent,
+    set_object: Object<'a>,
+    keys: Function,
+    gc: NoGcScope,
+) -> Option<Set<'a>> {
+    let Object::Set(set) = set_object else {
+        return None;
+    };
+    let intrinsics = agent.current_realm_record().intrinsics();
+    if keys != intrinsics.set_prototype_values().into_function() {
+        return None;
+    }
+    let set_iterator_prototype = intrinsics.set_iterator_prototype();
+    let intrinsic_next = intrinsics.set_iterator_prototype_next().into_value();
+    // Note: A getter on the prototype chain makes the lookup observable, in
+    // which case try_get breaks.
+    let TryResult::Continue(next) = set_iterator_prototype.try_get(
+        agent,
+        BUILTIN_STRING_MEMORY.next.into(),
+        set_iterator_prototype.into_value(),
+        gc,
+    ) else {
+        return None;
+    };
+    (next == intrinsic_next).then_some(set)
+}
+
+/// Returns the values in the \[\[SetData]] of `s`, skipping EMPTY elements.
+fn set_data_values<'a>(agent: &Agent, s: Set, gc: NoGcScope<'a, '_>) -> Vec<Value<'a>> {
+    agent[s].values(gc).iter().flatten().copied().collect()
+}
+
+/// Creates a new Set object whose prototype is %Set.prototype% and whose
+/// \[\[SetData]] is a copy of the \[\[SetData]] of `s`.
+fn copy_set<'a>(agent: &mut Agent, s: Set, gc: NoGcScope<'a, '_>) -> Set<'a> {
+    let values = set_data_values(agent, s, gc);
+    let result = agent.heap.create(SetHeapData::default()).bind(gc);
+    for value in values {
+        set_data_append(agent, result, value);
+    }
+    result
+}
+
+/// ### [24.2.1.3 SetDataHas ( setData, value )](https://tc39.es/ecma262/#sec-setdatahas)
+///
+/// The abstract operation SetDataHas takes arguments setData (a List of either
+/// ECMAScript language values or EMPTY) and value (an ECMAScript language
+/// value) and returns a Boolean.
+///
+/// Note: The value is canonicalized using CanonicalizeKeyedCollectionKey
+/// before the lookup.
+fn set_data_has(agent: &Agent, s: Set, value: Value) -> bool {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        ..
+    } = &agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+    let SetData {
+        values, set_data, ..
+    } = &sets[s].borrow(&primitive_heap);
+    let set_data = set_data.borrow();
+
+    let value = canonicalize_keyed_collection_key(&primitive_heap, value);
+    let mut hasher = AHasher::default();
+    let value_hash = {
+        value.hash(&primitive_heap, &mut hasher);
+        hasher.finish()
+    };
+    // 1. If SetDataIndex(setData, value) is NOT-FOUND, return false.
+    // 2. Return true.
+    set_data
+        .find(value_hash, |hash_equal_index| {
+            let found_value = values[*hash_equal_index as usize].unwrap();
+            // Quick check: Equal values have the same value.
+            found_value == value || same_value(&primitive_heap, found_value, value)
+        })
+        .is_some()
+}
+
+/// Appends the canonicalized value to the \[\[SetData]] of `s` if it is not
+/// already present.
+pub(crate) fn set_data_append(agent: &mut Agent, s: Set, value: Value) {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        external_bytes,
+        ..
+    } = &mut agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+
+    let value = canonicalize_keyed_collection_key(numbers, value);
+
+    let SetData {
+        values, set_data, ..
+    } = &mut sets[s].borrow_mut(&primitive_heap);
+    let set_data = set_data.get_mut();
+    let hasher = |value: Value| {
+        let mut hasher = AHasher::default();
+        value.hash(&primitive_heap, &mut hasher);
+        hasher.finish()
+    };
+
+    let value_hash = hasher(value);
+
+    if let hashbrown::hash_table::Entry::Vacant(entry) = set_data.entry(
+        value_hash,
+        |hash_equal_index| {
+            let found_value = values[*hash_equal_index as usize].unwrap();
+            // Quick check: Equal values have the same value.
+            found_value == value || same_value(&primitive_heap, found_value, value)
+        },
+        |index_to_hash| hasher(values[*index_to_hash as usize].unwrap()),
+    ) {
+        let index = u32::try_from(values.len()).unwrap();
+        entry.insert(index);
+        values.push(Some(value.unbind()));
+        *external_bytes += SetData::ENTRY_BYTES;
+    }
+}
+
+/// Replaces the canonicalized value in the \[\[SetData]] of `s` with EMPTY.
+/// Returns true if the value was found.
+fn set_data_remove(agent: &mut Agent, s: Set, value: Value) -> bool {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        ..
+    } = &mut agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+
+    let value = canonicalize_keyed_collection_key(numbers, value);
+    let mut hasher = AHasher::default();
+    let value_hash = {
+        value.hash(&primitive_heap, &mut hasher);
+        hasher.finish()
+    };
+    let SetData {
+        values, set_data, ..
+    } = &mut sets[s].borrow_mut(&primitive_heap);
+    let set_data = set_data.get_mut();
+    if let Ok(entry) = set_data.find_entry(value_hash, |hash_equal_index| {
+        let found_value = values[*hash_equal_index as usize].unwrap();
+        // Quick check: Equal keys have the same value.
+        found_value == value || same_value(&primitive_heap, found_value, value)
+    }) {
+        let index = *entry.get() as usize;
+        values[index] = None;
+        let _ = entry.remove();
+        true
+    } else {
+        false
+    }
+}
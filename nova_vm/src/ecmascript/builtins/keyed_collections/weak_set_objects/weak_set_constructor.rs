@@ -2,13 +2,26 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                IteratorRecord, get_iterator, if_abrupt_close_iterator, iterator_step_value,
+            },
+            operations_on_objects::{call_function, get, throw_not_callable},
+            testing_and_comparison::is_callable,
+        },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, IntoObject, Object, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
     },
     heap::IntrinsicConstructorIndexes,
 };
@@ -26,14 +39,111 @@ impl BuiltinIntrinsicConstructor for WeakSetConstructor {
 }
 
 impl WeakSetConstructor {
+    /// ### [24.4.1.1 WeakSet ( \[ iterable \] )](https://tc39.es/ecma262/#sec-weakset-iterable)
     fn constructor<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        _: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let nogc = gc.nogc();
+        let iterable = arguments.get(0).bind(nogc);
+        let new_target = new_target.bind(nogc);
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor WeakSet requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // 2. Let set be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakSet.prototype%", « [[WeakSetData]] »).
+        // 3. Set set.[[WeakSetData]] to a new empty List.
+        // 4. If iterable is either undefined or null, return set.
+        if iterable.is_undefined() || iterable.is_null() {
+            return ordinary_create_from_constructor(
+                agent,
+                new_target.unbind(),
+                ProtoIntrinsics::WeakSet,
+                gc,
+            )
+            .map(|set| set.into_value());
+        }
+        let iterable = iterable.scope(agent, nogc);
+        let set = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::WeakSet,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .scope(agent, gc.nogc());
+        // 5. Let adder be ? Get(set, "add").
+        let adder = get(
+            agent,
+            set.get(agent),
+            BUILTIN_STRING_MEMORY.add.to_property_key(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        let Some(adder) = is_callable(adder, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "WeakSet.prototype.add is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        let adder = adder.scope(agent, gc.nogc());
+        // 7. Let iteratorRecord be ? GetIterator(iterable, SYNC).
+        let Some(IteratorRecord {
+            iterator,
+            next_method,
+        }) = get_iterator(agent, iterable.get(agent), false, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+        else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.scope(agent, gc.nogc());
+
+        // 8. Repeat,
+        loop {
+            // a. Let next be ? IteratorStepValue(iteratorRecord).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is DONE, return set.
+            let Some(next) = next else {
+                return Ok(set.get(agent).into_value());
+            };
+            // c. Let status be Completion(Call(adder, set, « next »)).
+            let status = call_function(
+                agent,
+                adder.get(agent),
+                set.get(agent).into_value(),
+                Some(ArgumentsList::from_mut_slice(&mut [next.unbind()])),
+                gc.reborrow(),
+            );
+            // d. IfAbruptCloseIterator(status, iteratorRecord).
+            let iterator_record = IteratorRecord {
+                iterator: iterator.get(agent),
+                next_method: next_method.get(agent),
+            };
+            let _ = if_abrupt_close_iterator!(agent, status, iterator_record, gc);
+        }
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
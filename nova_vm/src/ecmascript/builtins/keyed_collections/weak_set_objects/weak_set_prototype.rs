@@ -2,15 +2,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin,
+            weak_set::{WeakSet, data::WeakSetHeapData},
+        },
+        execution::{
+            Agent, JsResult, Realm,
+            agent::{ExceptionType, can_be_held_weakly},
+        },
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
     },
+    engine::context::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbolIndexes,
 };
 
@@ -36,31 +41,85 @@ impl Builtin for WeakSetPrototypeHas {
 }
 
 impl WeakSetPrototype {
+    /// ### [24.4.3.1 WeakSet.prototype.add ( value )](https://tc39.es/ecma262/#sec-weakset.prototype.add)
     fn add<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let value = arguments.get(0).bind(gc);
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let s = require_weak_set_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(value) is false, throw a TypeError exception.
+        if !can_be_held_weakly(agent, value) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid value used in weak set",
+                gc,
+            ));
+        }
+        // 4. For each element e of S.[[WeakSetData]], do
+        // a. If e is not EMPTY and SameValue(e, value) is true, then
+        // i. Return S.
+        // 5. Append value to S.[[WeakSetData]].
+        if agent[s].add(value) {
+            agent.heap.external_bytes += WeakSetHeapData::ENTRY_BYTES;
+        }
+        // 6. Return S.
+        Ok(s.into_value())
     }
 
+    /// ### [24.4.3.3 WeakSet.prototype.delete ( value )](https://tc39.es/ecma262/#sec-weakset.prototype.delete)
     fn delete<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let value = arguments.get(0).bind(gc);
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let s = require_weak_set_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(value) is false, return false.
+        if !can_be_held_weakly(agent, value) {
+            return Ok(false.into());
+        }
+        // 4. For each element e of S.[[WeakSetData]], do
+        // a. If e is not EMPTY and SameValue(e, value) is true, then
+        // i. Replace the element of S.[[WeakSetData]] whose value is e with
+        //    an element whose value is EMPTY.
+        // ii. Return true.
+        // 5. Return false.
+        Ok(agent[s].delete(value).into())
     }
 
+    /// ### [24.4.3.4 WeakSet.prototype.has ( value )](https://tc39.es/ecma262/#sec-weakset.prototype.has)
     fn has<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let value = arguments.get(0).bind(gc);
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let s = require_weak_set_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(value) is false, return false.
+        if !can_be_held_weakly(agent, value) {
+            return Ok(false.into());
+        }
+        // 4. For each element e of S.[[WeakSetData]], do
+        // a. If e is not EMPTY and SameValue(e, value) is true, return true.
+        // 5. Return false.
+        Ok(agent[s].has(value).into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -79,7 +138,7 @@ impl WeakSetPrototype {
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbolIndexes::ToStringTag.into())
-                    .with_value_readonly(BUILTIN_STRING_MEMORY.WeakMap.into_value())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.WeakSet.into_value())
                     .with_enumerable(false)
                     .with_configurable(true)
                     .build()
@@ -87,3 +146,19 @@ impl WeakSetPrototype {
             .build();
     }
 }
+
+#[inline(always)]
+fn require_weak_set_data_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, WeakSet<'a>> {
+    match value {
+        Value::WeakSet(set) => Ok(set.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a WeakSet",
+            gc,
+        )),
+    }
+}
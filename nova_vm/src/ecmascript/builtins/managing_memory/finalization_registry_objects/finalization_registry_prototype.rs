@@ -2,15 +2,19 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, finalization_registry::FinalizationRegistry,
+        },
+        execution::{
+            Agent, JsResult, Realm,
+            agent::{ExceptionType, can_be_held_weakly},
+        },
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
     },
+    engine::context::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbolIndexes,
 };
 
@@ -30,22 +34,90 @@ impl Builtin for FinalizationRegistryPrototypeUnregister {
 }
 
 impl FinalizationRegistryPrototype {
+    /// ### [26.2.3.2 FinalizationRegistry.prototype.register ( target, heldValue \[ , unregisterToken \] )](https://tc39.es/ecma262/#sec-finalization-registry.prototype.register)
     fn register<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let target = arguments.get(0).bind(gc);
+        let held_value = arguments.get(1).bind(gc);
+        let unregister_token = arguments.get(2).bind(gc);
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let finalization_registry =
+            require_finalization_registry_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        if !can_be_held_weakly(agent, target) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid value used as FinalizationRegistry target",
+                gc,
+            ));
+        }
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if target == held_value {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry target and held value must not be the same",
+                gc,
+            ));
+        }
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = if !can_be_held_weakly(agent, unregister_token) {
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            if !unregister_token.is_undefined() {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Invalid value used as FinalizationRegistry unregister token",
+                    gc,
+                ));
+            }
+            // b. Set unregisterToken to EMPTY.
+            None
+        } else {
+            Some(unregister_token)
+        };
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        agent[finalization_registry].register(target, held_value, unregister_token);
+        // 8. Return undefined.
+        Ok(Value::Undefined)
     }
 
+    /// ### [26.2.3.3 FinalizationRegistry.prototype.unregister ( unregisterToken )](https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister)
     fn unregister<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        let unregister_token = arguments.get(0).bind(gc);
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let finalization_registry =
+            require_finalization_registry_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        if !can_be_held_weakly(agent, unregister_token) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid value used as FinalizationRegistry unregister token",
+                gc,
+            ));
+        }
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        // a. If cell.[[UnregisterToken]] is not EMPTY and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        // i. Remove cell from finalizationRegistry.[[Cells]].
+        // ii. Set removed to true.
+        let removed = agent[finalization_registry].unregister(unregister_token);
+        // 6. Return removed.
+        Ok(removed.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -71,3 +143,19 @@ impl FinalizationRegistryPrototype {
             .build();
     }
 }
+
+#[inline(always)]
+fn require_finalization_registry_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, FinalizationRegistry<'a>> {
+    match value {
+        Value::FinalizationRegistry(finalization_registry) => Ok(finalization_registry.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a FinalizationRegistry",
+            gc,
+        )),
+    }
+}
@@ -86,6 +86,12 @@ impl<'a> MapHeapData<'a> {
         &mut self.map_data
     }
 
+    /// Returns the approximate number of bytes used by the entries of the
+    /// Map.
+    pub(crate) fn external_byte_size(&self) -> usize {
+        self.map_data.keys.len() * MapData::ENTRY_BYTES
+    }
+
     pub fn with_capacity(new_len: usize) -> Self {
         Self {
             map_data: MapData {
@@ -100,6 +106,11 @@ impl<'a> MapHeapData<'a> {
 }
 
 impl MapData<'_> {
+    /// Approximate number of bytes used by each entry: the key, the value,
+    /// and the hash table slot.
+    pub(crate) const ENTRY_BYTES: usize =
+        2 * core::mem::size_of::<Option<Value<'static>>>() + core::mem::size_of::<u32>();
+
     fn rehash_if_needed_mut(&mut self, arena: &impl PrimitiveHeapIndexable) {
         if !*self.needs_primitive_rehashing.get_mut() {
             return;
@@ -2,8 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use num_bigint::ToBigInt;
-use num_traits::Pow;
+use num_bigint::{Sign, ToBigInt};
 
 use crate::ecmascript::abstract_operations::testing_and_comparison::is_integral_number;
 use crate::ecmascript::abstract_operations::type_conversion::PreferredType;
@@ -27,7 +26,6 @@ use crate::ecmascript::types::IntoObject;
 use crate::ecmascript::types::IntoValue;
 use crate::ecmascript::types::Number;
 use crate::ecmascript::types::Object;
-use crate::ecmascript::types::bigint::SmallBigInt;
 use crate::ecmascript::types::{String, Value};
 
 use crate::SmallInteger;
@@ -36,6 +34,9 @@ use crate::engine::rootable::Scopable;
 use crate::heap::CreateHeapData;
 use crate::heap::IntrinsicConstructorIndexes;
 
+/// Largest bit size of a BigInt that BigInt.asUintN will construct.
+const MAX_BIGINT_BITS: u32 = 1 << 30;
+
 /// ### [21.1.2.1 BigInt ( value )](https://tc39.es/ecma262/#sec-bigint-constructor)
 pub struct BigIntConstructor;
 
@@ -110,6 +111,7 @@ impl BigIntConstructor {
     ) -> JsResult<'gc, Value<'gc>> {
         let bits = arguments.get(0).bind(gc.nogc());
         let bigint = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set bits to ? ToIndex(bits).
         let bits = to_index(agent, bits.unbind(), gc.reborrow())
             .unbind()?
             .bind(gc.nogc());
@@ -120,6 +122,7 @@ impl BigIntConstructor {
                 gc.into_nogc(),
             ));
         };
+        // 2. Set bigint to ? ToBigInt(bigint).
         let bigint = to_big_int(agent, bigint.get(agent), gc.reborrow())
             .unbind()?
             .bind(gc.nogc());
@@ -127,61 +130,35 @@ impl BigIntConstructor {
             return Ok(BigInt::zero().into_value());
         }
 
-        match 2i64.checked_pow(bits) {
-            Some(divisor) => {
-                match bigint {
-                    BigInt::BigInt(bigint) => {
-                        let modulo = &agent[bigint].data % divisor;
-                        // SAFETY: This cannot overflow since 2^bits didn't.
-                        let divisor_half = divisor >> 1;
-                        if let Ok(modulo) = i64::try_from(&modulo) {
-                            let modulo = if modulo >= divisor_half {
-                                modulo - divisor
-                            } else {
-                                modulo
-                            };
-                            Ok(BigInt::from_i64(agent, modulo).into_value())
-                        } else {
-                            Ok(BigInt::from_num_bigint(agent, modulo - divisor).into_value())
-                        }
-                    }
-                    BigInt::SmallBigInt(bigint) => {
-                        let bigint = bigint.into_i64();
-                        let modulo = bigint.rem_euclid(divisor);
-                        let modulo = if modulo >= 2i64.pow(bits - 1) {
-                            modulo - divisor
-                        } else {
-                            modulo
-                        };
-                        Ok(BigInt::from(SmallBigInt::try_from(modulo).unwrap()).into_value())
-                    }
+        // 3. Let mod be ℝ(bigint) modulo 2^bits.
+        // 4. If mod ≥ 2^(bits - 1), return ℤ(mod - 2^bits); otherwise, return
+        //    ℤ(mod).
+        match bigint {
+            BigInt::BigInt(bigint) => {
+                let data = &agent[bigint].data;
+                // Values in the interval (-2^(bits - 1), 2^(bits - 1)) are
+                // unchanged.
+                if data.bits() < u64::from(bits) {
+                    return Ok(bigint.into_value().unbind());
+                }
+                let divisor = num_bigint::BigInt::from(1u8) << bits;
+                let mut modulo = data % &divisor;
+                if modulo.sign() == Sign::Minus {
+                    modulo += &divisor;
                 }
+                if modulo >= &divisor >> 1u8 {
+                    modulo -= divisor;
+                }
+                Ok(BigInt::from_num_bigint(agent, modulo).into_value())
             }
-            _ => {
-                let divisor =
-                    num_bigint::BigInt::from_bytes_le(num_bigint::Sign::Plus, &[2]).pow(bits);
-                match bigint {
-                    BigInt::BigInt(bigint) => {
-                        let modulo = &agent[bigint].data % &divisor;
-                        let divisor_half = &divisor >> 1;
-                        if let Ok(modulo) = i64::try_from(&modulo) {
-                            // Maybe safe? Maybe not.
-                            Ok(BigInt::from_i64(agent, modulo).into_value())
-                        } else {
-                            let modulo = if modulo >= divisor_half {
-                                modulo - divisor
-                            } else {
-                                modulo
-                            };
-                            Ok(BigInt::from_num_bigint(agent, modulo).into_value())
-                        }
-                    }
-                    BigInt::SmallBigInt(_) => {
-                        // Probably safe: The divisor is bigger than i64 but
-                        // value is i54.
-                        Ok(bigint.into_value().unbind())
-                    }
+            BigInt::SmallBigInt(int) => {
+                let int = int.into_i64();
+                if bits >= 64 {
+                    return Ok(bigint.into_value().unbind());
                 }
+                // Truncate to the lowest bits and sign-extend the result.
+                let shift = 64 - bits;
+                Ok(BigInt::from_i64(agent, (int << shift) >> shift).into_value())
             }
         }
     }
@@ -195,6 +172,7 @@ impl BigIntConstructor {
     ) -> JsResult<'gc, Value<'gc>> {
         let bits = arguments.get(0).bind(gc.nogc());
         let bigint = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set bits to ? ToIndex(bits).
         let bits = to_index(agent, bits.unbind(), gc.reborrow())
             .unbind()?
             .bind(gc.nogc());
@@ -205,15 +183,53 @@ impl BigIntConstructor {
                 gc.into_nogc(),
             ));
         };
-        let bigint = to_big_int(agent, bigint.get(agent), gc.reborrow())
-            .unbind()?
-            .bind(gc.nogc());
+        // 2. Set bigint to ? ToBigInt(bigint).
+        let bigint = to_big_int(agent, bigint.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let bigint = bigint.bind(gc);
+
+        // 3. Return ℤ(ℝ(bigint) modulo 2^bits).
         match bigint {
-            BigInt::BigInt(_) => todo!(),
+            BigInt::BigInt(bigint) => {
+                let data = &agent[bigint].data;
+                if data.sign() != Sign::Minus {
+                    // Non-negative values that fit in bits are unchanged.
+                    if data.bits() <= u64::from(bits) {
+                        return Ok(bigint.into_value());
+                    }
+                } else if bits > MAX_BIGINT_BITS {
+                    // Negative values produce a result of exactly bits size.
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::RangeError,
+                        "Maximum BigInt size exceeded",
+                        gc,
+                    ));
+                }
+                let divisor = num_bigint::BigInt::from(1u8) << bits;
+                let mut modulo = data % &divisor;
+                if modulo.sign() == Sign::Minus {
+                    modulo += divisor;
+                }
+                Ok(BigInt::from_num_bigint(agent, modulo).into_value())
+            }
             BigInt::SmallBigInt(int) => {
                 let int = int.into_i64();
-                let modulo = int.rem_euclid(2i64.pow(bits));
-                Ok(BigInt::from(SmallBigInt::try_from(modulo).unwrap()).into_value())
+                if bits < 64 {
+                    // Truncate the two's complement representation.
+                    let mask = (1u64 << bits) - 1;
+                    Ok(BigInt::from_u64(agent, int as u64 & mask).into_value())
+                } else if int >= 0 {
+                    Ok(bigint.into_value())
+                } else if bits > MAX_BIGINT_BITS {
+                    Err(agent.throw_exception_with_static_message(
+                        ExceptionType::RangeError,
+                        "Maximum BigInt size exceeded",
+                        gc,
+                    ))
+                } else {
+                    let modulo = (num_bigint::BigInt::from(1u8) << bits) + int;
+                    Ok(BigInt::from_num_bigint(agent, modulo).into_value())
+                }
             }
         }
     }
@@ -2,8 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::ecmascript::abstract_operations::type_conversion::to_integer_or_infinity;
 use crate::ecmascript::builtins::Behaviour;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
@@ -14,6 +16,14 @@ use crate::{
     heap::WellKnownSymbolIndexes,
 };
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::{
+    abstract_operations::to_intl_mathematical_value,
+    number_format_objects::{
+        number_format_constructor::construct_number_format, number_format_prototype::format_numeric,
+    },
+};
+
 pub(crate) struct BigIntPrototype;
 
 struct BigIntPrototypeToLocaleString;
@@ -44,6 +54,8 @@ impl Builtin for BigIntPrototypeValueOf {
 }
 
 impl BigIntPrototype {
+    /// ### [21.2.3.2 BigInt.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-bigint.prototype.tolocalestring)
+    #[cfg(not(feature = "intl"))]
     fn to_locale_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
@@ -53,22 +65,71 @@ impl BigIntPrototype {
         Self::to_string(agent, this_value, arguments, gc)
     }
 
-    fn to_string<'gc>(
+    /// ### [20.1.1 BigInt.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring)
+    #[cfg(feature = "intl")]
+    fn to_locale_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let _x = this_big_int_value(agent, this_value, gc.nogc())
+        let locales = arguments.get(0).scope(agent, gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let x be ? ThisBigIntValue(this value).
+        let x = this_big_int_value(agent, this_value, gc.nogc())
             .unbind()?
             .bind(gc.nogc());
-        let radix = arguments.get(0).bind(gc.nogc());
+        let x = to_intl_mathematical_value(agent, x.into_value().unbind(), gc.reborrow()).unwrap();
+        // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales,
+        //    options »).
+        let number_format =
+            construct_number_format(agent, locales.get(agent), options.get(agent), gc.reborrow())
+                .unbind()?;
+        // 3. Return FormatNumeric(numberFormat, ℝ(x)).
+        let result = format_numeric(agent, number_format, &x);
+        Ok(Value::from_string(agent, result, gc.into_nogc()))
+    }
+
+    /// ### [21.2.3.3 BigInt.prototype.toString ( \[ radix \] )](https://tc39.es/ecma262/#sec-bigint.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let radix = arguments.get(0).bind(nogc);
+        // 1. Let x be ? ThisBigIntValue(this value).
+        let x = this_big_int_value(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 2. If radix is undefined, let radixMV be 10.
         if radix.is_undefined() || radix == Value::from(10u8) {
-            // BigInt::to_string_radix_10(agent, x).map(|result| result.into_value())
-            todo!();
+            return Ok(BigInt::to_string_radix_10(agent, x.unbind(), gc.into_nogc()).into_value());
+        }
+        let (x, radix_mv) = if let Value::Integer(radix) = radix {
+            (x.unbind(), radix.into_i64())
         } else {
-            todo!();
+            // 3. Else, let radixMV be ? ToIntegerOrInfinity(radix).
+            let x = x.scope(agent, nogc);
+            let radix_mv = to_integer_or_infinity(agent, radix.unbind(), gc.reborrow())
+                .unbind()?
+                .into_i64();
+            (x.get(agent).unbind(), radix_mv)
+        };
+        let gc = gc.into_nogc();
+        let x = x.bind(gc);
+        // 4. If radixMV is not in the inclusive interval from 2 to 36, throw a
+        //    RangeError exception.
+        if !(2..=36).contains(&radix_mv) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "radix must be an integer at least 2 and no greater than 36",
+                gc,
+            ));
         }
+        // 5. Return BigInt::toString(x, radixMV).
+        Ok(BigInt::to_string_radix_n(agent, x, radix_mv as u32, gc).into_value())
     }
 
     fn value_of<'gc>(
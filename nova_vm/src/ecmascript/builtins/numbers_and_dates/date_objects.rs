@@ -4,3 +4,4 @@
 
 pub mod date_constructor;
 pub mod date_prototype;
+pub(crate) mod time_zone;
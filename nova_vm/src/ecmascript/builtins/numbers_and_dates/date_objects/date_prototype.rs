@@ -2,6 +2,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format_objects::{
+    date_time_format_constructor::{
+        DateTimeFormatDefaults, DateTimeFormatRequired, construct_date_time_format,
+    },
+    date_time_format_prototype::format_date_time,
+};
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::instant::abstract_operations::create_instant_object;
 use crate::{
     SmallInteger,
     ecmascript::{
@@ -18,6 +27,7 @@ use crate::{
                 Date,
                 data::{DateValue, time_clip},
             },
+            numbers_and_dates::date_objects::time_zone::TimeZoneRules,
             text_processing::string_objects::string_prototype::to_zero_padded_decimal_string,
         },
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
@@ -273,6 +283,14 @@ impl Builtin for DatePrototypeToString {
     const LENGTH: u8 = 0;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(DatePrototype::to_string);
 }
+#[cfg(feature = "proposal-temporal")]
+struct DatePrototypeToTemporalInstant;
+#[cfg(feature = "proposal-temporal")]
+impl Builtin for DatePrototypeToTemporalInstant {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toTemporalInstant;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DatePrototype::to_temporal_instant);
+}
 struct DatePrototypeToTimeString;
 impl Builtin for DatePrototypeToTimeString {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.toTimeString;
@@ -1115,12 +1133,12 @@ impl DatePrototype {
     /// the ECMA-402 specification; implementations that do not include
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_date_string<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        to_locale_date_time_string(agent, this_value, arguments, LocaleFormat::Date, gc)
     }
 
     /// ### [21.4.4.39 Date.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
@@ -1139,12 +1157,12 @@ impl DatePrototype {
     /// the ECMA-402 specification; implementations that do not include
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_string<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        to_locale_date_time_string(agent, this_value, arguments, LocaleFormat::DateTime, gc)
     }
 
     /// ### [21.4.4.40 Date.prototype.toLocaleTimeString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring)
@@ -1164,12 +1182,12 @@ impl DatePrototype {
     /// the ECMA-402 specification; implementations that do not include
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_time_string<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        to_locale_date_time_string(agent, this_value, arguments, LocaleFormat::Time, gc)
     }
 
     /// ### [21.4.4.41 Date.prototype.toString ( )](https://tc39.es/ecma262/#sec-date.prototype.tostring)
@@ -1202,6 +1220,33 @@ impl DatePrototype {
         Ok(Value::from_string(agent, s, gc.into_nogc()))
     }
 
+    /// ### [14.1.1 Date.prototype.toTemporalInstant ( )](https://tc39.es/proposal-temporal/#sec-date.prototype.totemporalinstant)
+    #[cfg(feature = "proposal-temporal")]
+    fn to_temporal_instant<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = require_internal_slot_date(agent, this_value, gc)?;
+        // 3. Let t be dateObject.[[DateValue]].
+        let t = date_object.date_value(agent);
+        // 4. Let ns be ? NumberToBigInt(t) × ℤ(10**6).
+        let Some(t) = t.get_f64() else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid Date cannot be converted to a Temporal.Instant",
+                gc,
+            ));
+        };
+        let ns = t as i128 * 1_000_000;
+        // 5. Return ! CreateTemporalInstant(ns).
+        Ok(create_instant_object(agent, ns, gc).into_value())
+    }
+
     /// ### [21.4.4.42 Date.prototype.toTimeString ( )](https://tc39.es/ecma262/#sec-date.prototype.totimestring)
     fn to_time_string<'gc>(
         agent: &mut Agent,
@@ -1391,8 +1436,12 @@ impl DatePrototype {
         let this = intrinsics.date_prototype();
         let date_constructor = intrinsics.date();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(45)
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(if cfg!(feature = "proposal-temporal") {
+                46
+            } else {
+                45
+            })
             .with_prototype(object_prototype)
             .with_constructor_property(date_constructor)
             .with_builtin_function_property::<DatePrototypeGetDate>()
@@ -1434,7 +1483,12 @@ impl DatePrototype {
             .with_builtin_function_property::<DatePrototypeToLocaleDateString>()
             .with_builtin_function_property::<DatePrototypeToLocaleString>()
             .with_builtin_function_property::<DatePrototypeToLocaleTimeString>()
-            .with_builtin_function_property::<DatePrototypeToString>()
+            .with_builtin_function_property::<DatePrototypeToString>();
+
+        #[cfg(feature = "proposal-temporal")]
+        let builder = builder.with_builtin_function_property::<DatePrototypeToTemporalInstant>();
+
+        builder
             .with_builtin_function_property::<DatePrototypeToTimeString>()
             .with_builtin_intrinsic_function_property::<DatePrototypeToUTCString>()
             .with_builtin_function_property::<DatePrototypeValueOf>()
@@ -1443,6 +1497,82 @@ impl DatePrototype {
     }
 }
 
+/// The portion of the Date that the toLocale*String methods format.
+#[derive(Clone, Copy)]
+enum LocaleFormat {
+    DateTime,
+    Date,
+    Time,
+}
+
+/// ### [21.4.4.39 Date.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
+///
+/// Without the ECMA-402 API, the toLocale*String methods return the same
+/// Strings as toString, toDateString and toTimeString.
+#[cfg(not(feature = "intl"))]
+fn to_locale_date_time_string<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    format: LocaleFormat,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    match format {
+        LocaleFormat::DateTime => DatePrototype::to_string(agent, this_value, arguments, gc),
+        LocaleFormat::Date => DatePrototype::to_date_string(agent, this_value, arguments, gc),
+        LocaleFormat::Time => DatePrototype::to_time_string(agent, this_value, arguments, gc),
+    }
+}
+
+/// ### [20.4.1 Date.prototype.toLocaleDateString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring)
+/// ### [20.4.2 Date.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-date.prototype.tolocalestring)
+/// ### [20.4.3 Date.prototype.toLocaleTimeString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring)
+#[cfg(feature = "intl")]
+fn to_locale_date_time_string<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    format: LocaleFormat,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let locales = arguments.get(0).bind(gc.nogc());
+    let options = arguments.get(1).bind(gc.nogc());
+    // 1. Let dateObject be the this value.
+    // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+    let date_object = require_internal_slot_date(agent, this_value, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 3. Let x be dateObject.[[DateValue]].
+    // 4. If x is NaN, return "Invalid Date".
+    let Some(x) = date_object.date_value(agent).get_f64() else {
+        return Ok(Value::from_static_str(
+            agent,
+            "Invalid Date",
+            gc.into_nogc(),
+        ));
+    };
+    // 5. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%,
+    //    locales, options, required, defaults).
+    let (required, defaults) = match format {
+        LocaleFormat::DateTime => (DateTimeFormatRequired::Any, DateTimeFormatDefaults::All),
+        LocaleFormat::Date => (DateTimeFormatRequired::Date, DateTimeFormatDefaults::Date),
+        LocaleFormat::Time => (DateTimeFormatRequired::Time, DateTimeFormatDefaults::Time),
+    };
+    let date_format = construct_date_time_format(
+        agent,
+        locales.unbind(),
+        options.unbind(),
+        required,
+        defaults,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 6. Return ! FormatDateTime(dateFormat, x).
+    let result = format_date_time(agent, date_format, x, gc).unwrap();
+    Ok(Value::from_string(agent, result, gc))
+}
+
 #[inline(always)]
 fn require_internal_slot_date<'a>(
     agent: &mut Agent,
@@ -1475,6 +1605,8 @@ pub(super) const MS_PER_MINUTE: f64 = MS_PER_SECOND * SECONDS_PER_MINUTE;
 const MS_PER_HOUR: f64 = MS_PER_MINUTE * MINUTES_PER_HOUR;
 /// msPerDay = 86400000𝔽 = msPerHour × 𝔽(HoursPerDay)
 const MS_PER_DAY: f64 = MS_PER_HOUR * HOURS_PER_DAY;
+/// Nanoseconds per second, for epoch nanoseconds.
+const NS_PER_SECOND: i128 = 1_000_000_000;
 
 /// ### [21.4.1.3 Day ( t )](https://tc39.es/ecma262/#sec-day)
 ///
@@ -1501,7 +1633,7 @@ fn time_within_day(t: f64) -> f64 {
 /// The abstract operation DaysInYear takes argument y (an integral Number) and
 /// returns 365𝔽 or 366𝔽. It returns the number of days in year y. Leap years
 /// have 366 days; all other years have 365.
-fn days_in_year(y: i32) -> u16 {
+pub(crate) fn days_in_year(y: i32) -> u16 {
     // 1. Let ry be ℝ(y).
     let ry = y;
     // 2. If (ry modulo 400) = 0, return 366𝔽.
@@ -1568,7 +1700,7 @@ fn time_from_year(y: f64) -> f64 {
 /// The abstract operation YearFromTime takes argument t (a finite time value)
 /// and returns an integral Number. It returns the year in which t falls. It
 /// performs the following steps when called:
-fn year_from_time(t: f64) -> i32 {
+pub(crate) fn year_from_time(t: f64) -> i32 {
     const MS_PER_AVERAGE_YEAR: f64 = 12.0 * 30.436_875 * MS_PER_DAY;
 
     // 1. Return the largest integral Number y (closest to +∞) such that TimeFromYear(y) ≤ t.
@@ -1612,7 +1744,7 @@ fn in_leap_year(t: f64) -> u16 {
 /// specifies November; and 11𝔽 specifies December. Note that MonthFromTime
 /// (+0𝔽) = +0𝔽, corresponding to Thursday, 1 January 1970. It performs the
 /// following steps when called:
-fn month_from_time(t: f64) -> u8 {
+pub(crate) fn month_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -1653,7 +1785,7 @@ fn month_from_time(t: f64) -> u8 {
 /// The abstract operation DateFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from 1𝔽 to 31𝔽.
 /// It returns the day of the month in which t falls.
-fn date_from_time(t: f64) -> u8 {
+pub(crate) fn date_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -1702,7 +1834,7 @@ fn date_from_time(t: f64) -> u8 {
 /// 2𝔽 specifies Tuesday; 3𝔽 specifies Wednesday; 4𝔽 specifies Thursday;
 /// 5𝔽 specifies Friday; and 6𝔽 specifies Saturday.
 /// Note that WeekDay(+0𝔽) = 4𝔽, corresponding to Thursday, 1 January 1970.
-fn week_day(t: f64) -> u8 {
+pub(crate) fn week_day(t: f64) -> u8 {
     // 1. Return 𝔽(ℝ(Day(t) + 4𝔽) modulo 7).
     (day(t) + 4.0).rem_euclid(7.0) as u8
 }
@@ -1712,7 +1844,7 @@ fn week_day(t: f64) -> u8 {
 /// The abstract operation HourFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 23𝔽.
 /// It returns the hour of the day in which t falls.
-fn hour_from_time(t: f64) -> u8 {
+pub(crate) fn hour_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerHour)) modulo HoursPerDay).
     ((t / MS_PER_HOUR).floor()).rem_euclid(HOURS_PER_DAY) as u8
 }
@@ -1722,7 +1854,7 @@ fn hour_from_time(t: f64) -> u8 {
 /// The abstract operation MinFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 59𝔽.
 /// It returns the minute of the hour in which t falls.
-pub(super) fn min_from_time(t: f64) -> u8 {
+pub(crate) fn min_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerMinute)) modulo MinutesPerHour).
     ((t / MS_PER_MINUTE).floor()).rem_euclid(MINUTES_PER_HOUR) as u8
 }
@@ -1732,7 +1864,7 @@ pub(super) fn min_from_time(t: f64) -> u8 {
 /// The abstract operation SecFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 59𝔽.
 /// It returns the second of the minute in which t falls.
-fn sec_from_time(t: f64) -> u8 {
+pub(crate) fn sec_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerSecond)) modulo SecondsPerMinute).
     ((t / MS_PER_SECOND).floor()).rem_euclid(SECONDS_PER_MINUTE) as u8
 }
@@ -1742,7 +1874,7 @@ fn sec_from_time(t: f64) -> u8 {
 /// The abstract operation msFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 999𝔽.
 /// It returns the millisecond of the second in which t falls.
-fn ms_from_time(t: f64) -> u16 {
+pub(crate) fn ms_from_time(t: f64) -> u16 {
     // 1. Return 𝔽(ℝ(t) modulo ℝ(msPerSecond)).
     (t.rem_euclid(MS_PER_SECOND)) as u16
 }
@@ -1761,7 +1893,7 @@ fn ms_from_time(t: f64) -> u16 {
 /// since the epoch that corresponds to the given ISO 8601 calendar date and
 /// wall-clock time in UTC.
 #[allow(clippy::too_many_arguments)]
-fn get_utc_epoch_nanoseconds(
+pub(crate) fn get_utc_epoch_nanoseconds(
     year: i32,
     month: u8,
     day: u8,
@@ -1771,7 +1903,7 @@ fn get_utc_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> i64 {
+) -> i128 {
     // 1. Let date be MakeDay(𝔽(year), 𝔽(month - 1), 𝔽(day)).
     let date = make_day(year as f64, (month - 1) as f64, day as f64);
     // 2. Let time be MakeTime(𝔽(hour), 𝔽(minute), 𝔽(second), 𝔽(millisecond)).
@@ -1786,7 +1918,7 @@ fn get_utc_epoch_nanoseconds(
     // 4. Assert: ms is an integral Number.
     assert!(ms.fract() == 0.0);
     // 5. Return ℤ(ℝ(ms) × 10**6 + microsecond × 10**3 + nanosecond).
-    (ms * 1e6 + (microsecond as f64) * 1e3 + (nanosecond as f64)) as i64
+    ms as i128 * 1_000_000 + microsecond as i128 * 1_000 + nanosecond as i128
 }
 
 /// ### [21.4.1.20 GetNamedTimeZoneEpochNanoseconds ( timeZoneIdentifier, year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )](https://tc39.es/ecma262/#sec-getnamedtimezoneepochnanoseconds)
@@ -1837,6 +1969,7 @@ fn get_utc_epoch_nanoseconds(
 /// > 0, 0, 0, 0) would return an empty List.
 #[allow(clippy::too_many_arguments)]
 fn get_named_time_zone_epoch_nanoseconds(
+    agent: &Agent,
     time_zone_identifier: &str,
     year: i32,
     month: u8,
@@ -1847,11 +1980,9 @@ fn get_named_time_zone_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> [i64; 1] {
-    // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
-    // 2. Let epochNanoseconds be GetUTCEpochNanoseconds(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond).
-    let epoch_nanoseconds = get_utc_epoch_nanoseconds(
+) -> Vec<i128> {
+    // Note: The wall-clock time as if it were in UTC.
+    let local_nanoseconds = get_utc_epoch_nanoseconds(
         year,
         month,
         day,
@@ -1862,8 +1993,20 @@ fn get_named_time_zone_epoch_nanoseconds(
         microsecond,
         nanosecond,
     );
-    // 3. Return « epochNanoseconds ».
-    [epoch_nanoseconds]
+    let Some(rules) = named_time_zone_rules(agent, time_zone_identifier) else {
+        // 1. Assert: timeZoneIdentifier is "UTC".
+        // 2. Let epochNanoseconds be GetUTCEpochNanoseconds(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond).
+        // 3. Return « epochNanoseconds ».
+        return vec![local_nanoseconds];
+    };
+    // Each UTC offset at which the wall-clock time occurs gives one instant:
+    // two at a negative transition, none at a positive transition.
+    let local_seconds = local_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+    rules
+        .possible_offsets(local_seconds)
+        .into_iter()
+        .map(|offset| local_nanoseconds - i128::from(offset) * NS_PER_SECOND)
+        .collect()
 }
 
 /// ### [21.4.1.21 GetNamedTimeZoneOffsetNanoseconds ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/ecma262/#sec-getnamedtimezoneoffsetnanoseconds)
@@ -1884,13 +2027,30 @@ fn get_named_time_zone_epoch_nanoseconds(
 /// >
 /// > Time zone offset values may be positive or negative.
 fn get_named_time_zone_offset_nanoseconds(
+    agent: &Agent,
     time_zone_identifier: &str,
-    _epoch_nanoseconds: i64,
+    epoch_nanoseconds: i128,
 ) -> f64 {
-    // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
-    // 2. Return 0.
-    0.0
+    let Some(rules) = named_time_zone_rules(agent, time_zone_identifier) else {
+        // 1. Assert: timeZoneIdentifier is "UTC".
+        // 2. Return 0.
+        return 0.0;
+    };
+    let epoch_seconds = epoch_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+    f64::from(rules.offset_at(epoch_seconds)) * 1e9
+}
+
+/// Returns the local political rules of a named time zone, or None if the
+/// time zone is "UTC".
+///
+/// Only the rules of the system time zone are available.
+fn named_time_zone_rules<'a>(
+    agent: &'a Agent,
+    time_zone_identifier: &str,
+) -> Option<&'a TimeZoneRules> {
+    let system_time_zone = agent.system_time_zone();
+    debug_assert_eq!(system_time_zone.identifier(), time_zone_identifier);
+    system_time_zone.rules()
 }
 
 /// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
@@ -1914,14 +2074,12 @@ fn get_named_time_zone_offset_nanoseconds(
 /// > For example, if the host environment is a browser on a system where the
 /// > user has chosen US Eastern Time as their time zone,
 /// > SystemTimeZoneIdentifier returns "America/New_York".
-fn system_time_zone_identifier(_agent: &Agent) -> &'static str {
+pub(crate) fn system_time_zone_identifier(agent: &Agent) -> &str {
     // 1. If the implementation only supports the UTC time zone, return "UTC".
     // 2. Let systemTimeZoneString be the String representing the host environment's
     // current time zone, either a primary time zone identifier or an offset time zone identifier.
     // 3. Return systemTimeZoneString.
-
-    // TODO: implement this
-    "UTC"
+    agent.system_time_zone().identifier()
 }
 
 /// ### [21.4.1.25 LocalTime ( t )](https://tc39.es/ecma262/#sec-localtime)
@@ -1965,8 +2123,9 @@ fn local_time(agent: &Agent, t: f64) -> f64 {
     else {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(t) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
+            agent,
             system_time_zone_identifier,
-            (t * 1_000_000.0) as i64,
+            t as i128 * 1_000_000,
         )
     };
     // 4. Let offsetMs be truncate(offsetNs / 10**6).
@@ -2036,6 +2195,7 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
     else {
         // a. Let possibleInstants be GetNamedTimeZoneEpochNanoseconds(systemTimeZoneIdentifier, ℝ(YearFromTime(t)), ℝ(MonthFromTime(t)) + 1, ℝ(DateFromTime(t)), ℝ(HourFromTime(t)), ℝ(MinFromTime(t)), ℝ(SecFromTime(t)), ℝ(msFromTime(t)), 0, 0).
         let possible_instants = get_named_time_zone_epoch_nanoseconds(
+            agent,
             system_time_zone_identifier,
             year_from_time(t),
             month_from_time(t) + 1,
@@ -2074,10 +2234,18 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
             // possibleInstantsBefore is not empty (i.e., tBefore represents
             // the last local time before the transition).
             // iii. Let disambiguatedInstant be the last element of possibleInstantsBefore.
-            todo!()
+            // Note: Only the UTC offset of disambiguatedInstant matters, so
+            // we use an instant before the transition instead.
+            let rules = named_time_zone_rules(agent, system_time_zone_identifier).unwrap();
+            let local_seconds = (t as i128 * 1_000_000).div_euclid(NS_PER_SECOND) as i64;
+            i128::from(rules.instant_before_transition(local_seconds)) * NS_PER_SECOND
         };
         // e. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, disambiguatedInstant).
-        get_named_time_zone_offset_nanoseconds(system_time_zone_identifier, disambiguated_instant)
+        get_named_time_zone_offset_nanoseconds(
+            agent,
+            system_time_zone_identifier,
+            disambiguated_instant,
+        )
     };
     // 5. Let offsetMs be truncate(offsetNs / 10**6).
     let offset_ms = (offset_ns / 1_000_000.0).trunc();
@@ -2096,7 +2264,7 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
 /// > The arithmetic in MakeTime is floating-point arithmetic,
 /// > which is not associative, so the operations must be performed in the
 /// > correct order.
-pub(super) fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+pub(crate) fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
     // 1. If hour is not finite, min is not finite, sec is not finite, or ms is not finite, return NaN.
     if !hour.is_finite() || !min.is_finite() || !sec.is_finite() || !ms.is_finite() {
         return f64::NAN;
@@ -2131,7 +2299,7 @@ pub(super) fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
 /// The abstract operation MakeDay takes arguments year (a Number),
 /// month (a Number), and date (a Number) and returns a Number.
 /// It calculates a number of days.
-pub(super) fn make_day(year: f64, month: f64, date: f64) -> f64 {
+pub(crate) fn make_day(year: f64, month: f64, date: f64) -> f64 {
     // 1. If year is not finite, month is not finite, or date is not finite, return NaN.
     if !year.is_finite() || !month.is_finite() || !date.is_finite() {
         return f64::NAN;
@@ -2191,7 +2359,7 @@ pub(super) fn make_day(year: f64, month: f64, date: f64) -> f64 {
 ///
 /// The abstract operation MakeDate takes arguments day (a Number) and time (a Number)
 /// and returns a Number. It calculates a number of milliseconds.
-pub(super) fn make_date(day: f64, time: f64) -> f64 {
+pub(crate) fn make_date(day: f64, time: f64) -> f64 {
     // 1. If day is not finite or time is not finite, return NaN.
     if !day.is_finite() || !time.is_finite() {
         return f64::NAN;
@@ -2242,11 +2410,11 @@ pub(super) fn make_full_year(year: f64) -> f64 {
 /// (a String) and returns a Boolean. The return value indicates whether
 /// offsetString conforms to the grammar given by UTCOffset. It performs the
 /// following steps when called:
-fn is_time_zone_offset_string(_offset_string: &str) -> bool {
+pub(crate) fn is_time_zone_offset_string(offset_string: &str) -> bool {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
-    let parse_result: Result<(), ()> = Err(()); // TODO:
+    let parse_result = parse_utc_offset(offset_string);
     // 2. If parseResult is a List of errors, return false.
-    if parse_result.is_err() {
+    if parse_result.is_none() {
         return false;
     }
     // 3. Return true.
@@ -2259,29 +2427,34 @@ fn is_time_zone_offset_string(_offset_string: &str) -> bool {
 /// offsetString (a String) and returns an integer. The return value is the UTC
 /// offset, as a number of nanoseconds, that corresponds to the String
 /// offsetString.
-fn parse_time_zone_offset_string(_offset_string: &str) -> f64 {
+pub(crate) fn parse_time_zone_offset_string(offset_string: &str) -> f64 {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
     // 2. Assert: parseResult is not a List of errors.
+    let parse_result = parse_utc_offset(offset_string).unwrap();
     // 3. Assert: parseResult contains a ASCIISign Parse Node.
     // 4. Let parsedSign be the source text matched by the ASCIISign Parse Node contained within parseResult.
     // 5. If parsedSign is the single code point U+002D (HYPHEN-MINUS), then
     //    a. Let sign be -1.
     // 6. Else,
     //    a. Let sign be 1.
+    let sign = if parse_result.negative { -1.0 } else { 1.0 };
     // 7. NOTE: Applications of StringToNumber below do not lose precision, since each of the parsed values is guaranteed to be a sufficiently short string of decimal digits.
     // 8. Assert: parseResult contains an Hour Parse Node.
     // 9. Let parsedHours be the source text matched by the Hour Parse Node contained within parseResult.
     // 10. Let hours be ℝ(StringToNumber(CodePointsToString(parsedHours))).
+    let hours = f64::from(parse_result.hours);
     // 11. If parseResult does not contain a MinuteSecond Parse Node, then
     //     a. Let minutes be 0.
     // 12. Else,
     //     a. Let parsedMinutes be the source text matched by the first MinuteSecond Parse Node contained within parseResult.
     //     b. Let minutes be ℝ(StringToNumber(CodePointsToString(parsedMinutes))).
+    let minutes = f64::from(parse_result.minutes);
     // 13. If parseResult does not contain two MinuteSecond Parse Nodes, then
     //     a. Let seconds be 0.
     // 14. Else,
     //     a. Let parsedSeconds be the source text matched by the second MinuteSecond Parse Node contained within parseResult.
     //     b. Let seconds be ℝ(StringToNumber(CodePointsToString(parsedSeconds))).
+    let seconds = f64::from(parse_result.seconds);
     // 15. If parseResult does not contain a TemporalDecimalFraction Parse Node, then
     //     a. Let nanoseconds be 0.
     // 16. Else,
@@ -2289,8 +2462,97 @@ fn parse_time_zone_offset_string(_offset_string: &str) -> f64 {
     //     b. Let fraction be the string-concatenation of CodePointsToString(parsedFraction) and "000000000".
     //     c. Let nanosecondsString be the substring of fraction from 1 to 10.
     //     d. Let nanoseconds be ℝ(StringToNumber(nanosecondsString)).
+    let nanoseconds = f64::from(parse_result.nanoseconds);
     // 17. Return sign × (((hours × 60 + minutes) × 60 + seconds) × 10**9 + nanoseconds).
-    todo!()
+    sign * (((hours * 60.0 + minutes) * 60.0 + seconds) * 1e9 + nanoseconds)
+}
+
+/// The parts of a string matched by UTCOffset.
+struct UtcOffset {
+    negative: bool,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    nanoseconds: u32,
+}
+
+/// Parses the text of offsetString using UTCOffset as the goal symbol.
+///
+/// ```text
+/// UTCOffset :::
+///     ASCIISign Hour
+///     ASCIISign Hour HourSubcomponents[+Extended]
+///     ASCIISign Hour HourSubcomponents[~Extended]
+///
+/// HourSubcomponents[Extended] :::
+///     TimeSeparator[?Extended] MinuteSecond
+///     TimeSeparator[?Extended] MinuteSecond TimeSeparator[?Extended] MinuteSecond TemporalDecimalFraction_opt
+/// ```
+fn parse_utc_offset(offset_string: &str) -> Option<UtcOffset> {
+    let bytes = offset_string.as_bytes();
+    // ASCIISign ::: one of + -
+    let negative = match bytes.first()? {
+        b'+' => false,
+        b'-' => true,
+        _ => return None,
+    };
+    let two_digits = |index: usize, max: u8| -> Option<u8> {
+        match bytes.get(index..index + 2)? {
+            &[tens @ b'0'..=b'9', ones @ b'0'..=b'9'] => {
+                let value = (tens - b'0') * 10 + (ones - b'0');
+                (value <= max).then_some(value)
+            }
+            _ => None,
+        }
+    };
+    // Hour ::: 0 DecimalDigit | 1 DecimalDigit | 20 | 21 | 22 | 23
+    let hours = two_digits(1, 23)?;
+    let mut result = UtcOffset {
+        negative,
+        hours,
+        minutes: 0,
+        seconds: 0,
+        nanoseconds: 0,
+    };
+    if bytes.len() == 3 {
+        return Some(result);
+    }
+    // TimeSeparator[Extended] ::: [+Extended] : | [~Extended] [empty]
+    let extended = bytes[3] == b':';
+    let separator_length = usize::from(extended);
+    // MinuteSecond ::: 0 DecimalDigit | 1 DecimalDigit | ... | 5 DecimalDigit
+    let mut index = 3 + separator_length;
+    result.minutes = two_digits(index, 59)?;
+    index += 2;
+    if index == bytes.len() {
+        return Some(result);
+    }
+    if extended && bytes[index] != b':' {
+        return None;
+    }
+    index += separator_length;
+    result.seconds = two_digits(index, 59)?;
+    index += 2;
+    if index == bytes.len() {
+        return Some(result);
+    }
+    // TemporalDecimalFraction ::: TemporalDecimalSeparator DecimalDigit{1,9}
+    // TemporalDecimalSeparator ::: one of . ,
+    if !matches!(bytes[index], b'.' | b',') {
+        return None;
+    }
+    let fraction = &bytes[index + 1..];
+    if fraction.is_empty() || fraction.len() > 9 || !fraction.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    result.nanoseconds = fraction
+        .iter()
+        .chain(core::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |nanoseconds, digit| {
+            nanoseconds * 10 + u32::from(digit - b'0')
+        });
+    Some(result)
 }
 
 /// ### [21.4.4.41.1 TimeString ( tv )](https://tc39.es/ecma262/#sec-timestring)
@@ -2369,8 +2631,9 @@ fn time_zone_string(agent: &Agent, tv: f64) -> std::string::String {
     else {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(tv) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
+            agent,
             system_time_zone_identifier,
-            (tv * 1_000_000.0) as i64,
+            tv as i128 * 1_000_000,
         )
     };
     // 4. Let offset be 𝔽(truncate(offsetNs / 10**6)).
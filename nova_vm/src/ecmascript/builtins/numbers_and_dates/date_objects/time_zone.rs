@@ -0,0 +1,805 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Local political rules of the host time zone.
+//!
+//! Named time zones are read from the TZif files ([RFC 8536](https://www.rfc-editor.org/rfc/rfc8536))
+//! of the IANA Time Zone Database installed on the host, by default in
+//! `/usr/share/zoneinfo`. The directory can be changed using the `TZDIR`
+//! environment variable. Instants after the last transition of a TZif file
+//! are resolved using the POSIX TZ string footer of the file.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use super::date_prototype::is_time_zone_offset_string;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The time zone returned by SystemTimeZoneIdentifier, together with the
+/// political rules needed to resolve local times in it.
+#[derive(Debug)]
+pub(crate) struct SystemTimeZone {
+    /// Either a time zone identifier or a UTC offset string.
+    identifier: Box<str>,
+    /// The rules of a named time zone. If the identifier is "UTC" or a UTC
+    /// offset string, then this is None.
+    rules: Option<Arc<TimeZoneRules>>,
+}
+
+impl SystemTimeZone {
+    /// Resolve the host time zone.
+    ///
+    /// The time zone override given through the Agent Options takes
+    /// precedence over the `TZ` environment variable, which in turn takes
+    /// precedence over the system time zone configured in `/etc/localtime`.
+    /// If the time zone cannot be resolved, UTC is used.
+    pub(crate) fn new(time_zone_override: Option<&str>) -> Self {
+        if let Some(time_zone) = time_zone_override {
+            return Self::from_tz(time_zone).unwrap_or_else(Self::utc);
+        }
+        if let Ok(time_zone) = std::env::var("TZ") {
+            return Self::from_tz(&time_zone).unwrap_or_else(Self::utc);
+        }
+        Self::from_localtime().unwrap_or_else(Self::utc)
+    }
+
+    pub(crate) fn utc() -> Self {
+        Self {
+            identifier: "UTC".into(),
+            rules: None,
+        }
+    }
+
+    pub(crate) fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    pub(crate) fn rules(&self) -> Option<&TimeZoneRules> {
+        self.rules.as_deref()
+    }
+
+    /// Returns a shared reference to the rules of a named time zone.
+    pub(crate) fn shared_rules(&self) -> Option<Arc<TimeZoneRules>> {
+        self.rules.clone()
+    }
+
+    /// Resolve a time zone given in the format of the `TZ` environment
+    /// variable: a UTC offset string, a time zone identifier, a path to a
+    /// TZif file, or a POSIX TZ string.
+    fn from_tz(time_zone: &str) -> Option<Self> {
+        let time_zone = time_zone.strip_prefix(':').unwrap_or(time_zone);
+        if time_zone.is_empty() || is_utc_identifier(time_zone) {
+            return Some(Self::utc());
+        }
+        if is_time_zone_offset_string(time_zone) {
+            return Some(Self {
+                identifier: time_zone.into(),
+                rules: None,
+            });
+        }
+        if Path::new(time_zone).is_absolute() {
+            let rules = TimeZoneRules::from_tzif(&std::fs::read(time_zone).ok()?)?;
+            let identifier =
+                identifier_from_path(Path::new(time_zone)).unwrap_or_else(|| time_zone.to_string());
+            return Some(Self::named(identifier, rules));
+        }
+        if let Some(rules) = TimeZoneRules::from_identifier(time_zone) {
+            return Some(Self::named(time_zone.to_string(), rules));
+        }
+        // Note: A POSIX TZ string is not a time zone identifier, but it is the
+        // best name we have for the time zone.
+        let rules = TimeZoneRules::from_posix_tz(time_zone)?;
+        Some(Self::named(time_zone.to_string(), rules))
+    }
+
+    /// Resolve the system time zone configured in `/etc/localtime`.
+    fn from_localtime() -> Option<Self> {
+        let rules = TimeZoneRules::from_tzif(&std::fs::read("/etc/localtime").ok()?)?;
+        let identifier = std::fs::read_link("/etc/localtime")
+            .ok()
+            .and_then(|target| identifier_from_path(&target))
+            .or_else(|| {
+                std::fs::read_to_string("/etc/timezone")
+                    .ok()
+                    .map(|identifier| identifier.trim().to_string())
+                    .filter(|identifier| is_valid_identifier(identifier))
+            })
+            // Note: This is the CLDR identifier of an unknown time zone.
+            .unwrap_or_else(|| "Etc/Unknown".to_string());
+        if is_utc_identifier(&identifier) {
+            return Some(Self::utc());
+        }
+        Some(Self::named(identifier, rules))
+    }
+
+    fn named(identifier: std::string::String, rules: TimeZoneRules) -> Self {
+        Self {
+            identifier: identifier.into_boxed_str(),
+            rules: Some(Arc::new(rules)),
+        }
+    }
+}
+
+/// The local political rules of a named time zone.
+#[derive(Debug)]
+pub(crate) struct TimeZoneRules {
+    /// Transition instants in seconds since the epoch, in ascending order.
+    transitions: Box<[i64]>,
+    /// UTC offset in seconds in effect from each transition onwards.
+    offsets: Box<[i32]>,
+    /// UTC offset in seconds in effect before the first transition.
+    initial_offset: i32,
+    /// Rule for instants after the last transition.
+    rule: Option<PosixTimeZone>,
+}
+
+impl TimeZoneRules {
+    /// Load the rules of a time zone from the time zone database.
+    pub(crate) fn from_identifier(identifier: &str) -> Option<Self> {
+        if !is_valid_identifier(identifier) {
+            return None;
+        }
+        let data = std::fs::read(time_zone_database_directory().join(identifier)).ok()?;
+        Self::from_tzif(&data)
+    }
+
+    /// Returns the UTC offset in seconds at the given number of seconds since
+    /// the epoch.
+    pub(crate) fn offset_at(&self, epoch_seconds: i64) -> i32 {
+        let index = self
+            .transitions
+            .partition_point(|&transition| transition <= epoch_seconds);
+        if index == self.transitions.len() {
+            if let Some(rule) = &self.rule {
+                return rule.offset_at(epoch_seconds);
+            }
+        }
+        if index == 0 {
+            self.initial_offset
+        } else {
+            self.offsets[index - 1]
+        }
+    }
+
+    /// Returns the UTC offsets in seconds with which the given local time in
+    /// seconds since the epoch corresponds to an instant.
+    ///
+    /// A local time repeated at a negative transition has two offsets, the
+    /// offset before the transition coming first. A local time skipped at a
+    /// positive transition has none.
+    pub(crate) fn possible_offsets(&self, local_seconds: i64) -> Vec<i32> {
+        // Note: This assumes that the time zone does not transition more than
+        // once within a day of the local time.
+        let before = self.offset_at(self.instant_before_transition(local_seconds));
+        let after = self.offset_at(local_seconds.saturating_add(SECONDS_PER_DAY));
+        let mut offsets = Vec::with_capacity(2);
+        for offset in [before, after] {
+            if !offsets.contains(&offset)
+                && self.offset_at(local_seconds - i64::from(offset)) == offset
+            {
+                offsets.push(offset);
+            }
+        }
+        // Instants in ascending order: a larger offset is an earlier instant.
+        offsets.sort_unstable_by(|a, b| b.cmp(a));
+        offsets
+    }
+
+    /// Returns an instant in seconds since the epoch that precedes any
+    /// transition around the given local time in seconds since the epoch.
+    pub(crate) fn instant_before_transition(&self, local_seconds: i64) -> i64 {
+        local_seconds.saturating_sub(SECONDS_PER_DAY)
+    }
+
+    /// Returns the first instant in seconds since the epoch after the given
+    /// one at which the UTC offset changes, if any.
+    pub(crate) fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let index = self
+            .transitions
+            .partition_point(|&transition| transition <= epoch_seconds);
+        let next = self.transitions[index..]
+            .iter()
+            .copied()
+            .find(|&transition| self.offset_at(transition - 1) != self.offset_at(transition));
+        if next.is_some() {
+            return next;
+        }
+        let last_transition = self.transitions.last().copied().unwrap_or(i64::MIN);
+        self.rule
+            .as_ref()?
+            .next_transition(epoch_seconds.max(last_transition))
+    }
+
+    /// Returns the last instant in seconds since the epoch before the given
+    /// one at which the UTC offset changes, if any.
+    pub(crate) fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        if let Some(rule) = &self.rule {
+            let previous = rule.previous_transition(epoch_seconds);
+            let last_transition = self.transitions.last().copied();
+            if let Some(previous) = previous.filter(|&previous| {
+                last_transition.is_none_or(|last_transition| previous > last_transition)
+            }) {
+                return Some(previous);
+            }
+        }
+        let index = self
+            .transitions
+            .partition_point(|&transition| transition < epoch_seconds);
+        self.transitions[..index]
+            .iter()
+            .rev()
+            .copied()
+            .find(|&transition| self.offset_at(transition - 1) != self.offset_at(transition))
+    }
+
+    /// Parse a TZif file.
+    fn from_tzif(data: &[u8]) -> Option<Self> {
+        let (version, counts) = parse_tzif_header(data)?;
+        let v1_length = counts.block_length(4);
+        let rules = if version == 0 {
+            parse_tzif_block(&data[TZIF_HEADER_LENGTH..], &counts, 4)?.0
+        } else {
+            // Version 2 and later files repeat the data with 64-bit
+            // transition times, followed by a POSIX TZ string footer.
+            let data = data.get(TZIF_HEADER_LENGTH + v1_length..)?;
+            let (_, counts) = parse_tzif_header(data)?;
+            let (mut rules, footer) = parse_tzif_block(&data[TZIF_HEADER_LENGTH..], &counts, 8)?;
+            let footer = footer.strip_prefix(b"\n")?;
+            let end = footer.iter().position(|&byte| byte == b'\n')?;
+            let footer = core::str::from_utf8(&footer[..end]).ok()?;
+            if !footer.is_empty() {
+                rules.rule = Some(PosixTimeZone::parse(footer)?);
+            }
+            rules
+        };
+        Some(rules)
+    }
+
+    /// Parse a POSIX TZ string, e.g. `EST5EDT,M3.2.0,M11.1.0`.
+    fn from_posix_tz(tz: &str) -> Option<Self> {
+        let rule = PosixTimeZone::parse(tz)?;
+        Some(Self {
+            transitions: Box::default(),
+            offsets: Box::default(),
+            initial_offset: rule.std_offset,
+            rule: Some(rule),
+        })
+    }
+}
+
+const TZIF_HEADER_LENGTH: usize = 44;
+
+/// The counts of a TZif header.
+struct TzifCounts {
+    is_ut_count: usize,
+    is_std_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl TzifCounts {
+    fn block_length(&self, time_size: usize) -> usize {
+        self.time_count * time_size
+            + self.time_count
+            + self.type_count * 6
+            + self.char_count
+            + self.leap_count * (time_size + 4)
+            + self.is_std_count
+            + self.is_ut_count
+    }
+}
+
+fn parse_tzif_header(data: &[u8]) -> Option<(u8, TzifCounts)> {
+    let header = data.get(..TZIF_HEADER_LENGTH)?;
+    if &header[..4] != b"TZif" {
+        return None;
+    }
+    let version = match header[4] {
+        0 => 0,
+        version @ b'2'..=b'9' => version - b'0',
+        _ => return None,
+    };
+    let count = |index: usize| {
+        let start = 20 + index * 4;
+        u32::from_be_bytes(header[start..start + 4].try_into().unwrap()) as usize
+    };
+    let counts = TzifCounts {
+        is_ut_count: count(0),
+        is_std_count: count(1),
+        leap_count: count(2),
+        time_count: count(3),
+        type_count: count(4),
+        char_count: count(5),
+    };
+    if counts.type_count == 0 {
+        return None;
+    }
+    Some((version, counts))
+}
+
+/// Parse a TZif data block, returning the rules and the rest of the data.
+fn parse_tzif_block<'a>(
+    data: &'a [u8],
+    counts: &TzifCounts,
+    time_size: usize,
+) -> Option<(TimeZoneRules, &'a [u8])> {
+    let block = data.get(..counts.block_length(time_size))?;
+    let (times, rest) = block.split_at(counts.time_count * time_size);
+    let (type_indices, rest) = rest.split_at(counts.time_count);
+    let (types, _) = rest.split_at(counts.type_count * 6);
+    // Each local time type is a 32-bit UTC offset, a DST flag and an
+    // abbreviation index.
+    let type_offset = |index: usize| -> Option<i32> {
+        let local_time_type = types.get(index * 6..index * 6 + 4)?;
+        Some(i32::from_be_bytes(local_time_type.try_into().unwrap()))
+    };
+    let transitions = times
+        .chunks_exact(time_size)
+        .map(|time| match time_size {
+            4 => i64::from(i32::from_be_bytes(time.try_into().unwrap())),
+            _ => i64::from_be_bytes(time.try_into().unwrap()),
+        })
+        .collect::<Box<[i64]>>();
+    let offsets = type_indices
+        .iter()
+        .map(|&index| type_offset(usize::from(index)))
+        .collect::<Option<Box<[i32]>>>()?;
+    let rules = TimeZoneRules {
+        transitions,
+        offsets,
+        // Local time for timestamps before the first transition is specified
+        // by the first time type.
+        initial_offset: type_offset(0)?,
+        rule: None,
+    };
+    Some((rules, &data[block.len()..]))
+}
+
+/// A POSIX TZ string: a standard time offset and optional daylight saving
+/// time rules.
+#[derive(Debug)]
+struct PosixTimeZone {
+    /// Standard time UTC offset in seconds.
+    std_offset: i32,
+    dst: Option<PosixDaylightSavingTime>,
+}
+
+#[derive(Debug)]
+struct PosixDaylightSavingTime {
+    /// Daylight saving time UTC offset in seconds.
+    offset: i32,
+    start: PosixTransitionRule,
+    end: PosixTransitionRule,
+}
+
+/// The local time of a transition in a year.
+#[derive(Debug)]
+struct PosixTransitionRule {
+    date: PosixDate,
+    /// Local time of day in seconds, possibly negative or more than a day.
+    time: i64,
+}
+
+#[derive(Debug)]
+enum PosixDate {
+    /// `Jn`: Julian day from 1 to 365, February 29 is never counted.
+    JulianWithoutLeapDay(u16),
+    /// `n`: Zero-based Julian day from 0 to 365, February 29 is counted.
+    Julian(u16),
+    /// `Mm.w.d`: Day d (0 is Sunday) of week w (5 is the last week) of month
+    /// m.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl PosixTimeZone {
+    fn parse(tz: &str) -> Option<Self> {
+        let mut parser = PosixParser {
+            rest: tz.as_bytes(),
+        };
+        parser.parse_name()?;
+        // Note: POSIX offsets are positive west of Greenwich.
+        let std_offset = -parser.parse_offset()?;
+        if parser.rest.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+        parser.parse_name()?;
+        let offset = match parser.rest.first() {
+            Some(b'0'..=b'9' | b'+' | b'-') => -parser.parse_offset()?,
+            _ => std_offset + 3600,
+        };
+        let (start, end) = if parser.rest.is_empty() {
+            // Note: The default rules are those of the United States.
+            (
+                PosixTransitionRule {
+                    date: PosixDate::MonthWeekDay {
+                        month: 3,
+                        week: 2,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+                PosixTransitionRule {
+                    date: PosixDate::MonthWeekDay {
+                        month: 11,
+                        week: 1,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+            )
+        } else {
+            parser.expect(b',')?;
+            let start = parser.parse_transition_rule()?;
+            parser.expect(b',')?;
+            let end = parser.parse_transition_rule()?;
+            (start, end)
+        };
+        if !parser.rest.is_empty() {
+            return None;
+        }
+        Some(Self {
+            std_offset,
+            dst: Some(PosixDaylightSavingTime { offset, start, end }),
+        })
+    }
+
+    fn offset_at(&self, epoch_seconds: i64) -> i32 {
+        let Some(dst) = &self.dst else {
+            return self.std_offset;
+        };
+        let year = year_from_days(
+            (epoch_seconds + i64::from(self.std_offset)).div_euclid(SECONDS_PER_DAY),
+        );
+        // The start is given in standard time and the end in daylight saving
+        // time.
+        let start = dst.start.local_seconds(year) - i64::from(self.std_offset);
+        let end = dst.end.local_seconds(year) - i64::from(dst.offset);
+        let is_dst = if start < end {
+            start <= epoch_seconds && epoch_seconds < end
+        } else {
+            // Southern hemisphere: daylight saving time spans the new year.
+            !(end <= epoch_seconds && epoch_seconds < start)
+        };
+        if is_dst { dst.offset } else { self.std_offset }
+    }
+
+    /// Returns the instants in seconds since the epoch of the daylight saving
+    /// time transitions around the given instant, in no particular order.
+    fn transitions_around(&self, epoch_seconds: i64) -> Vec<i64> {
+        let Some(dst) = self
+            .dst
+            .as_ref()
+            .filter(|dst| dst.offset != self.std_offset)
+        else {
+            return Vec::new();
+        };
+        let year = year_from_days(
+            (epoch_seconds + i64::from(self.std_offset)).div_euclid(SECONDS_PER_DAY),
+        );
+        (year - 1..=year + 1)
+            .flat_map(|year| {
+                [
+                    dst.start.local_seconds(year) - i64::from(self.std_offset),
+                    dst.end.local_seconds(year) - i64::from(dst.offset),
+                ]
+            })
+            .collect()
+    }
+
+    fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        self.transitions_around(epoch_seconds)
+            .into_iter()
+            .filter(|&transition| transition > epoch_seconds)
+            .min()
+    }
+
+    fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        self.transitions_around(epoch_seconds)
+            .into_iter()
+            .filter(|&transition| transition < epoch_seconds)
+            .max()
+    }
+}
+
+impl PosixTransitionRule {
+    /// Returns the local time of the transition in the given year in seconds
+    /// since the epoch.
+    fn local_seconds(&self, year: i64) -> i64 {
+        let days = match self.date {
+            PosixDate::JulianWithoutLeapDay(day) => {
+                let day = i64::from(day);
+                let leap_day = i64::from(is_leap_year(year) && day >= 60);
+                days_from_civil(year, 1, 1) + day - 1 + leap_day
+            }
+            PosixDate::Julian(day) => days_from_civil(year, 1, 1) + i64::from(day),
+            PosixDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + (i64::from(week) - 1) * 7;
+                let days_in_month = days_from_civil(
+                    if month == 12 { year + 1 } else { year },
+                    if month == 12 { 1 } else { month + 1 },
+                    1,
+                ) - first;
+                while day >= days_in_month {
+                    day -= 7;
+                }
+                first + day
+            }
+        };
+        days * SECONDS_PER_DAY + self.time
+    }
+}
+
+struct PosixParser<'a> {
+    rest: &'a [u8],
+}
+
+impl PosixParser<'_> {
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        let (&first, rest) = self.rest.split_first()?;
+        if first != byte {
+            return None;
+        }
+        self.rest = rest;
+        Some(())
+    }
+
+    /// Parse a time zone abbreviation: either at least three letters, or any
+    /// characters quoted in angle brackets.
+    fn parse_name(&mut self) -> Option<()> {
+        let length = if self.rest.first() == Some(&b'<') {
+            let end = self.rest.iter().position(|&byte| byte == b'>')?;
+            self.rest = &self.rest[end + 1..];
+            return Some(());
+        } else {
+            self.rest
+                .iter()
+                .position(|byte| !byte.is_ascii_alphabetic())
+                .unwrap_or(self.rest.len())
+        };
+        if length < 3 {
+            return None;
+        }
+        self.rest = &self.rest[length..];
+        Some(())
+    }
+
+    fn parse_number(&mut self, max_digits: usize) -> Option<i64> {
+        let length = self
+            .rest
+            .iter()
+            .take(max_digits)
+            .position(|byte| !byte.is_ascii_digit())
+            .unwrap_or(self.rest.len().min(max_digits));
+        if length == 0 {
+            return None;
+        }
+        let (digits, rest) = self.rest.split_at(length);
+        self.rest = rest;
+        Some(
+            digits
+                .iter()
+                .fold(0, |number, digit| number * 10 + i64::from(digit - b'0')),
+        )
+    }
+
+    /// Parse `[+|-]hh[:mm[:ss]]` as seconds.
+    fn parse_time(&mut self) -> Option<i64> {
+        let sign = match self.rest.first() {
+            Some(b'-') => {
+                self.rest = &self.rest[1..];
+                -1
+            }
+            Some(b'+') => {
+                self.rest = &self.rest[1..];
+                1
+            }
+            _ => 1,
+        };
+        let mut seconds = self.parse_number(3)? * 3600;
+        for unit in [60, 1] {
+            if self.rest.first() != Some(&b':') {
+                break;
+            }
+            self.rest = &self.rest[1..];
+            seconds += self.parse_number(2)? * unit;
+        }
+        Some(sign * seconds)
+    }
+
+    fn parse_offset(&mut self) -> Option<i32> {
+        let offset = self.parse_time()?;
+        // Offsets are at most 24:59:59.
+        if offset.abs() >= 25 * 3600 {
+            return None;
+        }
+        Some(offset as i32)
+    }
+
+    fn parse_transition_rule(&mut self) -> Option<PosixTransitionRule> {
+        let date = match self.rest.first()? {
+            b'J' => {
+                self.rest = &self.rest[1..];
+                let day = self.parse_number(3)?;
+                if !(1..=365).contains(&day) {
+                    return None;
+                }
+                PosixDate::JulianWithoutLeapDay(day as u16)
+            }
+            b'M' => {
+                self.rest = &self.rest[1..];
+                let month = self.parse_number(2)?;
+                self.expect(b'.')?;
+                let week = self.parse_number(1)?;
+                self.expect(b'.')?;
+                let weekday = self.parse_number(1)?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                    return None;
+                }
+                PosixDate::MonthWeekDay {
+                    month: month as u8,
+                    week: week as u8,
+                    weekday: weekday as u8,
+                }
+            }
+            _ => {
+                let day = self.parse_number(3)?;
+                if day > 365 {
+                    return None;
+                }
+                PosixDate::Julian(day as u16)
+            }
+        };
+        let time = if self.rest.first() == Some(&b'/') {
+            self.rest = &self.rest[1..];
+            let time = self.parse_time()?;
+            // Times are at most 167 hours, per RFC 8536.
+            if time.abs() > 167 * 3600 {
+                return None;
+            }
+            time
+        } else {
+            7200
+        };
+        Some(PosixTransitionRule { date, time })
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days since the epoch of a date in the proleptic
+/// Gregorian calendar.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year in the proleptic Gregorian calendar of a number of days
+/// since the epoch.
+fn year_from_days(days: i64) -> i64 {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400;
+    // The computation above uses years starting on March 1.
+    if month >= 10 { year + 1 } else { year }
+}
+
+fn time_zone_database_directory() -> PathBuf {
+    std::env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo"))
+}
+
+/// Returns true if the identifier can name a file in the time zone database.
+fn is_valid_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && !identifier.starts_with('/')
+        && identifier
+            .split('/')
+            .all(|part| part != "." && part != "..")
+        && identifier
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'_' | b'-' | b'+'))
+}
+
+/// Returns the case-normalized form of a time zone identifier available in
+/// the time zone database, together with the rules of the time zone. The
+/// rules of UTC are None.
+pub(crate) fn get_available_named_time_zone_identifier(
+    identifier: &str,
+) -> Option<(std::string::String, Option<TimeZoneRules>)> {
+    if let Some(utc) = UTC_IDENTIFIERS
+        .iter()
+        .find(|utc| utc.eq_ignore_ascii_case(identifier))
+    {
+        return Some((utc.to_string(), None));
+    }
+    if !is_valid_identifier(identifier) {
+        return None;
+    }
+    // Find each component of the identifier in the directory of the previous
+    // component, ignoring ASCII case.
+    let mut path = time_zone_database_directory();
+    let mut normalized = std::string::String::with_capacity(identifier.len());
+    for part in identifier.split('/') {
+        let entry = std::fs::read_dir(&path).ok()?.find_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.eq_ignore_ascii_case(part).then_some(name)
+        })?;
+        if !normalized.is_empty() {
+            normalized.push('/');
+        }
+        normalized.push_str(&entry);
+        path.push(entry);
+    }
+    let rules = TimeZoneRules::from_tzif(&std::fs::read(path).ok()?)?;
+    Some((normalized, Some(rules)))
+}
+
+/// The identifiers of UTC.
+const UTC_IDENTIFIERS: &[&str] = &[
+    "UTC",
+    "Etc/UTC",
+    "Etc/UCT",
+    "UCT",
+    "Etc/Universal",
+    "Universal",
+    "Etc/Zulu",
+    "Zulu",
+    "GMT",
+    "Etc/GMT",
+    "GMT0",
+    "Etc/GMT0",
+    "GMT+0",
+    "Etc/GMT+0",
+    "GMT-0",
+    "Etc/GMT-0",
+    "Greenwich",
+    "Etc/Greenwich",
+];
+
+pub(crate) fn is_utc_identifier(identifier: &str) -> bool {
+    UTC_IDENTIFIERS.contains(&identifier)
+}
+
+/// Returns the time zone identifier of a path into the time zone database,
+/// e.g. `/usr/share/zoneinfo/Europe/Helsinki`.
+fn identifier_from_path(path: &Path) -> Option<std::string::String> {
+    let path = path.to_str()?;
+    let (_, identifier) = path.rsplit_once("zoneinfo/")?;
+    // Skip the alternative `posix/` and `right/` databases.
+    let identifier = identifier
+        .strip_prefix("posix/")
+        .or_else(|| identifier.strip_prefix("right/"))
+        .unwrap_or(identifier);
+    is_valid_identifier(identifier).then(|| identifier.to_string())
+}
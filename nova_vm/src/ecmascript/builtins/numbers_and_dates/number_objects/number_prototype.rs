@@ -19,6 +19,14 @@ use crate::{
     },
 };
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::{
+    abstract_operations::to_intl_mathematical_value,
+    number_format_objects::{
+        number_format_constructor::construct_number_format, number_format_prototype::format_numeric,
+    },
+};
+
 pub(crate) struct NumberPrototype;
 
 struct NumberPrototypeToExponential;
@@ -173,6 +181,8 @@ impl NumberPrototype {
         Ok(Value::from_str(agent, string, gc))
     }
 
+    /// ### [21.1.3.4 Number.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-number.prototype.tolocalestring)
+    #[cfg(not(feature = "intl"))]
     fn to_locale_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
@@ -182,6 +192,31 @@ impl NumberPrototype {
         Self::to_string(agent, this_value, arguments, gc)
     }
 
+    /// ### [20.2.1 Number.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-number.prototype.tolocalestring)
+    #[cfg(feature = "intl")]
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).scope(agent, gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let x = to_intl_mathematical_value(agent, x.into_value().unbind(), gc.reborrow()).unwrap();
+        // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales,
+        //    options »).
+        let number_format =
+            construct_number_format(agent, locales.get(agent), options.get(agent), gc.reborrow())
+                .unbind()?;
+        // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+        let result = format_numeric(agent, number_format, &x);
+        Ok(Value::from_string(agent, result, gc.into_nogc()))
+    }
+
     /// ### [21.1.3.5 Number.prototype.toPrecision ( )](https://tc39.es/ecma262/#sec-number.prototype.toprecision)
     /// Copied from Boa JS engine. Source https://github.com/boa-dev/boa/blob/6f1d7d11ce49040eafe54e5ff2da379be4d998c2/core/engine/src/builtins/number/mod.rs#L412
     ///
@@ -433,22 +468,46 @@ impl NumberPrototype {
         (flt.len() as i32) - 1
     }
 
+    /// ### [21.1.3.6 Number.prototype.toString ( \[ radix \] )](https://tc39.es/ecma262/#sec-number.prototype.tostring)
     fn to_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let nogc = gc.into_nogc();
-        let x = this_number_value(agent, this_value, nogc)?;
+        let nogc = gc.nogc();
         let radix = arguments.get(0).bind(nogc);
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = this_number_value(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 2. If radix is undefined, let radixMV be 10.
         if radix.is_undefined() || radix == Value::from(10u8) {
-            Ok(Number::to_string_radix_10(agent, x, nogc)
-                .unbind()
-                .into_value())
+            return Ok(Number::to_string_radix_10(agent, x.unbind(), gc.into_nogc()).into_value());
+        }
+        let (x, radix_mv) = if let Value::Integer(radix) = radix {
+            (x.unbind(), radix.into_i64())
         } else {
-            todo!();
+            // 3. Else, let radixMV be ? ToIntegerOrInfinity(radix).
+            let x = x.scope(agent, nogc);
+            let radix_mv = to_integer_or_infinity(agent, radix.unbind(), gc.reborrow())
+                .unbind()?
+                .into_i64();
+            (x.get(agent).unbind(), radix_mv)
+        };
+        let gc = gc.into_nogc();
+        let x = x.bind(gc);
+        // 4. If radixMV is not in the inclusive interval from 2 to 36, throw a
+        //    RangeError exception.
+        if !(2..=36).contains(&radix_mv) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "radix must be an integer at least 2 and no greater than 36",
+                gc,
+            ));
         }
+        // 5. Return Number::toString(x, radixMV).
+        Ok(Number::to_string_radix_n(agent, x, radix_mv as u32, gc).into_value())
     }
 
     fn value_of<'gc>(
@@ -35,21 +35,45 @@ use crate::{
 
 #[cfg(feature = "date")]
 use super::date::data::DateHeapData;
+#[cfg(feature = "intl")]
+use super::intl::collator::data::CollatorHeapData;
+#[cfg(feature = "intl")]
+use super::intl::date_time_format::data::DateTimeFormatHeapData;
+#[cfg(feature = "intl")]
+use super::intl::number_format::data::NumberFormatHeapData;
+#[cfg(feature = "intl")]
+use super::intl::plural_rules::data::PluralRulesHeapData;
 #[cfg(feature = "regexp")]
 use super::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use super::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "proposal-temporal")]
+use super::temporal::{
+    duration::data::DurationHeapData, instant::data::InstantHeapData,
+    plain_date::data::PlainDateHeapData, plain_date_time::data::PlainDateTimeHeapData,
+    plain_time::data::PlainTimeHeapData, zoned_date_time::data::ZonedDateTimeHeapData,
+};
 #[cfg(feature = "array-buffer")]
 use super::{
     ArrayBufferHeapData, data_view::data::DataViewHeapData, typed_array::data::TypedArrayHeapData,
 };
 use super::{
-    ArrayHeapData, async_generator_objects::AsyncGeneratorHeapData,
-    control_abstraction_objects::generator_objects::GeneratorHeapData, error::ErrorHeapData,
+    ArrayHeapData,
+    async_generator_objects::AsyncGeneratorHeapData,
+    control_abstraction_objects::{
+        generator_objects::GeneratorHeapData,
+        iteration::{
+            iterator_helper::IteratorHelperHeapData,
+            wrap_for_valid_iterator::WrapForValidIteratorHeapData,
+        },
+    },
+    error::ErrorHeapData,
     finalization_registry::data::FinalizationRegistryHeapData,
     indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
     keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
-    map::data::MapHeapData, module::Module, primitive_objects::PrimitiveObjectHeapData,
+    map::data::MapHeapData,
+    module::Module,
+    primitive_objects::PrimitiveObjectHeapData,
     promise::data::PromiseHeapData,
 };
 #[cfg(feature = "set")]
@@ -1244,6 +1268,51 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .into_object(),
         #[cfg(feature = "date")]
         ProtoIntrinsics::Date => agent.heap.create(DateHeapData::new_invalid()).into_object(),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::Collator => agent.heap.create(CollatorHeapData::default()).into_object(),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::DateTimeFormat => agent
+            .heap
+            .create(DateTimeFormatHeapData::default())
+            .into_object(),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::NumberFormat => agent
+            .heap
+            .create(NumberFormatHeapData::default())
+            .into_object(),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::PluralRules => agent
+            .heap
+            .create(PluralRulesHeapData::default())
+            .into_object(),
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalDuration => {
+            agent.heap.create(DurationHeapData::default()).into_object()
+        }
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalInstant => {
+            agent.heap.create(InstantHeapData::default()).into_object()
+        }
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalPlainDate => agent
+            .heap
+            .create(PlainDateHeapData::default())
+            .into_object(),
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalPlainDateTime => agent
+            .heap
+            .create(PlainDateTimeHeapData::default())
+            .into_object(),
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalPlainTime => agent
+            .heap
+            .create(PlainTimeHeapData::default())
+            .into_object(),
+        #[cfg(feature = "proposal-temporal")]
+        ProtoIntrinsics::TemporalZonedDateTime => agent
+            .heap
+            .create(ZonedDateTimeHeapData::default())
+            .into_object(),
         ProtoIntrinsics::Function => todo!(),
         ProtoIntrinsics::Number => agent
             .heap
@@ -1372,6 +1441,10 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
                 &[],
             )
             .into_object(),
+        ProtoIntrinsics::IteratorHelper => agent
+            .heap
+            .create(IteratorHelperHeapData::default())
+            .into_object(),
         ProtoIntrinsics::Map => agent.heap.create(MapHeapData::default()).into_object(),
         ProtoIntrinsics::MapIterator => agent
             .heap
@@ -1418,6 +1491,10 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         ProtoIntrinsics::WeakRef => agent.heap.create(WeakRefHeapData::default()).into_object(),
         #[cfg(feature = "weak-refs")]
         ProtoIntrinsics::WeakSet => agent.heap.create(WeakSetHeapData::default()).into_object(),
+        ProtoIntrinsics::WrapForValidIterator => agent
+            .heap
+            .create(WrapForValidIteratorHeapData::default())
+            .into_object(),
     };
 
     if let Some(prototype) = prototype {
@@ -1524,6 +1601,36 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::DataView => Some(intrinsics.data_view().into_function()),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => Some(intrinsics.date().into_function()),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::Collator => Some(intrinsics.collator().into_function()),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::DateTimeFormat => Some(intrinsics.date_time_format().into_function()),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::NumberFormat => Some(intrinsics.number_format().into_function()),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::PluralRules => Some(intrinsics.plural_rules().into_function()),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalDuration => {
+                Some(intrinsics.temporal_duration().into_function())
+            }
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalInstant => Some(intrinsics.temporal_instant().into_function()),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainDate => {
+                Some(intrinsics.temporal_plain_date().into_function())
+            }
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainDateTime => {
+                Some(intrinsics.temporal_plain_date_time().into_function())
+            }
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainTime => {
+                Some(intrinsics.temporal_plain_time().into_function())
+            }
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalZonedDateTime => {
+                Some(intrinsics.temporal_zoned_date_time().into_function())
+            }
             ProtoIntrinsics::Error => Some(intrinsics.error().into_function()),
             ProtoIntrinsics::EvalError => Some(intrinsics.eval_error().into_function()),
             ProtoIntrinsics::FinalizationRegistry => {
@@ -1547,6 +1654,7 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => Some(intrinsics.int8_array().into_function()),
             ProtoIntrinsics::Iterator => Some(intrinsics.iterator().into_function()),
+            ProtoIntrinsics::IteratorHelper => None,
             ProtoIntrinsics::Map => Some(intrinsics.map().into_function()),
             ProtoIntrinsics::MapIterator => None,
             ProtoIntrinsics::Number => Some(intrinsics.number().into_function()),
@@ -1585,6 +1693,7 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::WeakRef => Some(intrinsics.weak_ref().into_function()),
             #[cfg(feature = "weak-refs")]
             ProtoIntrinsics::WeakSet => Some(intrinsics.weak_set().into_function()),
+            ProtoIntrinsics::WrapForValidIterator => None,
         };
         if Some(constructor) == intrinsic_constructor {
             // The ProtoIntrinsic's constructor matches the constructor we're
@@ -41,6 +41,7 @@ use super::ordinary::is_compatible_property_descriptor;
 
 pub(crate) mod abstract_operations;
 pub mod data;
+pub(crate) mod revoker_function;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -57,6 +58,12 @@ impl Proxy<'_> {
 
     pub(crate) fn is_callable(self, agent: &Agent, gc: NoGcScope) -> bool {
         match agent[self] {
+            // A Proxy has a [[Call]] internal method if its target has one,
+            // and the target may itself be a Proxy.
+            ProxyHeapData::NonRevoked {
+                proxy_target: Object::Proxy(proxy_target),
+                ..
+            } => proxy_target.is_callable(agent, gc),
             ProxyHeapData::NonRevoked { proxy_target, .. } => {
                 is_callable(proxy_target, gc).is_some()
             }
@@ -64,6 +71,16 @@ impl Proxy<'_> {
             ProxyHeapData::Revoked => false,
         }
     }
+
+    /// Set the \[\[ProxyTarget]] and \[\[ProxyHandler]] of the Proxy to
+    /// null, remembering whether the Proxy was callable.
+    pub(crate) fn revoke(self, agent: &mut Agent, gc: NoGcScope) {
+        agent[self] = if self.is_callable(agent, gc) {
+            ProxyHeapData::RevokedCallable
+        } else {
+            ProxyHeapData::Revoked
+        };
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
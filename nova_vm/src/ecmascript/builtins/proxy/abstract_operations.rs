@@ -50,6 +50,10 @@ pub(crate) fn validate_non_revoked_proxy<'a>(
     proxy: Proxy,
     gc: NoGcScope<'a, '_>,
 ) -> JsResult<'a, NonRevokedProxy<'a>> {
+    // NOTE: Every Proxy internal method starts by validating the proxy, and
+    // proxies can wrap other proxies arbitrarily deeply. Guard against
+    // overflowing the native stack here.
+    agent.check_call_stack_limit(gc)?;
     let ProxyHeapData::NonRevoked {
         proxy_handler: handler,
         proxy_target: target,
@@ -0,0 +1,391 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        builtins::ArgumentsList,
+        execution::{Agent, JsResult, ProtoIntrinsics},
+        types::{
+            Function, FunctionInternalProperties, InternalMethods, InternalSlots, IntoFunction,
+            IntoObject, IntoValue, Object, OrdinaryObject, PropertyDescriptor, PropertyKey, String,
+            Value, function_create_backing_object, function_internal_define_own_property,
+            function_internal_delete, function_internal_get, function_internal_get_own_property,
+            function_internal_has_property, function_internal_own_property_keys,
+            function_internal_set, function_try_get, function_try_has_property, function_try_set,
+        },
+    },
+    engine::{
+        Scoped, TryResult,
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::BaseIndex,
+    },
+};
+
+use super::Proxy;
+
+/// ### [28.2.2.1.1 Proxy Revocation Functions](https://tc39.es/ecma262/#sec-proxy-revocation-functions)
+///
+/// A proxy revocation function is an anonymous built-in function that has
+/// a \[\[RevocableProxy\]\] internal slot.
+///
+/// The "length" property of a proxy revocation function is +0𝔽.
+#[derive(Debug, Clone)]
+pub struct ProxyRevokerFunctionHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[RevocableProxy\]\]
+    ///
+    /// Set to None once the revoker function has been called.
+    pub(crate) revocable_proxy: Option<Proxy<'a>>,
+}
+
+pub(crate) type BuiltinProxyRevokerFunctionIndex<'a> =
+    BaseIndex<'a, ProxyRevokerFunctionHeapData<'static>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BuiltinProxyRevokerFunction<'a>(pub(crate) BuiltinProxyRevokerFunctionIndex<'a>);
+
+impl BuiltinProxyRevokerFunction<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, BuiltinProxyRevokerFunction<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(BaseIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for BuiltinProxyRevokerFunction<'_> {
+    type Of<'a> = BuiltinProxyRevokerFunction<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> From<BuiltinProxyRevokerFunction<'a>> for Function<'a> {
+    fn from(value: BuiltinProxyRevokerFunction<'a>) -> Self {
+        Self::BuiltinProxyRevokerFunction(value)
+    }
+}
+
+impl<'a> IntoFunction<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn into_function(self) -> Function<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<BuiltinProxyRevokerFunction<'a>> for Object<'a> {
+    fn from(value: BuiltinProxyRevokerFunction<'a>) -> Self {
+        Self::BuiltinProxyRevokerFunction(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<BuiltinProxyRevokerFunction<'a>> for Value<'a> {
+    fn from(value: BuiltinProxyRevokerFunction<'a>) -> Self {
+        Self::BuiltinProxyRevokerFunction(value)
+    }
+}
+
+impl<'a> IntoValue<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> FunctionInternalProperties<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn get_name(self, _: &Agent) -> String<'static> {
+        String::EMPTY_STRING
+    }
+
+    fn get_length(self, _: &Agent) -> u8 {
+        0
+    }
+}
+
+impl<'a> InternalSlots<'a> for BuiltinProxyRevokerFunction<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Function;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+
+    fn create_backing_object(self, agent: &mut Agent) -> OrdinaryObject<'static> {
+        function_create_backing_object(self, agent)
+    }
+}
+
+impl<'a> InternalMethods<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn try_get_own_property<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Option<PropertyDescriptor<'gc>>> {
+        TryResult::Continue(function_internal_get_own_property(
+            self,
+            agent,
+            property_key,
+            gc,
+        ))
+    }
+
+    fn try_define_own_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        property_descriptor: PropertyDescriptor,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        TryResult::Continue(function_internal_define_own_property(
+            self,
+            agent,
+            property_key,
+            property_descriptor,
+            gc,
+        ))
+    }
+
+    fn try_has_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        function_try_has_property(self, agent, property_key, gc)
+    }
+
+    fn internal_has_property<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        function_internal_has_property(self, agent, property_key, gc)
+    }
+
+    fn try_get<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Value<'gc>> {
+        function_try_get(self, agent, property_key, receiver, gc)
+    }
+
+    fn internal_get<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        function_internal_get(self, agent, property_key, receiver, gc)
+    }
+
+    fn try_set(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        function_try_set(self, agent, property_key, value, receiver, gc)
+    }
+
+    fn internal_set<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        function_internal_set(self, agent, property_key, value, receiver, gc)
+    }
+
+    fn try_delete(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        TryResult::Continue(function_internal_delete(self, agent, property_key, gc))
+    }
+
+    fn try_own_property_keys<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Vec<PropertyKey<'gc>>> {
+        TryResult::Continue(function_internal_own_property_keys(self, agent, gc))
+    }
+
+    fn internal_call<'gc>(
+        self,
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments_list: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let F be the active function object.
+        // 2. Let p be F.[[RevocableProxy]].
+        // 4. Set F.[[RevocableProxy]] to null.
+        let Some(p) = agent[self].revocable_proxy.take() else {
+            // 3. If p is null, return undefined.
+            return Ok(Value::Undefined);
+        };
+        // 5. Assert: p is a Proxy exotic object.
+        // 6. Set p.[[ProxyTarget]] to null.
+        // 7. Set p.[[ProxyHandler]] to null.
+        p.revoke(agent, gc.nogc());
+        // 8. Return undefined.
+        Ok(Value::Undefined)
+    }
+}
+
+impl Index<BuiltinProxyRevokerFunction<'_>> for Agent {
+    type Output = ProxyRevokerFunctionHeapData<'static>;
+
+    fn index(&self, index: BuiltinProxyRevokerFunction) -> &Self::Output {
+        &self.heap.proxy_revoker_functions[index]
+    }
+}
+
+impl IndexMut<BuiltinProxyRevokerFunction<'_>> for Agent {
+    fn index_mut(&mut self, index: BuiltinProxyRevokerFunction) -> &mut Self::Output {
+        &mut self.heap.proxy_revoker_functions[index]
+    }
+}
+
+impl Index<BuiltinProxyRevokerFunction<'_>> for Vec<Option<ProxyRevokerFunctionHeapData<'static>>> {
+    type Output = ProxyRevokerFunctionHeapData<'static>;
+
+    fn index(&self, index: BuiltinProxyRevokerFunction) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("BuiltinProxyRevokerFunction out of bounds")
+            .as_ref()
+            .expect("BuiltinProxyRevokerFunction slot empty")
+    }
+}
+
+impl IndexMut<BuiltinProxyRevokerFunction<'_>>
+    for Vec<Option<ProxyRevokerFunctionHeapData<'static>>>
+{
+    fn index_mut(&mut self, index: BuiltinProxyRevokerFunction) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("BuiltinProxyRevokerFunction out of bounds")
+            .as_mut()
+            .expect("BuiltinProxyRevokerFunction slot empty")
+    }
+}
+
+impl Rootable for BuiltinProxyRevokerFunction<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::BuiltinProxyRevokerFunction(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::BuiltinProxyRevokerFunction(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> CreateHeapData<ProxyRevokerFunctionHeapData<'a>, BuiltinProxyRevokerFunction<'a>>
+    for Heap
+{
+    fn create(
+        &mut self,
+        data: ProxyRevokerFunctionHeapData<'a>,
+    ) -> BuiltinProxyRevokerFunction<'a> {
+        self.proxy_revoker_functions.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter +=
+                core::mem::size_of::<Option<ProxyRevokerFunctionHeapData<'static>>>();
+        }
+        BuiltinProxyRevokerFunction(BaseIndex::last(&self.proxy_revoker_functions))
+    }
+}
+
+impl HeapMarkAndSweep for BuiltinProxyRevokerFunction<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.proxy_revoker_functions.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.proxy_revoker_functions.shift_index(&mut self.0);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ProxyRevokerFunctionHeapData<'_> {
+    type Of<'a> = ProxyRevokerFunctionHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ProxyRevokerFunctionHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        self.object_index.mark_values(queues);
+        self.revocable_proxy.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        self.object_index.sweep_values(compactions);
+        self.revocable_proxy.sweep_values(compactions);
+    }
+}
@@ -3,8 +3,9 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::ecmascript::builtins::proxy::proxy_create;
+use crate::ecmascript::builtins::proxy::revoker_function::ProxyRevokerFunctionHeapData;
 use crate::ecmascript::execution::agent::ExceptionType;
-use crate::ecmascript::types::IntoValue;
+use crate::ecmascript::types::{IntoObject, IntoValue};
 use crate::engine::context::{Bindable, GcScope};
 use crate::{
     ecmascript::{
@@ -13,7 +14,7 @@ use crate::{
         execution::{Agent, JsResult, Realm},
         types::{BUILTIN_STRING_MEMORY, Object, String, Value},
     },
-    heap::IntrinsicConstructorIndexes,
+    heap::{CreateHeapData, IntrinsicConstructorIndexes, ObjectEntry},
 };
 
 pub(crate) struct ProxyConstructor;
@@ -61,13 +62,51 @@ impl ProxyConstructor {
         proxy_create(agent, target, handler, gc).map(|proxy| proxy.into_value())
     }
 
+    /// ### [28.2.2.1 Proxy.revocable ( target, handler )](https://tc39.es/ecma262/#sec-proxy.revocable)
+    ///
+    /// This function creates a revocable Proxy object.
     fn revocable<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let gc = gc.into_nogc();
+        let target = arguments.get(0).bind(gc);
+        let handler = arguments.get(1).bind(gc);
+        // 1. Let proxy be ? ProxyCreate(target, handler).
+        let proxy = proxy_create(agent, target, handler, gc)?;
+        // 2. Let revokerClosure be a new Abstract Closure with no parameters
+        //    that captures nothing and performs the following steps when
+        //    called:
+        // 3. Let revoker be CreateBuiltinFunction(revokerClosure, 0, "", « [[RevocableProxy]] »).
+        // 4. Set revoker.[[RevocableProxy]] to proxy.
+        let revoker = agent.heap.create(ProxyRevokerFunctionHeapData {
+            object_index: None,
+            revocable_proxy: Some(proxy.unbind()),
+        });
+        // 5. Let result be OrdinaryObjectCreate(%Object.prototype%).
+        // 6. Perform ! CreateDataPropertyOrThrow(result, "proxy", proxy).
+        // 7. Perform ! CreateDataPropertyOrThrow(result, "revoke", revoker).
+        let result = agent.heap.create_object_with_prototype(
+            agent
+                .current_realm_record()
+                .intrinsics()
+                .object_prototype()
+                .into_object(),
+            &[
+                ObjectEntry::new_data_entry(
+                    BUILTIN_STRING_MEMORY.proxy.into(),
+                    proxy.into_value().unbind(),
+                ),
+                ObjectEntry::new_data_entry(
+                    BUILTIN_STRING_MEMORY.revoke.into(),
+                    revoker.into_value(),
+                ),
+            ],
+        );
+        // 8. Return result.
+        Ok(result.into_value().bind(gc))
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -62,6 +62,12 @@ impl<'a> SetHeapData<'a> {
         self.set_data.rehash_if_needed(arena);
         &mut self.set_data
     }
+
+    /// Returns the approximate number of bytes used by the entries of the
+    /// Set.
+    pub(crate) fn external_byte_size(&self) -> usize {
+        self.set_data.values.len() * SetData::ENTRY_BYTES
+    }
 }
 
 #[derive(Debug, Default)]
@@ -80,6 +86,11 @@ pub(crate) struct SetData<'a> {
 }
 
 impl SetData<'_> {
+    /// Approximate number of bytes used by each entry: the value and the
+    /// hash table slot.
+    pub(crate) const ENTRY_BYTES: usize =
+        core::mem::size_of::<Option<Value<'static>>>() + core::mem::size_of::<u32>();
+
     fn rehash_if_needed(&self, arena: &impl PrimitiveHeapIndexable) {
         if !self.needs_primitive_rehashing.load(Ordering::Relaxed) {
             return;
@@ -225,6 +225,12 @@ impl ArrayBufferPrototype {
 
         // 9. Let oldBlock be O.[[ArrayBufferData]].
         // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        let old_byte_length = o.byte_length(agent);
+        if new_byte_length > old_byte_length {
+            let additional = new_byte_length - old_byte_length;
+            agent.check_heap_limit(additional, gc.nogc()).unbind()?;
+            agent.heap.external_bytes += additional;
+        }
         // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
         // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
         // 13. NOTE: Neither creation of the new Data Block nor copying from
@@ -366,7 +372,7 @@ impl ArrayBufferPrototype {
             // a. Let count be min(newLen, currentLen - first).
             let count = new_len.min(current_len - first);
             // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
-            new.copy_array_buffer_data(agent, o, first, count);
+            new.copy_array_buffer_data(agent, 0, o, first, count);
         }
         // 28. Return new.
         Ok(new.into_value())
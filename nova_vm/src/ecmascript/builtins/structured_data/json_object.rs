@@ -2,9 +2,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::fmt::Write;
+mod json_parser;
 
-use sonic_rs::{JsonContainerTrait, JsonValueTrait};
+use std::fmt::Write;
 
 use crate::{
     SmallInteger,
@@ -12,10 +12,10 @@ use crate::{
         abstract_operations::{
             operations_on_objects::{
                 call_function, create_data_property, enumerable_own_keys, get, get_v,
-                length_of_array_like, scoped_enumerable_own_keys, try_create_data_property,
+                length_of_array_like, scoped_enumerable_own_keys,
                 try_create_data_property_or_throw,
             },
-            testing_and_comparison::{is_array, is_callable},
+            testing_and_comparison::{is_array, is_callable, same_value},
             type_conversion::{
                 to_integer_or_infinity_number, to_number, to_property_key, to_property_key_simple,
                 to_string,
@@ -23,7 +23,7 @@ use crate::{
         },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
         builtins::{
-            ArgumentsList, Behaviour, Builtin, array_create,
+            ArgumentsList, Behaviour, Builtin,
             ordinary::ordinary_object_create_with_intrinsics,
             primitive_objects::{PrimitiveObject, PrimitiveObjectData},
         },
@@ -35,13 +35,26 @@ use crate::{
     },
     engine::{
         Scoped,
-        context::{Bindable, GcScope, NoGcScope},
+        context::{Bindable, GcScope},
         rootable::Scopable,
         unwrap_try,
     },
     heap::WellKnownSymbolIndexes,
 };
 
+use json_parser::{JsonParseRecord, parse_json_text};
+
+#[cfg(feature = "proposal-json-parse-with-source")]
+use crate::{
+    ecmascript::abstract_operations::operations_on_objects::try_get,
+    engine::context::NoGcScope,
+    heap::{ObjectEntry, ObjectEntryPropertyDescriptor},
+};
+#[cfg(feature = "proposal-json-parse-with-source")]
+use json_parser::parse_json_text_with_record;
+#[cfg(feature = "proposal-json-parse-with-source")]
+use wtf8::Wtf8Buf;
+
 pub(crate) struct JSONObject;
 
 struct JSONObjectParse;
@@ -62,6 +75,28 @@ impl Builtin for JSONObjectStringify {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::stringify);
 }
 
+#[cfg(feature = "proposal-json-parse-with-source")]
+struct JSONObjectIsRawJSON;
+#[cfg(feature = "proposal-json-parse-with-source")]
+impl Builtin for JSONObjectIsRawJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isRawJSON;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::is_raw_json);
+}
+
+#[cfg(feature = "proposal-json-parse-with-source")]
+struct JSONObjectRawJSON;
+#[cfg(feature = "proposal-json-parse-with-source")]
+impl Builtin for JSONObjectRawJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.rawJSON;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::raw_json);
+}
+
 impl JSONObject {
     /// ### [25.5.1 JSON.parse ( text \[ , reviver \] )](https://tc39.es/ecma262/#sec-json.parse)
     ///
@@ -109,12 +144,23 @@ impl JSONObject {
             .bind(gc.nogc());
 
         // 2. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
-        let json_value = match sonic_rs::from_str::<sonic_rs::Value>(json_string.as_str(agent)) {
-            Ok(value) => value,
+        // NOTE: JSON Parse Records are only needed by revivers; see step 11.
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        let result = if is_callable(reviver.get(agent), gc.nogc()).is_some() {
+            parse_json_text_with_record(agent, json_string, gc.nogc())
+                .map(|(value, record)| (value, Some(record)))
+        } else {
+            parse_json_text(agent, json_string, gc.nogc()).map(|value| (value, None))
+        };
+        #[cfg(not(feature = "proposal-json-parse-with-source"))]
+        let result = parse_json_text(agent, json_string, gc.nogc()).map(|value| (value, None));
+        let (completion, snapshot): (_, Option<JsonParseRecord>) = match result {
+            Ok(result) => result,
             Err(error) => {
+                let message = error.message(json_string.as_wtf8(agent));
                 return Err(agent.throw_exception(
                     ExceptionType::SyntaxError,
-                    error.to_string(),
+                    message,
                     gc.into_nogc(),
                 ));
             }
@@ -125,7 +171,7 @@ impl JSONObject {
         // 5. NOTE: The early error rules defined in 13.2.5.1 have special handling for the above invocation of ParseText.
         // 6. Assert: script is a Parse Node.
         // 7. Let completion be Completion(Evaluation of script).
-        let completion = value_from_json(agent, &json_value, gc.nogc());
+        // NOTE: The JSON text was parsed and evaluated in a single pass above.
 
         // 8. NOTE: The PropertyDefinitionEvaluation semantics defined in 13.2.5.5 have special handling for the above evaluation.
         // 9. Let unfiltered be completion.[[Value]].
@@ -170,10 +216,23 @@ impl JSONObject {
             ))
             .unwrap();
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            // NOTE: The snapshot was created by the parser in step 2.
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
             let root = root.unbind().into_object().scope(agent, gc.nogc());
             let reviver = reviver.unbind().scope(agent, gc.nogc());
-            return internalize_json_property(agent, root, root_name, reviver, gc);
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            let json_text = json_string.scope(agent, gc.nogc());
+            return internalize_json_property(
+                agent,
+                root,
+                root_name,
+                reviver,
+                snapshot.as_ref(),
+                #[cfg(feature = "proposal-json-parse-with-source")]
+                &json_text,
+                gc,
+            );
         }
 
         // 12. Else,
@@ -439,15 +498,73 @@ impl JSONObject {
         }
     }
 
+    /// ### [JSON.isRawJSON ( O )](https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson)
+    ///
+    /// This function returns true if O is a raw JSON object created by
+    /// JSON.rawJSON, and false otherwise.
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    fn is_raw_json<'gc>(
+        _agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If O is an Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(Value::Boolean(matches!(
+            arguments.get(0),
+            Value::RawJSON(_)
+        )))
+    }
+
+    /// ### [JSON.rawJSON ( text )](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+    ///
+    /// This function returns an object representing raw JSON text: its
+    /// `rawJSON` property is serialized as-is by JSON.stringify. The text
+    /// must be valid JSON text for a primitive value, such as a number that
+    /// cannot be represented as a Number without losing precision.
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    fn raw_json<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let text = arguments.get(0).bind(gc.nogc());
+
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = to_string(agent, text.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+
+        // NOTE: Steps 2 to 8 are performed by create_raw_json_object.
+        create_raw_json_object(agent, json_string.unbind(), gc.into_nogc())
+            .map(|obj| obj.into_value())
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let object_prototype = intrinsics.object_prototype();
         let this = intrinsics.json();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(3)
-            .with_prototype(object_prototype)
-            .with_builtin_function_property::<JSONObjectParse>()
+        let mut property_capacity = 3;
+        if cfg!(feature = "proposal-json-parse-with-source") {
+            property_capacity += 2;
+        }
+
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(property_capacity)
+            .with_prototype(object_prototype);
+
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        let builder = builder.with_builtin_function_property::<JSONObjectIsRawJSON>();
+
+        let builder = builder.with_builtin_function_property::<JSONObjectParse>();
+
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        let builder = builder.with_builtin_function_property::<JSONObjectRawJSON>();
+
+        builder
             .with_builtin_function_property::<JSONObjectStringify>()
             .with_property(|builder| {
                 builder
@@ -475,45 +592,107 @@ impl JSONObject {
 /// > Note 2
 /// > In the case where there are duplicate name Strings within an object,
 /// > lexically preceding values for the same key shall be overwritten.
+///
+/// This implements the operation as modified by the
+/// [JSON.parse source text access proposal](https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty),
+/// which adds the parseRecord argument (a JSON Parse Record or empty). The
+/// source text of unmodified primitive values, sliced from jsonText, is passed
+/// to the reviver in a context object when the
+/// `proposal-json-parse-with-source` feature is enabled.
 fn internalize_json_property<'a>(
     agent: &mut Agent,
     holder: Scoped<Object>,
     name: Scoped<PropertyKey>,
     reviver: Scoped<Function>,
+    parse_record: Option<&JsonParseRecord>,
+    #[cfg(feature = "proposal-json-parse-with-source")] json_text: &Scoped<String>,
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, Value<'a>> {
+    // Deeply nested values recurse on the native stack.
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
     // 1. Let val be ? Get(holder, name).
     let val = get(agent, holder.get(agent), name.get(agent), gc.reborrow())
         .unbind()?
         .bind(gc.nogc());
-    // 2. If val is an Object, then
+    // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+    // NOTE: This is checked before step 2 as the context depends on it.
+    let parse_record = parse_record
+        .filter(|parse_record| same_value(agent, parse_record.value.get(agent), val.unbind()));
+    // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    let context = {
+        let context = ordinary_object_create_with_intrinsics(
+            agent,
+            Some(ProtoIntrinsics::Object),
+            None,
+            gc.nogc(),
+        );
+        // a. If val is not an Object, then
+        if let Some(parse_record) = parse_record.filter(|_| !val.is_object()) {
+            // i. Let parseNode be parseRecord.[[ParseNode]].
+            // ii. Assert: parseNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+            // iii. Let sourceText be the source text matched by parseNode.
+            let json_text = json_text.get(agent);
+            let source_text = json_text
+                .as_wtf8(agent)
+                .slice(parse_record.source.start, parse_record.source.end);
+            let mut buffer = Wtf8Buf::with_capacity(source_text.len());
+            buffer.push_wtf8(source_text);
+            // iv. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+            let source_text = String::from_wtf8_buf(agent, buffer, gc.nogc());
+            unwrap_try(try_create_data_property_or_throw(
+                agent,
+                context,
+                BUILTIN_STRING_MEMORY.source.to_property_key(),
+                source_text.into_value(),
+                gc.nogc(),
+            ))
+            .unwrap();
+        }
+        context.unbind().scope(agent, gc.nogc())
+    };
+    // b. Let elementRecords be parseRecord.[[Elements]].
+    // c. Let entryRecords be parseRecord.[[Entries]].
+    // 4. Else,
+    // a. Let elementRecords be a new empty List.
+    // b. Let entryRecords be a new empty List.
+    let child_records =
+        parse_record.map_or(&[][..], |parse_record| parse_record.children.as_slice());
+    // 5. If val is an Object, then
     let val = if let Ok(val) = Object::try_from(val) {
         // a. Let isArray be ? IsArray(val).
         // b. If isArray is true, then
         let scoped_val = val.scope(agent, gc.nogc());
         if is_array(agent, val, gc.nogc()).unbind()? {
-            // i. Let len be ? LengthOfArrayLike(val).
+            // i. Let elementRecordsLen be the number of elements in elementRecords.
+            // ii. Let len be ? LengthOfArrayLike(val).
             let len = length_of_array_like(agent, val.unbind(), gc.reborrow()).unbind()?;
             // let val = val.scope(agent, gc.nogc());
-            // ii. Let I be 0.
+            // iii. Let I be 0.
             let mut i = 0;
-            // iii. Repeat, while I < len,
+            // iv. Repeat, while I < len,
             while i < len {
                 // 1. Let prop be ! ToString(𝔽(I)).
                 let prop = PropertyKey::from(SmallInteger::try_from(i).unwrap()).scope_static();
 
-                // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                // 2. If I < elementRecordsLen, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                let element_record = usize::try_from(i).ok().and_then(|i| child_records.get(i));
+
+                // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                 let new_element = internalize_json_property(
                     agent,
                     scoped_val.clone(),
                     prop.clone(),
                     reviver.clone(),
+                    element_record,
+                    #[cfg(feature = "proposal-json-parse-with-source")]
+                    json_text,
                     gc.reborrow(),
                 )
                 .unbind()?
                 .bind(gc.nogc());
 
-                // 3. If newElement is undefined, then
+                // 4. If newElement is undefined, then
                 if new_element.is_undefined() {
                     // a. Perform ? val.[[Delete]](prop).
                     // Note: Deleting from an Array never calls into JavaScript.
@@ -522,7 +701,7 @@ fn internalize_json_property<'a>(
                         .internal_delete(agent, prop.unwrap(), gc.reborrow())
                         .unbind()?;
                 } else {
-                    // 4. Else,
+                    // 5. Else,
                     // a. Perform ? CreateDataProperty(val, prop, newElement).
                     // Note: Defining a property on an Array never calls into
                     // JavaScript.
@@ -536,7 +715,7 @@ fn internalize_json_property<'a>(
                     .unbind()?;
                 }
 
-                // 5. Set I to I + 1.
+                // 6. Set I to I + 1.
                 i += 1;
             }
         } else {
@@ -547,19 +726,25 @@ fn internalize_json_property<'a>(
                 .bind(gc.nogc());
 
             // ii. For each String P of keys, do
-            for p in keys {
-                // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
+            for (index, p) in keys.into_iter().enumerate() {
+                // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                let entry_record = find_entry_record(agent, child_records, index, p.get(agent));
+
+                // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
                 let new_element = internalize_json_property(
                     agent,
                     scoped_val.clone(),
                     p.clone(),
                     reviver.clone(),
+                    entry_record,
+                    #[cfg(feature = "proposal-json-parse-with-source")]
+                    json_text,
                     gc.reborrow(),
                 )
                 .unbind()?
                 .bind(gc.nogc());
 
-                // 2. If newElement is undefined, then
+                // 3. If newElement is undefined, then
                 if new_element.is_undefined() {
                     // a. Perform ? val.[[Delete]](P).
                     scoped_val
@@ -567,7 +752,7 @@ fn internalize_json_property<'a>(
                         .internal_delete(agent, p.get(agent), gc.reborrow())
                         .unbind()?;
                 } else {
-                    // 3. Else,
+                    // 4. Else,
                     // a. Perform ? CreateDataProperty(val, P, newElement).
                     create_data_property(
                         agent,
@@ -590,22 +775,48 @@ fn internalize_json_property<'a>(
         val
     };
 
-    // 3. Return ? Call(reviver, holder, « name, val »).
+    // 6. Return ? Call(reviver, holder, « name, val, context »).
     // Note: Because this call gets holder as `this`, it can do dirty things to
     // it, such as `holder[other_key] = new Proxy()`.
     let name = name.get(agent).convert_to_value(agent, gc.nogc());
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    let arguments = &mut [
+        name.unbind(),
+        val.unbind(),
+        context.get(agent).into_value().unbind(),
+    ];
+    #[cfg(not(feature = "proposal-json-parse-with-source"))]
+    let arguments = &mut [name.unbind(), val.unbind()];
     call_function(
         agent,
         reviver.get(agent),
         holder.get(agent).into_value(),
-        Some(ArgumentsList::from_mut_slice(&mut [
-            name.unbind(),
-            val.unbind(),
-        ])),
+        Some(ArgumentsList::from_mut_slice(arguments)),
         gc,
     )
 }
 
+/// Find the JSON Parse Record of the entry with the given key. The entry is
+/// usually found at the key's index, as the keys of an object that has not
+/// been modified by the reviver are in the order of its entries.
+fn find_entry_record<'r, 's>(
+    agent: &Agent,
+    entry_records: &'r [JsonParseRecord<'s>],
+    index: usize,
+    key: PropertyKey,
+) -> Option<&'r JsonParseRecord<'s>> {
+    let has_key = |entry_record: &&JsonParseRecord| {
+        entry_record
+            .key
+            .as_ref()
+            .is_some_and(|entry_key| entry_key.get(agent) == key)
+    };
+    entry_records
+        .get(index)
+        .filter(has_key)
+        .or_else(|| entry_records.iter().find(has_key))
+}
+
 struct JSONSerializationRecord<'a> {
     result: std::string::String,
     replacer_function: Option<Scoped<'a, Function<'static>>>,
@@ -782,6 +993,21 @@ fn serialize_json_property_value<'a, 'b>(
             // None from get_serializable_json_property_value.
             let value = Object::try_from(value).unwrap();
             debug_assert!(is_callable(value, gc.nogc()).is_none());
+            // 4. If value is an Object, then
+            // a. If value has an [[IsRawJSON]] internal slot, then
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            if let Object::RawJSON(value) = value {
+                // i. Return ! Get(value, "rawJSON").
+                let raw_json = unwrap_try(try_get(
+                    agent,
+                    value,
+                    BUILTIN_STRING_MEMORY.rawJSON.to_property_key(),
+                    gc.nogc(),
+                ));
+                let raw_json = String::try_from(raw_json).unwrap();
+                push_raw_json(agent, &mut state.result, raw_json);
+                return Ok(());
+            }
             // a. Let isArray be ? IsArray(value).
             // b. If isArray is true, return ? SerializeJSONArray(state, value).
             if is_array(agent, value, gc.nogc()).unbind()? {
@@ -803,6 +1029,77 @@ fn serialize_json_property_value<'a, 'b>(
     Ok(())
 }
 
+/// Creates a raw JSON object: a frozen object with a null prototype, an
+/// \[\[IsRawJSON]] internal slot and a `rawJSON` property holding the text.
+///
+/// This performs steps 2 to 8 of JSON.rawJSON, throwing a SyntaxError if the
+/// text is not valid JSON text for a primitive value.
+#[cfg(feature = "proposal-json-parse-with-source")]
+pub(crate) fn create_raw_json_object<'a>(
+    agent: &mut Agent,
+    json_string: String,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    let json_string = json_string.bind(gc);
+    // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+    let json_text = json_string.as_wtf8(agent);
+    let is_json_whitespace = |byte: u8| matches!(byte, b'\t' | b'\n' | b'\r' | b' ');
+    let len = json_text.len();
+    let first = (len > 0).then(|| json_text.ascii_byte_at(0));
+    let last = (len > 0).then(|| json_text.ascii_byte_at(len - 1));
+    if first.is_none_or(is_json_whitespace) || last.is_some_and(is_json_whitespace) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::SyntaxError,
+            "Raw JSON text must not be empty or start or end with whitespace",
+            gc,
+        ));
+    }
+
+    // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+    if let Some(b'{' | b'[') = first {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::SyntaxError,
+            "Raw JSON text must be a primitive value",
+            gc,
+        ));
+    }
+    if let Err(error) = parse_json_text(agent, json_string, gc) {
+        let message = error.message(json_string.as_wtf8(agent));
+        return Err(agent.throw_exception(ExceptionType::SyntaxError, message, gc));
+    }
+
+    // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+    // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+    // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+    // 7. Perform ! SetIntegrityLevel(obj, frozen).
+    let obj = agent.heap.create_null_object(&[ObjectEntry {
+        key: BUILTIN_STRING_MEMORY.rawJSON.to_property_key(),
+        value: ObjectEntryPropertyDescriptor::Data {
+            value: json_string.into_value(),
+            writable: false,
+            enumerable: true,
+            configurable: false,
+        },
+    }]);
+    agent[obj].extensible = false;
+    // 8. Return obj.
+    Ok(Object::RawJSON(obj.bind(gc)))
+}
+
+/// Appends the text of a raw JSON object to the JSON.stringify result.
+///
+/// The text is valid JSON text, so lone surrogates can only appear in a
+/// string literal, where they are written as escape sequences instead.
+#[cfg(feature = "proposal-json-parse-with-source")]
+fn push_raw_json(agent: &Agent, product: &mut std::string::String, raw_json: String) {
+    for c in raw_json.as_wtf8(agent).code_points() {
+        match c.to_char() {
+            Some(c) => product.push(c),
+            None => write!(product, "\\u{:04x}", c.to_u32()).unwrap(),
+        }
+    }
+}
+
 /// ### [25.5.2.3 QuoteJSONString ( value )](https://tc39.es/ecma262/#sec-quotejsonstring)
 ///
 /// The abstract operation QuoteJSONString takes argument value (a String) and
@@ -815,7 +1112,17 @@ fn quote_json_string(agent: &Agent, product: &mut std::string::String, value: St
     //    0x0022 (QUOTATION MARK).
     product.push('"');
     // 2. For each code point C of StringToCodePoints(value), do
-    for c in value.as_str(agent).chars() {
+    for c in value.as_wtf8(agent).code_points() {
+        let Some(c) = c.to_char() else {
+            // b. Else if C has the same numeric value as a leading surrogate
+            //    or trailing surrogate, then
+            // i. Let unit be the code unit whose numeric value is the
+            //    numeric value of C.
+            // ii. Set product to the string-concatenation of product and
+            //     UnicodeEscape(unit).
+            write!(product, "\\u{:04x}", c.to_u32()).unwrap();
+            continue;
+        };
         match c {
             // a. If C is listed in the “Code Point” column of Table 81, then
             // i. Set product to the string-concatenation of product and the
@@ -898,6 +1205,8 @@ fn serialize_json_object<'a, 'b>(
             gc.into_nogc(),
         ));
     }
+    // Deeply nested values recurse on the native stack.
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
 
     // 5. If state.[[PropertyList]] is not undefined, then
     // a. Let K be state.[[PropertyList]].
@@ -1056,6 +1365,8 @@ fn serialize_json_array<'a, 'b>(
             gc.into_nogc(),
         ));
     }
+    // Deeply nested values recurse on the native stack.
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
     // 6. Let len be ? LengthOfArrayLike(value).
     let len = length_of_array_like(agent, stack_value.unbind(), gc.reborrow()).unbind()? as u64;
 
@@ -1165,46 +1476,3 @@ fn serialize_json_array<'a, 'b>(
     // 13. Return final.
     Ok(())
 }
-
-pub(crate) fn value_from_json<'gc>(
-    agent: &mut Agent,
-    json: &sonic_rs::Value,
-    gc: NoGcScope<'gc, '_>,
-) -> Value<'gc> {
-    match json.get_type() {
-        sonic_rs::JsonType::Null => Value::Null,
-        sonic_rs::JsonType::Boolean => Value::Boolean(json.is_true()),
-        sonic_rs::JsonType::Number => Number::from_f64(agent, json.as_f64().unwrap(), gc).into(),
-        sonic_rs::JsonType::String => String::from_str(agent, json.as_str().unwrap(), gc).into(),
-        sonic_rs::JsonType::Array => {
-            let json_array = json.as_array().unwrap();
-            let len = json_array.len();
-            let array_obj = array_create(agent, len, len, None, gc).unwrap();
-            for (i, value) in json_array.iter().enumerate() {
-                let prop = PropertyKey::from(SmallInteger::try_from(i as i64).unwrap());
-                let js_value = value_from_json(agent, value, gc);
-                unwrap_try(try_create_data_property(
-                    agent, array_obj, prop, js_value, gc,
-                ));
-            }
-            array_obj.into_value()
-        }
-        sonic_rs::JsonType::Object => {
-            let json_object = json.as_object().unwrap();
-            let Object::Object(object) = ordinary_object_create_with_intrinsics(
-                agent,
-                Some(ProtoIntrinsics::Object),
-                None,
-                gc,
-            ) else {
-                unreachable!()
-            };
-            for (key, value) in json_object.iter() {
-                let prop = PropertyKey::from_str(agent, key, gc);
-                let js_value = value_from_json(agent, value, gc);
-                unwrap_try(try_create_data_property(agent, object, prop, js_value, gc));
-            }
-            object.into()
-        }
-    }
-}
@@ -0,0 +1,639 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## JSON text parser
+//!
+//! Parses a JSON text as specified in
+//! [ECMA-404](https://ecma-international.org/publications-and-standards/standards/ecma-404/)
+//! and builds the resulting ECMAScript values directly on the heap, without
+//! an intermediate tree. This is the result of steps 2 through 9 of
+//! [JSON.parse](https://tc39.es/ecma262/#sec-json.parse): arrays are created
+//! with ArrayCreate, objects with OrdinaryObjectCreate and properties are
+//! defined with CreateDataProperty, so duplicate keys keep the position of
+//! their first occurrence and the value of their last one, and `"__proto__"`
+//! is an ordinary property.
+//!
+//! Nested arrays and objects are tracked on an explicit stack instead of
+//! the native stack, so deeply nested texts cannot overflow it.
+
+use core::ops::Range;
+use std::collections::hash_map::Entry;
+
+use ahash::AHashMap;
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{create_array_from_list, try_create_data_property},
+            type_conversion::parse_string_to_integer_property_key,
+        },
+        builtins::ordinary::ordinary_object_create_with_intrinsics,
+        execution::{Agent, ProtoIntrinsics},
+        types::{IntoValue, Number, Object, OrdinaryObject, PropertyKey, String, Value},
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::Scopable,
+        unwrap_try,
+    },
+};
+
+/// A JSON text failed to parse.
+///
+/// The error holds the WTF-8 byte offset into the text at which parsing
+/// failed; [`JsonSyntaxError::message`] converts it into the UTF-16 position
+/// reported to JavaScript.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct JsonSyntaxError {
+    kind: JsonSyntaxErrorKind,
+    position: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum JsonSyntaxErrorKind {
+    UnexpectedEnd,
+    UnexpectedToken(CodePoint),
+    UnexpectedNonWhitespace,
+    BadControlCharacter,
+    BadEscapedCharacter,
+    BadUnicodeEscape,
+    UnterminatedString,
+    NoNumberAfterMinusSign,
+    UnterminatedFractionalNumber,
+    ExponentMissingNumber,
+    ExpectedCommaOrBracket,
+    ExpectedCommaOrBrace,
+    ExpectedPropertyNameOrBrace,
+    ExpectedPropertyName,
+    ExpectedColon,
+}
+
+impl JsonSyntaxError {
+    /// Create the SyntaxError message for this error in the given text.
+    pub(crate) fn message(&self, text: &Wtf8) -> std::string::String {
+        let position = text.slice_to(self.position).to_ill_formed_utf16().count();
+        let description = match self.kind {
+            JsonSyntaxErrorKind::UnexpectedEnd => {
+                return "Unexpected end of JSON input".to_owned();
+            }
+            JsonSyntaxErrorKind::UnexpectedToken(token) => match token.to_char() {
+                Some(token) => format!("Unexpected token '{token}'"),
+                // Lone surrogates cannot be written into a Rust string.
+                None => format!("Unexpected token '\\u{:04X}'", token.to_u32()),
+            },
+            JsonSyntaxErrorKind::UnexpectedNonWhitespace => {
+                return format!(
+                    "Unexpected non-whitespace character after JSON at position {position}"
+                );
+            }
+            JsonSyntaxErrorKind::BadControlCharacter => {
+                "Bad control character in string literal".to_owned()
+            }
+            JsonSyntaxErrorKind::BadEscapedCharacter => "Bad escaped character".to_owned(),
+            JsonSyntaxErrorKind::BadUnicodeEscape => "Bad Unicode escape".to_owned(),
+            JsonSyntaxErrorKind::UnterminatedString => "Unterminated string".to_owned(),
+            JsonSyntaxErrorKind::NoNumberAfterMinusSign => "No number after minus sign".to_owned(),
+            JsonSyntaxErrorKind::UnterminatedFractionalNumber => {
+                "Unterminated fractional number".to_owned()
+            }
+            JsonSyntaxErrorKind::ExponentMissingNumber => {
+                "Exponent part is missing a number".to_owned()
+            }
+            JsonSyntaxErrorKind::ExpectedCommaOrBracket => {
+                "Expected ',' or ']' after array element".to_owned()
+            }
+            JsonSyntaxErrorKind::ExpectedCommaOrBrace => {
+                "Expected ',' or '}' after property value".to_owned()
+            }
+            JsonSyntaxErrorKind::ExpectedPropertyNameOrBrace => {
+                "Expected property name or '}'".to_owned()
+            }
+            JsonSyntaxErrorKind::ExpectedPropertyName => {
+                "Expected double-quoted property name".to_owned()
+            }
+            JsonSyntaxErrorKind::ExpectedColon => "Expected ':' after property name".to_owned(),
+        };
+        format!("{description} in JSON at position {position}")
+    }
+}
+
+/// ### [JSON Parse Record](https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record)
+///
+/// A JSON Parse Record is a Record value used to describe the initial state
+/// of a value parsed from JSON text: revivers are given the source text of
+/// primitive values that they have not modified.
+pub(crate) struct JsonParseRecord<'scope> {
+    /// [[Key]]: the property name of an object entry. Array elements are
+    /// identified by their position in their array's [[Elements]] instead.
+    pub(crate) key: Option<Scoped<'scope, PropertyKey<'static>>>,
+    /// [[Value]]
+    pub(crate) value: Scoped<'scope, Value<'static>>,
+    /// WTF-8 byte range of the source text matched by [[ParseNode]].
+    pub(crate) source: Range<usize>,
+    /// [[Elements]] of an array or [[Entries]] of an object.
+    pub(crate) children: Vec<JsonParseRecord<'scope>>,
+}
+
+/// An array or object whose elements are still being parsed.
+enum Container<'gc> {
+    /// Array whose elements so far are stored in the parser's element list
+    /// starting at the given index.
+    Array(usize),
+    /// Object and the key of the property whose value is being parsed.
+    Object(OrdinaryObject<'gc>, PropertyKey<'gc>),
+}
+
+/// The parser reads the text through the Agent whenever it needs it, as the
+/// text may be a heap string and the parser allocates onto the heap.
+struct JsonParser<'a> {
+    text: String<'a>,
+    len: usize,
+    position: usize,
+}
+
+/// Parse a JSON text into an ECMAScript value.
+pub(crate) fn parse_json_text<'gc>(
+    agent: &mut Agent,
+    text: String,
+    gc: NoGcScope<'gc, '_>,
+) -> Result<Value<'gc>, JsonSyntaxError> {
+    parse(agent, text, false, gc).map(|(value, _)| value)
+}
+
+/// Parse a JSON text into an ECMAScript value and create the JSON Parse
+/// Record of the value.
+#[cfg(feature = "proposal-json-parse-with-source")]
+pub(crate) fn parse_json_text_with_record<'gc, 'scope>(
+    agent: &mut Agent,
+    text: String,
+    gc: NoGcScope<'gc, 'scope>,
+) -> Result<(Value<'gc>, JsonParseRecord<'scope>), JsonSyntaxError> {
+    parse(agent, text, true, gc).map(|(value, record)| (value, record.unwrap()))
+}
+
+fn parse<'gc, 'scope>(
+    agent: &mut Agent,
+    text: String,
+    create_records: bool,
+    gc: NoGcScope<'gc, 'scope>,
+) -> Result<(Value<'gc>, Option<JsonParseRecord<'scope>>), JsonSyntaxError> {
+    let text = text.bind(gc);
+    let mut parser = JsonParser {
+        text,
+        len: text.len(agent),
+        position: 0,
+    };
+    let mut stack: Vec<Container<'gc>> = Vec::new();
+    // Elements of all arrays on the stack, innermost array last.
+    let mut elements: Vec<Value<'gc>> = Vec::new();
+    // Start of the source text and the parse records of the elements or
+    // entries of each array and object on the stack.
+    let mut records: Vec<(usize, Vec<JsonParseRecord<'scope>>)> = Vec::new();
+    'value: loop {
+        parser.skip_whitespace(agent);
+        let value_start = parser.position;
+        let mut value = match parser.peek(agent) {
+            Some(b'{') => {
+                parser.position += 1;
+                let object = create_object(agent, gc);
+                parser.skip_whitespace(agent);
+                if parser.peek(agent) == Some(b'}') {
+                    parser.position += 1;
+                    object.into_value()
+                } else {
+                    let key = parser.parse_property_name(
+                        agent,
+                        JsonSyntaxErrorKind::ExpectedPropertyNameOrBrace,
+                        gc,
+                    )?;
+                    stack.push(Container::Object(object, key));
+                    if create_records {
+                        records.push((value_start, Vec::new()));
+                    }
+                    continue 'value;
+                }
+            }
+            Some(b'[') => {
+                parser.position += 1;
+                parser.skip_whitespace(agent);
+                if parser.peek(agent) == Some(b']') {
+                    parser.position += 1;
+                    create_array_from_list(agent, &[], gc).into_value()
+                } else {
+                    stack.push(Container::Array(elements.len()));
+                    if create_records {
+                        records.push((value_start, Vec::new()));
+                    }
+                    continue 'value;
+                }
+            }
+            Some(b'"') => {
+                parser.position += 1;
+                parser.parse_string(agent, gc)?.into_value()
+            }
+            Some(b'-' | b'0'..=b'9') => parser.parse_number(agent, gc)?.into_value(),
+            Some(b't') => parser.parse_literal(agent, "true", Value::Boolean(true))?,
+            Some(b'f') => parser.parse_literal(agent, "false", Value::Boolean(false))?,
+            Some(b'n') => parser.parse_literal(agent, "null", Value::Null)?,
+            _ => return Err(parser.unexpected_token(agent)),
+        };
+        let mut record = create_records.then(|| JsonParseRecord {
+            key: None,
+            value: value.scope(agent, gc),
+            source: value_start..parser.position,
+            children: Vec::new(),
+        });
+        // The value is complete: add it to its containing array or object,
+        // completing those in turn if they end after it.
+        loop {
+            match stack.last_mut() {
+                None => {
+                    parser.skip_whitespace(agent);
+                    if parser.position < parser.len {
+                        return Err(parser.error(JsonSyntaxErrorKind::UnexpectedNonWhitespace));
+                    }
+                    return Ok((value, record));
+                }
+                Some(Container::Array(start)) => {
+                    let start = *start;
+                    elements.push(value);
+                    if let Some(record) = record.take() {
+                        records.last_mut().unwrap().1.push(record);
+                    }
+                    parser.skip_whitespace(agent);
+                    match parser.peek(agent) {
+                        Some(b',') => {
+                            parser.position += 1;
+                            continue 'value;
+                        }
+                        Some(b']') => {
+                            parser.position += 1;
+                            stack.pop();
+                            value =
+                                create_array_from_list(agent, &elements[start..], gc).into_value();
+                            elements.truncate(start);
+                            if create_records {
+                                let (source_start, children) = records.pop().unwrap();
+                                record = Some(JsonParseRecord {
+                                    key: None,
+                                    value: value.scope(agent, gc),
+                                    source: source_start..parser.position,
+                                    children,
+                                });
+                            }
+                        }
+                        None => return Err(parser.error(JsonSyntaxErrorKind::UnexpectedEnd)),
+                        _ => {
+                            return Err(parser.error(JsonSyntaxErrorKind::ExpectedCommaOrBracket));
+                        }
+                    }
+                }
+                Some(Container::Object(object, key)) => {
+                    unwrap_try(try_create_data_property(agent, *object, *key, value, gc));
+                    if let Some(mut record) = record.take() {
+                        record.key = Some(key.scope(agent, gc));
+                        records.last_mut().unwrap().1.push(record);
+                    }
+                    parser.skip_whitespace(agent);
+                    match parser.peek(agent) {
+                        Some(b',') => {
+                            parser.position += 1;
+                            parser.skip_whitespace(agent);
+                            *key = parser.parse_property_name(
+                                agent,
+                                JsonSyntaxErrorKind::ExpectedPropertyName,
+                                gc,
+                            )?;
+                            continue 'value;
+                        }
+                        Some(b'}') => {
+                            parser.position += 1;
+                            value = object.into_value();
+                            stack.pop();
+                            if create_records {
+                                let (source_start, entries) = records.pop().unwrap();
+                                record = Some(JsonParseRecord {
+                                    key: None,
+                                    value: value.scope(agent, gc),
+                                    source: source_start..parser.position,
+                                    children: deduplicate_entries(agent, entries),
+                                });
+                            }
+                        }
+                        None => return Err(parser.error(JsonSyntaxErrorKind::UnexpectedEnd)),
+                        _ => {
+                            return Err(parser.error(JsonSyntaxErrorKind::ExpectedCommaOrBrace));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keep only the last entry of each property name, at the position of its
+/// first entry: the object's property has the last entry's value, and is
+/// enumerated at the position it was first defined in.
+fn deduplicate_entries<'scope>(
+    agent: &Agent,
+    entries: Vec<JsonParseRecord<'scope>>,
+) -> Vec<JsonParseRecord<'scope>> {
+    let mut positions = AHashMap::with_capacity(entries.len());
+    let mut result: Vec<JsonParseRecord> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let key = entry.key.as_ref().unwrap().get(agent);
+        match positions.entry(key) {
+            Entry::Occupied(position) => result[*position.get()] = entry,
+            Entry::Vacant(position) => {
+                position.insert(result.len());
+                result.push(entry);
+            }
+        }
+    }
+    result
+}
+
+fn create_object<'gc>(agent: &mut Agent, gc: NoGcScope<'gc, '_>) -> OrdinaryObject<'gc> {
+    let Object::Object(object) =
+        ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None, gc)
+    else {
+        unreachable!()
+    };
+    object
+}
+
+impl JsonParser<'_> {
+    fn peek(&self, agent: &Agent) -> Option<u8> {
+        self.peek_in(self.text.as_wtf8(agent))
+    }
+
+    /// Peek at the byte at the current position of the text, which must be
+    /// this parser's text. Non-ASCII bytes are all returned as 0xFF.
+    fn peek_in(&self, text: &Wtf8) -> Option<u8> {
+        (self.position < self.len).then(|| text.ascii_byte_at(self.position))
+    }
+
+    fn skip_whitespace(&mut self, agent: &Agent) {
+        let text = self.text.as_wtf8(agent);
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek_in(text) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, kind: JsonSyntaxErrorKind) -> JsonSyntaxError {
+        JsonSyntaxError {
+            kind,
+            position: self.position,
+        }
+    }
+
+    /// Error for an unexpected character at the current position, or for the
+    /// end of the text.
+    fn unexpected_token(&self, agent: &Agent) -> JsonSyntaxError {
+        let text = self.text.as_wtf8(agent);
+        match text.slice_from(self.position).code_points().next() {
+            Some(token) => self.error(JsonSyntaxErrorKind::UnexpectedToken(token)),
+            None => self.error(JsonSyntaxErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn parse_literal<'gc>(
+        &mut self,
+        agent: &Agent,
+        literal: &'static str,
+        value: Value<'gc>,
+    ) -> Result<Value<'gc>, JsonSyntaxError> {
+        for expected in literal.bytes() {
+            if self.peek(agent) != Some(expected) {
+                return Err(self.unexpected_token(agent));
+            }
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Parse a property name and the colon following it.
+    fn parse_property_name<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        missing: JsonSyntaxErrorKind,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<PropertyKey<'gc>, JsonSyntaxError> {
+        match self.peek(agent) {
+            Some(b'"') => self.position += 1,
+            None => return Err(self.error(JsonSyntaxErrorKind::UnexpectedEnd)),
+            _ => return Err(self.error(missing)),
+        }
+        let string = self.scan_string(agent)?;
+        let integer_key = match &string {
+            JsonString::Borrowed(range) => {
+                let text = self.text.as_wtf8(agent);
+                text.slice(range.start, range.end)
+                    .as_str()
+                    .and_then(parse_string_to_integer_property_key)
+            }
+            JsonString::Owned(buffer) => buffer
+                .as_str()
+                .and_then(parse_string_to_integer_property_key),
+        };
+        let key = match integer_key {
+            Some(key) => key,
+            None => self.create_string(agent, string, gc).into(),
+        };
+        self.skip_whitespace(agent);
+        match self.peek(agent) {
+            Some(b':') => {
+                self.position += 1;
+                Ok(key)
+            }
+            None => Err(self.error(JsonSyntaxErrorKind::UnexpectedEnd)),
+            _ => Err(self.error(JsonSyntaxErrorKind::ExpectedColon)),
+        }
+    }
+
+    /// Parse the rest of a string whose opening quote has been consumed.
+    fn parse_string<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<String<'gc>, JsonSyntaxError> {
+        let string = self.scan_string(agent)?;
+        Ok(self.create_string(agent, string, gc))
+    }
+
+    fn create_string<'gc>(
+        &self,
+        agent: &mut Agent,
+        string: JsonString,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        let buffer = match string {
+            JsonString::Borrowed(range) => {
+                let text = self.text.as_wtf8(agent).slice(range.start, range.end);
+                if let Some(small_string) = text.as_str().and_then(|str| String::try_from(str).ok())
+                {
+                    return small_string;
+                }
+                // NOTE: The text is borrowed from the heap, so it must be
+                // copied before the heap can be mutated.
+                let mut buffer = Wtf8Buf::with_capacity(text.len());
+                buffer.push_wtf8(text);
+                buffer
+            }
+            JsonString::Owned(buffer) => buffer,
+        };
+        String::from_wtf8_buf(agent, buffer, gc)
+    }
+
+    /// Scan the rest of a string whose opening quote has been consumed. A
+    /// string without escapes is returned as a range of the text.
+    fn scan_string(&mut self, agent: &Agent) -> Result<JsonString, JsonSyntaxError> {
+        let string = self.text;
+        let text = string.as_wtf8(agent);
+        let start = self.position;
+        // Fast path: find the end of a string without escapes.
+        loop {
+            match self.peek_in(text) {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(JsonString::Borrowed(start..self.position - 1));
+                }
+                Some(b'\\') => break,
+                Some(0x00..=0x1F) => {
+                    return Err(self.error(JsonSyntaxErrorKind::BadControlCharacter));
+                }
+                Some(_) => self.position += 1,
+                None => return Err(self.error(JsonSyntaxErrorKind::UnterminatedString)),
+            }
+        }
+        let mut buffer = Wtf8Buf::with_capacity(self.position - start + 16);
+        buffer.push_wtf8(text.slice(start, self.position));
+        loop {
+            let chunk_start = self.position;
+            while let Some(byte) = self.peek_in(text) {
+                if matches!(byte, b'"' | b'\\' | 0x00..=0x1F) {
+                    break;
+                }
+                self.position += 1;
+            }
+            buffer.push_wtf8(text.slice(chunk_start, self.position));
+            match self.peek_in(text) {
+                Some(b'"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escaped = match self.peek_in(text) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{C}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.position += 1;
+                            let code_unit = self.parse_hex_code_unit(text)?;
+                            // NOTE: Surrogate pairs written as two escapes are
+                            // combined into one code point by Wtf8Buf::push.
+                            buffer.push(CodePoint::from_u32(code_unit as u32).unwrap());
+                            continue;
+                        }
+                        None => return Err(self.error(JsonSyntaxErrorKind::UnterminatedString)),
+                        _ => return Err(self.error(JsonSyntaxErrorKind::BadEscapedCharacter)),
+                    };
+                    self.position += 1;
+                    buffer.push_char(escaped);
+                }
+                Some(_) => return Err(self.error(JsonSyntaxErrorKind::BadControlCharacter)),
+                None => return Err(self.error(JsonSyntaxErrorKind::UnterminatedString)),
+            }
+        }
+        Ok(JsonString::Owned(buffer))
+    }
+
+    fn parse_hex_code_unit(&mut self, text: &Wtf8) -> Result<u16, JsonSyntaxError> {
+        let mut code_unit = 0u16;
+        for _ in 0..4 {
+            let digit = match self.peek_in(text) {
+                Some(byte @ b'0'..=b'9') => byte - b'0',
+                Some(byte @ b'a'..=b'f') => byte - b'a' + 10,
+                Some(byte @ b'A'..=b'F') => byte - b'A' + 10,
+                None => return Err(self.error(JsonSyntaxErrorKind::UnterminatedString)),
+                _ => return Err(self.error(JsonSyntaxErrorKind::BadUnicodeEscape)),
+            };
+            code_unit = (code_unit << 4) | digit as u16;
+            self.position += 1;
+        }
+        Ok(code_unit)
+    }
+
+    fn parse_number<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<Number<'gc>, JsonSyntaxError> {
+        let string = self.text;
+        let text = string.as_wtf8(agent);
+        let start = self.position;
+        let negative = self.peek_in(text) == Some(b'-');
+        if negative {
+            self.position += 1;
+        }
+        // Integer part: a single zero or a non-zero digit followed by digits.
+        match self.peek_in(text) {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.skip_digits(text),
+            None => return Err(self.error(JsonSyntaxErrorKind::UnexpectedEnd)),
+            _ => return Err(self.error(JsonSyntaxErrorKind::NoNumberAfterMinusSign)),
+        }
+        let integer_end = self.position;
+        if self.peek_in(text) == Some(b'.') {
+            self.position += 1;
+            if !matches!(self.peek_in(text), Some(b'0'..=b'9')) {
+                return Err(self.error(JsonSyntaxErrorKind::UnterminatedFractionalNumber));
+            }
+            self.skip_digits(text);
+        }
+        if let Some(b'e' | b'E') = self.peek_in(text) {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek_in(text) {
+                self.position += 1;
+            }
+            if !matches!(self.peek_in(text), Some(b'0'..=b'9')) {
+                return Err(self.error(JsonSyntaxErrorKind::ExponentMissingNumber));
+            }
+            self.skip_digits(text);
+        }
+        // The number consists of ASCII characters only.
+        let digits = text.slice(start, self.position).as_str().unwrap();
+        // Fast path: integers that are exactly representable as f64. Negative
+        // zero must go through the float path.
+        if integer_end == self.position
+            && integer_end - start <= 15
+            && !(negative && integer_end - start == 2 && digits.ends_with('0'))
+        {
+            let integer = digits.parse::<i64>().unwrap();
+            return Ok(Number::from_i64(agent, integer, gc));
+        }
+        let number = fast_float::parse::<f64, _>(digits).unwrap();
+        Ok(Number::from_f64(agent, number, gc))
+    }
+
+    fn skip_digits(&mut self, text: &Wtf8) {
+        while let Some(b'0'..=b'9') = self.peek_in(text) {
+            self.position += 1;
+        }
+    }
+}
+
+enum JsonString {
+    /// WTF-8 byte range of a string without escapes in the text.
+    Borrowed(Range<usize>),
+    Owned(Wtf8Buf),
+}
@@ -0,0 +1,26 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [Temporal](https://tc39.es/proposal-temporal/)
+//!
+//! The Temporal proposal with the ISO 8601 calendar only.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod calendar;
+pub mod duration;
+pub mod duration_objects;
+pub mod instant;
+pub mod instant_objects;
+pub mod now_object;
+pub(crate) mod parsing;
+pub mod plain_date;
+pub mod plain_date_objects;
+pub mod plain_date_time;
+pub mod plain_date_time_objects;
+pub mod plain_time;
+pub mod plain_time_objects;
+pub mod temporal_object;
+pub(crate) mod time_zone;
+pub mod zoned_date_time;
+pub mod zoned_date_time_objects;
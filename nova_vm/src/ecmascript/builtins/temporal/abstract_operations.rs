@@ -0,0 +1,1619 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [13 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-abstractops)
+//!
+//! Units, rounding and the reading of options shared by all Temporal types.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::get,
+            type_conversion::{to_number, to_string},
+        },
+        builtins::ordinary::ordinary_object_create_with_intrinsics,
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        types::{BUILTIN_STRING_MEMORY, Object, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+    },
+};
+
+/// Defines a string-valued option as an enum.
+macro_rules! option_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub(crate) enum $name {
+            $($variant,)+
+        }
+
+        impl $name {
+            /// The valid option values, in the order of the variants.
+            pub(crate) const VALUES: &'static [&'static str] = &[$($value,)+];
+
+            pub(crate) fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+
+            /// Get the variant of a valid option value.
+            pub(crate) fn from_option(value: &str) -> Self {
+                const VARIANTS: &[$name] = &[$($name::$variant,)+];
+                let index = Self::VALUES.iter().position(|v| *v == value).unwrap();
+                VARIANTS[index]
+            }
+        }
+
+        $crate::engine::context::trivially_bindable!($name);
+    };
+}
+
+option_enum!(
+    /// ### [Table 22: Rounding modes](https://tc39.es/proposal-temporal/#table-temporal-rounding-modes)
+    RoundingMode {
+        Ceil => "ceil",
+        Floor => "floor",
+        Expand => "expand",
+        Trunc => "trunc",
+        HalfCeil => "halfCeil",
+        HalfFloor => "halfFloor",
+        HalfExpand => "halfExpand",
+        HalfTrunc => "halfTrunc",
+        HalfEven => "halfEven",
+    }
+);
+
+option_enum!(
+    /// The "overflow" option of operations creating dates from fields.
+    Overflow {
+        Constrain => "constrain",
+        Reject => "reject",
+    }
+);
+
+option_enum!(
+    /// The "disambiguation" option of operations resolving a wall-clock time
+    /// in a time zone.
+    Disambiguation {
+        Compatible => "compatible",
+        Earlier => "earlier",
+        Later => "later",
+        Reject => "reject",
+    }
+);
+
+option_enum!(
+    /// The "offset" option of operations resolving a wall-clock time with a
+    /// UTC offset in a time zone.
+    OffsetOption {
+        Prefer => "prefer",
+        Use => "use",
+        Ignore => "ignore",
+        Reject => "reject",
+    }
+);
+
+option_enum!(
+    /// The "calendarName" option of toString methods.
+    ShowCalendar {
+        Auto => "auto",
+        Always => "always",
+        Never => "never",
+        Critical => "critical",
+    }
+);
+
+option_enum!(
+    /// The "offset" option of ZonedDateTime.prototype.toString.
+    ShowOffset {
+        Auto => "auto",
+        Never => "never",
+    }
+);
+
+option_enum!(
+    /// The "timeZoneName" option of ZonedDateTime.prototype.toString.
+    ShowTimeZone {
+        Auto => "auto",
+        Never => "never",
+        Critical => "critical",
+    }
+);
+
+option_enum!(
+    /// The "direction" option of ZonedDateTime.prototype.getTimeZoneTransition.
+    Direction {
+        Next => "next",
+        Previous => "previous",
+    }
+);
+
+/// ### [Table 21: Temporal units by descending magnitude](https://tc39.es/proposal-temporal/#table-temporal-units)
+///
+/// The units are ordered from the largest to the smallest, so the larger of
+/// two units compares as less.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+trivially_bindable!(Unit);
+
+impl Unit {
+    /// All units, in the order of Table 21.
+    pub(crate) const ALL: [Unit; 10] = [
+        Unit::Year,
+        Unit::Month,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+    ];
+
+    /// The "Singular property name" column of Table 21.
+    pub(crate) fn singular(self) -> &'static str {
+        match self {
+            Unit::Year => "year",
+            Unit::Month => "month",
+            Unit::Week => "week",
+            Unit::Day => "day",
+            Unit::Hour => "hour",
+            Unit::Minute => "minute",
+            Unit::Second => "second",
+            Unit::Millisecond => "millisecond",
+            Unit::Microsecond => "microsecond",
+            Unit::Nanosecond => "nanosecond",
+        }
+    }
+
+    /// The "Plural property name" column of Table 21.
+    pub(crate) fn plural(self) -> &'static str {
+        match self {
+            Unit::Year => "years",
+            Unit::Month => "months",
+            Unit::Week => "weeks",
+            Unit::Day => "days",
+            Unit::Hour => "hours",
+            Unit::Minute => "minutes",
+            Unit::Second => "seconds",
+            Unit::Millisecond => "milliseconds",
+            Unit::Microsecond => "microseconds",
+            Unit::Nanosecond => "nanoseconds",
+        }
+    }
+
+    /// ### [13.19 TemporalUnitCategory ( unit )](https://tc39.es/proposal-temporal/#sec-temporal-temporalunitcategory)
+    ///
+    /// Returns true if the category of the unit is date, and false if it is
+    /// time.
+    pub(crate) fn is_date_unit(self) -> bool {
+        self <= Unit::Day
+    }
+
+    /// ### [13.17 IsCalendarUnit ( unit )](https://tc39.es/proposal-temporal/#sec-temporal-iscalendarunit)
+    pub(crate) fn is_calendar_unit(self) -> bool {
+        // 1. If unit is year, return true.
+        // 2. If unit is month, return true.
+        // 3. If unit is week, return true.
+        // 4. Return false.
+        self <= Unit::Week
+    }
+
+    /// ### [13.18 LengthInNanoseconds ( unit )](https://tc39.es/proposal-temporal/#sec-temporal-lengthinnanoseconds)
+    ///
+    /// The "Length in nanoseconds" column of Table 21. Calendar units have no
+    /// fixed length.
+    pub(crate) fn length_in_nanoseconds(self) -> i128 {
+        match self {
+            Unit::Year | Unit::Month | Unit::Week => {
+                unreachable!("calendar units have no fixed length")
+            }
+            Unit::Day => NS_PER_DAY,
+            Unit::Hour => 3_600_000_000_000,
+            Unit::Minute => 60_000_000_000,
+            Unit::Second => 1_000_000_000,
+            Unit::Millisecond => 1_000_000,
+            Unit::Microsecond => 1_000,
+            Unit::Nanosecond => 1,
+        }
+    }
+}
+
+/// ### [13.16 LargerOfTwoTemporalUnits ( u1, u2 )](https://tc39.es/proposal-temporal/#sec-temporal-largeroftwotemporalunits)
+pub(crate) fn larger_of_two_temporal_units(u1: Unit, u2: Unit) -> Unit {
+    // 1. For each row of Table 21, except the header row, in table order, do
+    //    a. Let unit be the value in the "Value" column of the row.
+    //    b. If u1 is unit, return unit.
+    //    c. If u2 is unit, return unit.
+    u1.min(u2)
+}
+
+/// The value of an option read by GetTemporalUnitValuedOption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitValue {
+    Unset,
+    Auto,
+    Unit(Unit),
+}
+trivially_bindable!(UnitValue);
+
+/// The unitGroup argument of ValidateTemporalUnitValue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+/// The precision of the seconds in a string representation of a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Precision {
+    Auto,
+    Minute,
+    /// A fixed number of fractional second digits, from 0 to 9.
+    Digits(u8),
+}
+trivially_bindable!(Precision);
+
+/// The operation argument of the difference operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DifferenceOperation {
+    Until,
+    Since,
+}
+
+/// The operation argument of the addition operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArithmeticOperation {
+    Add,
+    Subtract,
+}
+
+/// Number of nanoseconds in a day, nsPerDay.
+pub(crate) const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// nsMaxInstant = 10**8 × nsPerDay.
+pub(crate) const NS_MAX_INSTANT: i128 = 100_000_000 * NS_PER_DAY;
+
+/// nsMinInstant = -nsMaxInstant.
+pub(crate) const NS_MIN_INSTANT: i128 = -NS_MAX_INSTANT;
+
+/// ### [8.5.1 IsValidEpochNanoseconds ( epochNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-isvalidepochnanoseconds)
+pub(crate) fn is_valid_epoch_nanoseconds(epoch_nanoseconds: i128) -> bool {
+    // 1. If ℝ(epochNanoseconds) < nsMinInstant or ℝ(epochNanoseconds) >
+    //    nsMaxInstant, then
+    //    a. Return false.
+    // 2. Return true.
+    (NS_MIN_INSTANT..=NS_MAX_INSTANT).contains(&epoch_nanoseconds)
+}
+
+/// A RangeError thrown by an abstract operation which does not call into
+/// JavaScript, carrying the message of the error.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RangeError(pub(crate) &'static str);
+trivially_bindable!(RangeError);
+
+impl RangeError {
+    pub(crate) fn throw<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        agent.throw_exception_with_static_message(ExceptionType::RangeError, self.0, gc)
+    }
+}
+
+/// The result of an abstract operation which can only throw a RangeError.
+pub(crate) type TemporalResult<T> = Result<T, RangeError>;
+
+/// ### [13.6 GetOptionsObject ( options )](https://tc39.es/proposal-temporal/#sec-getoptionsobject)
+pub(crate) fn get_options_object<'a>(
+    agent: &mut Agent,
+    options: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(ordinary_object_create_with_intrinsics(
+            agent, None, None, gc,
+        ));
+    }
+    // 2. If options is an Object, then
+    if let Ok(options) = Object::try_from(options) {
+        // a. Return options.
+        return Ok(options.bind(gc));
+    }
+    // 3. Throw a TypeError exception.
+    Err(agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "options must be an object or undefined",
+        gc,
+    ))
+}
+
+/// ### [13.7 GetOption ( options, property, type, values, default )](https://tc39.es/proposal-temporal/#sec-getoption)
+///
+/// GetOption with type string and a non-empty list of values. A return value
+/// of None means that the option is undefined.
+pub(crate) fn get_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: PropertyKey,
+    values: &'static [&'static str],
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<&'static str>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // a. If default is required, throw a RangeError exception.
+        // b. Return default.
+        return Ok(None);
+    }
+    // 3. If type is boolean, then
+    //    a. Set value to ToBoolean(value).
+    // 4. Else,
+    //    a. Assert: type is string.
+    //    b. Set value to ? ToString(value).
+    let value = to_string(agent, value.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 5. If values is not empty and values does not contain value, throw a
+    //    RangeError exception.
+    let Some(value) = values
+        .iter()
+        .copied()
+        .find(|v| value.as_wtf8(agent).as_str() == Some(*v))
+    else {
+        let value = value.as_wtf8(agent).to_string_lossy().into_owned();
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!("Value {value} out of range for option"),
+            gc.into_nogc(),
+        ));
+    };
+    // 6. Return value.
+    Ok(Some(value))
+}
+
+/// ### [13.1 GetTemporalOverflowOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloverflowoption)
+pub(crate) fn get_temporal_overflow_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Overflow> {
+    // 1. Let stringValue be ? GetOption(options, "overflow", string,
+    //    « "constrain", "reject" », "constrain").
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.overflow.into(),
+        Overflow::VALUES,
+        gc,
+    )?;
+    // 2. If stringValue is "constrain", return constrain.
+    // 3. Return reject.
+    Ok(string_value.map_or(Overflow::Constrain, Overflow::from_option))
+}
+
+/// Read the overflow option of an options argument: GetOptionsObject
+/// followed by GetTemporalOverflowOption.
+pub(crate) fn get_overflow_from_options<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Overflow> {
+    // 1. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options, gc.nogc()).unbind()?;
+    // 2. Return ? GetTemporalOverflowOption(resolvedOptions).
+    get_temporal_overflow_option(agent, resolved_options, gc)
+}
+
+/// ### [13.2 GetTemporalDisambiguationOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaldisambiguationoption)
+pub(crate) fn get_temporal_disambiguation_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Disambiguation> {
+    // 1. Let stringValue be ? GetOption(options, "disambiguation", string,
+    //    « "compatible", "earlier", "later", "reject" », "compatible").
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.disambiguation.into(),
+        Disambiguation::VALUES,
+        gc,
+    )?;
+    // 2. If stringValue is "compatible", return compatible.
+    // 3. If stringValue is "earlier", return earlier.
+    // 4. If stringValue is "later", return later.
+    // 5. Return reject.
+    Ok(string_value.map_or(Disambiguation::Compatible, Disambiguation::from_option))
+}
+
+/// ### [13.4 GetTemporalOffsetOption ( options, fallback )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloffsetoption)
+pub(crate) fn get_temporal_offset_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    fallback: OffsetOption,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, OffsetOption> {
+    // 1-5. Let stringFallback be the String of fallback.
+    // 6. Let stringValue be ? GetOption(options, "offset", string,
+    //    « "prefer", "use", "ignore", "reject" », stringFallback).
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.offset.into(),
+        OffsetOption::VALUES,
+        gc,
+    )?;
+    // 7-10. Return the value corresponding to stringValue.
+    Ok(string_value.map_or(fallback, OffsetOption::from_option))
+}
+
+/// ### [13.5 GetTemporalShowCalendarNameOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowcalendarnameoption)
+pub(crate) fn get_temporal_show_calendar_name_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ShowCalendar> {
+    // 1. Let stringValue be ? GetOption(options, "calendarName", string,
+    //    « "auto", "always", "never", "critical" », "auto").
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.calendarName.into(),
+        ShowCalendar::VALUES,
+        gc,
+    )?;
+    // 2-5. Return the value corresponding to stringValue.
+    Ok(string_value.map_or(ShowCalendar::Auto, ShowCalendar::from_option))
+}
+
+/// ### [13.3 GetTemporalShowOffsetOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowoffsetoption)
+pub(crate) fn get_temporal_show_offset_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ShowOffset> {
+    // 1. Let stringValue be ? GetOption(options, "offset", string,
+    //    « "auto", "never" », "auto").
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.offset.into(),
+        ShowOffset::VALUES,
+        gc,
+    )?;
+    // 2. If stringValue is "never", return never.
+    // 3. Return auto.
+    Ok(string_value.map_or(ShowOffset::Auto, ShowOffset::from_option))
+}
+
+/// ### [13.3 GetTemporalShowTimeZoneNameOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowtimezonenameoption)
+pub(crate) fn get_temporal_show_time_zone_name_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ShowTimeZone> {
+    // 1. Let stringValue be ? GetOption(options, "timeZoneName", string,
+    //    « "auto", "never", "critical" », "auto").
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.timeZoneName.into(),
+        ShowTimeZone::VALUES,
+        gc,
+    )?;
+    // 2. If stringValue is "never", return never.
+    // 3. If stringValue is "critical", return critical.
+    // 4. Return auto.
+    Ok(string_value.map_or(ShowTimeZone::Auto, ShowTimeZone::from_option))
+}
+
+/// ### [13.6 GetDirectionOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-getdirectionoption)
+///
+/// Returns None if the direction is unset.
+pub(crate) fn get_direction_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Direction>> {
+    // 1. Let stringValue be ? GetOption(options, "direction", string,
+    //    « "next", "previous" », unset).
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.direction.into(),
+        Direction::VALUES,
+        gc,
+    )?;
+    // 2. If stringValue is undefined, return unset.
+    // 3. If stringValue is "next", return next.
+    // 4. If stringValue is "previous", return previous.
+    Ok(string_value.map(Direction::from_option))
+}
+
+/// ### [13.8 GetRoundingModeOption ( options, fallback )](https://tc39.es/proposal-temporal/#sec-temporal-getroundingmodeoption)
+pub(crate) fn get_rounding_mode_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    fallback: RoundingMode,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, RoundingMode> {
+    // 1. Let allowedStrings be the List of Strings from the "String
+    //    Identifier" column of Table 22.
+    // 2. Let stringFallback be the value from the "String Identifier" column
+    //    of the row with fallback in its "Rounding Mode" column.
+    // 3. Let stringValue be ? GetOption(options, "roundingMode", string,
+    //    allowedStrings, stringFallback).
+    let string_value = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.roundingMode.into(),
+        RoundingMode::VALUES,
+        gc,
+    )?;
+    // 4. Return the value from the "Rounding Mode" column of the row with
+    //    stringValue in its "String Identifier" column.
+    Ok(string_value.map_or(fallback, RoundingMode::from_option))
+}
+
+/// ### [13.9 NegateRoundingMode ( roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-negateroundingmode)
+pub(crate) fn negate_rounding_mode(rounding_mode: RoundingMode) -> RoundingMode {
+    match rounding_mode {
+        // 1. If roundingMode is ceil, return floor.
+        RoundingMode::Ceil => RoundingMode::Floor,
+        // 2. If roundingMode is floor, return ceil.
+        RoundingMode::Floor => RoundingMode::Ceil,
+        // 3. If roundingMode is half-ceil, return half-floor.
+        RoundingMode::HalfCeil => RoundingMode::HalfFloor,
+        // 4. If roundingMode is half-floor, return half-ceil.
+        RoundingMode::HalfFloor => RoundingMode::HalfCeil,
+        // 5. Return roundingMode.
+        rounding_mode => rounding_mode,
+    }
+}
+
+/// ### [13.10 GetRoundingIncrementOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-getroundingincrementoption)
+pub(crate) fn get_rounding_increment_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, u32> {
+    // 1. Let value be ? Get(options, "roundingIncrement").
+    let value = get(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.roundingIncrement.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If value is undefined, return 1𝔽.
+    if value.is_undefined() {
+        return Ok(1);
+    }
+    // 3. Let integerIncrement be ? ToIntegerWithTruncation(value).
+    let integer_increment =
+        to_integer_with_truncation(agent, value.unbind(), gc.reborrow()).unbind()?;
+    // 4. If integerIncrement < 1 or integerIncrement > 10**9, throw a
+    //    RangeError exception.
+    if !(1.0..=1e9).contains(&integer_increment) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "roundingIncrement must be between 1 and 1e9",
+            gc.into_nogc(),
+        ));
+    }
+    // 5. Return integerIncrement.
+    Ok(integer_increment as u32)
+}
+
+/// ### [13.11 ValidateTemporalRoundingIncrement ( increment, dividend, inclusive )](https://tc39.es/proposal-temporal/#sec-validatetemporalroundingincrement)
+pub(crate) fn validate_temporal_rounding_increment(
+    increment: u32,
+    dividend: u64,
+    inclusive: bool,
+) -> TemporalResult<()> {
+    let increment = u64::from(increment);
+    // 1. If inclusive is true, then
+    //    a. Let maximum be dividend.
+    // 2. Else,
+    //    a. Assert: dividend > 1.
+    //    b. Let maximum be dividend - 1.
+    let maximum = if inclusive { dividend } else { dividend - 1 };
+    // 3. If increment > maximum, throw a RangeError exception.
+    if increment > maximum {
+        return Err(RangeError("roundingIncrement out of range"));
+    }
+    // 4. If dividend modulo increment ≠ 0, then
+    if dividend % increment != 0 {
+        // a. Throw a RangeError exception.
+        return Err(RangeError(
+            "roundingIncrement must divide the next larger unit",
+        ));
+    }
+    // 5. Return unused.
+    Ok(())
+}
+
+/// ### [13.12 GetTemporalFractionalSecondDigitsOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalfractionalseconddigitsoption)
+///
+/// A return value of None means auto.
+pub(crate) fn get_temporal_fractional_second_digits_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u8>> {
+    // 1. Let digitsValue be ? Get(options, "fractionalSecondDigits").
+    let digits_value = get(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.fractionalSecondDigits.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If digitsValue is undefined, return auto.
+    if digits_value.is_undefined() {
+        return Ok(None);
+    }
+    // 3. If digitsValue is not a Number, then
+    if !digits_value.is_number() {
+        // a. If ? ToString(digitsValue) is not "auto", throw a RangeError
+        //    exception.
+        let string = to_string(agent, digits_value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        if string.as_wtf8(agent).as_str() != Some("auto") {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "fractionalSecondDigits must be 'auto' or a number from 0 to 9",
+                gc.into_nogc(),
+            ));
+        }
+        // b. Return auto.
+        return Ok(None);
+    }
+    let digits_value = to_number(agent, digits_value.unbind(), gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 4. If digitsValue is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+    // 5. Let digitCount be floor(ℝ(digitsValue)).
+    let digit_count = digits_value.floor();
+    // 6. If digitCount < 0 or digitCount > 9, throw a RangeError exception.
+    if !(0.0..=9.0).contains(&digit_count) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "fractionalSecondDigits must be 'auto' or a number from 0 to 9",
+            gc.into_nogc(),
+        ));
+    }
+    // 7. Return digitCount.
+    Ok(Some(digit_count as u8))
+}
+
+/// The Record returned by ToSecondsStringPrecisionRecord.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SecondsStringPrecision {
+    /// \[\[Precision]]
+    pub(crate) precision: Precision,
+    /// \[\[Unit]]
+    pub(crate) unit: Unit,
+    /// \[\[Increment]]
+    pub(crate) increment: u32,
+}
+
+/// ### [13.13 ToSecondsStringPrecisionRecord ( smallestUnit, fractionalDigitCount )](https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecisionrecord)
+///
+/// The smallest unit must have been validated to be a time unit other than
+/// hour.
+pub(crate) fn to_seconds_string_precision_record(
+    smallest_unit: Option<Unit>,
+    fractional_digit_count: Option<u8>,
+) -> SecondsStringPrecision {
+    let record = |precision, unit, increment| SecondsStringPrecision {
+        precision,
+        unit,
+        increment,
+    };
+    match smallest_unit {
+        // 1. If smallestUnit is minute, then
+        //    a. Return the Record { [[Precision]]: minute, [[Unit]]: minute,
+        //       [[Increment]]: 1 }.
+        Some(Unit::Minute) => record(Precision::Minute, Unit::Minute, 1),
+        // 2. If smallestUnit is second, then
+        //    a. Return the Record { [[Precision]]: 0, [[Unit]]: second,
+        //       [[Increment]]: 1 }.
+        Some(Unit::Second) => record(Precision::Digits(0), Unit::Second, 1),
+        // 3. If smallestUnit is millisecond, then
+        //    a. Return the Record { [[Precision]]: 3, [[Unit]]: millisecond,
+        //       [[Increment]]: 1 }.
+        Some(Unit::Millisecond) => record(Precision::Digits(3), Unit::Millisecond, 1),
+        // 4. If smallestUnit is microsecond, then
+        //    a. Return the Record { [[Precision]]: 6, [[Unit]]: microsecond,
+        //       [[Increment]]: 1 }.
+        Some(Unit::Microsecond) => record(Precision::Digits(6), Unit::Microsecond, 1),
+        // 5. If smallestUnit is nanosecond, then
+        //    a. Return the Record { [[Precision]]: 9, [[Unit]]: nanosecond,
+        //       [[Increment]]: 1 }.
+        Some(Unit::Nanosecond) => record(Precision::Digits(9), Unit::Nanosecond, 1),
+        Some(_) => unreachable!(),
+        // 6. Assert: smallestUnit is unset.
+        None => match fractional_digit_count {
+            // 7. If fractionalDigitCount is auto, then
+            //    a. Return the Record { [[Precision]]: auto, [[Unit]]:
+            //       nanosecond, [[Increment]]: 1 }.
+            None => record(Precision::Auto, Unit::Nanosecond, 1),
+            // 8. If fractionalDigitCount = 0, then
+            //    a. Return the Record { [[Precision]]: 0, [[Unit]]: second,
+            //       [[Increment]]: 1 }.
+            Some(0) => record(Precision::Digits(0), Unit::Second, 1),
+            // 9. If fractionalDigitCount is in the inclusive interval from 1
+            //    to 3, then
+            //    a. Return the Record { [[Precision]]: fractionalDigitCount,
+            //       [[Unit]]: millisecond, [[Increment]]:
+            //       10**(3 - fractionalDigitCount) }.
+            Some(digits @ 1..=3) => record(
+                Precision::Digits(digits),
+                Unit::Millisecond,
+                10u32.pow(3 - u32::from(digits)),
+            ),
+            // 10. If fractionalDigitCount is in the inclusive interval from 4
+            //     to 6, then
+            //     a. Return the Record { [[Precision]]: fractionalDigitCount,
+            //        [[Unit]]: microsecond, [[Increment]]:
+            //        10**(6 - fractionalDigitCount) }.
+            Some(digits @ 4..=6) => record(
+                Precision::Digits(digits),
+                Unit::Microsecond,
+                10u32.pow(6 - u32::from(digits)),
+            ),
+            // 11. Assert: fractionalDigitCount is in the inclusive interval
+            //     from 7 to 9.
+            // 12. Return the Record { [[Precision]]: fractionalDigitCount,
+            //     [[Unit]]: nanosecond, [[Increment]]:
+            //     10**(9 - fractionalDigitCount) }.
+            Some(digits) => record(
+                Precision::Digits(digits),
+                Unit::Nanosecond,
+                10u32.pow(9 - u32::from(digits)),
+            ),
+        },
+    }
+}
+
+/// ### [13.14 GetTemporalUnitValuedOption ( options, key, default )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunitvaluedoption)
+///
+/// If required is true, the default is required; otherwise it is unset.
+pub(crate) fn get_temporal_unit_valued_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    key: String<'static>,
+    required: bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, UnitValue> {
+    const ALLOWED_STRINGS: &[&str] = &[
+        "year",
+        "years",
+        "month",
+        "months",
+        "week",
+        "weeks",
+        "day",
+        "days",
+        "hour",
+        "hours",
+        "minute",
+        "minutes",
+        "second",
+        "seconds",
+        "millisecond",
+        "milliseconds",
+        "microsecond",
+        "microseconds",
+        "nanosecond",
+        "nanoseconds",
+        "auto",
+    ];
+    // 1. Let allowedStrings be a List containing all values in the "Singular
+    //    property name" and "Plural property name" columns of Table 21,
+    //    except the header row.
+    // 2. Append "auto" to allowedStrings.
+    // 3. NOTE: For each singular Temporal unit name that is contained within
+    //    allowedStrings, the corresponding plural name is also contained
+    //    within it.
+    // 4. If default is unset, then
+    //    a. Let defaultValue be undefined.
+    // 5. Else,
+    //    a. Let defaultValue be default.
+    // 6. Let value be ? GetOption(options, key, string, allowedStrings,
+    //    defaultValue).
+    let value = get_option(agent, options, key.into(), ALLOWED_STRINGS, gc.reborrow()).unbind()?;
+    let Some(value) = value else {
+        if required {
+            let message = format!("{} is required", key.as_str(agent));
+            return Err(agent.throw_exception(ExceptionType::RangeError, message, gc.into_nogc()));
+        }
+        // 7. If value is undefined, return unset.
+        return Ok(UnitValue::Unset);
+    };
+    // 8. If value is "auto", return auto.
+    if value == "auto" {
+        return Ok(UnitValue::Auto);
+    }
+    // 9. Return the value in the "Value" column of Table 21 corresponding to
+    //    the row with value in its "Singular property name" or "Plural
+    //    property name" column.
+    let unit = Unit::ALL
+        .into_iter()
+        .find(|unit| unit.singular() == value || unit.plural() == value)
+        .unwrap();
+    Ok(UnitValue::Unit(unit))
+}
+
+/// ### [13.15 ValidateTemporalUnitValue ( value, unitGroup \[ , extraValues \] )](https://tc39.es/proposal-temporal/#sec-temporal-validatetemporalunitvaluedoption)
+pub(crate) fn validate_temporal_unit_value(
+    value: UnitValue,
+    unit_group: UnitGroup,
+    extra_values: &[UnitValue],
+) -> TemporalResult<()> {
+    // 1. If value is unset, return unused.
+    // 2. If extraValues is present and extraValues contains value, return
+    //    unused.
+    if value == UnitValue::Unset || extra_values.contains(&value) {
+        return Ok(());
+    }
+    // 3. Let category be the value in the “Category” column of the row of
+    //    Table 21 whose “Value” column contains value. If there is no such
+    //    row, throw a RangeError exception.
+    let UnitValue::Unit(unit) = value else {
+        return Err(RangeError("'auto' is not allowed for this option"));
+    };
+    match (unit.is_date_unit(), unit_group) {
+        // 4. If category is date and unitGroup is date or datetime, return
+        //    unused.
+        (true, UnitGroup::Date | UnitGroup::DateTime) => Ok(()),
+        // 5. If category is time and unitGroup is time or datetime, return
+        //    unused.
+        (false, UnitGroup::Time | UnitGroup::DateTime) => Ok(()),
+        // 6. Throw a RangeError exception.
+        (true, _) => Err(RangeError("a date unit is not allowed for this option")),
+        (false, _) => Err(RangeError("a time unit is not allowed for this option")),
+    }
+}
+
+/// ### [13.20 MaximumTemporalDurationRoundingIncrement ( unit )](https://tc39.es/proposal-temporal/#sec-temporal-maximumtemporaldurationroundingincrement)
+///
+/// A return value of None means unset.
+pub(crate) fn maximum_temporal_duration_rounding_increment(unit: Unit) -> Option<u64> {
+    match unit {
+        // 1. Return the value from the "Maximum duration rounding increment"
+        //    column of the row of Table 21 in which unit is in the "Value"
+        //    column.
+        Unit::Year | Unit::Month | Unit::Week | Unit::Day => None,
+        Unit::Hour => Some(24),
+        Unit::Minute | Unit::Second => Some(60),
+        Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond => Some(1000),
+    }
+}
+
+/// The Record returned by GetDifferenceSettings.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DifferenceSettings {
+    /// \[\[SmallestUnit]]
+    pub(crate) smallest_unit: Unit,
+    /// \[\[LargestUnit]]
+    pub(crate) largest_unit: Unit,
+    /// \[\[RoundingMode]]
+    pub(crate) rounding_mode: RoundingMode,
+    /// \[\[RoundingIncrement]]
+    pub(crate) rounding_increment: u32,
+}
+trivially_bindable!(DifferenceSettings);
+
+/// ### [13.42 GetDifferenceSettings ( operation, options, unitGroup, disallowedUnits, fallbackSmallestUnit, smallestLargestDefaultUnit )](https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn get_difference_settings<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    options: Object,
+    unit_group: UnitGroup,
+    disallowed_units: &[Unit],
+    fallback_smallest_unit: Unit,
+    smallest_largest_default_unit: Unit,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DifferenceSettings> {
+    let options = options.scope(agent, gc.nogc());
+    // 1. NOTE: The following steps read options and perform independent
+    //    validation in alphabetical order.
+    // 2. Let largestUnit be ? GetTemporalUnitValuedOption(options,
+    //    "largestUnit", unset).
+    let largest_unit = get_temporal_unit_valued_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.largestUnit,
+        false,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 3. Let roundingIncrement be ? GetRoundingIncrementOption(options).
+    let rounding_increment =
+        get_rounding_increment_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 4. Let roundingMode be ? GetRoundingModeOption(options, trunc).
+    let rounding_mode = get_rounding_mode_option(
+        agent,
+        options.get(agent),
+        RoundingMode::Trunc,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 5. Let smallestUnit be ? GetTemporalUnitValuedOption(options,
+    //    "smallestUnit", unset).
+    let smallest_unit = get_temporal_unit_valued_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.smallestUnit,
+        false,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    difference_settings(
+        operation,
+        largest_unit,
+        rounding_increment,
+        rounding_mode,
+        smallest_unit,
+        unit_group,
+        disallowed_units,
+        fallback_smallest_unit,
+        smallest_largest_default_unit,
+    )
+    .map_err(|err| err.throw(agent, gc))
+}
+
+/// The validation steps of GetDifferenceSettings.
+#[allow(clippy::too_many_arguments)]
+fn difference_settings(
+    operation: DifferenceOperation,
+    largest_unit: UnitValue,
+    rounding_increment: u32,
+    rounding_mode: RoundingMode,
+    smallest_unit: UnitValue,
+    unit_group: UnitGroup,
+    disallowed_units: &[Unit],
+    fallback_smallest_unit: Unit,
+    smallest_largest_default_unit: Unit,
+) -> TemporalResult<DifferenceSettings> {
+    // 6. Perform ? ValidateTemporalUnitValue(largestUnit, unitGroup, « auto »).
+    validate_temporal_unit_value(largest_unit, unit_group, &[UnitValue::Auto])?;
+    // 7. If largestUnit is unset, then
+    //    a. Set largestUnit to auto.
+    // 8. If disallowedUnits contains largestUnit, throw a RangeError
+    //    exception.
+    let largest_unit = match largest_unit {
+        UnitValue::Unit(unit) if disallowed_units.contains(&unit) => {
+            return Err(RangeError("largestUnit is not allowed for this operation"));
+        }
+        UnitValue::Unit(unit) => Some(unit),
+        UnitValue::Unset | UnitValue::Auto => None,
+    };
+    // 9. If operation is since, then
+    //    a. Set roundingMode to NegateRoundingMode(roundingMode).
+    let rounding_mode = match operation {
+        DifferenceOperation::Since => negate_rounding_mode(rounding_mode),
+        DifferenceOperation::Until => rounding_mode,
+    };
+    // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, unitGroup).
+    validate_temporal_unit_value(smallest_unit, unit_group, &[])?;
+    // 11. If smallestUnit is unset, then
+    //     a. Set smallestUnit to fallbackSmallestUnit.
+    let smallest_unit = match smallest_unit {
+        UnitValue::Unit(unit) => unit,
+        _ => fallback_smallest_unit,
+    };
+    // 12. If disallowedUnits contains smallestUnit, throw a RangeError
+    //     exception.
+    if disallowed_units.contains(&smallest_unit) {
+        return Err(RangeError("smallestUnit is not allowed for this operation"));
+    }
+    // 13. Let defaultLargestUnit be
+    //     LargerOfTwoTemporalUnits(smallestLargestDefaultUnit, smallestUnit).
+    let default_largest_unit =
+        larger_of_two_temporal_units(smallest_largest_default_unit, smallest_unit);
+    // 14. If largestUnit is auto, set largestUnit to defaultLargestUnit.
+    let largest_unit = largest_unit.unwrap_or(default_largest_unit);
+    // 15. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not
+    //     largestUnit, throw a RangeError exception.
+    if larger_of_two_temporal_units(largest_unit, smallest_unit) != largest_unit {
+        return Err(RangeError("smallestUnit is larger than largestUnit"));
+    }
+    // 16. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+    // 17. If maximum is not unset, perform
+    //     ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum,
+    //     false).
+    if let Some(maximum) = maximum_temporal_duration_rounding_increment(smallest_unit) {
+        validate_temporal_rounding_increment(rounding_increment, maximum, false)?;
+    }
+    // 18. Return the Record { [[SmallestUnit]]: smallestUnit,
+    //     [[LargestUnit]]: largestUnit, [[RoundingMode]]: roundingMode,
+    //     [[RoundingIncrement]]: roundingIncrement,  }.
+    Ok(DifferenceSettings {
+        smallest_unit,
+        largest_unit,
+        rounding_mode,
+        rounding_increment,
+    })
+}
+
+/// The options of the round methods, read by the steps common to
+/// Temporal.Instant.prototype.round and the other round methods.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RoundToOptions {
+    pub(crate) rounding_increment: u32,
+    pub(crate) rounding_mode: RoundingMode,
+    pub(crate) smallest_unit: UnitValue,
+}
+trivially_bindable!(RoundToOptions);
+
+/// Read the roundingIncrement, roundingMode and the required smallestUnit
+/// options of the roundTo argument of a round method, in alphabetical order.
+pub(crate) fn get_round_to_options<'gc>(
+    agent: &mut Agent,
+    round_to: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, RoundToOptions> {
+    let round_to = round_to.bind(gc.nogc());
+    // 3. If roundTo is undefined, then
+    if round_to.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "round requires an options argument",
+            gc.into_nogc(),
+        ));
+    }
+    // 4. If roundTo is a String, then
+    if let Ok(param_string) = String::try_from(round_to) {
+        // a. Let paramString be roundTo.
+        // b. Set roundTo to OrdinaryObjectCreate(null).
+        // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit",
+        //    paramString).
+        // NOTE: Reading the other options from the new object gives their
+        // defaults, so the object need not be created.
+        let Some(smallest_unit) = parse_unit_string(agent, param_string) else {
+            let value = param_string.as_wtf8(agent).to_string_lossy().into_owned();
+            return Err(agent.throw_exception(
+                ExceptionType::RangeError,
+                format!("Value {value} out of range for option"),
+                gc.into_nogc(),
+            ));
+        };
+        return Ok(RoundToOptions {
+            rounding_increment: 1,
+            rounding_mode: RoundingMode::HalfExpand,
+            smallest_unit,
+        });
+    }
+    // 5. Else,
+    //    a. Set roundTo to ? GetOptionsObject(roundTo).
+    let round_to = get_options_object(agent, round_to, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 6. NOTE: The following steps read options and perform independent
+    //    validation in alphabetical order.
+    // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+    let rounding_increment =
+        get_rounding_increment_option(agent, round_to.get(agent), gc.reborrow()).unbind()?;
+    // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+    let rounding_mode = get_rounding_mode_option(
+        agent,
+        round_to.get(agent),
+        RoundingMode::HalfExpand,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo,
+    //    "smallestUnit", required).
+    let smallest_unit = get_temporal_unit_valued_option(
+        agent,
+        round_to.get(agent),
+        BUILTIN_STRING_MEMORY.smallestUnit,
+        true,
+        gc,
+    )?;
+    Ok(RoundToOptions {
+        rounding_increment,
+        rounding_mode,
+        smallest_unit,
+    })
+}
+
+/// Match a string against the allowed values of
+/// GetTemporalUnitValuedOption.
+pub(crate) fn parse_unit_string(agent: &Agent, string: String) -> Option<UnitValue> {
+    let string = string.as_wtf8(agent).as_str()?;
+    if string == "auto" {
+        return Some(UnitValue::Auto);
+    }
+    Unit::ALL
+        .into_iter()
+        .find(|unit| unit.singular() == string || unit.plural() == string)
+        .map(UnitValue::Unit)
+}
+
+/// ### [13.35 ToIntegerWithTruncation ( argument )](https://tc39.es/proposal-temporal/#sec-tointegerwithtruncation)
+pub(crate) fn to_integer_with_truncation<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = to_number(agent, argument, gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 2. If number is NaN, +∞𝔽 or -∞𝔽, throw a RangeError exception.
+    if !number.is_finite() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "value must be a finite number",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Return truncate(ℝ(number)).
+    Ok(number.trunc() + 0.0)
+}
+
+/// ### [13.36 ToPositiveIntegerWithTruncation ( argument )](https://tc39.es/proposal-temporal/#sec-topositiveintegerwithtruncation)
+pub(crate) fn to_positive_integer_with_truncation<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, f64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(agent, argument, gc.reborrow()).unbind()?;
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0.0 {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "value must be a positive integer",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// ### [13.37 ToIntegerIfIntegral ( argument )](https://tc39.es/proposal-temporal/#sec-tointegerifintegral)
+pub(crate) fn to_integer_if_integral<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = to_number(agent, argument, gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 2. If number is not an integral Number, throw a RangeError exception.
+    if !number.is_finite() || number.trunc() != number {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "value must be an integer",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Return ℝ(number).
+    Ok(number + 0.0)
+}
+
+/// ### [13.40 IsPartialTemporalObject ( value )](https://tc39.es/proposal-temporal/#sec-temporal-ispartialtemporalobject)
+///
+/// Returns the value as an Object if it is a partial Temporal object.
+pub(crate) fn is_partial_temporal_object<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Object<'gc>>> {
+    let value = value.bind(gc.nogc());
+    // 1. If value is not an Object, return false.
+    let Ok(value) = Object::try_from(value) else {
+        return Ok(None);
+    };
+    // 2. If value has an [[InitializedTemporalDate]],
+    //    [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //    [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]], or
+    //    [[InitializedTemporalZonedDateTime]] internal slot, return false.
+    if matches!(
+        value,
+        Object::PlainDate(_)
+            | Object::PlainDateTime(_)
+            | Object::PlainTime(_)
+            | Object::ZonedDateTime(_)
+    ) {
+        return Ok(None);
+    }
+    let value = value.scope(agent, gc.nogc());
+    // 3. Let calendarProperty be ? Get(value, "calendar").
+    let calendar_property = get(
+        agent,
+        value.get(agent),
+        BUILTIN_STRING_MEMORY.calendar.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 4. If calendarProperty is not undefined, return false.
+    if !calendar_property.is_undefined() {
+        return Ok(None);
+    }
+    // 5. Let timeZoneProperty be ? Get(value, "timeZone").
+    let time_zone_property = get(
+        agent,
+        value.get(agent),
+        BUILTIN_STRING_MEMORY.timeZone.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 6. If timeZoneProperty is not undefined, return false.
+    if !time_zone_property.is_undefined() {
+        return Ok(None);
+    }
+    // 7. Return true.
+    Ok(Some(value.get(agent).bind(gc.into_nogc())))
+}
+
+/// ### [13.22 Unsigned rounding modes](https://tc39.es/proposal-temporal/#table-temporal-unsigned-rounding-modes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnsignedRoundingMode {
+    Infinity,
+    Zero,
+    HalfInfinity,
+    HalfZero,
+    HalfEven,
+}
+
+/// ### [13.23 GetUnsignedRoundingMode ( roundingMode, sign )](https://tc39.es/proposal-temporal/#sec-getunsignedroundingmode)
+///
+/// The sign is negative if is_negative is true, and positive otherwise.
+pub(crate) fn get_unsigned_rounding_mode(
+    rounding_mode: RoundingMode,
+    is_negative: bool,
+) -> UnsignedRoundingMode {
+    // 1. Return the specification type in the "Unsigned Rounding Mode"
+    //    column of Table 23 for the row where the value in the "Rounding
+    //    Mode" column is roundingMode and the value in the "Sign" column is
+    //    sign.
+    match (rounding_mode, is_negative) {
+        (RoundingMode::Ceil, false) | (RoundingMode::Floor, true) => UnsignedRoundingMode::Infinity,
+        (RoundingMode::Ceil, true) | (RoundingMode::Floor, false) => UnsignedRoundingMode::Zero,
+        (RoundingMode::Expand, _) => UnsignedRoundingMode::Infinity,
+        (RoundingMode::Trunc, _) => UnsignedRoundingMode::Zero,
+        (RoundingMode::HalfCeil, false) | (RoundingMode::HalfFloor, true) => {
+            UnsignedRoundingMode::HalfInfinity
+        }
+        (RoundingMode::HalfCeil, true) | (RoundingMode::HalfFloor, false) => {
+            UnsignedRoundingMode::HalfZero
+        }
+        (RoundingMode::HalfExpand, _) => UnsignedRoundingMode::HalfInfinity,
+        (RoundingMode::HalfTrunc, _) => UnsignedRoundingMode::HalfZero,
+        (RoundingMode::HalfEven, _) => UnsignedRoundingMode::HalfEven,
+    }
+}
+
+/// ### [13.24 ApplyUnsignedRoundingMode ( x, r1, r2, unsignedRoundingMode )](https://tc39.es/proposal-temporal/#sec-applyunsignedroundingmode)
+///
+/// Takes the quotient r1 of a division and the remainder and divisor of the
+/// division, such that x = r1 + remainder / divisor with 0 ≤ remainder <
+/// divisor, and returns true if x rounds to r2 = r1 + 1 rather than r1.
+pub(crate) fn apply_unsigned_rounding_mode(
+    r1: i128,
+    remainder: i128,
+    divisor: i128,
+    unsigned_rounding_mode: UnsignedRoundingMode,
+) -> bool {
+    debug_assert!(0 <= remainder && remainder < divisor);
+    // 1. If x = r1, return r1.
+    if remainder == 0 {
+        return false;
+    }
+    // 2. Assert: r1 < x < r2.
+    // 3. Assert: unsignedRoundingMode is not undefined.
+    match unsigned_rounding_mode {
+        // 4. If unsignedRoundingMode is zero, return r1.
+        UnsignedRoundingMode::Zero => false,
+        // 5. If unsignedRoundingMode is infinity, return r2.
+        UnsignedRoundingMode::Infinity => true,
+        _ => {
+            // 6. Let d1 be x - r1.
+            // 7. Let d2 be r2 - x.
+            let d1 = remainder;
+            let d2 = divisor - remainder;
+            match d1.cmp(&d2) {
+                // 8. If d1 < d2, return r1.
+                core::cmp::Ordering::Less => false,
+                // 9. If d2 < d1, return r2.
+                core::cmp::Ordering::Greater => true,
+                // 10. Assert: d1 is equal to d2.
+                core::cmp::Ordering::Equal => match unsigned_rounding_mode {
+                    // 11. If unsignedRoundingMode is half-zero, return r1.
+                    UnsignedRoundingMode::HalfZero => false,
+                    // 12. If unsignedRoundingMode is half-infinity, return r2.
+                    UnsignedRoundingMode::HalfInfinity => true,
+                    // 13. Assert: unsignedRoundingMode is half-even.
+                    // 14. Let cardinality be (r1 / (r2 - r1)) modulo 2.
+                    // 15. If cardinality = 0, return r1.
+                    // 16. Return r2.
+                    _ => r1.rem_euclid(2) != 0,
+                },
+            }
+        }
+    }
+}
+
+/// ### [13.25 RoundNumberToIncrement ( x, increment, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrement)
+pub(crate) fn round_number_to_increment(
+    x: i128,
+    increment: i128,
+    rounding_mode: RoundingMode,
+) -> i128 {
+    // 1. Let quotient be x / increment.
+    // 2. If quotient < 0, then
+    //    a. Let isNegative be negative.
+    //    b. Set quotient to -quotient.
+    // 3. Else,
+    //    a. Let isNegative be positive.
+    let is_negative = x < 0;
+    let magnitude = x.abs();
+    // 4. Let unsignedRoundingMode be GetUnsignedRoundingMode(roundingMode,
+    //    isNegative).
+    let unsigned_rounding_mode = get_unsigned_rounding_mode(rounding_mode, is_negative);
+    // 5. Let r1 be the largest integer such that r1 ≤ quotient.
+    // 6. Let r2 be the smallest integer such that r2 > quotient.
+    let r1 = magnitude / increment;
+    let remainder = magnitude % increment;
+    // 7. Let rounded be ApplyUnsignedRoundingMode(quotient, r1, r2,
+    //    unsignedRoundingMode).
+    let rounded = if apply_unsigned_rounding_mode(r1, remainder, increment, unsigned_rounding_mode)
+    {
+        r1 + 1
+    } else {
+        r1
+    };
+    // 8. If isNegative is negative, set rounded to -rounded.
+    let rounded = if is_negative { -rounded } else { rounded };
+    // 9. Return rounded × increment.
+    rounded * increment
+}
+
+/// ### [13.26 RoundNumberToIncrementAsIfPositive ( x, increment, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrementasifpositive)
+pub(crate) fn round_number_to_increment_as_if_positive(
+    x: i128,
+    increment: i128,
+    rounding_mode: RoundingMode,
+) -> i128 {
+    // 1. Let quotient be x / increment.
+    // 2. Let unsignedRoundingMode be GetUnsignedRoundingMode(roundingMode,
+    //    positive).
+    let unsigned_rounding_mode = get_unsigned_rounding_mode(rounding_mode, false);
+    // 3. Let r1 be the largest integer such that r1 ≤ quotient.
+    // 4. Let r2 be the smallest integer such that r2 > quotient.
+    let r1 = x.div_euclid(increment);
+    let remainder = x.rem_euclid(increment);
+    // 5. Let rounded be ApplyUnsignedRoundingMode(quotient, r1, r2,
+    //    unsignedRoundingMode).
+    let rounded = if apply_unsigned_rounding_mode(r1, remainder, increment, unsigned_rounding_mode)
+    {
+        r1 + 1
+    } else {
+        r1
+    };
+    // 6. Return rounded × increment.
+    rounded * increment
+}
+
+/// ### [13.27 FormatFractionalSeconds ( subSecondNanoseconds, precision )](https://tc39.es/proposal-temporal/#sec-temporal-formatfractionalseconds)
+pub(crate) fn format_fractional_seconds(
+    sub_second_nanoseconds: u32,
+    precision: Precision,
+) -> std::string::String {
+    // 1. If precision is auto, then
+    let fraction_string = match precision {
+        Precision::Auto => {
+            // a. If subSecondNanoseconds = 0, return the empty String.
+            if sub_second_nanoseconds == 0 {
+                return std::string::String::new();
+            }
+            // b. Let fractionString be ToZeroPaddedDecimalString(
+            //    subSecondNanoseconds, 9).
+            // c. Set fractionString to the longest prefix of fractionString
+            //    ending with a code unit other than 0x0030 (DIGIT ZERO).
+            format!("{sub_second_nanoseconds:09}")
+                .trim_end_matches('0')
+                .to_string()
+        }
+        // 2. Else,
+        Precision::Digits(precision) => {
+            // a. If precision = 0, return the empty String.
+            if precision == 0 {
+                return std::string::String::new();
+            }
+            // b. Let fractionString be ToZeroPaddedDecimalString(
+            //    subSecondNanoseconds, 9).
+            // c. Set fractionString to the substring of fractionString from
+            //    0 to precision.
+            let mut fraction_string = format!("{sub_second_nanoseconds:09}");
+            fraction_string.truncate(usize::from(precision));
+            fraction_string
+        }
+        Precision::Minute => unreachable!(),
+    };
+    // 3. Return the string-concatenation of the code unit 0x002E (FULL STOP)
+    //    and fractionString.
+    format!(".{fraction_string}")
+}
+
+/// ### [13.28 FormatTimeString ( hour, minute, second, subSecondNanoseconds, precision \[ , style \] )](https://tc39.es/proposal-temporal/#sec-temporal-formattimestring)
+///
+/// The style is separated if separated is true, and unseparated otherwise.
+pub(crate) fn format_time_string(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    sub_second_nanoseconds: u32,
+    precision: Precision,
+    separated: bool,
+) -> std::string::String {
+    // 1. If style is present and style is unseparated, let separator be the
+    //    empty String; otherwise, let separator be ":".
+    let separator = if separated { ":" } else { "" };
+    // 2. Let hh be ToZeroPaddedDecimalString(hour, 2).
+    // 3. Let mm be ToZeroPaddedDecimalString(minute, 2).
+    // 4. If precision is minute, return the string-concatenation of hh,
+    //    separator, and mm.
+    if precision == Precision::Minute {
+        return format!("{hour:02}{separator}{minute:02}");
+    }
+    // 5. Let ss be ToZeroPaddedDecimalString(second, 2).
+    // 6. Let subSecondsPart be FormatFractionalSeconds(subSecondNanoseconds,
+    //    precision).
+    let sub_seconds_part = format_fractional_seconds(sub_second_nanoseconds, precision);
+    // 7. Return the string-concatenation of hh, separator, mm, separator, ss,
+    //    and subSecondsPart.
+    format!("{hour:02}{separator}{minute:02}{separator}{second:02}{sub_seconds_part}")
+}
+
+/// ### [13.29 FormatOffsetTimeZoneIdentifier ( offsetMinutes \[ , style \] )](https://tc39.es/proposal-temporal/#sec-temporal-formatoffsettimezoneidentifier)
+pub(crate) fn format_offset_time_zone_identifier(
+    offset_minutes: i32,
+    separated: bool,
+) -> std::string::String {
+    // 1. If offsetMinutes ≥ 0, let sign be the code unit 0x002B (PLUS SIGN);
+    //    otherwise, let sign be the code unit 0x002D (HYPHEN-MINUS).
+    let sign = if offset_minutes >= 0 { '+' } else { '-' };
+    // 2. Let absoluteMinutes be abs(offsetMinutes).
+    let absolute_minutes = offset_minutes.unsigned_abs();
+    // 3. Let hour be floor(absoluteMinutes / 60).
+    // 4. Let minute be absoluteMinutes modulo 60.
+    // 5. Let timeString be FormatTimeString(hour, minute, 0, 0, minute,
+    //    style).
+    let time_string = format_time_string(
+        (absolute_minutes / 60) as u8,
+        (absolute_minutes % 60) as u8,
+        0,
+        0,
+        Precision::Minute,
+        separated,
+    );
+    // 6. Return the string-concatenation of sign and timeString.
+    format!("{sign}{time_string}")
+}
+
+/// ### [13.30 FormatUTCOffsetNanoseconds ( offsetNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-formatutcoffsetnanoseconds)
+pub(crate) fn format_utc_offset_nanoseconds(offset_nanoseconds: i64) -> std::string::String {
+    // 1. If offsetNanoseconds ≥ 0, let sign be the code unit 0x002B (PLUS
+    //    SIGN); otherwise, let sign be the code unit 0x002D (HYPHEN-MINUS).
+    let sign = if offset_nanoseconds >= 0 { '+' } else { '-' };
+    // 2. Let absoluteNanoseconds be abs(offsetNanoseconds).
+    let absolute_nanoseconds = offset_nanoseconds.unsigned_abs();
+    // 3. Let hour be floor(absoluteNanoseconds / (3600 × 10**9)).
+    let hour = absolute_nanoseconds / 3_600_000_000_000;
+    // 4. Let minute be floor(absoluteNanoseconds / (60 × 10**9)) modulo 60.
+    let minute = (absolute_nanoseconds / 60_000_000_000) % 60;
+    // 5. Let second be floor(absoluteNanoseconds / 10**9) modulo 60.
+    let second = (absolute_nanoseconds / 1_000_000_000) % 60;
+    // 6. Let subSecondNanoseconds be absoluteNanoseconds modulo 10**9.
+    let sub_second_nanoseconds = absolute_nanoseconds % 1_000_000_000;
+    // 7. If second = 0 and subSecondNanoseconds = 0, let precision be minute;
+    //    otherwise, let precision be auto.
+    let precision = if second == 0 && sub_second_nanoseconds == 0 {
+        Precision::Minute
+    } else {
+        Precision::Auto
+    };
+    // 8. Let timeString be FormatTimeString(hour, minute, second,
+    //    subSecondNanoseconds, precision).
+    let time_string = format_time_string(
+        hour as u8,
+        minute as u8,
+        second as u8,
+        sub_second_nanoseconds as u32,
+        precision,
+        true,
+    );
+    // 9. Return the string-concatenation of sign and timeString.
+    format!("{sign}{time_string}")
+}
+
+/// ### [13.31 FormatDateTimeUTCOffsetRounded ( offsetNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-formatdatetimeutcoffsetrounded)
+pub(crate) fn format_date_time_utc_offset_rounded(offset_nanoseconds: i64) -> std::string::String {
+    // 1. Set offsetNanoseconds to RoundNumberToIncrement(offsetNanoseconds,
+    //    60 × 10**9, half-expand).
+    let offset_nanoseconds = round_number_to_increment(
+        i128::from(offset_nanoseconds),
+        60_000_000_000,
+        RoundingMode::HalfExpand,
+    );
+    // 2. Let offsetMinutes be offsetNanoseconds / (60 × 10**9).
+    let offset_minutes = (offset_nanoseconds / 60_000_000_000) as i32;
+    // 3. Return FormatOffsetTimeZoneIdentifier(offsetMinutes).
+    format_offset_time_zone_identifier(offset_minutes, true)
+}
+
+/// ### [13.32 PadISOYear ( y )](https://tc39.es/proposal-temporal/#sec-temporal-padisoyear)
+pub(crate) fn pad_iso_year(y: i32) -> std::string::String {
+    // 1. If y ≥ 0 and y ≤ 9999, then
+    if (0..=9999).contains(&y) {
+        // a. Return ToZeroPaddedDecimalString(y, 4).
+        return format!("{y:04}");
+    }
+    // 2. If y > 0, let yearSign be "+"; otherwise, let yearSign be "-".
+    let year_sign = if y > 0 { '+' } else { '-' };
+    // 3. Let year be ToZeroPaddedDecimalString(abs(y), 6).
+    // 4. Return the string-concatenation of yearSign and year.
+    format!("{year_sign}{:06}", y.unsigned_abs())
+}
+
+/// ### [12.2.3 FormatCalendarAnnotation ( id, showCalendar )](https://tc39.es/proposal-temporal/#sec-temporal-formatcalendarannotation)
+pub(crate) fn format_calendar_annotation(
+    id: &str,
+    show_calendar: ShowCalendar,
+) -> std::string::String {
+    // 1. If showCalendar is never, return the empty String.
+    // 2. If showCalendar is auto and id is "iso8601", return the empty
+    //    String.
+    if show_calendar == ShowCalendar::Never
+        || (show_calendar == ShowCalendar::Auto && id == "iso8601")
+    {
+        return std::string::String::new();
+    }
+    // 3. If showCalendar is critical, let flag be "!"; otherwise, let flag
+    //    be the empty String.
+    let flag = if show_calendar == ShowCalendar::Critical {
+        "!"
+    } else {
+        ""
+    };
+    // 4. Return the string-concatenation of "[", flag, "u-ca=", id, and "]".
+    format!("[{flag}u-ca={id}]")
+}
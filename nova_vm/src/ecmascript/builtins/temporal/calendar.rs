@@ -0,0 +1,1454 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [12 Calendars](https://tc39.es/proposal-temporal/#sec-temporal-calendars)
+//!
+//! The ISO 8601 calendar: ISO Date Records, Time Records and ISO Date-Time
+//! Records, and the arithmetic on them. Only the `iso8601` calendar is
+//! supported.
+
+use core::cmp::Ordering;
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::get,
+            type_conversion::{PreferredType, to_primitive},
+        },
+        builtins::{
+            numbers_and_dates::date_objects::date_prototype::{
+                date_from_time, days_in_year, get_utc_epoch_nanoseconds, make_day, month_from_time,
+                week_day, year_from_time,
+            },
+            temporal::{
+                abstract_operations::{
+                    NS_MAX_INSTANT, NS_MIN_INSTANT, NS_PER_DAY, Overflow, RangeError, RoundingMode,
+                    TemporalResult, Unit, round_number_to_increment, to_integer_with_truncation,
+                    to_positive_integer_with_truncation,
+                },
+                parsing::{parse_date_time_utc_offset, parse_temporal_calendar_string},
+                time_zone::{TimeZone, to_temporal_time_zone_identifier},
+            },
+        },
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        types::{BUILTIN_STRING_MEMORY, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+    },
+};
+
+/// The identifier of the ISO 8601 calendar.
+pub(crate) const ISO8601: &str = "iso8601";
+
+/// Milliseconds per day, for converting epoch days to time values.
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// ### [3.5.1 ISO Date Records](https://tc39.es/proposal-temporal/#sec-temporal-iso-date-records)
+///
+/// The fields are ordered so that comparing two records compares the dates
+/// as CompareISODate does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct IsoDate {
+    /// \[\[Year]]
+    pub(crate) year: i32,
+    /// \[\[Month]]
+    pub(crate) month: u8,
+    /// \[\[Day]]
+    pub(crate) day: u8,
+}
+trivially_bindable!(IsoDate);
+
+impl IsoDate {
+    /// ### [3.5.2 CreateISODateRecord ( year, month, day )](https://tc39.es/proposal-temporal/#sec-temporal-create-iso-date-record)
+    pub(crate) fn new(year: i32, month: u8, day: u8) -> Self {
+        // 1. Assert: IsValidISODate(year, month, day) is true.
+        debug_assert!(is_valid_iso_date(year.into(), month.into(), day.into()));
+        // 2. Return ISO Date Record { [[Year]]: year, [[Month]]: month,
+        //    [[Day]]: day }.
+        Self { year, month, day }
+    }
+
+    /// The number of days since the epoch of the date.
+    pub(crate) fn to_epoch_days(self) -> i64 {
+        make_day(
+            f64::from(self.year),
+            f64::from(self.month) - 1.0,
+            f64::from(self.day),
+        ) as i64
+    }
+
+    /// The date of a number of days since the epoch.
+    pub(crate) fn from_epoch_days(epoch_days: i64) -> Self {
+        let t = epoch_days as f64 * MS_PER_DAY;
+        Self {
+            year: year_from_time(t),
+            month: month_from_time(t) + 1,
+            day: date_from_time(t),
+        }
+    }
+
+    /// The "monthCode" of the date.
+    pub(crate) fn month_code(self) -> std::string::String {
+        format!("M{:02}", self.month)
+    }
+
+    /// ### [12.3.18 ISODayOfWeek ( isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-isodayofweek)
+    ///
+    /// Returns the day of the week from 1 (Monday) to 7 (Sunday).
+    pub(crate) fn day_of_week(self) -> u8 {
+        // WeekDay gives 0 for Sunday.
+        let week_day = week_day(self.to_epoch_days() as f64 * MS_PER_DAY);
+        if week_day == 0 { 7 } else { week_day }
+    }
+
+    /// ### [12.3.17 ISODayOfYear ( isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-isodayofyear)
+    pub(crate) fn day_of_year(self) -> u16 {
+        // 1. Let epochDays be ISODateToEpochDays(isoDate.[[Year]],
+        //    isoDate.[[Month]] - 1, isoDate.[[Day]]).
+        // 2. Let yearStart be ISODateToEpochDays(isoDate.[[Year]], 0, 1).
+        // 3. Return epochDays - yearStart + 1.
+        let year_start = IsoDate {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        (self.to_epoch_days() - year_start.to_epoch_days() + 1) as u16
+    }
+
+    /// ### [12.3.16 ISOWeekOfYear ( isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-isoweekofyear)
+    ///
+    /// Returns the week number and the week-numbering year of the date.
+    pub(crate) fn week_of_year(self) -> (u8, i32) {
+        // 1. Let year be isoDate.[[Year]].
+        let year = self.year;
+        // 2-5. Let wednesday be 3, thursday be 4, friday be 5 and sunday be 7.
+        // 6. Let maxWeekNumber be 53.
+        // 7. Let dayOfYear be ISODayOfYear(isoDate).
+        let day_of_year = i32::from(self.day_of_year());
+        // 8. Let dayOfWeek be ISODayOfWeek(isoDate).
+        let day_of_week = i32::from(self.day_of_week());
+        // 9. Let week be floor((dayOfYear + daysInWeek - dayOfWeek +
+        //    wednesday) / daysInWeek).
+        let week = (day_of_year + 7 - day_of_week + 3).div_euclid(7);
+        // 10. If week < 1, then
+        if week < 1 {
+            // a. NOTE: This is the last week of the previous year.
+            // b. Let jan1st be CreateISODateRecord(year, 1, 1).
+            // c. Let dayOfJan1st be ISODayOfWeek(jan1st).
+            let day_of_jan_1st = i32::from(IsoDate::new(year, 1, 1).day_of_week());
+            // d. If dayOfJan1st = friday, then
+            //    i. Return Year-Week Record { [[Week]]: maxWeekNumber,
+            //       [[Year]]: year - 1 }.
+            if day_of_jan_1st == 5 {
+                return (53, year - 1);
+            }
+            // e. If dayOfJan1st = saturday, and MathematicalInLeapYear(
+            //    EpochTimeForYear(year - 1)) = 1, then
+            //    i. Return Year-Week Record { [[Week]]: maxWeekNumber,
+            //       [[Year]]: year - 1 }.
+            if day_of_jan_1st == 6 && days_in_year(year - 1) == 366 {
+                return (53, year - 1);
+            }
+            // f. Return Year-Week Record { [[Week]]: maxWeekNumber - 1,
+            //    [[Year]]: year - 1 }.
+            return (52, year - 1);
+        }
+        // 11. If week = maxWeekNumber, then
+        if week == 53 {
+            // a. Let daysInYear be MathematicalDaysInYear(year).
+            let days_in_year = i32::from(days_in_year(year));
+            // b. Let daysLaterInYear be daysInYear - dayOfYear.
+            let days_later_in_year = days_in_year - day_of_year;
+            // c. Let daysAfterThursday be thursday - dayOfWeek.
+            let days_after_thursday = 4 - day_of_week;
+            // d. If daysLaterInYear < daysAfterThursday, then
+            if days_later_in_year < days_after_thursday {
+                // i. Return Year-Week Record { [[Week]]: 1, [[Year]]: year + 1 }.
+                return (1, year + 1);
+            }
+        }
+        // 12. Return Year-Week Record { [[Week]]: week, [[Year]]: year }.
+        (week as u8, year)
+    }
+}
+
+/// The fields of a Calendar Date Record, as read by the getters of the
+/// Temporal types with a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CalendarDateField {
+    Era,
+    EraYear,
+    Year,
+    Month,
+    MonthCode,
+    Day,
+    DayOfWeek,
+    DayOfYear,
+    WeekOfYear,
+    YearOfWeek,
+    DaysInWeek,
+    DaysInMonth,
+    DaysInYear,
+    MonthsInYear,
+    InLeapYear,
+}
+
+impl CalendarDateField {
+    /// All fields, in the order of the variants.
+    const ALL: [CalendarDateField; 15] = [
+        CalendarDateField::Era,
+        CalendarDateField::EraYear,
+        CalendarDateField::Year,
+        CalendarDateField::Month,
+        CalendarDateField::MonthCode,
+        CalendarDateField::Day,
+        CalendarDateField::DayOfWeek,
+        CalendarDateField::DayOfYear,
+        CalendarDateField::WeekOfYear,
+        CalendarDateField::YearOfWeek,
+        CalendarDateField::DaysInWeek,
+        CalendarDateField::DaysInMonth,
+        CalendarDateField::DaysInYear,
+        CalendarDateField::MonthsInYear,
+        CalendarDateField::InLeapYear,
+    ];
+}
+
+/// ### [12.3.20 CalendarISOToDate ( calendar, isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-calendarisotodate)
+///
+/// Returns one field of the Calendar Date Record of the date, given as the
+/// index of the field in the variants of [`CalendarDateField`].
+pub(crate) fn calendar_iso_to_date_field<'gc>(
+    agent: &mut Agent,
+    iso_date: IsoDate,
+    field: usize,
+    gc: NoGcScope<'gc, '_>,
+) -> Value<'gc> {
+    // 1. If calendar is "iso8601", then
+    match CalendarDateField::ALL[field] {
+        // a. Let monthNumberPart be ToZeroPaddedDecimalString(
+        //    isoDate.[[Month]], 2).
+        // b. Let monthCode be the string-concatenation of "M" and
+        //    monthNumberPart.
+        // c. If MathematicalInLeapYear(EpochTimeForYear(isoDate.[[Year]])) =
+        //    1, let inLeapYear be true; else let inLeapYear be false.
+        // d. Return Calendar Date Record { [[Era]]: undefined,
+        //    [[EraYear]]: undefined, [[Year]]: isoDate.[[Year]],
+        //    [[Month]]: isoDate.[[Month]], [[MonthCode]]: monthCode,
+        //    [[Day]]: isoDate.[[Day]], [[DayOfWeek]]: ISODayOfWeek(isoDate),
+        //    [[DayOfYear]]: ISODayOfYear(isoDate),
+        //    [[WeekOfYear]]: ISOWeekOfYear(isoDate), [[DaysInWeek]]: 7,
+        //    [[DaysInMonth]]: ISODaysInMonth(isoDate.[[Year]],
+        //    isoDate.[[Month]]), [[DaysInYear]]: MathematicalDaysInYear(
+        //    isoDate.[[Year]]), [[MonthsInYear]]: 12,
+        //    [[InLeapYear]]: inLeapYear }.
+        CalendarDateField::Era | CalendarDateField::EraYear => Value::Undefined,
+        CalendarDateField::Year => iso_date.year.into(),
+        CalendarDateField::Month => iso_date.month.into(),
+        CalendarDateField::MonthCode => Value::from_string(agent, iso_date.month_code(), gc),
+        CalendarDateField::Day => iso_date.day.into(),
+        CalendarDateField::DayOfWeek => iso_date.day_of_week().into(),
+        CalendarDateField::DayOfYear => iso_date.day_of_year().into(),
+        CalendarDateField::WeekOfYear => iso_date.week_of_year().0.into(),
+        CalendarDateField::YearOfWeek => iso_date.week_of_year().1.into(),
+        CalendarDateField::DaysInWeek => 7u8.into(),
+        CalendarDateField::DaysInMonth => iso_days_in_month(iso_date.year, iso_date.month).into(),
+        CalendarDateField::DaysInYear => days_in_year(iso_date.year).into(),
+        CalendarDateField::MonthsInYear => 12u8.into(),
+        CalendarDateField::InLeapYear => (days_in_year(iso_date.year) == 366).into(),
+    }
+}
+
+/// ### [4.5.1 Time Records](https://tc39.es/proposal-temporal/#sec-temporal-time-records)
+///
+/// The \[\[Days]] field of the specification is returned separately by the
+/// operations that balance times. The fields are ordered so that comparing
+/// two records compares the times as CompareTimeRecord does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct TimeRecord {
+    /// \[\[Hour]]
+    pub(crate) hour: u8,
+    /// \[\[Minute]]
+    pub(crate) minute: u8,
+    /// \[\[Second]]
+    pub(crate) second: u8,
+    /// \[\[Millisecond]]
+    pub(crate) millisecond: u16,
+    /// \[\[Microsecond]]
+    pub(crate) microsecond: u16,
+    /// \[\[Nanosecond]]
+    pub(crate) nanosecond: u16,
+}
+trivially_bindable!(TimeRecord);
+
+impl TimeRecord {
+    /// ### [4.5.3 MidnightTimeRecord ( )](https://tc39.es/proposal-temporal/#sec-temporal-midnighttimerecord)
+    pub(crate) const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        microsecond: 0,
+        nanosecond: 0,
+    };
+
+    /// ### [4.5.4 NoonTimeRecord ( )](https://tc39.es/proposal-temporal/#sec-temporal-noontimerecord)
+    pub(crate) const NOON: Self = Self {
+        hour: 12,
+        ..Self::MIDNIGHT
+    };
+
+    /// The number of nanoseconds since midnight of the time.
+    pub(crate) fn to_nanoseconds(self) -> i128 {
+        ((((i128::from(self.hour) * 60 + i128::from(self.minute)) * 60 + i128::from(self.second))
+            * 1000
+            + i128::from(self.millisecond))
+            * 1000
+            + i128::from(self.microsecond))
+            * 1000
+            + i128::from(self.nanosecond)
+    }
+
+    /// The nanoseconds within the second of the time.
+    pub(crate) fn sub_second_nanoseconds(self) -> u32 {
+        u32::from(self.millisecond) * 1_000_000
+            + u32::from(self.microsecond) * 1_000
+            + u32::from(self.nanosecond)
+    }
+
+    /// The field of the time for a time unit, given as the index of the unit
+    /// in [`Unit::ALL`].
+    pub(crate) fn field(self, unit: usize) -> u16 {
+        match Unit::ALL[unit] {
+            Unit::Hour => self.hour.into(),
+            Unit::Minute => self.minute.into(),
+            Unit::Second => self.second.into(),
+            Unit::Millisecond => self.millisecond,
+            Unit::Microsecond => self.microsecond,
+            Unit::Nanosecond => self.nanosecond,
+            _ => unreachable!("not a time unit"),
+        }
+    }
+}
+
+/// ### [5.5.1 ISO Date-Time Records](https://tc39.es/proposal-temporal/#sec-temporal-iso-date-time-records)
+///
+/// The fields are ordered so that comparing two records compares the date-
+/// times as CompareISODateTime does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct IsoDateTime {
+    /// \[\[ISODate]]
+    pub(crate) date: IsoDate,
+    /// \[\[Time]]
+    pub(crate) time: TimeRecord,
+}
+trivially_bindable!(IsoDateTime);
+
+impl IsoDateTime {
+    /// ### [5.5.3 CombineISODateAndTimeRecord ( isoDate, time )](https://tc39.es/proposal-temporal/#sec-temporal-combineisodateandtimerecord)
+    pub(crate) fn new(date: IsoDate, time: TimeRecord) -> Self {
+        // 1. NOTE: time.[[Days]] is ignored.
+        // 2. Return ISO Date-Time Record { [[ISODate]]: isoDate, [[Time]]:
+        //    time }.
+        Self { date, time }
+    }
+
+    /// ### [GetUTCEpochNanoseconds ( isoDateTime )](https://tc39.es/proposal-temporal/#sec-getutcepochnanoseconds)
+    pub(crate) fn to_epoch_nanoseconds(self) -> i128 {
+        let Self { date, time } = self;
+        get_utc_epoch_nanoseconds(
+            date.year,
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second,
+            time.millisecond,
+            time.microsecond,
+            time.nanosecond,
+        )
+    }
+
+    /// The wall-clock date and time in UTC of a number of nanoseconds since
+    /// the epoch.
+    pub(crate) fn from_epoch_nanoseconds(epoch_nanoseconds: i128) -> Self {
+        let epoch_days = epoch_nanoseconds.div_euclid(NS_PER_DAY) as i64;
+        let (_, time) = balance_time(epoch_nanoseconds.rem_euclid(NS_PER_DAY));
+        Self {
+            date: IsoDate::from_epoch_days(epoch_days),
+            time,
+        }
+    }
+}
+
+/// ### [12.3.19 ISODaysInMonth ( year, month )](https://tc39.es/proposal-temporal/#sec-temporal-isodaysinmonth)
+pub(crate) fn iso_days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        // 1. If month is 1, 3, 5, 7, 8, 10, or 12, return 31.
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        // 2. If month is 4, 6, 9, or 11, return 30.
+        4 | 6 | 9 | 11 => 30,
+        // 3. Assert: month is 2.
+        // 4. Return 28 + MathematicalInLeapYear(EpochTimeForYear(year)).
+        _ => {
+            debug_assert_eq!(month, 2);
+            if days_in_year(year) == 366 { 29 } else { 28 }
+        }
+    }
+}
+
+/// ### [3.5.7 IsValidISODate ( year, month, day )](https://tc39.es/proposal-temporal/#sec-temporal-isvalidisodate)
+pub(crate) fn is_valid_iso_date(year: f64, month: f64, day: f64) -> bool {
+    // 1. If month < 1 or month > 12, then
+    if !(1.0..=12.0).contains(&month) {
+        // a. Return false.
+        return false;
+    }
+    if year.abs() > f64::from(i32::MAX) {
+        // Note: Such a date is not within the limits of any Temporal object.
+        return false;
+    }
+    // 2. Let daysInMonth be ISODaysInMonth(year, month).
+    let days_in_month = iso_days_in_month(year as i32, month as u8);
+    // 3. If day < 1 or day > daysInMonth, then
+    //    a. Return false.
+    // 4. Return true.
+    (1.0..=f64::from(days_in_month)).contains(&day)
+}
+
+/// ### [3.5.6 RegulateISODate ( year, month, day, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-regulateisodate)
+pub(crate) fn regulate_iso_date(
+    year: f64,
+    month: f64,
+    day: f64,
+    overflow: Overflow,
+) -> TemporalResult<IsoDate> {
+    if year.abs() > f64::from(i32::MAX) {
+        // Note: Such a date is not within the limits of any Temporal object.
+        return Err(RangeError("date is out of range"));
+    }
+    let year = year as i32;
+    match overflow {
+        // 1. If overflow is constrain, then
+        Overflow::Constrain => {
+            // a. Set month to the result of clamping month between 1 and 12.
+            let month = month.clamp(1.0, 12.0) as u8;
+            // b. Let daysInMonth be ISODaysInMonth(year, month).
+            let days_in_month = iso_days_in_month(year, month);
+            // c. Set day to the result of clamping day between 1 and
+            //    daysInMonth.
+            let day = day.clamp(1.0, f64::from(days_in_month)) as u8;
+            // 3. Return CreateISODateRecord(year, month, day).
+            Ok(IsoDate::new(year, month, day))
+        }
+        // 2. Else,
+        //    a. Assert: overflow is reject.
+        Overflow::Reject => {
+            // b. If IsValidISODate(year, month, day) is false, throw a
+            //    RangeError exception.
+            if !is_valid_iso_date(f64::from(year), month, day) {
+                return Err(RangeError("invalid ISO date"));
+            }
+            // 3. Return CreateISODateRecord(year, month, day).
+            Ok(IsoDate::new(year, month as u8, day as u8))
+        }
+    }
+}
+
+/// ### [12.3.20 BalanceISOYearMonth ( year, month )](https://tc39.es/proposal-temporal/#sec-temporal-balanceisoyearmonth)
+pub(crate) fn balance_iso_year_month(year: i64, month: i64) -> (i64, u8) {
+    // 1. Set year to year + floor((month - 1) / 12).
+    let year = year + (month - 1).div_euclid(12);
+    // 2. Set month to ((month - 1) modulo 12) + 1.
+    let month = (month - 1).rem_euclid(12) + 1;
+    // 3. Return Year-Month Record { [[Year]]: year, [[Month]]: month }.
+    (year, month as u8)
+}
+
+/// ### [3.5.8 BalanceISODate ( year, month, day )](https://tc39.es/proposal-temporal/#sec-temporal-balanceisodate)
+pub(crate) fn balance_iso_date(year: i64, month: u8, day: i64) -> IsoDate {
+    // 1. Let epochDays be ISODateToEpochDays(year, month - 1, day).
+    let epoch_days = make_day(year as f64, f64::from(month) - 1.0, day as f64) as i64;
+    // 2. Let ms be EpochDaysToEpochMs(epochDays, 0).
+    // 3. Return CreateISODateRecord(EpochTimeToEpochYear(ms),
+    //    EpochTimeToMonthInYear(ms) + 1, EpochTimeToDate(ms)).
+    IsoDate::from_epoch_days(epoch_days)
+}
+
+/// ### [3.5.10 ISODateWithinLimits ( isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-isodatewithinlimits)
+pub(crate) fn iso_date_within_limits(iso_date: IsoDate) -> bool {
+    // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate,
+    //    NoonTimeRecord()).
+    // 2. Return ISODateTimeWithinLimits(isoDateTime).
+    iso_date_time_within_limits(IsoDateTime::new(iso_date, TimeRecord::NOON))
+}
+
+/// ### [5.5.6 ISODateTimeWithinLimits ( isoDateTime )](https://tc39.es/proposal-temporal/#sec-temporal-isodatetimewithinlimits)
+pub(crate) fn iso_date_time_within_limits(iso_date_time: IsoDateTime) -> bool {
+    // 1. If abs(ISODateToEpochDays(isoDateTime.[[ISODate]].[[Year]],
+    //    isoDateTime.[[ISODate]].[[Month]] - 1, isoDateTime.[[ISODate]].[[Day]]))
+    //    > 10**8 + 1, return false.
+    if iso_date_time.date.to_epoch_days().abs() > 100_000_001 {
+        return false;
+    }
+    // 2. Let ns be ℝ(GetUTCEpochNanoseconds(isoDateTime)).
+    let ns = iso_date_time.to_epoch_nanoseconds();
+    // 3. If ns ≤ nsMinInstant - nsPerDay, then
+    //    a. Return false.
+    // 4. If ns ≥ nsMaxInstant + nsPerDay, then
+    //    a. Return false.
+    // 5. Return true.
+    NS_MIN_INSTANT - NS_PER_DAY < ns && ns < NS_MAX_INSTANT + NS_PER_DAY
+}
+
+/// Throw a RangeError if the date is not within the limits of a
+/// Temporal.PlainDate.
+pub(crate) fn check_iso_date_within_limits(iso_date: IsoDate) -> TemporalResult<IsoDate> {
+    if iso_date_within_limits(iso_date) {
+        Ok(iso_date)
+    } else {
+        Err(RangeError("date is out of range"))
+    }
+}
+
+/// Throw a RangeError if the date-time is not within the limits of a
+/// Temporal.PlainDateTime.
+pub(crate) fn check_iso_date_time_within_limits(
+    iso_date_time: IsoDateTime,
+) -> TemporalResult<IsoDateTime> {
+    if iso_date_time_within_limits(iso_date_time) {
+        Ok(iso_date_time)
+    } else {
+        Err(RangeError("date-time is out of range"))
+    }
+}
+
+/// ### [4.5.10 IsValidTime ( hour, minute, second, millisecond, microsecond, nanosecond )](https://tc39.es/proposal-temporal/#sec-temporal-isvalidtime)
+pub(crate) fn is_valid_time(
+    hour: f64,
+    minute: f64,
+    second: f64,
+    millisecond: f64,
+    microsecond: f64,
+    nanosecond: f64,
+) -> bool {
+    // 1. If hour < 0 or hour > 23, then
+    //    a. Return false.
+    // 2. If minute < 0 or minute > 59, then
+    //    a. Return false.
+    // 3. If second < 0 or second > 59, then
+    //    a. Return false.
+    // 4. If millisecond < 0 or millisecond > 999, then
+    //    a. Return false.
+    // 5. If microsecond < 0 or microsecond > 999, then
+    //    a. Return false.
+    // 6. If nanosecond < 0 or nanosecond > 999, then
+    //    a. Return false.
+    // 7. Return true.
+    (0.0..=23.0).contains(&hour)
+        && (0.0..=59.0).contains(&minute)
+        && (0.0..=59.0).contains(&second)
+        && (0.0..=999.0).contains(&millisecond)
+        && (0.0..=999.0).contains(&microsecond)
+        && (0.0..=999.0).contains(&nanosecond)
+}
+
+/// ### [4.5.9 RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-regulatetime)
+pub(crate) fn regulate_time(
+    hour: f64,
+    minute: f64,
+    second: f64,
+    millisecond: f64,
+    microsecond: f64,
+    nanosecond: f64,
+    overflow: Overflow,
+) -> TemporalResult<TimeRecord> {
+    // 1. If overflow is constrain, then
+    if overflow == Overflow::Constrain {
+        // a. Set hour to the result of clamping hour between 0 and 23.
+        // b. Set minute to the result of clamping minute between 0 and 59.
+        // c. Set second to the result of clamping second between 0 and 59.
+        // d. Set millisecond to the result of clamping millisecond between 0
+        //    and 999.
+        // e. Set microsecond to the result of clamping microsecond between 0
+        //    and 999.
+        // f. Set nanosecond to the result of clamping nanosecond between 0
+        //    and 999.
+        return Ok(TimeRecord {
+            hour: hour.clamp(0.0, 23.0) as u8,
+            minute: minute.clamp(0.0, 59.0) as u8,
+            second: second.clamp(0.0, 59.0) as u8,
+            millisecond: millisecond.clamp(0.0, 999.0) as u16,
+            microsecond: microsecond.clamp(0.0, 999.0) as u16,
+            nanosecond: nanosecond.clamp(0.0, 999.0) as u16,
+        });
+    }
+    // 2. Else,
+    //    a. Assert: overflow is reject.
+    //    b. If IsValidTime(hour, minute, second, millisecond, microsecond,
+    //       nanosecond) is false, throw a RangeError exception.
+    if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
+        return Err(RangeError("invalid time"));
+    }
+    // 3. Return CreateTimeRecord(hour, minute, second, millisecond,
+    //    microsecond, nanosecond).
+    Ok(TimeRecord {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        millisecond: millisecond as u16,
+        microsecond: microsecond as u16,
+        nanosecond: nanosecond as u16,
+    })
+}
+
+/// ### [4.5.11 BalanceTime ( hour, minute, second, millisecond, microsecond, nanosecond )](https://tc39.es/proposal-temporal/#sec-temporal-balancetime)
+///
+/// Balances a number of nanoseconds since midnight, returning the number of
+/// days and the time within the day.
+pub(crate) fn balance_time(nanoseconds: i128) -> (i64, TimeRecord) {
+    let days = nanoseconds.div_euclid(NS_PER_DAY);
+    let nanoseconds = nanoseconds.rem_euclid(NS_PER_DAY) as u64;
+    let time = TimeRecord {
+        hour: (nanoseconds / 3_600_000_000_000) as u8,
+        minute: (nanoseconds / 60_000_000_000 % 60) as u8,
+        second: (nanoseconds / 1_000_000_000 % 60) as u8,
+        millisecond: (nanoseconds / 1_000_000 % 1000) as u16,
+        microsecond: (nanoseconds / 1_000 % 1000) as u16,
+        nanosecond: (nanoseconds % 1000) as u16,
+    };
+    (days as i64, time)
+}
+
+/// ### [4.5.15 AddTime ( time, timeDuration )](https://tc39.es/proposal-temporal/#sec-temporal-addtime)
+///
+/// Returns the number of days to carry and the resulting time.
+pub(crate) fn add_time(time: TimeRecord, time_duration: i128) -> (i64, TimeRecord) {
+    // 1. Return BalanceTime(time.[[Hour]], time.[[Minute]], time.[[Second]],
+    //    time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] +
+    //    timeDuration).
+    balance_time(time.to_nanoseconds() + time_duration)
+}
+
+/// ### [4.5.12 DifferenceTime ( time1, time2 )](https://tc39.es/proposal-temporal/#sec-temporal-differencetime)
+pub(crate) fn difference_time(time1: TimeRecord, time2: TimeRecord) -> i128 {
+    // 1-8. Let timeDuration be TimeDurationFromComponents(hours, minutes,
+    //      seconds, milliseconds, microseconds, nanoseconds) of the
+    //      differences of the fields.
+    // 9. Assert: abs(timeDuration) < nsPerDay.
+    // 10. Return timeDuration.
+    time2.to_nanoseconds() - time1.to_nanoseconds()
+}
+
+/// ### [4.5.16 RoundTime ( time, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundtime)
+///
+/// Returns the number of days to carry and the rounded time.
+pub(crate) fn round_time(
+    time: TimeRecord,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> (i64, TimeRecord) {
+    // 1-8. Let quantity be the time in units of nanoseconds, up to the given
+    //      unit.
+    // NOTE: The rounding increment divides the number of units in the next
+    // larger unit, so rounding the time since midnight gives the same result
+    // as rounding only the parts of the time up to the given unit.
+    let quantity = time.to_nanoseconds();
+    // 9. Let unitLength be LengthInNanoseconds(unit).
+    let unit_length = unit.length_in_nanoseconds();
+    // 10. Let result be RoundNumberToIncrement(quantity, increment ×
+    //     unitLength, roundingMode) / unitLength.
+    let result =
+        round_number_to_increment(quantity, i128::from(increment) * unit_length, rounding_mode);
+    // 11-17. Return BalanceTime of the result.
+    balance_time(result)
+}
+
+/// ### [12.3.23 ISODateSurpasses ( sign, y1, m1, d1, isoDate2 )](https://tc39.es/proposal-temporal/#sec-temporal-isodatesurpasses)
+fn iso_date_surpasses(sign: i64, y1: i64, m1: u8, d1: u8, iso_date2: IsoDate) -> bool {
+    let difference = match y1.cmp(&i64::from(iso_date2.year)) {
+        // 1. If y1 ≠ isoDate2.[[Year]], then
+        //    a. If sign × (y1 - isoDate2.[[Year]]) > 0, return true.
+        Ordering::Less => -1,
+        Ordering::Greater => 1,
+        Ordering::Equal => match m1.cmp(&iso_date2.month) {
+            // 2. Else if m1 ≠ isoDate2.[[Month]], then
+            //    a. If sign × (m1 - isoDate2.[[Month]]) > 0, return true.
+            Ordering::Less => -1,
+            Ordering::Greater => 1,
+            // 3. Else if d1 ≠ isoDate2.[[Day]], then
+            //    a. If sign × (d1 - isoDate2.[[Day]]) > 0, return true.
+            Ordering::Equal => d1.cmp(&iso_date2.day) as i64,
+        },
+    };
+    // 4. Return false.
+    sign * difference > 0
+}
+
+/// ### [7.5.2 Date Duration Records](https://tc39.es/proposal-temporal/#sec-temporal-date-duration-records)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DateDuration {
+    /// \[\[Years]]
+    pub(crate) years: i64,
+    /// \[\[Months]]
+    pub(crate) months: i64,
+    /// \[\[Weeks]]
+    pub(crate) weeks: i64,
+    /// \[\[Days]]
+    pub(crate) days: i64,
+}
+trivially_bindable!(DateDuration);
+
+impl DateDuration {
+    /// ### [7.5.13 DateDurationSign ( dateDuration )](https://tc39.es/proposal-temporal/#sec-temporal-datedurationsign)
+    pub(crate) fn sign(self) -> i64 {
+        // 1. For each value v of « dateDuration.[[Years]],
+        //    dateDuration.[[Months]], dateDuration.[[Weeks]],
+        //    dateDuration.[[Days]] », do
+        //    a. If v < 0, return -1.
+        //    b. If v > 0, return 1.
+        // 2. Return 0.
+        [self.years, self.months, self.weeks, self.days]
+            .into_iter()
+            .find(|v| *v != 0)
+            .map_or(0, i64::signum)
+    }
+}
+
+/// ### [12.3.21 CalendarDateAdd ( calendar, isoDate, duration, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-calendardateadd)
+pub(crate) fn calendar_date_add(
+    iso_date: IsoDate,
+    duration: DateDuration,
+    overflow: Overflow,
+) -> TemporalResult<IsoDate> {
+    // 1. If calendar is "iso8601", then
+    //    a. Let intermediate be BalanceISOYearMonth(isoDate.[[Year]] +
+    //       duration.[[Years]], isoDate.[[Month]] + duration.[[Months]]).
+    let (year, month) = balance_iso_year_month(
+        i64::from(iso_date.year) + duration.years,
+        i64::from(iso_date.month) + duration.months,
+    );
+    //    b. Set intermediate to ? RegulateISODate(intermediate.[[Year]],
+    //       intermediate.[[Month]], isoDate.[[Day]], overflow).
+    let intermediate = regulate_iso_date(
+        year as f64,
+        f64::from(month),
+        f64::from(iso_date.day),
+        overflow,
+    )?;
+    //    c. Let d be intermediate.[[Day]] + duration.[[Days]] + 7 ×
+    //       duration.[[Weeks]].
+    let d = i64::from(intermediate.day) + duration.days + 7 * duration.weeks;
+    //    d. Let result be BalanceISODate(intermediate.[[Year]],
+    //       intermediate.[[Month]], d).
+    let result = balance_iso_date(i64::from(intermediate.year), intermediate.month, d);
+    // 3. If ISODateWithinLimits(result) is false, throw a RangeError
+    //    exception.
+    // 4. Return result.
+    check_iso_date_within_limits(result)
+}
+
+/// ### [12.3.22 CalendarDateUntil ( calendar, one, two, largestUnit )](https://tc39.es/proposal-temporal/#sec-temporal-calendardateuntil)
+///
+/// The repetitions of the specification, which step through the candidate
+/// numbers of units one at a time, are replaced by computing the candidate
+/// directly.
+pub(crate) fn calendar_date_until(one: IsoDate, two: IsoDate, largest_unit: Unit) -> DateDuration {
+    // 1. If calendar is "iso8601", then
+    //    a. Let sign be -CompareISODate(one, two).
+    let sign = -(one.cmp(&two) as i64);
+    //    b. If sign = 0, return ZeroDateDuration().
+    if sign == 0 {
+        return DateDuration::default();
+    }
+    //    c. Let years be 0.
+    let mut years = 0;
+    //    d. If largestUnit is year, then
+    if largest_unit == Unit::Year {
+        // i. Let candidateYears be sign.
+        // ii. Repeat, while ISODateSurpasses(sign, one.[[Year]] +
+        //     candidateYears, one.[[Month]], one.[[Day]], two) is false,
+        //     1. Set years to candidateYears.
+        //     2. Set candidateYears to candidateYears + sign.
+        years = i64::from(two.year) - i64::from(one.year);
+        while years != 0
+            && iso_date_surpasses(sign, i64::from(one.year) + years, one.month, one.day, two)
+        {
+            years -= sign;
+        }
+    }
+    //    e. Let months be 0.
+    let mut months = 0;
+    //    f. If largestUnit is year or largestUnit is month, then
+    if matches!(largest_unit, Unit::Year | Unit::Month) {
+        // i. Let candidateMonths be sign.
+        // ii. Let intermediate be BalanceISOYearMonth(one.[[Year]] + years,
+        //     one.[[Month]] + candidateMonths).
+        // iii. Repeat, while ISODateSurpasses(sign, intermediate.[[Year]],
+        //      intermediate.[[Month]], one.[[Day]], two) is false,
+        //      1. Set months to candidateMonths.
+        //      2. Set candidateMonths to candidateMonths + sign.
+        //      3. Set intermediate to BalanceISOYearMonth(intermediate.[[Year]],
+        //         intermediate.[[Month]] + sign).
+        months = (i64::from(two.year) - i64::from(one.year) - years) * 12 + i64::from(two.month)
+            - i64::from(one.month);
+        while months != 0 {
+            let (year, month) =
+                balance_iso_year_month(i64::from(one.year) + years, i64::from(one.month) + months);
+            if !iso_date_surpasses(sign, year, month, one.day, two) {
+                break;
+            }
+            months -= sign;
+        }
+    }
+    //    g. Set intermediate to BalanceISOYearMonth(one.[[Year]] + years,
+    //       one.[[Month]] + months).
+    let (year, month) =
+        balance_iso_year_month(i64::from(one.year) + years, i64::from(one.month) + months);
+    //    h. Let constrained be ! RegulateISODate(intermediate.[[Year]],
+    //       intermediate.[[Month]], one.[[Day]], constrain).
+    let constrained = regulate_iso_date(
+        year as f64,
+        f64::from(month),
+        f64::from(one.day),
+        Overflow::Constrain,
+    )
+    .unwrap();
+    let remaining_days = two.to_epoch_days() - constrained.to_epoch_days();
+    //    i. Let weeks be 0.
+    //    j. If largestUnit is week, then
+    //       i. Let candidateWeeks be sign.
+    //       ii. Set intermediate to BalanceISODate(constrained.[[Year]],
+    //           constrained.[[Month]], constrained.[[Day]] + 7 ×
+    //           candidateWeeks).
+    //       iii. Repeat, while ISODateSurpasses(sign, intermediate.[[Year]],
+    //            intermediate.[[Month]], intermediate.[[Day]], two) is false,
+    //            1. Set weeks to candidateWeeks.
+    //            2. Set candidateWeeks to candidateWeeks + sign.
+    //            3. Set intermediate to BalanceISODate(intermediate.[[Year]],
+    //               intermediate.[[Month]], intermediate.[[Day]] + 7 × sign).
+    let weeks = if largest_unit == Unit::Week {
+        remaining_days / 7
+    } else {
+        0
+    };
+    //    k. Let days be 0.
+    //    l. Let candidateDays be sign.
+    //    m. Set intermediate to BalanceISODate(constrained.[[Year]],
+    //       constrained.[[Month]], constrained.[[Day]] + 7 × weeks +
+    //       candidateDays).
+    //    n. Repeat, while ISODateSurpasses(sign, intermediate.[[Year]],
+    //       intermediate.[[Month]], intermediate.[[Day]], two) is false,
+    //       i. Set days to candidateDays.
+    //       ii. Set candidateDays to candidateDays + sign.
+    //       iii. Set intermediate to BalanceISODate(intermediate.[[Year]],
+    //            intermediate.[[Month]], intermediate.[[Day]] + sign).
+    let days = remaining_days - 7 * weeks;
+    //    o. Return ! CreateDateDurationRecord(years, months, weeks, days).
+    DateDuration {
+        years,
+        months,
+        weeks,
+        days,
+    }
+}
+
+/// ### [12.2.1 CanonicalizeCalendar ( id )](https://tc39.es/proposal-temporal/#sec-temporal-canonicalizecalendar)
+pub(crate) fn canonicalize_calendar(id: &str) -> TemporalResult<&'static str> {
+    // 1. Let calendars be AvailableCalendars().
+    // 2. If calendars does not contain the ASCII-lowercase of id, throw a
+    //    RangeError exception.
+    if !id.eq_ignore_ascii_case(ISO8601) {
+        return Err(RangeError("unsupported calendar"));
+    }
+    // 3. Return CanonicalizeUValue("ca", id).
+    Ok(ISO8601)
+}
+
+/// ### [12.2.9 ToTemporalCalendarIdentifier ( temporalCalendarLike )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendaridentifier)
+pub(crate) fn to_temporal_calendar_identifier<'a>(
+    agent: &mut Agent,
+    temporal_calendar_like: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, &'static str> {
+    // 1. If temporalCalendarLike is an Object, then
+    //    a. If temporalCalendarLike has an [[InitializedTemporalDate]],
+    //       [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //       [[InitializedTemporalYearMonth]], or
+    //       [[InitializedTemporalZonedDateTime]] internal slot, then
+    //       i. Return temporalCalendarLike.[[Calendar]].
+    if Object::try_from(temporal_calendar_like).is_ok_and(has_calendar_slot) {
+        return Ok(ISO8601);
+    }
+    // 2. If temporalCalendarLike is not a String, throw a TypeError exception.
+    let Ok(temporal_calendar_like) = String::try_from(temporal_calendar_like) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "calendar must be a string",
+            gc,
+        ));
+    };
+    // 3. Let identifier be ? ParseTemporalCalendarString(temporalCalendarLike).
+    // 4. Return ? CanonicalizeCalendar(identifier).
+    let result =
+        parse_temporal_calendar_string(&temporal_calendar_like.as_wtf8(agent).to_string_lossy())
+            .and_then(|identifier| canonicalize_calendar(&identifier));
+    result.map_err(|err| err.throw(agent, gc))
+}
+
+/// ### [12.2.10 GetTemporalCalendarIdentifierWithISODefault ( item )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarslotvaluewithisodefault)
+pub(crate) fn get_temporal_calendar_identifier_with_iso_default<'gc>(
+    agent: &mut Agent,
+    item: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, &'static str> {
+    // 1. If item has an [[InitializedTemporalDate]],
+    //    [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //    [[InitializedTemporalYearMonth]], or
+    //    [[InitializedTemporalZonedDateTime]] internal slot, then
+    //    a. Return item.[[Calendar]].
+    if has_calendar_slot(item) {
+        return Ok(ISO8601);
+    }
+    // 2. Let calendarLike be ? Get(item, "calendar").
+    let calendar_like = get(
+        agent,
+        item,
+        BUILTIN_STRING_MEMORY.calendar.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 3. If calendarLike is undefined, then
+    if calendar_like.is_undefined() {
+        // a. Return "iso8601".
+        return Ok(ISO8601);
+    }
+    // 4. Return ? ToTemporalCalendarIdentifier(calendarLike).
+    to_temporal_calendar_identifier(agent, calendar_like, gc)
+}
+
+/// Returns true if the object has a \[\[Calendar]] internal slot.
+fn has_calendar_slot(object: Object) -> bool {
+    matches!(
+        object,
+        Object::PlainDate(_) | Object::PlainDateTime(_) | Object::ZonedDateTime(_)
+    )
+}
+
+/// The property names of the fields of a Calendar Fields Record, in
+/// alphabetical order.
+///
+/// ### [Table 19: Calendar Fields Record Fields](https://tc39.es/proposal-temporal/#table-temporal-calendar-fields-record-fields)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Field {
+    Day,
+    Hour,
+    Microsecond,
+    Millisecond,
+    Minute,
+    Month,
+    MonthCode,
+    Nanosecond,
+    Offset,
+    Second,
+    TimeZone,
+    Year,
+}
+
+impl Field {
+    /// The date fields, the calendarFieldNames of the ISO 8601 calendar.
+    pub(crate) const DATE: &'static [Field] =
+        &[Field::Day, Field::Month, Field::MonthCode, Field::Year];
+
+    /// The time fields.
+    pub(crate) const TIME: &'static [Field] = &[
+        Field::Hour,
+        Field::Microsecond,
+        Field::Millisecond,
+        Field::Minute,
+        Field::Nanosecond,
+        Field::Second,
+    ];
+
+    /// All fields, in alphabetical order.
+    const ALL: [Field; 12] = [
+        Field::Day,
+        Field::Hour,
+        Field::Microsecond,
+        Field::Millisecond,
+        Field::Minute,
+        Field::Month,
+        Field::MonthCode,
+        Field::Nanosecond,
+        Field::Offset,
+        Field::Second,
+        Field::TimeZone,
+        Field::Year,
+    ];
+
+    /// The "Property Key" column of Table 19.
+    fn property_key(self) -> String<'static> {
+        match self {
+            Field::Day => BUILTIN_STRING_MEMORY.day,
+            Field::Hour => BUILTIN_STRING_MEMORY.hour,
+            Field::Microsecond => BUILTIN_STRING_MEMORY.microsecond,
+            Field::Millisecond => BUILTIN_STRING_MEMORY.millisecond,
+            Field::Minute => BUILTIN_STRING_MEMORY.minute,
+            Field::Month => BUILTIN_STRING_MEMORY.month,
+            Field::MonthCode => BUILTIN_STRING_MEMORY.monthCode,
+            Field::Nanosecond => BUILTIN_STRING_MEMORY.nanosecond,
+            Field::Offset => BUILTIN_STRING_MEMORY.offset,
+            Field::Second => BUILTIN_STRING_MEMORY.second,
+            Field::TimeZone => BUILTIN_STRING_MEMORY.timeZone,
+            Field::Year => BUILTIN_STRING_MEMORY.year,
+        }
+    }
+}
+
+/// ### [12.2.2 Calendar Fields Records](https://tc39.es/proposal-temporal/#sec-temporal-calendar-fields-records)
+///
+/// A field is None if it is unset.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CalendarFields {
+    /// \[\[Year]]
+    pub(crate) year: Option<f64>,
+    /// \[\[Month]]
+    pub(crate) month: Option<f64>,
+    /// \[\[MonthCode]], as the month number it names.
+    pub(crate) month_code: Option<u8>,
+    /// \[\[Day]]
+    pub(crate) day: Option<f64>,
+    /// \[\[Hour]]
+    pub(crate) hour: Option<f64>,
+    /// \[\[Minute]]
+    pub(crate) minute: Option<f64>,
+    /// \[\[Second]]
+    pub(crate) second: Option<f64>,
+    /// \[\[Millisecond]]
+    pub(crate) millisecond: Option<f64>,
+    /// \[\[Microsecond]]
+    pub(crate) microsecond: Option<f64>,
+    /// \[\[Nanosecond]]
+    pub(crate) nanosecond: Option<f64>,
+    /// \[\[OffsetString]], as a number of nanoseconds.
+    pub(crate) offset: Option<i64>,
+    /// \[\[TimeZone]]
+    pub(crate) time_zone: Option<TimeZone>,
+}
+trivially_bindable!(CalendarFields);
+
+impl CalendarFields {
+    /// The fields of an ISO date.
+    pub(crate) fn from_iso_date(iso_date: IsoDate) -> Self {
+        Self {
+            year: Some(f64::from(iso_date.year)),
+            month: Some(f64::from(iso_date.month)),
+            month_code: Some(iso_date.month),
+            day: Some(f64::from(iso_date.day)),
+            ..Self::default()
+        }
+    }
+
+    /// The time fields of a time.
+    pub(crate) fn with_time(self, time: TimeRecord) -> Self {
+        Self {
+            hour: Some(f64::from(time.hour)),
+            minute: Some(f64::from(time.minute)),
+            second: Some(f64::from(time.second)),
+            millisecond: Some(f64::from(time.millisecond)),
+            microsecond: Some(f64::from(time.microsecond)),
+            nanosecond: Some(f64::from(time.nanosecond)),
+            ..self
+        }
+    }
+
+    /// ### [12.2.5 CalendarMergeFields ( calendar, fields, additionalFields )](https://tc39.es/proposal-temporal/#sec-temporal-calendarmergefields)
+    pub(crate) fn merge(self, additional_fields: Self) -> Self {
+        // 1. Let additionalKeys be CalendarFieldKeysPresent(additionalFields).
+        // 2. Let overriddenKeys be CalendarFieldKeysToIgnore(calendar,
+        //    additionalKeys).
+        // 3. Let merged be a Calendar Fields Record with all fields unset.
+        // 4. Let fieldsKeys be CalendarFieldKeysPresent(fields).
+        // 5. For each value key of fieldsKeys, do
+        //    a. If fieldsKeys contains key and overriddenKeys does not contain
+        //       key, then
+        //       i. Let propValue be the value of the field of fields whose
+        //          name is given in the Field Name column of the same row.
+        //       ii. Set merged's field whose name is given in the Field Name
+        //           column of the same row to propValue.
+        // 6. For each value key of additionalKeys, do
+        //    a. Let propValue be the value of the field of additionalFields
+        //       whose name is given in the Field Name column of the same row.
+        //    b. Set merged's field whose name is given in the Field Name
+        //       column of the same row to propValue.
+        // 7. Return merged.
+        // NOTE: For the ISO 8601 calendar, month and monthCode override each
+        // other.
+        let (month, month_code) =
+            if additional_fields.month.is_some() || additional_fields.month_code.is_some() {
+                (additional_fields.month, additional_fields.month_code)
+            } else {
+                (self.month, self.month_code)
+            };
+        Self {
+            year: additional_fields.year.or(self.year),
+            month,
+            month_code,
+            day: additional_fields.day.or(self.day),
+            hour: additional_fields.hour.or(self.hour),
+            minute: additional_fields.minute.or(self.minute),
+            second: additional_fields.second.or(self.second),
+            millisecond: additional_fields.millisecond.or(self.millisecond),
+            microsecond: additional_fields.microsecond.or(self.microsecond),
+            nanosecond: additional_fields.nanosecond.or(self.nanosecond),
+            offset: additional_fields.offset.or(self.offset),
+            time_zone: additional_fields.time_zone.or(self.time_zone),
+        }
+    }
+
+    /// Returns the time fields as a list, with unset fields defaulting to 0:
+    /// hour, minute, second, millisecond, microsecond and nanosecond.
+    pub(crate) fn time_fields(&self) -> [f64; 6] {
+        [
+            self.hour.unwrap_or(0.0),
+            self.minute.unwrap_or(0.0),
+            self.second.unwrap_or(0.0),
+            self.millisecond.unwrap_or(0.0),
+            self.microsecond.unwrap_or(0.0),
+            self.nanosecond.unwrap_or(0.0),
+        ]
+    }
+}
+
+/// The requiredFieldNames argument of PrepareCalendarFields.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RequiredFields {
+    /// A list of required field names; the unset time fields default to 0.
+    List(&'static [Field]),
+    /// partial
+    Partial,
+}
+
+/// ### [12.2.3 PrepareCalendarFields ( calendar, fields, calendarFieldNames, nonCalendarFieldNames, requiredFieldNames )](https://tc39.es/proposal-temporal/#sec-temporal-preparecalendarfields)
+///
+/// The field names are the union of calendarFieldNames and
+/// nonCalendarFieldNames.
+pub(crate) fn prepare_calendar_fields<'gc>(
+    agent: &mut Agent,
+    fields: Object,
+    field_names: &[&[Field]],
+    required_field_names: RequiredFields,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, CalendarFields> {
+    let fields = fields.scope(agent, gc.nogc());
+    // 1. Assert: If requiredFieldNames is a List, requiredFieldNames contains
+    //    zero or one of each of the elements of calendarFieldNames and
+    //    nonCalendarFieldNames.
+    // 2. Let fieldNames be the list-concatenation of calendarFieldNames and
+    //    nonCalendarFieldNames.
+    // 3. Let extraFieldNames be CalendarExtraFields(calendar,
+    //    calendarFieldNames).
+    // 4. Set fieldNames to the list-concatenation of fieldNames and
+    //    extraFieldNames.
+    // 5. Assert: fieldNames contains no duplicate elements.
+    // 6. Let result be a Calendar Fields Record with all fields equal to
+    //    unset.
+    let mut result = CalendarFields::default();
+    // 7. Let any be false.
+    let mut any = false;
+    // 8. Let sortedPropertyNames be a List whose elements are the values in
+    //    the Property Key column of Table 19 corresponding to the elements of
+    //    fieldNames, sorted according to lexicographic code unit order.
+    let sorted_property_names = Field::ALL
+        .into_iter()
+        .filter(|field| field_names.iter().any(|names| names.contains(field)));
+    // 9. For each property name property of sortedPropertyNames, do
+    for field in sorted_property_names {
+        // a. Let key be the value in the Enumeration Key column of Table 19
+        //    corresponding to the row whose Property Key value is property.
+        // b. Let value be ? Get(fields, property).
+        let value = get(
+            agent,
+            fields.get(agent),
+            field.property_key().into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // c. If value is not undefined, then
+        if !value.is_undefined() {
+            // i. Set any to true.
+            any = true;
+            // ii. Let Conversion be the Conversion value of the same row.
+            // iii-x. Set value to the result of the Conversion of value.
+            // xi. Set result's field whose name is given in the Field Name
+            //     column of the same row to value.
+            let value = value.unbind();
+            match field {
+                // ToPositiveIntegerWithTruncation
+                Field::Day => {
+                    result.day = Some(
+                        to_positive_integer_with_truncation(agent, value, gc.reborrow())
+                            .unbind()?,
+                    )
+                }
+                Field::Month => {
+                    result.month = Some(
+                        to_positive_integer_with_truncation(agent, value, gc.reborrow())
+                            .unbind()?,
+                    )
+                }
+                // ToMonthCode
+                Field::MonthCode => {
+                    result.month_code = Some(to_month_code(agent, value, gc.reborrow()).unbind()?)
+                }
+                // ToOffsetString
+                Field::Offset => {
+                    result.offset = Some(to_offset_string(agent, value, gc.reborrow()).unbind()?)
+                }
+                // ToTemporalTimeZoneIdentifier
+                Field::TimeZone => {
+                    result.time_zone =
+                        Some(to_temporal_time_zone_identifier(agent, value, gc.nogc()).unbind()?)
+                }
+                // ToIntegerWithTruncation
+                _ => {
+                    let value = to_integer_with_truncation(agent, value, gc.reborrow()).unbind()?;
+                    let slot = match field {
+                        Field::Hour => &mut result.hour,
+                        Field::Microsecond => &mut result.microsecond,
+                        Field::Millisecond => &mut result.millisecond,
+                        Field::Minute => &mut result.minute,
+                        Field::Nanosecond => &mut result.nanosecond,
+                        Field::Second => &mut result.second,
+                        Field::Year => &mut result.year,
+                        _ => unreachable!(),
+                    };
+                    *slot = Some(value);
+                }
+            }
+        } else if let RequiredFields::List(required_field_names) = required_field_names {
+            // d. Else if requiredFieldNames is a List, then
+            //    i. If requiredFieldNames contains key, then
+            if required_field_names.contains(&field) {
+                // 1. Throw a TypeError exception.
+                let message = format!(
+                    "required property '{}' is missing or undefined",
+                    field.property_key().as_str(agent)
+                );
+                return Err(agent.throw_exception(
+                    ExceptionType::TypeError,
+                    message,
+                    gc.into_nogc(),
+                ));
+            }
+            //    ii. Set result's field whose name is given in the Field Name
+            //        column of the same row to the corresponding Default
+            //        value of the same row.
+            // NOTE: The time fields default to 0 through
+            // CalendarFields::time_fields.
+        }
+    }
+    // 10. If requiredFieldNames is partial and any is false, then
+    if matches!(required_field_names, RequiredFields::Partial) && !any {
+        // a. Throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "object must have at least one recognized property",
+            gc.into_nogc(),
+        ));
+    }
+    // 11. Return result.
+    Ok(result)
+}
+
+/// ### [13.40 ToMonthCode ( argument )](https://tc39.es/proposal-temporal/#sec-temporal-tomonthcode)
+///
+/// Returns the number of the month named by the month code.
+fn to_month_code<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, u8> {
+    // 1. Let monthCode be ? ToPrimitive(argument, string).
+    let month_code =
+        to_primitive(agent, argument, Some(PreferredType::String), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 2. If monthCode is not a String, throw a TypeError exception.
+    let Ok(month_code) = String::try_from(month_code) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "monthCode must be a string",
+            gc,
+        ));
+    };
+    // 3. If the length of monthCode is not 3 or 4, throw a RangeError
+    //    exception.
+    // 4. If the first code unit of monthCode is not 0x004D (LATIN CAPITAL
+    //    LETTER M), throw a RangeError exception.
+    // 5. If the second and third code units of monthCode are not both ASCII
+    //    digits, throw a RangeError exception.
+    // 6. If the length of monthCode is 4 and the fourth code unit is not
+    //    0x004C (LATIN CAPITAL LETTER L), throw a RangeError exception.
+    // 7. Let monthCodeDigits be the substring of monthCode from 1 to 3.
+    // 8. Let monthCodeInteger be ℝ(StringToNumber(monthCodeDigits)).
+    // 9. If monthCodeInteger is 0 and the length of monthCode is not 4, throw
+    //    a RangeError exception.
+    // 10. Return monthCode.
+    // NOTE: The ISO 8601 calendar has no leap months, so only the month codes
+    // M01 to M12 are valid; the others throw in CalendarResolveFields.
+    let month = match month_code.as_wtf8(agent).as_str().map(str::as_bytes) {
+        Some(&[b'M', tens @ b'0'..=b'9', ones @ b'0'..=b'9']) => {
+            Some((tens - b'0') * 10 + (ones - b'0'))
+        }
+        _ => None,
+    };
+    let Some(month) = month else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "invalid monthCode",
+            gc,
+        ));
+    };
+    if !(1..=12).contains(&month) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "monthCode is not valid for the ISO 8601 calendar",
+            gc,
+        ));
+    }
+    Ok(month)
+}
+
+/// ### [13.41 ToOffsetString ( argument )](https://tc39.es/proposal-temporal/#sec-temporal-tooffsetstring)
+///
+/// Returns the offset as a number of nanoseconds.
+fn to_offset_string<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, i64> {
+    // 1. Let offset be ? ToPrimitive(argument, string).
+    let offset =
+        to_primitive(agent, argument, Some(PreferredType::String), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 2. If offset is not a String, throw a TypeError exception.
+    let Ok(offset) = String::try_from(offset) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "offset must be a string",
+            gc,
+        ));
+    };
+    // 3. Perform ? ParseDateTimeUTCOffset(offset).
+    // 4. Return offset.
+    let result = parse_date_time_utc_offset(&offset.as_wtf8(agent).to_string_lossy())
+        .map(|offset| offset.nanoseconds);
+    result.map_err(|err| err.throw(agent, gc))
+}
+
+/// ### [12.2.6 CalendarDateFromFields ( calendar, fields, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-calendardatefromfields)
+///
+/// The fields must contain the year and day, and the month or the month
+/// code.
+pub(crate) fn calendar_date_from_fields(
+    fields: &CalendarFields,
+    overflow: Overflow,
+) -> Result<IsoDate, CalendarFieldsError> {
+    // 1. Perform ? CalendarResolveFields(calendar, fields, date).
+    let (year, month, day) = calendar_resolve_fields(fields)?;
+    // 2. Let result be ? CalendarDateToISO(calendar, fields, overflow).
+    let result = regulate_iso_date(year, month, day, overflow)?;
+    // 3. If ISODateWithinLimits(result) is false, throw a RangeError
+    //    exception.
+    // 4. Return result.
+    Ok(check_iso_date_within_limits(result)?)
+}
+
+/// An error of the operations resolving Calendar Fields Records.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CalendarFieldsError {
+    TypeError(&'static str),
+    RangeError(RangeError),
+}
+trivially_bindable!(CalendarFieldsError);
+
+impl From<RangeError> for CalendarFieldsError {
+    fn from(value: RangeError) -> Self {
+        Self::RangeError(value)
+    }
+}
+
+impl CalendarFieldsError {
+    pub(crate) fn throw<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        match self {
+            Self::TypeError(message) => {
+                agent.throw_exception_with_static_message(ExceptionType::TypeError, message, gc)
+            }
+            Self::RangeError(err) => err.throw(agent, gc),
+        }
+    }
+}
+
+/// ### [12.3.28 CalendarResolveFields ( calendar, fields, type )](https://tc39.es/proposal-temporal/#sec-temporal-calendarresolvefields)
+///
+/// Resolves the fields of the type date, returning the year, month and day.
+fn calendar_resolve_fields(
+    fields: &CalendarFields,
+) -> Result<(f64, f64, f64), CalendarFieldsError> {
+    // 1. If calendar is "iso8601", then
+    //    a. If type is date or year-month and fields.[[Year]] is unset, throw
+    //       a TypeError exception.
+    let Some(year) = fields.year else {
+        return Err(CalendarFieldsError::TypeError("year is required"));
+    };
+    //    b. If type is date or month-day and fields.[[Day]] is unset, throw a
+    //       TypeError exception.
+    let Some(day) = fields.day else {
+        return Err(CalendarFieldsError::TypeError("day is required"));
+    };
+    //    c. Let month be fields.[[Month]].
+    //    d. Let monthCode be fields.[[MonthCode]].
+    //    e. If monthCode is unset, then
+    //       i. If month is unset, throw a TypeError exception.
+    //       ii. Return unused.
+    //    f. Assert: monthCode is a String.
+    //    g. NOTE: The ISO 8601 calendar does not include leap months.
+    //    h. If the length of monthCode is not 3, throw a RangeError exception.
+    //    i. If the first code unit of monthCode is not 0x004D (LATIN CAPITAL
+    //       LETTER M), throw a RangeError exception.
+    //    j. If the second code unit of monthCode is not in the inclusive
+    //       interval from 0x0030 (DIGIT ZERO) to 0x0039 (DIGIT NINE), throw a
+    //       RangeError exception.
+    //    k. If the third code unit of monthCode is not in the inclusive
+    //       interval from 0x0030 (DIGIT ZERO) to 0x0039 (DIGIT NINE), throw a
+    //       RangeError exception.
+    //    l. Let monthCodeDigits be the substring of monthCode from 1.
+    //    m. Let monthCodeInteger be ℝ(StringToNumber(monthCodeDigits)).
+    //    n. If monthCodeInteger < 1 or monthCodeInteger > 12, throw a
+    //       RangeError exception.
+    //    o. If month is not unset and month ≠ monthCodeInteger, throw a
+    //       RangeError exception.
+    //    p. Set fields.[[Month]] to monthCodeInteger.
+    let month = match (fields.month, fields.month_code) {
+        (None, None) => {
+            return Err(CalendarFieldsError::TypeError(
+                "month or monthCode is required",
+            ));
+        }
+        (Some(month), None) => month,
+        (Some(month), Some(month_code)) if month != f64::from(month_code) => {
+            return Err(RangeError("month and monthCode do not agree").into());
+        }
+        (_, Some(month_code)) => f64::from(month_code),
+    };
+    Ok((year, month, day))
+}
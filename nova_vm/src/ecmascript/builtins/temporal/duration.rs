@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::DurationIndex,
+    },
+};
+
+use self::data::DurationHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Duration<'a>(pub(crate) DurationIndex<'a>);
+
+impl Duration<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, Duration<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(DurationIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for Duration<'_> {
+    type Of<'a> = Duration<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for Duration<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Duration<'a>> for Value<'a> {
+    fn from(value: Duration<'a>) -> Self {
+        Value::Duration(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for Duration<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Duration<'a>> for Object<'a> {
+    fn from(value: Duration) -> Self {
+        Object::Duration(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for Duration<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::Duration(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for Duration<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::Duration(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for Duration<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalDuration;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for Duration<'a> {}
+
+impl Index<Duration<'_>> for Agent {
+    type Output = DurationHeapData<'static>;
+
+    fn index(&self, index: Duration) -> &Self::Output {
+        &self.heap.durations[index]
+    }
+}
+
+impl IndexMut<Duration<'_>> for Agent {
+    fn index_mut(&mut self, index: Duration) -> &mut Self::Output {
+        &mut self.heap.durations[index]
+    }
+}
+
+impl Index<Duration<'_>> for Vec<Option<DurationHeapData<'static>>> {
+    type Output = DurationHeapData<'static>;
+
+    fn index(&self, index: Duration) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("Duration out of bounds")
+            .as_ref()
+            .expect("Duration slot empty")
+    }
+}
+
+impl IndexMut<Duration<'_>> for Vec<Option<DurationHeapData<'static>>> {
+    fn index_mut(&mut self, index: Duration) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("Duration out of bounds")
+            .as_mut()
+            .expect("Duration slot empty")
+    }
+}
+
+impl Rootable for Duration<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::Duration(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::Duration(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for Duration<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.durations.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.durations.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<DurationHeapData<'a>, Duration<'a>> for Heap {
+    fn create(&mut self, data: DurationHeapData<'a>) -> Duration<'a> {
+        self.durations.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<DurationHeapData<'static>>>();
+        }
+        Duration(DurationIndex::last(&self.durations))
+    }
+}
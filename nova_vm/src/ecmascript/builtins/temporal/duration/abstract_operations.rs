@@ -0,0 +1,1885 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [7.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-duration-abstract-ops)
+//!
+//! Time durations are represented as a number of nanoseconds in an `i128`.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::get,
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    Disambiguation, NS_PER_DAY, OffsetOption, Overflow, Precision, RangeError,
+                    RoundingMode, TemporalResult, Unit, apply_unsigned_rounding_mode,
+                    format_fractional_seconds, get_unsigned_rounding_mode,
+                    larger_of_two_temporal_units, round_number_to_increment,
+                    to_integer_if_integral,
+                },
+                calendar::{
+                    CalendarFields, DateDuration, Field, ISO8601, IsoDate, IsoDateTime,
+                    RequiredFields, TimeRecord, add_time, balance_iso_date, calendar_date_add,
+                    calendar_date_until, canonicalize_calendar, check_iso_date_within_limits,
+                    get_temporal_calendar_identifier_with_iso_default, prepare_calendar_fields,
+                },
+                duration::{Duration, data::DurationHeapData},
+                parsing::{parse_temporal_duration_string, parse_temporal_relative_to_string},
+                plain_date_time::abstract_operations::interpret_temporal_date_time_fields,
+                time_zone::{
+                    OffsetBehaviour, TimeZone, get_epoch_nanoseconds_for,
+                    interpret_iso_date_time_offset, to_time_zone,
+                },
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// maxTimeDuration = 2**53 × 10**9 - 1.
+pub(crate) const MAX_TIME_DURATION: i128 = (1 << 53) * 1_000_000_000 - 1;
+
+/// ### [7.5.1 Duration Records](https://tc39.es/proposal-temporal/#sec-temporal-duration-records)
+///
+/// The fields hold integral Number values.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord {
+    /// \[\[Years]]
+    pub(crate) years: f64,
+    /// \[\[Months]]
+    pub(crate) months: f64,
+    /// \[\[Weeks]]
+    pub(crate) weeks: f64,
+    /// \[\[Days]]
+    pub(crate) days: f64,
+    /// \[\[Hours]]
+    pub(crate) hours: f64,
+    /// \[\[Minutes]]
+    pub(crate) minutes: f64,
+    /// \[\[Seconds]]
+    pub(crate) seconds: f64,
+    /// \[\[Milliseconds]]
+    pub(crate) milliseconds: f64,
+    /// \[\[Microseconds]]
+    pub(crate) microseconds: f64,
+    /// \[\[Nanoseconds]]
+    pub(crate) nanoseconds: f64,
+}
+trivially_bindable!(DurationRecord);
+
+impl DurationRecord {
+    /// The fields of the record, in the order of Table 21.
+    pub(crate) fn fields(self) -> [f64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+
+    /// A record from its fields, in the order of Table 21.
+    pub(crate) fn from_fields(fields: [f64; 10]) -> Self {
+        let [
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        ] = fields;
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        }
+    }
+
+    /// Apply a function to each field of the record.
+    pub(crate) fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        Self::from_fields(self.fields().map(f))
+    }
+
+    /// ### [7.5.29 CreateNegatedTemporalDuration ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-createnegatedtemporalduration)
+    pub(crate) fn negated(self) -> Self {
+        // 1. Return ! CreateTemporalDuration(-duration.[[Years]],
+        //    -duration.[[Months]], -duration.[[Weeks]], -duration.[[Days]],
+        //    -duration.[[Hours]], -duration.[[Minutes]],
+        //    -duration.[[Seconds]], -duration.[[Milliseconds]],
+        //    -duration.[[Microseconds]], -duration.[[Nanoseconds]]).
+        self.map(|value| -value + 0.0)
+    }
+
+    /// ### [7.5.10 DurationSign ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-durationsign)
+    pub(crate) fn sign(self) -> i8 {
+        // 1. For each value v of « duration.[[Years]], duration.[[Months]],
+        //    duration.[[Weeks]], duration.[[Days]], duration.[[Hours]],
+        //    duration.[[Minutes]], duration.[[Seconds]],
+        //    duration.[[Milliseconds]], duration.[[Microseconds]],
+        //    duration.[[Nanoseconds]] », do
+        //    a. If v < 0, return -1.
+        //    b. If v > 0, return 1.
+        // 2. Return 0.
+        for value in self.fields() {
+            if value < 0.0 {
+                return -1;
+            }
+            if value > 0.0 {
+                return 1;
+            }
+        }
+        0
+    }
+}
+
+/// ### [7.5.11 IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration)
+pub(crate) fn is_valid_duration(duration: &DurationRecord) -> bool {
+    // 1. Let sign be 0.
+    let mut sign = 0.0;
+    // 2. For each value v of « years, months, weeks, days, hours, minutes,
+    //    seconds, milliseconds, microseconds, nanoseconds », do
+    for v in duration.fields() {
+        // a. If 𝔽(v) is not finite, return false.
+        if !v.is_finite() {
+            return false;
+        }
+        // b. If v < 0, then
+        //    i. If sign > 0, return false.
+        //    ii. Set sign to -1.
+        // c. Else if v > 0, then
+        //    i. If sign < 0, return false.
+        //    ii. Set sign to 1.
+        if v != 0.0 {
+            if sign * v < 0.0 {
+                return false;
+            }
+            sign = v.signum();
+        }
+    }
+    // 3. If abs(years) ≥ 2**32, return false.
+    // 4. If abs(months) ≥ 2**32, return false.
+    // 5. If abs(weeks) ≥ 2**32, return false.
+    const TWO_POW_32: f64 = 4_294_967_296.0;
+    if duration.years.abs() >= TWO_POW_32
+        || duration.months.abs() >= TWO_POW_32
+        || duration.weeks.abs() >= TWO_POW_32
+    {
+        return false;
+    }
+    // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes ×
+    //    60 + seconds + ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) ×
+    //    10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+    // 7. NOTE: The above step cannot be implemented directly using
+    //    floating-point arithmetic.
+    // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+    // NOTE: All the fields have the same sign, so if any of the fields alone
+    // is at least 2**53 seconds, so is normalizedSeconds.
+    const TWO_POW_53: f64 = 9_007_199_254_740_992.0;
+    if duration.days.abs() >= TWO_POW_53
+        || duration.hours.abs() >= TWO_POW_53
+        || duration.minutes.abs() >= TWO_POW_53
+        || duration.seconds.abs() >= TWO_POW_53
+        || duration.milliseconds.abs() >= TWO_POW_53 * 1e3
+        || duration.microseconds.abs() >= TWO_POW_53 * 1e6
+        || duration.nanoseconds.abs() >= TWO_POW_53 * 1e9
+    {
+        return false;
+    }
+    let normalized_nanoseconds = time_duration_from_components(
+        duration.days * 24.0 + duration.hours,
+        duration.minutes,
+        duration.seconds,
+        duration.milliseconds,
+        duration.microseconds,
+        duration.nanoseconds,
+    );
+    // 9. Return true.
+    normalized_nanoseconds.abs() <= MAX_TIME_DURATION
+}
+
+/// ### [7.5.12 DefaultTemporalLargestUnit ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-defaulttemporallargestunit)
+pub(crate) fn default_temporal_largest_unit(duration: &DurationRecord) -> Unit {
+    // 1. If duration.[[Years]] ≠ 0, return year.
+    // 2. If duration.[[Months]] ≠ 0, return month.
+    // 3. If duration.[[Weeks]] ≠ 0, return week.
+    // 4. If duration.[[Days]] ≠ 0, return day.
+    // 5. If duration.[[Hours]] ≠ 0, return hour.
+    // 6. If duration.[[Minutes]] ≠ 0, return minute.
+    // 7. If duration.[[Seconds]] ≠ 0, return second.
+    // 8. If duration.[[Milliseconds]] ≠ 0, return millisecond.
+    // 9. If duration.[[Microseconds]] ≠ 0, return microsecond.
+    // 10. Return nanosecond.
+    duration
+        .fields()
+        .into_iter()
+        .zip(Unit::ALL)
+        .find(|(value, _)| *value != 0.0)
+        .map_or(Unit::Nanosecond, |(_, unit)| unit)
+}
+
+/// ### [7.5.3 Internal Duration Records](https://tc39.es/proposal-temporal/#sec-temporal-internal-duration-records)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct InternalDuration {
+    /// \[\[Date]]
+    pub(crate) date: DateDuration,
+    /// \[\[Time]]
+    pub(crate) time: i128,
+}
+trivially_bindable!(InternalDuration);
+
+impl InternalDuration {
+    /// ### [7.5.6 CombineDateAndTimeDuration ( dateDuration, timeDuration )](https://tc39.es/proposal-temporal/#sec-temporal-combinedateandtimeduration)
+    pub(crate) fn new(date: DateDuration, time: i128) -> Self {
+        // 1. Let dateSign be DateDurationSign(dateDuration).
+        // 2. Let timeSign be TimeDurationSign(timeDuration).
+        // 3. Assert: If dateSign ≠ 0 and timeSign ≠ 0, dateSign = timeSign.
+        debug_assert!(date.sign() == 0 || time == 0 || date.sign() == time.signum() as i64);
+        // 4. Return Internal Duration Record { [[Date]]: dateDuration,
+        //    [[Time]]: timeDuration }.
+        Self { date, time }
+    }
+
+    /// ### [7.5.14 InternalDurationSign ( internalDuration )](https://tc39.es/proposal-temporal/#sec-temporal-internaldurationsign)
+    pub(crate) fn sign(self) -> i64 {
+        // 1. Let dateSign be DateDurationSign(internalDuration.[[Date]]).
+        let date_sign = self.date.sign();
+        // 2. If dateSign ≠ 0, return dateSign.
+        if date_sign != 0 {
+            return date_sign;
+        }
+        // 3. Return TimeDurationSign(internalDuration.[[Time]]).
+        self.time.signum() as i64
+    }
+}
+
+/// ### [7.5.4 ToInternalDurationRecord ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecord)
+pub(crate) fn to_internal_duration_record(duration: &DurationRecord) -> InternalDuration {
+    // 1. Let dateDuration be ! CreateDateDurationRecord(duration.[[Years]],
+    //    duration.[[Months]], duration.[[Weeks]], duration.[[Days]]).
+    let date_duration = years_months_weeks(duration);
+    // 2. Let timeDuration be TimeDurationFromComponents(duration.[[Hours]],
+    //    duration.[[Minutes]], duration.[[Seconds]],
+    //    duration.[[Milliseconds]], duration.[[Microseconds]],
+    //    duration.[[Nanoseconds]]).
+    let time_duration = time_duration_from_components(
+        duration.hours,
+        duration.minutes,
+        duration.seconds,
+        duration.milliseconds,
+        duration.microseconds,
+        duration.nanoseconds,
+    );
+    // 3. Return CombineDateAndTimeDuration(dateDuration, timeDuration).
+    InternalDuration::new(
+        DateDuration {
+            days: duration.days as i64,
+            ..date_duration
+        },
+        time_duration,
+    )
+}
+
+/// ### [7.5.5 ToInternalDurationRecordWith24HourDays ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecordwith24hourdays)
+pub(crate) fn to_internal_duration_record_with_24_hour_days(
+    duration: &DurationRecord,
+) -> InternalDuration {
+    // 1. Let timeDuration be TimeDurationFromComponents(duration.[[Hours]],
+    //    duration.[[Minutes]], duration.[[Seconds]],
+    //    duration.[[Milliseconds]], duration.[[Microseconds]],
+    //    duration.[[Nanoseconds]]).
+    // 2. Set timeDuration to ! Add24HourDaysToTimeDuration(timeDuration,
+    //    duration.[[Days]]).
+    let time_duration = time_duration_from_components(
+        duration.days * 24.0 + duration.hours,
+        duration.minutes,
+        duration.seconds,
+        duration.milliseconds,
+        duration.microseconds,
+        duration.nanoseconds,
+    );
+    // 3. Let dateDuration be ! CreateDateDurationRecord(duration.[[Years]],
+    //    duration.[[Months]], duration.[[Weeks]], 0).
+    // 4. Return CombineDateAndTimeDuration(dateDuration, timeDuration).
+    InternalDuration::new(years_months_weeks(duration), time_duration)
+}
+
+/// The years, months and weeks of a duration, with zero days.
+fn years_months_weeks(duration: &DurationRecord) -> DateDuration {
+    DateDuration {
+        years: duration.years as i64,
+        months: duration.months as i64,
+        weeks: duration.weeks as i64,
+        days: 0,
+    }
+}
+
+/// ### [7.5.7 ToDateDurationRecordWithoutTime ( duration )](https://tc39.es/proposal-temporal/#sec-temporal-todatedurationrecordwithouttime)
+pub(crate) fn to_date_duration_record_without_time(
+    duration: &DurationRecord,
+) -> TemporalResult<DateDuration> {
+    // 1. Let internalDuration be ToInternalDurationRecordWith24HourDays(
+    //    duration).
+    let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+    // 2. Let days be truncate(internalDuration.[[Time]] / nsPerDay).
+    let days = (internal_duration.time / NS_PER_DAY) as i64;
+    // 3. Return ? CreateDateDurationRecord(
+    //    internalDuration.[[Date]].[[Years]],
+    //    internalDuration.[[Date]].[[Months]],
+    //    internalDuration.[[Date]].[[Weeks]], days).
+    let date = internal_duration.date;
+    create_date_duration_record(date.years, date.months, date.weeks, days)
+}
+
+/// ### [7.5.8 TemporalDurationFromInternal ( internalDuration, largestUnit )](https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal)
+pub(crate) fn temporal_duration_from_internal(
+    internal_duration: InternalDuration,
+    largest_unit: Unit,
+) -> TemporalResult<DurationRecord> {
+    // 1. Let days, hours, minutes, seconds, milliseconds, and microseconds
+    //    be 0.
+    let mut days = 0;
+    let mut hours = 0;
+    let mut minutes = 0;
+    let mut seconds = 0;
+    let mut milliseconds = 0;
+    let mut microseconds = 0;
+    // 2. Let sign be TimeDurationSign(internalDuration.[[Time]]).
+    let sign = internal_duration.time.signum();
+    // 3. Let nanoseconds be abs(internalDuration.[[Time]]).
+    let mut nanoseconds = internal_duration.time.abs();
+    match largest_unit {
+        // 4. If TemporalUnitCategory(largestUnit) is date, then
+        Unit::Year | Unit::Month | Unit::Week | Unit::Day => {
+            // a. Set microseconds to floor(nanoseconds / 1000).
+            microseconds = nanoseconds / 1000;
+            // b. Set nanoseconds to nanoseconds modulo 1000.
+            nanoseconds %= 1000;
+            // c. Set milliseconds to floor(microseconds / 1000).
+            milliseconds = microseconds / 1000;
+            // d. Set microseconds to microseconds modulo 1000.
+            microseconds %= 1000;
+            // e. Set seconds to floor(milliseconds / 1000).
+            seconds = milliseconds / 1000;
+            // f. Set milliseconds to milliseconds modulo 1000.
+            milliseconds %= 1000;
+            // g. Set minutes to floor(seconds / 60).
+            minutes = seconds / 60;
+            // h. Set seconds to seconds modulo 60.
+            seconds %= 60;
+            // i. Set hours to floor(minutes / 60).
+            hours = minutes / 60;
+            // j. Set minutes to minutes modulo 60.
+            minutes %= 60;
+            // k. Set days to floor(hours / 24).
+            days = hours / 24;
+            // l. Set hours to hours modulo 24.
+            hours %= 24;
+        }
+        // 5. Else if largestUnit is hour, then
+        Unit::Hour => {
+            microseconds = nanoseconds / 1000;
+            nanoseconds %= 1000;
+            milliseconds = microseconds / 1000;
+            microseconds %= 1000;
+            seconds = milliseconds / 1000;
+            milliseconds %= 1000;
+            minutes = seconds / 60;
+            seconds %= 60;
+            hours = minutes / 60;
+            minutes %= 60;
+        }
+        // 6. Else if largestUnit is minute, then
+        Unit::Minute => {
+            microseconds = nanoseconds / 1000;
+            nanoseconds %= 1000;
+            milliseconds = microseconds / 1000;
+            microseconds %= 1000;
+            seconds = milliseconds / 1000;
+            milliseconds %= 1000;
+            minutes = seconds / 60;
+            seconds %= 60;
+        }
+        // 7. Else if largestUnit is second, then
+        Unit::Second => {
+            microseconds = nanoseconds / 1000;
+            nanoseconds %= 1000;
+            milliseconds = microseconds / 1000;
+            microseconds %= 1000;
+            seconds = milliseconds / 1000;
+            milliseconds %= 1000;
+        }
+        // 8. Else if largestUnit is millisecond, then
+        Unit::Millisecond => {
+            microseconds = nanoseconds / 1000;
+            nanoseconds %= 1000;
+            milliseconds = microseconds / 1000;
+            microseconds %= 1000;
+        }
+        // 9. Else if largestUnit is microsecond, then
+        Unit::Microsecond => {
+            microseconds = nanoseconds / 1000;
+            nanoseconds %= 1000;
+        }
+        // 10. Else,
+        //     a. Assert: largestUnit is nanosecond.
+        Unit::Nanosecond => {}
+    }
+    // 11. NOTE: When largestUnit is millisecond, microsecond, or nanosecond,
+    //     milliseconds, microseconds, or nanoseconds may be an unsafe
+    //     integer. In this case, care must be taken when implementing the
+    //     calculation using floating point arithmetic. It can be implemented
+    //     in C++ using std::fma(). String manipulation will also give an
+    //     exact result, since the multiplication is by a power of 10.
+    // 12. Return ? CreateTemporalDuration(internalDuration.[[Date]].[[Years]],
+    //     internalDuration.[[Date]].[[Months]],
+    //     internalDuration.[[Date]].[[Weeks]],
+    //     internalDuration.[[Date]].[[Days]] + days × sign, hours × sign,
+    //     minutes × sign, seconds × sign, milliseconds × sign, microseconds
+    //     × sign, nanoseconds × sign).
+    let date = internal_duration.date;
+    let duration = DurationRecord {
+        years: date.years as f64,
+        months: date.months as f64,
+        weeks: date.weeks as f64,
+        days: (i128::from(date.days) + days * sign) as f64,
+        hours: (hours * sign) as f64,
+        minutes: (minutes * sign) as f64,
+        seconds: (seconds * sign) as f64,
+        milliseconds: (milliseconds * sign) as f64,
+        microseconds: (microseconds * sign) as f64,
+        nanoseconds: (nanoseconds * sign) as f64,
+    };
+    if !is_valid_duration(&duration) {
+        return Err(RangeError("duration is out of range"));
+    }
+    Ok(duration)
+}
+
+/// ### [7.5.9 CreateDateDurationRecord ( years, months, weeks, days )](https://tc39.es/proposal-temporal/#sec-temporal-createdatedurationrecord)
+pub(crate) fn create_date_duration_record(
+    years: i64,
+    months: i64,
+    weeks: i64,
+    days: i64,
+) -> TemporalResult<DateDuration> {
+    // 1. If IsValidDuration(years, months, weeks, days, 0, 0, 0, 0, 0, 0) is
+    //    false, throw a RangeError exception.
+    let duration = DurationRecord {
+        years: years as f64,
+        months: months as f64,
+        weeks: weeks as f64,
+        days: days as f64,
+        ..Default::default()
+    };
+    if !is_valid_duration(&duration) {
+        return Err(RangeError("duration is out of range"));
+    }
+    // 2. Return Date Duration Record { [[Years]]: ℝ(𝔽(years)), [[Months]]:
+    //    ℝ(𝔽(months)), [[Weeks]]: ℝ(𝔽(weeks)), [[Days]]: ℝ(𝔽(days)) }.
+    Ok(DateDuration {
+        years,
+        months,
+        weeks,
+        days,
+    })
+}
+
+/// ### [7.5.10 AdjustDateDurationRecord ( dateDuration, days \[ , weeks \[ , months \] \] )](https://tc39.es/proposal-temporal/#sec-temporal-adjustdatedurationrecord)
+pub(crate) fn adjust_date_duration_record(
+    date_duration: DateDuration,
+    days: i64,
+    weeks: Option<i64>,
+    months: Option<i64>,
+) -> TemporalResult<DateDuration> {
+    // 1. If weeks is not present, set weeks to dateDuration.[[Weeks]].
+    // 2. If months is not present, set months to dateDuration.[[Months]].
+    // 3. Return ? CreateDateDurationRecord(dateDuration.[[Years]], months,
+    //    weeks, days).
+    create_date_duration_record(
+        date_duration.years,
+        months.unwrap_or(date_duration.months),
+        weeks.unwrap_or(date_duration.weeks),
+        days,
+    )
+}
+
+/// ### [7.5.15 TimeDurationFromComponents ( hours, minutes, seconds, milliseconds, microseconds, nanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-timedurationfromcomponents)
+pub(crate) fn time_duration_from_components(
+    hours: f64,
+    minutes: f64,
+    seconds: f64,
+    milliseconds: f64,
+    microseconds: f64,
+    nanoseconds: f64,
+) -> i128 {
+    // 1. Set minutes to minutes + hours × 60.
+    // 2. Set seconds to seconds + minutes × 60.
+    // 3. Set milliseconds to milliseconds + seconds × 1000.
+    // 4. Set microseconds to microseconds + milliseconds × 1000.
+    // 5. Set nanoseconds to nanoseconds + microseconds × 1000.
+    // 6. Assert: abs(nanoseconds) ≤ maxTimeDuration.
+    // 7. Return nanoseconds.
+    hours as i128 * 3_600_000_000_000
+        + minutes as i128 * 60_000_000_000
+        + seconds as i128 * 1_000_000_000
+        + milliseconds as i128 * 1_000_000
+        + microseconds as i128 * 1_000
+        + nanoseconds as i128
+}
+
+/// ### [7.5.16 AddTimeDuration ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal-addtimeduration)
+pub(crate) fn add_time_duration(one: i128, two: i128) -> TemporalResult<i128> {
+    // 1. Let result be one + two.
+    let result = one + two;
+    // 2. If abs(result) > maxTimeDuration, throw a RangeError exception.
+    if result.abs() > MAX_TIME_DURATION {
+        return Err(RangeError("duration is out of range"));
+    }
+    // 3. Return result.
+    Ok(result)
+}
+
+/// ### [7.5.17 Add24HourDaysToTimeDuration ( d, days )](https://tc39.es/proposal-temporal/#sec-temporal-add24hourdaystonormalizedtimeduration)
+pub(crate) fn add_24_hour_days_to_time_duration(d: i128, days: i64) -> TemporalResult<i128> {
+    // 1. Let result be d + days × nsPerDay.
+    // 2. If abs(result) > maxTimeDuration, throw a RangeError exception.
+    // 3. Return result.
+    add_time_duration(d, i128::from(days) * NS_PER_DAY)
+}
+
+/// ### [7.5.18 AddTimeDurationToEpochNanoseconds ( d, epochNs )](https://tc39.es/proposal-temporal/#sec-temporal-addtimedurationtoepochnanoseconds)
+pub(crate) fn add_time_duration_to_epoch_nanoseconds(d: i128, epoch_ns: i128) -> i128 {
+    // 1. Return epochNs + ℤ(d).
+    epoch_ns + d
+}
+
+/// ### [7.5.21 RoundTimeDurationToIncrement ( d, increment, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundtimedurationtoincrement)
+pub(crate) fn round_time_duration_to_increment(
+    d: i128,
+    increment: i128,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<i128> {
+    // 1. Let rounded be RoundNumberToIncrement(d, increment, roundingMode).
+    let rounded = round_number_to_increment(d, increment, rounding_mode);
+    // 2. If abs(rounded) > maxTimeDuration, throw a RangeError exception.
+    if rounded.abs() > MAX_TIME_DURATION {
+        return Err(RangeError("duration is out of range"));
+    }
+    // 3. Return rounded.
+    Ok(rounded)
+}
+
+/// ### [7.5.24 RoundTimeDuration ( timeDuration, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundtimeduration)
+pub(crate) fn round_time_duration(
+    time_duration: i128,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<i128> {
+    // 1. Let divisor be the value in the "Length in Nanoseconds" column of
+    //    the row of Table 21 whose "Value" column contains unit.
+    let divisor = unit.length_in_nanoseconds();
+    // 2. Return ? RoundTimeDurationToIncrement(timeDuration, divisor ×
+    //    increment, roundingMode).
+    round_time_duration_to_increment(
+        time_duration,
+        divisor * i128::from(increment),
+        rounding_mode,
+    )
+}
+
+/// ### [7.5.25 TotalTimeDuration ( timeDuration, unit )](https://tc39.es/proposal-temporal/#sec-temporal-totaltimeduration)
+pub(crate) fn total_time_duration(time_duration: i128, unit: Unit) -> f64 {
+    // 1. Let divisor be the value in the "Length in Nanoseconds" column of
+    //    the row of Table 21 whose "Value" column contains unit.
+    let divisor = unit.length_in_nanoseconds();
+    // 2. NOTE: The following step cannot be implemented directly using
+    //    floating-point arithmetic when 𝔽(timeDuration) is not a safe
+    //    integer. The division can be implemented in C++ with the
+    //    __float128 type if the compiler supports it, or with software
+    //    emulation such as in the SoftFP library.
+    // 3. Return timeDuration / divisor.
+    divide_exactly(time_duration, divisor)
+}
+
+/// The Number closest to the quotient of two integers.
+fn divide_exactly(dividend: i128, divisor: i128) -> f64 {
+    let quotient = dividend / divisor;
+    let remainder = dividend % divisor;
+    if remainder == 0 {
+        return quotient as f64;
+    }
+    // The integral part of the quotient is exact if it is a safe integer, so
+    // adding the fraction only rounds once.
+    quotient as f64 + remainder as f64 / divisor as f64
+}
+
+/// ### [7.5.35 Duration Nudge Result Records](https://tc39.es/proposal-temporal/#sec-temporal-duration-nudge-result-records)
+#[derive(Debug, Clone, Copy)]
+struct DurationNudgeResult {
+    /// \[\[Duration]]
+    duration: InternalDuration,
+    /// \[\[NudgedEpochNs]]
+    nudged_epoch_ns: i128,
+    /// \[\[DidExpandCalendarUnit]]
+    did_expand_calendar_unit: bool,
+}
+
+/// The epoch nanoseconds of a date-time in the time zone, or in UTC if the
+/// time zone is unset.
+fn epoch_nanoseconds_of(
+    time_zone: Option<&TimeZone>,
+    iso_date_time: IsoDateTime,
+) -> TemporalResult<i128> {
+    match time_zone {
+        None => Ok(iso_date_time.to_epoch_nanoseconds()),
+        Some(time_zone) => {
+            get_epoch_nanoseconds_for(time_zone, iso_date_time, Disambiguation::Compatible)
+        }
+    }
+}
+
+/// ### [7.5.36 NudgeToCalendarUnit ( sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-nudgetocalendarunit)
+///
+/// Returns the nudge result and the total.
+#[allow(clippy::too_many_arguments)]
+fn nudge_to_calendar_unit(
+    sign: i64,
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    iso_date_time: IsoDateTime,
+    time_zone: Option<&TimeZone>,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<(DurationNudgeResult, f64)> {
+    let increment = i64::from(increment);
+    let date = duration.date;
+    // 1. Let didExpandCalendarUnit be false.
+    let mut did_expand_calendar_unit = false;
+    let truncate = |value: i64| {
+        round_number_to_increment(value.into(), increment.into(), RoundingMode::Trunc) as i64
+    };
+    let (r1, r2, start_duration, end_duration) = match unit {
+        // 2. If unit is year, then
+        Unit::Year => {
+            // a. Let years be RoundNumberToIncrement(duration.[[Date]].[[Years]],
+            //    increment, trunc).
+            let years = truncate(date.years);
+            // b. Let r1 be years.
+            // c. Let r2 be years + increment × sign.
+            let r1 = years;
+            let r2 = years + increment * sign;
+            // d. Let startDuration be ? CreateDateDurationRecord(r1, 0, 0, 0).
+            // e. Let endDuration be ? CreateDateDurationRecord(r2, 0, 0, 0).
+            (
+                r1,
+                r2,
+                create_date_duration_record(r1, 0, 0, 0)?,
+                create_date_duration_record(r2, 0, 0, 0)?,
+            )
+        }
+        // 3. Else if unit is month, then
+        Unit::Month => {
+            // a. Let months be RoundNumberToIncrement(
+            //    duration.[[Date]].[[Months]], increment, trunc).
+            let months = truncate(date.months);
+            // b. Let r1 be months.
+            // c. Let r2 be months + increment × sign.
+            let r1 = months;
+            let r2 = months + increment * sign;
+            // d. Let startDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], 0, 0, r1).
+            // e. Let endDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], 0, 0, r2).
+            (
+                r1,
+                r2,
+                adjust_date_duration_record(date, 0, Some(0), Some(r1))?,
+                adjust_date_duration_record(date, 0, Some(0), Some(r2))?,
+            )
+        }
+        // 4. Else if unit is week, then
+        Unit::Week => {
+            // a. Let yearsMonths be ! AdjustDateDurationRecord(
+            //    duration.[[Date]], 0, 0).
+            let years_months = adjust_date_duration_record(date, 0, Some(0), None)?;
+            // b. Let weeksStart be ? CalendarDateAdd(calendar,
+            //    isoDateTime.[[ISODate]], yearsMonths, constrain).
+            let weeks_start =
+                calendar_date_add(iso_date_time.date, years_months, Overflow::Constrain)?;
+            // c. Let weeksEnd be BalanceISODate(weeksStart.[[Year]],
+            //    weeksStart.[[Month]], weeksStart.[[Day]] +
+            //    duration.[[Date]].[[Days]]).
+            let weeks_end = balance_iso_date(
+                i64::from(weeks_start.year),
+                weeks_start.month,
+                i64::from(weeks_start.day) + date.days,
+            );
+            // d. Let untilResult be CalendarDateUntil(calendar, weeksStart,
+            //    weeksEnd, week).
+            let until_result = calendar_date_until(weeks_start, weeks_end, Unit::Week);
+            // e. Let weeks be RoundNumberToIncrement(
+            //    duration.[[Date]].[[Weeks]] + untilResult.[[Weeks]],
+            //    increment, trunc).
+            let weeks = truncate(date.weeks + until_result.weeks);
+            // f. Let r1 be weeks.
+            // g. Let r2 be weeks + increment × sign.
+            let r1 = weeks;
+            let r2 = weeks + increment * sign;
+            // h. Let startDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], 0, r1).
+            // i. Let endDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], 0, r2).
+            (
+                r1,
+                r2,
+                adjust_date_duration_record(date, 0, Some(r1), None)?,
+                adjust_date_duration_record(date, 0, Some(r2), None)?,
+            )
+        }
+        // 5. Else,
+        _ => {
+            // a. Assert: unit is day.
+            debug_assert_eq!(unit, Unit::Day);
+            // b. Let days be RoundNumberToIncrement(duration.[[Date]].[[Days]],
+            //    increment, trunc).
+            let days = truncate(date.days);
+            // c. Let r1 be days.
+            // d. Let r2 be days + increment × sign.
+            let r1 = days;
+            let r2 = days + increment * sign;
+            // e. Let startDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], r1).
+            // f. Let endDuration be ? AdjustDateDurationRecord(
+            //    duration.[[Date]], r2).
+            (
+                r1,
+                r2,
+                adjust_date_duration_record(date, r1, None, None)?,
+                adjust_date_duration_record(date, r2, None, None)?,
+            )
+        }
+    };
+    // 6. Assert: If sign is 1, r1 ≥ 0 and r1 < r2.
+    // 7. Assert: If sign is -1, r1 ≤ 0 and r1 > r2.
+    debug_assert!(if sign == 1 {
+        r1 >= 0 && r1 < r2
+    } else {
+        r1 <= 0 && r1 > r2
+    });
+    // 8. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]],
+    //    startDuration, constrain).
+    let start = calendar_date_add(iso_date_time.date, start_duration, Overflow::Constrain)?;
+    // 9. Let end be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]],
+    //    endDuration, constrain).
+    let end = calendar_date_add(iso_date_time.date, end_duration, Overflow::Constrain)?;
+    // 10. Let startDateTime be CombineISODateAndTimeRecord(start,
+    //     isoDateTime.[[Time]]).
+    let start_date_time = IsoDateTime::new(start, iso_date_time.time);
+    // 11. Let endDateTime be CombineISODateAndTimeRecord(end,
+    //     isoDateTime.[[Time]]).
+    let end_date_time = IsoDateTime::new(end, iso_date_time.time);
+    // 12. If timeZone is unset, then
+    //     a. Let startEpochNs be GetUTCEpochNanoseconds(startDateTime).
+    //     b. Let endEpochNs be GetUTCEpochNanoseconds(endDateTime).
+    // 13. Else,
+    //     a. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone,
+    //        startDateTime, compatible).
+    //     b. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone,
+    //        endDateTime, compatible).
+    let start_epoch_ns = epoch_nanoseconds_of(time_zone, start_date_time)?;
+    let end_epoch_ns = epoch_nanoseconds_of(time_zone, end_date_time)?;
+    // 14. If sign is 1, then
+    //     a. If startEpochNs > destEpochNs or destEpochNs ≥ endEpochNs, throw
+    //        a RangeError exception.
+    // 15. Else,
+    //     a. If endEpochNs ≥ destEpochNs or destEpochNs > startEpochNs, throw
+    //        a RangeError exception.
+    let in_range = if sign == 1 {
+        start_epoch_ns <= dest_epoch_ns && dest_epoch_ns < end_epoch_ns
+    } else {
+        end_epoch_ns < dest_epoch_ns && dest_epoch_ns <= start_epoch_ns
+    };
+    // 16. If endEpochNs = startEpochNs, throw a RangeError exception.
+    if !in_range || end_epoch_ns == start_epoch_ns {
+        return Err(RangeError("cannot round the duration to the calendar unit"));
+    }
+    // 17. Let progress be (destEpochNs - startEpochNs) / (endEpochNs -
+    //     startEpochNs).
+    let progress_numerator = (dest_epoch_ns - start_epoch_ns).abs();
+    let progress_denominator = (end_epoch_ns - start_epoch_ns).abs();
+    let progress = progress_numerator as f64 / progress_denominator as f64;
+    // 18. Let total be r1 + progress × increment × sign.
+    // 19. NOTE: The above two steps cannot be implemented directly using
+    //     floating-point arithmetic. This division can be implemented as if
+    //     expressing the denominator and numerator of total as two time
+    //     durations, and performing one division operation with a floating-
+    //     point result.
+    let total = r1 as f64
+        + divide_exactly(
+            progress_numerator * i128::from(increment),
+            progress_denominator,
+        ) * sign as f64;
+    // 20. Assert: 0 ≤ progress ≤ 1.
+    debug_assert!((0.0..=1.0).contains(&progress));
+    // 21. If sign < 0, let isNegative be negative.
+    // 22. Else, let isNegative be positive.
+    // 23. Let unsignedRoundingMode be GetUnsignedRoundingMode(roundingMode,
+    //     isNegative).
+    let unsigned_rounding_mode = get_unsigned_rounding_mode(rounding_mode, sign < 0);
+    // 24. If progress = 1, then
+    //     a. Let roundedUnit be abs(r2).
+    // 25. Else,
+    //     a. Assert: abs(r1) ≤ abs(total) < abs(r2).
+    //     b. Let roundedUnit be ApplyUnsignedRoundingMode(abs(total),
+    //        abs(r1), abs(r2), unsignedRoundingMode).
+    let rounds_to_r2 = progress_numerator == progress_denominator
+        || apply_unsigned_rounding_mode(
+            i128::from(r1.abs() / increment),
+            progress_numerator,
+            progress_denominator,
+            unsigned_rounding_mode,
+        );
+    // 26. If roundedUnit - abs(r2) = 0, then
+    let (result_duration, nudged_epoch_ns) = if rounds_to_r2 {
+        // a. Let didExpandCalendarUnit be true.
+        did_expand_calendar_unit = true;
+        // b. Let resultDuration be endDuration.
+        // c. Let nudgedEpochNs be endEpochNs.
+        (end_duration, end_epoch_ns)
+    } else {
+        // 27. Else,
+        //     a. Let resultDuration be startDuration.
+        //     b. Let nudgedEpochNs be startEpochNs.
+        (start_duration, start_epoch_ns)
+    };
+    // 28. Set resultDuration to CombineDateAndTimeDuration(resultDuration, 0).
+    // 29. Let nudgeResult be Duration Nudge Result Record { [[Duration]]:
+    //     resultDuration, [[NudgedEpochNs]]: nudgedEpochNs,
+    //     [[DidExpandCalendarUnit]]: didExpandCalendarUnit }.
+    let nudge_result = DurationNudgeResult {
+        duration: InternalDuration::new(result_duration, 0),
+        nudged_epoch_ns,
+        did_expand_calendar_unit,
+    };
+    // 30. Return the Record { [[NudgeResult]]: nudgeResult, [[Total]]: total }.
+    Ok((nudge_result, total))
+}
+
+/// ### [7.5.37 NudgeToZonedTime ( sign, duration, isoDateTime, timeZone, calendar, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-nudgetozonedtime)
+#[allow(clippy::too_many_arguments)]
+fn nudge_to_zoned_time(
+    sign: i64,
+    duration: InternalDuration,
+    iso_date_time: IsoDateTime,
+    time_zone: &TimeZone,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<DurationNudgeResult> {
+    // 1. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]],
+    //    duration.[[Date]], constrain).
+    let start = calendar_date_add(iso_date_time.date, duration.date, Overflow::Constrain)?;
+    // 2. Let startDateTime be CombineISODateAndTimeRecord(start,
+    //    isoDateTime.[[Time]]).
+    let start_date_time = IsoDateTime::new(start, iso_date_time.time);
+    // 3. Let endDate be BalanceISODate(start.[[Year]], start.[[Month]],
+    //    start.[[Day]] + sign).
+    let end_date = balance_iso_date(
+        i64::from(start.year),
+        start.month,
+        i64::from(start.day) + sign,
+    );
+    // 4. Let endDateTime be CombineISODateAndTimeRecord(endDate,
+    //    isoDateTime.[[Time]]).
+    let end_date_time = IsoDateTime::new(end_date, iso_date_time.time);
+    // 5. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone,
+    //    startDateTime, compatible).
+    let start_epoch_ns =
+        get_epoch_nanoseconds_for(time_zone, start_date_time, Disambiguation::Compatible)?;
+    // 6. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime,
+    //    compatible).
+    let end_epoch_ns =
+        get_epoch_nanoseconds_for(time_zone, end_date_time, Disambiguation::Compatible)?;
+    // 7. Let daySpan be TimeDurationFromEpochNanosecondsDifference(
+    //    endEpochNs, startEpochNs).
+    let day_span = end_epoch_ns - start_epoch_ns;
+    // 8. Assert: TimeDurationSign(daySpan) = sign.
+    debug_assert_eq!(day_span.signum() as i64, sign);
+    // 9. Let unitLength be the value in the "Length in Nanoseconds" column
+    //    of the row of Table 21 whose "Value" column contains unit.
+    let unit_length = unit.length_in_nanoseconds();
+    // 10. Let roundedTimeDuration be ? RoundTimeDurationToIncrement(
+    //     duration.[[Time]], increment × unitLength, roundingMode).
+    let mut rounded_time_duration = round_time_duration_to_increment(
+        duration.time,
+        i128::from(increment) * unit_length,
+        rounding_mode,
+    )?;
+    // 11. Let beyondDaySpan be ! AddTimeDuration(roundedTimeDuration,
+    //     -daySpan).
+    let beyond_day_span = rounded_time_duration - day_span;
+    // 12. If TimeDurationSign(beyondDaySpan) ≠ -sign, then
+    let (did_round_beyond_day, day_delta, nudged_epoch_ns) =
+        if beyond_day_span.signum() as i64 != -sign {
+            // a. Let didRoundBeyondDay be true.
+            // b. Let dayDelta be sign.
+            // c. Set roundedTimeDuration to ? RoundTimeDurationToIncrement(
+            //    beyondDaySpan, increment × unitLength, roundingMode).
+            rounded_time_duration = round_time_duration_to_increment(
+                beyond_day_span,
+                i128::from(increment) * unit_length,
+                rounding_mode,
+            )?;
+            // d. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(
+            //    roundedTimeDuration, endEpochNs).
+            (
+                true,
+                sign,
+                add_time_duration_to_epoch_nanoseconds(rounded_time_duration, end_epoch_ns),
+            )
+        } else {
+            // 13. Else,
+            //     a. Let didRoundBeyondDay be false.
+            //     b. Let dayDelta be 0.
+            //     c. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(
+            //        roundedTimeDuration, startEpochNs).
+            (
+                false,
+                0,
+                add_time_duration_to_epoch_nanoseconds(rounded_time_duration, start_epoch_ns),
+            )
+        };
+    // 14. Let dateDuration be ? AdjustDateDurationRecord(duration.[[Date]],
+    //     duration.[[Date]].[[Days]] + dayDelta).
+    let date_duration =
+        adjust_date_duration_record(duration.date, duration.date.days + day_delta, None, None)?;
+    // 15. Let resultDuration be CombineDateAndTimeDuration(dateDuration,
+    //     roundedTimeDuration).
+    // 16. Return Duration Nudge Result Record { [[Duration]]: resultDuration,
+    //     [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]:
+    //     didRoundBeyondDay }.
+    Ok(DurationNudgeResult {
+        duration: InternalDuration::new(date_duration, rounded_time_duration),
+        nudged_epoch_ns,
+        did_expand_calendar_unit: did_round_beyond_day,
+    })
+}
+
+/// ### [7.5.38 NudgeToDayOrTime ( duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-nudgetodayortime)
+fn nudge_to_day_or_time(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    largest_unit: Unit,
+    increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<DurationNudgeResult> {
+    // 1. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]],
+    //    duration.[[Date]].[[Days]]).
+    let time_duration = add_24_hour_days_to_time_duration(duration.time, duration.date.days)?;
+    // 2. Let unitLength be the value in the "Length in Nanoseconds" column
+    //    of the row of Table 21 whose "Value" column contains smallestUnit.
+    let unit_length = smallest_unit.length_in_nanoseconds();
+    // 3. Let roundedTime be ? RoundTimeDurationToIncrement(timeDuration,
+    //    unitLength × increment, roundingMode).
+    let rounded_time = round_time_duration_to_increment(
+        time_duration,
+        unit_length * i128::from(increment),
+        rounding_mode,
+    )?;
+    // 4. Let diffTime be ! AddTimeDuration(roundedTime, -timeDuration).
+    let diff_time = rounded_time - time_duration;
+    // 5. Let wholeDays be truncate(TotalTimeDuration(timeDuration, day)).
+    let whole_days = time_duration / NS_PER_DAY;
+    // 6. Let roundedWholeDays be truncate(TotalTimeDuration(roundedTime,
+    //    day)).
+    let rounded_whole_days = rounded_time / NS_PER_DAY;
+    // 7. Let dayDelta be roundedWholeDays - wholeDays.
+    let day_delta = rounded_whole_days - whole_days;
+    // 8. If dayDelta < 0, let dayDeltaSign be -1; else if dayDelta > 0, let
+    //    dayDeltaSign be 1; else let dayDeltaSign be 0.
+    // 9. If dayDeltaSign = TimeDurationSign(timeDuration), let didExpandDays
+    //    be true; else let didExpandDays be false.
+    let did_expand_days = day_delta.signum() == time_duration.signum();
+    // 10. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(diffTime,
+    //     destEpochNs).
+    let nudged_epoch_ns = add_time_duration_to_epoch_nanoseconds(diff_time, dest_epoch_ns);
+    // 11. Let days be 0.
+    // 12. Let remainder be roundedTime.
+    // 13. If TemporalUnitCategory(largestUnit) is date, then
+    //     a. Set days to roundedWholeDays.
+    //     b. Set remainder to ! AddTimeDuration(roundedTime,
+    //        TimeDurationFromComponents(-roundedWholeDays × HoursPerDay, 0,
+    //        0, 0, 0, 0)).
+    let (days, remainder) = if largest_unit.is_date_unit() {
+        (
+            rounded_whole_days as i64,
+            rounded_time - rounded_whole_days * NS_PER_DAY,
+        )
+    } else {
+        (0, rounded_time)
+    };
+    // 14. Let dateDuration be ? AdjustDateDurationRecord(duration.[[Date]],
+    //     days).
+    let date_duration = adjust_date_duration_record(duration.date, days, None, None)?;
+    // 15. Let resultDuration be CombineDateAndTimeDuration(dateDuration,
+    //     remainder).
+    // 16. Return Duration Nudge Result Record { [[Duration]]: resultDuration,
+    //     [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]:
+    //     didExpandDays }.
+    Ok(DurationNudgeResult {
+        duration: InternalDuration::new(date_duration, remainder),
+        nudged_epoch_ns,
+        did_expand_calendar_unit: did_expand_days,
+    })
+}
+
+/// ### [7.5.39 BubbleRelativeDuration ( sign, duration, nudgedEpochNs, isoDateTime, timeZone, calendar, largestUnit, smallestUnit )](https://tc39.es/proposal-temporal/#sec-temporal-bubblerelativeduration)
+#[allow(clippy::too_many_arguments)]
+fn bubble_relative_duration(
+    sign: i64,
+    mut duration: InternalDuration,
+    nudged_epoch_ns: i128,
+    iso_date_time: IsoDateTime,
+    time_zone: Option<&TimeZone>,
+    largest_unit: Unit,
+    smallest_unit: Unit,
+) -> TemporalResult<InternalDuration> {
+    // 1. If smallestUnit is largestUnit, return duration.
+    if smallest_unit == largest_unit {
+        return Ok(duration);
+    }
+    // 2. Let largestUnitIndex be the ordinal index of the row of Table 21
+    //    whose "Value" column contains largestUnit.
+    let largest_unit_index = largest_unit as usize;
+    // 3. Let smallestUnitIndex be the ordinal index of the row of Table 21
+    //    whose "Value" column contains smallestUnit.
+    let smallest_unit_index = smallest_unit as usize;
+    // 4. Let unitIndex be smallestUnitIndex - 1.
+    // 5. Let done be false.
+    // 6. Repeat, while unitIndex ≥ largestUnitIndex and done is false,
+    for unit_index in (largest_unit_index..smallest_unit_index).rev() {
+        // a. Let unit be the value in the "Value" column of Table 21 in the
+        //    row whose ordinal index is unitIndex.
+        let unit = Unit::ALL[unit_index];
+        // b. If unit is not week, or largestUnit is week, then
+        if unit == Unit::Week && largest_unit != Unit::Week {
+            continue;
+        }
+        let end_duration = match unit {
+            // i. If unit is year, then
+            //    1. Let years be duration.[[Date]].[[Years]] + sign.
+            //    2. Let endDuration be ? CreateDateDurationRecord(years, 0,
+            //       0, 0).
+            Unit::Year => create_date_duration_record(duration.date.years + sign, 0, 0, 0)?,
+            // ii. Else if unit is month, then
+            //     1. Let months be duration.[[Date]].[[Months]] + sign.
+            //     2. Let endDuration be ? AdjustDateDurationRecord(
+            //        duration.[[Date]], 0, 0, months).
+            Unit::Month => adjust_date_duration_record(
+                duration.date,
+                0,
+                Some(0),
+                Some(duration.date.months + sign),
+            )?,
+            // iii. Else,
+            //      1. Assert: unit is week.
+            //      2. Let weeks be duration.[[Date]].[[Weeks]] + sign.
+            //      3. Let endDuration be ? AdjustDateDurationRecord(
+            //         duration.[[Date]], 0, weeks).
+            Unit::Week => adjust_date_duration_record(
+                duration.date,
+                0,
+                Some(duration.date.weeks + sign),
+                None,
+            )?,
+            // NOTE: Days are only bubbled up into weeks and larger units.
+            _ => continue,
+        };
+        // iv. Let end be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]],
+        //     endDuration, constrain).
+        let end = calendar_date_add(iso_date_time.date, end_duration, Overflow::Constrain)?;
+        // v. Let endDateTime be CombineISODateAndTimeRecord(end,
+        //    isoDateTime.[[Time]]).
+        let end_date_time = IsoDateTime::new(end, iso_date_time.time);
+        // vi. If timeZone is unset, then
+        //     1. Let endEpochNs be GetUTCEpochNanoseconds(endDateTime).
+        // vii. Else,
+        //      1. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone,
+        //         endDateTime, compatible).
+        let end_epoch_ns = epoch_nanoseconds_of(time_zone, end_date_time)?;
+        // viii. Let beyondEnd be nudgedEpochNs - endEpochNs.
+        let beyond_end = nudged_epoch_ns - end_epoch_ns;
+        // ix. If beyondEnd < 0, let beyondEndSign be -1; else if beyondEnd >
+        //     0, let beyondEndSign be 1; else let beyondEndSign be 0.
+        let beyond_end_sign = beyond_end.signum() as i64;
+        // x. If beyondEndSign ≠ -sign, then
+        if beyond_end_sign != -sign {
+            // 1. Set duration to CombineDateAndTimeDuration(endDuration, 0).
+            duration = InternalDuration::new(end_duration, 0);
+        } else {
+            // xi. Else,
+            //     1. Set done to true.
+            break;
+        }
+        // c. Set unitIndex to unitIndex - 1.
+    }
+    // 7. Return duration.
+    Ok(duration)
+}
+
+/// ### [7.5.40 RoundRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, largestUnit, increment, smallestUnit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundrelativeduration)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn round_relative_duration(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    iso_date_time: IsoDateTime,
+    time_zone: Option<&TimeZone>,
+    largest_unit: Unit,
+    increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<InternalDuration> {
+    // 1. Let irregularLengthUnit be false.
+    // 2. If IsCalendarUnit(smallestUnit) is true, set irregularLengthUnit to
+    //    true.
+    // 3. If timeZone is not unset and smallestUnit is day, set
+    //    irregularLengthUnit to true.
+    let irregular_length_unit =
+        smallest_unit.is_calendar_unit() || (time_zone.is_some() && smallest_unit == Unit::Day);
+    // 4. If InternalDurationSign(duration) < 0, let sign be -1; else let sign
+    //    be 1.
+    let sign = if duration.sign() < 0 { -1 } else { 1 };
+    let nudge_result = if irregular_length_unit {
+        // 5. If irregularLengthUnit is true, then
+        //    a. Let record be ? NudgeToCalendarUnit(sign, duration,
+        //       destEpochNs, isoDateTime, timeZone, calendar, increment,
+        //       smallestUnit, roundingMode).
+        //    b. Let nudgeResult be record.[[NudgeResult]].
+        nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_ns,
+            iso_date_time,
+            time_zone,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+        .0
+    } else if let Some(time_zone) = time_zone {
+        // 6. Else if timeZone is not unset, then
+        //    a. Let nudgeResult be ? NudgeToZonedTime(sign, duration,
+        //       isoDateTime, timeZone, calendar, increment, smallestUnit,
+        //       roundingMode).
+        nudge_to_zoned_time(
+            sign,
+            duration,
+            iso_date_time,
+            time_zone,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+    } else {
+        // 7. Else,
+        //    a. Let nudgeResult be ? NudgeToDayOrTime(duration, destEpochNs,
+        //       largestUnit, increment, smallestUnit, roundingMode).
+        nudge_to_day_or_time(
+            duration,
+            dest_epoch_ns,
+            largest_unit,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+    };
+    // 8. Set duration to nudgeResult.[[Duration]].
+    let mut duration = nudge_result.duration;
+    // 9. If nudgeResult.[[DidExpandCalendarUnit]] is true and smallestUnit is
+    //    not week, then
+    if nudge_result.did_expand_calendar_unit && smallest_unit != Unit::Week {
+        // a. Let startUnit be LargerOfTwoTemporalUnits(smallestUnit, day).
+        let start_unit = larger_of_two_temporal_units(smallest_unit, Unit::Day);
+        // b. Set duration to ? BubbleRelativeDuration(sign, duration,
+        //    nudgeResult.[[NudgedEpochNs]], isoDateTime, timeZone, calendar,
+        //    largestUnit, startUnit).
+        duration = bubble_relative_duration(
+            sign,
+            duration,
+            nudge_result.nudged_epoch_ns,
+            iso_date_time,
+            time_zone,
+            largest_unit,
+            start_unit,
+        )?;
+    }
+    // 10. Return duration.
+    Ok(duration)
+}
+
+/// ### [7.5.41 TotalRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, unit )](https://tc39.es/proposal-temporal/#sec-temporal-totalrelativeduration)
+pub(crate) fn total_relative_duration(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    iso_date_time: IsoDateTime,
+    time_zone: Option<&TimeZone>,
+    unit: Unit,
+) -> TemporalResult<f64> {
+    // 1. If IsCalendarUnit(unit) is true, or timeZone is not unset and unit
+    //    is day, then
+    if unit.is_calendar_unit() || (time_zone.is_some() && unit == Unit::Day) {
+        // a. Let sign be InternalDurationSign(duration).
+        let sign = if duration.sign() < 0 { -1 } else { 1 };
+        // b. Let record be ? NudgeToCalendarUnit(sign, duration, destEpochNs,
+        //    isoDateTime, timeZone, calendar, 1, unit, trunc).
+        let (_, total) = nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_ns,
+            iso_date_time,
+            time_zone,
+            1,
+            unit,
+            RoundingMode::Trunc,
+        )?;
+        // c. Return record.[[Total]].
+        return Ok(total);
+    }
+    // 2. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]],
+    //    duration.[[Date]].[[Days]]).
+    let time_duration = add_24_hour_days_to_time_duration(duration.time, duration.date.days)?;
+    // 3. Return TotalTimeDuration(timeDuration, unit).
+    Ok(total_time_duration(time_duration, unit))
+}
+
+/// ### [7.5.42 TemporalDurationToString ( duration, precision )](https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationtostring)
+pub(crate) fn temporal_duration_to_string(
+    duration: &DurationRecord,
+    precision: Precision,
+) -> std::string::String {
+    // 1. Let sign be DurationSign(duration).
+    let sign = duration.sign();
+    // Format the absolute value of an integral field.
+    let format = |value: f64| format!("{:.0}", value.abs());
+    // 2. Let datePart be the empty String.
+    let mut date_part = std::string::String::new();
+    // 3. If duration.[[Years]] ≠ 0, then
+    //    a. Set datePart to the string concatenation of abs(duration.[[Years]])
+    //       formatted as a decimal number and the code unit 0x0059 (LATIN
+    //       CAPITAL LETTER Y).
+    // 4. If duration.[[Months]] ≠ 0, then
+    //    a. Set datePart to the string concatenation of datePart,
+    //       abs(duration.[[Months]]) formatted as a decimal number, and the
+    //       code unit 0x004D (LATIN CAPITAL LETTER M).
+    // 5. If duration.[[Weeks]] ≠ 0, then
+    //    a. Set datePart to the string concatenation of datePart,
+    //       abs(duration.[[Weeks]]) formatted as a decimal number, and the
+    //       code unit 0x0057 (LATIN CAPITAL LETTER W).
+    // 6. If duration.[[Days]] ≠ 0, then
+    //    a. Set datePart to the string concatenation of datePart,
+    //       abs(duration.[[Days]]) formatted as a decimal number, and the code
+    //       unit 0x0044 (LATIN CAPITAL LETTER D).
+    for (value, designator) in [
+        (duration.years, 'Y'),
+        (duration.months, 'M'),
+        (duration.weeks, 'W'),
+        (duration.days, 'D'),
+    ] {
+        if value != 0.0 {
+            date_part.push_str(&format(value));
+            date_part.push(designator);
+        }
+    }
+    // 7. Let timePart be the empty String.
+    let mut time_part = std::string::String::new();
+    // 8. If duration.[[Hours]] ≠ 0, then
+    //    a. Set timePart to the string concatenation of
+    //       abs(duration.[[Hours]]) formatted as a decimal number and the
+    //       code unit 0x0048 (LATIN CAPITAL LETTER H).
+    // 9. If duration.[[Minutes]] ≠ 0, then
+    //    a. Set timePart to the string concatenation of timePart,
+    //       abs(duration.[[Minutes]]) formatted as a decimal number, and the
+    //       code unit 0x004D (LATIN CAPITAL LETTER M).
+    for (value, designator) in [(duration.hours, 'H'), (duration.minutes, 'M')] {
+        if value != 0.0 {
+            time_part.push_str(&format(value));
+            time_part.push(designator);
+        }
+    }
+    // 10. Let zeroMinutesAndHigher be false.
+    // 11. If DefaultTemporalLargestUnit(duration) is second, millisecond,
+    //     microsecond, or nanosecond, set zeroMinutesAndHigher to true.
+    let zero_minutes_and_higher = default_temporal_largest_unit(duration) >= Unit::Second;
+    // 12. Let secondsDuration be TimeDurationFromComponents(0, 0,
+    //     duration.[[Seconds]], duration.[[Milliseconds]],
+    //     duration.[[Microseconds]], duration.[[Nanoseconds]]).
+    let seconds_duration = time_duration_from_components(
+        0.0,
+        0.0,
+        duration.seconds,
+        duration.milliseconds,
+        duration.microseconds,
+        duration.nanoseconds,
+    );
+    // 13. If secondsDuration ≠ 0, or zeroMinutesAndHigher is true, or
+    //     precision is not auto, then
+    if seconds_duration != 0 || zero_minutes_and_higher || precision != Precision::Auto {
+        // a. Let secondsPart be abs(truncate(secondsDuration / 10**9))
+        //    formatted as a decimal number.
+        let seconds_part = (seconds_duration / 1_000_000_000).abs();
+        // b. Let subSecondsPart be FormatFractionalSeconds(
+        //    abs(remainder(secondsDuration, 10**9)), precision).
+        let sub_seconds_part = format_fractional_seconds(
+            (seconds_duration % 1_000_000_000).unsigned_abs() as u32,
+            precision,
+        );
+        // c. Set timePart to the string concatenation of timePart,
+        //    secondsPart, subSecondsPart, and the code unit 0x0053 (LATIN
+        //    CAPITAL LETTER S).
+        time_part.push_str(&format!("{seconds_part}{sub_seconds_part}S"));
+    }
+    // 14. Let signPart be the code unit 0x002D (HYPHEN-MINUS) if sign < 0,
+    //     and otherwise the empty String.
+    let sign_part = if sign < 0 { "-" } else { "" };
+    // 15. Let result be the string concatenation of signPart, the code unit
+    //     0x0050 (LATIN CAPITAL LETTER P) and datePart.
+    let mut result = format!("{sign_part}P{date_part}");
+    // 16. If timePart is not empty, then
+    if !time_part.is_empty() {
+        // a. Set result to the string concatenation of result, the code unit
+        //    0x0054 (LATIN CAPITAL LETTER T), and timePart.
+        result.push('T');
+        result.push_str(&time_part);
+    }
+    // 17. Return result.
+    result
+}
+
+/// ### [7.5.43 AddDurations ( operation, duration, other )](https://tc39.es/proposal-temporal/#sec-temporal-adddurations)
+///
+/// The steps after the conversion of other, with other already negated for
+/// subtraction.
+pub(crate) fn add_durations(
+    duration: &DurationRecord,
+    other: &DurationRecord,
+) -> TemporalResult<DurationRecord> {
+    // 3. Let largestUnit1 be DefaultTemporalLargestUnit(duration).
+    let largest_unit1 = default_temporal_largest_unit(duration);
+    // 4. Let largestUnit2 be DefaultTemporalLargestUnit(other).
+    let largest_unit2 = default_temporal_largest_unit(other);
+    // 5. Let largestUnit be LargerOfTwoTemporalUnits(largestUnit1,
+    //    largestUnit2).
+    let largest_unit = larger_of_two_temporal_units(largest_unit1, largest_unit2);
+    // 6. If IsCalendarUnit(largestUnit) is true, throw a RangeError
+    //    exception.
+    if largest_unit.is_calendar_unit() {
+        return Err(RangeError(
+            "cannot add durations with years, months or weeks without a relative date",
+        ));
+    }
+    // 7. Let d1 be ToInternalDurationRecordWith24HourDays(duration).
+    let d1 = to_internal_duration_record_with_24_hour_days(duration);
+    // 8. Let d2 be ToInternalDurationRecordWith24HourDays(other).
+    let d2 = to_internal_duration_record_with_24_hour_days(other);
+    // 9. Let timeResult be ? AddTimeDuration(d1.[[Time]], d2.[[Time]]).
+    let time_result = add_time_duration(d1.time, d2.time)?;
+    // 10. Let result be CombineDateAndTimeDuration(ZeroDateDuration(),
+    //     timeResult).
+    let result = InternalDuration::new(DateDuration::default(), time_result);
+    // 11. Return ? TemporalDurationFromInternal(result, largestUnit).
+    temporal_duration_from_internal(result, largest_unit)
+}
+
+/// ### [7.5.28 DateDurationDays ( dateDuration, plainRelativeTo )](https://tc39.es/proposal-temporal/#sec-temporal-datedurationdays)
+pub(crate) fn date_duration_days(
+    date_duration: DateDuration,
+    plain_relative_to: IsoDate,
+) -> TemporalResult<i64> {
+    // 1. Let yearsMonthsWeeksDuration be ! AdjustDateDurationRecord(
+    //    dateDuration, 0).
+    let years_months_weeks_duration = DateDuration {
+        days: 0,
+        ..date_duration
+    };
+    // 2. If DateDurationSign(yearsMonthsWeeksDuration) = 0, return
+    //    dateDuration.[[Days]].
+    if years_months_weeks_duration.sign() == 0 {
+        return Ok(date_duration.days);
+    }
+    // 3. Let later be ? CalendarDateAdd(plainRelativeTo.[[Calendar]],
+    //    plainRelativeTo.[[ISODate]], yearsMonthsWeeksDuration, constrain).
+    let later = calendar_date_add(
+        plain_relative_to,
+        years_months_weeks_duration,
+        Overflow::Constrain,
+    )?;
+    // 4. Let epochDays1 be ISODateToEpochDays(
+    //    plainRelativeTo.[[ISODate]].[[Year]],
+    //    plainRelativeTo.[[ISODate]].[[Month]] - 1,
+    //    plainRelativeTo.[[ISODate]].[[Day]]).
+    let epoch_days1 = plain_relative_to.to_epoch_days();
+    // 5. Let epochDays2 be ISODateToEpochDays(later.[[Year]],
+    //    later.[[Month]] - 1, later.[[Day]]).
+    let epoch_days2 = later.to_epoch_days();
+    // 6. Let yearsMonthsWeeksInDays be epochDays2 - epochDays1.
+    // 7. Return dateDuration.[[Days]] + yearsMonthsWeeksInDays.
+    Ok(date_duration.days + epoch_days2 - epoch_days1)
+}
+
+/// The date which a duration is relative to, read by
+/// GetTemporalRelativeToOption.
+#[derive(Debug, Clone)]
+pub(crate) enum RelativeTo {
+    None,
+    /// \[\[PlainRelativeTo]]
+    Plain(IsoDate),
+    /// \[\[ZonedRelativeTo]]
+    Zoned {
+        epoch_nanoseconds: i128,
+        time_zone: TimeZone,
+    },
+}
+trivially_bindable!(RelativeTo);
+
+/// ### [7.5.27 GetTemporalRelativeToOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalrelativetooption)
+pub(crate) fn get_temporal_relative_to_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, RelativeTo> {
+    // 1. Let value be ? Get(options, "relativeTo").
+    let value = get(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.relativeTo.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If value is undefined, return the Record { [[PlainRelativeTo]]:
+    //    undefined, [[ZonedRelativeTo]]: undefined }.
+    if value.is_undefined() {
+        return Ok(RelativeTo::None);
+    }
+    // 3. Let offsetBehaviour be option.
+    let mut offset_behaviour = OffsetBehaviour::Option;
+    // 4. Let matchBehaviour be match-exactly.
+    let mut match_minutes = false;
+    // 5. If value is an Object, then
+    let (iso_date, time, time_zone, offset_nanoseconds, gc) = if let Ok(value) =
+        Object::try_from(value)
+    {
+        match value {
+            // a. If value has an [[InitializedTemporalZonedDateTime]]
+            //    internal slot, then
+            //    i. Return the Record { [[PlainRelativeTo]]: undefined,
+            //       [[ZonedRelativeTo]]: value }.
+            Object::ZonedDateTime(zoned_date_time) => {
+                let data = &agent[zoned_date_time];
+                return Ok(RelativeTo::Zoned {
+                    epoch_nanoseconds: data.epoch_nanoseconds,
+                    time_zone: data.time_zone.clone(),
+                });
+            }
+            // b. If value has an [[InitializedTemporalDate]] internal
+            //    slot, then
+            //    i. Return the Record { [[PlainRelativeTo]]: value,
+            //       [[ZonedRelativeTo]]: undefined }.
+            Object::PlainDate(plain_date) => {
+                return Ok(RelativeTo::Plain(agent[plain_date].iso_date));
+            }
+            // c. If value has an [[InitializedTemporalDateTime]] internal
+            //    slot, then
+            //    i. Let plainDate be ! CreateTemporalDate(
+            //       value.[[ISODateTime]].[[ISODate]],
+            //       value.[[Calendar]]).
+            //    ii. Return the Record { [[PlainRelativeTo]]: plainDate,
+            //        [[ZonedRelativeTo]]: undefined }.
+            Object::PlainDateTime(plain_date_time) => {
+                return Ok(RelativeTo::Plain(agent[plain_date_time].iso_date_time.date));
+            }
+            _ => {}
+        }
+        let value = value.scope(agent, gc.nogc());
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(
+        //    value).
+        get_temporal_calendar_identifier_with_iso_default(agent, value.get(agent), gc.reborrow())
+            .unbind()?;
+        // e. Let fields be ? PrepareCalendarFields(calendar, value,
+        //    « year, month, month-code, day », « hour, minute, second,
+        //    millisecond, microsecond, nanosecond, offset, time-zone »,
+        //    « »).
+        let fields: CalendarFields = prepare_calendar_fields(
+            agent,
+            value.get(agent),
+            &[Field::DATE, Field::TIME, &[Field::Offset, Field::TimeZone]],
+            RequiredFields::List(&[]),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // f. Let result be ? InterpretTemporalDateTimeFields(calendar,
+        //    fields, constrain).
+        let result = interpret_temporal_date_time_fields(&fields, Overflow::Constrain)
+            .map_err(|err| err.throw(agent, gc))?;
+        // g. Let timeZone be fields.[[TimeZone]].
+        // h. Let offsetString be fields.[[OffsetString]].
+        // i. If offsetString is unset, then
+        if fields.offset.is_none() {
+            // i. Set offsetBehaviour to wall.
+            offset_behaviour = OffsetBehaviour::Wall;
+        }
+        // j. Let isoDate be result.[[ISODate]].
+        // k. Let time be result.[[Time]].
+        (
+            result.date,
+            Some(result.time),
+            fields.time_zone,
+            fields.offset.unwrap_or(0),
+            gc,
+        )
+    } else {
+        let value = value.unbind();
+        let gc = gc.into_nogc();
+        let value = value.bind(gc);
+        // 6. Else,
+        //    a. If value is not a String, throw a TypeError exception.
+        let Ok(value) = String::try_from(value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "relativeTo must be a Temporal object, an object or a string",
+                gc,
+            ));
+        };
+        //    b. Let result be ? ParseISODateTime(value,
+        //       « TemporalDateTimeString[+Zoned],
+        //       TemporalDateTimeString[~Zoned] »).
+        let result = parse_temporal_relative_to_string(&value.as_wtf8(agent).to_string_lossy());
+        let result = result.map_err(|err| err.throw(agent, gc))?;
+        //    c. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+        //    d. Let annotation be
+        //       result.[[TimeZone]].[[TimeZoneAnnotation]].
+        //    e. If annotation is empty, then
+        //       i. Let timeZone be unset.
+        //    f. Else,
+        let time_zone = match result.time_zone {
+            None => None,
+            Some(annotation) => {
+                // i. Let timeZone be ? ToTemporalTimeZoneIdentifier(
+                //    annotation).
+                let time_zone = to_time_zone(annotation).map_err(|err| err.throw(agent, gc))?;
+                // ii. If result.[[TimeZone]].[[Z]] is true, then
+                if result.z {
+                    // 1. Set offsetBehaviour to exact.
+                    offset_behaviour = OffsetBehaviour::Exact;
+                } else if result.offset.is_none() {
+                    // iii. Else if offsetString is empty, then
+                    //      1. Set offsetBehaviour to wall.
+                    offset_behaviour = OffsetBehaviour::Wall;
+                }
+                // iv. Set matchBehaviour to match-minutes.
+                // v. If offsetString is not empty, then
+                //    1. Let offsetParseResult be ParseText(
+                //       StringToCodePoints(offsetString),
+                //       UTCOffset[+SubMinutePrecision]).
+                //    2. Assert: offsetParseResult is a Parse Node.
+                //    3. If offsetParseResult contains more than one
+                //       MinuteSecond Parse Node, set matchBehaviour to
+                //       match-exactly.
+                match_minutes = !result
+                    .offset
+                    .is_some_and(|offset| offset.has_sub_minute_precision);
+                Some(time_zone)
+            }
+        };
+        //    g. Let calendar be result.[[Calendar]].
+        //    h. If calendar is empty, set calendar to "iso8601".
+        //    i. Set calendar to ? CanonicalizeCalendar(calendar).
+        canonicalize_calendar(result.calendar.as_deref().unwrap_or(ISO8601))
+            .map_err(|err| err.throw(agent, gc))?;
+        //    j. Let isoDate be CreateISODateRecord(result.[[Year]],
+        //       result.[[Month]], result.[[Day]]).
+        //    k. Let time be result.[[Time]].
+        (
+            result.date,
+            result.time,
+            time_zone,
+            result.offset.map_or(0, |offset| offset.nanoseconds),
+            gc,
+        )
+    };
+    // 7. If timeZone is unset, then
+    let Some(time_zone) = time_zone else {
+        // a. Let plainDate be ? CreateTemporalDate(isoDate, calendar).
+        let iso_date =
+            check_iso_date_within_limits(iso_date).map_err(|err| err.throw(agent, gc))?;
+        // b. Return the Record { [[PlainRelativeTo]]: plainDate,
+        //    [[ZonedRelativeTo]]: undefined }.
+        return Ok(RelativeTo::Plain(iso_date));
+    };
+    // 8. If offsetBehaviour is option, then
+    //    a. Let offsetNs be ! ParseDateTimeUTCOffset(offsetString).
+    // 9. Else,
+    //    a. Let offsetNs be 0.
+    let offset_ns = if offset_behaviour == OffsetBehaviour::Option {
+        offset_nanoseconds
+    } else {
+        0
+    };
+    // 10. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time,
+    //     offsetBehaviour, offsetNs, timeZone, compatible, reject,
+    //     matchBehaviour).
+    let epoch_nanoseconds = interpret_iso_date_time_offset(
+        iso_date,
+        time,
+        offset_behaviour,
+        offset_ns,
+        &time_zone,
+        Disambiguation::Compatible,
+        OffsetOption::Reject,
+        match_minutes,
+    )
+    .map_err(|err| err.throw(agent, gc))?;
+    // 11. Let zonedRelativeTo be ! CreateTemporalZonedDateTime(
+    //     epochNanoseconds, timeZone, calendar).
+    // 12. Return the Record { [[PlainRelativeTo]]: undefined,
+    //     [[ZonedRelativeTo]]: zonedRelativeTo }.
+    Ok(RelativeTo::Zoned {
+        epoch_nanoseconds,
+        time_zone,
+    })
+}
+
+/// ### [7.5.19 ToTemporalDuration ( item )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalduration)
+pub(crate) fn to_temporal_duration<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DurationRecord> {
+    // 1. If item is an Object and item has an [[InitializedTemporalDuration]]
+    //    internal slot, then
+    if let Value::Duration(item) = item {
+        // a. Return ! CreateTemporalDuration(item.[[Years]], item.[[Months]],
+        //    item.[[Weeks]], item.[[Days]], item.[[Hours]], item.[[Minutes]],
+        //    item.[[Seconds]], item.[[Milliseconds]], item.[[Microseconds]],
+        //    item.[[Nanoseconds]]).
+        return Ok(agent[item].duration);
+    }
+    // 2. If item is not an Object, then
+    let Ok(item) = Object::try_from(item) else {
+        let gc = gc.into_nogc();
+        // a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "duration must be a Temporal.Duration, an object or a string",
+                gc,
+            ));
+        };
+        // b. Return ? ParseTemporalDurationString(item).
+        let duration = parse_temporal_duration_string(&item.as_wtf8(agent).to_string_lossy());
+        let duration = duration
+            .and_then(|duration| {
+                if is_valid_duration(&duration) {
+                    Ok(duration)
+                } else {
+                    Err(RangeError("duration is out of range"))
+                }
+            })
+            .map_err(|err| err.throw(agent, gc))?;
+        return Ok(duration);
+    };
+    // 3. Let result be a new Partial Duration Record with each field set to
+    //    0.
+    // 4. Let partial be ? ToTemporalPartialDurationRecord(item).
+    let partial =
+        to_temporal_partial_duration_record(agent, item.into_value().unbind(), gc.reborrow())
+            .unbind()?;
+    let gc = gc.into_nogc();
+    // 5. If partial.[[Years]] is not undefined, set result.[[Years]] to
+    //    partial.[[Years]].
+    // 6-14. Likewise for the other fields.
+    // 15. Return ? CreateTemporalDuration(result.[[Years]], result.[[Months]],
+    //     result.[[Weeks]], result.[[Days]], result.[[Hours]],
+    //     result.[[Minutes]], result.[[Seconds]], result.[[Milliseconds]],
+    //     result.[[Microseconds]], result.[[Nanoseconds]]).
+    let result = DurationRecord::from_fields(partial.map(|field| field.unwrap_or(0.0)));
+    if !is_valid_duration(&result) {
+        return Err(RangeError("duration is out of range").throw(agent, gc));
+    }
+    Ok(result)
+}
+
+/// A Partial Duration Record, with the fields in the order of Table 21 and
+/// unset fields as None.
+pub(crate) type PartialDurationRecord = [Option<f64>; 10];
+trivially_bindable!(PartialDurationRecord);
+
+/// ### [7.5.31 ToTemporalPartialDurationRecord ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalpartialdurationrecord)
+pub(crate) fn to_temporal_partial_duration_record<'gc>(
+    agent: &mut Agent,
+    temporal_duration_like: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PartialDurationRecord> {
+    // 1. If temporalDurationLike is not an Object, then
+    let Ok(temporal_duration_like) = Object::try_from(temporal_duration_like) else {
+        // a. Throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "duration-like must be an object",
+            gc.into_nogc(),
+        ));
+    };
+    let temporal_duration_like = temporal_duration_like.scope(agent, gc.nogc());
+    // 2. Let result be a new partial Duration Record with each field set to
+    //    undefined.
+    let mut result: PartialDurationRecord = [None; 10];
+    // 3. NOTE: The following steps read properties and perform independent
+    //    validation in alphabetical order.
+    // 4-23. For each of "days", "hours", "microseconds", "milliseconds",
+    //       "minutes", "months", "nanoseconds", "seconds", "weeks" and
+    //       "years", do
+    //       a. Let value be ? Get(temporalDurationLike, property).
+    //       b. If value is not undefined, then
+    //          i. Set result's field to ? ToIntegerIfIntegral(value).
+    let properties = [
+        (BUILTIN_STRING_MEMORY.days, Unit::Day),
+        (BUILTIN_STRING_MEMORY.hours, Unit::Hour),
+        (BUILTIN_STRING_MEMORY.microseconds, Unit::Microsecond),
+        (BUILTIN_STRING_MEMORY.milliseconds, Unit::Millisecond),
+        (BUILTIN_STRING_MEMORY.minutes, Unit::Minute),
+        (BUILTIN_STRING_MEMORY.months, Unit::Month),
+        (BUILTIN_STRING_MEMORY.nanoseconds, Unit::Nanosecond),
+        (BUILTIN_STRING_MEMORY.seconds, Unit::Second),
+        (BUILTIN_STRING_MEMORY.weeks, Unit::Week),
+        (BUILTIN_STRING_MEMORY.years, Unit::Year),
+    ];
+    for (property, unit) in properties {
+        let value = get(
+            agent,
+            temporal_duration_like.get(agent),
+            property.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        if !value.is_undefined() {
+            result[unit as usize] =
+                Some(to_integer_if_integral(agent, value.unbind(), gc.reborrow()).unbind()?);
+        }
+    }
+    // 24. If years is undefined, and months is undefined, and weeks is
+    //     undefined, and days is undefined, and hours is undefined, and
+    //     minutes is undefined, and seconds is undefined, and milliseconds is
+    //     undefined, and microseconds is undefined, and nanoseconds is
+    //     undefined, throw a TypeError exception.
+    if result.iter().all(Option::is_none) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "duration-like object must have at least one duration property",
+            gc.into_nogc(),
+        ));
+    }
+    // 25. Return result.
+    Ok(result)
+}
+
+/// ### [7.5.20 CreateTemporalDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalduration)
+pub(crate) fn create_temporal_duration<'gc>(
+    agent: &mut Agent,
+    duration: DurationRecord,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    // 1. If IsValidDuration(years, months, weeks, days, hours, minutes,
+    //    seconds, milliseconds, microseconds, nanoseconds) is false, throw a
+    //    RangeError exception.
+    if !is_valid_duration(&duration) {
+        return Err(RangeError("duration is out of range").throw(agent, gc.into_nogc()));
+    }
+    // 2. If newTarget is not present, set newTarget to %Temporal.Duration%.
+    let Some(new_target) = new_target else {
+        return Ok(create_duration_object(agent, duration, gc.into_nogc()));
+    };
+    // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.Duration.prototype%", « [[InitializedTemporalDuration]],
+    //    [[Years]], [[Months]], [[Weeks]], [[Days]], [[Hours]], [[Minutes]],
+    //    [[Seconds]], [[Milliseconds]], [[Microseconds]], [[Nanoseconds]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalDuration,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = Duration::try_from(object.bind(gc)).unwrap();
+    // 4. Set object.[[Years]] to ℝ(𝔽(years)).
+    // 5-13. Likewise for the other fields.
+    agent[object].duration = duration;
+    // 14. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalDuration without a newTarget, for a valid duration.
+pub(crate) fn create_duration_object<'gc>(
+    agent: &mut Agent,
+    duration: DurationRecord,
+    gc: NoGcScope<'gc, '_>,
+) -> Duration<'gc> {
+    debug_assert!(is_valid_duration(&duration));
+    agent
+        .heap
+        .create(DurationHeapData {
+            object_index: None,
+            duration,
+        })
+        .bind(gc)
+}
+
+/// Create a Temporal.Duration from an Internal Duration Record with
+/// TemporalDurationFromInternal.
+pub(crate) fn create_duration_from_internal<'gc>(
+    agent: &mut Agent,
+    internal_duration: InternalDuration,
+    largest_unit: Unit,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let duration = temporal_duration_from_internal(internal_duration, largest_unit)
+        .map_err(|err| err.throw(agent, gc))?;
+    Ok(create_duration_object(agent, duration, gc))
+}
+
+/// The start of the day of a time which has overflowed into the next days,
+/// as AddTime gives for a target time: the number of days and the time.
+pub(crate) fn add_time_to_midnight(time_duration: i128) -> (i64, TimeRecord) {
+    add_time(TimeRecord::MIDNIGHT, time_duration)
+}
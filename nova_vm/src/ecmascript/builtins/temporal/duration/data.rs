@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::temporal::duration::abstract_operations::DurationRecord, types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [7.4 Properties of Temporal.Duration Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-duration-instances)
+#[derive(Debug, Clone, Default)]
+pub struct DurationHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// The fields from \[\[Years]] to \[\[Nanoseconds]].
+    pub(crate) duration: DurationRecord,
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DurationHeapData<'_> {
+    type Of<'a> = DurationHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for DurationHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            duration: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            duration: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
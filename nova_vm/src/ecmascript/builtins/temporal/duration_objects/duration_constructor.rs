@@ -0,0 +1,251 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{
+                    Overflow, RangeError, TemporalResult, get_options_object,
+                    to_integer_if_integral,
+                },
+                duration::abstract_operations::{
+                    DurationRecord, RelativeTo, add_24_hour_days_to_time_duration,
+                    create_duration_object, create_temporal_duration, date_duration_days,
+                    default_temporal_largest_unit, get_temporal_relative_to_option,
+                    to_internal_duration_record, to_temporal_duration,
+                },
+                zoned_date_time::abstract_operations::add_zoned_date_time,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct DurationConstructor;
+impl Builtin for DurationConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Duration;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for DurationConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalDuration;
+}
+
+struct DurationFrom;
+impl Builtin for DurationFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationConstructor::from);
+}
+struct DurationCompare;
+impl Builtin for DurationCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationConstructor::compare);
+}
+
+impl DurationConstructor {
+    /// ### [7.1.1 Temporal.Duration ( \[ years \[ , months \[ , weeks \[ , days \[ , hours \[ , minutes \[ , seconds \[ , milliseconds \[ , microseconds \[ , nanoseconds \] \] \] \] \] \] \] \] \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.duration)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.Duration requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        let values = (0..10)
+            .map(|index| arguments.get(index).scope(agent, gc.nogc()))
+            .collect::<Vec<_>>();
+        // 2. If years is undefined, let y be 0; else let y be
+        //    ? ToIntegerIfIntegral(years).
+        // 3-11. Likewise for months, weeks, days, hours, minutes, seconds,
+        //       milliseconds, microseconds and nanoseconds.
+        let mut fields = [0.0; 10];
+        for (field, value) in fields.iter_mut().zip(values) {
+            let value = value.get(agent);
+            if !value.is_undefined() {
+                *field = to_integer_if_integral(agent, value, gc.reborrow()).unbind()?;
+            }
+        }
+        // 12. Return ? CreateTemporalDuration(y, mo, w, d, h, m, s, ms, mis,
+        //     ns, NewTarget).
+        create_temporal_duration(
+            agent,
+            DurationRecord::from_fields(fields),
+            Some(new_target.get(agent)),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [7.2.2 Temporal.Duration.from ( item )](https://tc39.es/proposal-temporal/#sec-temporal.duration.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalDuration(item).
+        let duration = to_temporal_duration(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        Ok(create_duration_object(agent, duration, gc.into_nogc()).into_value())
+    }
+
+    /// ### [7.2.3 Temporal.Duration.compare ( one, two \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.duration.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        let options = arguments.get(2).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalDuration(one).
+        let one = to_temporal_duration(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalDuration(two).
+        let two = to_temporal_duration(agent, two.get(agent), gc.reborrow()).unbind()?;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+        // 4. Let relativeToRecord be ? GetTemporalRelativeToOption(
+        //    resolvedOptions).
+        let relative_to =
+            get_temporal_relative_to_option(agent, resolved_options, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let result = compare_durations(&one, &two, &relative_to);
+        let result = result.map_err(|err| err.throw(agent, gc))?;
+        Ok(result.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let duration_prototype = intrinsics.temporal_duration_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<DurationConstructor>(agent, realm)
+            .with_property_capacity(3)
+            .with_prototype_property(duration_prototype.into_object())
+            .with_builtin_function_property::<DurationFrom>()
+            .with_builtin_function_property::<DurationCompare>()
+            .build();
+    }
+}
+
+/// Steps 5 to 17 of Temporal.Duration.compare.
+fn compare_durations(
+    one: &DurationRecord,
+    two: &DurationRecord,
+    relative_to: &RelativeTo,
+) -> TemporalResult<i8> {
+    // 5. If one.[[Years]] = two.[[Years]], and one.[[Months]] =
+    //    two.[[Months]], and one.[[Weeks]] = two.[[Weeks]], and one.[[Days]]
+    //    = two.[[Days]], and one.[[Hours]] = two.[[Hours]], and
+    //    one.[[Minutes]] = two.[[Minutes]], and one.[[Seconds]] =
+    //    two.[[Seconds]], and one.[[Milliseconds]] = two.[[Milliseconds]],
+    //    and one.[[Microseconds]] = two.[[Microseconds]], and
+    //    one.[[Nanoseconds]] = two.[[Nanoseconds]], then
+    if one == two {
+        // a. Return +0𝔽.
+        return Ok(0);
+    }
+    // 6. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+    // 7. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+    // 8. Let largestUnit1 be DefaultTemporalLargestUnit(one).
+    let largest_unit1 = default_temporal_largest_unit(one);
+    // 9. Let largestUnit2 be DefaultTemporalLargestUnit(two).
+    let largest_unit2 = default_temporal_largest_unit(two);
+    // 10. Let duration1 be ToInternalDurationRecord(one).
+    let duration1 = to_internal_duration_record(one);
+    // 11. Let duration2 be ToInternalDurationRecord(two).
+    let duration2 = to_internal_duration_record(two);
+    // 12. If zonedRelativeTo is not undefined, and either
+    //     TemporalUnitCategory(largestUnit1) or
+    //     TemporalUnitCategory(largestUnit2) is date, then
+    if let RelativeTo::Zoned {
+        epoch_nanoseconds,
+        time_zone,
+    } = relative_to
+    {
+        if largest_unit1.is_date_unit() || largest_unit2.is_date_unit() {
+            // a. Let timeZone be zonedRelativeTo.[[TimeZone]].
+            // b. Let calendar be zonedRelativeTo.[[Calendar]].
+            // c. Let after1 be ? AddZonedDateTime(
+            //    zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar,
+            //    duration1, constrain).
+            let after1 = add_zoned_date_time(
+                *epoch_nanoseconds,
+                time_zone,
+                duration1,
+                Overflow::Constrain,
+            )?;
+            // d. Let after2 be ? AddZonedDateTime(
+            //    zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar,
+            //    duration2, constrain).
+            let after2 = add_zoned_date_time(
+                *epoch_nanoseconds,
+                time_zone,
+                duration2,
+                Overflow::Constrain,
+            )?;
+            // e. If after1 > after2, return 1𝔽.
+            // f. If after1 < after2, return -1𝔽.
+            // g. Return +0𝔽.
+            return Ok(after1.cmp(&after2) as i8);
+        }
+    }
+    // 13. If IsCalendarUnit(largestUnit1) is true or
+    //     IsCalendarUnit(largestUnit2) is true, then
+    let (days1, days2) = if largest_unit1.is_calendar_unit() || largest_unit2.is_calendar_unit() {
+        // a. If plainRelativeTo is undefined, throw a RangeError exception.
+        let RelativeTo::Plain(plain_relative_to) = relative_to else {
+            return Err(RangeError(
+                "comparing durations with years, months or weeks requires relativeTo",
+            ));
+        };
+        // b. Let days1 be ? DateDurationDays(duration1.[[Date]],
+        //    plainRelativeTo).
+        // c. Let days2 be ? DateDurationDays(duration2.[[Date]],
+        //    plainRelativeTo).
+        (
+            date_duration_days(duration1.date, *plain_relative_to)?,
+            date_duration_days(duration2.date, *plain_relative_to)?,
+        )
+    } else {
+        // 14. Else,
+        //     a. Let days1 be one.[[Days]].
+        //     b. Let days2 be two.[[Days]].
+        (one.days as i64, two.days as i64)
+    };
+    // 15. Let timeDuration1 be ? Add24HourDaysToTimeDuration(
+    //     duration1.[[Time]], days1).
+    let time_duration1 = add_24_hour_days_to_time_duration(duration1.time, days1)?;
+    // 16. Let timeDuration2 be ? Add24HourDaysToTimeDuration(
+    //     duration2.[[Time]], days2).
+    let time_duration2 = add_24_hour_days_to_time_duration(duration2.time, days2)?;
+    // 17. Return 𝔽(CompareTimeDuration(timeDuration1, timeDuration2)).
+    Ok(time_duration1.cmp(&time_duration2) as i8)
+}
@@ -0,0 +1,1168 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    NS_PER_DAY, Overflow, Precision, RangeError, RoundingMode, TemporalResult,
+                    Unit, UnitGroup, UnitValue, get_options_object, get_rounding_increment_option,
+                    get_rounding_mode_option, get_temporal_fractional_second_digits_option,
+                    get_temporal_unit_valued_option, larger_of_two_temporal_units,
+                    maximum_temporal_duration_rounding_increment, parse_unit_string,
+                    round_number_to_increment, to_seconds_string_precision_record,
+                    validate_temporal_rounding_increment, validate_temporal_unit_value,
+                },
+                calendar::{IsoDate, IsoDateTime, TimeRecord, calendar_date_add},
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        DurationRecord, InternalDuration, RelativeTo, add_durations,
+                        add_time_to_midnight, adjust_date_duration_record,
+                        create_date_duration_record, create_duration_object,
+                        create_temporal_duration, default_temporal_largest_unit,
+                        get_temporal_relative_to_option, round_time_duration,
+                        temporal_duration_from_internal, temporal_duration_to_string,
+                        to_internal_duration_record, to_internal_duration_record_with_24_hour_days,
+                        to_temporal_duration, to_temporal_partial_duration_record,
+                        total_time_duration,
+                    },
+                },
+                plain_date_time::abstract_operations::{
+                    difference_plain_date_time_with_rounding, difference_plain_date_time_with_total,
+                },
+                zoned_date_time::abstract_operations::{
+                    add_zoned_date_time, difference_zoned_date_time_with_rounding,
+                    difference_zoned_date_time_with_total,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct DurationPrototype;
+
+struct DurationPrototypeGetYears;
+impl Builtin for DurationPrototypeGetYears {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_years;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.years.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Year as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetYears {}
+struct DurationPrototypeGetMonths;
+impl Builtin for DurationPrototypeGetMonths {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_months;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.months.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Month as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetMonths {}
+struct DurationPrototypeGetWeeks;
+impl Builtin for DurationPrototypeGetWeeks {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weeks;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.weeks.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Week as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetWeeks {}
+struct DurationPrototypeGetDays;
+impl Builtin for DurationPrototypeGetDays {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_days;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.days.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Day as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetDays {}
+struct DurationPrototypeGetHours;
+impl Builtin for DurationPrototypeGetHours {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hours;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hours.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Hour as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetHours {}
+struct DurationPrototypeGetMinutes;
+impl Builtin for DurationPrototypeGetMinutes {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minutes;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minutes.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Minute as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetMinutes {}
+struct DurationPrototypeGetSeconds;
+impl Builtin for DurationPrototypeGetSeconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_seconds;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.seconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Second as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetSeconds {}
+struct DurationPrototypeGetMilliseconds;
+impl Builtin for DurationPrototypeGetMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_milliseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.milliseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Millisecond as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetMilliseconds {}
+struct DurationPrototypeGetMicroseconds;
+impl Builtin for DurationPrototypeGetMicroseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Microsecond as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetMicroseconds {}
+struct DurationPrototypeGetNanoseconds;
+impl Builtin for DurationPrototypeGetNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanoseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanoseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DurationPrototype::get_field::<{ Unit::Nanosecond as usize }>);
+}
+impl BuiltinGetter for DurationPrototypeGetNanoseconds {}
+struct DurationPrototypeGetSign;
+impl Builtin for DurationPrototypeGetSign {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_sign;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.sign.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::get_sign);
+}
+impl BuiltinGetter for DurationPrototypeGetSign {}
+struct DurationPrototypeGetBlank;
+impl Builtin for DurationPrototypeGetBlank {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_blank;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.blank.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::get_blank);
+}
+impl BuiltinGetter for DurationPrototypeGetBlank {}
+struct DurationPrototypeWith;
+impl Builtin for DurationPrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::with);
+}
+struct DurationPrototypeNegated;
+impl Builtin for DurationPrototypeNegated {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.negated;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::negated);
+}
+struct DurationPrototypeAbs;
+impl Builtin for DurationPrototypeAbs {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.abs;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::abs);
+}
+struct DurationPrototypeAdd;
+impl Builtin for DurationPrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::add);
+}
+struct DurationPrototypeSubtract;
+impl Builtin for DurationPrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::subtract);
+}
+struct DurationPrototypeRound;
+impl Builtin for DurationPrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::round);
+}
+struct DurationPrototypeTotal;
+impl Builtin for DurationPrototypeTotal {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.total;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::total);
+}
+struct DurationPrototypeToString;
+impl Builtin for DurationPrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::to_string);
+}
+struct DurationPrototypeToJSON;
+impl Builtin for DurationPrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::to_json);
+}
+struct DurationPrototypeToLocaleString;
+impl Builtin for DurationPrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::to_locale_string);
+}
+struct DurationPrototypeValueOf;
+impl Builtin for DurationPrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DurationPrototype::value_of);
+}
+
+impl DurationPrototype {
+    /// ### [7.3.3 get Temporal.Duration.prototype.years](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.years)
+    ///
+    /// The getters from years to nanoseconds, with the unit of the field as
+    /// an index into the fields of the Duration Record.
+    fn get_field<'gc, const UNIT: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Years]]).
+        let value = agent[duration].duration.fields()[UNIT];
+        Ok(Value::from_f64(agent, value, gc))
+    }
+
+    /// ### [7.3.13 get Temporal.Duration.prototype.sign](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.sign)
+    fn get_sign<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(DurationSign(duration)).
+        Ok(agent[duration].duration.sign().into())
+    }
+
+    /// ### [7.3.14 get Temporal.Duration.prototype.blank](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.blank)
+    fn get_blank<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. If DurationSign(duration) = 0, return true.
+        // 4. Return false.
+        Ok((agent[duration].duration.sign() == 0).into())
+    }
+
+    /// ### [7.3.15 Temporal.Duration.prototype.with ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let fields = agent[duration].duration.fields();
+        // 3. Let temporalDurationLike be ? ToTemporalPartialDurationRecord(
+        //    temporalDurationLike).
+        let temporal_duration_like =
+            to_temporal_partial_duration_record(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 4. If temporalDurationLike.[[Years]] is not undefined, then
+        //    a. Let years be temporalDurationLike.[[Years]].
+        // 5. Else,
+        //    a. Let years be duration.[[Years]].
+        // 6-13. Likewise for the other fields.
+        let mut result = fields;
+        for (field, value) in result.iter_mut().zip(temporal_duration_like) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+        // 14. Return ? CreateTemporalDuration(years, months, weeks, days,
+        //     hours, minutes, seconds, milliseconds, microseconds,
+        //     nanoseconds).
+        create_temporal_duration(agent, DurationRecord::from_fields(result), None, gc)
+            .map(|duration| duration.into_value())
+    }
+
+    /// ### [7.3.16 Temporal.Duration.prototype.negated ( )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.negated)
+    fn negated<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return CreateNegatedTemporalDuration(duration).
+        let result = agent[duration].duration.negated();
+        Ok(create_duration_object(agent, result, gc).into_value())
+    }
+
+    /// ### [7.3.17 Temporal.Duration.prototype.abs ( )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.abs)
+    fn abs<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalDuration(abs(duration.[[Years]]),
+        //    abs(duration.[[Months]]), abs(duration.[[Weeks]]),
+        //    abs(duration.[[Days]]), abs(duration.[[Hours]]),
+        //    abs(duration.[[Minutes]]), abs(duration.[[Seconds]]),
+        //    abs(duration.[[Milliseconds]]), abs(duration.[[Microseconds]]),
+        //    abs(duration.[[Nanoseconds]])).
+        let result = agent[duration].duration.map(f64::abs);
+        Ok(create_duration_object(agent, result, gc).into_value())
+    }
+
+    /// ### [7.3.18 Temporal.Duration.prototype.add ( other )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(add, duration, other).
+        add_durations_to_duration(agent, false, this_value, arguments.get(0), gc)
+    }
+
+    /// ### [7.3.19 Temporal.Duration.prototype.subtract ( other )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(subtract, duration, other).
+        add_durations_to_duration(agent, true, this_value, arguments.get(0), gc)
+    }
+
+    /// ### [7.3.20 Temporal.Duration.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let round_to = arguments.get(0).bind(gc.nogc());
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let duration = agent[duration].duration;
+        // 3. If roundTo is undefined, then
+        if round_to.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "round requires an options argument",
+                gc.into_nogc(),
+            ));
+        }
+        let options = if let Ok(param_string) = String::try_from(round_to) {
+            // 4. If roundTo is a String, then
+            //    a. Let paramString be roundTo.
+            //    b. Set roundTo to OrdinaryObjectCreate(null).
+            //    c. Perform ! CreateDataPropertyOrThrow(roundTo,
+            //       "smallestUnit", paramString).
+            // NOTE: Reading the other options from the new object gives their
+            // defaults, so the object need not be created.
+            let Some(smallest_unit) = parse_unit_string(agent, param_string) else {
+                let value = param_string.as_wtf8(agent).to_string_lossy().into_owned();
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    format!("Value {value} out of range for option"),
+                    gc.into_nogc(),
+                ));
+            };
+            DurationRoundOptions {
+                largest_unit: UnitValue::Unset,
+                relative_to: RelativeTo::None,
+                rounding_increment: 1,
+                rounding_mode: RoundingMode::HalfExpand,
+                smallest_unit,
+            }
+        } else {
+            // 5. Else,
+            //    a. Set roundTo to ? GetOptionsObject(roundTo).
+            let round_to = get_options_object(agent, round_to, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+            // 6. Let smallestUnitPresent be true.
+            // 7. Let largestUnitPresent be true.
+            // 8. NOTE: The following steps read options and perform
+            //    independent validation in alphabetical order.
+            // 9. Let largestUnit be ? GetTemporalUnitValuedOption(roundTo,
+            //    "largestUnit", unset).
+            let largest_unit = get_temporal_unit_valued_option(
+                agent,
+                round_to.get(agent),
+                BUILTIN_STRING_MEMORY.largestUnit,
+                false,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // 10. Let relativeToRecord be ? GetTemporalRelativeToOption(
+            //     roundTo).
+            // 11. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+            // 12. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+            let relative_to =
+                get_temporal_relative_to_option(agent, round_to.get(agent), gc.reborrow())
+                    .unbind()?;
+            // 13. Let roundingIncrement be ? GetRoundingIncrementOption(
+            //     roundTo).
+            let rounding_increment =
+                get_rounding_increment_option(agent, round_to.get(agent), gc.reborrow())
+                    .unbind()?;
+            // 14. Let roundingMode be ? GetRoundingModeOption(roundTo,
+            //     half-expand).
+            let rounding_mode = get_rounding_mode_option(
+                agent,
+                round_to.get(agent),
+                RoundingMode::HalfExpand,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // 15. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo,
+            //     "smallestUnit", unset).
+            let smallest_unit = get_temporal_unit_valued_option(
+                agent,
+                round_to.get(agent),
+                BUILTIN_STRING_MEMORY.smallestUnit,
+                false,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            DurationRoundOptions {
+                largest_unit,
+                relative_to,
+                rounding_increment,
+                rounding_mode,
+                smallest_unit,
+            }
+        };
+        let gc = gc.into_nogc();
+        let result = round_duration(&duration, &options);
+        let result = result.map_err(|err| err.throw(agent, gc))?;
+        Ok(create_duration_object(agent, result, gc).into_value())
+    }
+
+    /// ### [7.3.21 Temporal.Duration.prototype.total ( totalOf )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.total)
+    fn total<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let total_of = arguments.get(0).bind(gc.nogc());
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let duration = agent[duration].duration;
+        // 3. If totalOf is undefined, throw a TypeError exception.
+        if total_of.is_undefined() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "total requires an options argument",
+                gc.into_nogc(),
+            ));
+        }
+        let (relative_to, unit) = if let Ok(param_string) = String::try_from(total_of) {
+            // 4. If totalOf is a String, then
+            //    a. Let paramString be totalOf.
+            //    b. Set totalOf to OrdinaryObjectCreate(null).
+            //    c. Perform ! CreateDataPropertyOrThrow(totalOf, "unit",
+            //       paramString).
+            let Some(unit) = parse_unit_string(agent, param_string) else {
+                let value = param_string.as_wtf8(agent).to_string_lossy().into_owned();
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    format!("Value {value} out of range for option"),
+                    gc.into_nogc(),
+                ));
+            };
+            (RelativeTo::None, unit)
+        } else {
+            // 5. Else,
+            //    a. Set totalOf to ? GetOptionsObject(totalOf).
+            let total_of = get_options_object(agent, total_of, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+            // 6. NOTE: The following steps read options and perform
+            //    independent validation in alphabetical order.
+            // 7. Let relativeToRecord be ? GetTemporalRelativeToOption(
+            //    totalOf).
+            // 8. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+            // 9. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+            let relative_to =
+                get_temporal_relative_to_option(agent, total_of.get(agent), gc.reborrow())
+                    .unbind()?;
+            // 10. Let unit be ? GetTemporalUnitValuedOption(totalOf, "unit",
+            //     required).
+            let unit = get_temporal_unit_valued_option(
+                agent,
+                total_of.get(agent),
+                BUILTIN_STRING_MEMORY.unit,
+                true,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            (relative_to, unit)
+        };
+        let gc = gc.into_nogc();
+        let result = total_duration(&duration, &relative_to, unit);
+        let total = result.map_err(|err| err.throw(agent, gc))?;
+        // 15. Return 𝔽(total).
+        Ok(Value::from_f64(agent, total, gc))
+    }
+
+    /// ### [7.3.22 Temporal.Duration.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let duration = agent[duration].duration;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. NOTE: The following steps read options and perform independent
+        //    validation in alphabetical order.
+        // 5. Let digits be ? GetTemporalFractionalSecondDigitsOption(
+        //    resolvedOptions).
+        let digits = get_temporal_fractional_second_digits_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 6. Let roundingMode be ? GetRoundingModeOption(resolvedOptions,
+        //    trunc).
+        let rounding_mode = get_rounding_mode_option(
+            agent,
+            resolved_options.get(agent),
+            RoundingMode::Trunc,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions,
+        //    "smallestUnit", unset).
+        let smallest_unit = get_temporal_unit_valued_option(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.smallestUnit,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let result = duration_to_string(&duration, digits, rounding_mode, smallest_unit);
+        let result = result.map_err(|err| err.throw(agent, gc))?;
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [7.3.23 Temporal.Duration.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return TemporalDurationToString(duration, auto).
+        let result = temporal_duration_to_string(&agent[duration].duration, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [7.3.24 Temporal.Duration.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive duration formatting this produces the same
+    /// result as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration,
+        //    [[InitializedTemporalDuration]]).
+        let duration = require_temporal_duration(agent, this_value, gc)?;
+        // 3. Return TemporalDurationToString(duration, auto).
+        let result = temporal_duration_to_string(&agent[duration].duration, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [7.3.25 Temporal.Duration.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.Duration cannot be converted to a primitive value; use compare()",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_duration_prototype();
+        let duration_constructor = intrinsics.temporal_duration();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(25)
+            .with_prototype(object_prototype)
+            .with_constructor_property(duration_constructor)
+            .with_builtin_function_getter_property::<DurationPrototypeGetYears>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetMonths>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetWeeks>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetDays>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetHours>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetMinutes>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetSeconds>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetMilliseconds>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetMicroseconds>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetNanoseconds>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetSign>()
+            .with_builtin_function_getter_property::<DurationPrototypeGetBlank>()
+            .with_builtin_function_property::<DurationPrototypeWith>()
+            .with_builtin_function_property::<DurationPrototypeNegated>()
+            .with_builtin_function_property::<DurationPrototypeAbs>()
+            .with_builtin_function_property::<DurationPrototypeAdd>()
+            .with_builtin_function_property::<DurationPrototypeSubtract>()
+            .with_builtin_function_property::<DurationPrototypeRound>()
+            .with_builtin_function_property::<DurationPrototypeTotal>()
+            .with_builtin_function_property::<DurationPrototypeToString>()
+            .with_builtin_function_property::<DurationPrototypeToJSON>()
+            .with_builtin_function_property::<DurationPrototypeToLocaleString>()
+            .with_builtin_function_property::<DurationPrototypeValueOf>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_Duration.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [7.5.43 AddDurations ( operation, duration, other )](https://tc39.es/proposal-temporal/#sec-temporal-adddurations)
+///
+/// Includes the RequireInternalSlot check of the add and subtract methods.
+fn add_durations_to_duration<'gc>(
+    agent: &mut Agent,
+    subtract: bool,
+    this_value: Value,
+    other: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let duration = require_temporal_duration(agent, this_value, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    let duration = agent[duration].duration;
+    // 1. Set other to ? ToTemporalDuration(other).
+    let other = to_temporal_duration(agent, other, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 2. If operation is subtract, set other to
+    //    CreateNegatedTemporalDuration(other).
+    let other = if subtract { other.negated() } else { other };
+    let result = add_durations(&duration, &other);
+    let result = result.map_err(|err| err.throw(agent, gc))?;
+    // 12. Return ? CreateTemporalDuration(result.[[Years]], ...).
+    Ok(create_duration_object(agent, result, gc).into_value())
+}
+
+/// The options of Temporal.Duration.prototype.round.
+struct DurationRoundOptions {
+    largest_unit: UnitValue,
+    relative_to: RelativeTo,
+    rounding_increment: u32,
+    rounding_mode: RoundingMode,
+    smallest_unit: UnitValue,
+}
+
+/// Steps 16 to 34 of Temporal.Duration.prototype.round.
+fn round_duration(
+    duration: &DurationRecord,
+    options: &DurationRoundOptions,
+) -> TemporalResult<DurationRecord> {
+    let rounding_increment = options.rounding_increment;
+    let rounding_mode = options.rounding_mode;
+    // 16. Perform ? ValidateTemporalUnitValue(smallestUnit, datetime).
+    validate_temporal_unit_value(options.smallest_unit, UnitGroup::DateTime, &[])?;
+    // 17. If smallestUnit is unset, then
+    //     a. Set smallestUnitPresent to false.
+    //     b. Set smallestUnit to nanosecond.
+    let (smallest_unit_present, smallest_unit) = match options.smallest_unit {
+        UnitValue::Unit(unit) => (true, unit),
+        _ => (false, Unit::Nanosecond),
+    };
+    // 18. Let existingLargestUnit be DefaultTemporalLargestUnit(duration).
+    let existing_largest_unit = default_temporal_largest_unit(duration);
+    // 19. Let defaultLargestUnit be LargerOfTwoTemporalUnits(
+    //     existingLargestUnit, smallestUnit).
+    let default_largest_unit = larger_of_two_temporal_units(existing_largest_unit, smallest_unit);
+    let (largest_unit_present, largest_unit) = match options.largest_unit {
+        // 20. If largestUnit is unset, then
+        //     a. Set largestUnitPresent to false.
+        //     b. Set largestUnit to defaultLargestUnit.
+        UnitValue::Unset => (false, default_largest_unit),
+        // 21. Else if largestUnit is auto, then
+        //     a. Set largestUnit to defaultLargestUnit.
+        UnitValue::Auto => (true, default_largest_unit),
+        UnitValue::Unit(unit) => (true, unit),
+    };
+    // 22. If smallestUnitPresent is false and largestUnitPresent is false,
+    //     then
+    if !smallest_unit_present && !largest_unit_present {
+        // a. Throw a RangeError exception.
+        return Err(RangeError(
+            "at least one of smallestUnit and largestUnit is required",
+        ));
+    }
+    // 23. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not
+    //     largestUnit, throw a RangeError exception.
+    if larger_of_two_temporal_units(largest_unit, smallest_unit) != largest_unit {
+        return Err(RangeError("smallestUnit is larger than largestUnit"));
+    }
+    // 24. Let maximum be MaximumTemporalDurationRoundingIncrement(
+    //     smallestUnit).
+    // 25. If maximum is not unset, perform
+    //     ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum,
+    //     false).
+    if let Some(maximum) = maximum_temporal_duration_rounding_increment(smallest_unit) {
+        validate_temporal_rounding_increment(rounding_increment, maximum, false)?;
+    }
+    // 26. If roundingIncrement > 1, and largestUnit is not smallestUnit, and
+    //     TemporalUnitCategory(smallestUnit) is date, throw a RangeError
+    //     exception.
+    if rounding_increment > 1 && largest_unit != smallest_unit && smallest_unit.is_date_unit() {
+        return Err(RangeError(
+            "roundingIncrement of a date unit requires largestUnit to be smallestUnit",
+        ));
+    }
+    match &options.relative_to {
+        // 27. If zonedRelativeTo is not undefined, then
+        RelativeTo::Zoned {
+            epoch_nanoseconds,
+            time_zone,
+        } => {
+            // a. Let internalDuration be ToInternalDurationRecord(duration).
+            let internal_duration = to_internal_duration_record(duration);
+            // b. Let timeZone be zonedRelativeTo.[[TimeZone]].
+            // c. Let calendar be zonedRelativeTo.[[Calendar]].
+            // d. Let relativeEpochNs be zonedRelativeTo.[[EpochNanoseconds]].
+            // e. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs,
+            //    timeZone, calendar, internalDuration, constrain).
+            let target_epoch_ns = add_zoned_date_time(
+                *epoch_nanoseconds,
+                time_zone,
+                internal_duration,
+                Overflow::Constrain,
+            )?;
+            // f. Set internalDuration to
+            //    ? DifferenceZonedDateTimeWithRounding(relativeEpochNs,
+            //    targetEpochNs, timeZone, calendar, largestUnit,
+            //    roundingIncrement, smallestUnit, roundingMode).
+            let internal_duration = difference_zoned_date_time_with_rounding(
+                *epoch_nanoseconds,
+                target_epoch_ns,
+                time_zone,
+                largest_unit,
+                rounding_increment,
+                smallest_unit,
+                rounding_mode,
+            )?;
+            // g. If TemporalUnitCategory(largestUnit) is date, set
+            //    largestUnit to hour.
+            let largest_unit = if largest_unit.is_date_unit() {
+                Unit::Hour
+            } else {
+                largest_unit
+            };
+            // h. Return ? TemporalDurationFromInternal(internalDuration,
+            //    largestUnit).
+            temporal_duration_from_internal(internal_duration, largest_unit)
+        }
+        // 28. If plainRelativeTo is not undefined, then
+        RelativeTo::Plain(plain_relative_to) => {
+            // a-g.
+            let (iso_date_time, target_date_time) =
+                plain_relative_date_times(duration, *plain_relative_to)?;
+            // h. Let internalDuration be
+            //    ? DifferencePlainDateTimeWithRounding(isoDateTime,
+            //    targetDateTime, calendar, largestUnit, roundingIncrement,
+            //    smallestUnit, roundingMode).
+            let internal_duration = difference_plain_date_time_with_rounding(
+                iso_date_time,
+                target_date_time,
+                largest_unit,
+                rounding_increment,
+                smallest_unit,
+                rounding_mode,
+            )?;
+            // i. Return ? TemporalDurationFromInternal(internalDuration,
+            //    largestUnit).
+            temporal_duration_from_internal(internal_duration, largest_unit)
+        }
+        RelativeTo::None => {
+            // 29. If IsCalendarUnit(existingLargestUnit) is true, or
+            //     IsCalendarUnit(largestUnit) is true, throw a RangeError
+            //     exception.
+            if existing_largest_unit.is_calendar_unit() || largest_unit.is_calendar_unit() {
+                return Err(RangeError(
+                    "rounding durations with years, months or weeks requires relativeTo",
+                ));
+            }
+            // 30. Assert: IsCalendarUnit(smallestUnit) is false.
+            debug_assert!(!smallest_unit.is_calendar_unit());
+            // 31. Let internalDuration be
+            //     ToInternalDurationRecordWith24HourDays(duration).
+            let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+            let internal_duration = if smallest_unit == Unit::Day {
+                // 32. If smallestUnit is day, then
+                //     a. Let fractionalDays be TotalTimeDuration(
+                //        internalDuration.[[Time]], day).
+                //     b. Let days be RoundNumberToIncrement(fractionalDays,
+                //        roundingIncrement, roundingMode).
+                let days = round_number_to_increment(
+                    internal_duration.time,
+                    NS_PER_DAY * i128::from(rounding_increment),
+                    rounding_mode,
+                ) / NS_PER_DAY;
+                //     c. Let dateDuration be ? CreateDateDurationRecord(0, 0,
+                //        0, days).
+                let date_duration = create_date_duration_record(
+                    0,
+                    0,
+                    0,
+                    i64::try_from(days).map_err(|_| RangeError("duration is out of range"))?,
+                )?;
+                //     d. Set internalDuration to CombineDateAndTimeDuration(
+                //        dateDuration, 0).
+                InternalDuration::new(date_duration, 0)
+            } else {
+                // 33. Else,
+                //     a. Let timeDuration be ? RoundTimeDuration(
+                //        internalDuration.[[Time]], roundingIncrement,
+                //        smallestUnit, roundingMode).
+                let time_duration = round_time_duration(
+                    internal_duration.time,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                )?;
+                //     b. Set internalDuration to CombineDateAndTimeDuration(
+                //        ZeroDateDuration(), timeDuration).
+                InternalDuration::new(Default::default(), time_duration)
+            };
+            // 34. Return ? TemporalDurationFromInternal(internalDuration,
+            //     largestUnit).
+            temporal_duration_from_internal(internal_duration, largest_unit)
+        }
+    }
+}
+
+/// Steps 11 to 14 of Temporal.Duration.prototype.total.
+fn total_duration(
+    duration: &DurationRecord,
+    relative_to: &RelativeTo,
+    unit: UnitValue,
+) -> TemporalResult<f64> {
+    // 11. Perform ? ValidateTemporalUnitValue(unit, datetime).
+    validate_temporal_unit_value(unit, UnitGroup::DateTime, &[])?;
+    let UnitValue::Unit(unit) = unit else {
+        unreachable!()
+    };
+    match relative_to {
+        // 12. If zonedRelativeTo is not undefined, then
+        RelativeTo::Zoned {
+            epoch_nanoseconds,
+            time_zone,
+        } => {
+            // a. Let internalDuration be ToInternalDurationRecord(duration).
+            let internal_duration = to_internal_duration_record(duration);
+            // b. Let timeZone be zonedRelativeTo.[[TimeZone]].
+            // c. Let calendar be zonedRelativeTo.[[Calendar]].
+            // d. Let relativeEpochNs be zonedRelativeTo.[[EpochNanoseconds]].
+            // e. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs,
+            //    timeZone, calendar, internalDuration, constrain).
+            let target_epoch_ns = add_zoned_date_time(
+                *epoch_nanoseconds,
+                time_zone,
+                internal_duration,
+                Overflow::Constrain,
+            )?;
+            // f. Let total be ? DifferenceZonedDateTimeWithTotal(
+            //    relativeEpochNs, targetEpochNs, timeZone, calendar, unit).
+            difference_zoned_date_time_with_total(
+                *epoch_nanoseconds,
+                target_epoch_ns,
+                time_zone,
+                unit,
+            )
+        }
+        // 13. Else if plainRelativeTo is not undefined, then
+        RelativeTo::Plain(plain_relative_to) => {
+            // a-g.
+            let (iso_date_time, target_date_time) =
+                plain_relative_date_times(duration, *plain_relative_to)?;
+            // h. Let total be ? DifferencePlainDateTimeWithTotal(isoDateTime,
+            //    targetDateTime, calendar, unit).
+            difference_plain_date_time_with_total(iso_date_time, target_date_time, unit)
+        }
+        // 14. Else,
+        RelativeTo::None => {
+            // a. Let largestUnit be DefaultTemporalLargestUnit(duration).
+            let largest_unit = default_temporal_largest_unit(duration);
+            // b. If IsCalendarUnit(largestUnit) is true, or
+            //    IsCalendarUnit(unit) is true, throw a RangeError exception.
+            if largest_unit.is_calendar_unit() || unit.is_calendar_unit() {
+                return Err(RangeError(
+                    "totaling durations with years, months or weeks requires relativeTo",
+                ));
+            }
+            // c. Let internalDuration be
+            //    ToInternalDurationRecordWith24HourDays(duration).
+            let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+            // d. Let total be TotalTimeDuration(internalDuration.[[Time]],
+            //    unit).
+            Ok(total_time_duration(internal_duration.time, unit))
+        }
+    }
+}
+
+/// Steps a to g of the plainRelativeTo cases of
+/// Temporal.Duration.prototype.round and total: the start and the end of the
+/// duration relative to the date, as ISO Date-Time Records.
+fn plain_relative_date_times(
+    duration: &DurationRecord,
+    plain_relative_to: IsoDate,
+) -> TemporalResult<(IsoDateTime, IsoDateTime)> {
+    // a. Let internalDuration be ToInternalDurationRecordWith24HourDays(
+    //    duration).
+    let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+    // b. Let targetTime be AddTime(MidnightTimeRecord(),
+    //    internalDuration.[[Time]]).
+    let (days, target_time) = add_time_to_midnight(internal_duration.time);
+    // c. Let calendar be plainRelativeTo.[[Calendar]].
+    // d. Let dateDuration be ! AdjustDateDurationRecord(
+    //    internalDuration.[[Date]], targetTime.[[Days]]).
+    let date_duration = adjust_date_duration_record(internal_duration.date, days, None, None)?;
+    // e. Let targetDate be ? CalendarDateAdd(calendar,
+    //    plainRelativeTo.[[ISODate]], dateDuration, constrain).
+    let target_date = calendar_date_add(plain_relative_to, date_duration, Overflow::Constrain)?;
+    // f. Let isoDateTime be CombineISODateAndTimeRecord(
+    //    plainRelativeTo.[[ISODate]], MidnightTimeRecord()).
+    let iso_date_time = IsoDateTime::new(plain_relative_to, TimeRecord::MIDNIGHT);
+    // g. Let targetDateTime be CombineISODateAndTimeRecord(targetDate,
+    //    targetTime).
+    let target_date_time = IsoDateTime::new(target_date, target_time);
+    Ok((iso_date_time, target_date_time))
+}
+
+/// Steps 8 to 18 of Temporal.Duration.prototype.toString.
+fn duration_to_string(
+    duration: &DurationRecord,
+    digits: Option<u8>,
+    rounding_mode: RoundingMode,
+    smallest_unit: UnitValue,
+) -> TemporalResult<std::string::String> {
+    // 8. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+    validate_temporal_unit_value(smallest_unit, UnitGroup::Time, &[])?;
+    let smallest_unit = match smallest_unit {
+        UnitValue::Unit(unit) => Some(unit),
+        _ => None,
+    };
+    // 9. If smallestUnit is hour or minute, throw a RangeError exception.
+    if matches!(smallest_unit, Some(Unit::Hour | Unit::Minute)) {
+        return Err(RangeError("smallestUnit must not be hour or minute"));
+    }
+    // 10. Let precision be ToSecondsStringPrecisionRecord(smallestUnit,
+    //     digits).
+    let precision = to_seconds_string_precision_record(smallest_unit, digits);
+    // 11. If precision.[[Unit]] is nanosecond and precision.[[Increment]] =
+    //     1, then
+    if precision.unit == Unit::Nanosecond && precision.increment == 1 {
+        // a. Return TemporalDurationToString(duration,
+        //    precision.[[Precision]]).
+        return Ok(temporal_duration_to_string(duration, precision.precision));
+    }
+    // 12. Let largestUnit be DefaultTemporalLargestUnit(duration).
+    let largest_unit = default_temporal_largest_unit(duration);
+    // 13. Let internalDuration be ToInternalDurationRecord(duration).
+    let internal_duration = to_internal_duration_record(duration);
+    // 14. Let timeDuration be ? RoundTimeDuration(internalDuration.[[Time]],
+    //     precision.[[Increment]], precision.[[Unit]], roundingMode).
+    let time_duration = round_time_duration(
+        internal_duration.time,
+        precision.increment,
+        precision.unit,
+        rounding_mode,
+    )?;
+    // 15. Set internalDuration to CombineDateAndTimeDuration(
+    //     internalDuration.[[Date]], timeDuration).
+    let internal_duration = InternalDuration::new(internal_duration.date, time_duration);
+    // 16. Let roundedLargestUnit be LargerOfTwoTemporalUnits(largestUnit,
+    //     second).
+    let rounded_largest_unit = larger_of_two_temporal_units(largest_unit, Unit::Second);
+    // 17. Let roundedDuration be ? TemporalDurationFromInternal(
+    //     internalDuration, roundedLargestUnit).
+    let rounded_duration =
+        temporal_duration_from_internal(internal_duration, rounded_largest_unit)?;
+    // 18. Return TemporalDurationToString(roundedDuration,
+    //     precision.[[Precision]]).
+    Ok(temporal_duration_to_string(
+        &rounded_duration,
+        precision.precision,
+    ))
+}
+
+#[inline(always)]
+fn require_temporal_duration<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Duration<'a>> {
+    Duration::try_from(value)
+        .map(|duration| duration.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.Duration object",
+                gc,
+            )
+        })
+}
@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::InstantIndex,
+    },
+};
+
+use self::data::InstantHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Instant<'a>(pub(crate) InstantIndex<'a>);
+
+impl Instant<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, Instant<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(InstantIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for Instant<'_> {
+    type Of<'a> = Instant<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for Instant<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Instant<'a>> for Value<'a> {
+    fn from(value: Instant<'a>) -> Self {
+        Value::Instant(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for Instant<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<Instant<'a>> for Object<'a> {
+    fn from(value: Instant) -> Self {
+        Object::Instant(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for Instant<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::Instant(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for Instant<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::Instant(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for Instant<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalInstant;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for Instant<'a> {}
+
+impl Index<Instant<'_>> for Agent {
+    type Output = InstantHeapData<'static>;
+
+    fn index(&self, index: Instant) -> &Self::Output {
+        &self.heap.instants[index]
+    }
+}
+
+impl IndexMut<Instant<'_>> for Agent {
+    fn index_mut(&mut self, index: Instant) -> &mut Self::Output {
+        &mut self.heap.instants[index]
+    }
+}
+
+impl Index<Instant<'_>> for Vec<Option<InstantHeapData<'static>>> {
+    type Output = InstantHeapData<'static>;
+
+    fn index(&self, index: Instant) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("Instant out of bounds")
+            .as_ref()
+            .expect("Instant slot empty")
+    }
+}
+
+impl IndexMut<Instant<'_>> for Vec<Option<InstantHeapData<'static>>> {
+    fn index_mut(&mut self, index: Instant) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("Instant out of bounds")
+            .as_mut()
+            .expect("Instant slot empty")
+    }
+}
+
+impl Rootable for Instant<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::Instant(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::Instant(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for Instant<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.instants.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.instants.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<InstantHeapData<'a>, Instant<'a>> for Heap {
+    fn create(&mut self, data: InstantHeapData<'a>) -> Instant<'a> {
+        self.instants.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<InstantHeapData<'static>>>();
+        }
+        Instant(InstantIndex::last(&self.instants))
+    }
+}
@@ -0,0 +1,405 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [8.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-instant-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        abstract_operations::type_conversion::{PreferredType, to_primitive},
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Precision, RangeError, RoundingMode,
+                    ShowCalendar, TemporalResult, Unit, UnitGroup,
+                    format_date_time_utc_offset_rounded, get_difference_settings,
+                    get_options_object, is_valid_epoch_nanoseconds,
+                    round_number_to_increment_as_if_positive,
+                },
+                calendar::{ISO8601, IsoDateTime, TimeRecord},
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        DurationRecord, InternalDuration, create_duration_object,
+                        default_temporal_largest_unit, round_time_duration,
+                        temporal_duration_from_internal,
+                        to_internal_duration_record_with_24_hour_days, to_temporal_duration,
+                    },
+                },
+                instant::{Instant, data::InstantHeapData},
+                parsing::parse_temporal_instant_string,
+                plain_date_time::abstract_operations::iso_date_time_to_string,
+                time_zone::{
+                    TimeZone, add_nanoseconds_to_iso_date_time, check_iso_days_range,
+                    get_iso_date_time_for, get_offset_nanoseconds_for,
+                },
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{BigInt, Function, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// Convert epoch nanoseconds into a BigInt.
+pub(crate) fn epoch_nanoseconds_to_big_int<'a>(
+    agent: &mut Agent,
+    epoch_nanoseconds: i128,
+    gc: NoGcScope<'a, '_>,
+) -> BigInt<'a> {
+    BigInt::from_num_bigint(agent, num_bigint::BigInt::from(epoch_nanoseconds)).bind(gc)
+}
+
+/// Convert a BigInt into an i128, or None if it does not fit.
+pub(crate) fn big_int_to_i128(agent: &Agent, big_int: BigInt) -> Option<i128> {
+    match big_int {
+        BigInt::BigInt(heap_big_int) => i128::try_from(&agent[heap_big_int].data).ok(),
+        BigInt::SmallBigInt(small_big_int) => Some(i128::from(small_big_int.into_i64())),
+    }
+}
+
+/// ### [8.5.2 CreateTemporalInstant ( epochNanoseconds \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalinstant)
+pub(crate) fn create_temporal_instant<'gc>(
+    agent: &mut Agent,
+    epoch_nanoseconds: i128,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Instant<'gc>> {
+    // 1. Assert: IsValidEpochNanoseconds(epochNanoseconds) is true.
+    debug_assert!(is_valid_epoch_nanoseconds(epoch_nanoseconds));
+    // 2. If newTarget is not present, set newTarget to %Temporal.Instant%.
+    let Some(new_target) = new_target else {
+        return Ok(create_instant_object(
+            agent,
+            epoch_nanoseconds,
+            gc.into_nogc(),
+        ));
+    };
+    // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.Instant.prototype%", « [[InitializedTemporalInstant]],
+    //    [[EpochNanoseconds]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalInstant,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = Instant::try_from(object.bind(gc)).unwrap();
+    // 4. Set object.[[EpochNanoseconds]] to epochNanoseconds.
+    agent[object].epoch_nanoseconds = epoch_nanoseconds;
+    // 5. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalInstant without a newTarget.
+pub(crate) fn create_instant_object<'gc>(
+    agent: &mut Agent,
+    epoch_nanoseconds: i128,
+    gc: NoGcScope<'gc, '_>,
+) -> Instant<'gc> {
+    debug_assert!(is_valid_epoch_nanoseconds(epoch_nanoseconds));
+    agent
+        .heap
+        .create(InstantHeapData {
+            object_index: None,
+            epoch_nanoseconds,
+        })
+        .bind(gc)
+}
+
+/// ### [8.5.3 ToTemporalInstant ( item )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalinstant)
+///
+/// Returns the epoch nanoseconds of the instant.
+pub(crate) fn to_temporal_instant<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, i128> {
+    let mut item = item.bind(gc.nogc());
+    // 1. If item is an Object, then
+    if let Ok(object) = Object::try_from(item) {
+        match object {
+            // a. If item has an [[InitializedTemporalInstant]] or
+            //    [[InitializedTemporalZonedDateTime]] internal slot, then
+            //    i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
+            Object::Instant(instant) => return Ok(agent[instant].epoch_nanoseconds),
+            Object::ZonedDateTime(zoned_date_time) => {
+                return Ok(agent[zoned_date_time].epoch_nanoseconds);
+            }
+            _ => {}
+        }
+        // b. NOTE: This use of ToPrimitive allows Instant-like objects to be
+        //    converted.
+        // c. Set item to ? ToPrimitive(item, string).
+        item = to_primitive(
+            agent,
+            object.unbind(),
+            Some(PreferredType::String),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc())
+        .into();
+    }
+    let item = item.unbind();
+    let gc = gc.into_nogc();
+    let item = item.bind(gc);
+    // 2. If item is not a String, throw a TypeError exception.
+    let Ok(item) = String::try_from(item) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "instant must be a Temporal.Instant or a string",
+            gc,
+        ));
+    };
+    let result = parse_instant(&item.as_wtf8(agent).to_string_lossy());
+    result.map_err(|err| err.throw(agent, gc))
+}
+
+/// Steps 3 to 10 of ToTemporalInstant.
+fn parse_instant(item: &str) -> TemporalResult<i128> {
+    // 3. Let parsed be ? ParseTemporalInstantString(item).
+    let parsed = parse_temporal_instant_string(item)?;
+    // 4. If parsed.[[TimeZone]].[[Z]] is true, let offsetNanoseconds be 0;
+    //    otherwise, let offsetNanoseconds be ! ParseDateTimeUTCOffset(
+    //    parsed.[[TimeZone]].[[OffsetString]]).
+    let offset_nanoseconds = if parsed.z {
+        0
+    } else {
+        parsed.offset.map_or(0, |offset| offset.nanoseconds)
+    };
+    // 5. If parsed.[[Time]] is start-of-day, let time be
+    //    MidnightTimeRecord(); else let time be parsed.[[Time]].
+    let time = parsed.time.unwrap_or(TimeRecord::MIDNIGHT);
+    // 6. Let balanced be BalanceISODateTime(parsed.[[Year]], parsed.[[Month]],
+    //    parsed.[[Day]], time.[[Hour]], time.[[Minute]], time.[[Second]],
+    //    time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] -
+    //    offsetNanoseconds).
+    let balanced = add_nanoseconds_to_iso_date_time(
+        IsoDateTime::new(parsed.date, time),
+        -i128::from(offset_nanoseconds),
+    );
+    // 7. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+    check_iso_days_range(balanced.date)?;
+    // 8. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+    let epoch_nanoseconds = balanced.to_epoch_nanoseconds();
+    // 9. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+    //    RangeError exception.
+    if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        return Err(RangeError("instant is out of range"));
+    }
+    // 10. Return ! CreateTemporalInstant(epochNanoseconds).
+    Ok(epoch_nanoseconds)
+}
+
+/// ### [8.5.4 CompareEpochNanoseconds ( epochNanosecondsOne, epochNanosecondsTwo )](https://tc39.es/proposal-temporal/#sec-temporal-compareepochnanoseconds)
+pub(crate) fn compare_epoch_nanoseconds(
+    epoch_nanoseconds_one: i128,
+    epoch_nanoseconds_two: i128,
+) -> i8 {
+    // 1. If epochNanosecondsOne > epochNanosecondsTwo, return 1.
+    // 2. If epochNanosecondsOne < epochNanosecondsTwo, return -1.
+    // 3. Return 0.
+    epoch_nanoseconds_one.cmp(&epoch_nanoseconds_two) as i8
+}
+
+/// ### [8.5.5 AddInstant ( epochNanoseconds, timeDuration )](https://tc39.es/proposal-temporal/#sec-temporal-addinstant)
+pub(crate) fn add_instant(epoch_nanoseconds: i128, time_duration: i128) -> TemporalResult<i128> {
+    // 1. Let result be AddTimeDurationToEpochNanoseconds(timeDuration,
+    //    epochNanoseconds).
+    let result = epoch_nanoseconds + time_duration;
+    // 2. If IsValidEpochNanoseconds(result) is false, throw a RangeError
+    //    exception.
+    if !is_valid_epoch_nanoseconds(result) {
+        return Err(RangeError("instant is out of range"));
+    }
+    // 3. Return result.
+    Ok(result)
+}
+
+/// ### [8.5.6 DifferenceInstant ( ns1, ns2, roundingIncrement, smallestUnit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-differenceinstant)
+pub(crate) fn difference_instant(
+    ns1: i128,
+    ns2: i128,
+    rounding_increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<InternalDuration> {
+    // 1. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2,
+    //    ns1).
+    let time_duration = ns2 - ns1;
+    // 2. Set timeDuration to ! RoundTimeDuration(timeDuration,
+    //    roundingIncrement, smallestUnit, roundingMode).
+    let time_duration = round_time_duration(
+        time_duration,
+        rounding_increment,
+        smallest_unit,
+        rounding_mode,
+    )?;
+    // 3. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+    Ok(InternalDuration::new(Default::default(), time_duration))
+}
+
+/// ### [8.5.7 RoundTemporalInstant ( ns, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundtemporalinstant)
+pub(crate) fn round_temporal_instant(
+    ns: i128,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> i128 {
+    // 1. Let unitLength be the value in the "Length in Nanoseconds" column of
+    //    the row of Table 21 whose "Value" column contains unit.
+    let unit_length = unit.length_in_nanoseconds();
+    // 2. Let incrementNs be increment × unitLength.
+    let increment_ns = i128::from(increment) * unit_length;
+    // 3. Return ℤ(RoundNumberToIncrementAsIfPositive(ℝ(ns), incrementNs,
+    //    roundingMode)).
+    round_number_to_increment_as_if_positive(ns, increment_ns, rounding_mode)
+}
+
+/// ### [8.5.8 TemporalInstantToString ( instant, timeZone, precision )](https://tc39.es/proposal-temporal/#sec-temporal-temporalinstanttostring)
+pub(crate) fn temporal_instant_to_string(
+    epoch_ns: i128,
+    time_zone: Option<&TimeZone>,
+    precision: Precision,
+) -> std::string::String {
+    // 1. Let outputTimeZone be timeZone.
+    // 2. If outputTimeZone is undefined, set outputTimeZone to "UTC".
+    // 3. Let epochNs be instant.[[EpochNanoseconds]].
+    // 4. Let isoDateTime be GetISODateTimeFor(outputTimeZone, epochNs).
+    let iso_date_time = match time_zone {
+        Some(time_zone) => get_iso_date_time_for(time_zone, epoch_ns),
+        None => IsoDateTime::from_epoch_nanoseconds(epoch_ns),
+    };
+    // 5. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601",
+    //    precision, never).
+    let date_time_string =
+        iso_date_time_to_string(iso_date_time, ISO8601, precision, ShowCalendar::Never);
+    // 6. If timeZone is undefined, then
+    //    a. Let timeZoneString be "Z".
+    // 7. Else,
+    //    a. Let offsetNanoseconds be GetOffsetNanosecondsFor(outputTimeZone,
+    //       epochNs).
+    //    b. Let timeZoneString be FormatDateTimeUTCOffsetRounded(
+    //       offsetNanoseconds).
+    let time_zone_string = match time_zone {
+        None => "Z".to_string(),
+        Some(time_zone) => {
+            format_date_time_utc_offset_rounded(get_offset_nanoseconds_for(time_zone, epoch_ns))
+        }
+    };
+    // 8. Return the string-concatenation of dateTimeString and
+    //    timeZoneString.
+    date_time_string + &time_zone_string
+}
+
+/// ### [8.5.9 DifferenceTemporalInstant ( operation, instant, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalinstant)
+pub(crate) fn difference_temporal_instant<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    instant: Instant,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let instant = instant.bind(gc.nogc()).scope(agent, gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalInstant(other).
+    let other = to_temporal_instant(agent, other, gc.reborrow()).unbind()?;
+    // 2. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    time, « », nanosecond, second).
+    let settings = get_difference_settings(
+        agent,
+        operation,
+        resolved_options,
+        UnitGroup::Time,
+        &[],
+        Unit::Nanosecond,
+        Unit::Second,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 4. Let internalDuration be DifferenceInstant(
+    //    instant.[[EpochNanoseconds]], other.[[EpochNanoseconds]],
+    //    settings.[[RoundingIncrement]], settings.[[SmallestUnit]],
+    //    settings.[[RoundingMode]]).
+    let ns1 = agent[instant.get(agent)].epoch_nanoseconds;
+    // 5. Let result be ! TemporalDurationFromInternal(internalDuration,
+    //    settings.[[LargestUnit]]).
+    let result = difference_instant(
+        ns1,
+        other,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )
+    .and_then(|internal_duration| {
+        temporal_duration_from_internal(internal_duration, settings.largest_unit)
+    })
+    .map_err(|err| err.throw(agent, gc))?;
+    // 6. If operation is since, set result to
+    //    CreateNegatedTemporalDuration(result).
+    let result = match operation {
+        DifferenceOperation::Since => result.negated(),
+        DifferenceOperation::Until => result,
+    };
+    // 7. Return result.
+    Ok(create_duration_object(agent, result, gc))
+}
+
+/// ### [8.5.10 AddDurationToInstant ( operation, instant, temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoinstant)
+pub(crate) fn add_duration_to_instant<'gc>(
+    agent: &mut Agent,
+    operation: ArithmeticOperation,
+    instant: Instant,
+    temporal_duration_like: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Instant<'gc>> {
+    let instant = instant.bind(gc.nogc()).scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, temporal_duration_like, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 2. If operation is subtract, set duration to
+    //    CreateNegatedTemporalDuration(duration).
+    let duration = match operation {
+        ArithmeticOperation::Subtract => duration.negated(),
+        ArithmeticOperation::Add => duration,
+    };
+    let epoch_nanoseconds = agent[instant.get(agent)].epoch_nanoseconds;
+    let result = add_duration_record_to_instant(epoch_nanoseconds, &duration);
+    let ns = result.map_err(|err| err.throw(agent, gc))?;
+    // 7. Return ! CreateTemporalInstant(ns).
+    Ok(create_instant_object(agent, ns, gc))
+}
+
+/// Steps 3 to 6 of AddDurationToInstant.
+fn add_duration_record_to_instant(
+    epoch_nanoseconds: i128,
+    duration: &DurationRecord,
+) -> TemporalResult<i128> {
+    // 3. Let largestUnit be DefaultTemporalLargestUnit(duration).
+    let largest_unit = default_temporal_largest_unit(duration);
+    // 4. If TemporalUnitCategory(largestUnit) is date, throw a RangeError
+    //    exception.
+    if largest_unit.is_date_unit() {
+        return Err(RangeError(
+            "cannot add a duration with days or larger units to an instant",
+        ));
+    }
+    // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(
+    //    duration).
+    let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+    // 6. Let ns be ? AddInstant(instant.[[EpochNanoseconds]],
+    //    internalDuration.[[Time]]).
+    add_instant(epoch_nanoseconds, internal_duration.time)
+}
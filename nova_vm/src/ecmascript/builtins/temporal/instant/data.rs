@@ -0,0 +1,50 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::types::OrdinaryObject,
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [8.4 Properties of Temporal.Instant Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-instant-instances)
+#[derive(Debug, Clone, Default)]
+pub struct InstantHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[EpochNanoseconds]]
+    pub(crate) epoch_nanoseconds: i128,
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for InstantHeapData<'_> {
+    type Of<'a> = InstantHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for InstantHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            epoch_nanoseconds: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            epoch_nanoseconds: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
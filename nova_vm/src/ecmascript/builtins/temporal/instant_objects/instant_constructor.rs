@@ -0,0 +1,205 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::type_conversion::{to_big_int, to_number},
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{RangeError, is_valid_epoch_nanoseconds},
+                instant::abstract_operations::{
+                    big_int_to_i128, compare_epoch_nanoseconds, create_instant_object,
+                    create_temporal_instant, to_temporal_instant,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct InstantConstructor;
+impl Builtin for InstantConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Instant;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for InstantConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalInstant;
+}
+
+struct InstantFrom;
+impl Builtin for InstantFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantConstructor::from);
+}
+struct InstantFromEpochMilliseconds;
+impl Builtin for InstantFromEpochMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromEpochMilliseconds;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantConstructor::from_epoch_milliseconds);
+}
+struct InstantFromEpochNanoseconds;
+impl Builtin for InstantFromEpochNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromEpochNanoseconds;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantConstructor::from_epoch_nanoseconds);
+}
+struct InstantCompare;
+impl Builtin for InstantCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantConstructor::compare);
+}
+
+impl InstantConstructor {
+    /// ### [8.1.1 Temporal.Instant ( epochNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal.instant)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let epoch_nanoseconds = arguments.get(0).bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.Instant requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 2. Let epochNanoseconds be ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = to_big_int(agent, epoch_nanoseconds.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+        //    RangeError exception.
+        let Some(epoch_nanoseconds) =
+            big_int_to_i128(agent, epoch_nanoseconds).filter(|ns| is_valid_epoch_nanoseconds(*ns))
+        else {
+            return Err(
+                RangeError("epoch nanoseconds are out of range").throw(agent, gc.into_nogc())
+            );
+        };
+        // 4. Return ? CreateTemporalInstant(epochNanoseconds, NewTarget).
+        create_temporal_instant(agent, epoch_nanoseconds, Some(new_target.get(agent)), gc)
+            .map(|instant| instant.into_value())
+    }
+
+    /// ### [8.2.2 Temporal.Instant.from ( item )](https://tc39.es/proposal-temporal/#sec-temporal.instant.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalInstant(item).
+        let epoch_nanoseconds =
+            to_temporal_instant(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        Ok(create_instant_object(agent, epoch_nanoseconds, gc.into_nogc()).into_value())
+    }
+
+    /// ### [8.2.3 Temporal.Instant.fromEpochMilliseconds ( epochMilliseconds )](https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochmilliseconds)
+    fn from_epoch_milliseconds<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Set epochMilliseconds to ? ToNumber(epochMilliseconds).
+        let epoch_milliseconds = to_number(agent, arguments.get(0), gc.reborrow())
+            .unbind()?
+            .into_f64(agent);
+        let gc = gc.into_nogc();
+        // 2. Set epochMilliseconds to ? NumberToBigInt(epochMilliseconds).
+        if !epoch_milliseconds.is_finite() || epoch_milliseconds.trunc() != epoch_milliseconds {
+            return Err(RangeError("epoch milliseconds must be an integer").throw(agent, gc));
+        }
+        // 3. Let epochNanoseconds be epochMilliseconds × ℤ(10**6).
+        let epoch_nanoseconds = (epoch_milliseconds as i128).saturating_mul(1_000_000);
+        // 4. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+        //    RangeError exception.
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return Err(RangeError("epoch milliseconds are out of range").throw(agent, gc));
+        }
+        // 5. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(create_instant_object(agent, epoch_nanoseconds, gc).into_value())
+    }
+
+    /// ### [8.2.4 Temporal.Instant.fromEpochNanoseconds ( epochNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochnanoseconds)
+    fn from_epoch_nanoseconds<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = to_big_int(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let epoch_nanoseconds = epoch_nanoseconds.bind(gc);
+        // 2. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+        //    RangeError exception.
+        let Some(epoch_nanoseconds) =
+            big_int_to_i128(agent, epoch_nanoseconds).filter(|ns| is_valid_epoch_nanoseconds(*ns))
+        else {
+            return Err(RangeError("epoch nanoseconds are out of range").throw(agent, gc));
+        };
+        // 3. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(create_instant_object(agent, epoch_nanoseconds, gc).into_value())
+    }
+
+    /// ### [8.2.5 Temporal.Instant.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.instant.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalInstant(one).
+        let one = to_temporal_instant(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalInstant(two).
+        let two = to_temporal_instant(agent, two.get(agent), gc)?;
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[EpochNanoseconds]],
+        //    two.[[EpochNanoseconds]])).
+        Ok(compare_epoch_nanoseconds(one, two).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let instant_prototype = intrinsics.temporal_instant_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<InstantConstructor>(agent, realm)
+            .with_property_capacity(5)
+            .with_prototype_property(instant_prototype.into_object())
+            .with_builtin_function_property::<InstantFrom>()
+            .with_builtin_function_property::<InstantFromEpochMilliseconds>()
+            .with_builtin_function_property::<InstantFromEpochNanoseconds>()
+            .with_builtin_function_property::<InstantCompare>()
+            .build();
+    }
+}
@@ -0,0 +1,584 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::get,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, NS_PER_DAY, Precision, RangeError,
+                    RoundingMode, Unit, UnitGroup, UnitValue, get_options_object,
+                    get_round_to_options, get_rounding_mode_option,
+                    get_temporal_fractional_second_digits_option, get_temporal_unit_valued_option,
+                    to_seconds_string_precision_record, validate_temporal_rounding_increment,
+                    validate_temporal_unit_value,
+                },
+                instant::{
+                    Instant,
+                    abstract_operations::{
+                        add_duration_to_instant, create_instant_object,
+                        difference_temporal_instant, epoch_nanoseconds_to_big_int,
+                        round_temporal_instant, temporal_instant_to_string, to_temporal_instant,
+                    },
+                },
+                time_zone::to_temporal_time_zone_identifier,
+                zoned_date_time::abstract_operations::create_zoned_date_time_object,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct InstantPrototype;
+
+struct InstantPrototypeGetEpochMilliseconds;
+impl Builtin for InstantPrototypeGetEpochMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochMilliseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochMilliseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::get_epoch_milliseconds);
+}
+impl BuiltinGetter for InstantPrototypeGetEpochMilliseconds {}
+struct InstantPrototypeGetEpochNanoseconds;
+impl Builtin for InstantPrototypeGetEpochNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochNanoseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochNanoseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::get_epoch_nanoseconds);
+}
+impl BuiltinGetter for InstantPrototypeGetEpochNanoseconds {}
+struct InstantPrototypeAdd;
+impl Builtin for InstantPrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::add);
+}
+struct InstantPrototypeSubtract;
+impl Builtin for InstantPrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::subtract);
+}
+struct InstantPrototypeUntil;
+impl Builtin for InstantPrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::until);
+}
+struct InstantPrototypeSince;
+impl Builtin for InstantPrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::since);
+}
+struct InstantPrototypeRound;
+impl Builtin for InstantPrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::round);
+}
+struct InstantPrototypeEquals;
+impl Builtin for InstantPrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::equals);
+}
+struct InstantPrototypeToString;
+impl Builtin for InstantPrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::to_string);
+}
+struct InstantPrototypeToLocaleString;
+impl Builtin for InstantPrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::to_locale_string);
+}
+struct InstantPrototypeToJSON;
+impl Builtin for InstantPrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::to_json);
+}
+struct InstantPrototypeValueOf;
+impl Builtin for InstantPrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::value_of);
+}
+struct InstantPrototypeToZonedDateTimeISO;
+impl Builtin for InstantPrototypeToZonedDateTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toZonedDateTimeISO;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(InstantPrototype::to_zoned_date_time_iso);
+}
+
+impl InstantPrototype {
+    /// ### [8.3.3 get Temporal.Instant.prototype.epochMilliseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochmilliseconds)
+    fn get_epoch_milliseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc)?;
+        // 3. Let ns be instant.[[EpochNanoseconds]].
+        let ns = agent[instant].epoch_nanoseconds;
+        // 4. Let ms be floor(ℝ(ns) / 10**6).
+        let ms = ns.div_euclid(1_000_000);
+        // 5. Return 𝔽(ms).
+        Ok(Value::from_f64(agent, ms as f64, gc))
+    }
+
+    /// ### [8.3.4 get Temporal.Instant.prototype.epochNanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochnanoseconds)
+    fn get_epoch_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc)?;
+        // 3. Return instant.[[EpochNanoseconds]].
+        let ns = agent[instant].epoch_nanoseconds;
+        Ok(epoch_nanoseconds_to_big_int(agent, ns, gc).into_value())
+    }
+
+    /// ### [8.3.5 Temporal.Instant.prototype.add ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToInstant(add, instant,
+        //    temporalDurationLike).
+        add_duration_to_instant(
+            agent,
+            ArithmeticOperation::Add,
+            instant.unbind(),
+            arguments.get(0),
+            gc,
+        )
+        .map(|instant| instant.into_value())
+    }
+
+    /// ### [8.3.6 Temporal.Instant.prototype.subtract ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToInstant(subtract, instant,
+        //    temporalDurationLike).
+        add_duration_to_instant(
+            agent,
+            ArithmeticOperation::Subtract,
+            instant.unbind(),
+            arguments.get(0),
+            gc,
+        )
+        .map(|instant| instant.into_value())
+    }
+
+    /// ### [8.3.7 Temporal.Instant.prototype.until ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalInstant(until, instant, other,
+        //    options).
+        difference_temporal_instant(
+            agent,
+            DifferenceOperation::Until,
+            instant.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [8.3.8 Temporal.Instant.prototype.since ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalInstant(since, instant, other,
+        //    options).
+        difference_temporal_instant(
+            agent,
+            DifferenceOperation::Since,
+            instant.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [8.3.9 Temporal.Instant.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3-9. Read the roundingIncrement, roundingMode and smallestUnit
+        //      options of roundTo.
+        let options = get_round_to_options(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let instant = instant.get(agent).bind(gc);
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(options.smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let UnitValue::Unit(smallest_unit) = options.smallest_unit else {
+            unreachable!()
+        };
+        // 11. If smallestUnit is hour, then
+        //     a. Let maximum be HoursPerDay.
+        // 12-15. Likewise for minute, second, millisecond, microsecond and
+        //        nanosecond.
+        let maximum = (NS_PER_DAY / smallest_unit.length_in_nanoseconds()) as u64;
+        // 16. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement,
+        //     maximum, true).
+        validate_temporal_rounding_increment(options.rounding_increment, maximum, true)
+            .map_err(|err| err.throw(agent, gc))?;
+        // 17. Let roundedNs be RoundTemporalInstant(
+        //     instant.[[EpochNanoseconds]], roundingIncrement, smallestUnit,
+        //     roundingMode).
+        let rounded_ns = round_temporal_instant(
+            agent[instant].epoch_nanoseconds,
+            options.rounding_increment,
+            smallest_unit,
+            options.rounding_mode,
+        );
+        // 18. Return ! CreateTemporalInstant(roundedNs).
+        Ok(create_instant_object(agent, rounded_ns, gc).into_value())
+    }
+
+    /// ### [8.3.10 Temporal.Instant.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let epoch_nanoseconds = agent[instant].epoch_nanoseconds;
+        // 3. Set other to ? ToTemporalInstant(other).
+        let other = to_temporal_instant(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 4. If instant.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]],
+        //    return false.
+        // 5. Return true.
+        Ok((epoch_nanoseconds == other).into())
+    }
+
+    /// ### [8.3.11 Temporal.Instant.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let epoch_nanoseconds = agent[instant].epoch_nanoseconds;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. NOTE: The following steps read options and perform independent
+        //    validation in alphabetical order.
+        // 5. Let digits be ? GetTemporalFractionalSecondDigitsOption(
+        //    resolvedOptions).
+        let digits = get_temporal_fractional_second_digits_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 6. Let roundingMode be ? GetRoundingModeOption(resolvedOptions,
+        //    trunc).
+        let rounding_mode = get_rounding_mode_option(
+            agent,
+            resolved_options.get(agent),
+            RoundingMode::Trunc,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions,
+        //    "smallestUnit", unset).
+        let smallest_unit = get_temporal_unit_valued_option(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.smallestUnit,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 8. Let timeZone be ? Get(resolvedOptions, "timeZone").
+        let time_zone = get(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.timeZone.into(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let time_zone = time_zone.bind(gc);
+        // 9. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let smallest_unit = match smallest_unit {
+            UnitValue::Unit(unit) => Some(unit),
+            _ => None,
+        };
+        // 10. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(RangeError("smallestUnit must not be hour").throw(agent, gc));
+        }
+        // 11. If timeZone is not undefined, then
+        //     a. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(to_temporal_time_zone_identifier(agent, time_zone, gc)?)
+        };
+        // 12. Let precision be ToSecondsStringPrecisionRecord(smallestUnit,
+        //     digits).
+        let precision = to_seconds_string_precision_record(smallest_unit, digits);
+        // 13. Let roundedNs be RoundTemporalInstant(
+        //     instant.[[EpochNanoseconds]], precision.[[Increment]],
+        //     precision.[[Unit]], roundingMode).
+        let rounded_ns = round_temporal_instant(
+            epoch_nanoseconds,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        );
+        // 14. Let roundedInstant be ! CreateTemporalInstant(roundedNs).
+        // 15. Return TemporalInstantToString(roundedInstant, timeZone,
+        //     precision.[[Precision]]).
+        let result =
+            temporal_instant_to_string(rounded_ns, time_zone.as_ref(), precision.precision);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [8.3.12 Temporal.Instant.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive date formatting this produces the same
+    /// result as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc)?;
+        // 3. Return TemporalInstantToString(instant, undefined, auto).
+        let result =
+            temporal_instant_to_string(agent[instant].epoch_nanoseconds, None, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [8.3.13 Temporal.Instant.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc)?;
+        // 3. Return TemporalInstantToString(instant, undefined, auto).
+        let result =
+            temporal_instant_to_string(agent[instant].epoch_nanoseconds, None, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [8.3.14 Temporal.Instant.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.Instant cannot be converted to a primitive value; use compare() or equals()",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [8.3.15 Temporal.Instant.prototype.toZonedDateTimeISO ( timeZone )](https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tozoneddatetimeiso)
+    fn to_zoned_date_time_iso<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant,
+        //    [[InitializedTemporalInstant]]).
+        let instant = require_temporal_instant(agent, this_value, gc)?;
+        // 3. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = to_temporal_time_zone_identifier(agent, arguments.get(0), gc)?;
+        // 4. Return ! CreateTemporalZonedDateTime(instant.[[EpochNanoseconds]],
+        //    timeZone, "iso8601").
+        let epoch_nanoseconds = agent[instant].epoch_nanoseconds;
+        Ok(create_zoned_date_time_object(agent, epoch_nanoseconds, time_zone, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_instant_prototype();
+        let instant_constructor = intrinsics.temporal_instant();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(15)
+            .with_prototype(object_prototype)
+            .with_constructor_property(instant_constructor)
+            .with_builtin_function_getter_property::<InstantPrototypeGetEpochMilliseconds>()
+            .with_builtin_function_getter_property::<InstantPrototypeGetEpochNanoseconds>()
+            .with_builtin_function_property::<InstantPrototypeAdd>()
+            .with_builtin_function_property::<InstantPrototypeSubtract>()
+            .with_builtin_function_property::<InstantPrototypeUntil>()
+            .with_builtin_function_property::<InstantPrototypeSince>()
+            .with_builtin_function_property::<InstantPrototypeRound>()
+            .with_builtin_function_property::<InstantPrototypeEquals>()
+            .with_builtin_function_property::<InstantPrototypeToString>()
+            .with_builtin_function_property::<InstantPrototypeToLocaleString>()
+            .with_builtin_function_property::<InstantPrototypeToJSON>()
+            .with_builtin_function_property::<InstantPrototypeValueOf>()
+            .with_builtin_function_property::<InstantPrototypeToZonedDateTimeISO>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_Instant.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_temporal_instant<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Instant<'a>> {
+    Instant::try_from(value)
+        .map(|instant| instant.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.Instant object",
+                gc,
+            )
+        })
+}
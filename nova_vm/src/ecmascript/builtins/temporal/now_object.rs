@@ -0,0 +1,234 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::time::SystemTime;
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin,
+            temporal::{
+                abstract_operations::{NS_MAX_INSTANT, NS_MIN_INSTANT},
+                calendar::IsoDateTime,
+                instant::abstract_operations::create_instant_object,
+                plain_date::abstract_operations::create_plain_date_object,
+                plain_date_time::abstract_operations::create_plain_date_time_object,
+                plain_time::abstract_operations::create_plain_time_object,
+                time_zone::{TimeZone, get_iso_date_time_for, to_temporal_time_zone_identifier},
+                zoned_date_time::abstract_operations::create_zoned_date_time_object,
+            },
+        },
+        execution::{Agent, JsResult, Realm},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
+    },
+    engine::context::{GcScope, NoGcScope},
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct NowObject;
+
+struct NowObjectTimeZoneId;
+impl Builtin for NowObjectTimeZoneId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.timeZoneId;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::time_zone_id);
+}
+struct NowObjectInstant;
+impl Builtin for NowObjectInstant {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.instant;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::instant);
+}
+struct NowObjectPlainDateTimeISO;
+impl Builtin for NowObjectPlainDateTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainDateTimeISO;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::plain_date_time_iso);
+}
+struct NowObjectZonedDateTimeISO;
+impl Builtin for NowObjectZonedDateTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.zonedDateTimeISO;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::zoned_date_time_iso);
+}
+struct NowObjectPlainDateISO;
+impl Builtin for NowObjectPlainDateISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainDateISO;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::plain_date_iso);
+}
+struct NowObjectPlainTimeISO;
+impl Builtin for NowObjectPlainTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainTimeISO;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NowObject::plain_time_iso);
+}
+
+impl NowObject {
+    /// ### [2.2.1 Temporal.Now.timeZoneId ( )](https://tc39.es/proposal-temporal/#sec-temporal.now.timezoneid)
+    fn time_zone_id<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return SystemTimeZoneIdentifier().
+        let identifier = TimeZone::system(agent).identifier();
+        Ok(Value::from_string(agent, identifier, gc.into_nogc()))
+    }
+
+    /// ### [2.2.2 Temporal.Now.instant ( )](https://tc39.es/proposal-temporal/#sec-temporal.now.instant)
+    fn instant<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let ns be SystemUTCEpochNanoseconds().
+        let ns = system_utc_epoch_nanoseconds();
+        // 2. Return ! CreateTemporalInstant(ns).
+        Ok(create_instant_object(agent, ns, gc.into_nogc()).into_value())
+    }
+
+    /// ### [2.2.3 Temporal.Now.plainDateTimeISO ( \[ temporalTimeZoneLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaindatetimeiso)
+    fn plain_date_time_iso<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let iso_date_time = system_date_time(agent, arguments.get(0), gc)?;
+        // 2. Return ! CreateTemporalDateTime(isoDateTime, "iso8601").
+        Ok(create_plain_date_time_object(agent, iso_date_time, gc).into_value())
+    }
+
+    /// ### [2.2.4 Temporal.Now.zonedDateTimeISO ( \[ temporalTimeZoneLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.now.zoneddatetimeiso)
+    fn zoned_date_time_iso<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let temporal_time_zone_like = arguments.get(0);
+        // 1. If temporalTimeZoneLike is undefined, then
+        let time_zone = if temporal_time_zone_like.is_undefined() {
+            // a. Let timeZone be SystemTimeZoneIdentifier().
+            TimeZone::system(agent)
+        } else {
+            // 2. Else,
+            //    a. Let timeZone be ? ToTemporalTimeZoneIdentifier(
+            //       temporalTimeZoneLike).
+            to_temporal_time_zone_identifier(agent, temporal_time_zone_like, gc)?
+        };
+        // 3. Let ns be SystemUTCEpochNanoseconds().
+        let ns = system_utc_epoch_nanoseconds();
+        // 4. Return ! CreateTemporalZonedDateTime(ns, timeZone, "iso8601").
+        Ok(create_zoned_date_time_object(agent, ns, time_zone, gc).into_value())
+    }
+
+    /// ### [2.2.5 Temporal.Now.plainDateISO ( \[ temporalTimeZoneLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaindateiso)
+    fn plain_date_iso<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let iso_date_time = system_date_time(agent, arguments.get(0), gc)?;
+        // 2. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], "iso8601").
+        Ok(create_plain_date_object(agent, iso_date_time.date, gc).into_value())
+    }
+
+    /// ### [2.2.6 Temporal.Now.plainTimeISO ( \[ temporalTimeZoneLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaintimeiso)
+    fn plain_time_iso<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let iso_date_time = system_date_time(agent, arguments.get(0), gc)?;
+        // 2. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        Ok(create_plain_time_object(agent, iso_date_time.time, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_now();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(7)
+            .with_prototype(object_prototype)
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_Now.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .with_builtin_function_property::<NowObjectTimeZoneId>()
+            .with_builtin_function_property::<NowObjectInstant>()
+            .with_builtin_function_property::<NowObjectPlainDateTimeISO>()
+            .with_builtin_function_property::<NowObjectZonedDateTimeISO>()
+            .with_builtin_function_property::<NowObjectPlainDateISO>()
+            .with_builtin_function_property::<NowObjectPlainTimeISO>()
+            .build();
+    }
+}
+
+/// ### [2.3.2 SystemUTCEpochNanoseconds ( )](https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds)
+fn system_utc_epoch_nanoseconds() -> i128 {
+    // 1. Let global be GetGlobalObject().
+    // 2. Let nowNs be HostSystemUTCEpochNanoseconds(global).
+    let now_ns = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_nanos() as i128,
+        Err(err) => -(err.duration().as_nanos() as i128),
+    };
+    // 3. Return ℤ(nowNs).
+    // NOTE: HostSystemUTCEpochNanoseconds is required to return a value
+    // within the valid epoch nanoseconds.
+    now_ns.clamp(NS_MIN_INSTANT, NS_MAX_INSTANT)
+}
+
+/// ### [2.3.3 SystemDateTime ( temporalTimeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime)
+fn system_date_time<'a>(
+    agent: &mut Agent,
+    temporal_time_zone_like: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, IsoDateTime> {
+    // 1. If temporalTimeZoneLike is undefined, then
+    let time_zone = if temporal_time_zone_like.is_undefined() {
+        // a. Let timeZone be SystemTimeZoneIdentifier().
+        TimeZone::system(agent)
+    } else {
+        // 2. Else,
+        //    a. Let timeZone be ? ToTemporalTimeZoneIdentifier(
+        //       temporalTimeZoneLike).
+        to_temporal_time_zone_identifier(agent, temporal_time_zone_like, gc)?
+    };
+    // 3. Let epochNs be SystemUTCEpochNanoseconds().
+    let epoch_ns = system_utc_epoch_nanoseconds();
+    // 4. Return GetISODateTimeFor(timeZone, epochNs).
+    Ok(get_iso_date_time_for(&time_zone, epoch_ns))
+}
@@ -0,0 +1,810 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [13.33 ISO 8601 grammar](https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar)
+//!
+//! A hand-written parser for the ISO 8601 and RFC 9557 strings accepted by
+//! Temporal.
+
+use crate::{
+    ecmascript::builtins::temporal::{
+        abstract_operations::{RangeError, TemporalResult},
+        calendar::{IsoDate, TimeRecord, is_valid_iso_date},
+        duration::abstract_operations::DurationRecord,
+    },
+    engine::context::trivially_bindable,
+};
+
+const INVALID_STRING: RangeError = RangeError("invalid ISO 8601 string");
+
+/// A parsed time zone identifier: an offset in minutes or a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TimeZoneIdentifier {
+    Offset(i16),
+    Name(std::string::String),
+}
+
+/// A parsed UTC offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UtcOffset {
+    /// The offset in nanoseconds.
+    pub(crate) nanoseconds: i64,
+    /// True if the offset was given with seconds or fractional seconds.
+    pub(crate) has_sub_minute_precision: bool,
+}
+
+/// The result of ParseISODateTime.
+#[derive(Debug, Clone)]
+pub(crate) struct IsoDateTimeParseRecord {
+    /// \[\[Year]], \[\[Month]] and \[\[Day]]
+    pub(crate) date: IsoDate,
+    /// \[\[Time]]: None is start-of-day.
+    pub(crate) time: Option<TimeRecord>,
+    /// \[\[TimeZone]].\[\[Z]]
+    pub(crate) z: bool,
+    /// \[\[TimeZone]].\[\[OffsetString]]
+    pub(crate) offset: Option<UtcOffset>,
+    /// \[\[TimeZone]].\[\[TimeZoneAnnotation]]
+    pub(crate) time_zone: Option<TimeZoneIdentifier>,
+    /// \[\[Calendar]]
+    pub(crate) calendar: Option<std::string::String>,
+}
+
+trivially_bindable!(IsoDateTimeParseRecord);
+
+/// The annotations following a date-time.
+#[derive(Debug, Default)]
+struct Annotations {
+    time_zone: Option<TimeZoneIdentifier>,
+    calendar: Option<std::string::String>,
+}
+
+/// A date-time as written, before the date is validated.
+#[derive(Debug)]
+struct RawDateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    time: Option<TimeRecord>,
+    z: bool,
+    offset: Option<UtcOffset>,
+    annotations: Annotations,
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(string: &'a str) -> Self {
+        Self {
+            bytes: string.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn is_end(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_any(&mut self, bytes: &[u8]) -> Option<u8> {
+        let byte = self.peek().filter(|byte| bytes.contains(byte))?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    /// Parse exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.bytes.get(self.position..self.position + count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.position += count;
+        Some(
+            digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    }
+
+    /// Parse one or more decimal digits, returning them as a string.
+    fn decimal_digits(&mut self) -> Option<&'a str> {
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        if self.position == start {
+            return None;
+        }
+        core::str::from_utf8(&self.bytes[start..self.position]).ok()
+    }
+
+    /// Parse two digits within an inclusive range.
+    fn two_digits(&mut self, max: u32) -> Option<u8> {
+        let start = self.position;
+        match self.digits(2) {
+            Some(value) if value <= max => Some(value as u8),
+            _ => {
+                self.position = start;
+                None
+            }
+        }
+    }
+
+    /// TemporalDecimalFraction: a separator and one to nine digits, as
+    /// nanoseconds.
+    fn fraction(&mut self) -> Option<Option<u32>> {
+        if self.eat_any(b".,").is_none() {
+            return Some(None);
+        }
+        let digits = self.decimal_digits()?;
+        if digits.len() > 9 {
+            return None;
+        }
+        let value = digits.parse::<u32>().ok()?;
+        Some(Some(value * 10u32.pow(9 - digits.len() as u32)))
+    }
+
+    /// DateYear
+    fn date_year(&mut self) -> Option<i32> {
+        if let Some(sign) = self.eat_any(b"+-") {
+            let year = self.digits(6)? as i32;
+            if sign == b'-' {
+                // It is a Syntax Error if DateYear is "-000000".
+                if year == 0 {
+                    return None;
+                }
+                return Some(-year);
+            }
+            return Some(year);
+        }
+        self.digits(4).map(|year| year as i32)
+    }
+
+    /// DateSpec: a year, month and day, with or without separators.
+    fn date_spec(&mut self) -> Option<(i32, u8, u8)> {
+        let year = self.date_year()?;
+        let extended = self.eat(b'-');
+        let month = self.two_digits(12).filter(|month| *month >= 1)?;
+        if extended && !self.eat(b'-') {
+            return None;
+        }
+        let day = self.two_digits(31).filter(|day| *day >= 1)?;
+        Some((year, month, day))
+    }
+
+    /// TimeSpec: an hour, optionally followed by minutes, seconds and a
+    /// fraction, with or without separators.
+    fn time_spec(&mut self) -> Option<TimeRecord> {
+        let hour = self.two_digits(23)?;
+        let mut time = TimeRecord {
+            hour,
+            ..TimeRecord::MIDNIGHT
+        };
+        let extended = self.eat(b':');
+        let Some(minute) = self.two_digits(59) else {
+            return if extended { None } else { Some(time) };
+        };
+        time.minute = minute;
+        if extended && !self.eat(b':') {
+            return Some(time);
+        }
+        let Some(second) = self.two_digits(60) else {
+            return if extended { None } else { Some(time) };
+        };
+        // A leap second is treated as the last second of the minute.
+        time.second = second.min(59);
+        if let Some(nanoseconds) = self.fraction()? {
+            time.millisecond = (nanoseconds / 1_000_000) as u16;
+            time.microsecond = (nanoseconds / 1_000 % 1_000) as u16;
+            time.nanosecond = (nanoseconds % 1_000) as u16;
+        }
+        Some(time)
+    }
+
+    /// UTCOffset: a sign and an hour, optionally followed by minutes, and
+    /// if sub-minute precision is allowed, seconds and a fraction.
+    fn utc_offset(&mut self, allow_sub_minute: bool) -> Option<UtcOffset> {
+        let sign: i64 = if self.eat_any(b"+-")? == b'-' { -1 } else { 1 };
+        let hour = i64::from(self.two_digits(23)?);
+        let mut offset = UtcOffset {
+            nanoseconds: hour * 3_600_000_000_000,
+            has_sub_minute_precision: false,
+        };
+        let extended = self.eat(b':');
+        let Some(minute) = self.two_digits(59) else {
+            return if extended {
+                None
+            } else {
+                offset.nanoseconds *= sign;
+                Some(offset)
+            };
+        };
+        offset.nanoseconds += i64::from(minute) * 60_000_000_000;
+        let has_seconds = if extended {
+            self.peek() == Some(b':')
+        } else {
+            self.peek().is_some_and(|byte| byte.is_ascii_digit())
+        };
+        if has_seconds {
+            if !allow_sub_minute {
+                return None;
+            }
+            if extended {
+                self.eat(b':');
+            }
+            let second = i64::from(self.two_digits(59)?);
+            offset.nanoseconds += second * 1_000_000_000;
+            offset.nanoseconds += i64::from(self.fraction()?.unwrap_or(0));
+            offset.has_sub_minute_precision = true;
+        }
+        offset.nanoseconds *= sign;
+        Some(offset)
+    }
+
+    /// DateTimeUTCOffset: the UTC designator or a UTC offset.
+    fn date_time_utc_offset(&mut self) -> Option<(bool, Option<UtcOffset>)> {
+        if self.eat_any(b"Zz").is_some() {
+            return Some((true, None));
+        }
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            return Some((false, Some(self.utc_offset(true)?)));
+        }
+        Some((false, None))
+    }
+
+    /// Annotations: an optional time zone annotation followed by any number
+    /// of key-value annotations.
+    fn annotations(&mut self) -> Option<Annotations> {
+        let mut annotations = Annotations::default();
+        let mut calendar_was_critical = false;
+        let mut first = true;
+        while self.eat(b'[') {
+            let critical = self.eat(b'!');
+            let start = self.position;
+            let end = start + self.bytes[start..].iter().position(|byte| *byte == b']')?;
+            let content = core::str::from_utf8(&self.bytes[start..end]).ok()?;
+            self.position = end + 1;
+            let Some((key, value)) = content.split_once('=') else {
+                // TimeZoneAnnotation: only allowed before any other
+                // annotation.
+                if !first {
+                    return None;
+                }
+                annotations.time_zone = Some(parse_time_zone_identifier(content).ok()?);
+                first = false;
+                continue;
+            };
+            first = false;
+            if !is_annotation_key(key) || !is_annotation_value(value) {
+                return None;
+            }
+            if key == "u-ca" {
+                if annotations.calendar.is_none() {
+                    annotations.calendar = Some(value.into());
+                    calendar_was_critical = critical;
+                } else if critical || calendar_was_critical {
+                    // Multiple calendar annotations with one of them
+                    // critical are an error.
+                    return None;
+                }
+            } else if critical {
+                // Unknown critical annotations are an error.
+                return None;
+            }
+        }
+        Some(annotations)
+    }
+}
+
+/// AnnotationKey
+fn is_annotation_key(key: &str) -> bool {
+    let mut bytes = key.bytes();
+    bytes
+        .next()
+        .is_some_and(|byte| byte.is_ascii_lowercase() || byte == b'_')
+        && bytes.all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_' || byte == b'-'
+        })
+}
+
+/// AnnotationValue
+fn is_annotation_value(value: &str) -> bool {
+    value.split('-').all(|component| {
+        !component.is_empty() && component.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+/// TimeZoneIANAName
+fn is_time_zone_iana_name(name: &str) -> bool {
+    name.split('/').all(|component| {
+        let mut bytes = component.bytes();
+        component != "."
+            && component != ".."
+            && bytes
+                .next()
+                .is_some_and(|byte| byte.is_ascii_alphabetic() || byte == b'.' || byte == b'_')
+            && bytes.all(|byte| {
+                byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-' | b'+')
+            })
+    })
+}
+
+/// Parse a date-time: a date, optionally followed by a time and a UTC
+/// offset, and annotations.
+fn parse_raw_date_time(string: &str) -> Option<RawDateTime> {
+    let mut parser = Parser::new(string);
+    let (year, month, day) = parser.date_spec()?;
+    let (time, z, offset) = if parser.eat_any(b"Tt ").is_some() {
+        let time = parser.time_spec()?;
+        let (z, offset) = parser.date_time_utc_offset()?;
+        (Some(time), z, offset)
+    } else {
+        (None, false, None)
+    };
+    let annotations = parser.annotations()?;
+    if !parser.is_end() {
+        return None;
+    }
+    Some(RawDateTime {
+        year,
+        month,
+        day,
+        time,
+        z,
+        offset,
+        annotations,
+    })
+}
+
+/// Parse a time without a date: TimeDesignator TimeSpec or a time which is
+/// not ambiguous with a date, optionally followed by a UTC offset and
+/// annotations.
+fn parse_raw_time(string: &str) -> Option<(TimeRecord, Annotations)> {
+    let mut parser = Parser::new(string);
+    let has_designator = parser.eat_any(b"Tt").is_some();
+    let time = parser.time_spec()?;
+    // The UTC designator is not allowed in a time without a date.
+    if matches!(parser.peek(), Some(b'+' | b'-')) {
+        parser.utc_offset(true)?;
+    }
+    let time_end = parser.position;
+    let annotations = parser.annotations()?;
+    if !parser.is_end() {
+        return None;
+    }
+    // It is a Syntax Error if the time and offset without a designator can
+    // also be parsed as DateSpecMonthDay or DateSpecYearMonth.
+    if !has_designator && is_ambiguous_with_date(&string[..time_end]) {
+        return None;
+    }
+    Some((time, annotations))
+}
+
+/// Returns true if the string is a DateSpecMonthDay or a DateSpecYearMonth.
+fn is_ambiguous_with_date(string: &str) -> bool {
+    parse_month_day(string).is_some() || parse_year_month(string).is_some()
+}
+
+/// DateSpecMonthDay
+fn parse_month_day(string: &str) -> Option<(u8, u8)> {
+    let mut parser = Parser::new(string);
+    if parser.eat(b'-') && !parser.eat(b'-') {
+        return None;
+    }
+    let month = parser.two_digits(12).filter(|month| *month >= 1)?;
+    parser.eat(b'-');
+    let day = parser.two_digits(31).filter(|day| *day >= 1)?;
+    // It is a Syntax Error if the day does not exist in the month in a leap
+    // year.
+    if !parser.is_end() || !is_valid_iso_date(1972.0, f64::from(month), f64::from(day)) {
+        return None;
+    }
+    Some((month, day))
+}
+
+/// DateSpecYearMonth
+fn parse_year_month(string: &str) -> Option<(i32, u8)> {
+    let mut parser = Parser::new(string);
+    let year = parser.date_year()?;
+    parser.eat(b'-');
+    let month = parser.two_digits(12).filter(|month| *month >= 1)?;
+    if !parser.is_end() {
+        return None;
+    }
+    Some((year, month))
+}
+
+/// Parse the annotations of a TemporalMonthDayString or
+/// TemporalYearMonthString.
+fn parse_month_day_or_year_month_annotations(string: &str) -> Option<Annotations> {
+    let end = string.find('[').unwrap_or(string.len());
+    if !is_ambiguous_with_date(&string[..end]) {
+        return None;
+    }
+    let mut parser = Parser::new(string);
+    parser.position = end;
+    let annotations = parser.annotations()?;
+    parser.is_end().then_some(annotations)
+}
+
+/// Validate the date of a parsed date-time.
+fn validate_raw_date_time(raw: RawDateTime) -> TemporalResult<IsoDateTimeParseRecord> {
+    // If IsValidISODate(yearMV, monthMV, dayMV) is false, throw a RangeError
+    // exception.
+    if !is_valid_iso_date(
+        f64::from(raw.year),
+        f64::from(raw.month),
+        f64::from(raw.day),
+    ) {
+        return Err(RangeError("invalid date in ISO 8601 string"));
+    }
+    Ok(IsoDateTimeParseRecord {
+        date: IsoDate::new(raw.year, raw.month, raw.day),
+        time: raw.time,
+        z: raw.z,
+        offset: raw.offset,
+        time_zone: raw.annotations.time_zone,
+        calendar: raw.annotations.calendar,
+    })
+}
+
+/// ### [13.34 ParseISODateTime ( isoString, allowedFormats )](https://tc39.es/proposal-temporal/#sec-temporal-parseisodatetime)
+///
+/// Parses any of the formats accepted by Temporal, for the operations which
+/// only need the annotations or the time zone of the string.
+fn parse_any_iso_date_time(string: &str) -> TemporalResult<Annotations> {
+    if let Some(raw) = parse_raw_date_time(string) {
+        let record = validate_raw_date_time(raw)?;
+        return Ok(Annotations {
+            time_zone: record.time_zone,
+            calendar: record.calendar,
+        });
+    }
+    if let Some((_, annotations)) = parse_raw_time(string) {
+        return Ok(annotations);
+    }
+    parse_month_day_or_year_month_annotations(string).ok_or(INVALID_STRING)
+}
+
+/// ### [13.39 ParseTemporalInstantString ( isoString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporalinstantstring)
+///
+/// The result always has a time and either the UTC designator or an offset.
+pub(crate) fn parse_temporal_instant_string(
+    iso_string: &str,
+) -> TemporalResult<IsoDateTimeParseRecord> {
+    // 1. If ParseText(StringToCodePoints(isoString), TemporalInstantString)
+    //    is a List of errors, throw a RangeError exception.
+    let raw = parse_raw_date_time(iso_string).ok_or(INVALID_STRING)?;
+    if raw.time.is_none() || (!raw.z && raw.offset.is_none()) {
+        return Err(INVALID_STRING);
+    }
+    // 2. Let result be ? ParseISODateTime(isoString, « TemporalInstantString »).
+    // 3. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+    // 4. If result.[[TimeZone]].[[Z]] is true, then
+    //    a. Set offsetString to "+00:00".
+    // 5. Assert: offsetString is not empty.
+    // 6. Return result.
+    validate_raw_date_time(raw)
+}
+
+/// ### [13.40 ParseTemporalDateTimeString ( isoString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaldatetimestring)
+pub(crate) fn parse_temporal_date_time_string(
+    iso_string: &str,
+) -> TemporalResult<IsoDateTimeParseRecord> {
+    // 1. Let parseResult be ? ParseISODateTime(isoString,
+    //    « TemporalDateTimeString[~Zoned] »).
+    let raw = parse_raw_date_time(iso_string).ok_or(INVALID_STRING)?;
+    // The UTC designator is not allowed in a TemporalDateTimeString[~Zoned].
+    if raw.z {
+        return Err(RangeError(
+            "the UTC designator is not allowed for a plain date or time",
+        ));
+    }
+    // 2. Return parseResult.
+    validate_raw_date_time(raw)
+}
+
+/// ### [13.41 ParseTemporalTimeString ( isoString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimestring)
+pub(crate) fn parse_temporal_time_string(iso_string: &str) -> TemporalResult<TimeRecord> {
+    // 1. Let parseResult be ? ParseISODateTime(isoString,
+    //    « TemporalTimeString »).
+    if let Some((time, _)) = parse_raw_time(iso_string) {
+        return Ok(time);
+    }
+    let raw = parse_raw_date_time(iso_string).ok_or(INVALID_STRING)?;
+    // 2. If parseResult.[[TimeZone]].[[Z]] is true, throw a RangeError
+    //    exception.
+    if raw.z {
+        return Err(RangeError(
+            "the UTC designator is not allowed for a plain date or time",
+        ));
+    }
+    let record = validate_raw_date_time(raw)?;
+    // 3. Assert: parseResult.[[Time]] is not start-of-day.
+    // 4. Return parseResult.
+    record.time.ok_or(INVALID_STRING)
+}
+
+/// ### [13.42 ParseTemporalZonedDateTimeString ( isoString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporalzoneddatetimestring)
+///
+/// The result always has a time zone annotation.
+pub(crate) fn parse_temporal_zoned_date_time_string(
+    iso_string: &str,
+) -> TemporalResult<IsoDateTimeParseRecord> {
+    // 1. Let parseResult be ? ParseISODateTime(isoString,
+    //    « TemporalDateTimeString[+Zoned] »).
+    let raw = parse_raw_date_time(iso_string).ok_or(INVALID_STRING)?;
+    if raw.annotations.time_zone.is_none() {
+        return Err(RangeError(
+            "a time zone annotation is required for a zoned date-time",
+        ));
+    }
+    // 2. Return parseResult.
+    validate_raw_date_time(raw)
+}
+
+/// ParseISODateTime with the allowed formats
+/// « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned] », as
+/// used by GetTemporalRelativeToOption.
+pub(crate) fn parse_temporal_relative_to_string(
+    iso_string: &str,
+) -> TemporalResult<IsoDateTimeParseRecord> {
+    let raw = parse_raw_date_time(iso_string).ok_or(INVALID_STRING)?;
+    // The UTC designator is only allowed with a time zone annotation.
+    if raw.z && raw.annotations.time_zone.is_none() {
+        return Err(RangeError(
+            "the UTC designator is not allowed for a plain date or time",
+        ));
+    }
+    validate_raw_date_time(raw)
+}
+
+/// ### [13.35 ParseTemporalCalendarString ( string )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporalcalendarstring)
+pub(crate) fn parse_temporal_calendar_string(string: &str) -> TemporalResult<std::string::String> {
+    // 1. Let parseResult be Completion(ParseISODateTime(string,
+    //    « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned],
+    //    TemporalInstantString, TemporalTimeString, TemporalMonthDayString,
+    //    TemporalYearMonthString »)).
+    // 2. If parseResult is a normal completion, then
+    if let Ok(annotations) = parse_any_iso_date_time(string) {
+        // a. Let calendar be parseResult.[[Value]].[[Calendar]].
+        // b. If calendar is empty, return "iso8601".
+        // c. Else, return calendar.
+        return Ok(annotations.calendar.unwrap_or_else(|| "iso8601".into()));
+    }
+    // 3. Else,
+    //    a. Set parseResult to ParseText(StringToCodePoints(string),
+    //       AnnotationValue).
+    //    b. If parseResult is a List of errors, throw a RangeError exception.
+    //    c. Else, return string.
+    if is_annotation_value(string) {
+        Ok(string.into())
+    } else {
+        Err(RangeError("invalid calendar"))
+    }
+}
+
+/// ### [13.43 ParseTemporalTimeZoneString ( timeZoneString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimezonestring)
+pub(crate) fn parse_temporal_time_zone_string(
+    time_zone_string: &str,
+) -> TemporalResult<TimeZoneIdentifier> {
+    // 1. Let parseResult be ParseText(StringToCodePoints(timeZoneString),
+    //    TimeZoneIdentifier).
+    // 2. If parseResult is a Parse Node, then
+    //    a. Return ! ParseTimeZoneIdentifier(timeZoneString).
+    if let Ok(identifier) = parse_time_zone_identifier(time_zone_string) {
+        return Ok(identifier);
+    }
+    // 3. Let result be ? ParseISODateTime(timeZoneString,
+    //    « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned],
+    //    TemporalInstantString, TemporalTimeString, TemporalMonthDayString,
+    //    TemporalYearMonthString »).
+    // 4. Let timeZoneResult be result.[[TimeZone]].
+    if let Some(raw) = parse_raw_date_time(time_zone_string) {
+        let record = validate_raw_date_time(raw)?;
+        // 5. If timeZoneResult.[[TimeZoneAnnotation]] is not empty, then
+        //    a. Return ! ParseTimeZoneIdentifier(
+        //       timeZoneResult.[[TimeZoneAnnotation]]).
+        if let Some(time_zone) = record.time_zone {
+            return Ok(time_zone);
+        }
+        // 6. If timeZoneResult.[[Z]] is true, then
+        //    a. Return ! ParseTimeZoneIdentifier("UTC").
+        if record.z {
+            return Ok(TimeZoneIdentifier::Name("UTC".into()));
+        }
+        // 7. If timeZoneResult.[[OffsetString]] is not empty, then
+        //    a. Return ? ParseTimeZoneIdentifier(
+        //       timeZoneResult.[[OffsetString]]).
+        if let Some(offset) = record.offset {
+            if offset.has_sub_minute_precision {
+                return Err(RangeError(
+                    "a time zone offset must not have sub-minute precision",
+                ));
+            }
+            return Ok(TimeZoneIdentifier::Offset(
+                (offset.nanoseconds / 60_000_000_000) as i16,
+            ));
+        }
+        // 8. Throw a RangeError exception.
+        return Err(RangeError("the string does not contain a time zone"));
+    }
+    let annotations = parse_any_iso_date_time(time_zone_string)?;
+    annotations
+        .time_zone
+        .ok_or(RangeError("the string does not contain a time zone"))
+}
+
+/// ### [13.37 ParseTimeZoneIdentifier ( identifier )](https://tc39.es/proposal-temporal/#sec-parsetimezoneidentifier)
+pub(crate) fn parse_time_zone_identifier(identifier: &str) -> TemporalResult<TimeZoneIdentifier> {
+    // 1. Let parseResult be ParseText(StringToCodePoints(identifier),
+    //    TimeZoneIdentifier).
+    // 2. If parseResult is a List of errors, throw a RangeError exception.
+    if matches!(identifier.as_bytes().first(), Some(b'+' | b'-')) {
+        // 4. Else,
+        //    a. Assert: parseResult contains a UTCOffset[~SubMinutePrecision]
+        //       Parse Node.
+        //    b. Let offsetString be the source text matched by the
+        //       UTCOffset[~SubMinutePrecision] Parse Node contained within
+        //       parseResult.
+        //    c. Let offsetNanoseconds be ! ParseDateTimeUTCOffset(
+        //       offsetString).
+        //    d. Let offsetMinutes be offsetNanoseconds / (60 × 10**9).
+        //    e. Assert: offsetMinutes is an integer.
+        //    f. Return Time Zone Identifier Parse Record { [[Name]]: empty,
+        //       [[OffsetMinutes]]: offsetMinutes }.
+        let mut parser = Parser::new(identifier);
+        let offset = parser.utc_offset(false).ok_or(INVALID_STRING)?;
+        if !parser.is_end() {
+            return Err(INVALID_STRING);
+        }
+        return Ok(TimeZoneIdentifier::Offset(
+            (offset.nanoseconds / 60_000_000_000) as i16,
+        ));
+    }
+    // 3. If parseResult contains a TimeZoneIANAName Parse Node, then
+    //    a. Let name be the source text matched by the TimeZoneIANAName Parse
+    //       Node contained within parseResult.
+    //    b. NOTE: name is syntactically valid, but does not necessarily
+    //       conform to IANA Time Zone Database naming guidelines or
+    //       correspond with an available named time zone identifier.
+    //    c. Return Time Zone Identifier Parse Record { [[Name]]: name,
+    //       [[OffsetMinutes]]: empty }.
+    if is_time_zone_iana_name(identifier) {
+        Ok(TimeZoneIdentifier::Name(identifier.into()))
+    } else {
+        Err(RangeError("invalid time zone identifier"))
+    }
+}
+
+/// ### [13.38 ParseDateTimeUTCOffset ( offsetString )](https://tc39.es/proposal-temporal/#sec-temporal-parsedatetimeutcoffset)
+pub(crate) fn parse_date_time_utc_offset(offset_string: &str) -> TemporalResult<UtcOffset> {
+    // 1. Let parseResult be ParseText(StringToCodePoints(offsetString),
+    //    UTCOffset[+SubMinutePrecision]).
+    // 2. If parseResult is a List of errors, throw a RangeError exception.
+    let mut parser = Parser::new(offset_string);
+    let offset = parser
+        .utc_offset(true)
+        .filter(|_| parser.is_end())
+        .ok_or(RangeError("invalid UTC offset"))?;
+    // 3-17. Return sign × (((hours × 60 + minutes) × 60 + seconds) × 10**9 +
+    //       nanoseconds).
+    Ok(offset)
+}
+
+/// ### [7.5.22 ParseTemporalDurationString ( isoString )](https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaldurationstring)
+///
+/// The result is not yet validated with IsValidDuration.
+pub(crate) fn parse_temporal_duration_string(iso_string: &str) -> TemporalResult<DurationRecord> {
+    // 1. Let duration be ParseText(StringToCodePoints(isoString),
+    //    TemporalDurationString).
+    // 2. If duration is a List of errors, throw a RangeError exception.
+    parse_duration(iso_string).ok_or(RangeError("invalid ISO 8601 duration string"))
+}
+
+fn parse_duration(iso_string: &str) -> Option<DurationRecord> {
+    let mut parser = Parser::new(iso_string);
+    // 21. If sign contains the code point U+002D (HYPHEN-MINUS), then
+    //     a. Let factor be -1.
+    // 22. Else,
+    //     a. Let factor be 1.
+    let factor = match parser.eat_any(b"+-") {
+        Some(b'-') => -1.0,
+        _ => 1.0,
+    };
+    parser.eat_any(b"Pp")?;
+    let mut duration = DurationRecord::default();
+    let mut has_component = false;
+    // DurationDate: years, months, weeks and days, in order.
+    let date_designators: [&[u8]; 4] = [b"Yy", b"Mm", b"Ww", b"Dd"];
+    let mut next_designator = 0;
+    while parser.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+        let value = parser.decimal_digits()?.parse::<f64>().ok()?;
+        let designator = parser.peek()?;
+        let index =
+            (next_designator..4).find(|index| date_designators[*index].contains(&designator))?;
+        parser.position += 1;
+        match index {
+            0 => duration.years = value,
+            1 => duration.months = value,
+            2 => duration.weeks = value,
+            _ => duration.days = value,
+        }
+        next_designator = index + 1;
+        has_component = true;
+    }
+    if parser.eat_any(b"Tt").is_some() {
+        // DurationTime: hours, minutes and seconds, in order. Only the last
+        // component may have a fraction.
+        let time_designators: [&[u8]; 3] = [b"Hh", b"Mm", b"Ss"];
+        let mut next_designator = 0;
+        let mut has_time_component = false;
+        let mut fraction_seen = false;
+        while parser.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            if fraction_seen {
+                return None;
+            }
+            let value = parser.decimal_digits()?.parse::<f64>().ok()?;
+            let fraction = parser.fraction()?;
+            let designator = parser.peek()?;
+            let index = (next_designator..3)
+                .find(|index| time_designators[*index].contains(&designator))?;
+            parser.position += 1;
+            // The fraction of a unit as a number of nanoseconds.
+            let fraction_nanoseconds = fraction.map(|fraction| {
+                let unit_nanoseconds: u64 =
+                    [3_600_000_000_000, 60_000_000_000, 1_000_000_000][index];
+                u64::from(fraction) * unit_nanoseconds / 1_000_000_000
+            });
+            match index {
+                0 => duration.hours = value,
+                1 => duration.minutes = value,
+                _ => duration.seconds = value,
+            }
+            if let Some(nanoseconds) = fraction_nanoseconds {
+                fraction_seen = true;
+                // 13-20. Balance the fractional part of the unit into the
+                //        smaller units.
+                if index == 0 {
+                    duration.minutes = (nanoseconds / 60_000_000_000) as f64;
+                }
+                if index <= 1 {
+                    duration.seconds = (nanoseconds / 1_000_000_000 % 60) as f64;
+                }
+                duration.milliseconds = (nanoseconds / 1_000_000 % 1_000) as f64;
+                duration.microseconds = (nanoseconds / 1_000 % 1_000) as f64;
+                duration.nanoseconds = (nanoseconds % 1_000) as f64;
+            }
+            next_designator = index + 1;
+            has_time_component = true;
+        }
+        if !has_time_component {
+            return None;
+        }
+        has_component = true;
+    }
+    if !has_component || !parser.is_end() {
+        return None;
+    }
+    // 23. Return ? CreateDurationRecord(yearsMV × factor, monthsMV × factor,
+    //     weeksMV × factor, daysMV × factor, hoursMV × factor, minutesMV ×
+    //     factor, secondsMV × factor, millisecondsMV × factor,
+    //     microsecondsMV × factor, nanosecondsMV × factor).
+    Some(duration.map(|value| value * factor + 0.0))
+}
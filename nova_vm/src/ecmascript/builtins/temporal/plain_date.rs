@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::PlainDateIndex,
+    },
+};
+
+use self::data::PlainDateHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PlainDate<'a>(pub(crate) PlainDateIndex<'a>);
+
+impl PlainDate<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, PlainDate<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(PlainDateIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainDate<'_> {
+    type Of<'a> = PlainDate<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for PlainDate<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainDate<'a>> for Value<'a> {
+    fn from(value: PlainDate<'a>) -> Self {
+        Value::PlainDate(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for PlainDate<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainDate<'a>> for Object<'a> {
+    fn from(value: PlainDate) -> Self {
+        Object::PlainDate(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for PlainDate<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::PlainDate(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for PlainDate<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::PlainDate(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for PlainDate<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainDate;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for PlainDate<'a> {}
+
+impl Index<PlainDate<'_>> for Agent {
+    type Output = PlainDateHeapData<'static>;
+
+    fn index(&self, index: PlainDate) -> &Self::Output {
+        &self.heap.plain_dates[index]
+    }
+}
+
+impl IndexMut<PlainDate<'_>> for Agent {
+    fn index_mut(&mut self, index: PlainDate) -> &mut Self::Output {
+        &mut self.heap.plain_dates[index]
+    }
+}
+
+impl Index<PlainDate<'_>> for Vec<Option<PlainDateHeapData<'static>>> {
+    type Output = PlainDateHeapData<'static>;
+
+    fn index(&self, index: PlainDate) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("PlainDate out of bounds")
+            .as_ref()
+            .expect("PlainDate slot empty")
+    }
+}
+
+impl IndexMut<PlainDate<'_>> for Vec<Option<PlainDateHeapData<'static>>> {
+    fn index_mut(&mut self, index: PlainDate) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("PlainDate out of bounds")
+            .as_mut()
+            .expect("PlainDate slot empty")
+    }
+}
+
+impl Rootable for PlainDate<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::PlainDate(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::PlainDate(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for PlainDate<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_dates.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_dates.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<PlainDateHeapData<'a>, PlainDate<'a>> for Heap {
+    fn create(&mut self, data: PlainDateHeapData<'a>) -> PlainDate<'a> {
+        self.plain_dates.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<PlainDateHeapData<'static>>>();
+        }
+        PlainDate(PlainDateIndex::last(&self.plain_dates))
+    }
+}
@@ -0,0 +1,336 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [3.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-plaindate-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, ShowCalendar, Unit, UnitGroup,
+                    format_calendar_annotation, get_difference_settings, get_options_object,
+                    get_overflow_from_options, pad_iso_year,
+                },
+                calendar::{
+                    Field, ISO8601, IsoDate, IsoDateTime, RequiredFields, TimeRecord,
+                    calendar_date_add, calendar_date_from_fields, calendar_date_until,
+                    canonicalize_calendar, check_iso_date_within_limits,
+                    get_temporal_calendar_identifier_with_iso_default, prepare_calendar_fields,
+                },
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        DurationRecord, InternalDuration, create_duration_object,
+                        round_relative_duration, temporal_duration_from_internal,
+                        to_date_duration_record_without_time, to_temporal_duration,
+                    },
+                },
+                parsing::parse_temporal_date_time_string,
+                plain_date::{PlainDate, data::PlainDateHeapData},
+                time_zone::get_iso_date_time_for,
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{Function, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// ### [3.5.4 CreateTemporalDate ( isoDate, calendar \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldate)
+pub(crate) fn create_temporal_date<'gc>(
+    agent: &mut Agent,
+    iso_date: IsoDate,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainDate<'gc>> {
+    // 1. If ISODateWithinLimits(isoDate) is false, throw a RangeError
+    //    exception.
+    let iso_date =
+        check_iso_date_within_limits(iso_date).map_err(|err| err.throw(agent, gc.nogc()));
+    let iso_date = iso_date.unbind()?;
+    // 2. If newTarget is not present, set newTarget to %Temporal.PlainDate%.
+    let Some(new_target) = new_target else {
+        return Ok(create_plain_date_object(agent, iso_date, gc.into_nogc()));
+    };
+    // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.PlainDate.prototype%", « [[InitializedTemporalDate]],
+    //    [[ISODate]], [[Calendar]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalPlainDate,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = PlainDate::try_from(object.bind(gc)).unwrap();
+    // 4. Set object.[[ISODate]] to isoDate.
+    // 5. Set object.[[Calendar]] to calendar.
+    agent[object].iso_date = iso_date;
+    // 6. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalDate without a newTarget, for a date within the limits.
+pub(crate) fn create_plain_date_object<'gc>(
+    agent: &mut Agent,
+    iso_date: IsoDate,
+    gc: NoGcScope<'gc, '_>,
+) -> PlainDate<'gc> {
+    agent
+        .heap
+        .create(PlainDateHeapData {
+            object_index: None,
+            iso_date,
+        })
+        .bind(gc)
+}
+
+/// ### [3.5.5 ToTemporalDate ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate)
+///
+/// Returns the date of the Temporal.PlainDate.
+pub(crate) fn to_temporal_date<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IsoDate> {
+    let item = item.bind(gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    let options = options.scope(agent, gc.nogc());
+    // 2. If item is an Object, then
+    if let Ok(item) = Object::try_from(item) {
+        let iso_date = match item {
+            // a. If item has an [[InitializedTemporalDate]] internal slot,
+            //    then
+            //    iii. Return ! CreateTemporalDate(item.[[ISODate]],
+            //         item.[[Calendar]]).
+            Object::PlainDate(plain_date) => Some(agent[plain_date].iso_date),
+            // b. If item has an [[InitializedTemporalZonedDateTime]] internal
+            //    slot, then
+            //    i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]],
+            //       item.[[EpochNanoseconds]]).
+            //    iv. Return ! CreateTemporalDate(isoDateTime.[[ISODate]],
+            //        item.[[Calendar]]).
+            Object::ZonedDateTime(zoned_date_time) => {
+                let data = &agent[zoned_date_time];
+                Some(get_iso_date_time_for(&data.time_zone, data.epoch_nanoseconds).date)
+            }
+            // c. If item has an [[InitializedTemporalDateTime]] internal slot,
+            //    then
+            //    iii. Return ! CreateTemporalDate(
+            //         item.[[ISODateTime]].[[ISODate]], item.[[Calendar]]).
+            Object::PlainDateTime(plain_date_time) => {
+                Some(agent[plain_date_time].iso_date_time.date)
+            }
+            _ => None,
+        };
+        if let Some(iso_date) = iso_date {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow_from_options(agent, options.get(agent), gc)?;
+            return Ok(iso_date);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(
+        //    item).
+        get_temporal_calendar_identifier_with_iso_default(agent, item.get(agent), gc.reborrow())
+            .unbind()?;
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year,
+        //    month, month-code, day », « », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            item.get(agent),
+            &[Field::DATE],
+            RequiredFields::List(&[]),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // h. Let isoDate be ? CalendarDateFromFields(calendar, fields,
+        //    overflow).
+        // i. Return ! CreateTemporalDate(isoDate, calendar).
+        let iso_date = calendar_date_from_fields(&fields, overflow);
+        return iso_date.map_err(|err| err.throw(agent, gc));
+    }
+    // 3. If item is not a String, throw a TypeError exception.
+    let Ok(item) = String::try_from(item) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "date must be a Temporal.PlainDate, an object or a string",
+            gc.into_nogc(),
+        ));
+    };
+    // 4. Let result be ? ParseISODateTime(item,
+    //    « TemporalDateTimeString[~Zoned] »).
+    // 5. Let calendar be result.[[Calendar]].
+    // 6. If calendar is empty, set calendar to "iso8601".
+    // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+    let result = parse_temporal_date_time_string(&item.as_wtf8(agent).to_string_lossy()).and_then(
+        |result| {
+            canonicalize_calendar(result.calendar.as_deref().unwrap_or(ISO8601))?;
+            Ok(result.date)
+        },
+    );
+    let iso_date = result.map_err(|err| err.throw(agent, gc.nogc())).unbind()?;
+    // 8. Let resolvedOptions be ? GetOptionsObject(options).
+    // 9. Perform ? GetTemporalOverflowOption(resolvedOptions).
+    get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 10. Let isoDate be CreateISODateRecord(result.[[Year]],
+    //     result.[[Month]], result.[[Day]]).
+    // 11. Return ? CreateTemporalDate(isoDate, calendar).
+    check_iso_date_within_limits(iso_date).map_err(|err| err.throw(agent, gc))
+}
+
+/// ### [3.5.11 TemporalDateToString ( temporalDate, showCalendar )](https://tc39.es/proposal-temporal/#sec-temporal-temporaldatetostring)
+pub(crate) fn temporal_date_to_string(
+    iso_date: IsoDate,
+    show_calendar: ShowCalendar,
+) -> std::string::String {
+    // 1. Let year be PadISOYear(temporalDate.[[ISODate]].[[Year]]).
+    let year = pad_iso_year(iso_date.year);
+    // 2. Let month be ToZeroPaddedDecimalString(
+    //    temporalDate.[[ISODate]].[[Month]], 2).
+    // 3. Let day be ToZeroPaddedDecimalString(
+    //    temporalDate.[[ISODate]].[[Day]], 2).
+    // 4. Let calendar be FormatCalendarAnnotation(temporalDate.[[Calendar]],
+    //    showCalendar).
+    let calendar = format_calendar_annotation(ISO8601, show_calendar);
+    // 5. Return the string-concatenation of year, the code unit 0x002D
+    //    (HYPHEN-MINUS), month, the code unit 0x002D (HYPHEN-MINUS), day,
+    //    and calendar.
+    format!("{year}-{:02}-{:02}{calendar}", iso_date.month, iso_date.day)
+}
+
+/// ### [3.5.13 DifferenceTemporalPlainDate ( operation, temporalDate, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate)
+pub(crate) fn difference_temporal_plain_date<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    temporal_date: PlainDate,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let iso_date = agent[temporal_date].iso_date;
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalDate(other).
+    let other = to_temporal_date(agent, other, Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. If CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    date, « », day, day).
+    let settings = get_difference_settings(
+        agent,
+        operation,
+        resolved_options,
+        UnitGroup::Date,
+        &[],
+        Unit::Day,
+        Unit::Day,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 5. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) = 0,
+    //    then
+    if iso_date == other {
+        // a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        return Ok(create_duration_object(agent, DurationRecord::default(), gc));
+    }
+    // 6. Let dateDifference be CalendarDateUntil(calendar,
+    //    temporalDate.[[ISODate]], other.[[ISODate]],
+    //    settings.[[LargestUnit]]).
+    let date_difference = calendar_date_until(iso_date, other, settings.largest_unit);
+    // 7. Let duration be CombineDateAndTimeDuration(dateDifference, 0).
+    let duration = InternalDuration::new(date_difference, 0);
+    // 8. If settings.[[SmallestUnit]] is not day or
+    //    settings.[[RoundingIncrement]] ≠ 1, then
+    let duration = if settings.smallest_unit != Unit::Day || settings.rounding_increment != 1 {
+        // a. Let isoDateTime be CombineISODateAndTimeRecord(
+        //    temporalDate.[[ISODate]], MidnightTimeRecord()).
+        let iso_date_time = IsoDateTime::new(iso_date, TimeRecord::MIDNIGHT);
+        // b. Let isoDateTimeOther be CombineISODateAndTimeRecord(
+        //    other.[[ISODate]], MidnightTimeRecord()).
+        let iso_date_time_other = IsoDateTime::new(other, TimeRecord::MIDNIGHT);
+        // c. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTimeOther).
+        let dest_epoch_ns = iso_date_time_other.to_epoch_nanoseconds();
+        // d. Set duration to ? RoundRelativeDuration(duration, destEpochNs,
+        //    isoDateTime, unset, calendar, settings.[[LargestUnit]],
+        //    settings.[[RoundingIncrement]], settings.[[SmallestUnit]],
+        //    settings.[[RoundingMode]]).
+        round_relative_duration(
+            duration,
+            dest_epoch_ns,
+            iso_date_time,
+            None,
+            settings.largest_unit,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        )
+    } else {
+        Ok(duration)
+    };
+    // 9. Let result be ! TemporalDurationFromInternal(duration, day).
+    let result = duration
+        .and_then(|duration| temporal_duration_from_internal(duration, Unit::Day))
+        .map_err(|err| err.throw(agent, gc))?;
+    // 10. If operation is since, set result to
+    //     CreateNegatedTemporalDuration(result).
+    let result = match operation {
+        DifferenceOperation::Since => result.negated(),
+        DifferenceOperation::Until => result,
+    };
+    // 11. Return result.
+    Ok(create_duration_object(agent, result, gc))
+}
+
+/// ### [3.5.14 AddDurationToDate ( operation, temporalDate, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate)
+pub(crate) fn add_duration_to_date<'gc>(
+    agent: &mut Agent,
+    operation: ArithmeticOperation,
+    temporal_date: PlainDate,
+    temporal_duration_like: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainDate<'gc>> {
+    // 1. Let calendar be temporalDate.[[Calendar]].
+    let iso_date = agent[temporal_date].iso_date;
+    let options = options.scope(agent, gc.nogc());
+    // 2. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, temporal_duration_like, gc.reborrow()).unbind()?;
+    // 3. If operation is subtract, set duration to
+    //    CreateNegatedTemporalDuration(duration).
+    let duration = match operation {
+        ArithmeticOperation::Subtract => duration.negated(),
+        ArithmeticOperation::Add => duration,
+    };
+    // 4. Let dateDuration be ? ToDateDurationRecordWithoutTime(duration).
+    let date_duration = to_date_duration_record_without_time(&duration)
+        .map_err(|err| err.throw(agent, gc.nogc()))
+        .unbind()?;
+    // 5. Let resolvedOptions be ? GetOptionsObject(options).
+    // 6. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow = get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 7. Let result be ? CalendarDateAdd(calendar, temporalDate.[[ISODate]],
+    //    dateDuration, overflow).
+    let result =
+        calendar_date_add(iso_date, date_duration, overflow).map_err(|err| err.throw(agent, gc))?;
+    // 8. Return ! CreateTemporalDate(result, calendar).
+    Ok(create_plain_date_object(agent, result, gc))
+}
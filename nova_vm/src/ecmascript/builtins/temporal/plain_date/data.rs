@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{builtins::temporal::calendar::IsoDate, types::OrdinaryObject},
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [3.4 Properties of Temporal.PlainDate Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-plaindate-instances)
+#[derive(Debug, Clone)]
+pub struct PlainDateHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[ISODate]]; the \[\[Calendar]] is always "iso8601".
+    pub(crate) iso_date: IsoDate,
+}
+
+impl Default for PlainDateHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            iso_date: IsoDate::new(1970, 1, 1),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainDateHeapData<'_> {
+    type Of<'a> = PlainDateHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PlainDateHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            iso_date: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            iso_date: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{RangeError, to_integer_with_truncation},
+                calendar::{IsoDate, canonicalize_calendar, is_valid_iso_date},
+                plain_date::abstract_operations::{
+                    create_plain_date_object, create_temporal_date, to_temporal_date,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct PlainDateConstructor;
+impl Builtin for PlainDateConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainDate;
+
+    const LENGTH: u8 = 3;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for PlainDateConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainDate;
+}
+
+struct PlainDateFrom;
+impl Builtin for PlainDateFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateConstructor::from);
+}
+struct PlainDateCompare;
+impl Builtin for PlainDateCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateConstructor::compare);
+}
+
+impl PlainDateConstructor {
+    /// ### [3.1.1 Temporal.PlainDate ( isoYear, isoMonth, isoDay \[ , calendar \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.PlainDate requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        let values = (0..4)
+            .map(|index| arguments.get(index).scope(agent, gc.nogc()))
+            .collect::<Vec<_>>();
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let mut fields = [0.0; 3];
+        for (field, value) in fields.iter_mut().zip(&values) {
+            *field = to_integer_with_truncation(agent, value.get(agent), gc.reborrow()).unbind()?;
+        }
+        let [y, m, d] = fields;
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        let calendar = values[3].get(agent).bind(gc.nogc());
+        if !calendar.is_undefined() {
+            // 6. If calendar is not a String, throw a TypeError exception.
+            let Ok(calendar) = String::try_from(calendar) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "calendar must be a string",
+                    gc.into_nogc(),
+                ));
+            };
+            // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+            let result = canonicalize_calendar(&calendar.as_wtf8(agent).to_string_lossy());
+            if let Err(err) = result {
+                return Err(err.throw(agent, gc.into_nogc()));
+            }
+        }
+        // 8. If IsValidISODate(y, m, d) is false, throw a RangeError
+        //    exception.
+        if !is_valid_iso_date(y, m, d) {
+            return Err(RangeError("invalid date").throw(agent, gc.into_nogc()));
+        }
+        // 9. Let isoDate be CreateISODateRecord(y, m, d).
+        let iso_date = IsoDate::new(y as i32, m as u8, d as u8);
+        // 10. Return ? CreateTemporalDate(isoDate, calendar, NewTarget).
+        create_temporal_date(agent, iso_date, Some(new_target.get(agent)), gc)
+            .map(|plain_date| plain_date.into_value())
+    }
+
+    /// ### [3.2.2 Temporal.PlainDate.from ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalDate(item, options).
+        let iso_date =
+            to_temporal_date(agent, arguments.get(0), arguments.get(1), gc.reborrow()).unbind()?;
+        Ok(create_plain_date_object(agent, iso_date, gc.into_nogc()).into_value())
+    }
+
+    /// ### [3.2.3 Temporal.PlainDate.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalDate(one).
+        let one =
+            to_temporal_date(agent, arguments.get(0), Value::Undefined, gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalDate(two).
+        let two = to_temporal_date(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareISODate(one.[[ISODate]], two.[[ISODate]])).
+        Ok((one.cmp(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_date_prototype = intrinsics.temporal_plain_date_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<PlainDateConstructor>(agent, realm)
+            .with_property_capacity(3)
+            .with_prototype_property(plain_date_prototype.into_object())
+            .with_builtin_function_property::<PlainDateFrom>()
+            .with_builtin_function_property::<PlainDateCompare>()
+            .build();
+    }
+}
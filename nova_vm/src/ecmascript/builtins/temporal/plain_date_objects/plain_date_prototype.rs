@@ -0,0 +1,874 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::get,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, ShowCalendar, get_options_object,
+                    get_overflow_from_options, get_temporal_show_calendar_name_option,
+                    is_partial_temporal_object,
+                },
+                calendar::{
+                    CalendarDateField, CalendarFields, Field, ISO8601, IsoDateTime, RequiredFields,
+                    TimeRecord, calendar_date_from_fields, calendar_iso_to_date_field,
+                    prepare_calendar_fields, to_temporal_calendar_identifier,
+                },
+                plain_date::{
+                    PlainDate,
+                    abstract_operations::{
+                        add_duration_to_date, create_plain_date_object,
+                        difference_temporal_plain_date, temporal_date_to_string, to_temporal_date,
+                    },
+                },
+                plain_date_time::abstract_operations::create_temporal_date_time,
+                plain_time::abstract_operations::to_temporal_time,
+                time_zone::to_temporal_time_zone_identifier,
+                zoned_date_time::abstract_operations::{
+                    create_zoned_date_time_object, epoch_nanoseconds_for_date_and_time,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, Object, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct PlainDatePrototype;
+
+struct PlainDatePrototypeGetCalendarId;
+impl Builtin for PlainDatePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::get_calendar_id);
+}
+impl BuiltinGetter for PlainDatePrototypeGetCalendarId {}
+struct PlainDatePrototypeGetEra;
+impl Builtin for PlainDatePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::Era as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetEra {}
+struct PlainDatePrototypeGetEraYear;
+impl Builtin for PlainDatePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::EraYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetEraYear {}
+struct PlainDatePrototypeGetYear;
+impl Builtin for PlainDatePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::Year as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetYear {}
+struct PlainDatePrototypeGetMonth;
+impl Builtin for PlainDatePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::Month as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetMonth {}
+struct PlainDatePrototypeGetMonthCode;
+impl Builtin for PlainDatePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::MonthCode as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetMonthCode {}
+struct PlainDatePrototypeGetDay;
+impl Builtin for PlainDatePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::Day as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDay {}
+struct PlainDatePrototypeGetDayOfWeek;
+impl Builtin for PlainDatePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::DayOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDayOfWeek {}
+struct PlainDatePrototypeGetDayOfYear;
+impl Builtin for PlainDatePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::DayOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDayOfYear {}
+struct PlainDatePrototypeGetWeekOfYear;
+impl Builtin for PlainDatePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::WeekOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetWeekOfYear {}
+struct PlainDatePrototypeGetYearOfWeek;
+impl Builtin for PlainDatePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::YearOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetYearOfWeek {}
+struct PlainDatePrototypeGetDaysInWeek;
+impl Builtin for PlainDatePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::DaysInWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDaysInWeek {}
+struct PlainDatePrototypeGetDaysInMonth;
+impl Builtin for PlainDatePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::DaysInMonth as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDaysInMonth {}
+struct PlainDatePrototypeGetDaysInYear;
+impl Builtin for PlainDatePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::DaysInYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetDaysInYear {}
+struct PlainDatePrototypeGetMonthsInYear;
+impl Builtin for PlainDatePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::MonthsInYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetMonthsInYear {}
+struct PlainDatePrototypeGetInLeapYear;
+impl Builtin for PlainDatePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDatePrototype::get_date_field::<{ CalendarDateField::InLeapYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDatePrototypeGetInLeapYear {}
+struct PlainDatePrototypeAdd;
+impl Builtin for PlainDatePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::add);
+}
+struct PlainDatePrototypeSubtract;
+impl Builtin for PlainDatePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::subtract);
+}
+struct PlainDatePrototypeWith;
+impl Builtin for PlainDatePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::with);
+}
+struct PlainDatePrototypeWithCalendar;
+impl Builtin for PlainDatePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::with_calendar);
+}
+struct PlainDatePrototypeUntil;
+impl Builtin for PlainDatePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::until);
+}
+struct PlainDatePrototypeSince;
+impl Builtin for PlainDatePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::since);
+}
+struct PlainDatePrototypeEquals;
+impl Builtin for PlainDatePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::equals);
+}
+struct PlainDatePrototypeToPlainDateTime;
+impl Builtin for PlainDatePrototypeToPlainDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDateTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::to_plain_date_time);
+}
+struct PlainDatePrototypeToZonedDateTime;
+impl Builtin for PlainDatePrototypeToZonedDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toZonedDateTime;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::to_zoned_date_time);
+}
+struct PlainDatePrototypeToString;
+impl Builtin for PlainDatePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::to_string);
+}
+struct PlainDatePrototypeToLocaleString;
+impl Builtin for PlainDatePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::to_locale_string);
+}
+struct PlainDatePrototypeToJSON;
+impl Builtin for PlainDatePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::to_json);
+}
+struct PlainDatePrototypeValueOf;
+impl Builtin for PlainDatePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDatePrototype::value_of);
+}
+
+impl PlainDatePrototype {
+    /// ### [3.3.3 get Temporal.PlainDate.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        require_temporal_date(agent, this_value, gc)?;
+        // 3. Return plainDate.[[Calendar]].
+        Ok(Value::from_static_str(agent, ISO8601, gc))
+    }
+
+    /// ### [3.3.4 get Temporal.PlainDate.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.era)
+    ///
+    /// The getters from era to inLeapYear, with the field of the Calendar
+    /// Date Record as an index into the fields.
+    fn get_date_field<'gc, const FIELD: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDate.[[Calendar]],
+        //    plainDate.[[ISODate]]).[[Era]].
+        let iso_date = agent[plain_date].iso_date;
+        Ok(calendar_iso_to_date_field(agent, iso_date, FIELD, gc))
+    }
+
+    /// ### [3.3.22 Temporal.PlainDate.prototype.add ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDate(add, plainDate, temporalDurationLike,
+        //    options).
+        add_duration_to_date(
+            agent,
+            ArithmeticOperation::Add,
+            plain_date.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|plain_date| plain_date.into_value())
+    }
+
+    /// ### [3.3.23 Temporal.PlainDate.prototype.subtract ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDate(subtract, plainDate,
+        //    temporalDurationLike, options).
+        add_duration_to_date(
+            agent,
+            ArithmeticOperation::Subtract,
+            plain_date.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|plain_date| plain_date.into_value())
+    }
+
+    /// ### [3.3.24 Temporal.PlainDate.prototype.with ( temporalDateLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date].iso_date;
+        // 3. If ? IsPartialTemporalObject(temporalDateLike) is false, throw a
+        //    TypeError exception.
+        let Some(temporal_date_like) =
+            is_partial_temporal_object(agent, arguments.get(0), gc.reborrow()).unbind()?
+        else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "with requires a partial Temporal.PlainDate-like object",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let calendar be plainDate.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar, plainDate.[[ISODate]],
+        //    date).
+        let fields = CalendarFields::from_iso_date(iso_date);
+        // 6. Let partialDate be ? PrepareCalendarFields(calendar,
+        //    temporalDateLike, « year, month, month-code, day », « »,
+        //    partial).
+        let partial_date = prepare_calendar_fields(
+            agent,
+            temporal_date_like,
+            &[Field::DATE],
+            RequiredFields::Partial,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Set fields to CalendarMergeFields(calendar, fields,
+        //    partialDate).
+        let fields = fields.merge(partial_date);
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 10. Let isoDate be ? CalendarDateFromFields(calendar, fields,
+        //     overflow).
+        let result = calendar_date_from_fields(&fields, overflow);
+        let iso_date = result.map_err(|err| err.throw(agent, gc))?;
+        // 11. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(create_plain_date_object(agent, iso_date, gc).into_value())
+    }
+
+    /// ### [3.3.25 Temporal.PlainDate.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        to_temporal_calendar_identifier(agent, arguments.get(0), gc)?;
+        // 4. Return ! CreateTemporalDate(plainDate.[[ISODate]], calendar).
+        let iso_date = agent[plain_date].iso_date;
+        Ok(create_plain_date_object(agent, iso_date, gc).into_value())
+    }
+
+    /// ### [3.3.26 Temporal.PlainDate.prototype.until ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDate(until, plainDate, other,
+        //    options).
+        difference_temporal_plain_date(
+            agent,
+            DifferenceOperation::Until,
+            plain_date.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [3.3.27 Temporal.PlainDate.prototype.since ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDate(since, plainDate, other,
+        //    options).
+        difference_temporal_plain_date(
+            agent,
+            DifferenceOperation::Since,
+            plain_date.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [3.3.28 Temporal.PlainDate.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date].iso_date;
+        // 3. Set other to ? ToTemporalDate(other).
+        let other =
+            to_temporal_date(agent, arguments.get(0), Value::Undefined, gc.reborrow()).unbind()?;
+        // 4. If CompareISODate(plainDate.[[ISODate]], other.[[ISODate]]) ≠ 0,
+        //    return false.
+        // 5. Return CalendarEquals(plainDate.[[Calendar]],
+        //    other.[[Calendar]]).
+        Ok((iso_date == other).into())
+    }
+
+    /// ### [3.3.29 Temporal.PlainDate.prototype.toPlainDateTime ( \[ temporalTime \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplaindatetime)
+    fn to_plain_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_time = arguments.get(0).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date].iso_date;
+        // 3. Let time be ? ToTimeRecordOrMidnight(temporalTime).
+        let time = if temporal_time.is_undefined() {
+            TimeRecord::MIDNIGHT
+        } else {
+            to_temporal_time(
+                agent,
+                temporal_time.unbind(),
+                Value::Undefined,
+                gc.reborrow(),
+            )
+            .unbind()?
+        };
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(
+        //    plainDate.[[ISODate]], time).
+        let iso_date_time = IsoDateTime::new(iso_date, time);
+        // 5. Return ? CreateTemporalDateTime(isoDateTime,
+        //    plainDate.[[Calendar]]).
+        create_temporal_date_time(agent, iso_date_time, None, gc)
+            .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [3.3.30 Temporal.PlainDate.prototype.toZonedDateTime ( item )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tozoneddatetime)
+    fn to_zoned_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = arguments.get(0).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date].iso_date;
+        // 3. If item is an Object, then
+        let (time_zone, temporal_time) = if let Ok(item) = Object::try_from(item) {
+            let item = item.scope(agent, gc.nogc());
+            // a. Let timeZoneLike be ? Get(item, "timeZone").
+            let time_zone_like = get(
+                agent,
+                item.get(agent),
+                BUILTIN_STRING_MEMORY.timeZone.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            if time_zone_like.is_undefined() {
+                // b. If timeZoneLike is undefined, then
+                //    i. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+                //    ii. Let temporalTime be undefined.
+                let time_zone = to_temporal_time_zone_identifier(
+                    agent,
+                    item.get(agent).into_value(),
+                    gc.nogc(),
+                )
+                .unbind()?;
+                (time_zone, Value::Undefined)
+            } else {
+                // c. Else,
+                //    i. Let timeZone be ? ToTemporalTimeZoneIdentifier(
+                //       timeZoneLike).
+                let time_zone =
+                    to_temporal_time_zone_identifier(agent, time_zone_like, gc.nogc()).unbind()?;
+                //    ii. Let temporalTime be ? Get(item, "plainTime").
+                let temporal_time = get(
+                    agent,
+                    item.get(agent),
+                    BUILTIN_STRING_MEMORY.plainTime.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                (time_zone, temporal_time)
+            }
+        } else {
+            // 4. Else,
+            //    a. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+            //    b. Let temporalTime be undefined.
+            let time_zone = to_temporal_time_zone_identifier(agent, item, gc.nogc()).unbind()?;
+            (time_zone, Value::Undefined)
+        };
+        // 5. If temporalTime is undefined, then
+        //    a. Let epochNs be ? GetStartOfDay(timeZone,
+        //       plainDate.[[ISODate]]).
+        // 6. Else,
+        //    a. Set temporalTime to ? ToTemporalTime(temporalTime).
+        //    b-d. Let epochNs be ? GetEpochNanosecondsFor(timeZone,
+        //         isoDateTime, compatible).
+        let time = if temporal_time.is_undefined() {
+            None
+        } else {
+            Some(to_temporal_time(agent, temporal_time, Value::Undefined, gc.reborrow()).unbind()?)
+        };
+        let gc = gc.into_nogc();
+        let result = epoch_nanoseconds_for_date_and_time(&time_zone, iso_date, time);
+        let epoch_ns = result.map_err(|err| err.throw(agent, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone,
+        //    plainDate.[[Calendar]]).
+        Ok(create_zoned_date_time_object(agent, epoch_ns, time_zone, gc).into_value())
+    }
+
+    /// ### [3.3.31 Temporal.PlainDate.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date].iso_date;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc()).unbind()?;
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(
+        //    resolvedOptions).
+        let show_calendar =
+            get_temporal_show_calendar_name_option(agent, resolved_options, gc.reborrow())
+                .unbind()?;
+        // 5. Return TemporalDateToString(plainDate, showCalendar).
+        let result = temporal_date_to_string(iso_date, show_calendar);
+        Ok(Value::from_string(agent, result, gc.into_nogc()))
+    }
+
+    /// ### [3.3.32 Temporal.PlainDate.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive date formatting this produces the same result
+    /// as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc)?;
+        // 3. Return TemporalDateToString(plainDate, auto).
+        let result = temporal_date_to_string(agent[plain_date].iso_date, ShowCalendar::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [3.3.33 Temporal.PlainDate.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate,
+        //    [[InitializedTemporalDate]]).
+        let plain_date = require_temporal_date(agent, this_value, gc)?;
+        // 3. Return TemporalDateToString(plainDate, auto).
+        let result = temporal_date_to_string(agent[plain_date].iso_date, ShowCalendar::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [3.3.34 Temporal.PlainDate.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.PlainDate cannot be converted to a primitive value; use compare() or equals()",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_plain_date_prototype();
+        let plain_date_constructor = intrinsics.temporal_plain_date();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(31)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_date_constructor)
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetEra>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDay>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<PlainDatePrototypeGetInLeapYear>()
+            .with_builtin_function_property::<PlainDatePrototypeAdd>()
+            .with_builtin_function_property::<PlainDatePrototypeSubtract>()
+            .with_builtin_function_property::<PlainDatePrototypeWith>()
+            .with_builtin_function_property::<PlainDatePrototypeWithCalendar>()
+            .with_builtin_function_property::<PlainDatePrototypeUntil>()
+            .with_builtin_function_property::<PlainDatePrototypeSince>()
+            .with_builtin_function_property::<PlainDatePrototypeEquals>()
+            .with_builtin_function_property::<PlainDatePrototypeToPlainDateTime>()
+            .with_builtin_function_property::<PlainDatePrototypeToZonedDateTime>()
+            .with_builtin_function_property::<PlainDatePrototypeToString>()
+            .with_builtin_function_property::<PlainDatePrototypeToLocaleString>()
+            .with_builtin_function_property::<PlainDatePrototypeToJSON>()
+            .with_builtin_function_property::<PlainDatePrototypeValueOf>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainDate.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_temporal_date<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, PlainDate<'a>> {
+    PlainDate::try_from(value)
+        .map(|plain_date| plain_date.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.PlainDate object",
+                gc,
+            )
+        })
+}
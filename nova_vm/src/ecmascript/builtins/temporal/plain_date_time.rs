@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::PlainDateTimeIndex,
+    },
+};
+
+use self::data::PlainDateTimeHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PlainDateTime<'a>(pub(crate) PlainDateTimeIndex<'a>);
+
+impl PlainDateTime<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, PlainDateTime<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(PlainDateTimeIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainDateTime<'_> {
+    type Of<'a> = PlainDateTime<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for PlainDateTime<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainDateTime<'a>> for Value<'a> {
+    fn from(value: PlainDateTime<'a>) -> Self {
+        Value::PlainDateTime(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for PlainDateTime<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainDateTime<'a>> for Object<'a> {
+    fn from(value: PlainDateTime) -> Self {
+        Object::PlainDateTime(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for PlainDateTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::PlainDateTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for PlainDateTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::PlainDateTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for PlainDateTime<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainDateTime;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for PlainDateTime<'a> {}
+
+impl Index<PlainDateTime<'_>> for Agent {
+    type Output = PlainDateTimeHeapData<'static>;
+
+    fn index(&self, index: PlainDateTime) -> &Self::Output {
+        &self.heap.plain_date_times[index]
+    }
+}
+
+impl IndexMut<PlainDateTime<'_>> for Agent {
+    fn index_mut(&mut self, index: PlainDateTime) -> &mut Self::Output {
+        &mut self.heap.plain_date_times[index]
+    }
+}
+
+impl Index<PlainDateTime<'_>> for Vec<Option<PlainDateTimeHeapData<'static>>> {
+    type Output = PlainDateTimeHeapData<'static>;
+
+    fn index(&self, index: PlainDateTime) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("PlainDateTime out of bounds")
+            .as_ref()
+            .expect("PlainDateTime slot empty")
+    }
+}
+
+impl IndexMut<PlainDateTime<'_>> for Vec<Option<PlainDateTimeHeapData<'static>>> {
+    fn index_mut(&mut self, index: PlainDateTime) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("PlainDateTime out of bounds")
+            .as_mut()
+            .expect("PlainDateTime slot empty")
+    }
+}
+
+impl Rootable for PlainDateTime<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::PlainDateTime(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::PlainDateTime(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for PlainDateTime<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_date_times.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_date_times.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<PlainDateTimeHeapData<'a>, PlainDateTime<'a>> for Heap {
+    fn create(&mut self, data: PlainDateTimeHeapData<'a>) -> PlainDateTime<'a> {
+        self.plain_date_times.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<PlainDateTimeHeapData<'static>>>();
+        }
+        PlainDateTime(PlainDateTimeIndex::last(&self.plain_date_times))
+    }
+}
@@ -0,0 +1,548 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [5.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Overflow, Precision, RoundingMode,
+                    ShowCalendar, TemporalResult, Unit, UnitGroup, format_calendar_annotation,
+                    format_time_string, get_difference_settings, get_options_object,
+                    get_overflow_from_options, larger_of_two_temporal_units, pad_iso_year,
+                },
+                calendar::{
+                    CalendarFields, CalendarFieldsError, Field, ISO8601, IsoDateTime,
+                    RequiredFields, TimeRecord, add_time, balance_iso_date, calendar_date_add,
+                    calendar_date_from_fields, calendar_date_until, canonicalize_calendar,
+                    check_iso_date_time_within_limits, difference_time,
+                    get_temporal_calendar_identifier_with_iso_default, prepare_calendar_fields,
+                    regulate_time, round_time,
+                },
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        DurationRecord, InternalDuration, add_24_hour_days_to_time_duration,
+                        adjust_date_duration_record, create_duration_object,
+                        round_relative_duration, temporal_duration_from_internal,
+                        to_internal_duration_record_with_24_hour_days, to_temporal_duration,
+                        total_relative_duration,
+                    },
+                },
+                parsing::parse_temporal_date_time_string,
+                plain_date_time::{PlainDateTime, data::PlainDateTimeHeapData},
+                time_zone::{check_iso_days_range, get_iso_date_time_for},
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{Function, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// ### [5.5.5 InterpretTemporalDateTimeFields ( calendar, fields, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-interprettemporaldatetimefields)
+pub(crate) fn interpret_temporal_date_time_fields(
+    fields: &CalendarFields,
+    overflow: Overflow,
+) -> Result<IsoDateTime, CalendarFieldsError> {
+    // 1. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+    let iso_date = calendar_date_from_fields(fields, overflow)?;
+    // 2. Let time be ? RegulateTime(fields.[[Hour]], fields.[[Minute]],
+    //    fields.[[Second]], fields.[[Millisecond]], fields.[[Microsecond]],
+    //    fields.[[Nanosecond]], overflow).
+    let [hour, minute, second, millisecond, microsecond, nanosecond] = fields.time_fields();
+    let time = regulate_time(
+        hour,
+        minute,
+        second,
+        millisecond,
+        microsecond,
+        nanosecond,
+        overflow,
+    )?;
+    // 3. Return CombineISODateAndTimeRecord(isoDate, time).
+    Ok(IsoDateTime::new(iso_date, time))
+}
+
+/// ### [5.5.7 CreateTemporalDateTime ( isoDateTime, calendar \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldatetime)
+pub(crate) fn create_temporal_date_time<'gc>(
+    agent: &mut Agent,
+    iso_date_time: IsoDateTime,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainDateTime<'gc>> {
+    // 1. If ISODateTimeWithinLimits(isoDateTime) is false, then
+    //    a. Throw a RangeError exception.
+    let iso_date_time = check_iso_date_time_within_limits(iso_date_time)
+        .map_err(|err| err.throw(agent, gc.nogc()))
+        .unbind()?;
+    // 2. If newTarget is not present, set newTarget to
+    //    %Temporal.PlainDateTime%.
+    let Some(new_target) = new_target else {
+        return Ok(create_plain_date_time_object(
+            agent,
+            iso_date_time,
+            gc.into_nogc(),
+        ));
+    };
+    // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.PlainDateTime.prototype%",
+    //    « [[InitializedTemporalDateTime]], [[ISODateTime]], [[Calendar]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalPlainDateTime,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = PlainDateTime::try_from(object.bind(gc)).unwrap();
+    // 4. Set object.[[ISODateTime]] to isoDateTime.
+    // 5. Set object.[[Calendar]] to calendar.
+    agent[object].iso_date_time = iso_date_time;
+    // 6. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalDateTime without a newTarget, for a date-time within the
+/// limits.
+pub(crate) fn create_plain_date_time_object<'gc>(
+    agent: &mut Agent,
+    iso_date_time: IsoDateTime,
+    gc: NoGcScope<'gc, '_>,
+) -> PlainDateTime<'gc> {
+    agent
+        .heap
+        .create(PlainDateTimeHeapData {
+            object_index: None,
+            iso_date_time,
+        })
+        .bind(gc)
+}
+
+/// ### [5.5.6 ToTemporalDateTime ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime)
+///
+/// Returns the date-time of the Temporal.PlainDateTime.
+pub(crate) fn to_temporal_date_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IsoDateTime> {
+    let item = item.bind(gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    let options = options.scope(agent, gc.nogc());
+    // 2. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        let iso_date_time = match item {
+            // a. If item has an [[InitializedTemporalDateTime]] internal slot,
+            //    then
+            //    iii. Return ! CreateTemporalDateTime(item.[[ISODateTime]],
+            //         item.[[Calendar]]).
+            Object::PlainDateTime(plain_date_time) => Some(agent[plain_date_time].iso_date_time),
+            // b. If item has an [[InitializedTemporalZonedDateTime]] internal
+            //    slot, then
+            //    i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]],
+            //       item.[[EpochNanoseconds]]).
+            //    iv. Return ! CreateTemporalDateTime(isoDateTime,
+            //        item.[[Calendar]]).
+            Object::ZonedDateTime(zoned_date_time) => {
+                let data = &agent[zoned_date_time];
+                Some(get_iso_date_time_for(
+                    &data.time_zone,
+                    data.epoch_nanoseconds,
+                ))
+            }
+            // c. If item has an [[InitializedTemporalDate]] internal slot,
+            //    then
+            //    iii. Let isoDateTime be CombineISODateAndTimeRecord(
+            //         item.[[ISODate]], MidnightTimeRecord()).
+            //    iv. Return ? CreateTemporalDateTime(isoDateTime,
+            //        item.[[Calendar]]).
+            Object::PlainDate(plain_date) => Some(IsoDateTime::new(
+                agent[plain_date].iso_date,
+                TimeRecord::MIDNIGHT,
+            )),
+            _ => None,
+        };
+        if let Some(iso_date_time) = iso_date_time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+            let gc = gc.into_nogc();
+            return check_iso_date_time_within_limits(iso_date_time)
+                .map_err(|err| err.throw(agent, gc));
+        }
+        let item = item.scope(agent, gc.nogc());
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(
+        //    item).
+        get_temporal_calendar_identifier_with_iso_default(agent, item.get(agent), gc.reborrow())
+            .unbind()?;
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year,
+        //    month, month-code, day », « hour, minute, second, millisecond,
+        //    microsecond, nanosecond », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            item.get(agent),
+            &[Field::DATE, Field::TIME],
+            RequiredFields::List(&[]),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // h. Let result be ? InterpretTemporalDateTimeFields(calendar,
+        //    fields, overflow).
+        let result = interpret_temporal_date_time_fields(&fields, overflow);
+        result.map_err(|err| err.throw(agent, gc.nogc())).unbind()?
+    } else {
+        // 3. Else,
+        //    a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "date-time must be a Temporal.PlainDateTime, an object or a string",
+                gc.into_nogc(),
+            ));
+        };
+        //    b. Let result be ? ParseISODateTime(item,
+        //       « TemporalDateTimeString[~Zoned] »).
+        //    c. If result.[[Time]] is start-of-day, let time be
+        //       MidnightTimeRecord(); else let time be result.[[Time]].
+        //    d. Let calendar be result.[[Calendar]].
+        //    e. If calendar is empty, set calendar to "iso8601".
+        //    f. Set calendar to ? CanonicalizeCalendar(calendar).
+        //    i. Let isoDate be CreateISODateRecord(result.[[Year]],
+        //       result.[[Month]], result.[[Day]]).
+        //    j. Set result to CombineISODateAndTimeRecord(isoDate, time).
+        let result = parse_temporal_date_time_string(&item.as_wtf8(agent).to_string_lossy())
+            .and_then(|result| {
+                canonicalize_calendar(result.calendar.as_deref().unwrap_or(ISO8601))?;
+                Ok(IsoDateTime::new(
+                    result.date,
+                    result.time.unwrap_or(TimeRecord::MIDNIGHT),
+                ))
+            });
+        let result = result.map_err(|err| err.throw(agent, gc.nogc())).unbind()?;
+        //    g. Let resolvedOptions be ? GetOptionsObject(options).
+        //    h. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        result
+    };
+    let gc = gc.into_nogc();
+    // 4. Return ? CreateTemporalDateTime(result, calendar).
+    check_iso_date_time_within_limits(result).map_err(|err| err.throw(agent, gc))
+}
+
+/// ### [5.5.9 ISODateTimeToString ( isoDateTime, calendar, precision, showCalendar )](https://tc39.es/proposal-temporal/#sec-temporal-isodatetimetostring)
+pub(crate) fn iso_date_time_to_string(
+    iso_date_time: IsoDateTime,
+    calendar: &str,
+    precision: Precision,
+    show_calendar: ShowCalendar,
+) -> std::string::String {
+    let IsoDateTime { date, time } = iso_date_time;
+    // 1. Let yearString be PadISOYear(isoDateTime.[[ISODate]].[[Year]]).
+    let year_string = pad_iso_year(date.year);
+    // 2. Let monthString be ToZeroPaddedDecimalString(
+    //    isoDateTime.[[ISODate]].[[Month]], 2).
+    // 3. Let dayString be ToZeroPaddedDecimalString(
+    //    isoDateTime.[[ISODate]].[[Day]], 2).
+    // 4. Let subSecondNanoseconds be isoDateTime.[[Time]].[[Millisecond]] ×
+    //    10**6 + isoDateTime.[[Time]].[[Microsecond]] × 10**3 +
+    //    isoDateTime.[[Time]].[[Nanosecond]].
+    // 5. Let timeString be FormatTimeString(isoDateTime.[[Time]].[[Hour]],
+    //    isoDateTime.[[Time]].[[Minute]], isoDateTime.[[Time]].[[Second]],
+    //    subSecondNanoseconds, precision).
+    let time_string = format_time_string(
+        time.hour,
+        time.minute,
+        time.second,
+        time.sub_second_nanoseconds(),
+        precision,
+        true,
+    );
+    // 6. Let calendarString be FormatCalendarAnnotation(calendar,
+    //    showCalendar).
+    let calendar_string = format_calendar_annotation(calendar, show_calendar);
+    // 7. Return the string-concatenation of yearString, the code unit 0x002D
+    //    (HYPHEN-MINUS), monthString, the code unit 0x002D (HYPHEN-MINUS),
+    //    dayString, 0x0054 (LATIN CAPITAL LETTER T), timeString, and
+    //    calendarString.
+    format!(
+        "{year_string}-{:02}-{:02}T{time_string}{calendar_string}",
+        date.month, date.day
+    )
+}
+
+/// ### [5.5.11 RoundISODateTime ( isoDateTime, increment, unit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-roundisodatetime)
+pub(crate) fn round_iso_date_time(
+    iso_date_time: IsoDateTime,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> IsoDateTime {
+    // 1. Assert: ISODateTimeWithinLimits(isoDateTime) is true.
+    // 2. Let roundedTime be RoundTime(isoDateTime.[[Time]], increment, unit,
+    //    roundingMode).
+    let (days, rounded_time) = round_time(iso_date_time.time, increment, unit, rounding_mode);
+    // 3. Let balanceResult be BalanceISODate(
+    //    isoDateTime.[[ISODate]].[[Year]],
+    //    isoDateTime.[[ISODate]].[[Month]],
+    //    isoDateTime.[[ISODate]].[[Day]] + roundedTime.[[Days]]).
+    let date = iso_date_time.date;
+    let balance_result =
+        balance_iso_date(i64::from(date.year), date.month, i64::from(date.day) + days);
+    // 4. Return CombineISODateAndTimeRecord(balanceResult, roundedTime).
+    IsoDateTime::new(balance_result, rounded_time)
+}
+
+/// ### [5.5.12 DifferenceISODateTime ( isoDateTime1, isoDateTime2, calendar, largestUnit )](https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime)
+pub(crate) fn difference_iso_date_time(
+    iso_date_time1: IsoDateTime,
+    iso_date_time2: IsoDateTime,
+    largest_unit: Unit,
+) -> TemporalResult<InternalDuration> {
+    // 1. Assert: ISODateTimeWithinLimits(isoDateTime1) is true.
+    // 2. Assert: ISODateTimeWithinLimits(isoDateTime2) is true.
+    // 3. Let timeDuration be DifferenceTime(isoDateTime1.[[Time]],
+    //    isoDateTime2.[[Time]]).
+    let mut time_duration = difference_time(iso_date_time1.time, iso_date_time2.time);
+    // 4. Let timeSign be TimeDurationSign(timeDuration).
+    let time_sign = time_duration.signum() as i64;
+    // 5. Let dateSign be CompareISODate(isoDateTime1.[[ISODate]],
+    //    isoDateTime2.[[ISODate]]).
+    let date_sign = iso_date_time1.date.cmp(&iso_date_time2.date) as i64;
+    // 6. Let adjustedDate be isoDateTime2.[[ISODate]].
+    let mut adjusted_date = iso_date_time2.date;
+    // 7. If timeSign = dateSign, then
+    if time_sign != 0 && time_sign == date_sign {
+        // a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]],
+        //    adjustedDate.[[Month]], adjustedDate.[[Day]] + timeSign).
+        adjusted_date = balance_iso_date(
+            i64::from(adjusted_date.year),
+            adjusted_date.month,
+            i64::from(adjusted_date.day) + time_sign,
+        );
+        // b. Set timeDuration to ? Add24HourDaysToTimeDuration(timeDuration,
+        //    -timeSign).
+        time_duration = add_24_hour_days_to_time_duration(time_duration, -time_sign)?;
+    }
+    // 8. Let dateLargestUnit be LargerOfTwoTemporalUnits(day, largestUnit).
+    let date_largest_unit = larger_of_two_temporal_units(Unit::Day, largest_unit);
+    // 9. Let dateDifference be CalendarDateUntil(calendar,
+    //    isoDateTime1.[[ISODate]], adjustedDate, dateLargestUnit).
+    let mut date_difference =
+        calendar_date_until(iso_date_time1.date, adjusted_date, date_largest_unit);
+    // 10. If largestUnit is not dateLargestUnit, then
+    if largest_unit != date_largest_unit {
+        // a. Set timeDuration to ? Add24HourDaysToTimeDuration(timeDuration,
+        //    dateDifference.[[Days]]).
+        time_duration = add_24_hour_days_to_time_duration(time_duration, date_difference.days)?;
+        // b. Set dateDifference.[[Days]] to 0.
+        date_difference.days = 0;
+    }
+    // 11. Return ? CombineDateAndTimeDuration(dateDifference, timeDuration).
+    Ok(InternalDuration::new(date_difference, time_duration))
+}
+
+/// ### [5.5.13 DifferencePlainDateTimeWithRounding ( isoDateTime1, isoDateTime2, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithrounding)
+pub(crate) fn difference_plain_date_time_with_rounding(
+    iso_date_time1: IsoDateTime,
+    iso_date_time2: IsoDateTime,
+    largest_unit: Unit,
+    rounding_increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<InternalDuration> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    if iso_date_time1 == iso_date_time2 {
+        // a. Return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+        return Ok(InternalDuration::default());
+    }
+    // 2. Perform ? CheckISODaysRange(isoDateTime1.[[ISODate]]).
+    check_iso_days_range(iso_date_time1.date)?;
+    // 3. Perform ? CheckISODaysRange(isoDateTime2.[[ISODate]]).
+    check_iso_days_range(iso_date_time2.date)?;
+    // 4. Let diff be ? DifferenceISODateTime(isoDateTime1, isoDateTime2,
+    //    calendar, largestUnit).
+    let diff = difference_iso_date_time(iso_date_time1, iso_date_time2, largest_unit)?;
+    // 5. If smallestUnit is nanosecond and roundingIncrement = 1, return
+    //    diff.
+    if smallest_unit == Unit::Nanosecond && rounding_increment == 1 {
+        return Ok(diff);
+    }
+    // 6. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    let dest_epoch_ns = iso_date_time2.to_epoch_nanoseconds();
+    // 7. Return ? RoundRelativeDuration(diff, destEpochNs, isoDateTime1,
+    //    unset, calendar, largestUnit, roundingIncrement, smallestUnit,
+    //    roundingMode).
+    round_relative_duration(
+        diff,
+        dest_epoch_ns,
+        iso_date_time1,
+        None,
+        largest_unit,
+        rounding_increment,
+        smallest_unit,
+        rounding_mode,
+    )
+}
+
+/// ### [5.5.14 DifferencePlainDateTimeWithTotal ( isoDateTime1, isoDateTime2, calendar, unit )](https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithtotal)
+pub(crate) fn difference_plain_date_time_with_total(
+    iso_date_time1: IsoDateTime,
+    iso_date_time2: IsoDateTime,
+    unit: Unit,
+) -> TemporalResult<f64> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    if iso_date_time1 == iso_date_time2 {
+        // a. Return 0.
+        return Ok(0.0);
+    }
+    // 2. Perform ? CheckISODaysRange(isoDateTime1.[[ISODate]]).
+    check_iso_days_range(iso_date_time1.date)?;
+    // 3. Perform ? CheckISODaysRange(isoDateTime2.[[ISODate]]).
+    check_iso_days_range(iso_date_time2.date)?;
+    // 4. Let diff be ? DifferenceISODateTime(isoDateTime1, isoDateTime2,
+    //    calendar, unit).
+    let diff = difference_iso_date_time(iso_date_time1, iso_date_time2, unit)?;
+    // 5. If unit is nanosecond, return diff.[[Time]].
+    if unit == Unit::Nanosecond {
+        return Ok(diff.time as f64);
+    }
+    // 6. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    let dest_epoch_ns = iso_date_time2.to_epoch_nanoseconds();
+    // 7. Return ? TotalRelativeDuration(diff, destEpochNs, isoDateTime1,
+    //    unset, calendar, unit).
+    total_relative_duration(diff, dest_epoch_ns, iso_date_time1, None, unit)
+}
+
+/// ### [5.5.15 DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime)
+pub(crate) fn difference_temporal_plain_date_time<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    date_time: PlainDateTime,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let iso_date_time = agent[date_time].iso_date_time;
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalDateTime(other).
+    let other = to_temporal_date_time(agent, other, Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. If CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    datetime, « », nanosecond, day).
+    let settings = get_difference_settings(
+        agent,
+        operation,
+        resolved_options,
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Day,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 5. If CompareISODateTime(dateTime.[[ISODateTime]],
+    //    other.[[ISODateTime]]) = 0, then
+    if iso_date_time == other {
+        // a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        return Ok(create_duration_object(agent, DurationRecord::default(), gc));
+    }
+    // 6. Let internalDuration be ? DifferencePlainDateTimeWithRounding(
+    //    dateTime.[[ISODateTime]], other.[[ISODateTime]],
+    //    dateTime.[[Calendar]], settings.[[LargestUnit]],
+    //    settings.[[RoundingIncrement]], settings.[[SmallestUnit]],
+    //    settings.[[RoundingMode]]).
+    // 7. Let result be ? TemporalDurationFromInternal(internalDuration,
+    //    settings.[[LargestUnit]]).
+    let result = difference_plain_date_time_with_rounding(
+        iso_date_time,
+        other,
+        settings.largest_unit,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )
+    .and_then(|internal_duration| {
+        temporal_duration_from_internal(internal_duration, settings.largest_unit)
+    })
+    .map_err(|err| err.throw(agent, gc))?;
+    // 8. If operation is since, set result to
+    //    CreateNegatedTemporalDuration(result).
+    let result = match operation {
+        DifferenceOperation::Since => result.negated(),
+        DifferenceOperation::Until => result,
+    };
+    // 9. Return result.
+    Ok(create_duration_object(agent, result, gc))
+}
+
+/// ### [5.5.16 AddDurationToDateTime ( operation, dateTime, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime)
+pub(crate) fn add_duration_to_date_time<'gc>(
+    agent: &mut Agent,
+    operation: ArithmeticOperation,
+    date_time: PlainDateTime,
+    temporal_duration_like: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainDateTime<'gc>> {
+    let iso_date_time = agent[date_time].iso_date_time;
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, temporal_duration_like, gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to
+    //    CreateNegatedTemporalDuration(duration).
+    let duration = match operation {
+        ArithmeticOperation::Subtract => duration.negated(),
+        ArithmeticOperation::Add => duration,
+    };
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow = get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let result = add_duration_record_to_date_time(iso_date_time, &duration, overflow)
+        .map_err(|err| err.throw(agent, gc))?;
+    // 10. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+    Ok(create_plain_date_time_object(agent, result, gc))
+}
+
+/// Steps 5 to 10 of AddDurationToDateTime.
+fn add_duration_record_to_date_time(
+    iso_date_time: IsoDateTime,
+    duration: &DurationRecord,
+    overflow: Overflow,
+) -> TemporalResult<IsoDateTime> {
+    // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(
+    //    duration).
+    let internal_duration = to_internal_duration_record_with_24_hour_days(duration);
+    // 6. Let timeResult be AddTime(dateTime.[[ISODateTime]].[[Time]],
+    //    internalDuration.[[Time]]).
+    let (days, time_result) = add_time(iso_date_time.time, internal_duration.time);
+    // 7. Let dateDuration be ? AdjustDateDurationRecord(
+    //    internalDuration.[[Date]], timeResult.[[Days]]).
+    let date_duration = adjust_date_duration_record(internal_duration.date, days, None, None)?;
+    // 8. Let addedDate be ? CalendarDateAdd(dateTime.[[Calendar]],
+    //    dateTime.[[ISODateTime]].[[ISODate]], dateDuration, overflow).
+    let added_date = calendar_date_add(iso_date_time.date, date_duration, overflow)?;
+    // 9. Let result be CombineISODateAndTimeRecord(addedDate, timeResult).
+    let result = IsoDateTime::new(added_date, time_result);
+    // 10. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+    check_iso_date_time_within_limits(result)
+}
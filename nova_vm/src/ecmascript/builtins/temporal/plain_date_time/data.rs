@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::temporal::calendar::{IsoDate, IsoDateTime, TimeRecord},
+        types::OrdinaryObject,
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [5.4 Properties of Temporal.PlainDateTime Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-plaindatetime-instances)
+#[derive(Debug, Clone)]
+pub struct PlainDateTimeHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[ISODateTime]]; the \[\[Calendar]] is always "iso8601".
+    pub(crate) iso_date_time: IsoDateTime,
+}
+
+impl Default for PlainDateTimeHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            iso_date_time: IsoDateTime::new(IsoDate::new(1970, 1, 1), TimeRecord::MIDNIGHT),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainDateTimeHeapData<'_> {
+    type Of<'a> = PlainDateTimeHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PlainDateTimeHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            iso_date_time: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            iso_date_time: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,200 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{Overflow, RangeError, to_integer_with_truncation},
+                calendar::{
+                    IsoDate, IsoDateTime, canonicalize_calendar, is_valid_iso_date, is_valid_time,
+                    regulate_time,
+                },
+                plain_date_time::abstract_operations::{
+                    create_plain_date_time_object, create_temporal_date_time, to_temporal_date_time,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct PlainDateTimeConstructor;
+impl Builtin for PlainDateTimeConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainDateTime;
+
+    const LENGTH: u8 = 3;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for PlainDateTimeConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainDateTime;
+}
+
+struct PlainDateTimeFrom;
+impl Builtin for PlainDateTimeFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimeConstructor::from);
+}
+struct PlainDateTimeCompare;
+impl Builtin for PlainDateTimeCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimeConstructor::compare);
+}
+
+impl PlainDateTimeConstructor {
+    /// ### [5.1.1 Temporal.PlainDateTime ( isoYear, isoMonth, isoDay \[ , hour \[ , minute \[ , second \[ , millisecond \[ , microsecond \[ , nanosecond \[ , calendar \] \] \] \] \] \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.PlainDateTime requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        let values = (0..10)
+            .map(|index| arguments.get(index).scope(agent, gc.nogc()))
+            .collect::<Vec<_>>();
+        // 2. Set isoYear to ? ToIntegerWithTruncation(isoYear).
+        // 3. Set isoMonth to ? ToIntegerWithTruncation(isoMonth).
+        // 4. Set isoDay to ? ToIntegerWithTruncation(isoDay).
+        // 5. If hour is undefined, set hour to 0; else set hour to
+        //    ? ToIntegerWithTruncation(hour).
+        // 6-10. Likewise for minute, second, millisecond, microsecond and
+        //       nanosecond.
+        let mut fields = [0.0; 9];
+        for (index, (field, value)) in fields.iter_mut().zip(&values).enumerate() {
+            let value = value.get(agent);
+            if index < 3 || !value.is_undefined() {
+                *field = to_integer_with_truncation(agent, value, gc.reborrow()).unbind()?;
+            }
+        }
+        let [
+            iso_year,
+            iso_month,
+            iso_day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        ] = fields;
+        // 11. If calendar is undefined, set calendar to "iso8601".
+        let calendar = values[9].get(agent).bind(gc.nogc());
+        if !calendar.is_undefined() {
+            // 12. If calendar is not a String, throw a TypeError exception.
+            let Ok(calendar) = String::try_from(calendar) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "calendar must be a string",
+                    gc.into_nogc(),
+                ));
+            };
+            // 13. Set calendar to ? CanonicalizeCalendar(calendar).
+            let result = canonicalize_calendar(&calendar.as_wtf8(agent).to_string_lossy());
+            if let Err(err) = result {
+                return Err(err.throw(agent, gc.into_nogc()));
+            }
+        }
+        // 14. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a
+        //     RangeError exception.
+        if !is_valid_iso_date(iso_year, iso_month, iso_day) {
+            return Err(RangeError("invalid date").throw(agent, gc.into_nogc()));
+        }
+        // 15. Let isoDate be CreateISODateRecord(isoYear, isoMonth, isoDay).
+        let iso_date = IsoDate::new(iso_year as i32, iso_month as u8, iso_day as u8);
+        // 16. If IsValidTime(hour, minute, second, millisecond, microsecond,
+        //     nanosecond) is false, throw a RangeError exception.
+        if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
+            return Err(RangeError("invalid time").throw(agent, gc.into_nogc()));
+        }
+        // 17. Let time be CreateTimeRecord(hour, minute, second, millisecond,
+        //     microsecond, nanosecond).
+        let time = regulate_time(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            Overflow::Reject,
+        )
+        .unwrap();
+        // 18. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+        let iso_date_time = IsoDateTime::new(iso_date, time);
+        // 19. Return ? CreateTemporalDateTime(isoDateTime, calendar,
+        //     NewTarget).
+        create_temporal_date_time(agent, iso_date_time, Some(new_target.get(agent)), gc)
+            .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.2.2 Temporal.PlainDateTime.from ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalDateTime(item, options).
+        let iso_date_time =
+            to_temporal_date_time(agent, arguments.get(0), arguments.get(1), gc.reborrow())
+                .unbind()?;
+        Ok(create_plain_date_time_object(agent, iso_date_time, gc.into_nogc()).into_value())
+    }
+
+    /// ### [5.2.3 Temporal.PlainDateTime.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalDateTime(one).
+        let one = to_temporal_date_time(agent, arguments.get(0), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 2. Set two to ? ToTemporalDateTime(two).
+        let two = to_temporal_date_time(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareISODateTime(one.[[ISODateTime]],
+        //    two.[[ISODateTime]])).
+        Ok((one.cmp(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_date_time_prototype = intrinsics.temporal_plain_date_time_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<PlainDateTimeConstructor>(agent, realm)
+            .with_property_capacity(3)
+            .with_prototype_property(plain_date_time_prototype.into_object())
+            .with_builtin_function_property::<PlainDateTimeFrom>()
+            .with_builtin_function_property::<PlainDateTimeCompare>()
+            .build();
+    }
+}
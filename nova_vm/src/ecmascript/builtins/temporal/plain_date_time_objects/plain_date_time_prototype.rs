@@ -0,0 +1,1152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Precision, RangeError, RoundingMode,
+                    ShowCalendar, Unit, UnitGroup, UnitValue, get_options_object,
+                    get_overflow_from_options, get_round_to_options, get_rounding_mode_option,
+                    get_temporal_disambiguation_option,
+                    get_temporal_fractional_second_digits_option,
+                    get_temporal_show_calendar_name_option, get_temporal_unit_valued_option,
+                    is_partial_temporal_object, maximum_temporal_duration_rounding_increment,
+                    to_seconds_string_precision_record, validate_temporal_rounding_increment,
+                    validate_temporal_unit_value,
+                },
+                calendar::{
+                    CalendarDateField, CalendarFields, Field, ISO8601, IsoDateTime, RequiredFields,
+                    TimeRecord, calendar_iso_to_date_field, iso_date_time_within_limits,
+                    prepare_calendar_fields, to_temporal_calendar_identifier,
+                },
+                plain_date::abstract_operations::create_plain_date_object,
+                plain_date_time::{
+                    PlainDateTime,
+                    abstract_operations::{
+                        add_duration_to_date_time, create_plain_date_time_object,
+                        create_temporal_date_time, difference_temporal_plain_date_time,
+                        interpret_temporal_date_time_fields, iso_date_time_to_string,
+                        round_iso_date_time, to_temporal_date_time,
+                    },
+                },
+                plain_time::abstract_operations::{create_plain_time_object, to_temporal_time},
+                time_zone::{get_epoch_nanoseconds_for, to_temporal_time_zone_identifier},
+                zoned_date_time::abstract_operations::create_zoned_date_time_object,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct PlainDateTimePrototype;
+
+struct PlainDateTimePrototypeGetCalendarId;
+impl Builtin for PlainDateTimePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::get_calendar_id);
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetCalendarId {}
+struct PlainDateTimePrototypeGetEra;
+impl Builtin for PlainDateTimePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::Era as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetEra {}
+struct PlainDateTimePrototypeGetEraYear;
+impl Builtin for PlainDateTimePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::EraYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetEraYear {}
+struct PlainDateTimePrototypeGetYear;
+impl Builtin for PlainDateTimePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::Year as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetYear {}
+struct PlainDateTimePrototypeGetMonth;
+impl Builtin for PlainDateTimePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::Month as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMonth {}
+struct PlainDateTimePrototypeGetMonthCode;
+impl Builtin for PlainDateTimePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::MonthCode as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMonthCode {}
+struct PlainDateTimePrototypeGetDay;
+impl Builtin for PlainDateTimePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::Day as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDay {}
+struct PlainDateTimePrototypeGetDayOfWeek;
+impl Builtin for PlainDateTimePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::DayOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDayOfWeek {}
+struct PlainDateTimePrototypeGetDayOfYear;
+impl Builtin for PlainDateTimePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::DayOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDayOfYear {}
+struct PlainDateTimePrototypeGetWeekOfYear;
+impl Builtin for PlainDateTimePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::WeekOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetWeekOfYear {}
+struct PlainDateTimePrototypeGetYearOfWeek;
+impl Builtin for PlainDateTimePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::YearOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetYearOfWeek {}
+struct PlainDateTimePrototypeGetDaysInWeek;
+impl Builtin for PlainDateTimePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInWeek as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDaysInWeek {}
+struct PlainDateTimePrototypeGetDaysInMonth;
+impl Builtin for PlainDateTimePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInMonth as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDaysInMonth {}
+struct PlainDateTimePrototypeGetDaysInYear;
+impl Builtin for PlainDateTimePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetDaysInYear {}
+struct PlainDateTimePrototypeGetMonthsInYear;
+impl Builtin for PlainDateTimePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::MonthsInYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMonthsInYear {}
+struct PlainDateTimePrototypeGetInLeapYear;
+impl Builtin for PlainDateTimePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_date_field::<{ CalendarDateField::InLeapYear as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetInLeapYear {}
+struct PlainDateTimePrototypeGetHour;
+impl Builtin for PlainDateTimePrototypeGetHour {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hour;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hour.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainDateTimePrototype::get_time_field::<{ Unit::Hour as usize }>);
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetHour {}
+struct PlainDateTimePrototypeGetMinute;
+impl Builtin for PlainDateTimePrototypeGetMinute {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minute;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minute.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainDateTimePrototype::get_time_field::<{ Unit::Minute as usize }>);
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMinute {}
+struct PlainDateTimePrototypeGetSecond;
+impl Builtin for PlainDateTimePrototypeGetSecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_second;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.second.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainDateTimePrototype::get_time_field::<{ Unit::Second as usize }>);
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetSecond {}
+struct PlainDateTimePrototypeGetMillisecond;
+impl Builtin for PlainDateTimePrototypeGetMillisecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_millisecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.millisecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_time_field::<{ Unit::Millisecond as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMillisecond {}
+struct PlainDateTimePrototypeGetMicrosecond;
+impl Builtin for PlainDateTimePrototypeGetMicrosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microsecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microsecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        PlainDateTimePrototype::get_time_field::<{ Unit::Microsecond as usize }>,
+    );
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetMicrosecond {}
+struct PlainDateTimePrototypeGetNanosecond;
+impl Builtin for PlainDateTimePrototypeGetNanosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanosecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanosecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainDateTimePrototype::get_time_field::<{ Unit::Nanosecond as usize }>);
+}
+impl BuiltinGetter for PlainDateTimePrototypeGetNanosecond {}
+struct PlainDateTimePrototypeWith;
+impl Builtin for PlainDateTimePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::with);
+}
+struct PlainDateTimePrototypeWithPlainTime;
+impl Builtin for PlainDateTimePrototypeWithPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withPlainTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::with_plain_time);
+}
+struct PlainDateTimePrototypeWithCalendar;
+impl Builtin for PlainDateTimePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::with_calendar);
+}
+struct PlainDateTimePrototypeAdd;
+impl Builtin for PlainDateTimePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::add);
+}
+struct PlainDateTimePrototypeSubtract;
+impl Builtin for PlainDateTimePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::subtract);
+}
+struct PlainDateTimePrototypeUntil;
+impl Builtin for PlainDateTimePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::until);
+}
+struct PlainDateTimePrototypeSince;
+impl Builtin for PlainDateTimePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::since);
+}
+struct PlainDateTimePrototypeRound;
+impl Builtin for PlainDateTimePrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::round);
+}
+struct PlainDateTimePrototypeEquals;
+impl Builtin for PlainDateTimePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::equals);
+}
+struct PlainDateTimePrototypeToString;
+impl Builtin for PlainDateTimePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_string);
+}
+struct PlainDateTimePrototypeToLocaleString;
+impl Builtin for PlainDateTimePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_locale_string);
+}
+struct PlainDateTimePrototypeToJSON;
+impl Builtin for PlainDateTimePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_json);
+}
+struct PlainDateTimePrototypeValueOf;
+impl Builtin for PlainDateTimePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::value_of);
+}
+struct PlainDateTimePrototypeToZonedDateTime;
+impl Builtin for PlainDateTimePrototypeToZonedDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toZonedDateTime;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_zoned_date_time);
+}
+struct PlainDateTimePrototypeToPlainDate;
+impl Builtin for PlainDateTimePrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_plain_date);
+}
+struct PlainDateTimePrototypeToPlainTime;
+impl Builtin for PlainDateTimePrototypeToPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainDateTimePrototype::to_plain_time);
+}
+
+impl PlainDateTimePrototype {
+    /// ### [5.3.3 get Temporal.PlainDateTime.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return plainDateTime.[[Calendar]].
+        Ok(Value::from_static_str(agent, ISO8601, gc))
+    }
+
+    /// ### [5.3.4 get Temporal.PlainDateTime.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.era)
+    ///
+    /// The getters from era to inLeapYear, with the field of the Calendar
+    /// Date Record as an index into the fields.
+    fn get_date_field<'gc, const FIELD: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDateTime.[[Calendar]],
+        //    plainDateTime.[[ISODateTime]].[[ISODate]]).[[Era]].
+        let iso_date = agent[plain_date_time].iso_date_time.date;
+        Ok(calendar_iso_to_date_field(agent, iso_date, FIELD, gc))
+    }
+
+    /// ### [5.3.20 get Temporal.PlainDateTime.prototype.hour](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.hour)
+    ///
+    /// The getters from hour to nanosecond, with the unit of the field as an
+    /// index into the units.
+    fn get_time_field<'gc, const UNIT: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Hour]]).
+        Ok(agent[plain_date_time].iso_date_time.time.field(UNIT).into())
+    }
+
+    /// ### [5.3.26 Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        // 3. If ? IsPartialTemporalObject(temporalDateTimeLike) is false,
+        //    throw a TypeError exception.
+        let Some(temporal_date_time_like) =
+            is_partial_temporal_object(agent, arguments.get(0), gc.reborrow()).unbind()?
+        else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "with requires a partial Temporal.PlainDateTime-like object",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let calendar be plainDateTime.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar,
+        //    plainDateTime.[[ISODateTime]].[[ISODate]], date).
+        // 6-11. Set the time fields of fields to the fields of
+        //       plainDateTime.[[ISODateTime]].[[Time]].
+        let fields =
+            CalendarFields::from_iso_date(iso_date_time.date).with_time(iso_date_time.time);
+        // 12. Let partialDateTime be ? PrepareCalendarFields(calendar,
+        //     temporalDateTimeLike, « year, month, month-code, day », « hour,
+        //     minute, second, millisecond, microsecond, nanosecond »,
+        //     partial).
+        let partial_date_time = prepare_calendar_fields(
+            agent,
+            temporal_date_time_like,
+            &[Field::DATE, Field::TIME],
+            RequiredFields::Partial,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 13. Set fields to CalendarMergeFields(calendar, fields,
+        //     partialDateTime).
+        let fields = fields.merge(partial_date_time);
+        // 14. Let resolvedOptions be ? GetOptionsObject(options).
+        // 15. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // 16. Let result be ? InterpretTemporalDateTimeFields(calendar,
+        //     fields, overflow).
+        let result = interpret_temporal_date_time_fields(&fields, overflow)
+            .map_err(|err| err.throw(agent, gc.nogc()))
+            .unbind()?;
+        // 17. Return ? CreateTemporalDateTime(result, calendar).
+        create_temporal_date_time(agent, result, None, gc)
+            .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.3.27 Temporal.PlainDateTime.prototype.withPlainTime ( \[ plainTimeLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaintime)
+    fn with_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let plain_time_like = arguments.get(0).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date = agent[plain_date_time].iso_date_time.date;
+        // 3. Let time be ? ToTimeRecordOrMidnight(plainTimeLike).
+        let time = if plain_time_like.is_undefined() {
+            TimeRecord::MIDNIGHT
+        } else {
+            to_temporal_time(
+                agent,
+                plain_time_like.unbind(),
+                Value::Undefined,
+                gc.reborrow(),
+            )
+            .unbind()?
+        };
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(
+        //    plainDateTime.[[ISODateTime]].[[ISODate]], time).
+        let iso_date_time = IsoDateTime::new(iso_date, time);
+        // 5. Return ? CreateTemporalDateTime(isoDateTime,
+        //    plainDateTime.[[Calendar]]).
+        create_temporal_date_time(agent, iso_date_time, None, gc)
+            .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.3.28 Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        to_temporal_calendar_identifier(agent, arguments.get(0), gc)?;
+        // 4. Return ! CreateTemporalDateTime(plainDateTime.[[ISODateTime]],
+        //    calendar).
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        Ok(create_plain_date_time_object(agent, iso_date_time, gc).into_value())
+    }
+
+    /// ### [5.3.29 Temporal.PlainDateTime.prototype.add ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDateTime(add, plainDateTime,
+        //    temporalDurationLike, options).
+        add_duration_to_date_time(
+            agent,
+            ArithmeticOperation::Add,
+            plain_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.3.30 Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDateTime(subtract, plainDateTime,
+        //    temporalDurationLike, options).
+        add_duration_to_date_time(
+            agent,
+            ArithmeticOperation::Subtract,
+            plain_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.3.31 Temporal.PlainDateTime.prototype.until ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDateTime(until, plainDateTime,
+        //    other, options).
+        difference_temporal_plain_date_time(
+            agent,
+            DifferenceOperation::Until,
+            plain_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [5.3.32 Temporal.PlainDateTime.prototype.since ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDateTime(since, plainDateTime,
+        //    other, options).
+        difference_temporal_plain_date_time(
+            agent,
+            DifferenceOperation::Since,
+            plain_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [5.3.33 Temporal.PlainDateTime.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        // 3-9. Read the roundingIncrement, roundingMode and smallestUnit
+        //      options of roundTo.
+        let options = get_round_to_options(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time,
+        //     « day »).
+        validate_temporal_unit_value(
+            options.smallest_unit,
+            UnitGroup::Time,
+            &[UnitValue::Unit(Unit::Day)],
+        )
+        .map_err(|err| err.throw(agent, gc.nogc()))
+        .unbind()?;
+        let UnitValue::Unit(smallest_unit) = options.smallest_unit else {
+            unreachable!()
+        };
+        let (maximum, inclusive) = if smallest_unit == Unit::Day {
+            // 11. If smallestUnit is day, then
+            //     a. Let maximum be 1.
+            //     b. Let inclusive be true.
+            (1, true)
+        } else {
+            // 12. Else,
+            //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(
+            //        smallestUnit).
+            //     b. Assert: maximum is not unset.
+            //     c. Let inclusive be false.
+            (
+                maximum_temporal_duration_rounding_increment(smallest_unit).unwrap(),
+                false,
+            )
+        };
+        // 13. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement,
+        //     maximum, inclusive).
+        validate_temporal_rounding_increment(options.rounding_increment, maximum, inclusive)
+            .map_err(|err| err.throw(agent, gc.nogc()))
+            .unbind()?;
+        // 14. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        if smallest_unit == Unit::Nanosecond && options.rounding_increment == 1 {
+            // a. Return ! CreateTemporalDateTime(plainDateTime.[[ISODateTime]],
+            //    plainDateTime.[[Calendar]]).
+            return Ok(
+                create_plain_date_time_object(agent, iso_date_time, gc.into_nogc()).into_value(),
+            );
+        }
+        // 15. Let result be RoundISODateTime(plainDateTime.[[ISODateTime]],
+        //     roundingIncrement, smallestUnit, roundingMode).
+        let result = round_iso_date_time(
+            iso_date_time,
+            options.rounding_increment,
+            smallest_unit,
+            options.rounding_mode,
+        );
+        // 16. Return ? CreateTemporalDateTime(result,
+        //     plainDateTime.[[Calendar]]).
+        create_temporal_date_time(agent, result, None, gc)
+            .map(|plain_date_time| plain_date_time.into_value())
+    }
+
+    /// ### [5.3.34 Temporal.PlainDateTime.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        // 3. Set other to ? ToTemporalDateTime(other).
+        let other = to_temporal_date_time(agent, arguments.get(0), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 4. If CompareISODateTime(plainDateTime.[[ISODateTime]],
+        //    other.[[ISODateTime]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(plainDateTime.[[Calendar]],
+        //    other.[[Calendar]]).
+        Ok((iso_date_time == other).into())
+    }
+
+    /// ### [5.3.35 Temporal.PlainDateTime.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. NOTE: The following steps read options and perform independent
+        //    validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(
+        //    resolvedOptions).
+        let show_calendar = get_temporal_show_calendar_name_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(
+        //    resolvedOptions).
+        let digits = get_temporal_fractional_second_digits_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Let roundingMode be ? GetRoundingModeOption(resolvedOptions,
+        //    trunc).
+        let rounding_mode = get_rounding_mode_option(
+            agent,
+            resolved_options.get(agent),
+            RoundingMode::Trunc,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 8. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions,
+        //    "smallestUnit", unset).
+        let smallest_unit = get_temporal_unit_valued_option(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.smallestUnit,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // 9. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let smallest_unit = match smallest_unit {
+            UnitValue::Unit(unit) => Some(unit),
+            _ => None,
+        };
+        // 10. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(RangeError("smallestUnit must not be hour").throw(agent, gc));
+        }
+        // 11. Let precision be ToSecondsStringPrecisionRecord(smallestUnit,
+        //     digits).
+        let precision = to_seconds_string_precision_record(smallest_unit, digits);
+        // 12. Let result be RoundISODateTime(plainDateTime.[[ISODateTime]],
+        //     precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let result = round_iso_date_time(
+            iso_date_time,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        );
+        // 13. If ISODateTimeWithinLimits(result) is false, throw a RangeError
+        //     exception.
+        if !iso_date_time_within_limits(result) {
+            return Err(RangeError("date-time is out of range").throw(agent, gc));
+        }
+        // 14. Return ISODateTimeToString(result, plainDateTime.[[Calendar]],
+        //     precision.[[Precision]], showCalendar).
+        let result = iso_date_time_to_string(result, ISO8601, precision.precision, show_calendar);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [5.3.36 Temporal.PlainDateTime.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive date and time formatting this produces the
+    /// same result as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return ISODateTimeToString(plainDateTime.[[ISODateTime]],
+        //    plainDateTime.[[Calendar]], auto, auto).
+        let result = iso_date_time_to_string(
+            agent[plain_date_time].iso_date_time,
+            ISO8601,
+            Precision::Auto,
+            ShowCalendar::Auto,
+        );
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [5.3.37 Temporal.PlainDateTime.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return ISODateTimeToString(plainDateTime.[[ISODateTime]],
+        //    plainDateTime.[[Calendar]], auto, auto).
+        let result = iso_date_time_to_string(
+            agent[plain_date_time].iso_date_time,
+            ISO8601,
+            Precision::Auto,
+            ShowCalendar::Auto,
+        );
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [5.3.38 Temporal.PlainDateTime.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.PlainDateTime cannot be converted to a primitive value; use compare() or equals()",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [5.3.39 Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tozoneddatetime)
+    fn to_zoned_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let iso_date_time = agent[plain_date_time].iso_date_time;
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(
+        //    temporalTimeZoneLike).
+        let time_zone =
+            to_temporal_time_zone_identifier(agent, arguments.get(0), gc.nogc()).unbind()?;
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+        // 5. Let disambiguation be ? GetTemporalDisambiguationOption(
+        //    resolvedOptions).
+        let disambiguation =
+            get_temporal_disambiguation_option(agent, resolved_options, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 6. Let epochNs be ? GetEpochNanosecondsFor(timeZone,
+        //    plainDateTime.[[ISODateTime]], disambiguation).
+        let result = get_epoch_nanoseconds_for(&time_zone, iso_date_time, disambiguation);
+        let epoch_ns = result.map_err(|err| err.throw(agent, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone,
+        //    plainDateTime.[[Calendar]]).
+        Ok(create_zoned_date_time_object(agent, epoch_ns, time_zone, gc).into_value())
+    }
+
+    /// ### [5.3.40 Temporal.PlainDateTime.prototype.toPlainDate ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalDate(
+        //    plainDateTime.[[ISODateTime]].[[ISODate]],
+        //    plainDateTime.[[Calendar]]).
+        let iso_date = agent[plain_date_time].iso_date_time.date;
+        Ok(create_plain_date_object(agent, iso_date, gc).into_value())
+    }
+
+    /// ### [5.3.41 Temporal.PlainDateTime.prototype.toPlainTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaintime)
+    fn to_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime,
+        //    [[InitializedTemporalDateTime]]).
+        let plain_date_time = require_temporal_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalTime(
+        //    plainDateTime.[[ISODateTime]].[[Time]]).
+        let time = agent[plain_date_time].iso_date_time.time;
+        Ok(create_plain_time_object(agent, time, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_plain_date_time_prototype();
+        let plain_date_time_constructor = intrinsics.temporal_plain_date_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(40)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_date_time_constructor)
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetEra>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDay>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetInLeapYear>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetHour>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMinute>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetSecond>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMillisecond>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetMicrosecond>()
+            .with_builtin_function_getter_property::<PlainDateTimePrototypeGetNanosecond>()
+            .with_builtin_function_property::<PlainDateTimePrototypeWith>()
+            .with_builtin_function_property::<PlainDateTimePrototypeWithPlainTime>()
+            .with_builtin_function_property::<PlainDateTimePrototypeWithCalendar>()
+            .with_builtin_function_property::<PlainDateTimePrototypeAdd>()
+            .with_builtin_function_property::<PlainDateTimePrototypeSubtract>()
+            .with_builtin_function_property::<PlainDateTimePrototypeUntil>()
+            .with_builtin_function_property::<PlainDateTimePrototypeSince>()
+            .with_builtin_function_property::<PlainDateTimePrototypeRound>()
+            .with_builtin_function_property::<PlainDateTimePrototypeEquals>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToString>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToLocaleString>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToJSON>()
+            .with_builtin_function_property::<PlainDateTimePrototypeValueOf>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToZonedDateTime>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToPlainDate>()
+            .with_builtin_function_property::<PlainDateTimePrototypeToPlainTime>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainDateTime.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_temporal_date_time<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, PlainDateTime<'a>> {
+    PlainDateTime::try_from(value)
+        .map(|plain_date_time| plain_date_time.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.PlainDateTime object",
+                gc,
+            )
+        })
+}
@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::PlainTimeIndex,
+    },
+};
+
+use self::data::PlainTimeHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PlainTime<'a>(pub(crate) PlainTimeIndex<'a>);
+
+impl PlainTime<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, PlainTime<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(PlainTimeIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainTime<'_> {
+    type Of<'a> = PlainTime<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for PlainTime<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainTime<'a>> for Value<'a> {
+    fn from(value: PlainTime<'a>) -> Self {
+        Value::PlainTime(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for PlainTime<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<PlainTime<'a>> for Object<'a> {
+    fn from(value: PlainTime) -> Self {
+        Object::PlainTime(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for PlainTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::PlainTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for PlainTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::PlainTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for PlainTime<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainTime;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for PlainTime<'a> {}
+
+impl Index<PlainTime<'_>> for Agent {
+    type Output = PlainTimeHeapData<'static>;
+
+    fn index(&self, index: PlainTime) -> &Self::Output {
+        &self.heap.plain_times[index]
+    }
+}
+
+impl IndexMut<PlainTime<'_>> for Agent {
+    fn index_mut(&mut self, index: PlainTime) -> &mut Self::Output {
+        &mut self.heap.plain_times[index]
+    }
+}
+
+impl Index<PlainTime<'_>> for Vec<Option<PlainTimeHeapData<'static>>> {
+    type Output = PlainTimeHeapData<'static>;
+
+    fn index(&self, index: PlainTime) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("PlainTime out of bounds")
+            .as_ref()
+            .expect("PlainTime slot empty")
+    }
+}
+
+impl IndexMut<PlainTime<'_>> for Vec<Option<PlainTimeHeapData<'static>>> {
+    fn index_mut(&mut self, index: PlainTime) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("PlainTime out of bounds")
+            .as_mut()
+            .expect("PlainTime slot empty")
+    }
+}
+
+impl Rootable for PlainTime<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::PlainTime(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::PlainTime(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for PlainTime<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_times.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_times.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<PlainTimeHeapData<'a>, PlainTime<'a>> for Heap {
+    fn create(&mut self, data: PlainTimeHeapData<'a>) -> PlainTime<'a> {
+        self.plain_times.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<PlainTimeHeapData<'static>>>();
+        }
+        PlainTime(PlainTimeIndex::last(&self.plain_times))
+    }
+}
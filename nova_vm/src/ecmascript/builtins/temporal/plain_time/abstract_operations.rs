@@ -0,0 +1,285 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [4.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-plaintime-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Precision, Unit, UnitGroup,
+                    format_time_string, get_difference_settings, get_options_object,
+                    get_overflow_from_options,
+                },
+                calendar::{
+                    Field, RequiredFields, TimeRecord, add_time, difference_time,
+                    prepare_calendar_fields, regulate_time,
+                },
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        InternalDuration, create_duration_object, round_time_duration,
+                        temporal_duration_from_internal, to_internal_duration_record,
+                        to_temporal_duration,
+                    },
+                },
+                parsing::parse_temporal_time_string,
+                plain_time::{PlainTime, data::PlainTimeHeapData},
+                time_zone::get_iso_date_time_for,
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{Function, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// ### [4.5.8 CreateTemporalTime ( time \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporaltime)
+pub(crate) fn create_temporal_time<'gc>(
+    agent: &mut Agent,
+    time: TimeRecord,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainTime<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.PlainTime%.
+    let Some(new_target) = new_target else {
+        return Ok(create_plain_time_object(agent, time, gc.into_nogc()));
+    };
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.PlainTime.prototype%", « [[InitializedTemporalTime]],
+    //    [[Time]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalPlainTime,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = PlainTime::try_from(object.bind(gc)).unwrap();
+    // 3. Set object.[[Time]] to time.
+    agent[object].time = time;
+    // 4. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalTime without a newTarget.
+pub(crate) fn create_plain_time_object<'gc>(
+    agent: &mut Agent,
+    time: TimeRecord,
+    gc: NoGcScope<'gc, '_>,
+) -> PlainTime<'gc> {
+    agent
+        .heap
+        .create(PlainTimeHeapData {
+            object_index: None,
+            time,
+        })
+        .bind(gc)
+}
+
+/// ### [4.5.6 ToTemporalTime ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime)
+///
+/// Returns the time of the Temporal.PlainTime.
+pub(crate) fn to_temporal_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TimeRecord> {
+    let item = item.bind(gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    let options = options.scope(agent, gc.nogc());
+    // 2. If item is an Object, then
+    if let Ok(item) = Object::try_from(item) {
+        let time = match item {
+            // a. If item has an [[InitializedTemporalTime]] internal slot,
+            //    then
+            //    iii. Return ! CreateTemporalTime(item.[[Time]]).
+            Object::PlainTime(plain_time) => Some(agent[plain_time].time),
+            // b. If item has an [[InitializedTemporalDateTime]] internal slot,
+            //    then
+            //    iii. Return ! CreateTemporalTime(
+            //         item.[[ISODateTime]].[[Time]]).
+            Object::PlainDateTime(plain_date_time) => {
+                Some(agent[plain_date_time].iso_date_time.time)
+            }
+            // c. If item has an [[InitializedTemporalZonedDateTime]] internal
+            //    slot, then
+            //    i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]],
+            //       item.[[EpochNanoseconds]]).
+            //    iv. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+            Object::ZonedDateTime(zoned_date_time) => {
+                let data = &agent[zoned_date_time];
+                Some(get_iso_date_time_for(&data.time_zone, data.epoch_nanoseconds).time)
+            }
+            _ => None,
+        };
+        if let Some(time) = time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow_from_options(agent, options.get(agent), gc)?;
+            return Ok(time);
+        }
+        // d. Let result be ? ToTemporalTimeRecord(item).
+        let result = prepare_calendar_fields(
+            agent,
+            item.unbind(),
+            &[Field::TIME],
+            RequiredFields::Partial,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Let resolvedOptions be ? GetOptionsObject(options).
+        // f. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // g. Set result to ? RegulateTime(result.[[Hour]], result.[[Minute]],
+        //    result.[[Second]], result.[[Millisecond]],
+        //    result.[[Microsecond]], result.[[Nanosecond]], overflow).
+        let [hour, minute, second, millisecond, microsecond, nanosecond] = result.time_fields();
+        let result = regulate_time(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            overflow,
+        );
+        // 4. Return ! CreateTemporalTime(result).
+        return result.map_err(|err| err.throw(agent, gc));
+    }
+    // 3. Else,
+    //    a. If item is not a String, throw a TypeError exception.
+    let Ok(item) = String::try_from(item) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "time must be a Temporal.PlainTime, an object or a string",
+            gc.into_nogc(),
+        ));
+    };
+    //    b. Let parseResult be ? ParseTemporalTimeString(item).
+    let parse_result = parse_temporal_time_string(&item.as_wtf8(agent).to_string_lossy());
+    let result = parse_result
+        .map_err(|err| err.throw(agent, gc.nogc()))
+        .unbind()?;
+    //    c. Let resolvedOptions be ? GetOptionsObject(options).
+    //    d. Perform ? GetTemporalOverflowOption(resolvedOptions).
+    get_overflow_from_options(agent, options.get(agent), gc)?;
+    //    e. Set result to parseResult.[[Time]].
+    // 4. Return ! CreateTemporalTime(result).
+    Ok(result)
+}
+
+/// ### [4.5.18 TimeRecordToString ( time, precision )](https://tc39.es/proposal-temporal/#sec-temporal-timerecordtostring)
+pub(crate) fn time_record_to_string(time: TimeRecord, precision: Precision) -> std::string::String {
+    // 1. Let subSecondNanoseconds be time.[[Millisecond]] × 10**6 +
+    //    time.[[Microsecond]] × 10**3 + time.[[Nanosecond]].
+    // 2. Return FormatTimeString(time.[[Hour]], time.[[Minute]],
+    //    time.[[Second]], subSecondNanoseconds, precision).
+    format_time_string(
+        time.hour,
+        time.minute,
+        time.second,
+        time.sub_second_nanoseconds(),
+        precision,
+        true,
+    )
+}
+
+/// ### [4.5.21 DifferenceTemporalPlainTime ( operation, temporalTime, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaintime)
+pub(crate) fn difference_temporal_plain_time<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    temporal_time: PlainTime,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let time = agent[temporal_time].time;
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalTime(other).
+    let other = to_temporal_time(agent, other, Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    time, « », nanosecond, hour).
+    let settings = get_difference_settings(
+        agent,
+        operation,
+        resolved_options,
+        UnitGroup::Time,
+        &[],
+        Unit::Nanosecond,
+        Unit::Hour,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 4. Let timeDuration be DifferenceTime(temporalTime.[[Time]],
+    //    other.[[Time]]).
+    let time_duration = difference_time(time, other);
+    // 5. Set timeDuration to ! RoundTimeDuration(timeDuration,
+    //    settings.[[RoundingIncrement]], settings.[[SmallestUnit]],
+    //    settings.[[RoundingMode]]).
+    // 6. Let duration be CombineDateAndTimeDuration(ZeroDateDuration(),
+    //    timeDuration).
+    // 7. Let result be ! TemporalDurationFromInternal(duration,
+    //    settings.[[LargestUnit]]).
+    let result = round_time_duration(
+        time_duration,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )
+    .and_then(|time_duration| {
+        temporal_duration_from_internal(
+            InternalDuration::new(Default::default(), time_duration),
+            settings.largest_unit,
+        )
+    })
+    .map_err(|err| err.throw(agent, gc))?;
+    // 8. If operation is since, set result to
+    //    CreateNegatedTemporalDuration(result).
+    let result = match operation {
+        DifferenceOperation::Since => result.negated(),
+        DifferenceOperation::Until => result,
+    };
+    // 9. Return result.
+    Ok(create_duration_object(agent, result, gc))
+}
+
+/// ### [4.5.22 AddDurationToTime ( operation, temporalTime, temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtotime)
+pub(crate) fn add_duration_to_time<'gc>(
+    agent: &mut Agent,
+    operation: ArithmeticOperation,
+    temporal_time: PlainTime,
+    temporal_duration_like: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PlainTime<'gc>> {
+    let time = agent[temporal_time].time;
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, temporal_duration_like, gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to
+    //    CreateNegatedTemporalDuration(duration).
+    let duration = match operation {
+        ArithmeticOperation::Subtract => duration.negated(),
+        ArithmeticOperation::Add => duration,
+    };
+    // 3. Let internalDuration be ToInternalDurationRecord(duration).
+    let internal_duration = to_internal_duration_record(&duration);
+    // 4. Let result be AddTime(temporalTime.[[Time]],
+    //    internalDuration.[[Time]]).
+    let (_, result) = add_time(time, internal_duration.time);
+    // 5. Return ! CreateTemporalTime(result).
+    Ok(create_plain_time_object(agent, result, gc.into_nogc()))
+}
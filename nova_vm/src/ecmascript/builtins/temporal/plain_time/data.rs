@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{builtins::temporal::calendar::TimeRecord, types::OrdinaryObject},
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [4.4 Properties of Temporal.PlainTime Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-plaintime-instances)
+#[derive(Debug, Clone)]
+pub struct PlainTimeHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[Time]]
+    pub(crate) time: TimeRecord,
+}
+
+impl Default for PlainTimeHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            time: TimeRecord::MIDNIGHT,
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PlainTimeHeapData<'_> {
+    type Of<'a> = PlainTimeHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PlainTimeHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            time: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            time: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{Overflow, RangeError, to_integer_with_truncation},
+                calendar::{is_valid_time, regulate_time},
+                plain_time::abstract_operations::{
+                    create_plain_time_object, create_temporal_time, to_temporal_time,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct PlainTimeConstructor;
+impl Builtin for PlainTimeConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for PlainTimeConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainTime;
+}
+
+struct PlainTimeFrom;
+impl Builtin for PlainTimeFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimeConstructor::from);
+}
+struct PlainTimeCompare;
+impl Builtin for PlainTimeCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimeConstructor::compare);
+}
+
+impl PlainTimeConstructor {
+    /// ### [4.1.1 Temporal.PlainTime ( \[ hour \[ , minute \[ , second \[ , millisecond \[ , microsecond \[ , nanosecond \] \] \] \] \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.PlainTime requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        let values = (0..6)
+            .map(|index| arguments.get(index).scope(agent, gc.nogc()))
+            .collect::<Vec<_>>();
+        // 2. If hour is undefined, set hour to 0; else set hour to
+        //    ? ToIntegerWithTruncation(hour).
+        // 3-7. Likewise for minute, second, millisecond, microsecond and
+        //      nanosecond.
+        let mut fields = [0.0; 6];
+        for (field, value) in fields.iter_mut().zip(values) {
+            let value = value.get(agent);
+            if !value.is_undefined() {
+                *field = to_integer_with_truncation(agent, value, gc.reborrow()).unbind()?;
+            }
+        }
+        let [hour, minute, second, millisecond, microsecond, nanosecond] = fields;
+        // 8. If IsValidTime(hour, minute, second, millisecond, microsecond,
+        //    nanosecond) is false, throw a RangeError exception.
+        if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
+            return Err(RangeError("invalid time").throw(agent, gc.into_nogc()));
+        }
+        // 9. Let time be CreateTimeRecord(hour, minute, second, millisecond,
+        //    microsecond, nanosecond).
+        let time = regulate_time(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            Overflow::Reject,
+        )
+        .unwrap();
+        // 10. Return ? CreateTemporalTime(time, NewTarget).
+        create_temporal_time(agent, time, Some(new_target.get(agent)), gc)
+            .map(|plain_time| plain_time.into_value())
+    }
+
+    /// ### [4.2.2 Temporal.PlainTime.from ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalTime(item, options).
+        let time =
+            to_temporal_time(agent, arguments.get(0), arguments.get(1), gc.reborrow()).unbind()?;
+        Ok(create_plain_time_object(agent, time, gc.into_nogc()).into_value())
+    }
+
+    /// ### [4.2.3 Temporal.PlainTime.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalTime(one).
+        let one =
+            to_temporal_time(agent, arguments.get(0), Value::Undefined, gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalTime(two).
+        let two = to_temporal_time(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareTimeRecord(one.[[Time]], two.[[Time]])).
+        Ok((one.cmp(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_time_prototype = intrinsics.temporal_plain_time_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<PlainTimeConstructor>(agent, realm)
+            .with_property_capacity(3)
+            .with_prototype_property(plain_time_prototype.into_object())
+            .with_builtin_function_property::<PlainTimeFrom>()
+            .with_builtin_function_property::<PlainTimeCompare>()
+            .build();
+    }
+}
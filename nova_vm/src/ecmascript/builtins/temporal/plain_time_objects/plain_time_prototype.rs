@@ -0,0 +1,641 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Precision, RangeError, RoundingMode,
+                    Unit, UnitGroup, UnitValue, get_options_object, get_overflow_from_options,
+                    get_round_to_options, get_rounding_mode_option,
+                    get_temporal_fractional_second_digits_option, get_temporal_unit_valued_option,
+                    is_partial_temporal_object, maximum_temporal_duration_rounding_increment,
+                    to_seconds_string_precision_record, validate_temporal_rounding_increment,
+                    validate_temporal_unit_value,
+                },
+                calendar::{
+                    CalendarFields, Field, RequiredFields, prepare_calendar_fields, regulate_time,
+                    round_time,
+                },
+                plain_time::{
+                    PlainTime,
+                    abstract_operations::{
+                        add_duration_to_time, create_plain_time_object,
+                        difference_temporal_plain_time, time_record_to_string, to_temporal_time,
+                    },
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct PlainTimePrototype;
+
+struct PlainTimePrototypeGetHour;
+impl Builtin for PlainTimePrototypeGetHour {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hour;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hour.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Hour as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetHour {}
+struct PlainTimePrototypeGetMinute;
+impl Builtin for PlainTimePrototypeGetMinute {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minute;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minute.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Minute as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetMinute {}
+struct PlainTimePrototypeGetSecond;
+impl Builtin for PlainTimePrototypeGetSecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_second;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.second.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Second as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetSecond {}
+struct PlainTimePrototypeGetMillisecond;
+impl Builtin for PlainTimePrototypeGetMillisecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_millisecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.millisecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Millisecond as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetMillisecond {}
+struct PlainTimePrototypeGetMicrosecond;
+impl Builtin for PlainTimePrototypeGetMicrosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microsecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microsecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Microsecond as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetMicrosecond {}
+struct PlainTimePrototypeGetNanosecond;
+impl Builtin for PlainTimePrototypeGetNanosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanosecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanosecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(PlainTimePrototype::get_field::<{ Unit::Nanosecond as usize }>);
+}
+impl BuiltinGetter for PlainTimePrototypeGetNanosecond {}
+struct PlainTimePrototypeAdd;
+impl Builtin for PlainTimePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::add);
+}
+struct PlainTimePrototypeSubtract;
+impl Builtin for PlainTimePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::subtract);
+}
+struct PlainTimePrototypeWith;
+impl Builtin for PlainTimePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::with);
+}
+struct PlainTimePrototypeUntil;
+impl Builtin for PlainTimePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::until);
+}
+struct PlainTimePrototypeSince;
+impl Builtin for PlainTimePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::since);
+}
+struct PlainTimePrototypeRound;
+impl Builtin for PlainTimePrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::round);
+}
+struct PlainTimePrototypeEquals;
+impl Builtin for PlainTimePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::equals);
+}
+struct PlainTimePrototypeToString;
+impl Builtin for PlainTimePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::to_string);
+}
+struct PlainTimePrototypeToLocaleString;
+impl Builtin for PlainTimePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::to_locale_string);
+}
+struct PlainTimePrototypeToJSON;
+impl Builtin for PlainTimePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::to_json);
+}
+struct PlainTimePrototypeValueOf;
+impl Builtin for PlainTimePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(PlainTimePrototype::value_of);
+}
+
+impl PlainTimePrototype {
+    /// ### [4.3.3 get Temporal.PlainTime.prototype.hour](https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.hour)
+    ///
+    /// The getters from hour to nanosecond, with the unit of the field as an
+    /// index into the units.
+    fn get_field<'gc, const UNIT: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainTime.[[Time]].[[Hour]]).
+        Ok(agent[plain_time].time.field(UNIT).into())
+    }
+
+    /// ### [4.3.9 Temporal.PlainTime.prototype.add ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToTime(add, plainTime, temporalDurationLike).
+        add_duration_to_time(
+            agent,
+            ArithmeticOperation::Add,
+            plain_time.unbind(),
+            arguments.get(0),
+            gc,
+        )
+        .map(|plain_time| plain_time.into_value())
+    }
+
+    /// ### [4.3.10 Temporal.PlainTime.prototype.subtract ( temporalDurationLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToTime(subtract, plainTime,
+        //    temporalDurationLike).
+        add_duration_to_time(
+            agent,
+            ArithmeticOperation::Subtract,
+            plain_time.unbind(),
+            arguments.get(0),
+            gc,
+        )
+        .map(|plain_time| plain_time.into_value())
+    }
+
+    /// ### [4.3.11 Temporal.PlainTime.prototype.with ( temporalTimeLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let time = agent[plain_time].time;
+        // 3. If ? IsPartialTemporalObject(temporalTimeLike) is false, throw a
+        //    TypeError exception.
+        let Some(temporal_time_like) =
+            is_partial_temporal_object(agent, arguments.get(0), gc.reborrow()).unbind()?
+        else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "with requires a partial Temporal.PlainTime-like object",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let partialTime be ? ToTemporalTimeRecord(temporalTimeLike,
+        //    partial).
+        let partial_time = prepare_calendar_fields(
+            agent,
+            temporal_time_like,
+            &[Field::TIME],
+            RequiredFields::Partial,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 5. If partialTime.[[Hour]] is not undefined, then
+        //    a. Let hour be partialTime.[[Hour]].
+        // 6. Else,
+        //    a. Let hour be plainTime.[[Time]].[[Hour]].
+        // 7-10. Likewise for minute, second, millisecond, microsecond and
+        //       nanosecond.
+        let fields = CalendarFields::default()
+            .with_time(time)
+            .merge(partial_time);
+        // 11. Let resolvedOptions be ? GetOptionsObject(options).
+        // 12. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_overflow_from_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 13. Let result be ? RegulateTime(hour, minute, second, millisecond,
+        //     microsecond, nanosecond, overflow).
+        let [hour, minute, second, millisecond, microsecond, nanosecond] = fields.time_fields();
+        let result = regulate_time(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            overflow,
+        );
+        let result = result.map_err(|err| err.throw(agent, gc))?;
+        // 14. Return ! CreateTemporalTime(result).
+        Ok(create_plain_time_object(agent, result, gc).into_value())
+    }
+
+    /// ### [4.3.12 Temporal.PlainTime.prototype.until ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainTime(until, plainTime, other,
+        //    options).
+        difference_temporal_plain_time(
+            agent,
+            DifferenceOperation::Until,
+            plain_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [4.3.13 Temporal.PlainTime.prototype.since ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainTime(since, plainTime, other,
+        //    options).
+        difference_temporal_plain_time(
+            agent,
+            DifferenceOperation::Since,
+            plain_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [4.3.14 Temporal.PlainTime.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let time = agent[plain_time].time;
+        // 3-9. Read the roundingIncrement, roundingMode and smallestUnit
+        //      options of roundTo.
+        let options = get_round_to_options(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(options.smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let UnitValue::Unit(smallest_unit) = options.smallest_unit else {
+            unreachable!()
+        };
+        // 11. Let maximum be MaximumTemporalDurationRoundingIncrement(
+        //     smallestUnit).
+        let maximum = maximum_temporal_duration_rounding_increment(smallest_unit).unwrap();
+        // 12. Assert: maximum is not unset.
+        // 13. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement,
+        //     maximum, false).
+        validate_temporal_rounding_increment(options.rounding_increment, maximum, false)
+            .map_err(|err| err.throw(agent, gc))?;
+        // 14. Let result be RoundTime(plainTime.[[Time]], roundingIncrement,
+        //     smallestUnit, roundingMode).
+        let (_, result) = round_time(
+            time,
+            options.rounding_increment,
+            smallest_unit,
+            options.rounding_mode,
+        );
+        // 15. Return ! CreateTemporalTime(result).
+        Ok(create_plain_time_object(agent, result, gc).into_value())
+    }
+
+    /// ### [4.3.15 Temporal.PlainTime.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let time = agent[plain_time].time;
+        // 3. Set other to ? ToTemporalTime(other).
+        let other =
+            to_temporal_time(agent, arguments.get(0), Value::Undefined, gc.reborrow()).unbind()?;
+        // 4. If CompareTimeRecord(plainTime.[[Time]], other.[[Time]]) = 0,
+        //    return true.
+        // 5. Return false.
+        Ok((time == other).into())
+    }
+
+    /// ### [4.3.16 Temporal.PlainTime.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let time = agent[plain_time].time;
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. NOTE: The following steps read options and perform independent
+        //    validation in alphabetical order.
+        // 5. Let digits be ? GetTemporalFractionalSecondDigitsOption(
+        //    resolvedOptions).
+        let digits = get_temporal_fractional_second_digits_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 6. Let roundingMode be ? GetRoundingModeOption(resolvedOptions,
+        //    trunc).
+        let rounding_mode = get_rounding_mode_option(
+            agent,
+            resolved_options.get(agent),
+            RoundingMode::Trunc,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions,
+        //    "smallestUnit", unset).
+        let smallest_unit = get_temporal_unit_valued_option(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.smallestUnit,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // 8. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let smallest_unit = match smallest_unit {
+            UnitValue::Unit(unit) => Some(unit),
+            _ => None,
+        };
+        // 9. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(RangeError("smallestUnit must not be hour").throw(agent, gc));
+        }
+        // 10. Let precision be ToSecondsStringPrecisionRecord(smallestUnit,
+        //     digits).
+        let precision = to_seconds_string_precision_record(smallest_unit, digits);
+        // 11. Let roundResult be RoundTime(plainTime.[[Time]],
+        //     precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let (_, round_result) =
+            round_time(time, precision.increment, precision.unit, rounding_mode);
+        // 12. Return TimeRecordToString(roundResult, precision.[[Precision]]).
+        let result = time_record_to_string(round_result, precision.precision);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [4.3.17 Temporal.PlainTime.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive time formatting this produces the same result
+    /// as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc)?;
+        // 3. Return TimeRecordToString(plainTime.[[Time]], auto).
+        let result = time_record_to_string(agent[plain_time].time, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [4.3.18 Temporal.PlainTime.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainTime,
+        //    [[InitializedTemporalTime]]).
+        let plain_time = require_temporal_time(agent, this_value, gc)?;
+        // 3. Return TimeRecordToString(plainTime.[[Time]], auto).
+        let result = time_record_to_string(agent[plain_time].time, Precision::Auto);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [4.3.19 Temporal.PlainTime.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.PlainTime cannot be converted to a primitive value; use compare() or equals()",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_plain_time_prototype();
+        let plain_time_constructor = intrinsics.temporal_plain_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(19)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_time_constructor)
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetHour>()
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetMinute>()
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetSecond>()
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetMillisecond>()
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetMicrosecond>()
+            .with_builtin_function_getter_property::<PlainTimePrototypeGetNanosecond>()
+            .with_builtin_function_property::<PlainTimePrototypeAdd>()
+            .with_builtin_function_property::<PlainTimePrototypeSubtract>()
+            .with_builtin_function_property::<PlainTimePrototypeWith>()
+            .with_builtin_function_property::<PlainTimePrototypeUntil>()
+            .with_builtin_function_property::<PlainTimePrototypeSince>()
+            .with_builtin_function_property::<PlainTimePrototypeRound>()
+            .with_builtin_function_property::<PlainTimePrototypeEquals>()
+            .with_builtin_function_property::<PlainTimePrototypeToString>()
+            .with_builtin_function_property::<PlainTimePrototypeToLocaleString>()
+            .with_builtin_function_property::<PlainTimePrototypeToJSON>()
+            .with_builtin_function_property::<PlainTimePrototypeValueOf>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainTime.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_temporal_time<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, PlainTime<'a>> {
+    PlainTime::try_from(value)
+        .map(|plain_time| plain_time.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.PlainTime object",
+                gc,
+            )
+        })
+}
@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        execution::{Agent, Realm},
+        types::{BUILTIN_STRING_MEMORY, IntoValue},
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct TemporalObject;
+
+impl TemporalObject {
+    /// ### [1 The Temporal Object](https://tc39.es/proposal-temporal/#sec-temporal-objects)
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal();
+        let duration = intrinsics.temporal_duration();
+        let instant = intrinsics.temporal_instant();
+        let now = intrinsics.temporal_now();
+        let plain_date = intrinsics.temporal_plain_date();
+        let plain_date_time = intrinsics.temporal_plain_date_time();
+        let plain_time = intrinsics.temporal_plain_time();
+        let zoned_date_time = intrinsics.temporal_zoned_date_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(8)
+            .with_prototype(object_prototype)
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.Duration.into())
+                    .with_value(duration.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.Instant.into())
+                    .with_value(instant.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainDate.into())
+                    .with_value(plain_date.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainDateTime.into())
+                    .with_value(plain_date_time.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainTime.into())
+                    .with_value(plain_time.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.ZonedDateTime.into())
+                    .with_value(zoned_date_time.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.Now.into())
+                    .with_value(now.into_value())
+                    .with_enumerable(false)
+                    .build()
+            })
+            .build();
+    }
+}
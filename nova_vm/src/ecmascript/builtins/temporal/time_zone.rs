@@ -0,0 +1,612 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [11 Time Zones](https://tc39.es/proposal-temporal/#sec-temporal-time-zones)
+//!
+//! Named time zones are resolved using the time zone database of the host,
+//! as is done for Date.
+
+use std::sync::Arc;
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            numbers_and_dates::date_objects::time_zone::{
+                TimeZoneRules, get_available_named_time_zone_identifier, is_utc_identifier,
+            },
+            temporal::{
+                abstract_operations::{
+                    Disambiguation, NS_PER_DAY, OffsetOption, RangeError, RoundingMode,
+                    TemporalResult, format_offset_time_zone_identifier, is_valid_epoch_nanoseconds,
+                    round_number_to_increment,
+                },
+                calendar::{IsoDate, IsoDateTime, TimeRecord, add_time, balance_iso_date},
+                parsing::{TimeZoneIdentifier, parse_temporal_time_zone_string},
+            },
+        },
+        execution::{Agent, JsResult, agent::ExceptionType},
+        types::{Object, String, Value},
+    },
+    engine::context::{NoGcScope, trivially_bindable},
+};
+
+const NS_PER_SECOND: i128 = 1_000_000_000;
+
+/// An available time zone: either an offset time zone or a named time zone.
+#[derive(Debug, Clone)]
+pub(crate) enum TimeZone {
+    /// An offset time zone identifier, as a number of minutes.
+    Offset(i16),
+    /// A named time zone.
+    Named {
+        /// The case-normalized time zone identifier.
+        identifier: Box<str>,
+        /// The rules of the time zone, or None for UTC.
+        rules: Option<Arc<TimeZoneRules>>,
+    },
+}
+trivially_bindable!(TimeZone);
+
+impl TimeZone {
+    /// The "UTC" time zone.
+    pub(crate) fn utc() -> Self {
+        Self::Named {
+            identifier: "UTC".into(),
+            rules: None,
+        }
+    }
+
+    /// The identifier of the time zone.
+    pub(crate) fn identifier(&self) -> std::string::String {
+        match self {
+            Self::Offset(offset_minutes) => {
+                format_offset_time_zone_identifier(i32::from(*offset_minutes), true)
+            }
+            Self::Named { identifier, .. } => identifier.to_string(),
+        }
+    }
+
+    /// ### [11.1.2 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
+    ///
+    /// The time zone of the host, as used by Date.
+    pub(crate) fn system(agent: &Agent) -> Self {
+        let system_time_zone = agent.system_time_zone();
+        let identifier = system_time_zone.identifier();
+        match parse_temporal_time_zone_string(identifier) {
+            Ok(TimeZoneIdentifier::Offset(offset_minutes)) => Self::Offset(offset_minutes),
+            _ if is_utc_identifier(identifier) => Self::utc(),
+            _ => Self::Named {
+                identifier: identifier.into(),
+                rules: system_time_zone.shared_rules(),
+            },
+        }
+    }
+}
+
+/// ### [11.1.2 GetNamedTimeZoneNextTransition ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezonenexttransition)
+///
+/// Returns None for an offset time zone, as well as when there is no
+/// transition after epochNanoseconds within the valid epoch nanoseconds.
+pub(crate) fn get_named_time_zone_next_transition(
+    time_zone: &TimeZone,
+    epoch_nanoseconds: i128,
+) -> Option<i128> {
+    let TimeZone::Named {
+        rules: Some(rules), ..
+    } = time_zone
+    else {
+        return None;
+    };
+    let epoch_seconds = epoch_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+    let transition = i128::from(rules.next_transition(epoch_seconds)?) * NS_PER_SECOND;
+    is_valid_epoch_nanoseconds(transition).then_some(transition)
+}
+
+/// ### [11.1.3 GetNamedTimeZonePreviousTransition ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezoneprevioustransition)
+///
+/// Returns None for an offset time zone, as well as when there is no
+/// transition before epochNanoseconds within the valid epoch nanoseconds.
+pub(crate) fn get_named_time_zone_previous_transition(
+    time_zone: &TimeZone,
+    epoch_nanoseconds: i128,
+) -> Option<i128> {
+    let TimeZone::Named {
+        rules: Some(rules), ..
+    } = time_zone
+    else {
+        return None;
+    };
+    // Note: Transitions happen on whole seconds, so a transition before
+    // epochNanoseconds is one before the following whole second.
+    let epoch_seconds = (epoch_nanoseconds + NS_PER_SECOND - 1).div_euclid(NS_PER_SECOND) as i64;
+    let transition = i128::from(rules.previous_transition(epoch_seconds)?) * NS_PER_SECOND;
+    is_valid_epoch_nanoseconds(transition).then_some(transition)
+}
+
+/// ### [11.1.4 TimeZoneEquals ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal-timezoneequals)
+pub(crate) fn time_zone_equals(one: &TimeZone, two: &TimeZone) -> bool {
+    match (one, two) {
+        // 3. If IsOffsetTimeZoneIdentifier(one) is true and
+        //    IsOffsetTimeZoneIdentifier(two) is true, then
+        //    a. Let offsetMinutesOne be ! ParseTimeZoneIdentifier(one).
+        //    b. Let offsetMinutesTwo be ! ParseTimeZoneIdentifier(two).
+        //    c. If offsetMinutesOne = offsetMinutesTwo, return true.
+        (TimeZone::Offset(one), TimeZone::Offset(two)) => one == two,
+        // 1. If one is two, return true.
+        // 2. If IsOffsetTimeZoneIdentifier(one) is false and
+        //    IsOffsetTimeZoneIdentifier(two) is false, then
+        //    a. Let recordOne be GetAvailableNamedTimeZoneIdentifier(one).
+        //    b. Let recordTwo be GetAvailableNamedTimeZoneIdentifier(two).
+        //    c. If recordOne is not empty and recordTwo is not empty and
+        //       recordOne.[[PrimaryIdentifier]] is
+        //       recordTwo.[[PrimaryIdentifier]], return true.
+        (
+            TimeZone::Named {
+                identifier: one,
+                rules: rules_one,
+            },
+            TimeZone::Named {
+                identifier: two,
+                rules: rules_two,
+            },
+        ) => one.eq_ignore_ascii_case(two) || (rules_one.is_none() && rules_two.is_none()),
+        // 4. Return false.
+        _ => false,
+    }
+}
+
+/// ### [11.1.6 ToTemporalTimeZoneIdentifier ( temporalTimeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezoneidentifier)
+pub(crate) fn to_temporal_time_zone_identifier<'a>(
+    agent: &mut Agent,
+    temporal_time_zone_like: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TimeZone> {
+    // 1. If temporalTimeZoneLike is an Object, then
+    //    a. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]]
+    //       internal slot, then
+    //       i. Return temporalTimeZoneLike.[[TimeZone]].
+    if let Ok(Object::ZonedDateTime(zoned_date_time)) = Object::try_from(temporal_time_zone_like) {
+        return Ok(agent[zoned_date_time].time_zone.clone());
+    }
+    // 2. If temporalTimeZoneLike is not a String, throw a TypeError exception.
+    let Ok(temporal_time_zone_like) = String::try_from(temporal_time_zone_like) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "time zone must be a string",
+            gc,
+        ));
+    };
+    // 3. Let parseResult be ? ParseTemporalTimeZoneString(temporalTimeZoneLike).
+    let parse_result =
+        parse_temporal_time_zone_string(&temporal_time_zone_like.as_wtf8(agent).to_string_lossy());
+    let parse_result = parse_result.map_err(|err| err.throw(agent, gc))?;
+    to_time_zone(parse_result).map_err(|err| err.throw(agent, gc))
+}
+
+/// Steps 4 to 9 of ToTemporalTimeZoneIdentifier: the available time zone
+/// of a parsed time zone identifier.
+pub(crate) fn to_time_zone(parse_result: TimeZoneIdentifier) -> TemporalResult<TimeZone> {
+    match parse_result {
+        // 4. Let offsetMinutes be parseResult.[[OffsetMinutes]].
+        // 5. If offsetMinutes is not empty, return
+        //    FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        TimeZoneIdentifier::Offset(offset_minutes) => Ok(TimeZone::Offset(offset_minutes)),
+        // 6. Let name be parseResult.[[Name]].
+        TimeZoneIdentifier::Name(name) => {
+            // 7. Let timeZoneIdentifierRecord be
+            //    GetAvailableNamedTimeZoneIdentifier(name).
+            // 8. If timeZoneIdentifierRecord is empty, throw a RangeError
+            //    exception.
+            let Some((identifier, rules)) = get_available_named_time_zone_identifier(&name) else {
+                return Err(RangeError("unknown time zone"));
+            };
+            // 9. Return timeZoneIdentifierRecord.[[Identifier]].
+            Ok(TimeZone::Named {
+                identifier: identifier.into_boxed_str(),
+                rules: rules.map(Arc::new),
+            })
+        }
+    }
+}
+
+/// ### [11.1.7 GetOffsetNanosecondsFor ( timeZone, epochNs )](https://tc39.es/proposal-temporal/#sec-temporal-getoffsetnanosecondsfor)
+pub(crate) fn get_offset_nanoseconds_for(time_zone: &TimeZone, epoch_ns: i128) -> i64 {
+    match time_zone {
+        // 1. Let parseResult be ! ParseTimeZoneIdentifier(timeZone).
+        // 2. If parseResult.[[OffsetMinutes]] is not empty, return
+        //    parseResult.[[OffsetMinutes]] × (60 × 10**9).
+        TimeZone::Offset(offset_minutes) => i64::from(*offset_minutes) * 60_000_000_000,
+        // 3. Return GetNamedTimeZoneOffsetNanoseconds(parseResult.[[Name]],
+        //    epochNs).
+        TimeZone::Named { rules: None, .. } => 0,
+        TimeZone::Named {
+            rules: Some(rules), ..
+        } => {
+            let epoch_seconds = epoch_ns.div_euclid(NS_PER_SECOND) as i64;
+            i64::from(rules.offset_at(epoch_seconds)) * 1_000_000_000
+        }
+    }
+}
+
+/// ### [11.1.9 GetISODateTimeFor ( timeZone, epochNs )](https://tc39.es/proposal-temporal/#sec-temporal-getisodatetimefor)
+pub(crate) fn get_iso_date_time_for(time_zone: &TimeZone, epoch_ns: i128) -> IsoDateTime {
+    // 1. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+    let offset_nanoseconds = get_offset_nanoseconds_for(time_zone, epoch_ns);
+    // 2. Let result be GetISOPartsFromEpoch(ℝ(epochNs)).
+    // 3. Return BalanceISODateTime(result.[[ISODate]].[[Year]],
+    //    result.[[ISODate]].[[Month]], result.[[ISODate]].[[Day]],
+    //    result.[[Time]].[[Hour]], result.[[Time]].[[Minute]],
+    //    result.[[Time]].[[Second]], result.[[Time]].[[Millisecond]],
+    //    result.[[Time]].[[Microsecond]], result.[[Time]].[[Nanosecond]] +
+    //    offsetNanoseconds).
+    IsoDateTime::from_epoch_nanoseconds(epoch_ns + i128::from(offset_nanoseconds))
+}
+
+/// ### [11.1.10 GetEpochNanosecondsFor ( timeZone, isoDateTime, disambiguation )](https://tc39.es/proposal-temporal/#sec-temporal-getepochnanosecondsfor)
+pub(crate) fn get_epoch_nanoseconds_for(
+    time_zone: &TimeZone,
+    iso_date_time: IsoDateTime,
+    disambiguation: Disambiguation,
+) -> TemporalResult<i128> {
+    // 1. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone,
+    //    isoDateTime).
+    let possible_epoch_ns = get_possible_epoch_nanoseconds(time_zone, iso_date_time)?;
+    // 2. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs,
+    //    timeZone, isoDateTime, disambiguation).
+    disambiguate_possible_epoch_nanoseconds(
+        &possible_epoch_ns,
+        time_zone,
+        iso_date_time,
+        disambiguation,
+    )
+}
+
+/// ### [11.1.11 DisambiguatePossibleEpochNanoseconds ( possibleEpochNs, timeZone, isoDateTime, disambiguation )](https://tc39.es/proposal-temporal/#sec-temporal-disambiguatepossibleepochnanoseconds)
+pub(crate) fn disambiguate_possible_epoch_nanoseconds(
+    possible_epoch_ns: &[i128],
+    time_zone: &TimeZone,
+    iso_date_time: IsoDateTime,
+    disambiguation: Disambiguation,
+) -> TemporalResult<i128> {
+    // 1. Let n be possibleEpochNs's length.
+    let n = possible_epoch_ns.len();
+    // 2. If n = 1, then
+    if n == 1 {
+        // a. Return possibleEpochNs[0].
+        return Ok(possible_epoch_ns[0]);
+    }
+    // 3. If n ≠ 0, then
+    if n != 0 {
+        return match disambiguation {
+            // a. If disambiguation is earlier or compatible, then
+            //    i. Return possibleEpochNs[0].
+            Disambiguation::Earlier | Disambiguation::Compatible => Ok(possible_epoch_ns[0]),
+            // b. If disambiguation is later, then
+            //    i. Return possibleEpochNs[n - 1].
+            Disambiguation::Later => Ok(possible_epoch_ns[n - 1]),
+            // c. Assert: disambiguation is reject.
+            // d. Throw a RangeError exception.
+            Disambiguation::Reject => Err(RangeError(
+                "the wall-clock time is ambiguous in the time zone",
+            )),
+        };
+    }
+    // 4. Assert: n = 0.
+    // 5. If disambiguation is reject, then
+    if disambiguation == Disambiguation::Reject {
+        // a. Throw a RangeError exception.
+        return Err(RangeError(
+            "the wall-clock time does not exist in the time zone",
+        ));
+    }
+    // 6. Let before be the latest possible ISO Date-Time Record for which
+    //    CompareISODateTime(before, isoDateTime) = -1 and
+    //    ! GetPossibleEpochNanoseconds(timeZone, before) is not empty.
+    // 7. Let after be the earliest possible ISO Date-Time Record for which
+    //    CompareISODateTime(after, isoDateTime) = 1 and
+    //    ! GetPossibleEpochNanoseconds(timeZone, after) is not empty.
+    // 8. Let beforePossible be ! GetPossibleEpochNanoseconds(timeZone,
+    //    before).
+    // 9. Assert: beforePossible's length is 1.
+    // 10. Let afterPossible be ! GetPossibleEpochNanoseconds(timeZone,
+    //     after).
+    // 11. Assert: afterPossible's length is 1.
+    // 12. Let offsetBefore be GetOffsetNanosecondsFor(timeZone,
+    //     beforePossible[0]).
+    // 13. Let offsetAfter be GetOffsetNanosecondsFor(timeZone,
+    //     afterPossible[0]).
+    // 14. Let nanoseconds be offsetAfter - offsetBefore.
+    // NOTE: The offsets before and after the transition are those a day
+    // before and after the wall-clock time.
+    let epoch_nanoseconds = iso_date_time.to_epoch_nanoseconds();
+    let day_before = epoch_nanoseconds - NS_PER_DAY;
+    let day_after = epoch_nanoseconds + NS_PER_DAY;
+    if !is_valid_epoch_nanoseconds(day_before) || !is_valid_epoch_nanoseconds(day_after) {
+        return Err(RangeError("date-time is out of range"));
+    }
+    let offset_before = get_offset_nanoseconds_for(time_zone, day_before);
+    let offset_after = get_offset_nanoseconds_for(time_zone, day_after);
+    let nanoseconds = i128::from(offset_after - offset_before);
+    // 15. Assert: abs(nanoseconds) ≤ nsPerDay.
+    debug_assert!(nanoseconds.abs() <= NS_PER_DAY);
+    // 16. If disambiguation is earlier, then
+    if disambiguation == Disambiguation::Earlier {
+        // a. Let timeDuration be TimeDurationFromComponents(0, 0, 0, 0, 0,
+        //    -nanoseconds).
+        // b. Let earlierTime be AddTime(isoDateTime.[[Time]], timeDuration).
+        // c. Let earlierDate be BalanceISODate(isoDateTime.[[ISODate]].[[Year]],
+        //    isoDateTime.[[ISODate]].[[Month]], isoDateTime.[[ISODate]].[[Day]]
+        //    + earlierTime.[[Days]]).
+        // d. Let earlier be CombineISODateAndTimeRecord(earlierDate,
+        //    earlierTime).
+        let earlier = add_nanoseconds_to_iso_date_time(iso_date_time, -nanoseconds);
+        // e. Set possibleEpochNs to ? GetPossibleEpochNanoseconds(timeZone,
+        //    earlier).
+        let possible_epoch_ns = get_possible_epoch_nanoseconds(time_zone, earlier)?;
+        // f. Assert: possibleEpochNs is not empty.
+        // g. Return possibleEpochNs[0].
+        return possible_epoch_ns.first().copied().ok_or(RangeError(
+            "the wall-clock time does not exist in the time zone",
+        ));
+    }
+    // 17. Assert: disambiguation is compatible or later.
+    // 18. Let timeDuration be TimeDurationFromComponents(0, 0, 0, 0, 0,
+    //     nanoseconds).
+    // 19. Let laterTime be AddTime(isoDateTime.[[Time]], timeDuration).
+    // 20. Let laterDate be BalanceISODate(isoDateTime.[[ISODate]].[[Year]],
+    //     isoDateTime.[[ISODate]].[[Month]], isoDateTime.[[ISODate]].[[Day]] +
+    //     laterTime.[[Days]]).
+    // 21. Let later be CombineISODateAndTimeRecord(laterDate, laterTime).
+    let later = add_nanoseconds_to_iso_date_time(iso_date_time, nanoseconds);
+    // 22. Set possibleEpochNs to ? GetPossibleEpochNanoseconds(timeZone,
+    //     later).
+    let possible_epoch_ns = get_possible_epoch_nanoseconds(time_zone, later)?;
+    // 23. Set n to possibleEpochNs's length.
+    // 24. Assert: n ≠ 0.
+    // 25. Return possibleEpochNs[n - 1].
+    possible_epoch_ns.last().copied().ok_or(RangeError(
+        "the wall-clock time does not exist in the time zone",
+    ))
+}
+
+/// Add a number of nanoseconds to the time of a date-time, balancing the
+/// date.
+pub(crate) fn add_nanoseconds_to_iso_date_time(
+    iso_date_time: IsoDateTime,
+    nanoseconds: i128,
+) -> IsoDateTime {
+    let (days, time) = add_time(iso_date_time.time, nanoseconds);
+    let date = iso_date_time.date;
+    let date = balance_iso_date(i64::from(date.year), date.month, i64::from(date.day) + days);
+    IsoDateTime::new(date, time)
+}
+
+/// ### [11.1.12 GetPossibleEpochNanoseconds ( timeZone, isoDateTime )](https://tc39.es/proposal-temporal/#sec-temporal-getpossibleepochnanoseconds)
+pub(crate) fn get_possible_epoch_nanoseconds(
+    time_zone: &TimeZone,
+    iso_date_time: IsoDateTime,
+) -> TemporalResult<Vec<i128>> {
+    // 1. Let parseResult be ! ParseTimeZoneIdentifier(timeZone).
+    let possible_epoch_nanoseconds = match time_zone {
+        // 2. If parseResult.[[OffsetMinutes]] is not empty, then
+        TimeZone::Offset(offset_minutes) => {
+            // a. Let balanced be BalanceISODateTime(
+            //    isoDateTime.[[ISODate]].[[Year]], ...,
+            //    isoDateTime.[[Time]].[[Minute]] - parseResult.[[OffsetMinutes]],
+            //    ...).
+            let balanced = add_nanoseconds_to_iso_date_time(
+                iso_date_time,
+                -i128::from(*offset_minutes) * 60 * NS_PER_SECOND,
+            );
+            // b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+            check_iso_days_range(balanced.date)?;
+            // c. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+            // d. Let possibleEpochNanoseconds be « epochNanoseconds ».
+            vec![balanced.to_epoch_nanoseconds()]
+        }
+        // 3. Else,
+        TimeZone::Named { rules, .. } => {
+            // a. Perform ? CheckISODaysRange(isoDateTime.[[ISODate]]).
+            check_iso_days_range(iso_date_time.date)?;
+            // b. Let possibleEpochNanoseconds be
+            //    GetNamedTimeZoneEpochNanoseconds(parseResult.[[Name]],
+            //    isoDateTime).
+            let local_nanoseconds = iso_date_time.to_epoch_nanoseconds();
+            match rules {
+                None => vec![local_nanoseconds],
+                Some(rules) => {
+                    let local_seconds = local_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+                    rules
+                        .possible_offsets(local_seconds)
+                        .into_iter()
+                        .map(|offset| local_nanoseconds - i128::from(offset) * NS_PER_SECOND)
+                        .collect()
+                }
+            }
+        }
+    };
+    // 4. For each value epochNanoseconds in possibleEpochNanoseconds, do
+    //    a. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+    //       RangeError exception.
+    if !possible_epoch_nanoseconds
+        .iter()
+        .all(|epoch_nanoseconds| is_valid_epoch_nanoseconds(*epoch_nanoseconds))
+    {
+        return Err(RangeError("date-time is out of range"));
+    }
+    // 5. Return possibleEpochNanoseconds.
+    Ok(possible_epoch_nanoseconds)
+}
+
+/// ### [11.1.13 GetStartOfDay ( timeZone, isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-getstartofday)
+pub(crate) fn get_start_of_day(time_zone: &TimeZone, iso_date: IsoDate) -> TemporalResult<i128> {
+    // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate,
+    //    MidnightTimeRecord()).
+    let iso_date_time = IsoDateTime::new(iso_date, TimeRecord::MIDNIGHT);
+    // 2. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone,
+    //    isoDateTime).
+    let possible_epoch_ns = get_possible_epoch_nanoseconds(time_zone, iso_date_time)?;
+    // 3. If possibleEpochNs is not empty, return possibleEpochNs[0].
+    if let Some(epoch_ns) = possible_epoch_ns.first() {
+        return Ok(*epoch_ns);
+    }
+    // 4. Assert: IsOffsetTimeZoneIdentifier(timeZone) is false.
+    let TimeZone::Named {
+        rules: Some(rules), ..
+    } = time_zone
+    else {
+        unreachable!();
+    };
+    // 5. Let possibleEpochNsAfter be GetNamedTimeZoneEpochNanoseconds(
+    //    timeZone, isoDateTimeAfter), where isoDateTimeAfter is the ISO
+    //    Date-Time Record for which DifferenceISODateTime(isoDateTime,
+    //    isoDateTimeAfter, "iso8601", hour).[[Time]] is the smallest possible
+    //    value > 0 for which possibleEpochNsAfter is not empty (i.e.,
+    //    isoDateTimeAfter represents the first local time after the
+    //    transition).
+    // 6. Assert: possibleEpochNsAfter's length is 1.
+    // 7. Return possibleEpochNsAfter[0].
+    // NOTE: The first local time after the transition corresponds to the
+    // instant of the transition, which lies between the instants of midnight
+    // with the offsets after and before the transition.
+    let local_seconds = (iso_date_time.to_epoch_nanoseconds() / NS_PER_SECOND) as i64;
+    let offset_before = rules.offset_at(rules.instant_before_transition(local_seconds));
+    let offset_after = rules.offset_at(local_seconds + 86_400);
+    let mut low = local_seconds - i64::from(offset_after);
+    let mut high = local_seconds - i64::from(offset_before);
+    // Find the first instant with the offset after the transition.
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if rules.offset_at(middle) == offset_after {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Ok(i128::from(high) * NS_PER_SECOND)
+}
+
+/// ### [11.1.15 CheckISODaysRange ( isoDate )](https://tc39.es/proposal-temporal/#sec-temporal-checkisodaysrange)
+pub(crate) fn check_iso_days_range(iso_date: IsoDate) -> TemporalResult<()> {
+    // 1. If abs(ISODateToEpochDays(isoDate.[[Year]], isoDate.[[Month]] - 1,
+    //    isoDate.[[Day]])) > 10**8, then
+    if iso_date.to_epoch_days().abs() > 100_000_000 {
+        // a. Throw a RangeError exception.
+        return Err(RangeError("date is out of range"));
+    }
+    // 2. Return unused.
+    Ok(())
+}
+
+/// The offsetBehaviour argument of InterpretISODateTimeOffset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetBehaviour {
+    Option,
+    Exact,
+    Wall,
+}
+
+/// ### [6.5.2 InterpretISODateTimeOffset ( isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour )](https://tc39.es/proposal-temporal/#sec-temporal-interpretisodatetimeoffset)
+///
+/// A time of None means start-of-day. The matchBehaviour is match-minutes if
+/// match_minutes is true, and match-exactly otherwise.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn interpret_iso_date_time_offset(
+    iso_date: IsoDate,
+    time: Option<TimeRecord>,
+    offset_behaviour: OffsetBehaviour,
+    offset_nanoseconds: i64,
+    time_zone: &TimeZone,
+    disambiguation: Disambiguation,
+    offset_option: OffsetOption,
+    match_minutes: bool,
+) -> TemporalResult<i128> {
+    // 1. If time is start-of-day, then
+    let Some(time) = time else {
+        // a. Assert: offsetBehaviour is wall.
+        // b. Assert: offsetNanoseconds is 0.
+        // c. Return ? GetStartOfDay(timeZone, isoDate).
+        return get_start_of_day(time_zone, iso_date);
+    };
+    // 2. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+    let iso_date_time = IsoDateTime::new(iso_date, time);
+    // 3. If offsetBehaviour is wall, or offsetBehaviour is option and
+    //    offsetOption is ignore, then
+    if offset_behaviour == OffsetBehaviour::Wall
+        || (offset_behaviour == OffsetBehaviour::Option && offset_option == OffsetOption::Ignore)
+    {
+        // a. Return ? GetEpochNanosecondsFor(timeZone, isoDateTime,
+        //    disambiguation).
+        return get_epoch_nanoseconds_for(time_zone, iso_date_time, disambiguation);
+    }
+    // 4. If offsetBehaviour is exact, or offsetBehaviour is option and
+    //    offsetOption is use, then
+    if offset_behaviour == OffsetBehaviour::Exact
+        || (offset_behaviour == OffsetBehaviour::Option && offset_option == OffsetOption::Use)
+    {
+        // a. Let balanced be BalanceISODateTime(isoDate.[[Year]],
+        //    isoDate.[[Month]], isoDate.[[Day]], time.[[Hour]],
+        //    time.[[Minute]], time.[[Second]], time.[[Millisecond]],
+        //    time.[[Microsecond]], time.[[Nanosecond]] - offsetNanoseconds).
+        let balanced =
+            add_nanoseconds_to_iso_date_time(iso_date_time, -i128::from(offset_nanoseconds));
+        // b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+        check_iso_days_range(balanced.date)?;
+        // c. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+        let epoch_nanoseconds = balanced.to_epoch_nanoseconds();
+        // d. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+        //    RangeError exception.
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return Err(RangeError("date-time is out of range"));
+        }
+        // e. Return epochNanoseconds.
+        return Ok(epoch_nanoseconds);
+    }
+    // 5. Assert: offsetBehaviour is option.
+    // 6. Assert: offsetOption is prefer or reject.
+    // 7. Perform ? CheckISODaysRange(isoDate).
+    check_iso_days_range(iso_date)?;
+    // 8. Let utcEpochNanoseconds be GetUTCEpochNanoseconds(isoDateTime).
+    let utc_epoch_nanoseconds = iso_date_time.to_epoch_nanoseconds();
+    // 9. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone,
+    //    isoDateTime).
+    let possible_epoch_ns = get_possible_epoch_nanoseconds(time_zone, iso_date_time)?;
+    // 10. For each element candidate of possibleEpochNs, do
+    for candidate in possible_epoch_ns.iter().copied() {
+        // a. Let candidateOffset be utcEpochNanoseconds - candidate.
+        let candidate_offset = utc_epoch_nanoseconds - candidate;
+        // b. If candidateOffset = offsetNanoseconds, then
+        if candidate_offset == i128::from(offset_nanoseconds) {
+            // i. Return candidate.
+            return Ok(candidate);
+        }
+        // c. If matchBehaviour is match-minutes, then
+        if match_minutes {
+            // i. Let roundedCandidateNanoseconds be RoundNumberToIncrement(
+            //    candidateOffset, 60 × 10**9, half-expand).
+            let rounded_candidate_nanoseconds = round_number_to_increment(
+                candidate_offset,
+                60 * NS_PER_SECOND,
+                RoundingMode::HalfExpand,
+            );
+            // ii. If roundedCandidateNanoseconds = offsetNanoseconds, then
+            if rounded_candidate_nanoseconds == i128::from(offset_nanoseconds) {
+                // 1. Return candidate.
+                return Ok(candidate);
+            }
+        }
+    }
+    // 11. If offsetOption is reject, throw a RangeError exception.
+    if offset_option == OffsetOption::Reject {
+        return Err(RangeError("the offset is not valid for the time zone"));
+    }
+    // 12. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs,
+    //     timeZone, isoDateTime, disambiguation).
+    disambiguate_possible_epoch_nanoseconds(
+        &possible_epoch_ns,
+        time_zone,
+        iso_date_time,
+        disambiguation,
+    )
+}
@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod abstract_operations;
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::ZonedDateTimeIndex,
+    },
+};
+
+use self::data::ZonedDateTimeHeapData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ZonedDateTime<'a>(pub(crate) ZonedDateTimeIndex<'a>);
+
+impl ZonedDateTime<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, ZonedDateTime<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(ZonedDateTimeIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ZonedDateTime<'_> {
+    type Of<'a> = ZonedDateTime<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for ZonedDateTime<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<ZonedDateTime<'a>> for Value<'a> {
+    fn from(value: ZonedDateTime<'a>) -> Self {
+        Value::ZonedDateTime(value)
+    }
+}
+
+impl<'a> IntoObject<'a> for ZonedDateTime<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<ZonedDateTime<'a>> for Object<'a> {
+    fn from(value: ZonedDateTime) -> Self {
+        Object::ZonedDateTime(value.unbind())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for ZonedDateTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, ()> {
+        match value {
+            Value::ZonedDateTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for ZonedDateTime<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::ZonedDateTime(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for ZonedDateTime<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalZonedDateTime;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for ZonedDateTime<'a> {}
+
+impl Index<ZonedDateTime<'_>> for Agent {
+    type Output = ZonedDateTimeHeapData<'static>;
+
+    fn index(&self, index: ZonedDateTime) -> &Self::Output {
+        &self.heap.zoned_date_times[index]
+    }
+}
+
+impl IndexMut<ZonedDateTime<'_>> for Agent {
+    fn index_mut(&mut self, index: ZonedDateTime) -> &mut Self::Output {
+        &mut self.heap.zoned_date_times[index]
+    }
+}
+
+impl Index<ZonedDateTime<'_>> for Vec<Option<ZonedDateTimeHeapData<'static>>> {
+    type Output = ZonedDateTimeHeapData<'static>;
+
+    fn index(&self, index: ZonedDateTime) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("ZonedDateTime out of bounds")
+            .as_ref()
+            .expect("ZonedDateTime slot empty")
+    }
+}
+
+impl IndexMut<ZonedDateTime<'_>> for Vec<Option<ZonedDateTimeHeapData<'static>>> {
+    fn index_mut(&mut self, index: ZonedDateTime) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("ZonedDateTime out of bounds")
+            .as_mut()
+            .expect("ZonedDateTime slot empty")
+    }
+}
+
+impl Rootable for ZonedDateTime<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::ZonedDateTime(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::ZonedDateTime(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ZonedDateTime<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.zoned_date_times.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.zoned_date_times.shift_index(&mut self.0);
+    }
+}
+
+impl<'a> CreateHeapData<ZonedDateTimeHeapData<'a>, ZonedDateTime<'a>> for Heap {
+    fn create(&mut self, data: ZonedDateTimeHeapData<'a>) -> ZonedDateTime<'a> {
+        self.zoned_date_times.push(Some(data.unbind()));
+        #[cfg(feature = "interleaved-gc")]
+        {
+            self.alloc_counter += core::mem::size_of::<Option<ZonedDateTimeHeapData<'static>>>();
+        }
+        ZonedDateTime(ZonedDateTimeIndex::last(&self.zoned_date_times))
+    }
+}
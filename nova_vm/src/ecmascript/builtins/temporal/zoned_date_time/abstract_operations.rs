@@ -0,0 +1,798 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [6.5 Abstract Operations](https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            ordinary::ordinary_create_from_constructor,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Disambiguation, OffsetOption,
+                    Overflow, Precision, RangeError, RoundingMode, ShowCalendar, ShowOffset,
+                    ShowTimeZone, TemporalResult, Unit, UnitGroup, format_calendar_annotation,
+                    format_date_time_utc_offset_rounded, get_difference_settings,
+                    get_options_object, get_temporal_disambiguation_option,
+                    get_temporal_offset_option, get_temporal_overflow_option,
+                    is_valid_epoch_nanoseconds, larger_of_two_temporal_units,
+                },
+                calendar::{
+                    Field, ISO8601, IsoDate, IsoDateTime, RequiredFields, TimeRecord,
+                    balance_iso_date, calendar_date_add, calendar_date_until,
+                    canonicalize_calendar, difference_time,
+                    get_temporal_calendar_identifier_with_iso_default, iso_date_time_within_limits,
+                    prepare_calendar_fields,
+                },
+                duration::{
+                    Duration,
+                    abstract_operations::{
+                        DurationRecord, InternalDuration, create_duration_object,
+                        round_relative_duration, temporal_duration_from_internal,
+                        to_internal_duration_record, to_temporal_duration, total_relative_duration,
+                        total_time_duration,
+                    },
+                },
+                instant::abstract_operations::{
+                    add_instant, difference_instant, round_temporal_instant,
+                },
+                parsing::parse_temporal_zoned_date_time_string,
+                plain_date_time::abstract_operations::{
+                    interpret_temporal_date_time_fields, iso_date_time_to_string,
+                },
+                time_zone::{
+                    OffsetBehaviour, TimeZone, get_epoch_nanoseconds_for, get_iso_date_time_for,
+                    get_offset_nanoseconds_for, interpret_iso_date_time_offset, time_zone_equals,
+                    to_time_zone,
+                },
+                zoned_date_time::{ZonedDateTime, data::ZonedDateTimeHeapData},
+            },
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{Function, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+/// ### [6.5.3 CreateTemporalZonedDateTime ( epochNanoseconds, timeZone, calendar \[ , newTarget \] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalzoneddatetime)
+pub(crate) fn create_temporal_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    epoch_nanoseconds: i128,
+    time_zone: TimeZone,
+    new_target: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ZonedDateTime<'gc>> {
+    // 1. Assert: IsValidEpochNanoseconds(epochNanoseconds) is true.
+    debug_assert!(is_valid_epoch_nanoseconds(epoch_nanoseconds));
+    // 2. If newTarget is not present, set newTarget to
+    //    %Temporal.ZonedDateTime%.
+    let Some(new_target) = new_target else {
+        return Ok(create_zoned_date_time_object(
+            agent,
+            epoch_nanoseconds,
+            time_zone,
+            gc.into_nogc(),
+        ));
+    };
+    // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget,
+    //    "%Temporal.ZonedDateTime.prototype%",
+    //    « [[InitializedTemporalZonedDateTime]], [[EpochNanoseconds]],
+    //    [[TimeZone]], [[Calendar]] »).
+    let object = ordinary_create_from_constructor(
+        agent,
+        new_target.unbind(),
+        ProtoIntrinsics::TemporalZonedDateTime,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let object = ZonedDateTime::try_from(object.bind(gc)).unwrap();
+    // 4. Set object.[[EpochNanoseconds]] to epochNanoseconds.
+    // 5. Set object.[[TimeZone]] to timeZone.
+    // 6. Set object.[[Calendar]] to calendar.
+    let data = &mut agent[object];
+    data.epoch_nanoseconds = epoch_nanoseconds;
+    data.time_zone = time_zone;
+    // 7. Return object.
+    Ok(object)
+}
+
+/// CreateTemporalZonedDateTime without a newTarget.
+pub(crate) fn create_zoned_date_time_object<'gc>(
+    agent: &mut Agent,
+    epoch_nanoseconds: i128,
+    time_zone: TimeZone,
+    gc: NoGcScope<'gc, '_>,
+) -> ZonedDateTime<'gc> {
+    debug_assert!(is_valid_epoch_nanoseconds(epoch_nanoseconds));
+    agent
+        .heap
+        .create(ZonedDateTimeHeapData {
+            object_index: None,
+            epoch_nanoseconds,
+            time_zone,
+        })
+        .bind(gc)
+}
+
+trivially_bindable!((i128, TimeZone));
+trivially_bindable!((Disambiguation, OffsetOption, Overflow));
+
+/// Read the disambiguation, offset and overflow options of
+/// ToTemporalZonedDateTime, in that order, with an offset fallback of
+/// reject.
+fn get_zoned_date_time_options<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (Disambiguation, OffsetOption, Overflow)> {
+    // Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // Let disambiguation be ? GetTemporalDisambiguationOption(
+    // resolvedOptions).
+    let disambiguation =
+        get_temporal_disambiguation_option(agent, resolved_options.get(agent), gc.reborrow())
+            .unbind()?;
+    // Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+    let offset_option = get_temporal_offset_option(
+        agent,
+        resolved_options.get(agent),
+        OffsetOption::Reject,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow = get_temporal_overflow_option(agent, resolved_options.get(agent), gc)?;
+    Ok((disambiguation, offset_option, overflow))
+}
+
+/// ### [6.5.2 ToTemporalZonedDateTime ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime)
+///
+/// Returns the epoch nanoseconds and the time zone of the
+/// Temporal.ZonedDateTime.
+pub(crate) fn to_temporal_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (i128, TimeZone)> {
+    let item = item.bind(gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    let options = options.scope(agent, gc.nogc());
+    // 2. Let offsetBehaviour be option.
+    let mut offset_behaviour = OffsetBehaviour::Option;
+    // 3. Let matchBehaviour be match-exactly.
+    let mut match_minutes = false;
+    // 4. If item is an Object, then
+    let (iso_date, time, time_zone, offset_nanoseconds, options, gc) = if let Ok(item) =
+        Object::try_from(item)
+    {
+        // a. If item has an [[InitializedTemporalZonedDateTime]] internal
+        //    slot, then
+        if let Object::ZonedDateTime(zoned_date_time) = item {
+            let data = &agent[zoned_date_time];
+            let result = (data.epoch_nanoseconds, data.time_zone.clone());
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalDisambiguationOption(
+            //     resolvedOptions).
+            // iii. Perform ? GetTemporalOffsetOption(resolvedOptions,
+            //      reject).
+            // iv. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_zoned_date_time_options(agent, options.get(agent), gc)?;
+            // v. Return ! CreateTemporalZonedDateTime(
+            //    item.[[EpochNanoseconds]], item.[[TimeZone]],
+            //    item.[[Calendar]]).
+            return Ok(result);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(
+        //    item).
+        get_temporal_calendar_identifier_with_iso_default(agent, item.get(agent), gc.reborrow())
+            .unbind()?;
+        // c. Let fields be ? PrepareCalendarFields(calendar, item, « year,
+        //    month, month-code, day », « hour, minute, second,
+        //    millisecond, microsecond, nanosecond, offset, time-zone »,
+        //    « time-zone »).
+        let fields = prepare_calendar_fields(
+            agent,
+            item.get(agent),
+            &[Field::DATE, Field::TIME, &[Field::Offset, Field::TimeZone]],
+            RequiredFields::List(&[Field::TimeZone]),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // d. Let timeZone be fields.[[TimeZone]].
+        // e. Let offsetString be fields.[[OffsetString]].
+        // f. If offsetString is unset, then
+        if fields.offset.is_none() {
+            // i. Set offsetBehaviour to wall.
+            offset_behaviour = OffsetBehaviour::Wall;
+        }
+        // g. Let resolvedOptions be ? GetOptionsObject(options).
+        // h. Let disambiguation be ? GetTemporalDisambiguationOption(
+        //    resolvedOptions).
+        // i. Let offsetOption be ? GetTemporalOffsetOption(
+        //    resolvedOptions, reject).
+        // j. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options =
+            get_zoned_date_time_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // k. Let result be ? InterpretTemporalDateTimeFields(calendar,
+        //    fields, overflow).
+        let result = interpret_temporal_date_time_fields(&fields, options.2)
+            .map_err(|err| err.throw(agent, gc))?;
+        // l. Let isoDate be result.[[ISODate]].
+        // m. Let time be result.[[Time]].
+        (
+            result.date,
+            Some(result.time),
+            fields.time_zone.unwrap(),
+            fields.offset.unwrap_or(0),
+            options,
+            gc,
+        )
+    } else {
+        // 5. Else,
+        //    a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "zoned date-time must be a Temporal.ZonedDateTime, an object or a string",
+                gc.into_nogc(),
+            ));
+        };
+        //    b. Let result be ? ParseISODateTime(item,
+        //       « TemporalDateTimeString[+Zoned] »).
+        let result = parse_temporal_zoned_date_time_string(&item.as_wtf8(agent).to_string_lossy());
+        let result = result.map_err(|err| err.throw(agent, gc.nogc())).unbind()?;
+        //    c. Let annotation be
+        //       result.[[TimeZone]].[[TimeZoneAnnotation]].
+        //    d. Assert: annotation is not empty.
+        //    e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+        let time_zone = to_time_zone(result.time_zone.clone().unwrap())
+            .map_err(|err| err.throw(agent, gc.nogc()))
+            .unbind()?;
+        //    f. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+        //    g. If result.[[TimeZone]].[[Z]] is true, then
+        if result.z {
+            // i. Set offsetBehaviour to exact.
+            offset_behaviour = OffsetBehaviour::Exact;
+        } else if result.offset.is_none() {
+            // h. Else if offsetString is empty, then
+            //    i. Set offsetBehaviour to wall.
+            offset_behaviour = OffsetBehaviour::Wall;
+        }
+        //    i. Let calendar be result.[[Calendar]].
+        //    j. If calendar is empty, set calendar to "iso8601".
+        //    k. Set calendar to ? CanonicalizeCalendar(calendar).
+        canonicalize_calendar(result.calendar.as_deref().unwrap_or(ISO8601))
+            .map_err(|err| err.throw(agent, gc.nogc()))
+            .unbind()?;
+        //    l. Set matchBehaviour to match-minutes.
+        //    m. If offsetString is not empty, then
+        //       i. Let offsetParseResult be ParseText(
+        //          StringToCodePoints(offsetString),
+        //          UTCOffset[+SubMinutePrecision]).
+        //       ii. Assert: offsetParseResult is a Parse Node.
+        //       iii. If offsetParseResult contains more than one
+        //            MinuteSecond Parse Node, set matchBehaviour to
+        //            match-exactly.
+        match_minutes = !result
+            .offset
+            .is_some_and(|offset| offset.has_sub_minute_precision);
+        //    n. Let resolvedOptions be ? GetOptionsObject(options).
+        //    o. Let disambiguation be ? GetTemporalDisambiguationOption(
+        //       resolvedOptions).
+        //    p. Let offsetOption be ? GetTemporalOffsetOption(
+        //       resolvedOptions, reject).
+        //    q. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        let options =
+            get_zoned_date_time_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+        //    r. Let isoDate be CreateISODateRecord(result.[[Year]],
+        //       result.[[Month]], result.[[Day]]).
+        //    s. Let time be result.[[Time]].
+        (
+            result.date,
+            result.time,
+            time_zone,
+            result.offset.map_or(0, |offset| offset.nanoseconds),
+            options,
+            gc.into_nogc(),
+        )
+    };
+    let (disambiguation, offset_option, _) = options;
+    // 6. If offsetBehaviour is option, then
+    //    a. Let offsetNanoseconds be ! ParseDateTimeUTCOffset(offsetString).
+    // 7. Else,
+    //    a. Let offsetNanoseconds be 0.
+    let offset_nanoseconds = if offset_behaviour == OffsetBehaviour::Option {
+        offset_nanoseconds
+    } else {
+        0
+    };
+    // 8. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time,
+    //    offsetBehaviour, offsetNanoseconds, timeZone, disambiguation,
+    //    offsetOption, matchBehaviour).
+    let epoch_nanoseconds = interpret_iso_date_time_offset(
+        iso_date,
+        time,
+        offset_behaviour,
+        offset_nanoseconds,
+        &time_zone,
+        disambiguation,
+        offset_option,
+        match_minutes,
+    )
+    .map_err(|err| err.throw(agent, gc))?;
+    // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+    //    calendar).
+    Ok((epoch_nanoseconds, time_zone))
+}
+
+/// ### [6.5.4 TemporalZonedDateTimeToString ( zonedDateTime, precision, showCalendar, showTimeZone, showOffset \[ , increment, unit, roundingMode \] )](https://tc39.es/proposal-temporal/#sec-temporal-temporalzoneddatetimetostring)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn temporal_zoned_date_time_to_string(
+    epoch_nanoseconds: i128,
+    time_zone: &TimeZone,
+    precision: Precision,
+    show_calendar: ShowCalendar,
+    show_time_zone: ShowTimeZone,
+    show_offset: ShowOffset,
+    increment: u32,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> std::string::String {
+    // 1. If increment is not present, set increment to 1.
+    // 2. If unit is not present, set unit to nanosecond.
+    // 3. If roundingMode is not present, set roundingMode to trunc.
+    // 4. Let epochNs be RoundTemporalInstant(
+    //    zonedDateTime.[[EpochNanoseconds]], increment, unit, roundingMode).
+    let epoch_ns = round_temporal_instant(epoch_nanoseconds, increment, unit, rounding_mode);
+    // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+    // 6. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+    let offset_nanoseconds = get_offset_nanoseconds_for(time_zone, epoch_ns);
+    // 7. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+    let iso_date_time = get_iso_date_time_for(time_zone, epoch_ns);
+    // 8. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601",
+    //    precision, never).
+    let date_time_string =
+        iso_date_time_to_string(iso_date_time, ISO8601, precision, ShowCalendar::Never);
+    // 9. If showOffset is never, then
+    //    a. Let offsetString be the empty String.
+    // 10. Else,
+    //     a. Let offsetString be FormatDateTimeUTCOffsetRounded(
+    //        offsetNanoseconds).
+    let offset_string = match show_offset {
+        ShowOffset::Never => std::string::String::new(),
+        ShowOffset::Auto => format_date_time_utc_offset_rounded(offset_nanoseconds),
+    };
+    // 11. If showTimeZone is never, then
+    //     a. Let timeZoneString be the empty String.
+    // 12. Else,
+    //     a. Let timeZoneIdentifier be timeZone.
+    //     b. If showTimeZone is critical, let flag be "!"; else let flag be
+    //        the empty String.
+    //     c. Let timeZoneString be the string-concatenation of the code unit
+    //        0x005B (LEFT SQUARE BRACKET), flag, timeZoneIdentifier, and the
+    //        code unit 0x005D (RIGHT SQUARE BRACKET).
+    let time_zone_string = match show_time_zone {
+        ShowTimeZone::Never => std::string::String::new(),
+        ShowTimeZone::Auto => format!("[{}]", time_zone.identifier()),
+        ShowTimeZone::Critical => format!("[!{}]", time_zone.identifier()),
+    };
+    // 13. Let calendarString be FormatCalendarAnnotation(
+    //     zonedDateTime.[[Calendar]], showCalendar).
+    let calendar_string = format_calendar_annotation(ISO8601, show_calendar);
+    // 14. Return the string-concatenation of dateTimeString, offsetString,
+    //     timeZoneString, and calendarString.
+    date_time_string + &offset_string + &time_zone_string + &calendar_string
+}
+
+/// ### [6.5.5 AddZonedDateTime ( epochNanoseconds, timeZone, calendar, duration, overflow )](https://tc39.es/proposal-temporal/#sec-temporal-addzoneddatetime)
+pub(crate) fn add_zoned_date_time(
+    epoch_nanoseconds: i128,
+    time_zone: &TimeZone,
+    duration: InternalDuration,
+    overflow: Overflow,
+) -> TemporalResult<i128> {
+    // 1. If DateDurationSign(duration.[[Date]]) = 0, then
+    if duration.date.sign() == 0 {
+        // a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
+        return add_instant(epoch_nanoseconds, duration.time);
+    }
+    // 2. Let isoDateTime be GetISODateTimeFor(timeZone, epochNanoseconds).
+    let iso_date_time = get_iso_date_time_for(time_zone, epoch_nanoseconds);
+    // 3. Let addedDate be ? CalendarDateAdd(calendar,
+    //    isoDateTime.[[ISODate]], duration.[[Date]], overflow).
+    let added_date = calendar_date_add(iso_date_time.date, duration.date, overflow)?;
+    // 4. Let intermediateDateTime be CombineISODateAndTimeRecord(addedDate,
+    //    isoDateTime.[[Time]]).
+    let intermediate_date_time = IsoDateTime::new(added_date, iso_date_time.time);
+    // 5. If ISODateTimeWithinLimits(intermediateDateTime) is false, throw a
+    //    RangeError exception.
+    if !iso_date_time_within_limits(intermediate_date_time) {
+        return Err(RangeError("date-time is out of range"));
+    }
+    // 6. Let intermediateNs be ! GetEpochNanosecondsFor(timeZone,
+    //    intermediateDateTime, compatible).
+    let intermediate_ns = get_epoch_nanoseconds_for(
+        time_zone,
+        intermediate_date_time,
+        Disambiguation::Compatible,
+    )?;
+    // 7. Return ? AddInstant(intermediateNs, duration.[[Time]]).
+    add_instant(intermediate_ns, duration.time)
+}
+
+/// ### [6.5.6 DifferenceZonedDateTime ( ns1, ns2, timeZone, calendar, largestUnit )](https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetime)
+pub(crate) fn difference_zoned_date_time(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZone,
+    largest_unit: Unit,
+) -> TemporalResult<InternalDuration> {
+    // 1. If ns1 = ns2, return CombineDateAndTimeDuration(ZeroDateDuration(),
+    //    0).
+    if ns1 == ns2 {
+        return Ok(InternalDuration::default());
+    }
+    // 2. Let startDateTime be GetISODateTimeFor(timeZone, ns1).
+    let start_date_time = get_iso_date_time_for(time_zone, ns1);
+    // 3. Let endDateTime be GetISODateTimeFor(timeZone, ns2).
+    let end_date_time = get_iso_date_time_for(time_zone, ns2);
+    // 4. If CompareISODate(startDateTime.[[ISODate]],
+    //    endDateTime.[[ISODate]]) = 0, then
+    if start_date_time.date == end_date_time.date {
+        // a. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(
+        //    ns2, ns1).
+        // b. Return CombineDateAndTimeDuration(ZeroDateDuration(),
+        //    timeDuration).
+        return Ok(InternalDuration::new(Default::default(), ns2 - ns1));
+    }
+    // 5. If ns2 - ns1 < 0, let sign be -1; else let sign be 1.
+    let sign: i64 = if ns2 - ns1 < 0 { -1 } else { 1 };
+    // 6. If sign = 1, let maxDayCorrection be 2; else let maxDayCorrection be
+    //    1.
+    let max_day_correction = if sign == 1 { 2 } else { 1 };
+    // 7. Let dayCorrection be 0.
+    let mut day_correction = 0;
+    // 8. Let timeDuration be DifferenceTime(startDateTime.[[Time]],
+    //    endDateTime.[[Time]]).
+    let mut time_duration = difference_time(start_date_time.time, end_date_time.time);
+    // 9. If TimeDurationSign(timeDuration) = -sign, set dayCorrection to
+    //    dayCorrection + 1.
+    if time_duration.signum() as i64 == -sign {
+        day_correction += 1;
+    }
+    // 10. Let success be false.
+    let mut success = false;
+    let mut intermediate_date = end_date_time.date;
+    // 11. Repeat, while dayCorrection ≤ maxDayCorrection and success is
+    //     false,
+    while day_correction <= max_day_correction && !success {
+        // a. Let intermediateDate be BalanceISODate(
+        //    endDateTime.[[ISODate]].[[Year]],
+        //    endDateTime.[[ISODate]].[[Month]],
+        //    endDateTime.[[ISODate]].[[Day]] - dayCorrection × sign).
+        intermediate_date = balance_iso_date(
+            i64::from(end_date_time.date.year),
+            end_date_time.date.month,
+            i64::from(end_date_time.date.day) - day_correction * sign,
+        );
+        // b. Let intermediateDateTime be CombineISODateAndTimeRecord(
+        //    intermediateDate, startDateTime.[[Time]]).
+        let intermediate_date_time = IsoDateTime::new(intermediate_date, start_date_time.time);
+        // c. Let intermediateNs be ? GetEpochNanosecondsFor(timeZone,
+        //    intermediateDateTime, compatible).
+        let intermediate_ns = get_epoch_nanoseconds_for(
+            time_zone,
+            intermediate_date_time,
+            Disambiguation::Compatible,
+        )?;
+        // d. Set timeDuration to TimeDurationFromEpochNanosecondsDifference(
+        //    ns2, intermediateNs).
+        time_duration = ns2 - intermediate_ns;
+        // e. Let timeSign be TimeDurationSign(timeDuration).
+        let time_sign = time_duration.signum() as i64;
+        // f. If sign ≠ -timeSign, then
+        if sign != -time_sign {
+            // i. Set success to true.
+            success = true;
+        }
+        // g. Set dayCorrection to dayCorrection + 1.
+        day_correction += 1;
+    }
+    // 12. Assert: success is true.
+    debug_assert!(success);
+    // 13. Let dateLargestUnit be LargerOfTwoTemporalUnits(largestUnit, day).
+    let date_largest_unit = larger_of_two_temporal_units(largest_unit, Unit::Day);
+    // 14. Let dateDifference be CalendarDateUntil(calendar,
+    //     startDateTime.[[ISODate]], intermediateDate, dateLargestUnit).
+    let date_difference =
+        calendar_date_until(start_date_time.date, intermediate_date, date_largest_unit);
+    // 15. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+    Ok(InternalDuration::new(date_difference, time_duration))
+}
+
+/// ### [6.5.7 DifferenceZonedDateTimeWithRounding ( ns1, ns2, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )](https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithrounding)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn difference_zoned_date_time_with_rounding(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZone,
+    largest_unit: Unit,
+    rounding_increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<InternalDuration> {
+    // 1. If TemporalUnitCategory(largestUnit) is time, then
+    if !largest_unit.is_date_unit() {
+        // a. Return DifferenceInstant(ns1, ns2, roundingIncrement,
+        //    smallestUnit, roundingMode).
+        return difference_instant(ns1, ns2, rounding_increment, smallest_unit, rounding_mode);
+    }
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone,
+    //    calendar, largestUnit).
+    let difference = difference_zoned_date_time(ns1, ns2, time_zone, largest_unit)?;
+    // 3. If smallestUnit is nanosecond and roundingIncrement = 1, return
+    //    difference.
+    if smallest_unit == Unit::Nanosecond && rounding_increment == 1 {
+        return Ok(difference);
+    }
+    // 4. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    let date_time = get_iso_date_time_for(time_zone, ns1);
+    // 5. Return ? RoundRelativeDuration(difference, ns2, dateTime, timeZone,
+    //    calendar, largestUnit, roundingIncrement, smallestUnit,
+    //    roundingMode).
+    round_relative_duration(
+        difference,
+        ns2,
+        date_time,
+        Some(time_zone),
+        largest_unit,
+        rounding_increment,
+        smallest_unit,
+        rounding_mode,
+    )
+}
+
+/// ### [6.5.8 DifferenceZonedDateTimeWithTotal ( ns1, ns2, timeZone, calendar, unit )](https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithtotal)
+pub(crate) fn difference_zoned_date_time_with_total(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZone,
+    unit: Unit,
+) -> TemporalResult<f64> {
+    // 1. If TemporalUnitCategory(unit) is time, then
+    if !unit.is_date_unit() {
+        // a. Let difference be TimeDurationFromEpochNanosecondsDifference(
+        //    ns2, ns1).
+        // b. Return TotalTimeDuration(difference, unit).
+        return Ok(total_time_duration(ns2 - ns1, unit));
+    }
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone,
+    //    calendar, unit).
+    let difference = difference_zoned_date_time(ns1, ns2, time_zone, unit)?;
+    // 3. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    let date_time = get_iso_date_time_for(time_zone, ns1);
+    // 4. Return ? TotalRelativeDuration(difference, ns2, dateTime, timeZone,
+    //    calendar, unit).
+    total_relative_duration(difference, ns2, date_time, Some(time_zone), unit)
+}
+
+/// ### [6.5.9 DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime)
+pub(crate) fn difference_temporal_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    operation: DifferenceOperation,
+    zoned_date_time: ZonedDateTime,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Duration<'gc>> {
+    let data = &agent[zoned_date_time];
+    let ns1 = data.epoch_nanoseconds;
+    let time_zone = data.time_zone.clone();
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalZonedDateTime(other).
+    let (ns2, other_time_zone) =
+        to_temporal_zoned_date_time(agent, other, Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. If CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is
+    //    false, then
+    //    a. Throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    datetime, « », nanosecond, hour).
+    let settings = get_difference_settings(
+        agent,
+        operation,
+        resolved_options,
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Hour,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let result = difference_zoned_date_time_settings(
+        ns1,
+        ns2,
+        &time_zone,
+        &other_time_zone,
+        settings.largest_unit,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )
+    .map_err(|err| err.throw(agent, gc))?;
+    // 11. If operation is since, set result to
+    //     CreateNegatedTemporalDuration(result).
+    let result = match operation {
+        DifferenceOperation::Since => result.negated(),
+        DifferenceOperation::Until => result,
+    };
+    // 12. Return result.
+    Ok(create_duration_object(agent, result, gc))
+}
+
+/// Steps 5 to 10 of DifferenceTemporalZonedDateTime.
+#[allow(clippy::too_many_arguments)]
+fn difference_zoned_date_time_settings(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZone,
+    other_time_zone: &TimeZone,
+    largest_unit: Unit,
+    rounding_increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<DurationRecord> {
+    // 5. If TemporalUnitCategory(settings.[[LargestUnit]]) is time, then
+    if !largest_unit.is_date_unit() {
+        // a. Let internalDuration be DifferenceInstant(
+        //    zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]],
+        //    settings.[[RoundingIncrement]], settings.[[SmallestUnit]],
+        //    settings.[[RoundingMode]]).
+        let internal_duration =
+            difference_instant(ns1, ns2, rounding_increment, smallest_unit, rounding_mode)?;
+        // b. Let result be ! TemporalDurationFromInternal(internalDuration,
+        //    settings.[[LargestUnit]]).
+        return temporal_duration_from_internal(internal_duration, largest_unit);
+    }
+    // 6. NOTE: To calculate differences in two different time zones,
+    //    settings.[[LargestUnit]] must be a time unit, because day lengths
+    //    can vary between time zones due to DST and other UTC offset shifts.
+    // 7. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is
+    //    false, then
+    if !time_zone_equals(time_zone, other_time_zone) {
+        // a. Throw a RangeError exception.
+        return Err(RangeError(
+            "cannot compute a difference in days or larger units between different time zones",
+        ));
+    }
+    // 8. If zonedDateTime.[[EpochNanoseconds]] =
+    //    other.[[EpochNanoseconds]], then
+    if ns1 == ns2 {
+        // a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        return Ok(DurationRecord::default());
+    }
+    // 9. Let internalDuration be ? DifferenceZonedDateTimeWithRounding(
+    //    zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]],
+    //    zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]],
+    //    settings.[[LargestUnit]], settings.[[RoundingIncrement]],
+    //    settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+    let internal_duration = difference_zoned_date_time_with_rounding(
+        ns1,
+        ns2,
+        time_zone,
+        largest_unit,
+        rounding_increment,
+        smallest_unit,
+        rounding_mode,
+    )?;
+    // 10. Let result be ! TemporalDurationFromInternal(internalDuration,
+    //     hour).
+    temporal_duration_from_internal(internal_duration, Unit::Hour)
+}
+
+/// ### [6.5.10 AddDurationToZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime)
+pub(crate) fn add_duration_to_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    operation: ArithmeticOperation,
+    zoned_date_time: ZonedDateTime,
+    temporal_duration_like: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ZonedDateTime<'gc>> {
+    let data = &agent[zoned_date_time];
+    let epoch_nanoseconds = data.epoch_nanoseconds;
+    // 5. Let calendar be zonedDateTime.[[Calendar]].
+    // 6. Let timeZone be zonedDateTime.[[TimeZone]].
+    let time_zone = data.time_zone.clone();
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, temporal_duration_like, gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to
+    //    CreateNegatedTemporalDuration(duration).
+    let duration = match operation {
+        ArithmeticOperation::Subtract => duration.negated(),
+        ArithmeticOperation::Add => duration,
+    };
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow = get_temporal_overflow_option(agent, resolved_options, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 7. Let internalDuration be ToInternalDurationRecord(duration).
+    let internal_duration = to_internal_duration_record(&duration);
+    // 8. Let epochNanoseconds be ? AddZonedDateTime(
+    //    zonedDateTime.[[EpochNanoseconds]], timeZone, calendar,
+    //    internalDuration, overflow).
+    let epoch_nanoseconds =
+        add_zoned_date_time(epoch_nanoseconds, &time_zone, internal_duration, overflow)
+            .map_err(|err| err.throw(agent, gc))?;
+    // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+    //    calendar).
+    Ok(create_zoned_date_time_object(
+        agent,
+        epoch_nanoseconds,
+        time_zone,
+        gc,
+    ))
+}
+
+/// The wall-clock date and time of a Temporal.ZonedDateTime.
+pub(crate) fn zoned_date_time_iso_date_time(
+    agent: &Agent,
+    zoned_date_time: ZonedDateTime,
+) -> IsoDateTime {
+    let data = &agent[zoned_date_time];
+    get_iso_date_time_for(&data.time_zone, data.epoch_nanoseconds)
+}
+
+/// The epoch nanoseconds of a wall-clock date and a time, or the start of
+/// the day if the time is None, in a time zone, as
+/// Temporal.PlainDate.prototype.toZonedDateTime and
+/// Temporal.ZonedDateTime.prototype.withPlainTime compute them.
+pub(crate) fn epoch_nanoseconds_for_date_and_time(
+    time_zone: &TimeZone,
+    iso_date: IsoDate,
+    time: Option<TimeRecord>,
+) -> TemporalResult<i128> {
+    match time {
+        // If temporalTime is undefined, then
+        //   Let epochNs be ? GetStartOfDay(timeZone, isoDate).
+        None => interpret_iso_date_time_offset(
+            iso_date,
+            None,
+            OffsetBehaviour::Wall,
+            0,
+            time_zone,
+            Disambiguation::Compatible,
+            OffsetOption::Prefer,
+            false,
+        ),
+        // Else,
+        //   Let resultISODateTime be CombineISODateAndTimeRecord(isoDate,
+        //   temporalTime.[[Time]]).
+        //   If ISODateTimeWithinLimits(resultISODateTime) is false, throw a
+        //   RangeError exception.
+        //   Let epochNs be ? GetEpochNanosecondsFor(timeZone,
+        //   resultISODateTime, compatible).
+        Some(time) => {
+            let result_iso_date_time = IsoDateTime::new(iso_date, time);
+            if !iso_date_time_within_limits(result_iso_date_time) {
+                return Err(RangeError("date-time is out of range"));
+            }
+            get_epoch_nanoseconds_for(time_zone, result_iso_date_time, Disambiguation::Compatible)
+        }
+    }
+}
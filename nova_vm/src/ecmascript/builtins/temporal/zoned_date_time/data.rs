@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{builtins::temporal::time_zone::TimeZone, types::OrdinaryObject},
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [6.4 Properties of Temporal.ZonedDateTime Instances](https://tc39.es/proposal-temporal/#sec-properties-of-temporal-zoneddatetime-instances)
+#[derive(Debug, Clone)]
+pub struct ZonedDateTimeHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[EpochNanoseconds]]
+    pub(crate) epoch_nanoseconds: i128,
+    /// \[\[TimeZone]]; the \[\[Calendar]] is always "iso8601".
+    pub(crate) time_zone: TimeZone,
+}
+
+impl Default for ZonedDateTimeHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            object_index: None,
+            epoch_nanoseconds: 0,
+            time_zone: TimeZone::utc(),
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ZonedDateTimeHeapData<'_> {
+    type Of<'a> = ZonedDateTimeHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ZonedDateTimeHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            epoch_nanoseconds: _,
+            time_zone: _,
+        } = self;
+        object_index.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            epoch_nanoseconds: _,
+            time_zone: _,
+        } = self;
+        object_index.sweep_values(compactions);
+    }
+}
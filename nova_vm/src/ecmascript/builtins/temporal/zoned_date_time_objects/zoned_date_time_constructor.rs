@@ -0,0 +1,195 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::type_conversion::to_big_int,
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            temporal::{
+                abstract_operations::{RangeError, is_valid_epoch_nanoseconds},
+                calendar::canonicalize_calendar,
+                instant::abstract_operations::{big_int_to_i128, compare_epoch_nanoseconds},
+                parsing::parse_time_zone_identifier,
+                time_zone::to_time_zone,
+                zoned_date_time::abstract_operations::{
+                    create_temporal_zoned_date_time, create_zoned_date_time_object,
+                    to_temporal_zoned_date_time,
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct ZonedDateTimeConstructor;
+impl Builtin for ZonedDateTimeConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.ZonedDateTime;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for ZonedDateTimeConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalZonedDateTime;
+}
+
+struct ZonedDateTimeFrom;
+impl Builtin for ZonedDateTimeFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimeConstructor::from);
+}
+struct ZonedDateTimeCompare;
+impl Builtin for ZonedDateTimeCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimeConstructor::compare);
+}
+
+impl ZonedDateTimeConstructor {
+    /// ### [6.1.1 Temporal.ZonedDateTime ( epochNanoseconds, timeZone \[ , calendar \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let epoch_nanoseconds = arguments.get(0).bind(gc.nogc());
+        let time_zone = arguments.get(1).scope(agent, gc.nogc());
+        let calendar = arguments.get(2).scope(agent, gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor Temporal.ZonedDateTime requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = to_big_int(agent, epoch_nanoseconds.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a
+        //    RangeError exception.
+        let Some(epoch_nanoseconds) =
+            big_int_to_i128(agent, epoch_nanoseconds).filter(|ns| is_valid_epoch_nanoseconds(*ns))
+        else {
+            return Err(
+                RangeError("epoch nanoseconds are out of range").throw(agent, gc.into_nogc())
+            );
+        };
+        // 4. If timeZone is not a String, throw a TypeError exception.
+        let Ok(time_zone) = String::try_from(time_zone.get(agent)) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "time zone must be a string",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. Let timeZoneParse be ? ParseTimeZoneIdentifier(timeZone).
+        // 6. If timeZoneParse.[[OffsetMinutes]] is empty, then
+        //    a. Let identifierRecord be GetAvailableNamedTimeZoneIdentifier(
+        //       timeZoneParse.[[Name]]).
+        //    b. If identifierRecord is empty, throw a RangeError exception.
+        //    c. Set timeZone to identifierRecord.[[Identifier]].
+        // 7. Else,
+        //    a. Set timeZone to FormatOffsetTimeZoneIdentifier(
+        //       timeZoneParse.[[OffsetMinutes]]).
+        let time_zone = parse_time_zone_identifier(&time_zone.as_wtf8(agent).to_string_lossy())
+            .and_then(to_time_zone)
+            .map_err(|err| err.throw(agent, gc.nogc()))
+            .unbind()?;
+        // 8. If calendar is undefined, set calendar to "iso8601".
+        let calendar = calendar.get(agent).bind(gc.nogc());
+        if !calendar.is_undefined() {
+            // 9. If calendar is not a String, throw a TypeError exception.
+            let Ok(calendar) = String::try_from(calendar) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "calendar must be a string",
+                    gc.into_nogc(),
+                ));
+            };
+            // 10. Set calendar to ? CanonicalizeCalendar(calendar).
+            let result = canonicalize_calendar(&calendar.as_wtf8(agent).to_string_lossy());
+            if let Err(err) = result {
+                return Err(err.throw(agent, gc.into_nogc()));
+            }
+        }
+        // 11. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+        //     calendar, NewTarget).
+        create_temporal_zoned_date_time(
+            agent,
+            epoch_nanoseconds,
+            time_zone,
+            Some(new_target.get(agent)),
+            gc,
+        )
+        .map(|zoned_date_time| zoned_date_time.into_value())
+    }
+
+    /// ### [6.2.2 Temporal.ZonedDateTime.from ( item \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? ToTemporalZonedDateTime(item, options).
+        let (epoch_nanoseconds, time_zone) =
+            to_temporal_zoned_date_time(agent, arguments.get(0), arguments.get(1), gc.reborrow())
+                .unbind()?;
+        Ok(
+            create_zoned_date_time_object(agent, epoch_nanoseconds, time_zone, gc.into_nogc())
+                .into_value(),
+        )
+    }
+
+    /// ### [6.2.3 Temporal.ZonedDateTime.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let two = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalZonedDateTime(one).
+        let (one, _) =
+            to_temporal_zoned_date_time(agent, arguments.get(0), Value::Undefined, gc.reborrow())
+                .unbind()?;
+        // 2. Set two to ? ToTemporalZonedDateTime(two).
+        let (two, _) = to_temporal_zoned_date_time(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[EpochNanoseconds]],
+        //    two.[[EpochNanoseconds]])).
+        Ok(compare_epoch_nanoseconds(one, two).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let zoned_date_time_prototype = intrinsics.temporal_zoned_date_time_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<ZonedDateTimeConstructor>(agent, realm)
+            .with_property_capacity(3)
+            .with_prototype_property(zoned_date_time_prototype.into_object())
+            .with_builtin_function_property::<ZonedDateTimeFrom>()
+            .with_builtin_function_property::<ZonedDateTimeCompare>()
+            .build();
+    }
+}
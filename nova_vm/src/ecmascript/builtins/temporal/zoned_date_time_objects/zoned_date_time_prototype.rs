@@ -0,0 +1,1728 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            temporal::{
+                abstract_operations::{
+                    ArithmeticOperation, DifferenceOperation, Direction, Disambiguation,
+                    OffsetOption, Precision, RangeError, RoundingMode, ShowCalendar, ShowOffset,
+                    ShowTimeZone, TemporalResult, Unit, UnitGroup, UnitValue,
+                    format_utc_offset_nanoseconds, get_direction_option, get_options_object,
+                    get_round_to_options, get_rounding_mode_option,
+                    get_temporal_disambiguation_option,
+                    get_temporal_fractional_second_digits_option, get_temporal_offset_option,
+                    get_temporal_overflow_option, get_temporal_show_calendar_name_option,
+                    get_temporal_show_offset_option, get_temporal_show_time_zone_name_option,
+                    get_temporal_unit_valued_option, is_partial_temporal_object,
+                    maximum_temporal_duration_rounding_increment,
+                    to_seconds_string_precision_record, validate_temporal_rounding_increment,
+                    validate_temporal_unit_value,
+                },
+                calendar::{
+                    CalendarDateField, CalendarFields, Field, ISO8601, IsoDate, RequiredFields,
+                    balance_iso_date, calendar_iso_to_date_field, prepare_calendar_fields,
+                    to_temporal_calendar_identifier,
+                },
+                duration::abstract_operations::{
+                    round_time_duration_to_increment, total_time_duration,
+                },
+                instant::abstract_operations::{
+                    create_instant_object, epoch_nanoseconds_to_big_int,
+                },
+                plain_date::abstract_operations::create_plain_date_object,
+                plain_date_time::abstract_operations::{
+                    create_plain_date_time_object, interpret_temporal_date_time_fields,
+                    round_iso_date_time,
+                },
+                plain_time::abstract_operations::{create_plain_time_object, to_temporal_time},
+                time_zone::{
+                    OffsetBehaviour, TimeZone, get_iso_date_time_for,
+                    get_named_time_zone_next_transition, get_named_time_zone_previous_transition,
+                    get_offset_nanoseconds_for, get_start_of_day, interpret_iso_date_time_offset,
+                    time_zone_equals, to_temporal_time_zone_identifier,
+                },
+                zoned_date_time::{
+                    ZonedDateTime,
+                    abstract_operations::{
+                        add_duration_to_zoned_date_time, create_zoned_date_time_object,
+                        difference_temporal_zoned_date_time, epoch_nanoseconds_for_date_and_time,
+                        temporal_zoned_date_time_to_string, to_temporal_zoned_date_time,
+                        zoned_date_time_iso_date_time,
+                    },
+                },
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+pub(crate) struct ZonedDateTimePrototype;
+
+struct ZonedDateTimePrototypeGetCalendarId;
+impl Builtin for ZonedDateTimePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_calendar_id);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetCalendarId {}
+struct ZonedDateTimePrototypeGetTimeZoneId;
+impl Builtin for ZonedDateTimePrototypeGetTimeZoneId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_timeZoneId;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.timeZoneId.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_time_zone_id);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetTimeZoneId {}
+struct ZonedDateTimePrototypeGetEra;
+impl Builtin for ZonedDateTimePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::Era as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetEra {}
+struct ZonedDateTimePrototypeGetEraYear;
+impl Builtin for ZonedDateTimePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::EraYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetEraYear {}
+struct ZonedDateTimePrototypeGetYear;
+impl Builtin for ZonedDateTimePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::Year as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetYear {}
+struct ZonedDateTimePrototypeGetMonth;
+impl Builtin for ZonedDateTimePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::Month as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMonth {}
+struct ZonedDateTimePrototypeGetMonthCode;
+impl Builtin for ZonedDateTimePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::MonthCode as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMonthCode {}
+struct ZonedDateTimePrototypeGetDay;
+impl Builtin for ZonedDateTimePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::Day as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDay {}
+struct ZonedDateTimePrototypeGetDayOfWeek;
+impl Builtin for ZonedDateTimePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::DayOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDayOfWeek {}
+struct ZonedDateTimePrototypeGetDayOfYear;
+impl Builtin for ZonedDateTimePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::DayOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDayOfYear {}
+struct ZonedDateTimePrototypeGetWeekOfYear;
+impl Builtin for ZonedDateTimePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::WeekOfYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetWeekOfYear {}
+struct ZonedDateTimePrototypeGetYearOfWeek;
+impl Builtin for ZonedDateTimePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::YearOfWeek as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetYearOfWeek {}
+struct ZonedDateTimePrototypeGetDaysInWeek;
+impl Builtin for ZonedDateTimePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInWeek as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDaysInWeek {}
+struct ZonedDateTimePrototypeGetDaysInMonth;
+impl Builtin for ZonedDateTimePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInMonth as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDaysInMonth {}
+struct ZonedDateTimePrototypeGetDaysInYear;
+impl Builtin for ZonedDateTimePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::DaysInYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetDaysInYear {}
+struct ZonedDateTimePrototypeGetMonthsInYear;
+impl Builtin for ZonedDateTimePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::MonthsInYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMonthsInYear {}
+struct ZonedDateTimePrototypeGetInLeapYear;
+impl Builtin for ZonedDateTimePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_date_field::<{ CalendarDateField::InLeapYear as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetInLeapYear {}
+struct ZonedDateTimePrototypeGetHour;
+impl Builtin for ZonedDateTimePrototypeGetHour {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hour;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hour.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ZonedDateTimePrototype::get_time_field::<{ Unit::Hour as usize }>);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetHour {}
+struct ZonedDateTimePrototypeGetMinute;
+impl Builtin for ZonedDateTimePrototypeGetMinute {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minute;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minute.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ZonedDateTimePrototype::get_time_field::<{ Unit::Minute as usize }>);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMinute {}
+struct ZonedDateTimePrototypeGetSecond;
+impl Builtin for ZonedDateTimePrototypeGetSecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_second;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.second.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ZonedDateTimePrototype::get_time_field::<{ Unit::Second as usize }>);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetSecond {}
+struct ZonedDateTimePrototypeGetMillisecond;
+impl Builtin for ZonedDateTimePrototypeGetMillisecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_millisecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.millisecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_time_field::<{ Unit::Millisecond as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMillisecond {}
+struct ZonedDateTimePrototypeGetMicrosecond;
+impl Builtin for ZonedDateTimePrototypeGetMicrosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microsecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microsecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(
+        ZonedDateTimePrototype::get_time_field::<{ Unit::Microsecond as usize }>,
+    );
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetMicrosecond {}
+struct ZonedDateTimePrototypeGetNanosecond;
+impl Builtin for ZonedDateTimePrototypeGetNanosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanosecond;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanosecond.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ZonedDateTimePrototype::get_time_field::<{ Unit::Nanosecond as usize }>);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetNanosecond {}
+struct ZonedDateTimePrototypeGetEpochMilliseconds;
+impl Builtin for ZonedDateTimePrototypeGetEpochMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochMilliseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochMilliseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_epoch_milliseconds);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetEpochMilliseconds {}
+struct ZonedDateTimePrototypeGetEpochNanoseconds;
+impl Builtin for ZonedDateTimePrototypeGetEpochNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochNanoseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochNanoseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_epoch_nanoseconds);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetEpochNanoseconds {}
+struct ZonedDateTimePrototypeGetHoursInDay;
+impl Builtin for ZonedDateTimePrototypeGetHoursInDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hoursInDay;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.hoursInDay.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_hours_in_day);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetHoursInDay {}
+struct ZonedDateTimePrototypeGetOffsetNanoseconds;
+impl Builtin for ZonedDateTimePrototypeGetOffsetNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_offsetNanoseconds;
+
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.offsetNanoseconds.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_offset_nanoseconds);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetOffsetNanoseconds {}
+struct ZonedDateTimePrototypeGetOffset;
+impl Builtin for ZonedDateTimePrototypeGetOffset {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_offset;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.offset.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::get_offset);
+}
+impl BuiltinGetter for ZonedDateTimePrototypeGetOffset {}
+struct ZonedDateTimePrototypeWith;
+impl Builtin for ZonedDateTimePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::with);
+}
+struct ZonedDateTimePrototypeWithPlainTime;
+impl Builtin for ZonedDateTimePrototypeWithPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withPlainTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::with_plain_time);
+}
+struct ZonedDateTimePrototypeWithTimeZone;
+impl Builtin for ZonedDateTimePrototypeWithTimeZone {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withTimeZone;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::with_time_zone);
+}
+struct ZonedDateTimePrototypeWithCalendar;
+impl Builtin for ZonedDateTimePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::with_calendar);
+}
+struct ZonedDateTimePrototypeAdd;
+impl Builtin for ZonedDateTimePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::add);
+}
+struct ZonedDateTimePrototypeSubtract;
+impl Builtin for ZonedDateTimePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::subtract);
+}
+struct ZonedDateTimePrototypeUntil;
+impl Builtin for ZonedDateTimePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::until);
+}
+struct ZonedDateTimePrototypeSince;
+impl Builtin for ZonedDateTimePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::since);
+}
+struct ZonedDateTimePrototypeRound;
+impl Builtin for ZonedDateTimePrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::round);
+}
+struct ZonedDateTimePrototypeEquals;
+impl Builtin for ZonedDateTimePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::equals);
+}
+struct ZonedDateTimePrototypeToString;
+impl Builtin for ZonedDateTimePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_string);
+}
+struct ZonedDateTimePrototypeToLocaleString;
+impl Builtin for ZonedDateTimePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_locale_string);
+}
+struct ZonedDateTimePrototypeToJSON;
+impl Builtin for ZonedDateTimePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_json);
+}
+struct ZonedDateTimePrototypeValueOf;
+impl Builtin for ZonedDateTimePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::value_of);
+}
+struct ZonedDateTimePrototypeStartOfDay;
+impl Builtin for ZonedDateTimePrototypeStartOfDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.startOfDay;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::start_of_day);
+}
+struct ZonedDateTimePrototypeGetTimeZoneTransition;
+impl Builtin for ZonedDateTimePrototypeGetTimeZoneTransition {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getTimeZoneTransition;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ZonedDateTimePrototype::get_time_zone_transition);
+}
+struct ZonedDateTimePrototypeToInstant;
+impl Builtin for ZonedDateTimePrototypeToInstant {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toInstant;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_instant);
+}
+struct ZonedDateTimePrototypeToPlainDate;
+impl Builtin for ZonedDateTimePrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_plain_date);
+}
+struct ZonedDateTimePrototypeToPlainTime;
+impl Builtin for ZonedDateTimePrototypeToPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_plain_time);
+}
+struct ZonedDateTimePrototypeToPlainDateTime;
+impl Builtin for ZonedDateTimePrototypeToPlainDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDateTime;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ZonedDateTimePrototype::to_plain_date_time);
+}
+
+impl ZonedDateTimePrototype {
+    /// ### [6.3.3 get Temporal.ZonedDateTime.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[Calendar]].
+        Ok(Value::from_static_str(agent, ISO8601, gc))
+    }
+
+    /// ### [6.3.4 get Temporal.ZonedDateTime.prototype.timeZoneId](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezoneid)
+    fn get_time_zone_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[TimeZone]].
+        let identifier = agent[zoned_date_time].time_zone.identifier();
+        Ok(Value::from_string(agent, identifier, gc))
+    }
+
+    /// ### [6.3.5 get Temporal.ZonedDateTime.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.era)
+    ///
+    /// The getters from era to inLeapYear, with the field of the Calendar
+    /// Date Record as an index into the fields.
+    fn get_date_field<'gc, const FIELD: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 4. Return CalendarISOToDate(zonedDateTime.[[Calendar]],
+        //    isoDateTime.[[ISODate]]).[[Era]].
+        Ok(calendar_iso_to_date_field(
+            agent,
+            iso_date_time.date,
+            FIELD,
+            gc,
+        ))
+    }
+
+    /// ### [6.3.11 get Temporal.ZonedDateTime.prototype.hour](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hour)
+    ///
+    /// The getters from hour to nanosecond, with the unit of the field as an
+    /// index into the units.
+    fn get_time_field<'gc, const UNIT: usize>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Hour]]).
+        Ok(iso_date_time.time.field(UNIT).into())
+    }
+
+    /// ### [6.3.17 get Temporal.ZonedDateTime.prototype.epochMilliseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmilliseconds)
+    fn get_epoch_milliseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        let ns = agent[zoned_date_time].epoch_nanoseconds;
+        // 4. Let ms be floor(ℝ(ns) / 10**6).
+        let ms = ns.div_euclid(1_000_000);
+        // 5. Return 𝔽(ms).
+        Ok(Value::from_f64(agent, ms as f64, gc))
+    }
+
+    /// ### [6.3.18 get Temporal.ZonedDateTime.prototype.epochNanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochnanoseconds)
+    fn get_epoch_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[EpochNanoseconds]].
+        let ns = agent[zoned_date_time].epoch_nanoseconds;
+        Ok(epoch_nanoseconds_to_big_int(agent, ns, gc).into_value())
+    }
+
+    /// ### [6.3.23 get Temporal.ZonedDateTime.prototype.hoursInDay](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hoursinday)
+    fn get_hours_in_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        let data = &agent[zoned_date_time];
+        // 4. Let today be GetISODateTimeFor(timeZone,
+        //    zonedDateTime.[[EpochNanoseconds]]).[[ISODate]].
+        let today = get_iso_date_time_for(&data.time_zone, data.epoch_nanoseconds).date;
+        // 5-7. Let todayNs be ? GetStartOfDay(timeZone, today), and
+        //      tomorrowNs the start of the following day.
+        let result = day_bounds(&data.time_zone, today);
+        let (today_ns, tomorrow_ns) = result.map_err(|err| err.throw(agent, gc))?;
+        // 8. Let diff be TimeDurationFromEpochNanosecondsDifference(tomorrowNs,
+        //    todayNs).
+        let diff = tomorrow_ns - today_ns;
+        // 9. Return 𝔽(TotalTimeDuration(diff, hour)).
+        Ok(Value::from_f64(
+            agent,
+            total_time_duration(diff, Unit::Hour),
+            gc,
+        ))
+    }
+
+    /// ### [6.3.29 get Temporal.ZonedDateTime.prototype.offsetNanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offsetnanoseconds)
+    fn get_offset_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]])).
+        let data = &agent[zoned_date_time];
+        let offset_nanoseconds =
+            get_offset_nanoseconds_for(&data.time_zone, data.epoch_nanoseconds);
+        Ok(Value::from_f64(agent, offset_nanoseconds as f64, gc))
+    }
+
+    /// ### [6.3.30 get Temporal.ZonedDateTime.prototype.offset](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offset)
+    fn get_offset<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let offsetNanoseconds be GetOffsetNanosecondsFor(
+        //    zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        let data = &agent[zoned_date_time];
+        let offset_nanoseconds =
+            get_offset_nanoseconds_for(&data.time_zone, data.epoch_nanoseconds);
+        // 4. Return FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let result = format_utc_offset_nanoseconds(offset_nanoseconds);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [6.3.31 Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let calendar be zonedDateTime.[[Calendar]].
+        let data = &agent[zoned_date_time];
+        let epoch_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        // 3. If ? IsPartialTemporalObject(temporalZonedDateTimeLike) is false,
+        //    throw a TypeError exception.
+        let Some(temporal_zoned_date_time_like) =
+            is_partial_temporal_object(agent, arguments.get(0), gc.reborrow()).unbind()?
+        else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "with requires a partial Temporal.ZonedDateTime-like object",
+                gc.into_nogc(),
+            ));
+        };
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone,
+        //    epochNs).
+        let offset_nanoseconds = get_offset_nanoseconds_for(&time_zone, epoch_ns);
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        let iso_date_time = get_iso_date_time_for(&time_zone, epoch_ns);
+        // 9. Let fields be ISODateToFields(calendar, isoDateTime.[[ISODate]],
+        //    date).
+        // 10-15. Set the time fields of fields to the fields of
+        //        isoDateTime.[[Time]].
+        let mut fields =
+            CalendarFields::from_iso_date(iso_date_time.date).with_time(iso_date_time.time);
+        // 16. Set fields.[[OffsetString]] to FormatUTCOffsetNanoseconds(
+        //     offsetNanoseconds).
+        fields.offset = Some(offset_nanoseconds);
+        // 17. Let partialZonedDateTime be ? PrepareCalendarFields(calendar,
+        //     temporalZonedDateTimeLike, « year, month, month-code, day »,
+        //     « hour, minute, second, millisecond, microsecond, nanosecond,
+        //     offset », partial).
+        let partial_zoned_date_time = prepare_calendar_fields(
+            agent,
+            temporal_zoned_date_time_like,
+            &[Field::DATE, Field::TIME, &[Field::Offset]],
+            RequiredFields::Partial,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 18. Set fields to CalendarMergeFields(calendar, fields,
+        //     partialZonedDateTime).
+        let fields = fields.merge(partial_zoned_date_time);
+        // 19. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options.get(agent), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 20. Let disambiguation be ? GetTemporalDisambiguationOption(
+        //     resolvedOptions).
+        let disambiguation =
+            get_temporal_disambiguation_option(agent, resolved_options.get(agent), gc.reborrow())
+                .unbind()?;
+        // 21. Let offset be ? GetTemporalOffsetOption(resolvedOptions, prefer).
+        let offset = get_temporal_offset_option(
+            agent,
+            resolved_options.get(agent),
+            OffsetOption::Prefer,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 22. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            get_temporal_overflow_option(agent, resolved_options.get(agent), gc.reborrow())
+                .unbind()?;
+        let gc = gc.into_nogc();
+        // 23. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar,
+        //     fields, overflow).
+        let date_time_result = interpret_temporal_date_time_fields(&fields, overflow)
+            .map_err(|err| err.throw(agent, gc))?;
+        // 24. Let newOffsetNanoseconds be ! ParseDateTimeUTCOffset(
+        //     fields.[[OffsetString]]).
+        let new_offset_nanoseconds = fields.offset.unwrap();
+        // 25. Let epochNanoseconds be ? InterpretISODateTimeOffset(
+        //     dateTimeResult.[[ISODate]], dateTimeResult.[[Time]], option,
+        //     newOffsetNanoseconds, timeZone, disambiguation, offset,
+        //     match-exactly).
+        let epoch_nanoseconds = interpret_iso_date_time_offset(
+            date_time_result.date,
+            Some(date_time_result.time),
+            OffsetBehaviour::Option,
+            new_offset_nanoseconds,
+            &time_zone,
+            disambiguation,
+            offset,
+            false,
+        )
+        .map_err(|err| err.throw(agent, gc))?;
+        // 26. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+        //     calendar).
+        Ok(create_zoned_date_time_object(agent, epoch_nanoseconds, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.32 Temporal.ZonedDateTime.prototype.withPlainTime ( \[ plainTimeLike \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaintime)
+    fn with_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let plain_time_like = arguments.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        let time_zone = agent[zoned_date_time].time_zone.clone();
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone,
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 6. If plainTimeLike is undefined, then
+        //    a. Let epochNs be ? GetStartOfDay(timeZone,
+        //       isoDateTime.[[ISODate]]).
+        // 7. Else,
+        //    a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+        //    b. Let resultISODateTime be CombineISODateAndTimeRecord(
+        //       isoDateTime.[[ISODate]], plainTime.[[Time]]).
+        //    c. Let epochNs be ? GetEpochNanosecondsFor(timeZone,
+        //       resultISODateTime, compatible).
+        let time = if plain_time_like.is_undefined() {
+            None
+        } else {
+            Some(
+                to_temporal_time(
+                    agent,
+                    plain_time_like.unbind(),
+                    Value::Undefined,
+                    gc.reborrow(),
+                )
+                .unbind()?,
+            )
+        };
+        let gc = gc.into_nogc();
+        let result = epoch_nanoseconds_for_date_and_time(&time_zone, iso_date_time.date, time);
+        let epoch_ns = result.map_err(|err| err.throw(agent, gc))?;
+        // 8. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Ok(create_zoned_date_time_object(agent, epoch_ns, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.33 Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withtimezone)
+    fn with_time_zone<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+        let time_zone = to_temporal_time_zone_identifier(agent, arguments.get(0), gc)?;
+        // 4. Return ! CreateTemporalZonedDateTime(
+        //    zonedDateTime.[[EpochNanoseconds]], timeZone,
+        //    zonedDateTime.[[Calendar]]).
+        let epoch_ns = agent[zoned_date_time].epoch_nanoseconds;
+        Ok(create_zoned_date_time_object(agent, epoch_ns, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.34 Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        to_temporal_calendar_identifier(agent, arguments.get(0), gc)?;
+        // 4. Return ! CreateTemporalZonedDateTime(
+        //    zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]],
+        //    calendar).
+        let data = &agent[zoned_date_time];
+        let epoch_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        Ok(create_zoned_date_time_object(agent, epoch_ns, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.35 Temporal.ZonedDateTime.prototype.add ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToZonedDateTime(add, zonedDateTime,
+        //    temporalDurationLike, options).
+        add_duration_to_zoned_date_time(
+            agent,
+            ArithmeticOperation::Add,
+            zoned_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|zoned_date_time| zoned_date_time.into_value())
+    }
+
+    /// ### [6.3.36 Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToZonedDateTime(subtract, zonedDateTime,
+        //    temporalDurationLike, options).
+        add_duration_to_zoned_date_time(
+            agent,
+            ArithmeticOperation::Subtract,
+            zoned_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|zoned_date_time| zoned_date_time.into_value())
+    }
+
+    /// ### [6.3.37 Temporal.ZonedDateTime.prototype.until ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalZonedDateTime(until, zonedDateTime,
+        //    other, options).
+        difference_temporal_zoned_date_time(
+            agent,
+            DifferenceOperation::Until,
+            zoned_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [6.3.38 Temporal.ZonedDateTime.prototype.since ( other \[ , options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalZonedDateTime(since, zonedDateTime,
+        //    other, options).
+        difference_temporal_zoned_date_time(
+            agent,
+            DifferenceOperation::Since,
+            zoned_date_time.unbind(),
+            arguments.get(0),
+            arguments.get(1),
+            gc,
+        )
+        .map(|duration| duration.into_value())
+    }
+
+    /// ### [6.3.39 Temporal.ZonedDateTime.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 15. Let thisNs be zonedDateTime.[[EpochNanoseconds]].
+        // 16. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 17. Let calendar be zonedDateTime.[[Calendar]].
+        let data = &agent[zoned_date_time];
+        let this_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        // 3-9. Read the roundingIncrement, roundingMode and smallestUnit
+        //      options of roundTo.
+        let options = get_round_to_options(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time,
+        //     « day »).
+        validate_temporal_unit_value(
+            options.smallest_unit,
+            UnitGroup::Time,
+            &[UnitValue::Unit(Unit::Day)],
+        )
+        .map_err(|err| err.throw(agent, gc))?;
+        let UnitValue::Unit(smallest_unit) = options.smallest_unit else {
+            unreachable!()
+        };
+        let (maximum, inclusive) = if smallest_unit == Unit::Day {
+            // 11. If smallestUnit is day, then
+            //     a. Let maximum be 1.
+            //     b. Let inclusive be true.
+            (1, true)
+        } else {
+            // 12. Else,
+            //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(
+            //        smallestUnit).
+            //     b. Assert: maximum is not unset.
+            //     c. Let inclusive be false.
+            (
+                maximum_temporal_duration_rounding_increment(smallest_unit).unwrap(),
+                false,
+            )
+        };
+        // 13. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement,
+        //     maximum, inclusive).
+        validate_temporal_rounding_increment(options.rounding_increment, maximum, inclusive)
+            .map_err(|err| err.throw(agent, gc))?;
+        // 14. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        if smallest_unit == Unit::Nanosecond && options.rounding_increment == 1 {
+            // a. Return ! CreateTemporalZonedDateTime(
+            //    zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]],
+            //    zonedDateTime.[[Calendar]]).
+            return Ok(create_zoned_date_time_object(agent, this_ns, time_zone, gc).into_value());
+        }
+        let result = round_zoned_date_time(
+            this_ns,
+            &time_zone,
+            options.rounding_increment,
+            smallest_unit,
+            options.rounding_mode,
+        );
+        let epoch_nanoseconds = result.map_err(|err| err.throw(agent, gc))?;
+        // 21. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+        //     calendar).
+        Ok(create_zoned_date_time_object(agent, epoch_nanoseconds, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.40 Temporal.ZonedDateTime.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let data = &agent[zoned_date_time];
+        let epoch_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
+        let (other_epoch_ns, other_time_zone) =
+            to_temporal_zoned_date_time(agent, arguments.get(0), Value::Undefined, gc)?;
+        // 4. If zonedDateTime.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]],
+        //    return false.
+        // 5. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]])
+        //    is false, return false.
+        // 6. Return CalendarEquals(zonedDateTime.[[Calendar]],
+        //    other.[[Calendar]]).
+        Ok((epoch_ns == other_epoch_ns && time_zone_equals(&time_zone, &other_time_zone)).into())
+    }
+
+    /// ### [6.3.41 Temporal.ZonedDateTime.prototype.toString ( \[ options \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let data = &agent[zoned_date_time];
+        let epoch_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, arguments.get(0), gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. NOTE: The following steps read options and perform independent
+        //    validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(
+        //    resolvedOptions).
+        let show_calendar = get_temporal_show_calendar_name_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(
+        //    resolvedOptions).
+        let digits = get_temporal_fractional_second_digits_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Let showOffset be ? GetTemporalShowOffsetOption(resolvedOptions).
+        let show_offset =
+            get_temporal_show_offset_option(agent, resolved_options.get(agent), gc.reborrow())
+                .unbind()?;
+        // 8. Let roundingMode be ? GetRoundingModeOption(resolvedOptions,
+        //    trunc).
+        let rounding_mode = get_rounding_mode_option(
+            agent,
+            resolved_options.get(agent),
+            RoundingMode::Trunc,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions,
+        //    "smallestUnit", unset).
+        let smallest_unit = get_temporal_unit_valued_option(
+            agent,
+            resolved_options.get(agent),
+            BUILTIN_STRING_MEMORY.smallestUnit,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 10. Let showTimeZone be ? GetTemporalShowTimeZoneNameOption(
+        //     resolvedOptions).
+        let show_time_zone = get_temporal_show_time_zone_name_option(
+            agent,
+            resolved_options.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // 11. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        validate_temporal_unit_value(smallest_unit, UnitGroup::Time, &[])
+            .map_err(|err| err.throw(agent, gc))?;
+        let smallest_unit = match smallest_unit {
+            UnitValue::Unit(unit) => Some(unit),
+            _ => None,
+        };
+        // 12. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(RangeError("smallestUnit must not be hour").throw(agent, gc));
+        }
+        // 13. Let precision be ToSecondsStringPrecisionRecord(smallestUnit,
+        //     digits).
+        let precision = to_seconds_string_precision_record(smallest_unit, digits);
+        // 14. Return TemporalZonedDateTimeToString(zonedDateTime,
+        //     precision.[[Precision]], showCalendar, showTimeZone, showOffset,
+        //     precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let result = temporal_zoned_date_time_to_string(
+            epoch_ns,
+            &time_zone,
+            precision.precision,
+            show_calendar,
+            show_time_zone,
+            show_offset,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        );
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [6.3.42 Temporal.ZonedDateTime.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tolocalestring)
+    ///
+    /// Without locale-sensitive date and time formatting this produces the
+    /// same result as toString().
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto,
+        //    auto, auto).
+        let result = zoned_date_time_to_default_string(agent, zoned_date_time);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [6.3.43 Temporal.ZonedDateTime.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto,
+        //    auto, auto).
+        let result = zoned_date_time_to_default_string(agent, zoned_date_time);
+        Ok(Value::from_string(agent, result, gc))
+    }
+
+    /// ### [6.3.44 Temporal.ZonedDateTime.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal.ZonedDateTime cannot be converted to a primitive value; use compare() or equals()",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [6.3.45 Temporal.ZonedDateTime.prototype.startOfDay ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.startofday)
+    fn start_of_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        let time_zone = agent[zoned_date_time].time_zone.clone();
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone,
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 6. Let epochNanoseconds be ? GetStartOfDay(timeZone,
+        //    isoDateTime.[[ISODate]]).
+        let result = get_start_of_day(&time_zone, iso_date_time.date);
+        let epoch_nanoseconds = result.map_err(|err| err.throw(agent, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone,
+        //    calendar).
+        Ok(create_zoned_date_time_object(agent, epoch_nanoseconds, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.46 Temporal.ZonedDateTime.prototype.getTimeZoneTransition ( directionParam )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.gettimezonetransition)
+    fn get_time_zone_transition<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let direction_param = arguments.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let data = &agent[zoned_date_time];
+        let epoch_ns = data.epoch_nanoseconds;
+        let time_zone = data.time_zone.clone();
+        // 3. If directionParam is undefined, throw a TypeError exception.
+        if direction_param.is_undefined() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "getTimeZoneTransition requires a direction",
+                gc.into_nogc(),
+            ));
+        }
+        let direction = if let Ok(direction_param) = String::try_from(direction_param) {
+            // 4. If directionParam is a String, then
+            //    a. Let paramString be directionParam.
+            //    b. Set directionParam to OrdinaryObjectCreate(null).
+            //    c. Perform ! CreateDataPropertyOrThrow(directionParam,
+            //       "direction", paramString).
+            // NOTE: GetDirectionOption reads paramString from such an object
+            // as is, so it is validated here directly.
+            let Some(direction) = Direction::VALUES
+                .iter()
+                .copied()
+                .find(|value| direction_param.as_wtf8(agent).as_str() == Some(*value))
+            else {
+                let value = direction_param
+                    .as_wtf8(agent)
+                    .to_string_lossy()
+                    .into_owned();
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    format!("Value {value} out of range for option"),
+                    gc.into_nogc(),
+                ));
+            };
+            Some(Direction::from_option(direction))
+        } else {
+            // 5. Else,
+            //    a. Set directionParam to ? GetOptionsObject(directionParam).
+            let direction_param = get_options_object(agent, direction_param, gc.nogc()).unbind()?;
+            // 6. Let direction be ? GetDirectionOption(directionParam).
+            get_direction_option(agent, direction_param, gc.reborrow()).unbind()?
+        };
+        let gc = gc.into_nogc();
+        // 7. If direction is unset, throw a RangeError exception.
+        let Some(direction) = direction else {
+            return Err(RangeError("direction is required").throw(agent, gc));
+        };
+        // 8. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 9. If IsOffsetTimeZoneIdentifier(timeZone) is true, return null.
+        // 10. If direction is next, then
+        //     a. Let transition be GetNamedTimeZoneNextTransition(timeZone,
+        //        zonedDateTime.[[EpochNanoseconds]]).
+        // 11. Else,
+        //     a. Assert: direction is previous.
+        //     b. Let transition be GetNamedTimeZonePreviousTransition(timeZone,
+        //        zonedDateTime.[[EpochNanoseconds]]).
+        let transition = match direction {
+            Direction::Next => get_named_time_zone_next_transition(&time_zone, epoch_ns),
+            Direction::Previous => get_named_time_zone_previous_transition(&time_zone, epoch_ns),
+        };
+        // 12. If transition is null, return null.
+        let Some(transition) = transition else {
+            return Ok(Value::Null);
+        };
+        // 13. Return ! CreateTemporalZonedDateTime(transition, timeZone,
+        //     zonedDateTime.[[Calendar]]).
+        Ok(create_zoned_date_time_object(agent, transition, time_zone, gc).into_value())
+    }
+
+    /// ### [6.3.47 Temporal.ZonedDateTime.prototype.toInstant ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toinstant)
+    fn to_instant<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalInstant(zonedDateTime.[[EpochNanoseconds]]).
+        let epoch_ns = agent[zoned_date_time].epoch_nanoseconds;
+        Ok(create_instant_object(agent, epoch_ns, gc).into_value())
+    }
+
+    /// ### [6.3.48 Temporal.ZonedDateTime.prototype.toPlainDate ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 4. Return ! CreateTemporalDate(isoDateTime.[[ISODate]],
+        //    zonedDateTime.[[Calendar]]).
+        Ok(create_plain_date_object(agent, iso_date_time.date, gc).into_value())
+    }
+
+    /// ### [6.3.49 Temporal.ZonedDateTime.prototype.toPlainTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaintime)
+    fn to_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 4. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        Ok(create_plain_time_object(agent, iso_date_time.time, gc).into_value())
+    }
+
+    /// ### [6.3.50 Temporal.ZonedDateTime.prototype.toPlainDateTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindatetime)
+    fn to_plain_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime,
+        //    [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time = require_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]],
+        //    zonedDateTime.[[EpochNanoseconds]]).
+        let iso_date_time = zoned_date_time_iso_date_time(agent, zoned_date_time);
+        // 4. Return ! CreateTemporalDateTime(isoDateTime,
+        //    zonedDateTime.[[Calendar]]).
+        Ok(create_plain_date_time_object(agent, iso_date_time, gc).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_zoned_date_time_prototype();
+        let zoned_date_time_constructor = intrinsics.temporal_zoned_date_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(50)
+            .with_prototype(object_prototype)
+            .with_constructor_property(zoned_date_time_constructor)
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetTimeZoneId>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetEra>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDay>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetInLeapYear>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetHour>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMinute>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetSecond>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMillisecond>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetMicrosecond>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetNanosecond>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetEpochMilliseconds>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetEpochNanoseconds>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetHoursInDay>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetOffsetNanoseconds>()
+            .with_builtin_function_getter_property::<ZonedDateTimePrototypeGetOffset>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeWith>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeWithPlainTime>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeWithTimeZone>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeWithCalendar>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeAdd>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeSubtract>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeUntil>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeSince>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeRound>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeEquals>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToString>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToLocaleString>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToJSON>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeValueOf>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeStartOfDay>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeGetTimeZoneTransition>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToInstant>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToPlainDate>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToPlainTime>()
+            .with_builtin_function_property::<ZonedDateTimePrototypeToPlainDateTime>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_ZonedDateTime.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_temporal_zoned_date_time<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ZonedDateTime<'a>> {
+    ZonedDateTime::try_from(value)
+        .map(|zoned_date_time| zoned_date_time.bind(gc))
+        .map_err(|_| {
+            agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "this is not a Temporal.ZonedDateTime object",
+                gc,
+            )
+        })
+}
+
+/// TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto), as
+/// toLocaleString and toJSON return it.
+fn zoned_date_time_to_default_string(
+    agent: &Agent,
+    zoned_date_time: ZonedDateTime,
+) -> std::string::String {
+    let data = &agent[zoned_date_time];
+    temporal_zoned_date_time_to_string(
+        data.epoch_nanoseconds,
+        &data.time_zone,
+        Precision::Auto,
+        ShowCalendar::Auto,
+        ShowTimeZone::Auto,
+        ShowOffset::Auto,
+        1,
+        Unit::Nanosecond,
+        RoundingMode::Trunc,
+    )
+}
+
+/// The start of the day of a date in a time zone and the start of the
+/// following day.
+fn day_bounds(time_zone: &TimeZone, iso_date: IsoDate) -> TemporalResult<(i128, i128)> {
+    // Let tomorrow be BalanceISODate(today.[[Year]], today.[[Month]],
+    // today.[[Day]] + 1).
+    let tomorrow = balance_iso_date(
+        i64::from(iso_date.year),
+        iso_date.month,
+        i64::from(iso_date.day) + 1,
+    );
+    // Let todayNs be ? GetStartOfDay(timeZone, today).
+    let today_ns = get_start_of_day(time_zone, iso_date)?;
+    // Let tomorrowNs be ? GetStartOfDay(timeZone, tomorrow).
+    let tomorrow_ns = get_start_of_day(time_zone, tomorrow)?;
+    Ok((today_ns, tomorrow_ns))
+}
+
+/// Steps 18 to 20 of Temporal.ZonedDateTime.prototype.round.
+fn round_zoned_date_time(
+    this_ns: i128,
+    time_zone: &TimeZone,
+    rounding_increment: u32,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> TemporalResult<i128> {
+    // 18. Let isoDateTime be GetISODateTimeFor(timeZone, thisNs).
+    let iso_date_time = get_iso_date_time_for(time_zone, this_ns);
+    // 19. If smallestUnit is day, then
+    if smallest_unit == Unit::Day {
+        // a. Let dateStart be isoDateTime.[[ISODate]].
+        // b. Let dateEnd be BalanceISODate(dateStart.[[Year]],
+        //    dateStart.[[Month]], dateStart.[[Day]] + 1).
+        // c. Let startNs be ? GetStartOfDay(timeZone, dateStart).
+        // d. Assert: thisNs ≥ startNs.
+        // e. Let endNs be ? GetStartOfDay(timeZone, dateEnd).
+        // f. Assert: thisNs < endNs.
+        let (start_ns, end_ns) = day_bounds(time_zone, iso_date_time.date)?;
+        debug_assert!(start_ns <= this_ns && this_ns < end_ns);
+        // g. Let dayLengthNs be ℝ(endNs - startNs).
+        let day_length_ns = end_ns - start_ns;
+        // h. Let dayProgressNs be TimeDurationFromEpochNanosecondsDifference(
+        //    thisNs, startNs).
+        let day_progress_ns = this_ns - start_ns;
+        // i. Let roundedDayNs be ! RoundTimeDurationToIncrement(
+        //    dayProgressNs, dayLengthNs, roundingMode).
+        let rounded_day_ns =
+            round_time_duration_to_increment(day_progress_ns, day_length_ns, rounding_mode)
+                .unwrap();
+        // j. Let epochNanoseconds be AddTimeDurationToEpochNanoseconds(
+        //    roundedDayNs, startNs).
+        return Ok(start_ns + rounded_day_ns);
+    }
+    // 20. Else,
+    //     a. Let roundResult be RoundISODateTime(isoDateTime,
+    //        roundingIncrement, smallestUnit, roundingMode).
+    let round_result = round_iso_date_time(
+        iso_date_time,
+        rounding_increment,
+        smallest_unit,
+        rounding_mode,
+    );
+    //     b. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone,
+    //        thisNs).
+    let offset_nanoseconds = get_offset_nanoseconds_for(time_zone, this_ns);
+    //     c. Let epochNanoseconds be ? InterpretISODateTimeOffset(
+    //        roundResult.[[ISODate]], roundResult.[[Time]], option,
+    //        offsetNanoseconds, timeZone, compatible, prefer,
+    //        match-exactly).
+    interpret_iso_date_time_offset(
+        round_result.date,
+        Some(round_result.time),
+        OffsetBehaviour::Option,
+        offset_nanoseconds,
+        time_zone,
+        Disambiguation::Compatible,
+        OffsetOption::Prefer,
+        false,
+    )
+}
@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use wtf8::Wtf8Buf;
+
 use crate::SmallString;
 use crate::ecmascript::abstract_operations::testing_and_comparison::is_integral_number;
 use crate::ecmascript::abstract_operations::type_conversion::to_number;
@@ -189,9 +191,9 @@ impl StringConstructor {
                 .bind(gc.nogc())
         };
 
-        let result = std::string::String::from_utf16_lossy(&buf);
+        let result = Wtf8Buf::from_ill_formed_utf16(&buf);
 
-        Ok(String::from_string(agent, result, gc.into_nogc()).into())
+        Ok(String::from_wtf8_buf(agent, result, gc.into_nogc()).into())
     }
 
     /// ### [22.1.2.2 String.fromCodePoint ( ...`codePoints` )](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.fromcodepoint)
@@ -15,6 +15,12 @@ use crate::ecmascript::abstract_operations::type_conversion::{
     try_to_string,
 };
 use crate::ecmascript::types::Primitive;
+#[cfg(feature = "intl")]
+use crate::ecmascript::{
+    abstract_operations::operations_on_objects::construct,
+    builtins::intl::{collator::Collator, collator_objects::collation::compare_strings},
+    types::IntoFunction,
+};
 use crate::engine::TryResult;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
@@ -516,8 +522,8 @@ impl StringPrototype {
         } else {
             // 6. Return the Number value for the numeric value of the code unit at index position
             // within the String S.
-            let ch = s.utf16_char(agent, usize::try_from(position).unwrap());
-            Ok(Value::from(ch as u32))
+            let code_unit = s.utf16_code_unit(agent, usize::try_from(position).unwrap());
+            Ok(Value::from(code_unit))
         }
     }
 
@@ -610,6 +616,8 @@ impl StringPrototype {
                     .into_iter()
                     .map(|arg| arg.get(agent).bind(nogc))
                     .collect::<Vec<_>>();
+                let length = string_args.iter().map(|s| s.len(agent)).sum();
+                agent.check_heap_limit(length, nogc)?;
                 return Ok(String::concat(agent, &string_args, nogc).into_value());
             }
         };
@@ -648,6 +656,8 @@ impl StringPrototype {
         //     a. Let nextString be ? ToString(next).
         //     b. Set R to the string-concatenation of R and nextString.
         // 5. Return R.
+        let length = strings.iter().map(|s| s.len(agent)).sum();
+        agent.check_heap_limit(length, nogc)?;
         Ok(String::concat(agent, &strings, nogc).into_value())
     }
 
@@ -1054,13 +1064,81 @@ impl StringPrototype {
         }
     }
 
+    /// ### [22.1.3.12 String.prototype.localeCompare ( that \[ , reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-string.prototype.localecompare)
+    ///
+    /// With the ECMA-402 API, this is
+    /// [19.1.1 String.prototype.localeCompare ( that \[ , locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-String.prototype.localeCompare).
     fn locale_compare<'gc>(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'gc, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        todo!()
+        let that = args.get(0).scope(agent, gc.nogc());
+        #[cfg(feature = "intl")]
+        let locales = args.get(1).scope(agent, gc.nogc());
+        #[cfg(feature = "intl")]
+        let options = args.get(2).scope(agent, gc.nogc());
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = require_object_coercible(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 2. Let S be ? ToString(O).
+        let s = to_string(agent, o.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let thatValue be ? ToString(that).
+        let that_value = to_string(agent, that.get(agent), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        #[cfg(feature = "intl")]
+        let result = {
+            // 4. Let collator be ? Construct(%Intl.Collator%, « locales,
+            //    options »).
+            let collator = agent.current_realm_record().intrinsics().collator();
+            let collator = construct(
+                agent,
+                collator.into_function(),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    locales.get(agent),
+                    options.get(agent),
+                ])),
+                None,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            let collator = Collator::try_from(collator).unwrap();
+            // 5. Return CompareStrings(collator, S, thatValue).
+            compare_strings(
+                &agent[collator].options,
+                &s.get(agent).as_wtf8(agent).to_string_lossy(),
+                &that_value.get(agent).as_wtf8(agent).to_string_lossy(),
+            )
+        };
+        // 4. Return an implementation-defined value.
+        // NOTE: Canonically equivalent Strings compare as equal. Other
+        // Strings are compared by their NFC normalized code units.
+        #[cfg(not(feature = "intl"))]
+        let result = {
+            let s = s
+                .get(agent)
+                .as_wtf8(agent)
+                .to_string_lossy()
+                .nfc()
+                .collect::<std::string::String>();
+            let that_value = that_value
+                .get(agent)
+                .as_wtf8(agent)
+                .to_string_lossy()
+                .nfc()
+                .collect::<std::string::String>();
+            s.encode_utf16().cmp(that_value.encode_utf16())
+        };
+        Ok(Value::from(match result {
+            core::cmp::Ordering::Less => -1,
+            core::cmp::Ordering::Equal => 0,
+            core::cmp::Ordering::Greater => 1,
+        }))
     }
 
     fn r#match<'gc>(
@@ -1261,6 +1339,10 @@ impl StringPrototype {
             return Ok(s.into_value().unbind());
         }
 
+        agent
+            .check_heap_limit(s.len(agent).saturating_mul(n as usize), gc.nogc())
+            .unbind()?;
+
         // 6. Return the String value that is made from n copies of S appended together.
         Ok(Value::from_string(
             agent,
@@ -2704,6 +2786,8 @@ fn string_pad<'gc>(
     let fill_len = max_len - string_len;
     let fill_string_len = fill_string.utf16_len(agent) as i64;
 
+    agent.check_heap_limit(max_len as usize, gc)?;
+
     // 5. Let truncatedStringFiller be the String value consisting of repeated concatenations of fillString truncated to length fillLen.
     let mut strings = if fill_len == fill_string_len {
         let mut vec = VecDeque::with_capacity(2);
@@ -132,6 +132,15 @@ impl TypedArray<'_> {
     ) -> ArrayBuffer<'a> {
         agent[self].viewed_array_buffer
     }
+
+    /// Returns true if the TypedArray's \[\[ContentType]] is bigint.
+    #[inline]
+    pub(crate) fn is_bigint(self) -> bool {
+        matches!(
+            self,
+            TypedArray::BigInt64Array(_) | TypedArray::BigUint64Array(_)
+        )
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
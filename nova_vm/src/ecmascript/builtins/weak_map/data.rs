@@ -5,25 +5,128 @@
 use crate::{
     ecmascript::types::{OrdinaryObject, Value},
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
+use ahash::AHasher;
+use core::hash::Hasher;
+use hashbrown::{HashTable, hash_table::Entry};
 
 #[derive(Debug, Clone, Default)]
 pub struct WeakMapHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
-    // TODO: This isn't even close to a hashmap; HashMap won't allow inserting
-    // Value as key; f32 isn't hashable. And our f64s are found on the Heap and
-    // require fetching; What we actually should do is more like:
-    // pub(crate) map: HashMap<ValueHash, u32>
-    // pub(crate) key_values: ParallelVec<Option<Value>, Option<Value>>
-    // ValueHash is created using a Value.hash(agent) function and connects to
-    // an index; the index points to a key and value in parallel vector / Vec2.
-    // Note that empty slots are deleted values in the ParallelVec.
+    // The keys are held weakly: A key-value pair is only kept alive while
+    // both the WeakMap and the key are reachable.
     pub(crate) keys: Vec<Value<'a>>,
     pub(crate) values: Vec<Value<'a>>,
-    // TODO: When an non-terminal (start or end) iterator exists for the Map,
-    // the items in the map cannot be compacted.
-    // pub(crate) observed: bool;
+    /// Low-level hash table pointing to keys-values indexes.
+    ///
+    /// Keys of a WeakMap are always objects or non-registered symbols, so
+    /// they compare by identity and are hashed by their heap index.
+    pub(crate) weak_map_data: HashTable<u32>,
+}
+
+/// Hash a WeakMap or WeakSet key by its heap index.
+pub(crate) fn hash_weak_key(key: Value) -> u64 {
+    let mut hasher = AHasher::default();
+    key.try_hash(&mut hasher)
+        .expect("Weak collection key must be an object or a symbol");
+    hasher.finish()
+}
+
+impl WeakMapHeapData<'_> {
+    /// Approximate number of bytes used by each entry: the key, the value,
+    /// and the hash table slot.
+    pub(crate) const ENTRY_BYTES: usize =
+        2 * core::mem::size_of::<Value<'static>>() + core::mem::size_of::<u32>();
+
+    /// Returns the approximate number of bytes used by the entries of the
+    /// WeakMap.
+    pub(crate) fn external_byte_size(&self) -> usize {
+        self.keys.len() * Self::ENTRY_BYTES
+    }
+
+    fn find(&self, key: Value) -> Option<usize> {
+        let key = key.unbind();
+        self.weak_map_data
+            .find(hash_weak_key(key), |index| {
+                self.keys[*index as usize] == key
+            })
+            .map(|index| *index as usize)
+    }
+
+    pub(crate) fn get(&self, key: Value) -> Option<Value<'static>> {
+        self.find(key).map(|index| self.values[index].unbind())
+    }
+
+    pub(crate) fn has(&self, key: Value) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Set the value of `key`. Returns true if a new entry was appended.
+    pub(crate) fn set(&mut self, key: Value, value: Value) -> bool {
+        let key = key.unbind();
+        let Self {
+            keys,
+            values,
+            weak_map_data,
+            ..
+        } = self;
+        let entry = weak_map_data.entry(
+            hash_weak_key(key),
+            |index| keys[*index as usize] == key,
+            |index| hash_weak_key(keys[*index as usize]),
+        );
+        match entry {
+            Entry::Occupied(occupied) => {
+                values[*occupied.get() as usize] = value.unbind();
+                false
+            }
+            Entry::Vacant(vacant) => {
+                vacant.insert(u32::try_from(keys.len()).unwrap());
+                keys.push(key);
+                values.push(value.unbind());
+                true
+            }
+        }
+    }
+
+    pub(crate) fn delete(&mut self, key: Value) -> bool {
+        let key = key.unbind();
+        let Ok(entry) = self.weak_map_data.find_entry(hash_weak_key(key), |index| {
+            self.keys[*index as usize] == key
+        }) else {
+            return false;
+        };
+        let (index, _) = entry.remove();
+        let index = index as usize;
+        self.keys.swap_remove(index);
+        self.values.swap_remove(index);
+        // The last entry was moved into the removed slot.
+        if let Some(&moved_key) = self.keys.get(index) {
+            let last_index = self.keys.len() as u32;
+            *self
+                .weak_map_data
+                .find_mut(hash_weak_key(moved_key), |index| *index == last_index)
+                .unwrap() = index as u32;
+        }
+        true
+    }
+
+    /// Rebuild the hash table after keys have been removed or have changed
+    /// identity.
+    fn rehash(&mut self) {
+        let Self {
+            keys,
+            weak_map_data,
+            ..
+        } = self;
+        weak_map_data.clear();
+        for (index, key) in keys.iter().enumerate() {
+            weak_map_data.insert_unique(hash_weak_key(*key), index as u32, |index| {
+                hash_weak_key(keys[*index as usize])
+            });
+        }
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -41,20 +144,48 @@ unsafe impl Bindable for WeakMapHeapData<'_> {
     }
 }
 
+impl WeakMapHeapData<'static> {
+    /// Mark the values of all entries whose keys have been marked.
+    ///
+    /// This must be called repeatedly until no new values are marked, as
+    /// marking a value may in turn make other keys reachable.
+    pub(crate) fn mark_ephemeron_values(&self, bits: &HeapBits, queues: &mut WorkQueues) {
+        for (key, value) in self.keys.iter().zip(self.values.iter()) {
+            if bits.is_marked(*key) && !bits.is_marked(*value) {
+                value.mark_values(queues);
+            }
+        }
+    }
+
+    /// Remove all entries whose keys were not marked.
+    pub(crate) fn remove_dead_entries(&mut self, bits: &HeapBits) {
+        let len = self.keys.len();
+        let mut index = 0;
+        while index < self.keys.len() {
+            if bits.is_marked(self.keys[index]) {
+                index += 1;
+            } else {
+                self.keys.swap_remove(index);
+                self.values.swap_remove(index);
+            }
+        }
+        if self.keys.len() != len {
+            self.rehash();
+        }
+    }
+}
+
 impl HeapMarkAndSweep for WeakMapHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             object_index,
-            keys,
-            values,
+            keys: _,
+            values: _,
+            weak_map_data: _,
         } = self;
+        // Note: Keys and values are marked ephemerally by the GC, see
+        // mark_ephemeron_values.
         object_index.mark_values(queues);
-        for ele in keys {
-            ele.mark_values(queues);
-        }
-        for ele in values {
-            ele.mark_values(queues);
-        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -62,13 +193,21 @@ impl HeapMarkAndSweep for WeakMapHeapData<'static> {
             object_index,
             keys,
             values,
+            weak_map_data: _,
         } = self;
         object_index.sweep_values(compactions);
-        for ele in keys {
+        let mut keys_moved = false;
+        for ele in keys.iter_mut() {
+            let old_key = *ele;
             ele.sweep_values(compactions);
+            keys_moved |= *ele != old_key;
         }
         for ele in values {
             ele.sweep_values(compactions);
         }
+        // Keys are hashed by their heap index.
+        if keys_moved {
+            self.rehash();
+        }
     }
 }
@@ -3,14 +3,124 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::{
+        builtins::weak_map::data::hash_weak_key,
+        types::{OrdinaryObject, Value},
+    },
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
+use hashbrown::{HashTable, hash_table::Entry};
 
 #[derive(Debug, Clone, Default)]
 pub struct WeakSetHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    // The values are held weakly and are removed once they become otherwise
+    // unreachable.
+    pub(crate) values: Vec<Value<'a>>,
+    /// Low-level hash table pointing to value indexes.
+    ///
+    /// Values of a WeakSet are always objects or non-registered symbols, so
+    /// they compare by identity and are hashed by their heap index.
+    pub(crate) weak_set_data: HashTable<u32>,
+}
+
+impl WeakSetHeapData<'_> {
+    /// Approximate number of bytes used by each entry: the value and the
+    /// hash table slot.
+    pub(crate) const ENTRY_BYTES: usize =
+        core::mem::size_of::<Value<'static>>() + core::mem::size_of::<u32>();
+
+    /// Returns the approximate number of bytes used by the entries of the
+    /// WeakSet.
+    pub(crate) fn external_byte_size(&self) -> usize {
+        self.values.len() * Self::ENTRY_BYTES
+    }
+
+    fn find(&self, value: Value) -> Option<usize> {
+        let value = value.unbind();
+        self.weak_set_data
+            .find(hash_weak_key(value), |index| {
+                self.values[*index as usize] == value
+            })
+            .map(|index| *index as usize)
+    }
+
+    pub(crate) fn has(&self, value: Value) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Add `value` to the set. Returns true if a new entry was appended.
+    pub(crate) fn add(&mut self, value: Value) -> bool {
+        let value = value.unbind();
+        let Self {
+            values,
+            weak_set_data,
+            ..
+        } = self;
+        let entry = weak_set_data.entry(
+            hash_weak_key(value),
+            |index| values[*index as usize] == value,
+            |index| hash_weak_key(values[*index as usize]),
+        );
+        let Entry::Vacant(vacant) = entry else {
+            return false;
+        };
+        vacant.insert(u32::try_from(values.len()).unwrap());
+        values.push(value);
+        true
+    }
+
+    pub(crate) fn delete(&mut self, value: Value) -> bool {
+        let value = value.unbind();
+        let Ok(entry) = self
+            .weak_set_data
+            .find_entry(hash_weak_key(value), |index| {
+                self.values[*index as usize] == value
+            })
+        else {
+            return false;
+        };
+        let (index, _) = entry.remove();
+        let index = index as usize;
+        self.values.swap_remove(index);
+        // The last value was moved into the removed slot.
+        if let Some(&moved_value) = self.values.get(index) {
+            let last_index = self.values.len() as u32;
+            *self
+                .weak_set_data
+                .find_mut(hash_weak_key(moved_value), |index| *index == last_index)
+                .unwrap() = index as u32;
+        }
+        true
+    }
+
+    /// Rebuild the hash table after values have been removed or have changed
+    /// identity.
+    fn rehash(&mut self) {
+        let Self {
+            values,
+            weak_set_data,
+            ..
+        } = self;
+        weak_set_data.clear();
+        for (index, value) in values.iter().enumerate() {
+            weak_set_data.insert_unique(hash_weak_key(*value), index as u32, |index| {
+                hash_weak_key(values[*index as usize])
+            });
+        }
+    }
+}
+
+impl WeakSetHeapData<'static> {
+    /// Remove all values that were not marked.
+    pub(crate) fn remove_dead_entries(&mut self, bits: &HeapBits) {
+        let len = self.values.len();
+        self.values.retain(|value| bits.is_marked(*value));
+        if self.values.len() != len {
+            self.rehash();
+        }
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -30,10 +140,31 @@ unsafe impl Bindable for WeakSetHeapData<'_> {
 
 impl HeapMarkAndSweep for WeakSetHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        self.object_index.mark_values(queues);
+        let Self {
+            object_index,
+            values: _,
+            weak_set_data: _,
+        } = self;
+        // Note: Values are held weakly and are not marked.
+        object_index.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        self.object_index.sweep_values(compactions);
+        let Self {
+            object_index,
+            values,
+            weak_set_data: _,
+        } = self;
+        object_index.sweep_values(compactions);
+        let mut values_moved = false;
+        for ele in values.iter_mut() {
+            let old_value = *ele;
+            ele.sweep_values(compactions);
+            values_moved |= *ele != old_value;
+        }
+        // Values are hashed by their heap index.
+        if values_moved {
+            self.rehash();
+        }
     }
 }
@@ -7,25 +7,121 @@
 //! - This is inspired by and/or copied from Kiesel engine:
 //!   Copyright (c) 2023-2024 Linus Groh
 
-use ahash::AHashMap;
-
 use super::{
     environments::{get_identifier_reference, try_get_identifier_reference}, initialize_default_realm, initialize_host_defined_realm, Environment, ExecutionContext, GlobalEnvironment, PrivateEnvironment, RealmRecord, Realm
 };
 use crate::{
     ecmascript::{
-        abstract_operations::type_conversion::to_string,
-        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::ErrorHeapData, promise::Promise},
+        abstract_operations::{operations_on_objects::get, type_conversion::to_string},
+        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::{capture_stack_trace, ErrorHeapData}, finalization_registry::FinalizationRegistryCleanupJob, fundamental_objects::symbol_objects::global_symbol_registry::{key_for_symbol, GlobalSymbolRegistry}, promise::Promise},
         scripts_and_modules::{script::{parse_script, script_evaluation}, source_code::SourceCode, ScriptOrModule},
-        types::{Function, IntoValue, Object, Reference, String, Symbol, Value, ValueRootRepr},
-    }, engine::{context::{Bindable, GcScope, NoGcScope}, rootable::{HeapRootCollectionData, HeapRootData, HeapRootRef, Rootable}, TryResult, Vm}, heap::{heap_gc::heap_gc, CreateHeapData, HeapMarkAndSweep, PrimitiveHeapIndexable}, Heap
+        types::{Function, IntoValue, Object, Reference, String, Value, ValueRootRepr, BUILTIN_STRING_MEMORY},
+    }, engine::{context::{Bindable, GcScope, NoGcScope}, rootable::{HeapRootCollectionData, HeapRootData, HeapRootRef, Rootable}, structured_clone::{structured_deserialize, structured_serialize, SerializedValue}, TryResult, Vm, VmFrame}, heap::{heap_gc::heap_gc, CreateHeapData, HeapMarkAndSweep, PrimitiveHeapIndexable}, Heap
 };
 use core::{any::Any, cell::RefCell, ptr::NonNull};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+#[cfg(feature = "date")]
+use crate::ecmascript::builtins::numbers_and_dates::date_objects::time_zone::SystemTimeZone;
+#[cfg(feature = "array-buffer")]
+use crate::engine::structured_clone::structured_serialize_with_transfer;
 
 #[derive(Debug, Default)]
 pub struct Options {
     pub disable_gc: bool,
     pub print_internals: bool,
+    /// Overrides the host time zone used for local time: a time zone
+    /// identifier such as "Europe/Helsinki", a UTC offset string such as
+    /// "+02:00", or a POSIX TZ string. If not given, the `TZ` environment
+    /// variable and the system time zone are used.
+    pub time_zone: Option<std::string::String>,
+    /// Maximum number of bytecode instructions that a single
+    /// [`GcAgent::run_in_realm`] call may execute. When the budget is
+    /// exhausted, execution is terminated and `run_in_realm` returns
+    /// [`ExecutionTerminated::BudgetExhausted`]. The budget is checked on
+    /// function calls and backward jumps, so a few instructions past the
+    /// budget may be executed before termination.
+    pub execution_budget: Option<u64>,
+    /// Maximum depth of the execution context stack. Calls that would exceed
+    /// it throw a RangeError. Defaults to [`DEFAULT_MAX_CALL_STACK_DEPTH`].
+    pub max_call_stack_depth: Option<usize>,
+    /// Maximum number of bytes of native stack that a single
+    /// [`GcAgent::run_in_realm`] call may use before calls, Proxy traps, and
+    /// JSON serialization throw a RangeError. Defaults to
+    /// [`DEFAULT_NATIVE_STACK_LIMIT`].
+    ///
+    /// The thread running the Agent must have some headroom beyond this
+    /// limit available on its stack.
+    pub native_stack_limit: Option<usize>,
+    /// Maximum number of bytes that the Agent heap may use. When an
+    /// allocation would grow the heap beyond this limit, the
+    /// [`HostHooks::near_heap_limit`] hook is called, and a RangeError is
+    /// thrown if the hook does not raise the limit.
+    ///
+    /// When the limit is found to be exceeded during execution, garbage
+    /// collection is performed before the hook is called. This requires the
+    /// `interleaved-gc` feature: without it, garbage could not be collected
+    /// during execution and would count towards the limit.
+    #[cfg(feature = "interleaved-gc")]
+    pub heap_limit: Option<usize>,
+}
+
+/// Default value of [`Options::max_call_stack_depth`].
+pub const DEFAULT_MAX_CALL_STACK_DEPTH: usize = 10_000;
+
+/// Default value of [`Options::native_stack_limit`].
+///
+/// Threads spawned with [`std::thread::spawn`] get a 2 MiB stack by default,
+/// and main threads commonly get 8 MiB. This default is half of the smaller
+/// one, leaving the rest as a safety margin for the native frames between
+/// limit checks and for the host's own frames. Hosts running the Agent on a
+/// smaller stack must set a lower limit.
+pub const DEFAULT_NATIVE_STACK_LIMIT: usize = 1024 * 1024;
+
+/// Reason for an uncatchable termination of execution, returned by
+/// [`GcAgent::run_in_realm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionTerminated {
+    /// The [`Options::execution_budget`] was exhausted.
+    BudgetExhausted,
+    /// Execution was interrupted through an [`InterruptHandle`].
+    Interrupted,
+}
+
+impl core::fmt::Display for ExecutionTerminated {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExecutionTerminated::BudgetExhausted => f.write_str("Execution budget exhausted"),
+            ExecutionTerminated::Interrupted => f.write_str("Execution interrupted"),
+        }
+    }
+}
+
+impl std::error::Error for ExecutionTerminated {}
+
+/// Thread-safe handle for interrupting the execution of a [`GcAgent`] from
+/// another thread.
+///
+/// The handle can be obtained with [`GcAgent::interrupt_handle`].
+#[derive(Debug, Clone)]
+pub struct InterruptHandle(Arc<AtomicBool>);
+
+impl InterruptHandle {
+    /// Request the Agent to terminate execution. If the Agent is not
+    /// currently executing code, the next [`GcAgent::run_in_realm`] call is
+    /// terminated as soon as it starts executing code.
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Returns the current native stack address.
+#[inline(always)]
+fn native_stack_address() -> usize {
+    let marker = 0u8;
+    core::hint::black_box(&raw const marker).addr()
 }
 
 pub type JsResult<'a, T> = core::result::Result<T, JsError<'a>>;
@@ -46,6 +142,20 @@ impl<'a> JsError<'a> {
     pub fn to_string<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> String<'gc> {
         to_string(agent, self.0, gc).unwrap()
     }
+
+    /// Get the `stack` string of the thrown value, if it is an object with a
+    /// String-valued `stack` property.
+    pub fn stack<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> Option<String<'gc>> {
+        let object = Object::try_from(self.0).ok()?;
+        let stack = get(
+            agent,
+            object.unbind(),
+            BUILTIN_STRING_MEMORY.stack.into(),
+            gc,
+        )
+        .ok()?;
+        String::try_from(stack).ok()
+    }
 }
 
 // SAFETY: Property implemented as a recursive bind.
@@ -103,6 +213,7 @@ impl HeapMarkAndSweep for JsError<'static> {
 pub(crate) enum InnerJob {
     PromiseResolveThenable(PromiseResolveThenableJob),
     PromiseReaction(PromiseReactionJob),
+    FinalizationRegistryCleanup(FinalizationRegistryCleanupJob),
 }
 
 pub struct Job {
@@ -132,6 +243,7 @@ impl Job {
         let result = match self.inner {
             InnerJob::PromiseResolveThenable(job) => job.run(agent, gc),
             InnerJob::PromiseReaction(job) => job.run(agent, gc),
+            InnerJob::FinalizationRegistryCleanup(job) => job.run(agent, gc),
         };
 
         if pushed_context {
@@ -167,6 +279,18 @@ pub trait HostHooks: core::fmt::Debug {
     /// ### [9.5.5 HostEnqueuePromiseJob ( job, realm )](https://tc39.es/ecma262/#sec-hostenqueuepromisejob)
     fn enqueue_promise_job(&self, job: Job);
 
+    /// ### [9.9.4.1 HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )](https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry)
+    ///
+    /// Called after garbage collection if any FinalizationRegistry has cells
+    /// whose targets were collected. The host should run the job at some
+    /// point in the future when no ECMAScript code is executing; running it
+    /// calls the registries' cleanup callbacks for each collected target.
+    /// The job holds no references into the heap and may be dropped freely.
+    fn host_enqueue_finalization_registry_cleanup_job(&self, _job: Job) {
+        // By default cleanup callbacks are never called, which the
+        // specification permits.
+    }
+
     /// ### [27.2.1.9 HostPromiseRejectionTracker ( promise, operation )](https://tc39.es/ecma262/#sec-host-promise-rejection-tracker)
     fn promise_rejection_tracker(
         &self,
@@ -176,6 +300,16 @@ pub trait HostHooks: core::fmt::Debug {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// Called when the heap is about to grow beyond the limit set in
+    /// [`Options::heap_limit`]. `required` is the heap size in bytes that
+    /// the pending allocation needs.
+    ///
+    /// Returns the new heap limit. If it is not at least `required`, a
+    /// RangeError is thrown. By default the current limit is kept.
+    fn near_heap_limit(&self, current_limit: usize, _required: usize) -> usize {
+        current_limit
+    }
+
     /// Get access to the Host data, useful to share state between calls of built-in functions.
     ///
     /// Note: This will panic if not implemented manually.
@@ -277,7 +411,17 @@ impl GcAgent {
         }
     }
 
-    pub fn run_in_realm<F, R>(&mut self, realm: &RealmRoot, func: F) -> R
+    /// Run the given function in the given Realm.
+    ///
+    /// If execution is terminated, either because the
+    /// [`Options::execution_budget`] was exhausted or because execution was
+    /// interrupted through an [`InterruptHandle`], the function's result is
+    /// discarded and the reason for the termination is returned instead.
+    pub fn run_in_realm<F, R>(
+        &mut self,
+        realm: &RealmRoot,
+        func: F,
+    ) -> Result<R, ExecutionTerminated>
     where
         F: for<'agent, 'gc, 'scope> FnOnce(&'agent mut Agent, GcScope<'gc, 'scope>) -> R,
     {
@@ -290,11 +434,28 @@ impl GcAgent {
             .as_ref()
             .expect(error_message);
         assert!(self.agent.execution_context_stack.is_empty());
+        self.agent.remaining_execution_budget = self.agent.options.execution_budget;
+        self.agent.native_stack_base = Some(native_stack_address());
         let result = self.agent.run_in_realm(realm, func);
+        self.agent.native_stack_base = None;
         assert!(self.agent.execution_context_stack.is_empty());
         assert!(self.agent.vm_stack.is_empty());
         self.agent.stack_refs.borrow_mut().clear();
-        result
+        if let Some(termination) = self.agent.termination.take() {
+            if termination == ExecutionTerminated::Interrupted {
+                self.agent
+                    .interrupt_requested
+                    .store(false, Ordering::Relaxed);
+            }
+            return Err(termination);
+        }
+        Ok(result)
+    }
+
+    /// Get a handle that can be used to interrupt execution from another
+    /// thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(self.agent.interrupt_requested.clone())
     }
 
     pub fn gc(&mut self) {
@@ -314,7 +475,7 @@ pub struct Agent {
     pub(crate) heap: Heap,
     pub(crate) options: Options,
     pub(crate) symbol_id: usize,
-    pub(crate) global_symbol_registry: AHashMap<&'static str, Symbol<'static>>,
+    pub(crate) global_symbol_registry: GlobalSymbolRegistry,
     pub(crate) host_hooks: &'static dyn HostHooks,
     pub(crate) execution_context_stack: Vec<ExecutionContext>,
     /// Temporary storage for on-stack heap roots.
@@ -326,23 +487,60 @@ pub struct Agent {
     pub(crate) stack_ref_collections: RefCell<Vec<HeapRootCollectionData>>,
     /// Temporary storage for on-stack VMs.
     pub(crate) vm_stack: Vec<NonNull<Vm>>,
+    /// Locations of currently executing VMs, used for capturing stack traces.
+    pub(crate) vm_frames: Vec<VmFrame>,
+    /// Remaining number of instructions that may be executed in the current
+    /// run.
+    pub(crate) remaining_execution_budget: Option<u64>,
+    /// Set by an [`InterruptHandle`] to request termination of execution.
+    pub(crate) interrupt_requested: Arc<AtomicBool>,
+    /// Set when execution is being terminated. Errors cannot be caught while
+    /// execution is being terminated.
+    pub(crate) termination: Option<ExecutionTerminated>,
+    /// Native stack address at the start of the current run, used for
+    /// measuring native stack usage.
+    pub(crate) native_stack_base: Option<usize>,
+    /// Current heap limit: initially [`Options::heap_limit`], but the host
+    /// can raise it through [`HostHooks::near_heap_limit`].
+    pub(crate) heap_limit: Option<usize>,
+    /// The host time zone, resolved on first use.
+    #[cfg(feature = "date")]
+    pub(crate) system_time_zone: core::cell::OnceCell<SystemTimeZone>,
 }
 
 impl Agent {
     pub(crate) fn new(options: Options, host_hooks: &'static dyn HostHooks) -> Self {
         Self {
             heap: Heap::new(),
+            #[cfg(feature = "interleaved-gc")]
+            heap_limit: options.heap_limit,
+            #[cfg(not(feature = "interleaved-gc"))]
+            heap_limit: None,
             options,
             symbol_id: 0,
-            global_symbol_registry: AHashMap::default(),
+            global_symbol_registry: GlobalSymbolRegistry::default(),
             host_hooks,
             execution_context_stack: Vec::new(),
             stack_refs: RefCell::new(Vec::with_capacity(64)),
             stack_ref_collections: RefCell::new(Vec::with_capacity(32)),
             vm_stack: Vec::with_capacity(16),
+            vm_frames: Vec::with_capacity(16),
+            remaining_execution_budget: None,
+            interrupt_requested: Arc::new(AtomicBool::new(false)),
+            termination: None,
+            native_stack_base: None,
+            #[cfg(feature = "date")]
+            system_time_zone: core::cell::OnceCell::new(),
         }
     }
 
+    /// Get the host time zone used for local time.
+    #[cfg(feature = "date")]
+    pub(crate) fn system_time_zone(&self) -> &SystemTimeZone {
+        self.system_time_zone
+            .get_or_init(|| SystemTimeZone::new(self.options.time_zone.as_deref()))
+    }
+
     fn get_created_realm_root(&mut self) -> Realm<'static> {
         assert!(!self.execution_context_stack.is_empty());
         let identifier = self.current_realm_id_internal();
@@ -481,8 +679,12 @@ impl Agent {
         gc: NoGcScope<'a, '_>,
     ) -> Value<'a> {
         let message = String::from_static_str(self, message, gc).unbind();
+        let stack = capture_stack_trace(self, 0, gc).unbind();
         self.heap
-            .create(ErrorHeapData::new(kind, Some(message), None))
+            .create(ErrorHeapData {
+                stack,
+                ..ErrorHeapData::new(kind, Some(message), None)
+            })
             .into_value()
     }
 
@@ -506,9 +708,13 @@ impl Agent {
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
         let message = String::from_string(self, message, gc).unbind();
+        let stack = capture_stack_trace(self, 0, gc).unbind();
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message), None))
+                .create(ErrorHeapData {
+                    stack,
+                    ..ErrorHeapData::new(kind, Some(message), None)
+                })
                 .into_value(),
         )
     }
@@ -519,14 +725,134 @@ impl Agent {
         message: String,
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
+        let message = message.bind(gc);
+        let stack = capture_stack_trace(self, 0, gc);
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message.unbind()), None))
+                .create(ErrorHeapData {
+                    stack: stack.unbind(),
+                    ..ErrorHeapData::new(kind, Some(message.unbind()), None)
+                })
                 .into_value()
                 .bind(gc),
         )
     }
 
+    /// Check that there is room on the call stack for another call, or for
+    /// another level of native recursion. Returns a RangeError if the
+    /// maximum call stack depth or the native stack limit is reached.
+    #[inline]
+    pub(crate) fn check_call_stack_limit<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        let max_depth = self
+            .options
+            .max_call_stack_depth
+            .unwrap_or(DEFAULT_MAX_CALL_STACK_DEPTH);
+        let native_stack_limit = self
+            .options
+            .native_stack_limit
+            .unwrap_or(DEFAULT_NATIVE_STACK_LIMIT);
+        let native_stack_used = self
+            .native_stack_base
+            .map_or(0, |base| base.abs_diff(native_stack_address()));
+        if self.execution_context_stack.len() >= max_depth
+            || native_stack_used >= native_stack_limit
+        {
+            return Err(self.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Maximum call stack size exceeded",
+                gc,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns true if allocating `additional` bytes would grow the heap
+    /// beyond the heap limit.
+    pub(crate) fn exceeds_heap_limit(&self, additional: usize) -> bool {
+        self.heap_limit
+            .is_some_and(|limit| self.heap.heap_size().saturating_add(additional) > limit)
+    }
+
+    /// Returns true if the heap has grown to within a quarter of the heap
+    /// limit. Garbage allocated between two safe points must fit in that
+    /// quarter for it to be collected before the limit is reached.
+    #[cfg(feature = "interleaved-gc")]
+    pub(crate) fn is_near_heap_limit(&self) -> bool {
+        self.heap_limit
+            .is_some_and(|limit| self.heap.heap_size() > limit - limit / 4)
+    }
+
+    /// Check that `additional` bytes can be allocated without growing the
+    /// heap beyond the heap limit. If they cannot, the host is asked to raise
+    /// the limit, and a RangeError is thrown if it does not.
+    pub(crate) fn check_heap_limit<'a>(
+        &mut self,
+        additional: usize,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let Some(limit) = self.heap_limit else {
+            return Ok(());
+        };
+        let required = self.heap.heap_size().saturating_add(additional);
+        if required <= limit {
+            return Ok(());
+        }
+        let limit = self.host_hooks.near_heap_limit(limit, required);
+        self.heap_limit = Some(limit);
+        if required <= limit {
+            return Ok(());
+        }
+        Err(self.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Out of memory",
+            gc,
+        ))
+    }
+
+    /// Returns true if execution is being terminated. Errors thrown while
+    /// execution is being terminated cannot be caught by ECMAScript code.
+    pub(crate) fn is_terminating(&self) -> bool {
+        self.termination.is_some()
+    }
+
+    /// Charge `instructions` executed instructions against the execution
+    /// budget and check for interrupt requests. Returns an uncatchable error
+    /// if execution must be terminated.
+    #[inline]
+    pub(crate) fn check_termination<'a>(
+        &mut self,
+        instructions: u64,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        if let Some(budget) = self.remaining_execution_budget.as_mut() {
+            let Some(remaining) = budget.checked_sub(instructions) else {
+                *budget = 0;
+                return Err(self.terminate(ExecutionTerminated::BudgetExhausted, gc));
+            };
+            *budget = remaining;
+        }
+        if self.interrupt_requested.load(Ordering::Relaxed) {
+            return Err(self.terminate(ExecutionTerminated::Interrupted, gc));
+        }
+        Ok(())
+    }
+
+    /// Charge `instructions` executed instructions against the execution
+    /// budget without checking for termination. An exhausted budget is
+    /// noticed at the next check.
+    #[inline]
+    pub(crate) fn charge_execution_budget(&mut self, instructions: u64) {
+        if let Some(budget) = self.remaining_execution_budget.as_mut() {
+            *budget = budget.saturating_sub(instructions);
+        }
+    }
+
+    #[cold]
+    fn terminate<'a>(&mut self, reason: ExecutionTerminated, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        let reason = *self.termination.get_or_insert(reason);
+        self.throw_exception(ExceptionType::Error, reason.to_string(), gc)
+    }
+
     pub(crate) fn running_execution_context(&self) -> &ExecutionContext {
         self.execution_context_stack.last().unwrap()
     }
@@ -661,6 +987,40 @@ impl Agent {
         };
         script_evaluation(self, script.unbind(), gc)
     }
+
+    /// Serialize a value with the HTML StructuredSerialize algorithm. The
+    /// result holds no references to this Agent's heap and can be
+    /// deserialized in any Realm of any Agent.
+    pub fn structured_serialize<'gc>(
+        &mut self,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, SerializedValue> {
+        structured_serialize(self, value, gc)
+    }
+
+    /// Serialize a value with the HTML StructuredSerializeWithTransfer
+    /// algorithm. The ArrayBuffers in the transfer list are detached and
+    /// their data is moved into the result.
+    #[cfg(feature = "array-buffer")]
+    pub fn structured_serialize_with_transfer<'gc>(
+        &mut self,
+        value: Value,
+        transfer_list: &[Value],
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, SerializedValue> {
+        structured_serialize_with_transfer(self, value, transfer_list, gc)
+    }
+
+    /// Deserialize a value with the HTML StructuredDeserialize algorithm,
+    /// creating its objects in the current Realm.
+    pub fn structured_deserialize<'gc>(
+        &mut self,
+        serialized: &SerializedValue,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        structured_deserialize(self, serialized, gc)
+    }
 }
 
 /// ### [9.4.1 GetActiveScriptOrModule ()](https://tc39.es/ecma262/#sec-getactivescriptormodule)
@@ -756,6 +1116,26 @@ pub(crate) fn resolve_binding<'a, 'b>(
     get_identifier_reference(agent, Some(env.unbind()), name.unbind(), strict, gc)
 }
 
+/// ### [9.13 CanBeHeldWeakly ( v )](https://tc39.es/ecma262/#sec-canbeheldweakly)
+///
+/// The abstract operation CanBeHeldWeakly takes argument v (an ECMAScript
+/// language value) and returns a Boolean. It returns true if and only if v is
+/// suitable for use as a weak reference. Only values that are suitable for use
+/// as a weak reference may be a key of a WeakMap, an element of a WeakSet, the
+/// target of a WeakRef, or one of the targets of a FinalizationRegistry.
+pub(crate) fn can_be_held_weakly(agent: &Agent, v: Value) -> bool {
+    // 1. If v is an Object, return true.
+    if Object::try_from(v).is_ok() {
+        return true;
+    }
+    // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+    if let Value::Symbol(symbol) = v {
+        return key_for_symbol(agent, symbol).is_none();
+    }
+    // 3. Return false.
+    false
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExceptionType {
     Error,
@@ -980,6 +980,22 @@ pub(crate) fn get_identifier_reference<'a, 'b>(
 }
 
 impl Environments {
+    /// Returns the number of bytes used by environment records.
+    pub(crate) fn heap_size(&self) -> usize {
+        let Environments {
+            declarative,
+            function,
+            global,
+            object,
+            private,
+        } = self;
+        core::mem::size_of_val(declarative.as_slice())
+            + core::mem::size_of_val(function.as_slice())
+            + core::mem::size_of_val(global.as_slice())
+            + core::mem::size_of_val(object.as_slice())
+            + core::mem::size_of_val(private.as_slice())
+    }
+
     pub(crate) fn push_declarative_environment<'a>(
         &mut self,
         env: DeclarativeEnvironmentRecord,
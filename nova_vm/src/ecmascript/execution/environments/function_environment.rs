@@ -436,7 +436,7 @@ impl FunctionEnvironment<'_> {
             Function::BuiltinConstructorFunction(_) => unreachable!(),
             Function::BuiltinPromiseResolvingFunction(_) => unreachable!(),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
         }
     }
 
@@ -462,7 +462,7 @@ impl FunctionEnvironment<'_> {
             Function::BuiltinConstructorFunction(_) => unreachable!(),
             Function::BuiltinPromiseResolvingFunction(_) => unreachable!(),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
         };
         // 3. Assert: home is an ordinary object.
         let home = OrdinaryObject::try_from(home).unwrap();
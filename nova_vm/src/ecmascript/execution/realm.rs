@@ -656,6 +656,24 @@ pub(crate) fn set_default_global_bindings<'a>(
 
         // 19.4.4 Reflect
         define_property!(intrinsic Reflect, reflect);
+
+        // ECMA-402 8 The Intl Object
+        #[cfg(feature = "intl")]
+        define_property!(intrinsic Intl, intl);
+
+        // Temporal 1 The Temporal Object
+        #[cfg(feature = "proposal-temporal")]
+        define_property!(intrinsic Temporal, temporal);
+    }
+
+    // B.2.1 Additional Properties of the Global Object
+    #[cfg(feature = "annex-b-global")]
+    {
+        // B.2.1.1 escape ( string )
+        define_property!(intrinsic escape, escape);
+
+        // B.2.1.2 unescape ( string )
+        define_property!(intrinsic unescape, unescape);
     }
 
     // 3. Return global.
@@ -3,6 +3,25 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use super::Realm;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::{
+    collator_objects::{
+        collator_constructor::CollatorConstructor, collator_prototype::CollatorPrototype,
+    },
+    date_time_format_objects::{
+        date_time_format_constructor::DateTimeFormatConstructor,
+        date_time_format_prototype::DateTimeFormatPrototype,
+    },
+    intl_object::IntlObject,
+    number_format_objects::{
+        number_format_constructor::NumberFormatConstructor,
+        number_format_prototype::NumberFormatPrototype,
+    },
+    plural_rules_objects::{
+        plural_rules_constructor::PluralRulesConstructor,
+        plural_rules_prototype::PluralRulesPrototype,
+    },
+};
 #[cfg(feature = "set")]
 use crate::ecmascript::builtins::keyed_collections::set_objects::{
     set_constructor::SetConstructor,
@@ -37,6 +56,31 @@ use crate::ecmascript::builtins::structured_data::shared_array_buffer_objects::{
     shared_array_buffer_constructor::SharedArrayBufferConstructor,
     shared_array_buffer_prototype::SharedArrayBufferPrototype,
 };
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration_objects::{
+        duration_constructor::DurationConstructor, duration_prototype::DurationPrototype,
+    },
+    instant_objects::{
+        instant_constructor::InstantConstructor, instant_prototype::InstantPrototype,
+    },
+    now_object::NowObject,
+    plain_date_objects::{
+        plain_date_constructor::PlainDateConstructor, plain_date_prototype::PlainDatePrototype,
+    },
+    plain_date_time_objects::{
+        plain_date_time_constructor::PlainDateTimeConstructor,
+        plain_date_time_prototype::PlainDateTimePrototype,
+    },
+    plain_time_objects::{
+        plain_time_constructor::PlainTimeConstructor, plain_time_prototype::PlainTimePrototype,
+    },
+    temporal_object::TemporalObject,
+    zoned_date_time_objects::{
+        zoned_date_time_constructor::ZonedDateTimeConstructor,
+        zoned_date_time_prototype::ZonedDateTimePrototype,
+    },
+};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::text_processing::regexp_objects::{
     regexp_constructor::RegExpConstructor, regexp_prototype::RegExpPrototype,
@@ -80,7 +124,9 @@ use crate::{
                 iteration::{
                     async_from_sync_iterator_prototype::AsyncFromSyncIteratorPrototype,
                     async_iterator_prototype::AsyncIteratorPrototype,
+                    iterator_helper_prototype::IteratorHelperPrototype,
                     iterator_prototype::IteratorPrototype,
+                    wrap_for_valid_iterator_prototype::WrapForValidIteratorPrototype,
                 },
                 promise_objects::{
                     promise_constructor::PromiseConstructor, promise_prototype::PromisePrototype,
@@ -178,10 +224,14 @@ pub enum ProtoIntrinsics {
     #[cfg(feature = "array-buffer")]
     BigUint64Array,
     Boolean,
+    #[cfg(feature = "intl")]
+    Collator,
     #[cfg(feature = "array-buffer")]
     DataView,
     #[cfg(feature = "date")]
     Date,
+    #[cfg(feature = "intl")]
+    DateTimeFormat,
     Error,
     EvalError,
     FinalizationRegistry,
@@ -201,10 +251,15 @@ pub enum ProtoIntrinsics {
     #[cfg(feature = "array-buffer")]
     Int8Array,
     Iterator,
+    IteratorHelper,
     Map,
     MapIterator,
     Number,
+    #[cfg(feature = "intl")]
+    NumberFormat,
     Object,
+    #[cfg(feature = "intl")]
+    PluralRules,
     Promise,
     RangeError,
     ReferenceError,
@@ -219,6 +274,18 @@ pub enum ProtoIntrinsics {
     String,
     Symbol,
     SyntaxError,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalDuration,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalInstant,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDate,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDateTime,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainTime,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalZonedDateTime,
     TypeError,
     #[cfg(feature = "array-buffer")]
     Uint16Array,
@@ -235,6 +302,7 @@ pub enum ProtoIntrinsics {
     WeakRef,
     #[cfg(feature = "weak-refs")]
     WeakSet,
+    WrapForValidIterator,
 }
 
 impl Intrinsics {
@@ -355,6 +423,8 @@ impl Intrinsics {
         FinalizationRegistryPrototype::create_intrinsic(agent, realm);
         FinalizationRegistryConstructor::create_intrinsic(agent, realm);
         IteratorPrototype::create_intrinsic(agent, realm);
+        IteratorHelperPrototype::create_intrinsic(agent, realm);
+        WrapForValidIteratorPrototype::create_intrinsic(agent, realm);
         AsyncIteratorPrototype::create_intrinsic(agent, realm);
         AsyncFromSyncIteratorPrototype::create_intrinsic(agent, realm);
         PromisePrototype::create_intrinsic(agent, realm);
@@ -368,6 +438,35 @@ impl Intrinsics {
         AsyncFunctionPrototype::create_intrinsic(agent, realm);
         AsyncFunctionConstructor::create_intrinsic(agent, realm);
         ReflectObject::create_intrinsic(agent, realm);
+        #[cfg(feature = "intl")]
+        {
+            CollatorPrototype::create_intrinsic(agent, realm);
+            CollatorConstructor::create_intrinsic(agent, realm);
+            DateTimeFormatPrototype::create_intrinsic(agent, realm);
+            DateTimeFormatConstructor::create_intrinsic(agent, realm);
+            NumberFormatPrototype::create_intrinsic(agent, realm);
+            NumberFormatConstructor::create_intrinsic(agent, realm);
+            PluralRulesPrototype::create_intrinsic(agent, realm);
+            PluralRulesConstructor::create_intrinsic(agent, realm);
+            IntlObject::create_intrinsic(agent, realm);
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            DurationPrototype::create_intrinsic(agent, realm);
+            DurationConstructor::create_intrinsic(agent, realm);
+            InstantPrototype::create_intrinsic(agent, realm);
+            InstantConstructor::create_intrinsic(agent, realm);
+            PlainDatePrototype::create_intrinsic(agent, realm);
+            PlainDateConstructor::create_intrinsic(agent, realm);
+            PlainDateTimePrototype::create_intrinsic(agent, realm);
+            PlainDateTimeConstructor::create_intrinsic(agent, realm);
+            PlainTimePrototype::create_intrinsic(agent, realm);
+            PlainTimeConstructor::create_intrinsic(agent, realm);
+            ZonedDateTimePrototype::create_intrinsic(agent, realm);
+            ZonedDateTimeConstructor::create_intrinsic(agent, realm);
+            NowObject::create_intrinsic(agent, realm);
+            TemporalObject::create_intrinsic(agent, realm);
+        }
         ProxyConstructor::create_intrinsic(agent, realm);
         IteratorConstructor::create_intrinsic(agent, realm);
     }
@@ -389,6 +488,30 @@ impl Intrinsics {
             ProtoIntrinsics::Error => self.error_prototype().into(),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => self.date_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::Collator => self.collator_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::DateTimeFormat => self.date_time_format_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::NumberFormat => self.number_format_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::PluralRules => self.plural_rules_prototype().into(),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalDuration => self.temporal_duration_prototype().into(),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalInstant => self.temporal_instant_prototype().into(),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainDate => self.temporal_plain_date_prototype().into(),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainDateTime => {
+                self.temporal_plain_date_time_prototype().into()
+            }
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalPlainTime => self.temporal_plain_time_prototype().into(),
+            #[cfg(feature = "proposal-temporal")]
+            ProtoIntrinsics::TemporalZonedDateTime => {
+                self.temporal_zoned_date_time_prototype().into()
+            }
             ProtoIntrinsics::EvalError => self.eval_error_prototype().into(),
             ProtoIntrinsics::Function => self.function_prototype().into(),
             ProtoIntrinsics::Number => self.number_prototype().into(),
@@ -428,6 +551,7 @@ impl Intrinsics {
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => self.int8_array_prototype().into(),
             ProtoIntrinsics::Iterator => self.iterator_prototype().into(),
+            ProtoIntrinsics::IteratorHelper => self.iterator_helper_prototype().into(),
             ProtoIntrinsics::Map => self.map_prototype().into(),
             ProtoIntrinsics::MapIterator => self.map_iterator_prototype().into(),
             ProtoIntrinsics::Promise => self.promise_prototype().into(),
@@ -453,6 +577,9 @@ impl Intrinsics {
             ProtoIntrinsics::WeakRef => self.weak_ref_prototype().into(),
             #[cfg(feature = "weak-refs")]
             ProtoIntrinsics::WeakSet => self.weak_set_prototype().into(),
+            ProtoIntrinsics::WrapForValidIterator => {
+                self.wrap_for_valid_iterator_prototype().into()
+            }
         }
     }
 
@@ -1081,6 +1208,13 @@ impl Intrinsics {
             .into()
     }
 
+    /// %IteratorHelperPrototype%
+    pub(crate) fn iterator_helper_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IteratorHelperPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
     #[cfg(feature = "json")]
     /// %JSON%
     pub(crate) fn json(&self) -> OrdinaryObject<'static> {
@@ -1262,6 +1396,240 @@ impl Intrinsics {
             .into()
     }
 
+    #[cfg(feature = "intl")]
+    /// %Intl%
+    pub(crate) fn intl(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IntlObject
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.Collator.prototype%
+    pub(crate) fn collator_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::CollatorPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.Collator%
+    pub(crate) fn collator(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Collator
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    pub(crate) fn collator_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::Collator.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.DateTimeFormat.prototype%
+    pub(crate) fn date_time_format_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::DateTimeFormatPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.DateTimeFormat%
+    pub(crate) fn date_time_format(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::DateTimeFormat
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    pub(crate) fn date_time_format_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::DateTimeFormat.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.NumberFormat.prototype%
+    pub(crate) fn number_format_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::NumberFormatPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.NumberFormat%
+    pub(crate) fn number_format(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::NumberFormat
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    pub(crate) fn number_format_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::NumberFormat.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.PluralRules.prototype%
+    pub(crate) fn plural_rules_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::PluralRulesPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    /// %Intl.PluralRules%
+    pub(crate) fn plural_rules(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::PluralRules
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "intl")]
+    pub(crate) fn plural_rules_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::PluralRules.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal%
+    pub(crate) fn temporal(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalObject
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.Now%
+    pub(crate) fn temporal_now(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalNowObject
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.Duration.prototype%
+    pub(crate) fn temporal_duration_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalDurationPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.Duration%
+    pub(crate) fn temporal_duration(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalDuration
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_duration_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalDuration.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.Instant.prototype%
+    pub(crate) fn temporal_instant_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalInstantPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.Instant%
+    pub(crate) fn temporal_instant(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalInstant
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_instant_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalInstant.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainDate.prototype%
+    pub(crate) fn temporal_plain_date_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainDatePrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainDate%
+    pub(crate) fn temporal_plain_date(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDate
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_plain_date_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDate.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainDateTime.prototype%
+    pub(crate) fn temporal_plain_date_time_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainDateTimePrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainDateTime%
+    pub(crate) fn temporal_plain_date_time(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDateTime
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_plain_date_time_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDateTime.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainTime.prototype%
+    pub(crate) fn temporal_plain_time_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainTimePrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.PlainTime%
+    pub(crate) fn temporal_plain_time(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainTime
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_plain_time_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainTime.get_object_index(self.object_index_base)
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.ZonedDateTime.prototype%
+    pub(crate) fn temporal_zoned_date_time_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalZonedDateTimePrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    /// %Temporal.ZonedDateTime%
+    pub(crate) fn temporal_zoned_date_time(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalZonedDateTime
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-temporal")]
+    pub(crate) fn temporal_zoned_date_time_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::TemporalZonedDateTime.get_object_index(self.object_index_base)
+    }
+
     /// %RegExp.prototype.exec%
     #[cfg(feature = "regexp")]
     pub(crate) fn reg_exp_prototype_exec(&self) -> BuiltinFunction<'static> {
@@ -1299,6 +1667,13 @@ impl Intrinsics {
             .into()
     }
 
+    /// %Set.prototype.has%
+    pub(crate) fn set_prototype_has(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::SetPrototypeHas
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
     /// %Set.prototype.values%
     pub(crate) fn set_prototype_values(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::SetPrototypeValues
@@ -1324,6 +1699,13 @@ impl Intrinsics {
         IntrinsicConstructorIndexes::Set.get_object_index(self.object_index_base)
     }
 
+    /// %SetIteratorPrototype%.next
+    pub(crate) fn set_iterator_prototype_next(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::SetIteratorPrototypeNext
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
     /// %SetIteratorPrototype%
     pub(crate) fn set_iterator_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::SetIteratorPrototype
@@ -1652,6 +2034,13 @@ impl Intrinsics {
     pub(crate) fn weak_set_base_object(&self) -> ObjectIndex<'static> {
         IntrinsicConstructorIndexes::WeakSet.get_object_index(self.object_index_base)
     }
+
+    /// %WrapForValidIteratorPrototype%
+    pub(crate) fn wrap_for_valid_iterator_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::WrapForValidIteratorPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
 }
 
 impl HeapMarkAndSweep for Intrinsics {
@@ -1742,6 +2131,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.int8_array_prototype().mark_values(queues);
         self.is_finite().mark_values(queues);
         self.is_nan().mark_values(queues);
+        self.iterator_helper_prototype().mark_values(queues);
         self.iterator_prototype().mark_values(queues);
         #[cfg(feature = "json")]
         self.json().mark_values(queues);
@@ -1766,6 +2156,37 @@ impl HeapMarkAndSweep for Intrinsics {
         self.reference_error_prototype().mark_values(queues);
         self.reference_error().mark_values(queues);
         self.reflect().mark_values(queues);
+        #[cfg(feature = "intl")]
+        {
+            self.intl().mark_values(queues);
+            self.collator_prototype().mark_values(queues);
+            self.collator().mark_values(queues);
+            self.date_time_format_prototype().mark_values(queues);
+            self.date_time_format().mark_values(queues);
+            self.number_format_prototype().mark_values(queues);
+            self.number_format().mark_values(queues);
+            self.plural_rules_prototype().mark_values(queues);
+            self.plural_rules().mark_values(queues);
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            self.temporal().mark_values(queues);
+            self.temporal_now().mark_values(queues);
+            self.temporal_duration_prototype().mark_values(queues);
+            self.temporal_duration().mark_values(queues);
+            self.temporal_instant_prototype().mark_values(queues);
+            self.temporal_instant().mark_values(queues);
+            self.temporal_plain_date_prototype().mark_values(queues);
+            self.temporal_plain_date().mark_values(queues);
+            self.temporal_plain_date_time_prototype()
+                .mark_values(queues);
+            self.temporal_plain_date_time().mark_values(queues);
+            self.temporal_plain_time_prototype().mark_values(queues);
+            self.temporal_plain_time().mark_values(queues);
+            self.temporal_zoned_date_time_prototype()
+                .mark_values(queues);
+            self.temporal_zoned_date_time().mark_values(queues);
+        }
         #[cfg(feature = "regexp")]
         self.reg_exp_prototype_exec().mark_values(queues);
         #[cfg(feature = "regexp")]
@@ -1774,9 +2195,11 @@ impl HeapMarkAndSweep for Intrinsics {
         self.reg_exp().mark_values(queues);
         #[cfg(feature = "regexp")]
         self.reg_exp_string_iterator_prototype().mark_values(queues);
+        self.set_prototype_has().mark_values(queues);
         self.set_prototype_values().mark_values(queues);
         self.set_prototype().mark_values(queues);
         self.set().mark_values(queues);
+        self.set_iterator_prototype_next().mark_values(queues);
         self.set_iterator_prototype().mark_values(queues);
         #[cfg(feature = "shared-array-buffer")]
         self.shared_array_buffer_prototype().mark_values(queues);
@@ -1834,6 +2257,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.weak_set_prototype().mark_values(queues);
         #[cfg(feature = "weak-refs")]
         self.weak_set().mark_values(queues);
+        self.wrap_for_valid_iterator_prototype().mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -80,6 +80,13 @@ impl Script<'_> {
     pub(crate) const fn into_u32(self) -> u32 {
         self.0
     }
+
+    /// Set the name of the script's source, such as a file path or a URL.
+    /// The name is shown in the stack traces of errors thrown by the script.
+    pub fn set_source_name(self, agent: &mut Agent, name: impl Into<Box<str>>) {
+        let source_code = agent[self].source_code;
+        source_code.set_name(agent, name.into());
+    }
 }
 
 impl Index<Script<'_>> for Agent {
@@ -7,7 +7,7 @@
 //! that the eval call defines functions. Those functions will refer to the
 //! SourceCode for their function source text.
 
-use core::{fmt::Debug, ops::Index, ptr::NonNull};
+use core::{cell::OnceCell, fmt::Debug, ops::Index, ptr::NonNull};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
@@ -129,6 +129,8 @@ impl<'a> SourceCode<'a> {
         let source_code = agent.heap.create(SourceCodeHeapData {
             source: source.unbind(),
             allocator,
+            name: None,
+            line_table: OnceCell::new(),
         });
 
         Ok((program, source_code))
@@ -138,6 +140,46 @@ impl<'a> SourceCode<'a> {
         agent[agent[self].source].as_str()
     }
 
+    /// Get the name of the source, such as a file path or a URL, if one was
+    /// set by the embedder.
+    pub(crate) fn get_name(self, agent: &Agent) -> Option<&str> {
+        agent[self].name.as_deref()
+    }
+
+    /// Get the one-based line and column numbers of a byte offset into the
+    /// source text. The column is counted in UTF-16 code units, like in V8.
+    pub(crate) fn get_line_and_column(self, agent: &Agent, position: u32) -> (usize, usize) {
+        let source_text = self.get_source_text(agent);
+        let line_table = agent[self]
+            .line_table
+            .get_or_init(|| LineTable::new(source_text));
+        let position = (position as usize).min(source_text.len());
+        // The first line starts at 0, so the partition point is at least 1.
+        let line = line_table
+            .starts
+            .partition_point(|&start| start as usize <= position);
+        let line_start = line_table.starts[line - 1] as usize;
+        let column = if line_table.ascii[line - 1] {
+            position - line_start
+        } else {
+            source_text
+                .get(line_start..position)
+                .map_or(position - line_start, |text| text.encode_utf16().count())
+        };
+        (line, column + 1)
+    }
+
+    pub(crate) fn set_name(self, agent: &mut Agent, name: Box<str>) {
+        agent
+            .heap
+            .source_codes
+            .get_mut(self.get_index())
+            .expect("SourceCode out of bounds")
+            .as_mut()
+            .expect("SourceCode slot empty")
+            .name = Some(name);
+    }
+
     pub(crate) fn get_index(self) -> usize {
         self.0.into_index()
     }
@@ -152,6 +194,45 @@ pub struct SourceCodeHeapData<'a> {
     source: HeapString<'a>,
     /// The arena that contains the parsed data of the eval source.
     allocator: NonNull<Allocator>,
+    /// Name of the source, such as a file path or a URL, used in stack
+    /// traces.
+    name: Option<Box<str>>,
+    /// Line start offsets of the source, computed when a stack trace first
+    /// needs a line and column number in this source.
+    line_table: OnceCell<LineTable>,
+}
+
+/// The lines of a source text, for mapping source positions to line and
+/// column numbers with a binary search.
+#[derive(Debug)]
+struct LineTable {
+    /// Byte offset of the start of each line.
+    starts: Box<[u32]>,
+    /// True for each line that only contains ASCII characters: Their UTF-16
+    /// columns equal their byte offsets.
+    ascii: Box<[bool]>,
+}
+
+impl LineTable {
+    fn new(source_text: &str) -> Self {
+        let mut starts = vec![0];
+        let mut ascii = Vec::new();
+        let mut line_is_ascii = true;
+        for (index, byte) in source_text.bytes().enumerate() {
+            if byte == b'\n' {
+                starts.push(u32::try_from(index + 1).unwrap());
+                ascii.push(line_is_ascii);
+                line_is_ascii = true;
+            } else if !byte.is_ascii() {
+                line_is_ascii = false;
+            }
+        }
+        ascii.push(line_is_ascii);
+        Self {
+            starts: starts.into_boxed_slice(),
+            ascii: ascii.into_boxed_slice(),
+        }
+    }
 }
 
 unsafe impl Send for SourceCodeHeapData<'_> {}
@@ -161,6 +242,8 @@ impl Debug for SourceCodeHeapData<'_> {
         f.debug_struct("SourceCodeHeapData")
             .field("source", &self.source)
             .field("allocator", &"[binary data]")
+            .field("name", &self.name)
+            .field("line_table", &self.line_table)
             .finish()
     }
 }
@@ -255,6 +338,8 @@ impl HeapMarkAndSweep for SourceCodeHeapData<'static> {
         let Self {
             source,
             allocator: _,
+            name: _,
+            line_table: _,
         } = self;
         source.mark_values(queues);
     }
@@ -263,6 +348,8 @@ impl HeapMarkAndSweep for SourceCodeHeapData<'static> {
         let Self {
             source,
             allocator: _,
+            name: _,
+            line_table: _,
         } = self;
         source.sweep_values(compactions);
     }
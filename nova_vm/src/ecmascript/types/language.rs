@@ -38,15 +38,37 @@ pub(crate) use primitive::HeapPrimitive;
 pub use primitive::Primitive;
 pub use string::{BUILTIN_STRING_MEMORY, BUILTIN_STRINGS_LIST, HeapString, String, StringHeapData};
 pub use symbol::{Symbol, SymbolHeapData};
+#[cfg(feature = "intl")]
+pub(crate) use value::COLLATOR_DISCRIMINANT;
 #[cfg(feature = "date")]
 pub(crate) use value::DATE_DISCRIMINANT;
+#[cfg(feature = "intl")]
+pub(crate) use value::DATE_TIME_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::DURATION_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 pub(crate) use value::FLOAT_16_ARRAY_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::INSTANT_DISCRIMINANT;
+#[cfg(feature = "intl")]
+pub(crate) use value::NUMBER_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::PLAIN_DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::PLAIN_DATE_TIME_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::PLAIN_TIME_DISCRIMINANT;
+#[cfg(feature = "intl")]
+pub(crate) use value::PLURAL_RULES_DISCRIMINANT;
+#[cfg(feature = "proposal-json-parse-with-source")]
+pub(crate) use value::RAW_JSON_DISCRIMINANT;
 #[cfg(feature = "regexp")]
 pub(crate) use value::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 pub(crate) use value::SHARED_ARRAY_BUFFER_DISCRIMINANT;
 pub use value::Value;
+#[cfg(feature = "proposal-temporal")]
+pub(crate) use value::ZONED_DATE_TIME_DISCRIMINANT;
 pub(crate) use value::{
     ARGUMENTS_DISCRIMINANT, ARRAY_DISCRIMINANT, ARRAY_ITERATOR_DISCRIMINANT,
     ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT, ASYNC_GENERATOR_DISCRIMINANT, BIGINT_DISCRIMINANT,
@@ -56,10 +78,10 @@ pub(crate) use value::{
     BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
     ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
     FINALIZATION_REGISTRY_DISCRIMINANT, FLOAT_DISCRIMINANT, GENERATOR_DISCRIMINANT,
-    INTEGER_DISCRIMINANT, ITERATOR_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
-    MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT,
-    PROXY_DISCRIMINANT, SMALL_BIGINT_DISCRIMINANT, SMALL_STRING_DISCRIMINANT, STRING_DISCRIMINANT,
-    SYMBOL_DISCRIMINANT, ValueRootRepr,
+    INTEGER_DISCRIMINANT, ITERATOR_DISCRIMINANT, ITERATOR_HELPER_DISCRIMINANT, MAP_DISCRIMINANT,
+    MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT,
+    PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, SMALL_BIGINT_DISCRIMINANT, SMALL_STRING_DISCRIMINANT,
+    STRING_DISCRIMINANT, SYMBOL_DISCRIMINANT, ValueRootRepr, WRAP_FOR_VALID_ITERATOR_DISCRIMINANT,
 };
 #[cfg(feature = "array-buffer")]
 pub(crate) use value::{
@@ -630,6 +630,35 @@ impl<'a> BigInt<'a> {
         )
     }
 
+    /// ### [6.1.6.2.21 BigInt::toString ( x, radix )](https://tc39.es/ecma262/#sec-numeric-types-bigint-tostring)
+    ///
+    /// The abstract operation BigInt::toString takes arguments x (a BigInt)
+    /// and radix (an integer in the inclusive interval from 2 to 36) and
+    /// returns a String. It represents x as a String using a positional
+    /// numeral system with radix radix.
+    pub(crate) fn to_string_radix_n<'gc>(
+        agent: &mut Agent,
+        x: Self,
+        radix: u32,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        debug_assert!((2..=36).contains(&radix));
+        // 1. If x < 0, return the string-concatenation of "-" and
+        //    BigInt::toString(-x, radix).
+        // 2. Return the String value consisting of the representation of x
+        //    using radix radix.
+        String::from_string(
+            agent,
+            match x {
+                BigInt::SmallBigInt(x) => {
+                    num_bigint::BigInt::from(x.into_i64()).to_str_radix(radix)
+                }
+                BigInt::BigInt(x) => agent[x].data.to_str_radix(radix),
+            },
+            gc,
+        )
+    }
+
     pub(crate) fn to_real(self, agent: &mut Agent) -> f64 {
         match self {
             BigInt::BigInt(heap_big_int) => {
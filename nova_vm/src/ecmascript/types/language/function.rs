@@ -18,7 +18,7 @@ use crate::engine::{context::{ Bindable, GcScope, NoGcScope}, TryResult};
 use crate::{
     ecmascript::{
         builtins::{
-            bound_function::BoundFunction, control_abstraction_objects::promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction, ArgumentsList, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction
+            bound_function::BoundFunction, control_abstraction_objects::promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction, proxy::revoker_function::BuiltinProxyRevokerFunction, ArgumentsList, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction
         },
         execution::{Agent, JsResult, ProtoIntrinsics},
         types::PropertyDescriptor,
@@ -48,7 +48,7 @@ pub enum Function<'a> {
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) = BUILTIN_PROXY_REVOKER_FUNCTION,
 }
 
 impl core::fmt::Debug for Function<'_> {
@@ -65,7 +65,9 @@ impl core::fmt::Debug for Function<'_> {
                 write!(f, "BuiltinPromiseResolvingFunction({:?})", d)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(d) => {
+                write!(f, "BuiltinProxyRevokerFunction({:?})", d)
+            }
         }
     }
 }
@@ -112,7 +114,9 @@ impl<'a> TryFrom<Object<'a>> for Function<'a> {
             Object::BuiltinPromiseCollectorFunction => {
                 Ok(Function::BuiltinPromiseCollectorFunction)
             }
-            Object::BuiltinProxyRevokerFunction => Ok(Function::BuiltinProxyRevokerFunction),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                Ok(Function::BuiltinProxyRevokerFunction(data))
+            }
             _ => Err(()),
         }
     }
@@ -133,7 +137,9 @@ impl<'a> TryFrom<Value<'a>> for Function<'a> {
                 Ok(Function::BuiltinPromiseResolvingFunction(data))
             }
             Value::BuiltinPromiseCollectorFunction => Ok(Function::BuiltinPromiseCollectorFunction),
-            Value::BuiltinProxyRevokerFunction => Ok(Function::BuiltinProxyRevokerFunction),
+            Value::BuiltinProxyRevokerFunction(data) => {
+                Ok(Function::BuiltinProxyRevokerFunction(data))
+            }
             _ => Err(()),
         }
     }
@@ -153,7 +159,9 @@ impl<'a> From<Function<'a>> for Object<'a> {
                 Object::BuiltinPromiseResolvingFunction(data.unbind())
             }
             Function::BuiltinPromiseCollectorFunction => Object::BuiltinPromiseCollectorFunction,
-            Function::BuiltinProxyRevokerFunction => Object::BuiltinProxyRevokerFunction,
+            Function::BuiltinProxyRevokerFunction(data) => {
+                Object::BuiltinProxyRevokerFunction(data.unbind())
+            }
         }
     }
 }
@@ -172,7 +180,9 @@ impl<'a> From<Function<'a>> for Value<'a> {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
             Function::BuiltinPromiseCollectorFunction => Value::BuiltinPromiseCollectorFunction,
-            Function::BuiltinProxyRevokerFunction => Value::BuiltinProxyRevokerFunction,
+            Function::BuiltinProxyRevokerFunction(data) => {
+                Value::BuiltinProxyRevokerFunction(data.unbind())
+            }
         }
     }
 }
@@ -187,7 +197,7 @@ impl Function<'_> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(_) => true,
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(_) => false,
         }
     }
 
@@ -238,7 +248,7 @@ impl<'a> InternalSlots<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(d) => agent[d].object_index,
             Function::BuiltinPromiseResolvingFunction(d) => agent[d].object_index,
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(d) => agent[d].object_index,
         }
     }
 
@@ -283,7 +293,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_get_prototype_of(agent, gc),
         }
     }
 
@@ -303,7 +313,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_set_prototype_of(agent, prototype, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_set_prototype_of(agent, prototype, gc)
+            }
         }
     }
 
@@ -316,7 +328,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_is_extensible(agent, gc),
         }
     }
 
@@ -329,7 +341,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_prevent_extensions(agent, gc),
         }
     }
 
@@ -351,7 +363,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_get_own_property(agent, property_key, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_get_own_property(agent, property_key, gc)
+            }
         }
     }
 
@@ -380,7 +394,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
         }
     }
 
@@ -400,7 +416,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_has_property(agent, property_key, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_has_property(agent, property_key, gc),
         }
     }
 
@@ -422,7 +438,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.internal_has_property(agent, property_key, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_has_property(agent, property_key, gc)
+            }
         }
     }
 
@@ -443,7 +461,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_get(agent, property_key, receiver, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_get(agent, property_key, receiver, gc)
+            }
         }
     }
 
@@ -466,7 +486,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.internal_get(agent, property_key, receiver, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_get(agent, property_key, receiver, gc)
+            }
         }
     }
 
@@ -490,7 +512,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.try_set(agent, property_key, value, receiver, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_set(agent, property_key, value, receiver, gc)
+            }
         }
     }
 
@@ -518,7 +542,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.internal_set(agent, property_key, value, receiver, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_set(agent, property_key, value, receiver, gc)
+            }
         }
     }
 
@@ -536,7 +562,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_delete(agent, property_key, gc),
         }
     }
 
@@ -553,7 +579,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_own_property_keys(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_own_property_keys(agent, gc),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_own_property_keys(agent, gc),
         }
     }
 
@@ -576,7 +602,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.internal_call(agent, this_argument, arguments, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_call(agent, this_argument, arguments, gc)
+            }
         }
     }
 
@@ -601,7 +629,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 x.internal_construct(agent, arguments, new_target, gc)
             }
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_construct(agent, arguments, new_target, gc)
+            }
         }
     }
 }
@@ -616,7 +646,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinConstructorFunction(x) => x.mark_values(queues),
             Function::BuiltinPromiseResolvingFunction(x) => x.mark_values(queues),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.mark_values(queues),
         }
     }
 
@@ -629,7 +659,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinConstructorFunction(x) => x.sweep_values(compactions),
             Function::BuiltinPromiseResolvingFunction(x) => x.sweep_values(compactions),
             Function::BuiltinPromiseCollectorFunction => todo!(),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.sweep_values(compactions),
         }
     }
 }
@@ -669,7 +699,9 @@ impl Rootable for Function<'_> {
             Self::BuiltinPromiseCollectorFunction => {
                 Err(HeapRootData::BuiltinPromiseCollectorFunction)
             }
-            Self::BuiltinProxyRevokerFunction => Err(HeapRootData::BuiltinProxyRevokerFunction),
+            Self::BuiltinProxyRevokerFunction(d) => {
+                Err(HeapRootData::BuiltinProxyRevokerFunction(d.unbind()))
+            }
         }
     }
 
@@ -706,7 +738,9 @@ impl Rootable for Function<'_> {
             HeapRootData::BuiltinPromiseCollectorFunction => {
                 Some(Self::BuiltinPromiseCollectorFunction)
             }
-            HeapRootData::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => Some(
+                Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function),
+            ),
             // Note: We use a catch-all here as we expect function variant
             // additions to be rare.
             _ => None,
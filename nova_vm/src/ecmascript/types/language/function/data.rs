@@ -6,7 +6,7 @@ use oxc_span::Span;
 
 use crate::{
     ecmascript::{
-        builtins::{Behaviour, ECMAScriptFunctionObjectHeapData},
+        builtins::{Behaviour, BuiltinClosure, ECMAScriptFunctionObjectHeapData},
         execution::{Environment, PrivateEnvironment, Realm},
         scripts_and_modules::source_code::SourceCode,
         types::{OrdinaryObject, String, Value},
@@ -51,6 +51,11 @@ pub struct BuiltinFunctionHeapData<'a> {
     /// 20.2.3.5 (`Function.prototype.toString()`).
     pub(crate) initial_name: Option<String<'a>>,
     pub(crate) behaviour: Behaviour,
+    /// Rust closure that is called in place of the behaviour of functions
+    /// created using [`create_builtin_closure`].
+    ///
+    /// [`create_builtin_closure`]: crate::ecmascript::builtins::create_builtin_closure
+    pub(crate) closure: Option<BuiltinClosure>,
 }
 
 #[derive(Debug, Clone)]
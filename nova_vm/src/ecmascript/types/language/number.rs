@@ -15,6 +15,7 @@ use crate::{
     ecmascript::{
         abstract_operations::type_conversion::{to_int32_number, to_uint32_number},
         execution::Agent,
+        types::BUILTIN_STRING_MEMORY,
     },
     engine::{
         context::{Bindable, NoGcScope},
@@ -1335,6 +1336,64 @@ impl<'a> Number<'a> {
         }
     }
 
+    /// ### [6.1.6.1.20 Number::toString ( x, radix )](https://tc39.es/ecma262/#sec-numeric-types-number-tostring)
+    ///
+    /// The abstract operation Number::toString takes arguments x (a Number)
+    /// and radix (an integer in the inclusive interval from 2 to 36) and
+    /// returns a String. It represents x as a String using a positional
+    /// numeral system with radix radix.
+    pub(crate) fn to_string_radix_n<'gc>(
+        agent: &mut Agent,
+        x: Self,
+        radix: u32,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        debug_assert!((2..=36).contains(&radix));
+        if radix == 10 {
+            return Self::to_string_radix_10(agent, x, gc);
+        }
+        match x {
+            Number::Integer(x) => {
+                let x = x.into_i64();
+                let mut digits = Vec::with_capacity(64);
+                let mut n = x.unsigned_abs();
+                loop {
+                    digits.push(char::from_digit((n % radix as u64) as u32, radix).unwrap());
+                    n /= radix as u64;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                if x < 0 {
+                    digits.push('-');
+                }
+                String::from_string(agent, digits.into_iter().rev().collect(), gc)
+            }
+            Number::Number(_) | Number::SmallF64(_) => {
+                let x = x.into_f64(agent);
+                // 1. If x is NaN, return "NaN".
+                if x.is_nan() {
+                    return BUILTIN_STRING_MEMORY.NaN;
+                }
+                // 2. If x is either +0𝔽 or -0𝔽, return "0".
+                if x == 0.0 {
+                    return BUILTIN_STRING_MEMORY._0;
+                }
+                // 3. If x < -0𝔽, return the string-concatenation of "-" and
+                //    Number::toString(-x, radix).
+                // 4. If x is +∞𝔽, return "Infinity".
+                if x.is_infinite() {
+                    return if x.is_sign_positive() {
+                        BUILTIN_STRING_MEMORY.Infinity
+                    } else {
+                        String::from_static_str(agent, "-Infinity", gc)
+                    };
+                }
+                String::from_string(agent, f64_to_radix_string(x, radix), gc)
+            }
+        }
+    }
+
     /// ### [ℝ](https://tc39.es/ecma262/#%E2%84%9D)
     pub(crate) fn to_real(self, agent: &impl Index<HeapNumber<'static>, Output = f64>) -> f64 {
         match self {
@@ -1345,6 +1404,90 @@ impl<'a> Number<'a> {
     }
 }
 
+/// Formats a finite, non-zero f64 in the given radix using the shortest
+/// digit sequence that uniquely identifies the value, including fractional
+/// digits. This follows the algorithm used by V8's DoubleToRadixCString.
+fn f64_to_radix_string(value: f64, radix: u32) -> std::string::String {
+    debug_assert!(value.is_finite() && value != 0.0);
+    let next_double = |x: f64| f64::from_bits(x.to_bits() + 1);
+    let radix_f = radix as f64;
+    let negative = value < 0.0;
+    let value = value.abs();
+    let mut integer = value.floor();
+    let mut fraction = value - integer;
+    // Only compute fractional digits up to the input's precision: delta is
+    // half the distance to the next representable double.
+    let mut delta = (0.5 * (next_double(value) - value)).max(next_double(0.0));
+    let mut fraction_digits: Vec<u32> = Vec::new();
+    if fraction >= delta {
+        loop {
+            // Shift up by one digit.
+            fraction *= radix_f;
+            delta *= radix_f;
+            // Write digit.
+            let digit = fraction as u32;
+            fraction_digits.push(digit);
+            // Calculate remainder.
+            fraction -= digit as f64;
+            // Round to even.
+            if (fraction > 0.5 || (fraction == 0.5 && digit & 1 == 1)) && fraction + delta > 1.0 {
+                // Back trace already written digits in case of carry-over.
+                loop {
+                    match fraction_digits.pop() {
+                        // Carry over to the integer part.
+                        None => {
+                            integer += 1.0;
+                            break;
+                        }
+                        Some(digit) if digit + 1 < radix => {
+                            fraction_digits.push(digit + 1);
+                            break;
+                        }
+                        Some(_) => {}
+                    }
+                }
+                break;
+            }
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    // Compute integer digits, least significant first. Digits below the
+    // precision of the double are filled with zeroes.
+    let mut integer_digits = Vec::new();
+    while integer / radix_f >= 9007199254740992.0 {
+        integer /= radix_f;
+        integer_digits.push('0');
+    }
+    loop {
+        let remainder = integer % radix_f;
+        integer_digits.push(char::from_digit(remainder as u32, radix).unwrap());
+        integer = (integer - remainder) / radix_f;
+        if integer <= 0.0 {
+            break;
+        }
+    }
+
+    let mut result = std::string::String::with_capacity(
+        negative as usize + integer_digits.len() + 1 + fraction_digits.len(),
+    );
+    if negative {
+        result.push('-');
+    }
+    result.extend(integer_digits.into_iter().rev());
+    if !fraction_digits.is_empty() {
+        result.push('.');
+        result.extend(
+            fraction_digits
+                .into_iter()
+                .map(|digit| char::from_digit(digit, radix).unwrap()),
+        );
+    }
+    result
+}
+
 // SAFETY: Property implemented as a lifetime transmute.
 unsafe impl Bindable for Number<'_> {
     type Of<'a> = Number<'a>;
@@ -11,14 +11,36 @@ mod property_storage;
 
 use core::hash::Hash;
 
+#[cfg(feature = "intl")]
+use super::value::COLLATOR_DISCRIMINANT;
 #[cfg(feature = "date")]
 use super::value::DATE_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use super::value::DATE_TIME_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::DURATION_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use super::value::FLOAT_16_ARRAY_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::INSTANT_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use super::value::NUMBER_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::PLAIN_DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::PLAIN_DATE_TIME_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::PLAIN_TIME_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use super::value::PLURAL_RULES_DISCRIMINANT;
+#[cfg(feature = "proposal-json-parse-with-source")]
+use super::value::RAW_JSON_DISCRIMINANT;
 #[cfg(feature = "regexp")]
 use super::value::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 use super::value::SHARED_ARRAY_BUFFER_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use super::value::ZONED_DATE_TIME_DISCRIMINANT;
 #[cfg(feature = "array-buffer")]
 use super::value::{
     ARRAY_BUFFER_DISCRIMINANT, BIGINT_64_ARRAY_DISCRIMINANT, BIGUINT_64_ARRAY_DISCRIMINANT,
@@ -40,16 +62,30 @@ use super::{
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
         FINALIZATION_REGISTRY_DISCRIMINANT, GENERATOR_DISCRIMINANT, ITERATOR_DISCRIMINANT,
-        MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, OBJECT_DISCRIMINANT,
-        PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
+        ITERATOR_HELPER_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
+        MODULE_DISCRIMINANT, OBJECT_DISCRIMINANT, PRIMITIVE_OBJECT_DISCRIMINANT,
+        PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, WRAP_FOR_VALID_ITERATOR_DISCRIMINANT,
     },
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::Duration, instant::Instant, plain_date::PlainDate, plain_date_time::PlainDateTime,
+    plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
 #[cfg(feature = "set")]
@@ -71,6 +107,9 @@ use crate::{
             ArgumentsList, Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+            },
             control_abstraction_objects::{
                 generator_objects::Generator,
                 promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction,
@@ -85,7 +124,7 @@ use crate::{
             ordinary::ordinary_object_create_with_intrinsics,
             primitive_objects::PrimitiveObject,
             promise::Promise,
-            proxy::Proxy,
+            proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
         },
         execution::{Agent, JsResult, ProtoIntrinsics},
         types::PropertyDescriptor,
@@ -123,9 +162,11 @@ pub enum Object<'a> {
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
     Arguments(OrdinaryObject<'a>) = ARGUMENTS_DISCRIMINANT,
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    RawJSON(OrdinaryObject<'a>) = RAW_JSON_DISCRIMINANT,
     Array(Array<'a>) = ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'a>) = ARRAY_BUFFER_DISCRIMINANT,
@@ -133,6 +174,26 @@ pub enum Object<'a> {
     DataView(DataView<'a>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'a>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    Collator(Collator<'a>) = COLLATOR_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    DateTimeFormat(DateTimeFormat<'a>) = DATE_TIME_FORMAT_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    NumberFormat(NumberFormat<'a>) = NUMBER_FORMAT_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    PluralRules(PluralRules<'a>) = PLURAL_RULES_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    Duration(Duration<'a>) = DURATION_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    Instant(Instant<'a>) = INSTANT_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainDate(PlainDate<'a>) = PLAIN_DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainDateTime(PlainDateTime<'a>) = PLAIN_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainTime(PlainTime<'a>) = PLAIN_TIME_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    ZonedDateTime(ZonedDateTime<'a>) = ZONED_DATE_TIME_DISCRIMINANT,
     Error(Error<'a>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'a>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'a>) = MAP_DISCRIMINANT,
@@ -181,6 +242,8 @@ pub enum Object<'a> {
     #[cfg(feature = "set")]
     SetIterator(SetIterator<'a>) = SET_ITERATOR_DISCRIMINANT,
     MapIterator(MapIterator<'a>) = MAP_ITERATOR_DISCRIMINANT,
+    IteratorHelper(IteratorHelper<'a>) = ITERATOR_HELPER_DISCRIMINANT,
+    WrapForValidIterator(WrapForValidIterator<'a>) = WRAP_FOR_VALID_ITERATOR_DISCRIMINANT,
     Generator(Generator<'a>) = GENERATOR_DISCRIMINANT,
     Module(Module<'a>) = MODULE_DISCRIMINANT,
     EmbedderObject(EmbedderObject<'a>) = EMBEDDER_OBJECT_DISCRIMINANT,
@@ -204,9 +267,13 @@ impl<'a> IntoValue<'a> for Object<'a> {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                Value::BuiltinProxyRevokerFunction(data.unbind())
+            }
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => Value::RawJSON(data.unbind()),
             Object::Array(data) => Value::Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => Value::ArrayBuffer(data.unbind()),
@@ -214,6 +281,26 @@ impl<'a> IntoValue<'a> for Object<'a> {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => Value::Collator(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => Value::DateTimeFormat(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => Value::NumberFormat(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => Value::PluralRules(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => Value::Duration(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => Value::Instant(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => Value::PlainDate(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => Value::PlainDateTime(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => Value::PlainTime(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => Value::ZonedDateTime(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -262,6 +349,8 @@ impl<'a> IntoValue<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => Value::SetIterator(data.unbind()),
             Object::MapIterator(data) => Value::MapIterator(data.unbind()),
+            Object::IteratorHelper(data) => Value::IteratorHelper(data.unbind()),
+            Object::WrapForValidIterator(data) => Value::WrapForValidIterator(data.unbind()),
             Object::Generator(data) => Value::Generator(data.unbind()),
             Object::Module(data) => Value::Module(data.unbind()),
             Object::EmbedderObject(data) => Value::EmbedderObject(data.unbind()),
@@ -440,9 +529,13 @@ impl<'a> From<Object<'a>> for Value<'a> {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
             Object::BuiltinPromiseCollectorFunction => Value::BuiltinPromiseCollectorFunction,
-            Object::BuiltinProxyRevokerFunction => Value::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(data) => {
+                Value::BuiltinProxyRevokerFunction(data.unbind())
+            }
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => Value::RawJSON(data.unbind()),
             Object::Array(data) => Value::Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => Value::ArrayBuffer(data.unbind()),
@@ -450,6 +543,26 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => Value::Collator(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => Value::DateTimeFormat(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => Value::NumberFormat(data.unbind()),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => Value::PluralRules(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => Value::Duration(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => Value::Instant(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => Value::PlainDate(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => Value::PlainDateTime(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => Value::PlainTime(data.unbind()),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => Value::ZonedDateTime(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -498,6 +611,8 @@ impl<'a> From<Object<'a>> for Value<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => Value::SetIterator(data.unbind()),
             Object::MapIterator(data) => Value::MapIterator(data.unbind()),
+            Object::IteratorHelper(data) => Value::IteratorHelper(data.unbind()),
+            Object::WrapForValidIterator(data) => Value::WrapForValidIterator(data.unbind()),
             Object::Generator(data) => Value::Generator(data.unbind()),
             Object::Module(data) => Value::Module(data.unbind()),
             Object::EmbedderObject(data) => Value::EmbedderObject(data.unbind()),
@@ -524,6 +639,26 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
             Value::Array(x) => Ok(Object::from(x)),
             #[cfg(feature = "date")]
             Value::Date(x) => Ok(Object::Date(x)),
+            #[cfg(feature = "intl")]
+            Value::Collator(x) => Ok(Object::Collator(x)),
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(x) => Ok(Object::DateTimeFormat(x)),
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(x) => Ok(Object::NumberFormat(x)),
+            #[cfg(feature = "intl")]
+            Value::PluralRules(x) => Ok(Object::PluralRules(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(x) => Ok(Object::Duration(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(x) => Ok(Object::Instant(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(x) => Ok(Object::PlainDate(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(x) => Ok(Object::PlainDateTime(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(x) => Ok(Object::PlainTime(x)),
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(x) => Ok(Object::ZonedDateTime(x)),
             Value::Error(x) => Ok(Object::from(x)),
             Value::BoundFunction(x) => Ok(Object::from(x)),
             Value::BuiltinFunction(x) => Ok(Object::from(x)),
@@ -534,9 +669,13 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
                 Ok(Object::BuiltinPromiseResolvingFunction(data))
             }
             Value::BuiltinPromiseCollectorFunction => Ok(Object::BuiltinPromiseCollectorFunction),
-            Value::BuiltinProxyRevokerFunction => Ok(Object::BuiltinProxyRevokerFunction),
+            Value::BuiltinProxyRevokerFunction(data) => {
+                Ok(Object::BuiltinProxyRevokerFunction(data))
+            }
             Value::PrimitiveObject(data) => Ok(Object::PrimitiveObject(data)),
             Value::Arguments(data) => Ok(Object::Arguments(data)),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => Ok(Object::RawJSON(data)),
             #[cfg(feature = "array-buffer")]
             Value::ArrayBuffer(idx) => Ok(Object::ArrayBuffer(idx)),
             #[cfg(feature = "array-buffer")]
@@ -588,6 +727,8 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
             #[cfg(feature = "set")]
             Value::SetIterator(data) => Ok(Object::SetIterator(data)),
             Value::MapIterator(data) => Ok(Object::MapIterator(data)),
+            Value::IteratorHelper(data) => Ok(Object::IteratorHelper(data)),
+            Value::WrapForValidIterator(data) => Ok(Object::WrapForValidIterator(data)),
             Value::Generator(data) => Ok(Object::Generator(data)),
             Value::Module(data) => Ok(Object::Module(data)),
             Value::EmbedderObject(data) => Ok(Object::EmbedderObject(data)),
@@ -613,9 +754,11 @@ impl Hash for Object<'_> {
             Object::BuiltinConstructorFunction(data) => data.get_index().hash(state),
             Object::BuiltinPromiseResolvingFunction(data) => data.get_index().hash(state),
             Object::BuiltinPromiseCollectorFunction => {}
-            Object::BuiltinProxyRevokerFunction => {}
+            Object::BuiltinProxyRevokerFunction(data) => data.get_index().hash(state),
             Object::PrimitiveObject(data) => data.get_index().hash(state),
             Object::Arguments(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.get_index().hash(state),
             Object::Array(data) => data.get_index().hash(state),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => data.get_index().hash(state),
@@ -623,6 +766,26 @@ impl Hash for Object<'_> {
             Object::DataView(data) => data.get_index().hash(state),
             #[cfg(feature = "date")]
             Object::Date(data) => data.get_index().hash(state),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.get_index().hash(state),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.get_index().hash(state),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.get_index().hash(state),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.get_index().hash(state),
             Object::Error(data) => data.get_index().hash(state),
             Object::FinalizationRegistry(data) => data.get_index().hash(state),
             Object::Map(data) => data.get_index().hash(state),
@@ -671,6 +834,8 @@ impl Hash for Object<'_> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.get_index().hash(state),
             Object::MapIterator(data) => data.get_index().hash(state),
+            Object::IteratorHelper(data) => data.get_index().hash(state),
+            Object::WrapForValidIterator(data) => data.get_index().hash(state),
             Object::Generator(data) => data.get_index().hash(state),
             Object::Module(data) => data.get_index().hash(state),
             Object::EmbedderObject(data) => data.get_index().hash(state),
@@ -699,6 +864,26 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_extensible(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_extensible(agent),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_extensible(agent),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_extensible(agent),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_extensible(agent),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_extensible(agent),
             Object::Error(data) => data.internal_extensible(agent),
             Object::BoundFunction(data) => data.internal_extensible(agent),
             Object::BuiltinFunction(data) => data.internal_extensible(agent),
@@ -707,9 +892,11 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.internal_extensible(agent),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_extensible(agent),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_extensible(agent),
             Object::PrimitiveObject(data) => data.internal_extensible(agent),
             Object::Arguments(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_extensible(agent),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_extensible(agent),
             Object::FinalizationRegistry(data) => data.internal_extensible(agent),
@@ -765,6 +952,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_extensible(agent),
             Object::MapIterator(data) => data.internal_extensible(agent),
+            Object::IteratorHelper(data) => data.internal_extensible(agent),
+            Object::WrapForValidIterator(data) => data.internal_extensible(agent),
             Object::Generator(data) => data.internal_extensible(agent),
             Object::Module(data) => data.internal_extensible(agent),
             Object::EmbedderObject(data) => data.internal_extensible(agent),
@@ -779,6 +968,26 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_set_extensible(agent, value),
             Object::Error(data) => data.internal_set_extensible(agent, value),
             Object::BoundFunction(data) => data.internal_set_extensible(agent, value),
             Object::BuiltinFunction(idx) => idx.internal_set_extensible(agent, value),
@@ -789,9 +998,11 @@ impl<'a> InternalSlots<'a> for Object<'a> {
                 data.internal_set_extensible(agent, value)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_set_extensible(agent, value),
             Object::PrimitiveObject(data) => data.internal_set_extensible(agent, value),
             Object::Arguments(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_extensible(agent, value),
             Object::FinalizationRegistry(data) => data.internal_set_extensible(agent, value),
@@ -865,6 +1076,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_set_extensible(agent, value),
             Object::MapIterator(data) => data.internal_set_extensible(agent, value),
+            Object::IteratorHelper(data) => data.internal_set_extensible(agent, value),
+            Object::WrapForValidIterator(data) => data.internal_set_extensible(agent, value),
             Object::Generator(data) => data.internal_set_extensible(agent, value),
             Object::Module(data) => data.internal_set_extensible(agent, value),
             Object::EmbedderObject(data) => data.internal_set_extensible(agent, value),
@@ -879,6 +1092,26 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prototype(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prototype(agent),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_prototype(agent),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_prototype(agent),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_prototype(agent),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_prototype(agent),
             Object::Error(data) => data.internal_prototype(agent),
             Object::BoundFunction(data) => data.internal_prototype(agent),
             Object::BuiltinFunction(data) => data.internal_prototype(agent),
@@ -887,9 +1120,11 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.internal_prototype(agent),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_prototype(agent),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_prototype(agent),
             Object::PrimitiveObject(data) => data.internal_prototype(agent),
             Object::Arguments(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_prototype(agent),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_prototype(agent),
             Object::FinalizationRegistry(data) => data.internal_prototype(agent),
@@ -945,6 +1180,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_prototype(agent),
             Object::MapIterator(data) => data.internal_prototype(agent),
+            Object::IteratorHelper(data) => data.internal_prototype(agent),
+            Object::WrapForValidIterator(data) => data.internal_prototype(agent),
             Object::Generator(data) => data.internal_prototype(agent),
             Object::Module(data) => data.internal_prototype(agent),
             Object::EmbedderObject(data) => data.internal_prototype(agent),
@@ -959,6 +1196,26 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_set_prototype(agent, prototype),
             Object::Error(data) => data.internal_set_prototype(agent, prototype),
             Object::BoundFunction(data) => data.internal_set_prototype(agent, prototype),
             Object::BuiltinFunction(data) => data.internal_set_prototype(agent, prototype),
@@ -971,9 +1228,13 @@ impl<'a> InternalSlots<'a> for Object<'a> {
                 data.internal_set_prototype(agent, prototype)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_set_prototype(agent, prototype)
+            }
             Object::PrimitiveObject(data) => data.internal_set_prototype(agent, prototype),
             Object::Arguments(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_prototype(agent, prototype),
             Object::FinalizationRegistry(data) => data.internal_set_prototype(agent, prototype),
@@ -1047,6 +1308,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::MapIterator(data) => data.internal_set_prototype(agent, prototype),
+            Object::IteratorHelper(data) => data.internal_set_prototype(agent, prototype),
+            Object::WrapForValidIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::Generator(data) => data.internal_set_prototype(agent, prototype),
             Object::Module(data) => data.internal_set_prototype(agent, prototype),
             Object::EmbedderObject(data) => data.internal_set_prototype(agent, prototype),
@@ -1067,6 +1330,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_get_prototype_of(agent, gc),
             Object::Error(data) => data.try_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.try_get_prototype_of(agent, gc),
@@ -1075,9 +1358,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::PrimitiveObject(data) => data.try_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get_prototype_of(agent, gc),
             Object::FinalizationRegistry(data) => data.try_get_prototype_of(agent, gc),
@@ -1149,6 +1434,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::MapIterator(data) => data.try_get_prototype_of(agent, gc),
+            Object::IteratorHelper(data) => data.try_get_prototype_of(agent, gc),
+            Object::WrapForValidIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::Generator(data) => data.try_get_prototype_of(agent, gc),
             Object::Module(data) => data.try_get_prototype_of(agent, gc),
             Object::EmbedderObject(data) => data.try_get_prototype_of(agent, gc),
@@ -1167,6 +1454,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_get_prototype_of(agent, gc),
             Object::Error(data) => data.internal_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.internal_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.internal_get_prototype_of(agent, gc),
@@ -1177,9 +1484,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_get_prototype_of(agent, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_get_prototype_of(agent, gc),
             Object::PrimitiveObject(data) => data.internal_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get_prototype_of(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_get_prototype_of(agent, gc),
@@ -1253,6 +1562,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::MapIterator(data) => data.internal_get_prototype_of(agent, gc),
+            Object::IteratorHelper(data) => data.internal_get_prototype_of(agent, gc),
+            Object::WrapForValidIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::Generator(data) => data.internal_get_prototype_of(agent, gc),
             Object::Module(data) => data.internal_get_prototype_of(agent, gc),
             Object::EmbedderObject(data) => data.internal_get_prototype_of(agent, gc),
@@ -1272,6 +1583,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1284,9 +1615,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_set_prototype_of(agent, prototype, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_set_prototype_of(agent, prototype, gc)
+            }
             Object::PrimitiveObject(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::FinalizationRegistry(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1360,6 +1695,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::MapIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
+            Object::IteratorHelper(data) => data.try_set_prototype_of(agent, prototype, gc),
+            Object::WrapForValidIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Generator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Module(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::EmbedderObject(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1379,6 +1716,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1393,9 +1750,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_set_prototype_of(agent, prototype, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_set_prototype_of(agent, prototype, gc)
+            }
             Object::PrimitiveObject(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::FinalizationRegistry(data) => {
@@ -1471,6 +1832,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::MapIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            Object::IteratorHelper(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            Object::WrapForValidIterator(data) => {
+                data.internal_set_prototype_of(agent, prototype, gc)
+            }
             Object::Generator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Module(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::EmbedderObject(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1485,6 +1850,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_is_extensible(agent, gc),
             Object::Error(data) => data.try_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.try_is_extensible(agent, gc),
@@ -1493,9 +1878,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.try_is_extensible(agent, gc),
             Object::PrimitiveObject(data) => data.try_is_extensible(agent, gc),
             Object::Arguments(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_is_extensible(agent, gc),
             Object::FinalizationRegistry(data) => data.try_is_extensible(agent, gc),
@@ -1557,6 +1944,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_is_extensible(agent, gc),
             Object::MapIterator(data) => data.try_is_extensible(agent, gc),
+            Object::IteratorHelper(data) => data.try_is_extensible(agent, gc),
+            Object::WrapForValidIterator(data) => data.try_is_extensible(agent, gc),
             Object::Generator(data) => data.try_is_extensible(agent, gc),
             Object::Module(data) => data.try_is_extensible(agent, gc),
             Object::EmbedderObject(data) => data.try_is_extensible(agent, gc),
@@ -1575,6 +1964,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_is_extensible(agent, gc),
             Object::Error(data) => data.internal_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.internal_is_extensible(agent, gc),
@@ -1583,9 +1992,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_is_extensible(agent, gc),
             Object::PrimitiveObject(data) => data.internal_is_extensible(agent, gc),
             Object::Arguments(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_is_extensible(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_is_extensible(agent, gc),
@@ -1659,6 +2070,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_is_extensible(agent, gc),
             Object::MapIterator(data) => data.internal_is_extensible(agent, gc),
+            Object::IteratorHelper(data) => data.internal_is_extensible(agent, gc),
+            Object::WrapForValidIterator(data) => data.internal_is_extensible(agent, gc),
             Object::Generator(data) => data.internal_is_extensible(agent, gc),
             Object::Module(data) => data.internal_is_extensible(agent, gc),
             Object::EmbedderObject(data) => data.internal_is_extensible(agent, gc),
@@ -1673,6 +2086,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_prevent_extensions(agent, gc),
             Object::Error(data) => data.try_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.try_prevent_extensions(agent, gc),
@@ -1681,9 +2114,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::PrimitiveObject(data) => data.try_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_prevent_extensions(agent, gc),
             Object::FinalizationRegistry(data) => data.try_prevent_extensions(agent, gc),
@@ -1757,6 +2192,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::MapIterator(data) => data.try_prevent_extensions(agent, gc),
+            Object::IteratorHelper(data) => data.try_prevent_extensions(agent, gc),
+            Object::WrapForValidIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::Generator(data) => data.try_prevent_extensions(agent, gc),
             Object::Module(data) => data.try_prevent_extensions(agent, gc),
             Object::EmbedderObject(data) => data.try_prevent_extensions(agent, gc),
@@ -1775,6 +2212,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_prevent_extensions(agent, gc),
             Object::Error(data) => data.internal_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.internal_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.internal_prevent_extensions(agent, gc),
@@ -1785,9 +2242,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_prevent_extensions(agent, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_prevent_extensions(agent, gc)
+            }
             Object::PrimitiveObject(data) => data.internal_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_prevent_extensions(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_prevent_extensions(agent, gc),
@@ -1861,6 +2322,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::MapIterator(data) => data.internal_prevent_extensions(agent, gc),
+            Object::IteratorHelper(data) => data.internal_prevent_extensions(agent, gc),
+            Object::WrapForValidIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::Generator(data) => data.internal_prevent_extensions(agent, gc),
             Object::Module(data) => data.internal_prevent_extensions(agent, gc),
             Object::EmbedderObject(data) => data.internal_prevent_extensions(agent, gc),
@@ -1880,6 +2343,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Error(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1892,9 +2375,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_get_own_property(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_get_own_property(agent, property_key, gc)
+            }
             Object::PrimitiveObject(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get_own_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -1970,6 +2457,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::MapIterator(data) => data.try_get_own_property(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.try_get_own_property(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => {
+                data.try_get_own_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Module(data) => data.try_get_own_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1989,6 +2480,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::Error(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => {
@@ -2005,11 +2516,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_get_own_property(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::PrimitiveObject(data) => {
                 data.internal_get_own_property(agent, property_key, gc)
             }
             Object::Arguments(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_get_own_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -2086,6 +2601,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_get_own_property(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.internal_get_own_property(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::Module(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_get_own_property(agent, property_key, gc),
@@ -2114,6 +2633,46 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "intl")]
+            Object::Collator(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::PluralRules(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2134,13 +2693,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::PrimitiveObject(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
             Object::Arguments(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2272,6 +2837,12 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::MapIterator(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            Object::IteratorHelper(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            Object::WrapForValidIterator(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Generator(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2306,6 +2877,46 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "intl")]
+            Object::Collator(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::PluralRules(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2326,13 +2937,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::PrimitiveObject(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
             Object::Arguments(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2440,6 +3057,12 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::MapIterator(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            Object::IteratorHelper(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
+            Object::WrapForValidIterator(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Generator(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2465,6 +3088,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_has_property(agent, property_key, gc),
             Object::Error(data) => data.try_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_has_property(agent, property_key, gc),
@@ -2477,9 +3120,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_has_property(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_has_property(agent, property_key, gc)
+            }
             Object::PrimitiveObject(data) => data.try_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_has_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.try_has_property(agent, property_key, gc),
@@ -2553,6 +3200,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::MapIterator(data) => data.try_has_property(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.try_has_property(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::Generator(data) => data.try_has_property(agent, property_key, gc),
             Object::Module(data) => data.try_has_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_has_property(agent, property_key, gc),
@@ -2572,6 +3221,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_has_property(agent, property_key, gc),
             Object::Error(data) => data.internal_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_has_property(agent, property_key, gc),
@@ -2584,9 +3253,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_has_property(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_has_property(agent, property_key, gc)
+            }
             Object::PrimitiveObject(data) => data.internal_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_has_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -2662,6 +3335,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_has_property(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_has_property(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.internal_has_property(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => {
+                data.internal_has_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.internal_has_property(agent, property_key, gc),
             Object::Module(data) => data.internal_has_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_has_property(agent, property_key, gc),
@@ -2682,6 +3359,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2694,9 +3391,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_get(agent, property_key, receiver, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_get(agent, property_key, receiver, gc)
+            }
             Object::PrimitiveObject(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get(agent, property_key, receiver, gc),
             Object::FinalizationRegistry(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2770,6 +3471,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::MapIterator(data) => data.try_get(agent, property_key, receiver, gc),
+            Object::IteratorHelper(data) => data.try_get(agent, property_key, receiver, gc),
+            Object::WrapForValidIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Generator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Module(data) => data.try_get(agent, property_key, receiver, gc),
             Object::EmbedderObject(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2790,6 +3493,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2804,9 +3527,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_get(agent, property_key, receiver, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_get(agent, property_key, receiver, gc)
+            }
             Object::PrimitiveObject(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -2882,6 +3609,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::MapIterator(data) => data.internal_get(agent, property_key, receiver, gc),
+            Object::IteratorHelper(data) => data.internal_get(agent, property_key, receiver, gc),
+            Object::WrapForValidIterator(data) => {
+                data.internal_get(agent, property_key, receiver, gc)
+            }
             Object::Generator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Module(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::EmbedderObject(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2903,6 +3634,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Error(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BuiltinFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -2917,9 +3668,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_set(agent, property_key, value, receiver, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.try_set(agent, property_key, value, receiver, gc)
+            }
             Object::PrimitiveObject(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Arguments(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -3001,6 +3756,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::MapIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
+            Object::IteratorHelper(data) => data.try_set(agent, property_key, value, receiver, gc),
+            Object::WrapForValidIterator(data) => {
+                data.try_set(agent, property_key, value, receiver, gc)
+            }
             Object::Generator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Module(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::EmbedderObject(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -3024,6 +3783,36 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             }
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::Error(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
@@ -3042,11 +3831,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::PrimitiveObject(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
             Object::Arguments(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -3156,6 +3949,12 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::MapIterator(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
+            Object::IteratorHelper(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
+            Object::WrapForValidIterator(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::Generator(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::Module(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::EmbedderObject(data) => {
@@ -3177,6 +3976,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_delete(agent, property_key, gc),
             Object::Error(data) => data.try_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_delete(agent, property_key, gc),
@@ -3187,9 +4006,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.try_delete(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.try_delete(agent, property_key, gc),
             Object::PrimitiveObject(data) => data.try_delete(agent, property_key, gc),
             Object::Arguments(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_delete(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.try_delete(agent, property_key, gc),
@@ -3263,6 +4084,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_delete(agent, property_key, gc),
             Object::MapIterator(data) => data.try_delete(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.try_delete(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => data.try_delete(agent, property_key, gc),
             Object::Generator(data) => data.try_delete(agent, property_key, gc),
             Object::Module(data) => data.try_delete(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_delete(agent, property_key, gc),
@@ -3282,6 +4105,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_delete(agent, property_key, gc),
             Object::Error(data) => data.internal_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_delete(agent, property_key, gc),
@@ -3294,9 +4137,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_delete(agent, property_key, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => {
+                data.internal_delete(agent, property_key, gc)
+            }
             Object::PrimitiveObject(data) => data.internal_delete(agent, property_key, gc),
             Object::Arguments(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_delete(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.internal_delete(agent, property_key, gc),
@@ -3370,6 +4217,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_delete(agent, property_key, gc),
+            Object::IteratorHelper(data) => data.internal_delete(agent, property_key, gc),
+            Object::WrapForValidIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::Generator(data) => data.internal_delete(agent, property_key, gc),
             Object::Module(data) => data.internal_delete(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_delete(agent, property_key, gc),
@@ -3388,6 +4237,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.try_own_property_keys(agent, gc),
             Object::Error(data) => data.try_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.try_own_property_keys(agent, gc),
@@ -3396,9 +4265,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinConstructorFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.try_own_property_keys(agent, gc),
             Object::PrimitiveObject(data) => data.try_own_property_keys(agent, gc),
             Object::Arguments(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_own_property_keys(agent, gc),
             Object::FinalizationRegistry(data) => data.try_own_property_keys(agent, gc),
@@ -3470,6 +4341,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_own_property_keys(agent, gc),
             Object::MapIterator(data) => data.try_own_property_keys(agent, gc),
+            Object::IteratorHelper(data) => data.try_own_property_keys(agent, gc),
+            Object::WrapForValidIterator(data) => data.try_own_property_keys(agent, gc),
             Object::Generator(data) => data.try_own_property_keys(agent, gc),
             Object::Module(data) => data.try_own_property_keys(agent, gc),
             Object::EmbedderObject(data) => data.try_own_property_keys(agent, gc),
@@ -3488,6 +4361,26 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.internal_own_property_keys(agent, gc),
             Object::Error(data) => data.internal_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.internal_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.internal_own_property_keys(agent, gc),
@@ -3498,9 +4391,11 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_own_property_keys(agent, gc)
             }
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.internal_own_property_keys(agent, gc),
             Object::PrimitiveObject(data) => data.internal_own_property_keys(agent, gc),
             Object::Arguments(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_own_property_keys(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_own_property_keys(agent, gc),
@@ -3574,6 +4469,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::MapIterator(data) => data.internal_own_property_keys(agent, gc),
+            Object::IteratorHelper(data) => data.internal_own_property_keys(agent, gc),
+            Object::WrapForValidIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::Generator(data) => data.internal_own_property_keys(agent, gc),
             Object::Module(data) => data.internal_own_property_keys(agent, gc),
             Object::EmbedderObject(data) => data.internal_own_property_keys(agent, gc),
@@ -3593,7 +4490,6 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ECMAScriptFunction(data) => {
                 data.internal_call(agent, this_value, arguments, gc)
             }
-            Object::EmbedderObject(_) => todo!(),
             _ => unreachable!(),
         }
     }
@@ -3629,6 +4525,26 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Object::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.mark_values(queues),
             Object::Error(data) => data.mark_values(queues),
             Object::BoundFunction(data) => data.mark_values(queues),
             Object::BuiltinFunction(data) => data.mark_values(queues),
@@ -3637,9 +4553,11 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::BuiltinConstructorFunction(data) => data.mark_values(queues),
             Object::BuiltinPromiseResolvingFunction(data) => data.mark_values(queues),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.mark_values(queues),
             Object::PrimitiveObject(data) => data.mark_values(queues),
             Object::Arguments(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.mark_values(queues),
             Object::FinalizationRegistry(data) => data.mark_values(queues),
@@ -3689,6 +4607,8 @@ impl HeapMarkAndSweep for Object<'static> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.mark_values(queues),
             Object::MapIterator(data) => data.mark_values(queues),
+            Object::IteratorHelper(data) => data.mark_values(queues),
+            Object::WrapForValidIterator(data) => data.mark_values(queues),
             Object::Generator(data) => data.mark_values(queues),
             Object::Module(data) => data.mark_values(queues),
             Object::EmbedderObject(data) => data.mark_values(queues),
@@ -3705,9 +4625,11 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::BuiltinConstructorFunction(data) => data.sweep_values(compactions),
             Object::BuiltinPromiseResolvingFunction(data) => data.sweep_values(compactions),
             Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinProxyRevokerFunction(data) => data.sweep_values(compactions),
             Object::PrimitiveObject(data) => data.sweep_values(compactions),
             Object::Arguments(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(data) => data.sweep_values(compactions),
             Object::Array(data) => data.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => data.sweep_values(compactions),
@@ -3715,6 +4637,26 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::DataView(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Object::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Object::Collator(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(data) => data.sweep_values(compactions),
             Object::Error(data) => data.sweep_values(compactions),
             Object::FinalizationRegistry(data) => data.sweep_values(compactions),
             Object::Map(data) => data.sweep_values(compactions),
@@ -3763,6 +4705,8 @@ impl HeapMarkAndSweep for Object<'static> {
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.sweep_values(compactions),
             Object::MapIterator(data) => data.sweep_values(compactions),
+            Object::IteratorHelper(data) => data.sweep_values(compactions),
+            Object::WrapForValidIterator(data) => data.sweep_values(compactions),
             Object::Generator(data) => data.sweep_values(compactions),
             Object::Module(data) => data.sweep_values(compactions),
             Object::EmbedderObject(data) => data.sweep_values(compactions),
@@ -3822,11 +4766,15 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::BuiltinPromiseCollectorFunction => {
                 Ok(Self::BuiltinPromiseCollectorFunction)
             }
-            HeapRootData::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => Ok(
+                Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function),
+            ),
             HeapRootData::PrimitiveObject(primitive_object) => {
                 Ok(Self::PrimitiveObject(primitive_object))
             }
             HeapRootData::Arguments(ordinary_object) => Ok(Self::Arguments(ordinary_object)),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            HeapRootData::RawJSON(ordinary_object) => Ok(Self::RawJSON(ordinary_object)),
             HeapRootData::Array(array) => Ok(Self::Array(array)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => Ok(Self::ArrayBuffer(array_buffer)),
@@ -3834,6 +4782,32 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::DataView(data_view) => Ok(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Ok(Self::Date(date)),
+            #[cfg(feature = "intl")]
+            HeapRootData::Collator(collator) => Ok(Self::Collator(collator)),
+            #[cfg(feature = "intl")]
+            HeapRootData::DateTimeFormat(date_time_format) => {
+                Ok(Self::DateTimeFormat(date_time_format))
+            }
+            #[cfg(feature = "intl")]
+            HeapRootData::NumberFormat(number_format) => Ok(Self::NumberFormat(number_format)),
+            #[cfg(feature = "intl")]
+            HeapRootData::PluralRules(plural_rules) => Ok(Self::PluralRules(plural_rules)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Duration(duration) => Ok(Self::Duration(duration)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Instant(instant) => Ok(Self::Instant(instant)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDate(plain_date) => Ok(Self::PlainDate(plain_date)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDateTime(plain_date_time) => {
+                Ok(Self::PlainDateTime(plain_date_time))
+            }
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainTime(plain_time) => Ok(Self::PlainTime(plain_time)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::ZonedDateTime(zoned_date_time) => {
+                Ok(Self::ZonedDateTime(zoned_date_time))
+            }
             HeapRootData::Error(error) => Ok(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Ok(Self::FinalizationRegistry(finalization_registry))
@@ -3886,12 +4860,19 @@ impl TryFrom<HeapRootData> for Object<'_> {
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => Ok(Self::SetIterator(set_iterator)),
             HeapRootData::MapIterator(map_iterator) => Ok(Self::MapIterator(map_iterator)),
+            HeapRootData::IteratorHelper(iterator_helper) => {
+                Ok(Self::IteratorHelper(iterator_helper))
+            }
+            HeapRootData::WrapForValidIterator(wrap_for_valid_iterator) => {
+                Ok(Self::WrapForValidIterator(wrap_for_valid_iterator))
+            }
             HeapRootData::Generator(generator) => Ok(Self::Generator(generator)),
             HeapRootData::Module(module) => Ok(Self::Module(module)),
             HeapRootData::EmbedderObject(embedder_object) => {
                 Ok(Self::EmbedderObject(embedder_object))
             }
-            HeapRootData::PromiseReaction(_)
+            HeapRootData::ArrayFromAsync(_)
+            | HeapRootData::PromiseReaction(_)
             | HeapRootData::Executable(_)
             | HeapRootData::Realm(_)
             | HeapRootData::Script(_)
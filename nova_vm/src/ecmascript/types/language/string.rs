@@ -29,7 +29,7 @@ use crate::{
 };
 
 pub use data::StringHeapData;
-use wtf8::Wtf8Buf;
+use wtf8::{Wtf8, Wtf8Buf};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -352,10 +352,9 @@ impl<'a> String<'a> {
                 }
                 Status::SmallString { data, len } => {
                     let string_len = string.len(agent);
-                    if *len + string_len <= 7 {
-                        let String::SmallString(smstr) = string else {
-                            unreachable!()
-                        };
+                    // NOTE: Heap strings can be shorter than 7 bytes if they
+                    // contain lone surrogates.
+                    if let (String::SmallString(smstr), true) = (string, *len + string_len <= 7) {
                         data[*len..(*len + string_len)]
                             .copy_from_slice(&smstr.data()[..string_len]);
                         *len += string_len;
@@ -381,7 +380,7 @@ impl<'a> String<'a> {
                 let str_slice = unsafe { core::str::from_utf8_unchecked(&data[..len]) };
                 SmallString::from_str_unchecked(str_slice).into()
             }
-            Status::String(string) => agent.heap.create(string.into_string().unwrap()).bind(gc),
+            Status::String(string) => agent.heap.create(string).bind(gc),
         }
     }
 
@@ -416,6 +415,23 @@ impl<'a> String<'a> {
         }
     }
 
+    /// Returns the UTF-16 code unit at the UTF-16 index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `idx` is not less than the UTF-16 string
+    /// length.
+    pub fn utf16_code_unit(
+        self,
+        agent: &impl Index<HeapString<'static>, Output = StringHeapData>,
+        idx: usize,
+    ) -> u16 {
+        match self {
+            String::String(s) => agent[s.unbind()].utf16_code_unit(idx),
+            String::SmallString(s) => s.utf16_code_unit(idx),
+        }
+    }
+
     /// Returns the corresponding UTF-8 index for a UTF-16 index into the
     /// string, or `None` if the UTF-16 index is the second code unit in a
     /// surrogate pair.
@@ -463,6 +479,16 @@ impl<'a> String<'a> {
         }
     }
 
+    pub fn as_wtf8<'string, 'agent: 'string>(
+        &'string self,
+        agent: &'agent impl Index<HeapString<'static>, Output = StringHeapData>,
+    ) -> &'string Wtf8 {
+        match self {
+            String::String(s) => agent[s.unbind()].as_wtf8(),
+            String::SmallString(s) => Wtf8::from_str(s.as_str()),
+        }
+    }
+
     /// If x and y have the same length and the same code units in the same
     /// positions, return true; otherwise, return false.
     pub fn eq(
@@ -555,6 +581,11 @@ impl<'gc> String<'gc> {
         agent.heap.create(string).bind(gc)
     }
 
+    /// Creates a String from WTF-8 data, which may contain lone surrogates.
+    pub fn from_wtf8_buf(agent: &mut Agent, buf: Wtf8Buf, gc: NoGcScope<'gc, '_>) -> Self {
+        agent.heap.create(buf).bind(gc)
+    }
+
     pub fn from_static_str(agent: &mut Agent, str: &'static str, _gc: NoGcScope<'gc, '_>) -> Self {
         if let Ok(value) = String::try_from(str) {
             value
@@ -594,6 +625,7 @@ impl Scoped<'_, String<'static>> {
 
 impl<'a> CreateHeapData<(StringHeapData, u64), String<'a>> for Heap {
     fn create(&mut self, (data, hash): (StringHeapData, u64)) -> String<'a> {
+        self.external_bytes += data.len();
         self.strings.push(Some(data));
         #[cfg(feature = "interleaved-gc")]
         {
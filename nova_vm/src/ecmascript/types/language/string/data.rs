@@ -4,7 +4,7 @@
 
 use core::{cell::OnceCell, hash::Hash, num::NonZeroUsize};
 
-use wtf8::{Wtf8, Wtf8Buf};
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
 
 use crate::heap::{CompactionLists, HeapMarkAndSweep, WorkQueues};
 
@@ -77,28 +77,34 @@ impl StringHeapData {
 
     fn index_mapping(&self) -> &IndexMapping {
         self.mapping.get_or_init(|| {
-            let mut iter = self.as_str().char_indices();
-
-            let Some((idx, ch)) = iter.find(|(_, ch)| !ch.is_ascii()) else {
-                return IndexMapping::Ascii;
+            let mut iter = self.as_wtf8().code_points();
+
+            // Note: ASCII code points are a single byte in WTF-8.
+            let mut idx = 0;
+            let cp = loop {
+                match iter.next() {
+                    Some(cp) if cp.to_u32() < 0x80 => idx += 1,
+                    Some(cp) => break cp,
+                    None => return IndexMapping::Ascii,
+                }
             };
 
             // All indices less than `idx` map to ASCII bytes, so all UTF-16
             // indices less *or equal* than `idx` map to that same UTF-8 index
             let mut mapping: Vec<Option<NonZeroUsize>> = (0..=idx).map(NonZeroUsize::new).collect();
 
-            if ch.len_utf16() != 1 {
-                debug_assert_eq!(ch.len_utf16(), 2);
+            if code_point_len_utf16(cp) != 1 {
                 mapping.push(None);
             }
+            idx += code_point_len_wtf8(cp);
 
-            for (idx, ch) in iter {
+            for cp in iter {
                 assert_ne!(idx, 0);
                 mapping.push(NonZeroUsize::new(idx));
-                if ch.len_utf16() != 1 {
-                    debug_assert_eq!(ch.len_utf16(), 2);
+                if code_point_len_utf16(cp) != 1 {
                     mapping.push(None);
                 }
+                idx += code_point_len_wtf8(cp);
             }
 
             assert!(
@@ -132,12 +138,43 @@ impl StringHeapData {
         } else {
             0
         };
-        let ch = self.as_str()[utf8_idx..].chars().next().unwrap();
+        let cp = self.as_wtf8().slice_from(utf8_idx).code_points().next();
         // TODO: Deal with surrogates.
+        let ch = cp.unwrap().to_char().unwrap();
         assert_eq!(ch.len_utf16(), 1);
         ch
     }
 
+    /// Returns the UTF-16 code unit at the UTF-16 index `idx`. Code points
+    /// outside the Basic Multilingual Plane are split into their surrogate
+    /// pair, and lone surrogates are returned as-is.
+    pub fn utf16_code_unit(&self, idx: usize) -> u16 {
+        let (utf8_idx, is_trailing) = match self.index_mapping() {
+            IndexMapping::Ascii => return u16::from(self.as_wtf8().ascii_byte_at(idx)),
+            IndexMapping::NonAscii { .. } if idx == 0 => (0, false),
+            IndexMapping::NonAscii { mapping } => match mapping[idx] {
+                Some(utf8_idx) => (utf8_idx.get(), false),
+                // The second code unit of a surrogate pair: the pair starts
+                // at the previous UTF-16 index.
+                None => (mapping[idx - 1].map_or(0, NonZeroUsize::get), true),
+            },
+        };
+        let cp = self
+            .as_wtf8()
+            .slice_from(utf8_idx)
+            .code_points()
+            .next()
+            .unwrap()
+            .to_u32();
+        if cp < 0x10000 {
+            cp as u16
+        } else if is_trailing {
+            0xDC00 | ((cp - 0x10000) & 0x3FF) as u16
+        } else {
+            0xD800 | ((cp - 0x10000) >> 10) as u16
+        }
+    }
+
     pub fn utf8_index(&self, utf16_idx: usize) -> Option<usize> {
         if utf16_idx == 0 {
             Some(0)
@@ -252,6 +289,15 @@ impl StringHeapData {
         }
     }
 
+    pub fn from_wtf8_buf(buf: Wtf8Buf) -> Self {
+        debug_assert!(buf.len() > 7 || buf.as_str().is_none());
+        assert!(buf.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
+        StringHeapData {
+            data: StringBuffer::Owned(buf),
+            mapping: OnceCell::new(),
+        }
+    }
+
     pub fn from_string(str: String) -> Self {
         debug_assert!(str.len() > 7);
         assert!(str.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
@@ -262,6 +308,22 @@ impl StringHeapData {
     }
 }
 
+/// Length of the code point in WTF-8 bytes. Surrogates are encoded like any
+/// other code point in the Basic Multilingual Plane.
+fn code_point_len_wtf8(cp: CodePoint) -> usize {
+    match cp.to_u32() {
+        0..0x80 => 1,
+        0x80..0x800 => 2,
+        0x800..0x10000 => 3,
+        _ => 4,
+    }
+}
+
+/// Length of the code point in UTF-16 code units.
+fn code_point_len_utf16(cp: CodePoint) -> usize {
+    if cp.to_u32() < 0x10000 { 1 } else { 2 }
+}
+
 impl HeapMarkAndSweep for StringHeapData {
     fn mark_values(&self, _queues: &mut WorkQueues) {
         let Self {
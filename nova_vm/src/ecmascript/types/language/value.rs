@@ -13,10 +13,23 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::Duration, instant::Instant, plain_date::PlainDate, plain_date_time::PlainDateTime,
+    plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+};
 #[cfg(feature = "set")]
 use crate::ecmascript::builtins::{
     keyed_collections::set_objects::set_iterator_objects::set_iterator::SetIterator, set::Set,
@@ -34,6 +47,9 @@ use crate::{
             Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+            },
             control_abstraction_objects::{
                 generator_objects::Generator,
                 promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction,
@@ -47,7 +63,7 @@ use crate::{
             module::Module,
             primitive_objects::PrimitiveObject,
             promise::Promise,
-            proxy::Proxy,
+            proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
         },
         execution::{Agent, JsResult},
         types::BUILTIN_STRING_MEMORY,
@@ -141,7 +157,7 @@ pub enum Value<'a> {
     BuiltinConstructorFunction(BuiltinConstructorFunction<'a>),
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>),
     BuiltinPromiseCollectorFunction,
-    BuiltinProxyRevokerFunction,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>),
 
     // Boolean, Number, String, Symbol, BigInt objects
     PrimitiveObject(PrimitiveObject<'a>),
@@ -157,6 +173,12 @@ pub enum Value<'a> {
     /// internal slot \[\[ParameterMap]] whose value is always **undefined**.
     Arguments(OrdinaryObject<'a>),
     // TODO: MappedArguments(MappedArgumentsObject),
+    /// ### [Raw JSON objects](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+    ///
+    /// A raw JSON object is a frozen ordinary object with a null prototype
+    /// and an additional internal slot \[\[IsRawJSON]].
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    RawJSON(OrdinaryObject<'a>),
     Array(Array<'a>),
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'a>),
@@ -164,6 +186,26 @@ pub enum Value<'a> {
     DataView(DataView<'a>),
     #[cfg(feature = "date")]
     Date(Date<'a>),
+    #[cfg(feature = "intl")]
+    Collator(Collator<'a>),
+    #[cfg(feature = "intl")]
+    DateTimeFormat(DateTimeFormat<'a>),
+    #[cfg(feature = "intl")]
+    NumberFormat(NumberFormat<'a>),
+    #[cfg(feature = "intl")]
+    PluralRules(PluralRules<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    Duration(Duration<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    Instant(Instant<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    PlainDate(PlainDate<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    PlainDateTime(PlainDateTime<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    PlainTime(PlainTime<'a>),
+    #[cfg(feature = "proposal-temporal")]
+    ZonedDateTime(ZonedDateTime<'a>),
     Error(Error<'a>),
     FinalizationRegistry(FinalizationRegistry<'a>),
     Map(Map<'a>),
@@ -217,6 +259,8 @@ pub enum Value<'a> {
     #[cfg(feature = "set")]
     SetIterator(SetIterator<'a>),
     MapIterator(MapIterator<'a>),
+    IteratorHelper(IteratorHelper<'a>),
+    WrapForValidIterator(WrapForValidIterator<'a>),
     Generator(Generator<'a>),
 
     // ECMAScript Module
@@ -267,6 +311,33 @@ pub(crate) const ARRAY_BUFFER_DISCRIMINANT: u8 =
     value_discriminant(Value::ArrayBuffer(ArrayBuffer::_def()));
 #[cfg(feature = "date")]
 pub(crate) const DATE_DISCRIMINANT: u8 = value_discriminant(Value::Date(Date::_def()));
+#[cfg(feature = "intl")]
+pub(crate) const COLLATOR_DISCRIMINANT: u8 = value_discriminant(Value::Collator(Collator::_def()));
+#[cfg(feature = "intl")]
+pub(crate) const DATE_TIME_FORMAT_DISCRIMINANT: u8 =
+    value_discriminant(Value::DateTimeFormat(DateTimeFormat::_def()));
+#[cfg(feature = "intl")]
+pub(crate) const NUMBER_FORMAT_DISCRIMINANT: u8 =
+    value_discriminant(Value::NumberFormat(NumberFormat::_def()));
+#[cfg(feature = "intl")]
+pub(crate) const PLURAL_RULES_DISCRIMINANT: u8 =
+    value_discriminant(Value::PluralRules(PluralRules::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const DURATION_DISCRIMINANT: u8 = value_discriminant(Value::Duration(Duration::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const INSTANT_DISCRIMINANT: u8 = value_discriminant(Value::Instant(Instant::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const PLAIN_DATE_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainDate(PlainDate::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const PLAIN_DATE_TIME_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainDateTime(PlainDateTime::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const PLAIN_TIME_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainTime(PlainTime::_def()));
+#[cfg(feature = "proposal-temporal")]
+pub(crate) const ZONED_DATE_TIME_DISCRIMINANT: u8 =
+    value_discriminant(Value::ZonedDateTime(ZonedDateTime::_def()));
 pub(crate) const ERROR_DISCRIMINANT: u8 = value_discriminant(Value::Error(Error::_def()));
 pub(crate) const BUILTIN_FUNCTION_DISCRIMINANT: u8 =
     value_discriminant(Value::BuiltinFunction(BuiltinFunction::_def()));
@@ -287,12 +358,16 @@ pub(crate) const BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT: u8 = value_dis
 );
 pub(crate) const BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT: u8 =
     value_discriminant(Value::BuiltinPromiseCollectorFunction);
-pub(crate) const BUILTIN_PROXY_REVOKER_FUNCTION: u8 =
-    value_discriminant(Value::BuiltinProxyRevokerFunction);
+pub(crate) const BUILTIN_PROXY_REVOKER_FUNCTION: u8 = value_discriminant(
+    Value::BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction::_def()),
+);
 pub(crate) const PRIMITIVE_OBJECT_DISCRIMINANT: u8 =
     value_discriminant(Value::PrimitiveObject(PrimitiveObject::_def()));
 pub(crate) const ARGUMENTS_DISCRIMINANT: u8 =
     value_discriminant(Value::Arguments(OrdinaryObject::_def()));
+#[cfg(feature = "proposal-json-parse-with-source")]
+pub(crate) const RAW_JSON_DISCRIMINANT: u8 =
+    value_discriminant(Value::RawJSON(OrdinaryObject::_def()));
 #[cfg(feature = "array-buffer")]
 pub(crate) const DATA_VIEW_DISCRIMINANT: u8 = value_discriminant(Value::DataView(DataView::_def()));
 pub(crate) const FINALIZATION_REGISTRY_DISCRIMINANT: u8 =
@@ -359,6 +434,10 @@ pub(crate) const SET_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::SetIterator(SetIterator::_def()));
 pub(crate) const MAP_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::MapIterator(MapIterator::_def()));
+pub(crate) const ITERATOR_HELPER_DISCRIMINANT: u8 =
+    value_discriminant(Value::IteratorHelper(IteratorHelper::_def()));
+pub(crate) const WRAP_FOR_VALID_ITERATOR_DISCRIMINANT: u8 =
+    value_discriminant(Value::WrapForValidIterator(WrapForValidIterator::_def()));
 pub(crate) const GENERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::Generator(Generator::_def()));
 pub(crate) const MODULE_DISCRIMINANT: u8 = value_discriminant(Value::Module(Module::_def()));
@@ -716,7 +795,10 @@ impl<'a> Value<'a> {
                 data.get_index().hash(hasher);
             }
             Value::BuiltinPromiseCollectorFunction => todo!(),
-            Value::BuiltinProxyRevokerFunction => todo!(),
+            Value::BuiltinProxyRevokerFunction(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::PrimitiveObject(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -725,6 +807,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Array(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -744,6 +831,56 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "intl")]
+            Value::Collator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::PluralRules(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -873,6 +1010,14 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            Value::IteratorHelper(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            Value::WrapForValidIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Generator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -947,7 +1092,10 @@ impl<'a> Value<'a> {
                 data.get_index().hash(hasher);
             }
             Value::BuiltinPromiseCollectorFunction => todo!(),
-            Value::BuiltinProxyRevokerFunction => todo!(),
+            Value::BuiltinProxyRevokerFunction(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::PrimitiveObject(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -956,6 +1104,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Array(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -975,6 +1128,56 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "intl")]
+            Value::Collator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "intl")]
+            Value::PluralRules(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1104,6 +1307,14 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            Value::IteratorHelper(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
+            Value::WrapForValidIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Generator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1261,13 +1472,17 @@ impl Rootable for Value<'_> {
             Self::BuiltinPromiseCollectorFunction => {
                 Err(HeapRootData::BuiltinPromiseCollectorFunction)
             }
-            Self::BuiltinProxyRevokerFunction => Err(HeapRootData::BuiltinProxyRevokerFunction),
+            Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => Err(
+                HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function.unbind()),
+            ),
             Self::PrimitiveObject(primitive_object) => {
                 Err(HeapRootData::PrimitiveObject(primitive_object.unbind()))
             }
             Self::Arguments(ordinary_object) => {
                 Err(HeapRootData::Arguments(ordinary_object.unbind()))
             }
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Self::RawJSON(ordinary_object) => Err(HeapRootData::RawJSON(ordinary_object.unbind())),
             Self::Array(array) => Err(HeapRootData::Array(array.unbind())),
             #[cfg(feature = "array-buffer")]
             Self::ArrayBuffer(array_buffer) => {
@@ -1277,6 +1492,36 @@ impl Rootable for Value<'_> {
             Self::DataView(data_view) => Err(HeapRootData::DataView(data_view.unbind())),
             #[cfg(feature = "date")]
             Self::Date(date) => Err(HeapRootData::Date(date.unbind())),
+            #[cfg(feature = "intl")]
+            Self::Collator(collator) => Err(HeapRootData::Collator(collator.unbind())),
+            #[cfg(feature = "intl")]
+            Self::DateTimeFormat(date_time_format) => {
+                Err(HeapRootData::DateTimeFormat(date_time_format.unbind()))
+            }
+            #[cfg(feature = "intl")]
+            Self::NumberFormat(number_format) => {
+                Err(HeapRootData::NumberFormat(number_format.unbind()))
+            }
+            #[cfg(feature = "intl")]
+            Self::PluralRules(plural_rules) => {
+                Err(HeapRootData::PluralRules(plural_rules.unbind()))
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Self::Duration(duration) => Err(HeapRootData::Duration(duration.unbind())),
+            #[cfg(feature = "proposal-temporal")]
+            Self::Instant(instant) => Err(HeapRootData::Instant(instant.unbind())),
+            #[cfg(feature = "proposal-temporal")]
+            Self::PlainDate(plain_date) => Err(HeapRootData::PlainDate(plain_date.unbind())),
+            #[cfg(feature = "proposal-temporal")]
+            Self::PlainDateTime(plain_date_time) => {
+                Err(HeapRootData::PlainDateTime(plain_date_time.unbind()))
+            }
+            #[cfg(feature = "proposal-temporal")]
+            Self::PlainTime(plain_time) => Err(HeapRootData::PlainTime(plain_time.unbind())),
+            #[cfg(feature = "proposal-temporal")]
+            Self::ZonedDateTime(zoned_date_time) => {
+                Err(HeapRootData::ZonedDateTime(zoned_date_time.unbind()))
+            }
             Self::Error(error) => Err(HeapRootData::Error(error.unbind())),
             Self::FinalizationRegistry(finalization_registry) => Err(
                 HeapRootData::FinalizationRegistry(finalization_registry.unbind()),
@@ -1341,6 +1586,12 @@ impl Rootable for Value<'_> {
             Self::MapIterator(map_iterator) => {
                 Err(HeapRootData::MapIterator(map_iterator.unbind()))
             }
+            Self::IteratorHelper(iterator_helper) => {
+                Err(HeapRootData::IteratorHelper(iterator_helper.unbind()))
+            }
+            Self::WrapForValidIterator(wrap_for_valid_iterator) => Err(
+                HeapRootData::WrapForValidIterator(wrap_for_valid_iterator.unbind()),
+            ),
             Self::Generator(generator) => Err(HeapRootData::Generator(generator.unbind())),
             Self::Module(module) => Err(HeapRootData::Module(module.unbind())),
             Self::EmbedderObject(embedder_object) => {
@@ -1395,11 +1646,15 @@ impl Rootable for Value<'_> {
             HeapRootData::BuiltinPromiseCollectorFunction => {
                 Some(Self::BuiltinPromiseCollectorFunction)
             }
-            HeapRootData::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => Some(
+                Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function),
+            ),
             HeapRootData::PrimitiveObject(primitive_object) => {
                 Some(Self::PrimitiveObject(primitive_object))
             }
             HeapRootData::Arguments(ordinary_object) => Some(Self::Arguments(ordinary_object)),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            HeapRootData::RawJSON(ordinary_object) => Some(Self::RawJSON(ordinary_object)),
             HeapRootData::Array(array) => Some(Self::Array(array)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => Some(Self::ArrayBuffer(array_buffer)),
@@ -1407,6 +1662,32 @@ impl Rootable for Value<'_> {
             HeapRootData::DataView(data_view) => Some(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Some(Self::Date(date)),
+            #[cfg(feature = "intl")]
+            HeapRootData::Collator(collator) => Some(Self::Collator(collator)),
+            #[cfg(feature = "intl")]
+            HeapRootData::DateTimeFormat(date_time_format) => {
+                Some(Self::DateTimeFormat(date_time_format))
+            }
+            #[cfg(feature = "intl")]
+            HeapRootData::NumberFormat(number_format) => Some(Self::NumberFormat(number_format)),
+            #[cfg(feature = "intl")]
+            HeapRootData::PluralRules(plural_rules) => Some(Self::PluralRules(plural_rules)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Duration(duration) => Some(Self::Duration(duration)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Instant(instant) => Some(Self::Instant(instant)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDate(plain_date) => Some(Self::PlainDate(plain_date)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDateTime(plain_date_time) => {
+                Some(Self::PlainDateTime(plain_date_time))
+            }
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainTime(plain_time) => Some(Self::PlainTime(plain_time)),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::ZonedDateTime(zoned_date_time) => {
+                Some(Self::ZonedDateTime(zoned_date_time))
+            }
             HeapRootData::Error(error) => Some(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Some(Self::FinalizationRegistry(finalization_registry))
@@ -1463,12 +1744,19 @@ impl Rootable for Value<'_> {
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => Some(Self::SetIterator(set_iterator)),
             HeapRootData::MapIterator(map_iterator) => Some(Self::MapIterator(map_iterator)),
+            HeapRootData::IteratorHelper(iterator_helper) => {
+                Some(Self::IteratorHelper(iterator_helper))
+            }
+            HeapRootData::WrapForValidIterator(wrap_for_valid_iterator) => {
+                Some(Self::WrapForValidIterator(wrap_for_valid_iterator))
+            }
             HeapRootData::Generator(generator) => Some(Self::Generator(generator)),
             HeapRootData::Module(module) => Some(Self::Module(module)),
             HeapRootData::EmbedderObject(embedder_object) => {
                 Some(Self::EmbedderObject(embedder_object))
             }
-            HeapRootData::Executable(_)
+            HeapRootData::ArrayFromAsync(_)
+            | HeapRootData::Executable(_)
             | HeapRootData::Realm(_)
             | HeapRootData::Script(_)
             | HeapRootData::SourceCode(_)
@@ -1520,6 +1808,26 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Value::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Value::Collator(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(data) => data.mark_values(queues),
+            #[cfg(feature = "intl")]
+            Value::PluralRules(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(data) => data.mark_values(queues),
             Value::Error(data) => data.mark_values(queues),
             Value::BoundFunction(data) => data.mark_values(queues),
             Value::BuiltinFunction(data) => data.mark_values(queues),
@@ -1528,6 +1836,8 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::RegExp(data) => data.mark_values(queues),
             Value::PrimitiveObject(data) => data.mark_values(queues),
             Value::Arguments(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             Value::DataView(data) => data.mark_values(queues),
             Value::FinalizationRegistry(data) => data.mark_values(queues),
@@ -1572,7 +1882,7 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::BuiltinConstructorFunction(data) => data.mark_values(queues),
             Value::BuiltinPromiseResolvingFunction(data) => data.mark_values(queues),
             Value::BuiltinPromiseCollectorFunction => todo!(),
-            Value::BuiltinProxyRevokerFunction => todo!(),
+            Value::BuiltinProxyRevokerFunction(data) => data.mark_values(queues),
             Value::AsyncFromSyncIterator => todo!(),
             Value::AsyncGenerator(data) => data.mark_values(queues),
             Value::Iterator => todo!(),
@@ -1580,6 +1890,8 @@ impl HeapMarkAndSweep for Value<'static> {
             #[cfg(feature = "set")]
             Value::SetIterator(data) => data.mark_values(queues),
             Value::MapIterator(data) => data.mark_values(queues),
+            Value::IteratorHelper(data) => data.mark_values(queues),
+            Value::WrapForValidIterator(data) => data.mark_values(queues),
             Value::Generator(data) => data.mark_values(queues),
             Value::Module(data) => data.mark_values(queues),
             Value::EmbedderObject(data) => data.mark_values(queues),
@@ -1607,6 +1919,26 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::ArrayBuffer(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Value::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Value::Collator(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(data) => data.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            Value::PluralRules(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(data) => data.sweep_values(compactions),
             Value::Error(data) => data.sweep_values(compactions),
             Value::BoundFunction(data) => data.sweep_values(compactions),
             Value::BuiltinFunction(data) => data.sweep_values(compactions),
@@ -1615,6 +1947,8 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::RegExp(data) => data.sweep_values(compactions),
             Value::PrimitiveObject(data) => data.sweep_values(compactions),
             Value::Arguments(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => data.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             Value::DataView(data) => data.sweep_values(compactions),
             Value::FinalizationRegistry(data) => data.sweep_values(compactions),
@@ -1659,7 +1993,7 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::BuiltinConstructorFunction(data) => data.sweep_values(compactions),
             Value::BuiltinPromiseResolvingFunction(data) => data.sweep_values(compactions),
             Value::BuiltinPromiseCollectorFunction => todo!(),
-            Value::BuiltinProxyRevokerFunction => todo!(),
+            Value::BuiltinProxyRevokerFunction(data) => data.sweep_values(compactions),
             Value::AsyncFromSyncIterator => todo!(),
             Value::AsyncGenerator(data) => data.sweep_values(compactions),
             Value::Iterator => todo!(),
@@ -1667,6 +2001,8 @@ impl HeapMarkAndSweep for Value<'static> {
             #[cfg(feature = "set")]
             Value::SetIterator(data) => data.sweep_values(compactions),
             Value::MapIterator(data) => data.sweep_values(compactions),
+            Value::IteratorHelper(data) => data.sweep_values(compactions),
+            Value::WrapForValidIterator(data) => data.sweep_values(compactions),
             Value::Generator(data) => data.sweep_values(compactions),
             Value::Module(data) => data.sweep_values(compactions),
             Value::EmbedderObject(data) => data.sweep_values(compactions),
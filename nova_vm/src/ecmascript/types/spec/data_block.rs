@@ -821,6 +821,10 @@ impl DataBlock {
             ))
         } else if let Ok(size) = usize::try_from(size) {
             // 2. Let db be a new Data Block value consisting of size bytes.
+            // NOTE: It is impossible to create a Data Block that would grow
+            // the heap beyond the heap limit.
+            agent.check_heap_limit(size, gc)?;
+            agent.heap.external_bytes += size;
             // 3. Set all of the bytes of db to 0.
             // 4. Return db.
             Ok(Self::new(size))
@@ -847,6 +851,8 @@ impl DataBlock {
     ) -> JsResult<'a, Self> {
         // 1. Let db be a new Shared Data Block value consisting of size bytes. If it is impossible to create such a Shared Data Block, throw a RangeError exception.
         if let Ok(size) = usize::try_from(size) {
+            agent.check_heap_limit(size, gc)?;
+            agent.heap.external_bytes += size;
             // 2. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
             // 3. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
             // 4. Let zero be « 0 ».
@@ -5,8 +5,11 @@
 mod bytecode;
 pub mod context;
 pub mod rootable;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod small_f64;
 pub mod small_integer;
+pub mod structured_clone;
 
 use core::ops::ControlFlow;
 
@@ -15,4 +15,4 @@ pub(crate) use executable::{
     Executable, ExecutableHeapData, FunctionExpression, IndexType, SendableRef,
 };
 pub(crate) use instructions::{Instruction, InstructionIter};
-pub(crate) use vm::{ExecutionResult, SuspendedVm, Vm, instanceof_operator};
+pub(crate) use vm::{ExecutionResult, SuspendedVm, Vm, VmFrame, instanceof_operator};
@@ -31,7 +31,7 @@ use oxc_ast::ast::{
     self, BindingPattern, BindingRestElement, CallExpression, NewExpression, Statement,
 };
 use oxc_ecmascript::BoundNames;
-use oxc_span::Atom;
+use oxc_span::{Atom, GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
 pub type IndexType = u16;
@@ -59,7 +59,10 @@ pub(crate) struct CompileContext<'agent, 'gc, 'scope> {
     function_expressions: Vec<FunctionExpression<'gc>>,
     /// Arrow function expressions being built
     arrow_function_expressions: Vec<ArrowFunctionExpression>,
-    class_initializer_bytecodes: Vec<(Option<Executable<'gc>>, bool)>,
+    /// Class constructor data being built: the class field initializer
+    /// bytecode, whether the class has a constructor parent, and the source
+    /// text of the class.
+    class_initializer_bytecodes: Vec<(Option<Executable<'gc>>, bool, Span)>,
     /// NamedEvaluation name parameter
     name_identifier: Option<NamedEvaluationParameter>,
     /// If true, indicates that all bindings being created are lexical.
@@ -77,6 +80,13 @@ pub(crate) struct CompileContext<'agent, 'gc, 'scope> {
     /// In a `(a?.b).unbind()?.bind(gc.nogc()).()` chain the evaluation of `(a?.b)` must be considered a
     /// reference.
     is_call_optional_chain_this: bool,
+    /// Source position table being built: pairs of instruction offsets and
+    /// the source text offset that the instruction at that offset (and all
+    /// instructions following it up to the next entry) originate from.
+    source_positions: Vec<(u32, u32)>,
+    /// Source text offset of the statement or expression currently being
+    /// compiled.
+    current_source_position: u32,
 }
 
 impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
@@ -99,6 +109,8 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             current_break: None,
             optional_chains: None,
             is_call_optional_chain_this: false,
+            source_positions: Vec::new(),
+            current_source_position: 0,
         }
     }
 
@@ -225,8 +237,9 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             class_initializer_bytecodes: self
                 .class_initializer_bytecodes
                 .into_iter()
-                .map(|(exe, b)| (exe.unbind(), b))
+                .map(|(exe, b, span)| (exe.unbind(), b, span))
                 .collect(),
+            source_positions: self.source_positions.into_boxed_slice(),
         })
     }
 
@@ -260,7 +273,28 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         None
     }
 
+    /// Set the source text position that following instructions originate
+    /// from. Returns the previous position, which should be restored with
+    /// [`CompileContext::exit_source_position`] once the statement or
+    /// expression has been compiled.
+    fn enter_source_position(&mut self, span: Span) -> u32 {
+        core::mem::replace(&mut self.current_source_position, span.start)
+    }
+
+    fn exit_source_position(&mut self, previous: u32) {
+        self.current_source_position = previous;
+    }
+
     fn _push_instruction(&mut self, instruction: Instruction) {
+        let position = self.current_source_position;
+        if self
+            .source_positions
+            .last()
+            .is_none_or(|&(_, last_position)| last_position != position)
+        {
+            let offset = u32::try_from(self.instructions.len()).expect("Bytecode is too large");
+            self.source_positions.push((offset, position));
+        }
         self.instructions
             .push(unsafe { core::mem::transmute::<Instruction, u8>(instruction) });
     }
@@ -786,23 +820,33 @@ impl CompileEvaluation for ast::ArrowFunctionExpression<'_> {
 
 impl CompileEvaluation for ast::Function<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        // CompileContext holds a name identifier for us if this is NamedEvaluation.
-        let identifier = ctx.name_identifier.take();
-        ctx.add_instruction_with_function_expression(
-            Instruction::InstantiateOrdinaryFunctionExpression,
-            FunctionExpression {
-                expression: SendableRef::new(unsafe {
-                    core::mem::transmute::<&ast::Function<'_>, &'static ast::Function<'static>>(
-                        self,
-                    )
-                }),
-                identifier,
-                compiled_bytecode: None,
-            },
-        );
+        compile_function_expression(self, self.span, ctx);
     }
 }
 
+/// Compiles a function expression whose \[\[SourceText]] is `source_text`.
+fn compile_function_expression(
+    function: &ast::Function,
+    source_text: Span,
+    ctx: &mut CompileContext,
+) {
+    // CompileContext holds a name identifier for us if this is NamedEvaluation.
+    let identifier = ctx.name_identifier.take();
+    ctx.add_instruction_with_function_expression(
+        Instruction::InstantiateOrdinaryFunctionExpression,
+        FunctionExpression {
+            expression: SendableRef::new(unsafe {
+                core::mem::transmute::<&ast::Function<'_>, &'static ast::Function<'static>>(
+                    function,
+                )
+            }),
+            identifier,
+            compiled_bytecode: None,
+            source_text,
+        },
+    );
+}
+
 impl CompileEvaluation for ast::ObjectExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         // TODO: Consider preparing the properties onto the stack and creating
@@ -901,7 +945,14 @@ impl CompileEvaluation for ast::ObjectExpression<'_> {
                             if !is_proto_setter && is_anonymous_function_definition(&prop.value) {
                                 ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
                             }
-                            prop.value.compile(ctx);
+                            match &prop.value {
+                                // MethodDefinition : ClassElementName ( UniqueFormalParameters ) { FunctionBody }
+                                // 6. Let sourceText be the source text matched by MethodDefinition.
+                                ast::Expression::FunctionExpression(function) if prop.method => {
+                                    compile_function_expression(function, prop.span, ctx)
+                                }
+                                value => value.compile(ctx),
+                            }
                             if is_reference(&prop.value) {
                                 ctx.add_instruction(Instruction::GetValue);
                             }
@@ -939,6 +990,7 @@ impl CompileEvaluation for ast::ObjectExpression<'_> {
                                     }),
                                     identifier: None,
                                     compiled_bytecode: None,
+                                    source_text: prop.span,
                                 },
                                 // enumerable: true,
                                 true.into(),
@@ -1532,6 +1584,7 @@ impl CompileEvaluation for ast::YieldExpression<'_> {
 
 impl CompileEvaluation for ast::Expression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        let previous_position = ctx.enter_source_position(self.span());
         match self {
             ast::Expression::ArrayExpression(x) => x.compile(ctx),
             ast::Expression::ArrowFunctionExpression(x) => x.compile(ctx),
@@ -1579,6 +1632,7 @@ impl CompileEvaluation for ast::Expression<'_> {
             | ast::Expression::TSNonNullExpression(_)
             | ast::Expression::TSInstantiationExpression(_) => unreachable!(),
         }
+        ctx.exit_source_position(previous_position);
     }
 }
 
@@ -2769,6 +2823,7 @@ impl CompileEvaluation for ast::ContinueStatement<'_> {
 
 impl CompileEvaluation for ast::Statement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        let previous_position = ctx.enter_source_position(self.span());
         match self {
             ast::Statement::ExpressionStatement(x) => x.compile(ctx),
             ast::Statement::ReturnStatement(x) => x.compile(ctx),
@@ -2810,6 +2865,7 @@ impl CompileEvaluation for ast::Statement<'_> {
             | Statement::TSModuleDeclaration(_)
             | Statement::TSNamespaceExportDeclaration(_) => unreachable!(),
         }
+        ctx.exit_source_position(previous_position);
     }
 }
 
@@ -23,6 +23,7 @@ use crate::{
 use ahash::{AHashMap, AHashSet};
 use oxc_ast::ast::{self, MethodDefinitionKind};
 use oxc_ecmascript::{BoundNames, PrivateBoundIdentifiers, PropName};
+use oxc_span::Span;
 
 use super::IndexType;
 
@@ -280,10 +281,17 @@ impl CompileEvaluation for ast::Class<'_> {
             // stack: [class_name, constructor_parent?, proto]
         }
 
+        // Note: The class constructor data is reserved here so that nested
+        // classes cannot take its index. The field initializer bytecode is
+        // filled in after the instance fields have been compiled.
+        let class_index = IndexType::try_from(ctx.class_initializer_bytecodes.len()).unwrap();
+        ctx.class_initializer_bytecodes
+            .push((None, has_constructor_parent, self.span));
+
         // 14. If constructor is not empty, then
         let constructor_index = if let Some(constructor) = constructor {
             // a. Let constructorInfo be ! DefineMethod of constructor with arguments proto and constructorParent.
-            define_constructor_method(ctx, constructor, has_constructor_parent)
+            define_constructor_method(ctx, constructor, class_index)
             // b. Let F be constructorInfo.[[Closure]].
             // c. Perform MakeClassConstructor(F).
             // d. Perform SetFunctionName(F, className).
@@ -293,12 +301,11 @@ impl CompileEvaluation for ast::Class<'_> {
             // ...
             // b. Let F be CreateBuiltinFunction(defaultConstructor, 0, className, « [[ConstructorKind]], [[SourceText]] », the current Realm Record, constructorParent).
 
-            let index = IndexType::try_from(ctx.class_initializer_bytecodes.len()).unwrap();
             ctx.add_instruction_with_immediate(
                 Instruction::ClassDefineDefaultConstructor,
-                index.into(),
+                class_index.into(),
             );
-            index
+            class_index
         };
 
         // result: F
@@ -479,12 +486,9 @@ impl CompileEvaluation for ast::Class<'_> {
                 ctx.function_expressions[constructor_index as usize].compiled_bytecode =
                     Some(executable);
             } else {
-                ctx.class_initializer_bytecodes
-                    .push((Some(constructor_ctx.finish()), has_constructor_parent));
+                ctx.class_initializer_bytecodes[class_index as usize].0 =
+                    Some(constructor_ctx.finish());
             }
-        } else if constructor.is_none() {
-            ctx.class_initializer_bytecodes
-                .push((None, has_constructor_parent));
         }
         // 30. For each PrivateElement method of staticPrivateMethods, do
         //     a. Perform ! PrivateMethodOrAccessorAdd(F, method).
@@ -560,8 +564,9 @@ fn compile_computed_field_name<'a, 'gc>(
 /// Creates an ECMAScript constructor for a class.
 ///
 /// The class name should be at the top of the stack, followed by the
-/// constructor parent if `has_constructor_parent` is true, and finally the
-/// prototype.
+/// constructor parent if the class has one, and finally the prototype.
+/// `class_index` is the index of the class constructor data in the
+/// CompileContext's class initializer bytecodes.
 ///
 /// After this call, the constructor will be in the result slot and the class
 /// prototype will be at the top of the stack.
@@ -570,7 +575,7 @@ fn compile_computed_field_name<'a, 'gc>(
 fn define_constructor_method(
     ctx: &mut CompileContext,
     class_element: &ast::MethodDefinition,
-    has_constructor_parent: bool,
+    class_index: IndexType,
 ) -> IndexType {
     // stack: [class_name, proto] or [class_name, constructor_parent, proto]
 
@@ -603,8 +608,9 @@ fn define_constructor_method(
             // CompileContext holds a name identifier for us if this is NamedEvaluation.
             identifier: None,
             compiled_bytecode: None,
+            source_text: class_element.span,
         },
-        has_constructor_parent.into(),
+        class_index.into(),
     )
 }
 
@@ -644,6 +650,18 @@ fn define_method(class_element: &ast::MethodDefinition, ctx: &mut CompileContext
         MethodDefinitionKind::Get => Instruction::ObjectDefineGetter,
         MethodDefinitionKind::Set => Instruction::ObjectDefineSetter,
     };
+    let source_text = if class_element.r#static {
+        // Note: ClassElement : static MethodDefinition; the source text of
+        // the MethodDefinition begins after the static keyword and any
+        // whitespace or comments that follow it. Those are skipped when the
+        // source text is read.
+        Span::new(
+            class_element.span.start + "static".len() as u32,
+            class_element.span.end,
+        )
+    } else {
+        class_element.span
+    };
     // CompileContext holds a name identifier for us if this is NamedEvaluation.
     let identifier = ctx.name_identifier.take();
 
@@ -664,6 +682,7 @@ fn define_method(class_element: &ast::MethodDefinition, ctx: &mut CompileContext
             }),
             identifier,
             compiled_bytecode: None,
+            source_text,
         },
         // enumerable: false,
         false.into(),
@@ -26,6 +26,7 @@ use crate::{
     heap::{CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues},
 };
 use oxc_ast::ast::{self, Program, Statement};
+use oxc_span::Span;
 
 #[derive(Debug)]
 /// A `Send` and `Sync` wrapper over a `&'static T` where `T` might not itself
@@ -83,6 +84,9 @@ pub(crate) struct FunctionExpression<'a> {
     pub(crate) identifier: Option<NamedEvaluationParameter>,
     /// Optionally eagerly compile the FunctionExpression into bytecode.
     pub(crate) compiled_bytecode: Option<Executable<'a>>,
+    /// The source text matched by the function: for methods this is the
+    /// MethodDefinition, which the function's own span does not cover.
+    pub(crate) source_text: Span,
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -134,7 +138,23 @@ pub struct ExecutableHeapData<'a> {
     pub(crate) constants: Box<[Value<'a>]>,
     pub(crate) function_expressions: Box<[FunctionExpression<'a>]>,
     pub(crate) arrow_function_expressions: Box<[ArrowFunctionExpression]>,
-    pub(crate) class_initializer_bytecodes: Box<[(Option<Executable<'a>>, bool)]>,
+    pub(crate) class_initializer_bytecodes: Box<[(Option<Executable<'a>>, bool, Span)]>,
+    /// Source position table: sorted pairs of instruction offsets and source
+    /// text offsets. Each entry covers all instructions from its instruction
+    /// offset up to the next entry's offset.
+    pub(crate) source_positions: Box<[(u32, u32)]>,
+}
+
+impl ExecutableHeapData<'_> {
+    /// Returns the number of bytes used by the bytecode and its tables.
+    pub(crate) fn external_byte_size(&self) -> usize {
+        core::mem::size_of_val(self.instructions.as_ref())
+            + core::mem::size_of_val(self.constants.as_ref())
+            + core::mem::size_of_val(self.function_expressions.as_ref())
+            + core::mem::size_of_val(self.arrow_function_expressions.as_ref())
+            + core::mem::size_of_val(self.class_initializer_bytecodes.as_ref())
+            + core::mem::size_of_val(self.source_positions.as_ref())
+    }
 }
 
 impl<'gc> Executable<'gc> {
@@ -288,9 +308,20 @@ impl<'gc> Executable<'gc> {
         agent: &Agent,
         index: usize,
         _: NoGcScope<'gc, '_>,
-    ) -> (Option<Executable<'gc>>, bool) {
+    ) -> (Option<Executable<'gc>>, bool, Span) {
         agent[self].class_initializer_bytecodes[index]
     }
+
+    /// Get the source text offset that the instruction at the given
+    /// instruction pointer originates from.
+    pub(crate) fn get_source_position(self, agent: &Agent, ip: usize) -> Option<u32> {
+        let source_positions = &agent[self].source_positions;
+        let ip = u32::try_from(ip).ok()?;
+        // Find the last entry starting at or before the instruction pointer.
+        let index = source_positions.partition_point(|&(offset, _)| offset <= ip);
+        let (_, position) = source_positions.get(index.checked_sub(1)?)?;
+        Some(*position)
+    }
 }
 
 impl Scoped<'_, Executable<'static>> {
@@ -361,7 +392,7 @@ impl Scoped<'_, Executable<'static>> {
         agent: &Agent,
         index: usize,
         gc: NoGcScope<'gc, '_>,
-    ) -> (Option<Executable<'gc>>, bool) {
+    ) -> (Option<Executable<'gc>>, bool, Span) {
         self.get(agent)
             .fetch_class_initializer_bytecode(agent, index, gc)
     }
@@ -471,6 +502,7 @@ impl Rootable for Executable<'_> {
 
 impl<'a> CreateHeapData<ExecutableHeapData<'a>, Executable<'a>> for Heap {
     fn create(&mut self, data: ExecutableHeapData<'a>) -> Executable<'a> {
+        self.external_bytes += data.external_byte_size();
         self.executables.push(data.unbind());
         #[cfg(feature = "interleaved-gc")]
         {
@@ -521,6 +553,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             function_expressions: _,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            source_positions: _,
         } = self;
         constants.mark_values(queues);
         for ele in class_initializer_bytecodes {
@@ -535,6 +568,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             function_expressions: _,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            source_positions: _,
         } = self;
         constants.sweep_values(compactions);
         for ele in class_initializer_bytecodes {
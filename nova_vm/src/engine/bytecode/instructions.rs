@@ -575,7 +575,7 @@ impl Instr {
                 )
             }
             Instruction::ClassDefineConstructor => {
-                if index1 == 1 {
+                if exe.fetch_class_initializer_bytecode(agent, index1, gc).1 {
                     "constructor() { super() }".to_string()
                 } else {
                     "constructor()".to_string()
@@ -9,7 +9,6 @@ use std::{ptr::NonNull, sync::OnceLock};
 use ahash::AHashSet;
 use binding_methods::{execute_simple_array_binding, execute_simple_object_binding};
 use oxc_ast::ast;
-use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
 
 #[cfg(feature = "interleaved-gc")]
@@ -148,6 +147,31 @@ enum ContinuationKind {
     Await,
 }
 
+/// Location of a currently executing VM, used for capturing stack traces.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct VmFrame {
+    /// Index of the execution context that the VM is executing in.
+    pub(crate) execution_context_index: usize,
+    /// The executable being executed.
+    pub(crate) executable: Executable<'static>,
+    /// The VM executing the executable. The VM does not move while its frame
+    /// is on the frame stack.
+    vm: NonNull<Vm>,
+}
+
+impl VmFrame {
+    /// Returns the instruction pointer of the instruction currently being
+    /// executed.
+    pub(crate) fn ip(&self) -> usize {
+        // SAFETY: The frame is popped before the VM moves or is dropped.
+        let vm = unsafe { self.vm.as_ref() };
+        // Note: The instruction pointer has already moved past the opcode,
+        // but any offset within the instruction maps to the same source
+        // position.
+        vm.ip.saturating_sub(1)
+    }
+}
+
 /// Indicates a place to jump after an exception is thrown.
 #[derive(Debug)]
 struct ExceptionJumpTarget<'a> {
@@ -339,6 +363,19 @@ impl Vm {
     }
 
     fn inner_execute<'gc>(
+        self,
+        agent: &mut Agent,
+        executable: Scoped<Executable>,
+        gc: GcScope<'gc, '_>,
+    ) -> ExecutionResult<'gc> {
+        let result = self.execute_loop(agent, executable, gc);
+        // Note: The frame is pushed by execute_loop, where the VM has its
+        // final address.
+        agent.vm_frames.pop();
+        result
+    }
+
+    fn execute_loop<'gc>(
         mut self,
         agent: &mut Agent,
         executable: Scoped<Executable>,
@@ -346,20 +383,41 @@ impl Vm {
     ) -> ExecutionResult<'gc> {
         #[cfg(feature = "interleaved-gc")]
         let do_gc = !agent.options.disable_gc;
-        #[cfg(feature = "interleaved-gc")]
         let mut instr_count = 0u8;
 
+        agent.vm_frames.push(VmFrame {
+            execution_context_index: agent.execution_context_stack.len().saturating_sub(1),
+            executable: executable.get(agent).unbind(),
+            vm: NonNull::from(&mut self),
+        });
+        // Note: Termination is checked when the frame is entered and on
+        // backward jumps, which between them bound the number of instructions
+        // run between checks. Executed instructions are counted locally and
+        // charged against the execution budget at each check.
+        let mut executed_instructions = 0u64;
+        if let Err(err) = agent.check_termination(executed_instructions, gc.nogc()) {
+            return ExecutionResult::Throw(err.unbind().bind(gc.into_nogc()));
+        }
         let stack_depth = agent.stack_refs.borrow().len();
         let instructions = executable.get_instructions(agent);
-        while let Some(instr) = get_instruction(instructions, &mut self.ip) {
+        let result = loop {
+            let Some(instr) = get_instruction(instructions, &mut self.ip) else {
+                break ExecutionResult::Return(Value::Undefined);
+            };
+            let next_ip = self.ip;
+            executed_instructions += 1;
+            instr_count = instr_count.wrapping_add(1);
             #[cfg(feature = "interleaved-gc")]
             if do_gc {
-                instr_count = instr_count.wrapping_add(1);
                 const ALLOC_COUNTER_LIMIT: usize = 1024 * 1024 * 2;
                 // Check allocation counter roughly every 256 instructions and
                 // perform garbage collection if over 2 MiB of allocations have
-                // been performed since last GC.
-                if instr_count == 0 && agent.heap.alloc_counter > ALLOC_COUNTER_LIMIT {
+                // been performed since last GC, or if allocations have been
+                // performed and the heap is nearing the heap limit.
+                if instr_count == 0
+                    && (agent.heap.alloc_counter > ALLOC_COUNTER_LIMIT
+                        || (agent.heap.alloc_counter > 0 && agent.is_near_heap_limit()))
+                {
                     let mut root_realms = agent
                         .heap
                         .realms
@@ -375,6 +433,19 @@ impl Vm {
                     );
                 }
             }
+            // Small allocations are not checked against the heap limit: check
+            // the heap size roughly every 256 instructions instead.
+            let heap_limit_check = if instr_count == 0 {
+                agent.check_heap_limit(0, gc.nogc())
+            } else {
+                Ok(())
+            };
+            if let Err(err) = heap_limit_check {
+                if !self.handle_error(agent, err) {
+                    break ExecutionResult::Throw(err.unbind().bind(gc.into_nogc()));
+                }
+                continue;
+            }
             match Self::execute_instruction(
                 agent,
                 &mut self,
@@ -385,36 +456,47 @@ impl Vm {
                 Ok(ContinuationKind::Normal) => {}
                 Ok(ContinuationKind::Return) => {
                     let result = self.result.unwrap_or(Value::Undefined);
-                    return ExecutionResult::Return(result);
+                    break ExecutionResult::Return(result);
                 }
                 Ok(ContinuationKind::Yield) => {
                     let yielded_value = self.result.take().unwrap();
-                    return ExecutionResult::Yield {
+                    break ExecutionResult::Yield {
                         vm: self.suspend(),
                         yielded_value,
                     };
                 }
                 Ok(ContinuationKind::Await) => {
                     let awaited_value = self.result.take().unwrap();
-                    return ExecutionResult::Await {
+                    break ExecutionResult::Await {
                         vm: self.suspend(),
                         awaited_value,
                     };
                 }
                 Err(err) => {
                     if !self.handle_error(agent, err) {
-                        return ExecutionResult::Throw(err.unbind().bind(gc.into_nogc()));
+                        break ExecutionResult::Throw(err.unbind().bind(gc.into_nogc()));
                     }
                 }
             }
             agent.stack_refs.borrow_mut().truncate(stack_depth);
-        }
-
-        ExecutionResult::Return(Value::Undefined)
+            if self.ip < next_ip {
+                // Backward jump.
+                if let Err(err) = agent.check_termination(executed_instructions, gc.nogc()) {
+                    break ExecutionResult::Throw(err.unbind().bind(gc.into_nogc()));
+                }
+                executed_instructions = 0;
+            }
+        };
+        agent.charge_execution_budget(executed_instructions);
+        result
     }
 
     #[must_use]
     fn handle_error(&mut self, agent: &mut Agent, err: JsError) -> bool {
+        if agent.is_terminating() {
+            // Termination cannot be caught.
+            return false;
+        }
         if let Some(ejt) = self.exception_jump_target_stack.pop() {
             self.ip = ejt.ip;
             agent.set_current_lexical_environment(ejt.lexical_environment);
@@ -646,12 +728,17 @@ impl Vm {
                 .unwrap();
             }
             Instruction::ObjectDefineMethod => {
-                let FunctionExpression { expression, .. } = executable.fetch_function_expression(
+                let FunctionExpression {
+                    expression,
+                    source_text,
+                    ..
+                } = executable.fetch_function_expression(
                     agent,
                     instr.args[0].unwrap() as usize,
                     gc.nogc(),
                 );
                 let function_expression = expression.get();
+                let source_text = *source_text;
                 let enumerable = instr.args[1].unwrap() != 0;
                 // 1. Let propKey be ? Evaluation of ClassElementName.
                 let prop_key = vm.stack.pop().unwrap();
@@ -681,7 +768,7 @@ impl Vm {
                     function_prototype: None,
                     source_code: None,
                     // 4. Let sourceText be the source text matched by MethodDefinition.
-                    source_text: function_expression.span,
+                    source_text,
                     parameters_list: &function_expression.params,
                     body: function_expression.body.as_ref().unwrap(),
                     is_concise_arrow_function: false,
@@ -746,12 +833,17 @@ impl Vm {
                 // c. Return unused.
             }
             Instruction::ObjectDefineGetter => {
-                let FunctionExpression { expression, .. } = executable.fetch_function_expression(
+                let FunctionExpression {
+                    expression,
+                    source_text,
+                    ..
+                } = executable.fetch_function_expression(
                     agent,
                     instr.args[0].unwrap() as usize,
                     gc.nogc(),
                 );
                 let function_expression = expression.get();
+                let source_text = *source_text;
                 let enumerable = instr.args[1].unwrap() != 0;
                 // 1. Let propKey be ? Evaluation of ClassElementName.
                 let prop_key = vm.stack.pop().unwrap();
@@ -785,7 +877,7 @@ impl Vm {
                     function_prototype: None,
                     source_code: None,
                     // 4. Let sourceText be the source text matched by MethodDefinition.
-                    source_text: function_expression.span,
+                    source_text,
                     parameters_list: &empty_parameters.0,
                     body: function_expression.body.as_ref().unwrap(),
                     is_async: function_expression.r#async,
@@ -842,12 +934,17 @@ impl Vm {
                 // c. Return unused.
             }
             Instruction::ObjectDefineSetter => {
-                let FunctionExpression { expression, .. } = executable.fetch_function_expression(
+                let FunctionExpression {
+                    expression,
+                    source_text,
+                    ..
+                } = executable.fetch_function_expression(
                     agent,
                     instr.args[0].unwrap() as usize,
                     gc.nogc(),
                 );
                 let function_expression = expression.get();
+                let source_text = *source_text;
                 let enumerable = instr.args[1].unwrap() != 0;
                 // 1. Let propKey be ? Evaluation of ClassElementName.
                 let prop_key = vm.stack.pop().unwrap();
@@ -867,7 +964,7 @@ impl Vm {
                     function_prototype: None,
                     source_code: None,
                     // 4. Let sourceText be the source text matched by MethodDefinition.
-                    source_text: function_expression.span,
+                    source_text,
                     parameters_list: &function_expression.params,
                     body: function_expression.body.as_ref().unwrap(),
                     is_concise_arrow_function: false,
@@ -1155,6 +1252,7 @@ impl Vm {
                 let FunctionExpression {
                     expression,
                     identifier,
+                    source_text,
                     ..
                 } = executable.fetch_function_expression(
                     agent,
@@ -1163,6 +1261,7 @@ impl Vm {
                 );
                 let function_expression = expression.get();
                 let identifier = *identifier;
+                let source_text = *source_text;
 
                 let (name, env, init_binding) = if let Some(parameter) = identifier {
                     debug_assert!(function_expression.id.is_none());
@@ -1209,7 +1308,7 @@ impl Vm {
                 let params = OrdinaryFunctionCreateParams {
                     function_prototype: None,
                     source_code: None,
-                    source_text: function_expression.span,
+                    source_text,
                     parameters_list: &function_expression.params,
                     body: function_expression.body.as_ref().unwrap(),
                     is_concise_arrow_function: false,
@@ -1306,9 +1405,12 @@ impl Vm {
                 );
                 let function_expression = expression.get();
                 let compiled_bytecode = *compiled_bytecode;
-                let has_constructor_parent = instr.args[1].unwrap();
-                assert!(has_constructor_parent <= 1);
-                let has_constructor_parent = has_constructor_parent == 1;
+                let (_, has_constructor_parent, class_source_text) = executable
+                    .fetch_class_initializer_bytecode(
+                        agent,
+                        instr.args[1].unwrap() as usize,
+                        gc.nogc(),
+                    );
 
                 let class_name = String::try_from(vm.stack.pop().unwrap()).unwrap();
                 let function_prototype = if has_constructor_parent {
@@ -1327,7 +1429,9 @@ impl Vm {
                 let params = OrdinaryFunctionCreateParams {
                     function_prototype,
                     source_code: None,
-                    source_text: function_expression.span,
+                    // Note: A class constructor's [[SourceText]] is the source
+                    // text of the whole class.
+                    source_text: class_source_text,
                     parameters_list: &function_expression.params,
                     body: function_expression.body.as_ref().unwrap(),
                     is_concise_arrow_function: false,
@@ -1368,8 +1472,8 @@ impl Vm {
             }
             Instruction::ClassDefineDefaultConstructor => {
                 let class_initializer_bytecode_index = instr.args[0].unwrap();
-                let (compiled_initializer_bytecode, has_constructor_parent) = executable
-                    .fetch_class_initializer_bytecode(
+                let (compiled_initializer_bytecode, has_constructor_parent, class_source_text) =
+                    executable.fetch_class_initializer_bytecode(
                         agent,
                         class_initializer_bytecode_index as usize,
                         gc.nogc(),
@@ -1404,7 +1508,7 @@ impl Vm {
                         env,
                         private_env,
                         source_code,
-                        source_text: Span::new(0, 0),
+                        source_text: class_source_text,
                     },
                     gc.nogc(),
                 );
@@ -2165,7 +2269,7 @@ impl Vm {
                     concat_string_from_slice(agent, &args, length, gc)
                 };
                 vm.stack.truncate(first_arg_index);
-                vm.result = Some(string.into_value().unbind());
+                vm.result = Some(string.unbind()?.into_value());
             }
             Instruction::Delete => {
                 let refer = vm.reference.take().unwrap().bind(gc.nogc());
@@ -2421,12 +2525,13 @@ fn concat_string_from_slice<'gc>(
     slice: &[String],
     string_length: usize,
     gc: NoGcScope<'gc, '_>,
-) -> String<'gc> {
+) -> JsResult<'gc, String<'gc>> {
+    agent.check_heap_limit(string_length, gc)?;
     let mut result_string = std::string::String::with_capacity(string_length);
     for string in slice.iter() {
         result_string.push_str(string.as_str(agent));
     }
-    String::from_string(agent, result_string, gc)
+    Ok(String::from_string(agent, result_string, gc))
 }
 
 /// ### [13.15.3 ApplyStringOrNumericBinaryOperator ( lval, opText, rval )](https://tc39.es/ecma262/#sec-applystringornumericbinaryoperator)
@@ -2493,6 +2598,7 @@ fn apply_string_or_numeric_binary_operator<'gc>(
         // c. If lprim is a String or rprim is a String, then
         match (String::try_from(lprim), String::try_from(rprim)) {
             (Ok(lstr), Ok(rstr)) => {
+                agent.check_heap_limit(lstr.len(agent) + rstr.len(agent), gc)?;
                 // iii. Return the string-concatenation of lstr and rstr.
                 return Ok(String::concat(agent, [lstr, rstr], gc).into_value());
             }
@@ -2500,6 +2606,7 @@ fn apply_string_or_numeric_binary_operator<'gc>(
                 let lstr = lstr.scope(agent, gc);
                 // ii. Let rstr be ? ToString(rprim).
                 let rstr = to_string_primitive(agent, rprim, gc)?;
+                agent.check_heap_limit(lstr.get(agent).len(agent) + rstr.len(agent), gc)?;
                 // iii. Return the string-concatenation of lstr and rstr.
                 return Ok(String::concat(agent, [lstr.get(agent).bind(gc), rstr], gc).into_value());
             }
@@ -2507,6 +2614,7 @@ fn apply_string_or_numeric_binary_operator<'gc>(
                 let rstr = rstr.scope(agent, gc);
                 // i. Let lstr be ? ToString(lprim).
                 let lstr = to_string_primitive(agent, lprim, gc)?;
+                agent.check_heap_limit(lstr.len(agent) + rstr.get(agent).len(agent), gc)?;
                 // iii. Return the string-concatenation of lstr and rstr.
                 return Ok(String::concat(agent, [lstr, rstr.get(agent).bind(gc)], gc).into_value());
             }
@@ -2657,9 +2765,13 @@ fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> String<'static>
         Value::Iterator |
         Value::ArrayIterator(_) |
         Value::MapIterator(_) |
+        Value::IteratorHelper(_) |
+        Value::WrapForValidIterator(_) |
         Value::Generator(_) |
         Value::Module(_) |
         Value::EmbedderObject(_) => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        Value::RawJSON(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "regexp")]
         Value::RegExp(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "weak-refs")]
@@ -2689,13 +2801,25 @@ fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> String<'static>
         Value::Float16Array(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "date")]
         Value::Date(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "intl")]
+        Value::Collator(_) |
+        Value::DateTimeFormat(_) |
+        Value::NumberFormat(_) |
+        Value::PluralRules(_) => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "proposal-temporal")]
+        Value::Duration(_) |
+        Value::Instant(_) |
+        Value::PlainDate(_) |
+        Value::PlainDateTime(_) |
+        Value::PlainTime(_) |
+        Value::ZonedDateTime(_) => BUILTIN_STRING_MEMORY.object,
         // 13. If val has a [[Call]] internal slot, return "function".
         Value::BoundFunction(_) | Value::BuiltinFunction(_) | Value::ECMAScriptFunction(_) |
         Value::BuiltinGeneratorFunction |
         Value::BuiltinConstructorFunction(_) |
         Value::BuiltinPromiseResolvingFunction(_) |
         Value::BuiltinPromiseCollectorFunction |
-        Value::BuiltinProxyRevokerFunction => BUILTIN_STRING_MEMORY.function,
+        Value::BuiltinProxyRevokerFunction(_) => BUILTIN_STRING_MEMORY.function,
         Value::Proxy(proxy) => {
             if proxy.is_callable(agent, gc) {
                 BUILTIN_STRING_MEMORY.function
@@ -2801,6 +2925,26 @@ fn with_vm_gc<'a, 'b, R: 'a>(
     }
 }
 
+impl HeapMarkAndSweep for VmFrame {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            execution_context_index: _,
+            executable,
+            vm: _,
+        } = self;
+        executable.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            execution_context_index: _,
+            executable,
+            vm: _,
+        } = self;
+        executable.sweep_values(compactions);
+    }
+}
+
 impl HeapMarkAndSweep for ExceptionJumpTarget<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
@@ -372,7 +372,7 @@ pub unsafe trait Bindable: Sized {
 macro_rules! trivially_bindable {
     ($self:ty) => {
         // SAFETY: Trivially safe.
-        unsafe impl Bindable for $self {
+        unsafe impl $crate::engine::context::Bindable for $self {
             type Of<'a> = $self;
 
             #[inline(always)]
@@ -381,12 +381,13 @@ macro_rules! trivially_bindable {
             }
 
             #[inline(always)]
-            fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+            fn bind<'a>(self, _gc: $crate::engine::context::NoGcScope<'a, '_>) -> Self::Of<'a> {
                 self
             }
         }
     };
 }
+pub(crate) use trivially_bindable;
 
 trivially_bindable!(());
 trivially_bindable!(bool);
@@ -397,11 +398,14 @@ trivially_bindable!(u16);
 trivially_bindable!(i32);
 trivially_bindable!(u32);
 trivially_bindable!(i64);
+trivially_bindable!(i128);
 trivially_bindable!(u64);
 trivially_bindable!(isize);
 trivially_bindable!(usize);
 trivially_bindable!(f32);
 trivially_bindable!(f64);
+trivially_bindable!(&'static str);
+trivially_bindable!(std::string::String);
 
 // SAFETY: Trivially safe.
 unsafe impl<'b, T: 'static + Rootable> Bindable for Scoped<'b, T> {
@@ -9,22 +9,57 @@ use private::{RootableCollectionSealed, RootableSealed};
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::Duration, instant::Instant, plain_date::PlainDate, plain_date_time::PlainDateTime,
+    plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
+#[cfg(feature = "intl")]
+use crate::ecmascript::types::COLLATOR_DISCRIMINANT;
 #[cfg(feature = "date")]
 use crate::ecmascript::types::DATE_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use crate::ecmascript::types::DATE_TIME_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::DURATION_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::types::FLOAT_16_ARRAY_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::INSTANT_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use crate::ecmascript::types::NUMBER_FORMAT_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::PLAIN_DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::PLAIN_DATE_TIME_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::PLAIN_TIME_DISCRIMINANT;
+#[cfg(feature = "intl")]
+use crate::ecmascript::types::PLURAL_RULES_DISCRIMINANT;
+#[cfg(feature = "proposal-json-parse-with-source")]
+use crate::ecmascript::types::RAW_JSON_DISCRIMINANT;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::types::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::types::SHARED_ARRAY_BUFFER_DISCRIMINANT;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::types::ZONED_DATE_TIME_DISCRIMINANT;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::types::{
     ARRAY_BUFFER_DISCRIMINANT, BIGINT_64_ARRAY_DISCRIMINANT, BIGUINT_64_ARRAY_DISCRIMINANT,
@@ -52,11 +87,17 @@ use crate::{
             Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+            },
             embedder_object::EmbedderObject,
             error::Error,
             finalization_registry::FinalizationRegistry,
             generator_objects::Generator,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+            indexed_collections::array_objects::{
+                array_from_async::ArrayFromAsync,
+                array_iterator_objects::array_iterator::ArrayIterator,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
             module::Module,
@@ -66,7 +107,7 @@ use crate::{
                 promise_reaction_records::PromiseReaction,
                 promise_resolving_functions::BuiltinPromiseResolvingFunction,
             },
-            proxy::Proxy,
+            proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
         },
         execution::{
             DeclarativeEnvironment, FunctionEnvironment, GlobalEnvironment, ModuleEnvironment,
@@ -83,10 +124,11 @@ use crate::{
             BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
             ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
             FINALIZATION_REGISTRY_DISCRIMINANT, GENERATOR_DISCRIMINANT, HeapNumber, HeapString,
-            ITERATOR_DISCRIMINANT, IntoObject, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
-            MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, Object, OrdinaryObject,
-            PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PropertyKey, STRING_DISCRIMINANT,
-            SYMBOL_DISCRIMINANT, Symbol, Value, bigint::HeapBigInt,
+            ITERATOR_DISCRIMINANT, ITERATOR_HELPER_DISCRIMINANT, IntoObject, MAP_DISCRIMINANT,
+            MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT,
+            OBJECT_DISCRIMINANT, Object, OrdinaryObject, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
+            PropertyKey, STRING_DISCRIMINANT, SYMBOL_DISCRIMINANT, Symbol, Value,
+            WRAP_FOR_VALID_ITERATOR_DISCRIMINANT, bigint::HeapBigInt,
         },
     },
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
@@ -95,10 +137,23 @@ use crate::{
 mod private {
     #[cfg(feature = "date")]
     use crate::ecmascript::builtins::date::Date;
+    #[cfg(feature = "intl")]
+    use crate::ecmascript::builtins::intl::collator::Collator;
+    #[cfg(feature = "intl")]
+    use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+    #[cfg(feature = "intl")]
+    use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+    #[cfg(feature = "intl")]
+    use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
     #[cfg(feature = "regexp")]
     use crate::ecmascript::builtins::regexp::RegExp;
     #[cfg(feature = "shared-array-buffer")]
     use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+    #[cfg(feature = "proposal-temporal")]
+    use crate::ecmascript::builtins::temporal::{
+        duration::Duration, instant::Instant, plain_date::PlainDate,
+        plain_date_time::PlainDateTime, plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+    };
     #[cfg(feature = "array-buffer")]
     use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView, typed_array::TypedArray};
     #[cfg(feature = "set")]
@@ -114,11 +169,17 @@ mod private {
                 ECMAScriptFunction,
                 async_generator_objects::AsyncGenerator,
                 bound_function::BoundFunction,
+                control_abstraction_objects::iteration::{
+                    iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+                },
                 embedder_object::EmbedderObject,
                 error::Error,
                 finalization_registry::FinalizationRegistry,
                 generator_objects::Generator,
-                indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+                indexed_collections::array_objects::{
+                    array_from_async::ArrayFromAsync,
+                    array_iterator_objects::array_iterator::ArrayIterator,
+                },
                 keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
                 map::Map,
                 module::Module,
@@ -128,7 +189,7 @@ mod private {
                     promise_reaction_records::PromiseReaction,
                     promise_resolving_functions::BuiltinPromiseResolvingFunction,
                 },
-                proxy::Proxy,
+                proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
             },
             execution::{
                 DeclarativeEnvironment, Environment, FunctionEnvironment, GlobalEnvironment,
@@ -148,6 +209,7 @@ mod private {
     impl RootableSealed for Array<'_> {}
     #[cfg(feature = "array-buffer")]
     impl RootableSealed for ArrayBuffer<'_> {}
+    impl RootableSealed for ArrayFromAsync<'_> {}
     impl RootableSealed for ArrayIterator<'_> {}
     impl RootableSealed for AsyncGenerator<'_> {}
     impl RootableSealed for BigInt<'_> {}
@@ -155,10 +217,31 @@ mod private {
     impl RootableSealed for BuiltinConstructorFunction<'_> {}
     impl RootableSealed for BuiltinFunction<'_> {}
     impl RootableSealed for BuiltinPromiseResolvingFunction<'_> {}
+    impl RootableSealed for BuiltinProxyRevokerFunction<'_> {}
     #[cfg(feature = "array-buffer")]
     impl RootableSealed for DataView<'_> {}
     #[cfg(feature = "date")]
     impl RootableSealed for Date<'_> {}
+    #[cfg(feature = "intl")]
+    impl RootableSealed for Collator<'_> {}
+    #[cfg(feature = "intl")]
+    impl RootableSealed for DateTimeFormat<'_> {}
+    #[cfg(feature = "intl")]
+    impl RootableSealed for NumberFormat<'_> {}
+    #[cfg(feature = "intl")]
+    impl RootableSealed for PluralRules<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for Duration<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for Instant<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for PlainDate<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for PlainDateTime<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for PlainTime<'_> {}
+    #[cfg(feature = "proposal-temporal")]
+    impl RootableSealed for ZonedDateTime<'_> {}
     impl RootableSealed for ECMAScriptFunction<'_> {}
     impl RootableSealed for EmbedderObject<'_> {}
     impl RootableSealed for Error<'_> {}
@@ -168,6 +251,8 @@ mod private {
     impl RootableSealed for Generator<'_> {}
     impl RootableSealed for Map<'_> {}
     impl RootableSealed for MapIterator<'_> {}
+    impl RootableSealed for IteratorHelper<'_> {}
+    impl RootableSealed for WrapForValidIterator<'_> {}
     impl RootableSealed for Module<'_> {}
     impl RootableSealed for Number<'_> {}
     impl RootableSealed for Numeric<'_> {}
@@ -305,9 +390,12 @@ pub enum HeapRootData {
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'static>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'static>) =
+        BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'static>),
     Arguments(OrdinaryObject<'static>) = ARGUMENTS_DISCRIMINANT,
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    RawJSON(OrdinaryObject<'static>) = RAW_JSON_DISCRIMINANT,
     Array(Array<'static>) = ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'static>) = ARRAY_BUFFER_DISCRIMINANT,
@@ -315,6 +403,26 @@ pub enum HeapRootData {
     DataView(DataView<'static>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'static>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    Collator(Collator<'static>) = COLLATOR_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    DateTimeFormat(DateTimeFormat<'static>) = DATE_TIME_FORMAT_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    NumberFormat(NumberFormat<'static>) = NUMBER_FORMAT_DISCRIMINANT,
+    #[cfg(feature = "intl")]
+    PluralRules(PluralRules<'static>) = PLURAL_RULES_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    Duration(Duration<'static>) = DURATION_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    Instant(Instant<'static>) = INSTANT_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainDate(PlainDate<'static>) = PLAIN_DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainDateTime(PlainDateTime<'static>) = PLAIN_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    PlainTime(PlainTime<'static>) = PLAIN_TIME_DISCRIMINANT,
+    #[cfg(feature = "proposal-temporal")]
+    ZonedDateTime(ZonedDateTime<'static>) = ZONED_DATE_TIME_DISCRIMINANT,
     Error(Error<'static>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'static>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'static>) = MAP_DISCRIMINANT,
@@ -363,6 +471,8 @@ pub enum HeapRootData {
     #[cfg(feature = "set")]
     SetIterator(SetIterator<'static>) = SET_ITERATOR_DISCRIMINANT,
     MapIterator(MapIterator<'static>) = MAP_ITERATOR_DISCRIMINANT,
+    IteratorHelper(IteratorHelper<'static>) = ITERATOR_HELPER_DISCRIMINANT,
+    WrapForValidIterator(WrapForValidIterator<'static>) = WRAP_FOR_VALID_ITERATOR_DISCRIMINANT,
     Generator(Generator<'static>) = GENERATOR_DISCRIMINANT,
     Module(Module<'static>) = MODULE_DISCRIMINANT,
     EmbedderObject(EmbedderObject<'static>) = EMBEDDER_OBJECT_DISCRIMINANT,
@@ -373,6 +483,7 @@ pub enum HeapRootData {
     //
     // The order here shouldn't be important at all, feel free to eg. keep
     // these in alphabetical order.
+    ArrayFromAsync(ArrayFromAsync<'static>),
     Executable(Executable<'static>),
     PromiseReaction(PromiseReaction<'static>),
     Realm(Realm<'static>),
@@ -404,13 +515,37 @@ impl From<Object<'static>> for HeapRootData {
                 Self::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function)
             }
             Object::BuiltinPromiseCollectorFunction => Self::BuiltinPromiseCollectorFunction,
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function)
+            }
             Object::PrimitiveObject(primitive_object) => Self::PrimitiveObject(primitive_object),
             Object::Arguments(ordinary_object) => Self::Arguments(ordinary_object),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Object::RawJSON(ordinary_object) => Self::RawJSON(ordinary_object),
             Object::Array(array) => Self::Array(array),
             Object::ArrayBuffer(array_buffer) => Self::ArrayBuffer(array_buffer),
             Object::DataView(data_view) => Self::DataView(data_view),
             Object::Date(date) => Self::Date(date),
+            #[cfg(feature = "intl")]
+            Object::Collator(collator) => Self::Collator(collator),
+            #[cfg(feature = "intl")]
+            Object::DateTimeFormat(date_time_format) => Self::DateTimeFormat(date_time_format),
+            #[cfg(feature = "intl")]
+            Object::NumberFormat(number_format) => Self::NumberFormat(number_format),
+            #[cfg(feature = "intl")]
+            Object::PluralRules(plural_rules) => Self::PluralRules(plural_rules),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Duration(duration) => Self::Duration(duration),
+            #[cfg(feature = "proposal-temporal")]
+            Object::Instant(instant) => Self::Instant(instant),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDate(plain_date) => Self::PlainDate(plain_date),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainDateTime(plain_date_time) => Self::PlainDateTime(plain_date_time),
+            #[cfg(feature = "proposal-temporal")]
+            Object::PlainTime(plain_time) => Self::PlainTime(plain_time),
+            #[cfg(feature = "proposal-temporal")]
+            Object::ZonedDateTime(zoned_date_time) => Self::ZonedDateTime(zoned_date_time),
             Object::Error(error) => Self::Error(error),
             Object::FinalizationRegistry(finalization_registry) => {
                 Self::FinalizationRegistry(finalization_registry)
@@ -447,6 +582,10 @@ impl From<Object<'static>> for HeapRootData {
             #[cfg(feature = "set")]
             Object::SetIterator(set_iterator) => Self::SetIterator(set_iterator),
             Object::MapIterator(map_iterator) => Self::MapIterator(map_iterator),
+            Object::IteratorHelper(iterator_helper) => Self::IteratorHelper(iterator_helper),
+            Object::WrapForValidIterator(wrap_for_valid_iterator) => {
+                Self::WrapForValidIterator(wrap_for_valid_iterator)
+            }
             Object::Generator(generator) => Self::Generator(generator),
             Object::Module(module) => Self::Module(module),
             Object::EmbedderObject(embedder_object) => Self::EmbedderObject(embedder_object),
@@ -512,9 +651,13 @@ impl HeapMarkAndSweep for HeapRootData {
                 builtin_promise_resolving_function.mark_values(queues)
             }
             HeapRootData::BuiltinPromiseCollectorFunction => todo!(),
-            HeapRootData::BuiltinProxyRevokerFunction => todo!(),
+            HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.mark_values(queues)
+            }
             HeapRootData::PrimitiveObject(primitive_object) => primitive_object.mark_values(queues),
             HeapRootData::Arguments(ordinary_object) => ordinary_object.mark_values(queues),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            HeapRootData::RawJSON(ordinary_object) => ordinary_object.mark_values(queues),
             HeapRootData::Array(array) => array.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => array_buffer.mark_values(queues),
@@ -522,6 +665,26 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.mark_values(queues),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.mark_values(queues),
+            #[cfg(feature = "intl")]
+            HeapRootData::Collator(collator) => collator.mark_values(queues),
+            #[cfg(feature = "intl")]
+            HeapRootData::DateTimeFormat(date_time_format) => date_time_format.mark_values(queues),
+            #[cfg(feature = "intl")]
+            HeapRootData::NumberFormat(number_format) => number_format.mark_values(queues),
+            #[cfg(feature = "intl")]
+            HeapRootData::PluralRules(plural_rules) => plural_rules.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Duration(duration) => duration.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Instant(instant) => instant.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDate(plain_date) => plain_date.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDateTime(plain_date_time) => plain_date_time.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainTime(plain_time) => plain_time.mark_values(queues),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::ZonedDateTime(zoned_date_time) => zoned_date_time.mark_values(queues),
             HeapRootData::Error(error) => error.mark_values(queues),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.mark_values(queues)
@@ -574,9 +737,14 @@ impl HeapMarkAndSweep for HeapRootData {
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => set_iterator.mark_values(queues),
             HeapRootData::MapIterator(map_iterator) => map_iterator.mark_values(queues),
+            HeapRootData::IteratorHelper(iterator_helper) => iterator_helper.mark_values(queues),
+            HeapRootData::WrapForValidIterator(wrap_for_valid_iterator) => {
+                wrap_for_valid_iterator.mark_values(queues)
+            }
             HeapRootData::Generator(generator) => generator.mark_values(queues),
             HeapRootData::Module(module) => module.mark_values(queues),
             HeapRootData::EmbedderObject(embedder_object) => embedder_object.mark_values(queues),
+            HeapRootData::ArrayFromAsync(array_from_async) => array_from_async.mark_values(queues),
             HeapRootData::Executable(exe) => exe.mark_values(queues),
             HeapRootData::PromiseReaction(promise_reaction) => promise_reaction.mark_values(queues),
             HeapRootData::Realm(realm) => realm.mark_values(queues),
@@ -625,11 +793,15 @@ impl HeapMarkAndSweep for HeapRootData {
                 builtin_promise_resolving_function.sweep_values(compactions)
             }
             HeapRootData::BuiltinPromiseCollectorFunction => todo!(),
-            HeapRootData::BuiltinProxyRevokerFunction => todo!(),
+            HeapRootData::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.sweep_values(compactions)
+            }
             HeapRootData::PrimitiveObject(primitive_object) => {
                 primitive_object.sweep_values(compactions)
             }
             HeapRootData::Arguments(ordinary_object) => ordinary_object.sweep_values(compactions),
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            HeapRootData::RawJSON(ordinary_object) => ordinary_object.sweep_values(compactions),
             HeapRootData::Array(array) => array.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => array_buffer.sweep_values(compactions),
@@ -637,6 +809,32 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.sweep_values(compactions),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            HeapRootData::Collator(collator) => collator.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            HeapRootData::DateTimeFormat(date_time_format) => {
+                date_time_format.sweep_values(compactions)
+            }
+            #[cfg(feature = "intl")]
+            HeapRootData::NumberFormat(number_format) => number_format.sweep_values(compactions),
+            #[cfg(feature = "intl")]
+            HeapRootData::PluralRules(plural_rules) => plural_rules.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Duration(duration) => duration.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::Instant(instant) => instant.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDate(plain_date) => plain_date.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainDateTime(plain_date_time) => {
+                plain_date_time.sweep_values(compactions)
+            }
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::PlainTime(plain_time) => plain_time.sweep_values(compactions),
+            #[cfg(feature = "proposal-temporal")]
+            HeapRootData::ZonedDateTime(zoned_date_time) => {
+                zoned_date_time.sweep_values(compactions)
+            }
             HeapRootData::Error(error) => error.sweep_values(compactions),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.sweep_values(compactions)
@@ -689,11 +887,20 @@ impl HeapMarkAndSweep for HeapRootData {
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => set_iterator.sweep_values(compactions),
             HeapRootData::MapIterator(map_iterator) => map_iterator.sweep_values(compactions),
+            HeapRootData::IteratorHelper(iterator_helper) => {
+                iterator_helper.sweep_values(compactions)
+            }
+            HeapRootData::WrapForValidIterator(wrap_for_valid_iterator) => {
+                wrap_for_valid_iterator.sweep_values(compactions)
+            }
             HeapRootData::Generator(generator) => generator.sweep_values(compactions),
             HeapRootData::Module(module) => module.sweep_values(compactions),
             HeapRootData::EmbedderObject(embedder_object) => {
                 embedder_object.sweep_values(compactions)
             }
+            HeapRootData::ArrayFromAsync(array_from_async) => {
+                array_from_async.sweep_values(compactions)
+            }
             HeapRootData::Executable(exe) => exe.sweep_values(compactions),
             HeapRootData::PromiseReaction(promise_reaction) => {
                 promise_reaction.sweep_values(compactions)
@@ -0,0 +1,188 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Serde integration
+//!
+//! Conversions between Rust types implementing [`serde::Serialize`] or
+//! [`serde::Deserialize`] and JavaScript Values.
+//!
+//! Deserialization reads primitives, Arrays, TypedArrays, Maps, Sets and
+//! other objects' enumerable own string-keyed properties. Reading properties
+//! may call into JavaScript (getters, Proxies) and is thus done with a
+//! [`GcScope`]; intermediate values are rooted using [`Scoped`] handles.
+//!
+//! Serialization creates plain objects and Arrays and cannot call into
+//! JavaScript, so it is done with a [`NoGcScope`]. Integers outside the safe
+//! integer range are serialized as BigInts.
+//!
+//! [`Scoped`]: crate::engine::Scoped
+
+mod de;
+mod ser;
+
+use core::fmt::{self, Display};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    ecmascript::{
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        types::{PropertyKey, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{Global, Scopable},
+    },
+};
+
+pub use de::ValueDeserializer;
+pub use ser::ValueSerializer;
+
+/// Convert a JavaScript Value into a Rust value.
+///
+/// Type mismatches throw a TypeError whose message includes the path to the
+/// mismatched value, eg. `invalid type: string "80", expected u16 at
+/// servers[0].port`. Errors thrown by getters are rethrown as-is.
+pub fn from_value<'gc, T: DeserializeOwned>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, T> {
+    let value = value.bind(gc.nogc()).scope(agent, gc.nogc());
+    let result = T::deserialize(ValueDeserializer::new(agent, value, gc.reborrow()));
+    result.map_err(|err| err.into_js_error(agent, gc.into_nogc()))
+}
+
+/// Convert a Rust value into a JavaScript Value.
+///
+/// Serialization errors, eg. from map keys that are not strings or integers,
+/// throw a TypeError.
+pub fn to_value<'gc, T: Serialize + ?Sized>(
+    agent: &mut Agent,
+    value: &T,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let result = value.serialize(ValueSerializer::new(agent, gc));
+    result.map_err(|err| err.into_js_error(agent, gc))
+}
+
+/// Error produced when converting between Rust values and JavaScript Values.
+///
+/// An error caused by a thrown JavaScript error roots the thrown value in a
+/// [`Global`]: convert the error using [`Error::into_js_error`] to release
+/// it.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    /// Path from the root value to the value that caused the error, in
+    /// reverse order.
+    path: Vec<PathSegment>,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Message(std::string::String),
+    /// A JavaScript error was thrown while reading a property.
+    Thrown(Global<JsError<'static>>),
+}
+
+#[derive(Debug)]
+enum PathSegment {
+    Key(std::string::String),
+    Index(usize),
+}
+
+impl Error {
+    fn thrown(agent: &Agent, error: JsError) -> Self {
+        Self {
+            kind: ErrorKind::Thrown(Global::new(agent, error.unbind())),
+            path: Vec::new(),
+        }
+    }
+
+    fn at_key(mut self, key: &str) -> Self {
+        self.path.push(PathSegment::Key(key.to_owned()));
+        self
+    }
+
+    fn at_index(mut self, index: usize) -> Self {
+        self.path.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Get the JavaScript error thrown while reading a property, if the error
+    /// was caused by one.
+    pub fn thrown_error<'gc>(
+        &self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<JsError<'gc>> {
+        match &self.kind {
+            ErrorKind::Thrown(error) => Some(error.get(agent, gc).bind(gc)),
+            ErrorKind::Message(_) => None,
+        }
+    }
+
+    /// Convert the error into a thrown JavaScript error: JavaScript errors
+    /// thrown while reading a property are returned as-is, other errors
+    /// become TypeErrors.
+    pub fn into_js_error<'gc>(self, agent: &mut Agent, gc: NoGcScope<'gc, '_>) -> JsError<'gc> {
+        match self.kind {
+            ErrorKind::Thrown(error) => error.take(agent).bind(gc),
+            ErrorKind::Message(_) => {
+                agent.throw_exception(ExceptionType::TypeError, self.to_string(), gc)
+            }
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Message(message) => f.write_str(message)?,
+            ErrorKind::Thrown(_) => f.write_str("JavaScript error thrown")?,
+        }
+        if self.path.is_empty() {
+            return Ok(());
+        }
+        f.write_str(" at ")?;
+        for (i, segment) in self.path.iter().rev().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => f.write_str(key)?,
+                PathSegment::Key(key) => write!(f, ".{key}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            kind: ErrorKind::Message(msg.to_string()),
+            path: Vec::new(),
+        }
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        <Self as serde::de::Error>::custom(msg)
+    }
+}
+
+fn property_key_to_string(agent: &Agent, key: PropertyKey) -> std::string::String {
+    match key {
+        PropertyKey::Integer(integer) => integer.into_i64().to_string(),
+        PropertyKey::SmallString(string) => string.as_str().to_owned(),
+        PropertyKey::String(string) => agent[string].as_wtf8().to_string_lossy().into_owned(),
+        PropertyKey::Symbol(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,556 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{
+    de::{
+        DeserializeSeed, Deserializer, EnumAccess, Error as _, IntoDeserializer, MapAccess,
+        SeqAccess, Unexpected, VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+};
+
+#[cfg(feature = "set")]
+use crate::ecmascript::builtins::set::Set;
+#[cfg(feature = "array-buffer")]
+use crate::ecmascript::builtins::typed_array::TypedArray;
+use crate::{
+    SmallInteger,
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{get, length_of_array_like, scoped_enumerable_own_keys},
+            testing_and_comparison::is_callable,
+        },
+        builtins::{Array, map::Map},
+        execution::Agent,
+        types::{IntoObject, IntoValue, Number, Object, PropertyKey, String, Value},
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+};
+
+use super::{Error, property_key_to_string};
+
+/// A [`Deserializer`] that reads a JavaScript Value.
+pub struct ValueDeserializer<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    value: Scoped<'scope, Value<'static>>,
+    gc: GcScope<'gc, 'scope>,
+}
+
+impl<'a, 'gc, 'scope> ValueDeserializer<'a, 'gc, 'scope> {
+    pub fn new(
+        agent: &'a mut Agent,
+        value: Scoped<'scope, Value<'static>>,
+        gc: GcScope<'gc, 'scope>,
+    ) -> Self {
+        Self { agent, value, gc }
+    }
+
+    fn is_nullish(&self) -> bool {
+        matches!(self.value.get(self.agent), Value::Undefined | Value::Null)
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_, '_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let Self { agent, value, gc } = self;
+        let value = value.get(agent).bind(gc.nogc());
+        match value {
+            Value::Undefined | Value::Null => visitor.visit_unit(),
+            Value::Boolean(bool) => visitor.visit_bool(bool),
+            Value::String(_) | Value::SmallString(_) => {
+                let string = String::try_from(value).unwrap();
+                visitor.visit_str(&string.as_wtf8(agent).to_string_lossy())
+            }
+            Value::Symbol(_) => Err(Error::invalid_type(Unexpected::Other("symbol"), &visitor)),
+            Value::Integer(integer) => visitor.visit_i64(integer.into_i64()),
+            Value::Number(_) | Value::SmallF64(_) => {
+                let number = Number::try_from(value).unwrap().into_f64(agent);
+                if number != 0.0 && number.trunc() == number && number.abs() < i64::MAX as f64 {
+                    visitor.visit_i64(number as i64)
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            Value::SmallBigInt(bigint) => visitor.visit_i64(bigint.into_i64()),
+            Value::BigInt(bigint) => {
+                let bigint = &agent[bigint].data;
+                if let Ok(bigint) = i64::try_from(bigint) {
+                    visitor.visit_i64(bigint)
+                } else if let Ok(bigint) = u64::try_from(bigint) {
+                    visitor.visit_u64(bigint)
+                } else if let Ok(bigint) = i128::try_from(bigint) {
+                    visitor.visit_i128(bigint)
+                } else if let Ok(bigint) = u128::try_from(bigint) {
+                    visitor.visit_u128(bigint)
+                } else {
+                    Err(Error::invalid_value(
+                        Unexpected::Other("BigInt out of 128-bit range"),
+                        &visitor,
+                    ))
+                }
+            }
+            _ => {
+                let object = Object::try_from(value).unwrap();
+                if is_callable(object, gc.nogc()).is_some() {
+                    return Err(Error::invalid_type(Unexpected::Other("function"), &visitor));
+                }
+                deserialize_object(agent, object.unbind(), visitor, gc)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            visitor.visit_unit()
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let Self {
+            agent,
+            value,
+            mut gc,
+        } = self;
+        let object = match value.get(agent).bind(gc.nogc()) {
+            // A unit variant.
+            Value::String(_) | Value::SmallString(_) => {
+                let variant = String::try_from(value.get(agent)).unwrap();
+                let variant = variant.as_wtf8(agent).to_string_lossy().into_owned();
+                return visitor.visit_enum(variant.into_deserializer());
+            }
+            // A variant with content: an object with a single key.
+            Value::Object(object) => object.into_object(),
+            value => {
+                return Err(Error::invalid_type(unexpected(value), &"string or object"));
+            }
+        };
+        let object = object.scope(agent, gc.nogc());
+        let keys = scoped_enumerable_own_keys(agent, object.clone(), gc.reborrow())
+            .map_err(|err| Error::thrown(agent, err))?;
+        let [key] = keys.as_slice() else {
+            return Err(Error::invalid_length(
+                keys.len(),
+                &"an object with a single key",
+            ));
+        };
+        let key = key.get(agent).bind(gc.nogc());
+        let variant = property_key_to_string(agent, key);
+        let object = object.get(agent);
+        let content = get(agent, object, key.unbind(), gc.reborrow())
+            .map_err(|err| Error::thrown(agent, err))?
+            .unbind()
+            .scope(agent, gc.nogc());
+        visitor
+            .visit_enum(VariantDeserializer {
+                variant: variant.clone(),
+                content: ValueDeserializer::new(agent, content, gc),
+            })
+            .map_err(|err| err.at_key(&variant))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier
+    }
+}
+
+fn deserialize_object<'de, V: Visitor<'de>>(
+    agent: &mut Agent,
+    object: Object,
+    visitor: V,
+    mut gc: GcScope,
+) -> Result<V::Value, Error> {
+    let object = object.bind(gc.nogc());
+    if let Ok(map) = Map::try_from(object) {
+        let map = map.scope(agent, gc.nogc());
+        return visitor.visit_map(CollectionMapAccess {
+            agent,
+            map,
+            index: 0,
+            value: None,
+            gc,
+        });
+    }
+    #[cfg(feature = "set")]
+    if let Ok(set) = Set::try_from(object) {
+        let set = set.scope(agent, gc.nogc());
+        return visitor.visit_seq(CollectionSeqAccess {
+            agent,
+            set,
+            index: 0,
+            gc,
+        });
+    }
+    #[cfg(feature = "array-buffer")]
+    let is_typed_array = TypedArray::try_from(object.into_value()).is_ok();
+    #[cfg(not(feature = "array-buffer"))]
+    let is_typed_array = false;
+    if Array::try_from(object).is_ok() || is_typed_array {
+        let scoped_object = object.scope(agent, gc.nogc());
+        let len = length_of_array_like(agent, object.unbind(), gc.reborrow())
+            .map_err(|err| Error::thrown(agent, err))?;
+        return visitor.visit_seq(ArrayAccess {
+            agent,
+            object: scoped_object,
+            index: 0,
+            len: len as usize,
+            gc,
+        });
+    }
+    let object = object.scope(agent, gc.nogc());
+    let keys = scoped_enumerable_own_keys(agent, object.clone(), gc.reborrow())
+        .map_err(|err| Error::thrown(agent, err))?;
+    visitor.visit_map(ObjectAccess {
+        agent,
+        object,
+        keys: keys.into_iter(),
+        key: None,
+        gc,
+    })
+}
+
+fn unexpected(value: Value) -> Unexpected<'static> {
+    match value {
+        Value::Undefined | Value::Null => Unexpected::Unit,
+        Value::Boolean(bool) => Unexpected::Bool(bool),
+        Value::Integer(integer) => Unexpected::Signed(integer.into_i64()),
+        Value::Symbol(_) => Unexpected::Other("symbol"),
+        Value::String(_) | Value::SmallString(_) => Unexpected::Other("string"),
+        Value::Number(_) | Value::SmallF64(_) => Unexpected::Other("number"),
+        Value::BigInt(_) | Value::SmallBigInt(_) => Unexpected::Other("BigInt"),
+        _ => Unexpected::Other("object"),
+    }
+}
+
+/// Accesses the elements of an Array or a TypedArray.
+struct ArrayAccess<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    object: Scoped<'scope, Object<'static>>,
+    index: usize,
+    len: usize,
+    gc: GcScope<'gc, 'scope>,
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'_, '_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+        let index = self.index;
+        self.index += 1;
+        let key = PropertyKey::Integer(SmallInteger::try_from(index as i64).unwrap());
+        let object = self.object.get(self.agent);
+        let element = get(self.agent, object, key, self.gc.reborrow())
+            .map_err(|err| Error::thrown(self.agent, err).at_index(index))?
+            .unbind()
+            .scope(self.agent, self.gc.nogc());
+        seed.deserialize(ValueDeserializer::new(
+            self.agent,
+            element,
+            self.gc.reborrow(),
+        ))
+        .map(Some)
+        .map_err(|err| err.at_index(index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// Accesses the values of a Set.
+#[cfg(feature = "set")]
+struct CollectionSeqAccess<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    set: Scoped<'scope, Set<'static>>,
+    index: usize,
+    gc: GcScope<'gc, 'scope>,
+}
+
+#[cfg(feature = "set")]
+impl<'de> SeqAccess<'de> for CollectionSeqAccess<'_, '_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let set = self.set.get(self.agent).bind(self.gc.nogc());
+        let values = self.agent[set].values(self.gc.nogc());
+        while self.index < values.len() {
+            let index = self.index;
+            self.index += 1;
+            // Note: Deleted entries are left empty.
+            let Some(value) = values[index] else {
+                continue;
+            };
+            let value = value.scope(self.agent, self.gc.nogc());
+            return seed
+                .deserialize(ValueDeserializer::new(
+                    self.agent,
+                    value,
+                    self.gc.reborrow(),
+                ))
+                .map(Some)
+                .map_err(|err| err.at_index(index));
+        }
+        Ok(None)
+    }
+}
+
+/// Accesses the entries of a Map.
+struct CollectionMapAccess<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    map: Scoped<'scope, Map<'static>>,
+    index: usize,
+    value: Option<Scoped<'scope, Value<'static>>>,
+    gc: GcScope<'gc, 'scope>,
+}
+
+impl<'de> MapAccess<'de> for CollectionMapAccess<'_, '_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let map = self.map.get(self.agent).bind(self.gc.nogc());
+        let len = self.agent[map].keys(self.gc.nogc()).len();
+        while self.index < len {
+            let index = self.index;
+            self.index += 1;
+            // Note: Deleted entries are left empty.
+            let Some(key) = self.agent[map].keys(self.gc.nogc())[index] else {
+                continue;
+            };
+            let value = self.agent[map].values(self.gc.nogc())[index].unwrap();
+            self.value = Some(value.scope(self.agent, self.gc.nogc()));
+            let key = key.scope(self.agent, self.gc.nogc());
+            return seed
+                .deserialize(ValueDeserializer::new(self.agent, key, self.gc.reborrow()))
+                .map(Some)
+                .map_err(|err| err.at_index(index));
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(ValueDeserializer::new(
+            self.agent,
+            value,
+            self.gc.reborrow(),
+        ))
+        .map_err(|err| err.at_index(self.index - 1))
+    }
+}
+
+/// Accesses the enumerable own string-keyed properties of an object.
+struct ObjectAccess<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    object: Scoped<'scope, Object<'static>>,
+    keys: std::vec::IntoIter<Scoped<'scope, PropertyKey<'static>>>,
+    key: Option<(Scoped<'scope, PropertyKey<'static>>, std::string::String)>,
+    gc: GcScope<'gc, 'scope>,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'_, '_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some(key) = self.keys.next() else {
+            return Ok(None);
+        };
+        let name = property_key_to_string(self.agent, key.get(self.agent));
+        let result = seed
+            .deserialize(KeyDeserializer { key: name.clone() })
+            .map(Some)
+            .map_err(|err| err.at_key(&name));
+        self.key = Some((key, name));
+        result
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (key, name) = self
+            .key
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        let object = self.object.get(self.agent);
+        let key = key.get(self.agent);
+        let value = get(self.agent, object, key, self.gc.reborrow())
+            .map_err(|err| Error::thrown(self.agent, err).at_key(&name))?
+            .unbind()
+            .scope(self.agent, self.gc.nogc());
+        seed.deserialize(ValueDeserializer::new(
+            self.agent,
+            value,
+            self.gc.reborrow(),
+        ))
+        .map_err(|err| err.at_key(&name))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Deserializes property keys: keys are strings, but can also be read as
+/// numbers for maps with integer keys.
+struct KeyDeserializer {
+    key: std::string::String,
+}
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.key.parse() {
+                    Ok(key) => visitor.$visit(key),
+                    Err(_) => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for KeyDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(self.key)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.key.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        bool char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Accesses the variant and content of an enum represented as an object with
+/// a single key.
+struct VariantDeserializer<'a, 'gc, 'scope> {
+    variant: std::string::String,
+    content: ValueDeserializer<'a, 'gc, 'scope>,
+}
+
+impl<'de, 'a, 'gc, 'scope> EnumAccess<'de> for VariantDeserializer<'a, 'gc, 'scope> {
+    type Error = Error;
+    type Variant = ValueDeserializer<'a, 'gc, 'scope>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant = seed.deserialize(KeyDeserializer { key: self.variant })?;
+        Ok((variant, self.content))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueDeserializer<'_, '_, '_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        serde::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+}
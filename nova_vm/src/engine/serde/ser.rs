@@ -0,0 +1,447 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{
+    Serialize,
+    ser::{
+        Error as _, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+    },
+};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{create_array_from_list, try_create_data_property},
+            type_conversion::to_property_key_simple,
+        },
+        execution::Agent,
+        types::{BigInt, IntoValue, Number, OrdinaryObject, PropertyKey, String, Value},
+    },
+    engine::{context::NoGcScope, unwrap_try},
+};
+
+use super::{Error, property_key_to_string};
+
+/// Largest integer that can be represented exactly as a Number.
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// A [`Serializer`] that builds a JavaScript Value.
+///
+/// Structs and maps become plain objects, sequences and tuples become Arrays,
+/// `None` becomes `undefined` and unit values become `null`. Enums are
+/// externally tagged: unit variants become strings and other variants become
+/// objects with the variant name as their single key.
+pub struct ValueSerializer<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    gc: NoGcScope<'gc, 'scope>,
+}
+
+impl<'a, 'gc, 'scope> ValueSerializer<'a, 'gc, 'scope> {
+    pub fn new(agent: &'a mut Agent, gc: NoGcScope<'gc, 'scope>) -> Self {
+        Self { agent, gc }
+    }
+
+    fn serialize_integer(self, value: i128) -> Value<'gc> {
+        if (-MAX_SAFE_INTEGER as i128..=MAX_SAFE_INTEGER as i128).contains(&value) {
+            Number::from_i64(self.agent, value as i64, self.gc).into_value()
+        } else {
+            BigInt::from_num_bigint(self.agent, value.into()).into_value()
+        }
+    }
+
+    fn serialize_wrapped(
+        self,
+        variant: &'static str,
+        value: Value<'gc>,
+    ) -> Result<Value<'gc>, Error> {
+        let object = OrdinaryObject::create_empty_object(self.agent, self.gc);
+        let key = PropertyKey::from_static_str(self.agent, variant, self.gc);
+        unwrap_try(try_create_data_property(
+            self.agent, object, key, value, self.gc,
+        ));
+        Ok(object.into_value())
+    }
+}
+
+impl<'a, 'gc, 'scope> Serializer for ValueSerializer<'a, 'gc, 'scope> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    type SerializeSeq = ArraySerializer<'a, 'gc, 'scope>;
+    type SerializeTuple = ArraySerializer<'a, 'gc, 'scope>;
+    type SerializeTupleStruct = ArraySerializer<'a, 'gc, 'scope>;
+    type SerializeTupleVariant = ArraySerializer<'a, 'gc, 'scope>;
+    type SerializeMap = ObjectSerializer<'a, 'gc, 'scope>;
+    type SerializeStruct = ObjectSerializer<'a, 'gc, 'scope>;
+    type SerializeStructVariant = ObjectSerializer<'a, 'gc, 'scope>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value<'gc>, Error> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value<'gc>, Error> {
+        Ok(self.serialize_integer(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value<'gc>, Error> {
+        match i128::try_from(v) {
+            Ok(v) => Ok(self.serialize_integer(v)),
+            Err(_) => Ok(BigInt::from_num_bigint(self.agent, v.into()).into_value()),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value<'gc>, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value<'gc>, Error> {
+        Ok(Number::from_f64(self.agent, v, self.gc).into_value())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value<'gc>, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value<'gc>, Error> {
+        Ok(String::from_str(self.agent, v, self.gc).into_value())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value<'gc>, Error> {
+        let elements = v.iter().map(|byte| Value::from(*byte)).collect::<Vec<_>>();
+        Ok(create_array_from_list(self.agent, &elements, self.gc).into_value())
+    }
+
+    fn serialize_none(self) -> Result<Value<'gc>, Error> {
+        Ok(Value::Undefined)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value<'gc>, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value<'gc>, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value<'gc>, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value<'gc>, Error> {
+        Ok(String::from_static_str(self.agent, variant, self.gc).into_value())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value<'gc>, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value<'gc>, Error> {
+        let value = value.serialize(ValueSerializer::new(self.agent, self.gc))?;
+        self.serialize_wrapped(variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(ArraySerializer {
+            agent: self.agent,
+            elements: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+            gc: self.gc,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(ArraySerializer {
+            agent: self.agent,
+            elements: Vec::with_capacity(len),
+            variant: Some(variant),
+            gc: self.gc,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(ObjectSerializer {
+            object: OrdinaryObject::create_empty_object(self.agent, self.gc),
+            agent: self.agent,
+            key: None,
+            variant: None,
+            gc: self.gc,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(ObjectSerializer {
+            object: OrdinaryObject::create_empty_object(self.agent, self.gc),
+            agent: self.agent,
+            key: None,
+            variant: Some(variant),
+            gc: self.gc,
+        })
+    }
+}
+
+/// Builds an Array from serialized elements. Tuple variants are wrapped in
+/// an object with the variant name as its key.
+pub struct ArraySerializer<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    elements: Vec<Value<'gc>>,
+    variant: Option<&'static str>,
+    gc: NoGcScope<'gc, 'scope>,
+}
+
+impl<'gc> ArraySerializer<'_, 'gc, '_> {
+    fn push_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let index = self.elements.len();
+        let value = value
+            .serialize(ValueSerializer::new(self.agent, self.gc))
+            .map_err(|err| err.at_index(index))?;
+        self.elements.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<'gc>, Error> {
+        let array = create_array_from_list(self.agent, &self.elements, self.gc).into_value();
+        match self.variant {
+            Some(variant) => {
+                ValueSerializer::new(self.agent, self.gc).serialize_wrapped(variant, array)
+            }
+            None => Ok(array),
+        }
+    }
+}
+
+impl<'gc> SerializeSeq for ArraySerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+impl<'gc> SerializeTuple for ArraySerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+impl<'gc> SerializeTupleStruct for ArraySerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+impl<'gc> SerializeTupleVariant for ArraySerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+/// Builds a plain object from serialized properties. Struct variants are
+/// wrapped in an object with the variant name as its key.
+pub struct ObjectSerializer<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    object: OrdinaryObject<'gc>,
+    key: Option<PropertyKey<'gc>>,
+    variant: Option<&'static str>,
+    gc: NoGcScope<'gc, 'scope>,
+}
+
+impl<'gc> ObjectSerializer<'_, 'gc, '_> {
+    fn define_property<T: Serialize + ?Sized>(
+        &mut self,
+        key: PropertyKey<'gc>,
+        value: &T,
+    ) -> Result<(), Error> {
+        let value = value
+            .serialize(ValueSerializer::new(self.agent, self.gc))
+            .map_err(|err| err.at_key(&property_key_to_string(self.agent, key)))?;
+        unwrap_try(try_create_data_property(
+            self.agent,
+            self.object,
+            key,
+            value,
+            self.gc,
+        ));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<'gc>, Error> {
+        let object = self.object.into_value();
+        match self.variant {
+            Some(variant) => {
+                ValueSerializer::new(self.agent, self.gc).serialize_wrapped(variant, object)
+            }
+            None => Ok(object),
+        }
+    }
+}
+
+impl<'gc> SerializeMap for ObjectSerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key.serialize(ValueSerializer::new(self.agent, self.gc))?;
+        match key {
+            Value::String(_) | Value::SmallString(_) | Value::Integer(_) => {
+                self.key = Some(unwrap_try(to_property_key_simple(self.agent, key, self.gc)));
+                Ok(())
+            }
+            _ => Err(Error::custom("map keys must be strings or integers")),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        self.define_property(key, value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+impl<'gc> SerializeStruct for ObjectSerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let key = PropertyKey::from_static_str(self.agent, key, self.gc);
+        self.define_property(key, value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
+
+impl<'gc> SerializeStructVariant for ObjectSerializer<'_, 'gc, '_> {
+    type Ok = Value<'gc>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let key = PropertyKey::from_static_str(self.agent, key, self.gc);
+        self.define_property(key, value)
+    }
+
+    fn end(self) -> Result<Value<'gc>, Error> {
+        self.finish()
+    }
+}
@@ -0,0 +1,1372 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Structured clone](https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data)
+//!
+//! Implementation of the HTML StructuredSerialize and StructuredDeserialize
+//! algorithms. A value is serialized into a [`SerializedValue`] which holds no
+//! references into the heap it was created from: it can be deserialized in
+//! another Realm or another Agent, sent to another thread or persisted.
+//!
+//! Objects are stored in [`SerializedValue::objects`] and referred to by
+//! their index, which preserves shared references and cycles.
+//!
+//! Differences from the HTML specification:
+//!
+//! - "DataCloneError" DOMExceptions are thrown as TypeErrors.
+//! - SharedArrayBuffers cannot be serialized, as there is no notion of an
+//!   agent cluster to share them with.
+//! - Raw JSON objects created by JSON.rawJSON are serialized with their
+//!   `rawJSON` text and deserialized as raw JSON objects.
+
+#[cfg(feature = "regexp")]
+use oxc_ast::ast::RegExpFlags;
+use wtf8::{Wtf8, Wtf8Buf};
+
+#[cfg(feature = "date")]
+use crate::ecmascript::builtins::date::data::{DateHeapData, time_clip};
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::regexp::reg_exp_create_literal;
+#[cfg(feature = "set")]
+use crate::ecmascript::builtins::{
+    keyed_collections::set_objects::set_prototype::set_data_append, set::data::SetHeapData,
+};
+#[cfg(feature = "proposal-json-parse-with-source")]
+use crate::ecmascript::{
+    abstract_operations::operations_on_objects::try_get,
+    builtins::structured_data::json_object::create_raw_json_object,
+};
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{
+                get, has_own_property, scoped_enumerable_own_keys, try_create_data_property,
+            },
+            testing_and_comparison::is_callable,
+            type_conversion::to_string,
+        },
+        builtins::{
+            array_create, check_array_elements_heap_limit, error::ErrorHeapData,
+            keyed_collections::map_objects::map_prototype::map_data_set, map::data::MapHeapData,
+            primitive_objects::PrimitiveObjectHeapData,
+        },
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        types::{
+            BUILTIN_STRING_MEMORY, BigInt, InternalMethods, IntoObject, IntoValue, Number, Object,
+            OrdinaryObject, PropertyKey, String, Value,
+        },
+    },
+    engine::{
+        Scoped,
+        context::{Bindable, GcScope, NoGcScope, trivially_bindable},
+        rootable::Scopable,
+        unwrap_try,
+    },
+    heap::CreateHeapData,
+};
+#[cfg(feature = "array-buffer")]
+use crate::{
+    ecmascript::{
+        builtins::{
+            ArrayBuffer,
+            array_buffer::{
+                ViewedArrayBufferByteLength, ViewedArrayBufferByteOffset, allocate_array_buffer,
+            },
+            data_view::data::DataViewHeapData,
+            typed_array::{
+                TypedArray,
+                data::{TypedArrayArrayLength, TypedArrayHeapData},
+            },
+        },
+        types::IntoFunction,
+    },
+    heap::indexes::TypedArrayIndex,
+};
+
+/// A value serialized with the StructuredSerialize algorithm.
+///
+/// The serialized form holds no references to the heap of the Agent that
+/// created it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerializedValue {
+    /// The serialized value itself.
+    pub root: Serialized,
+    /// All objects reachable from the serialized value. Objects refer to each
+    /// other using their index in this list.
+    pub objects: Vec<SerializedObject>,
+}
+trivially_bindable!(SerializedValue);
+
+/// A serialized primitive or a reference to a serialized object.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Serialized {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    BigInt(num_bigint::BigInt),
+    String(Wtf8Buf),
+    /// Index of an object in [`SerializedValue::objects`].
+    Object(usize),
+}
+trivially_bindable!(Serialized);
+
+/// A serialized object.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SerializedObject {
+    /// A Boolean wrapper object.
+    Boolean(bool),
+    /// A Number wrapper object.
+    Number(f64),
+    /// A BigInt wrapper object.
+    BigInt(num_bigint::BigInt),
+    /// A String wrapper object.
+    String(Wtf8Buf),
+    /// A Date object holding its time value.
+    #[cfg(feature = "date")]
+    Date(f64),
+    /// A RegExp object holding its original source and flags.
+    #[cfg(feature = "regexp")]
+    RegExp {
+        source: Wtf8Buf,
+        flags: std::string::String,
+    },
+    /// An ArrayBuffer holding a copy of its data. A resizable ArrayBuffer
+    /// also holds its maximum byte length.
+    #[cfg(feature = "array-buffer")]
+    ArrayBuffer {
+        data: Vec<u8>,
+        max_byte_length: Option<usize>,
+    },
+    /// A DataView over the ArrayBuffer at index `buffer`. A `None` byte
+    /// length tracks the length of the ArrayBuffer.
+    #[cfg(feature = "array-buffer")]
+    DataView {
+        buffer: usize,
+        byte_offset: usize,
+        byte_length: Option<usize>,
+    },
+    /// A TypedArray over the ArrayBuffer at index `buffer`. `None` lengths
+    /// track the length of the ArrayBuffer.
+    #[cfg(feature = "array-buffer")]
+    TypedArray {
+        kind: TypedArrayKind,
+        buffer: usize,
+        byte_offset: usize,
+        byte_length: Option<usize>,
+        array_length: Option<usize>,
+    },
+    /// A Map holding its entries in insertion order.
+    Map(Vec<(Serialized, Serialized)>),
+    /// A Set holding its values in insertion order.
+    #[cfg(feature = "set")]
+    Set(Vec<Serialized>),
+    /// An Error object holding its name and own message.
+    Error {
+        name: ExceptionType,
+        message: Option<Wtf8Buf>,
+    },
+    /// An Array holding its length and enumerable own properties.
+    Array {
+        length: u32,
+        properties: Vec<(Wtf8Buf, Serialized)>,
+    },
+    /// An ordinary object holding its enumerable own properties.
+    Object {
+        properties: Vec<(Wtf8Buf, Serialized)>,
+    },
+    /// A raw JSON object holding its `rawJSON` text.
+    #[cfg(feature = "proposal-json-parse-with-source")]
+    RawJSON(Wtf8Buf),
+}
+trivially_bindable!(SerializedObject);
+
+/// The \[\[TypedArrayName]] of a serialized TypedArray.
+#[cfg(feature = "array-buffer")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypedArrayKind {
+    Int8Array,
+    Uint8Array,
+    Uint8ClampedArray,
+    Int16Array,
+    Uint16Array,
+    Int32Array,
+    Uint32Array,
+    BigInt64Array,
+    BigUint64Array,
+    #[cfg(feature = "proposal-float16array")]
+    Float16Array,
+    Float32Array,
+    Float64Array,
+}
+
+#[cfg(feature = "array-buffer")]
+impl TypedArrayKind {
+    fn of(typed_array: TypedArray) -> Self {
+        match typed_array {
+            TypedArray::Int8Array(_) => Self::Int8Array,
+            TypedArray::Uint8Array(_) => Self::Uint8Array,
+            TypedArray::Uint8ClampedArray(_) => Self::Uint8ClampedArray,
+            TypedArray::Int16Array(_) => Self::Int16Array,
+            TypedArray::Uint16Array(_) => Self::Uint16Array,
+            TypedArray::Int32Array(_) => Self::Int32Array,
+            TypedArray::Uint32Array(_) => Self::Uint32Array,
+            TypedArray::BigInt64Array(_) => Self::BigInt64Array,
+            TypedArray::BigUint64Array(_) => Self::BigUint64Array,
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => Self::Float16Array,
+            TypedArray::Float32Array(_) => Self::Float32Array,
+            TypedArray::Float64Array(_) => Self::Float64Array,
+        }
+    }
+
+    fn typed_array(self, index: TypedArrayIndex) -> TypedArray {
+        match self {
+            Self::Int8Array => TypedArray::Int8Array(index),
+            Self::Uint8Array => TypedArray::Uint8Array(index),
+            Self::Uint8ClampedArray => TypedArray::Uint8ClampedArray(index),
+            Self::Int16Array => TypedArray::Int16Array(index),
+            Self::Uint16Array => TypedArray::Uint16Array(index),
+            Self::Int32Array => TypedArray::Int32Array(index),
+            Self::Uint32Array => TypedArray::Uint32Array(index),
+            Self::BigInt64Array => TypedArray::BigInt64Array(index),
+            Self::BigUint64Array => TypedArray::BigUint64Array(index),
+            #[cfg(feature = "proposal-float16array")]
+            Self::Float16Array => TypedArray::Float16Array(index),
+            Self::Float32Array => TypedArray::Float32Array(index),
+            Self::Float64Array => TypedArray::Float64Array(index),
+        }
+    }
+
+    /// The Element Size of the TypedArray kind.
+    fn element_size(self) -> usize {
+        match self {
+            Self::Int8Array | Self::Uint8Array | Self::Uint8ClampedArray => 1,
+            Self::Int16Array | Self::Uint16Array => 2,
+            #[cfg(feature = "proposal-float16array")]
+            Self::Float16Array => 2,
+            Self::Int32Array | Self::Uint32Array | Self::Float32Array => 4,
+            Self::BigInt64Array | Self::BigUint64Array | Self::Float64Array => 8,
+        }
+    }
+}
+
+#[cfg(feature = "regexp")]
+const REGEXP_FLAGS: [(RegExpFlags, char); 8] = [
+    (RegExpFlags::D, 'd'),
+    (RegExpFlags::G, 'g'),
+    (RegExpFlags::I, 'i'),
+    (RegExpFlags::M, 'm'),
+    (RegExpFlags::S, 's'),
+    (RegExpFlags::U, 'u'),
+    (RegExpFlags::V, 'v'),
+    (RegExpFlags::Y, 'y'),
+];
+
+/// The serialization memory: objects that have already been serialized and
+/// their serialized records. `memory[i]` was serialized into `objects[i]`.
+struct Serializer<'scope> {
+    memory: Vec<Scoped<'scope, Object<'static>>>,
+    objects: Vec<SerializedObject>,
+}
+
+impl<'scope> Serializer<'scope> {
+    fn new() -> Self {
+        Self {
+            memory: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    /// Returns the index of memory\[object] if it exists.
+    fn lookup(&self, agent: &Agent, object: Object) -> Option<usize> {
+        self.memory.iter().position(|x| x.get(agent) == object)
+    }
+
+    /// Sets memory\[object] to serialized.
+    fn memorize(
+        &mut self,
+        object: Scoped<'scope, Object<'static>>,
+        serialized: SerializedObject,
+    ) -> usize {
+        let index = self.objects.len();
+        self.memory.push(object);
+        self.objects.push(serialized);
+        index
+    }
+
+    /// Serializes the \[\[ViewedArrayBuffer]] of an ArrayBuffer view, or
+    /// returns its index if it has already been serialized.
+    #[cfg(feature = "array-buffer")]
+    fn serialize_viewed_array_buffer<'a>(
+        &mut self,
+        agent: &mut Agent,
+        buffer: ArrayBuffer,
+        gc: NoGcScope<'a, 'scope>,
+    ) -> JsResult<'a, usize> {
+        let buffer = buffer.bind(gc);
+        if let Some(index) = self.lookup(agent, buffer.into_object()) {
+            return Ok(index);
+        }
+        let serialized = serialize_array_buffer(agent, buffer, gc)?;
+        let buffer = buffer.into_object().scope(agent, gc);
+        Ok(self.memorize(buffer, serialized))
+    }
+}
+
+/// ### [StructuredSerialize ( value )](https://html.spec.whatwg.org/multipage/structured-data.html#structuredserialize)
+pub(crate) fn structured_serialize<'a>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, SerializedValue> {
+    let value = value.bind(gc.nogc());
+    let mut serializer = Serializer::new();
+    // 1. Return ? StructuredSerializeInternal(value, false).
+    let root = structured_serialize_internal(agent, &mut serializer, value.unbind(), gc.reborrow())
+        .unbind()?;
+    Ok(SerializedValue {
+        root,
+        objects: serializer.objects,
+    })
+}
+
+/// ### [StructuredSerializeWithTransfer ( value, transferList )](https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer)
+///
+/// Only ArrayBuffers are transferable. The data of a transferred ArrayBuffer
+/// is moved into the serialized form and the ArrayBuffer is detached.
+#[cfg(feature = "array-buffer")]
+pub(crate) fn structured_serialize_with_transfer<'a>(
+    agent: &mut Agent,
+    value: Value,
+    transfer_list: &[Value],
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, SerializedValue> {
+    let value = value.bind(gc.nogc());
+    // 1. Let memory be an empty map.
+    let mut serializer = Serializer::new();
+    // 2. For each transferable of transferList:
+    for transferable in transfer_list {
+        let transferable = transferable.bind(gc.nogc());
+        // 1. If transferable has neither an [[ArrayBufferData]] internal slot
+        //    nor a [[Detached]] internal slot, then throw a "DataCloneError"
+        //    DOMException.
+        // 2. If transferable has an [[ArrayBufferData]] internal slot and
+        //    IsSharedArrayBuffer(transferable) is true, then throw a
+        //    "DataCloneError" DOMException.
+        let Value::ArrayBuffer(buffer) = transferable else {
+            return Err(throw_data_clone_error(
+                agent,
+                "Value in transfer list could not be transferred",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. If memory[transferable] exists, then throw a "DataCloneError"
+        //    DOMException.
+        if serializer.lookup(agent, buffer.into_object()).is_some() {
+            return Err(throw_data_clone_error(
+                agent,
+                "ArrayBuffer is duplicated in transfer list",
+                gc.into_nogc(),
+            ));
+        }
+        // 4. Set memory[transferable] to { [[Type]]: an uninitialized value }.
+        let buffer = buffer.into_object().scope(agent, gc.nogc());
+        serializer.memorize(
+            buffer,
+            SerializedObject::ArrayBuffer {
+                data: Vec::new(),
+                max_byte_length: None,
+            },
+        );
+    }
+    // 3. Let serialized be ? StructuredSerializeInternal(value, false, memory).
+    let root = structured_serialize_internal(agent, &mut serializer, value.unbind(), gc.reborrow())
+        .unbind()?;
+    let gc = gc.into_nogc();
+    // 4. Let transferDataHolders be a new empty List.
+    // 5. For each transferable of transferList:
+    for index in 0..transfer_list.len() {
+        let Object::ArrayBuffer(transferable) = serializer.memory[index].get(agent).bind(gc) else {
+            unreachable!()
+        };
+        // 1. If transferable has an [[ArrayBufferData]] internal slot and
+        //    IsDetachedBuffer(transferable) is true, then throw a
+        //    "DataCloneError" DOMException.
+        if transferable.is_detached(agent) {
+            return Err(throw_data_clone_error(
+                agent,
+                "ArrayBuffer is detached and could not be transferred",
+                gc,
+            ));
+        }
+        // 3. Let dataHolder be memory[transferable].
+        // 4. If transferable has an [[ArrayBufferData]] internal slot, then:
+        //    1. If transferable has an [[ArrayBufferMaxByteLength]] internal
+        //       slot, then set dataHolder.[[Type]] to "ResizableArrayBuffer"
+        //       and dataHolder.[[ArrayBufferMaxByteLength]] to
+        //       transferable.[[ArrayBufferMaxByteLength]].
+        //    2. Set dataHolder.[[ArrayBufferData]] to
+        //       transferable.[[ArrayBufferData]] and
+        //       dataHolder.[[ArrayBufferByteLength]] to
+        //       transferable.[[ArrayBufferByteLength]].
+        let max_byte_length = transferable
+            .is_resizable(agent)
+            .then(|| transferable.max_byte_length(agent));
+        let data = transferable.as_slice(agent).to_vec();
+        //    3. Perform ? DetachArrayBuffer(transferable).
+        transferable.detach(agent, None, gc)?;
+        serializer.objects[index] = SerializedObject::ArrayBuffer {
+            data,
+            max_byte_length,
+        };
+        // 6. Append dataHolder to transferDataHolders.
+    }
+    // 6. Return { [[Serialized]]: serialized, [[TransferDataHolders]]: transferDataHolders }.
+    Ok(SerializedValue {
+        root,
+        objects: serializer.objects,
+    })
+}
+
+/// ### [StructuredSerializeInternal ( value, forStorage \[ , memory \] )](https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal)
+fn structured_serialize_internal<'a, 'scope>(
+    agent: &mut Agent,
+    serializer: &mut Serializer<'scope>,
+    value: Value,
+    mut gc: GcScope<'a, 'scope>,
+) -> JsResult<'a, Serialized> {
+    let value = value.bind(gc.nogc());
+    // 5. If value is undefined, null, a Boolean, a Number, a BigInt, or a
+    //    String, then return { [[Type]]: "primitive", [[Value]]: value }.
+    // 6. If value is a Symbol, then throw a "DataCloneError" DOMException.
+    let Ok(object) = Object::try_from(value) else {
+        return serialize_primitive(agent, value.unbind(), gc.into_nogc());
+    };
+    // 3. If memory[value] exists, then return memory[value].
+    if let Some(index) = serializer.lookup(agent, object) {
+        return Ok(Serialized::Object(index));
+    }
+    // Deeply nested values recurse on the native stack.
+    agent.check_call_stack_limit(gc.nogc()).unbind()?;
+    let scoped_object = object.scope(agent, gc.nogc());
+    // 4. Let deep be false.
+    let mut deep = false;
+    // 7. Let serialized be an uninitialized value.
+    let serialized = match object {
+        // 8. If value has a [[BooleanData]] internal slot, then set serialized
+        //    to { [[Type]]: "Boolean", [[BooleanData]]: value.[[BooleanData]] }.
+        // 9. Otherwise, if value has a [[NumberData]] internal slot, then set
+        //    serialized to { [[Type]]: "Number", [[NumberData]]: value.[[NumberData]] }.
+        // 10. Otherwise, if value has a [[BigIntData]] internal slot, then set
+        //     serialized to { [[Type]]: "BigInt", [[BigIntData]]: value.[[BigIntData]] }.
+        // 11. Otherwise, if value has a [[StringData]] internal slot, then set
+        //     serialized to { [[Type]]: "String", [[StringData]]: value.[[StringData]] }.
+        Object::PrimitiveObject(primitive) => {
+            let data = agent[primitive].data.into_value();
+            match serialize_primitive(agent, data, gc.nogc()).unbind()? {
+                Serialized::Boolean(data) => SerializedObject::Boolean(data),
+                Serialized::Number(data) => SerializedObject::Number(data),
+                Serialized::BigInt(data) => SerializedObject::BigInt(data),
+                Serialized::String(data) => SerializedObject::String(data),
+                _ => unreachable!(),
+            }
+        }
+        // 12. Otherwise, if value has a [[DateValue]] internal slot, then set
+        //     serialized to { [[Type]]: "Date", [[DateValue]]: value.[[DateValue]] }.
+        #[cfg(feature = "date")]
+        Object::Date(date) => {
+            SerializedObject::Date(date.date_value(agent).get_f64().unwrap_or(f64::NAN))
+        }
+        // 13. Otherwise, if value has a [[RegExpMatcher]] internal slot, then
+        //     set serialized to { [[Type]]: "RegExp", [[OriginalSource]]:
+        //     value.[[OriginalSource]], [[OriginalFlags]]: value.[[OriginalFlags]] }.
+        #[cfg(feature = "regexp")]
+        Object::RegExp(regexp) => {
+            let data = &agent[regexp];
+            let source = to_wtf8_buf(data.original_source.as_wtf8(agent));
+            let flags = REGEXP_FLAGS
+                .iter()
+                .filter(|(flag, _)| data.original_flags.contains(*flag))
+                .map(|(_, flag)| *flag)
+                .collect();
+            SerializedObject::RegExp { source, flags }
+        }
+        // 14. Otherwise, if value has an [[ArrayBufferData]] internal slot, then:
+        #[cfg(feature = "array-buffer")]
+        Object::ArrayBuffer(buffer) => serialize_array_buffer(agent, buffer, gc.nogc()).unbind()?,
+        //     1. If IsSharedArrayBuffer(value) is true, then:
+        //        1. If the current principal settings object's cross-origin
+        //           isolated capability is false, then throw a
+        //           "DataCloneError" DOMException.
+        #[cfg(feature = "shared-array-buffer")]
+        Object::SharedArrayBuffer(_) => {
+            return Err(throw_data_clone_error(
+                agent,
+                "SharedArrayBuffer could not be cloned",
+                gc.into_nogc(),
+            ));
+        }
+        // 15. Otherwise, if value has a [[ViewedArrayBuffer]] internal slot, then:
+        #[cfg(feature = "array-buffer")]
+        Object::DataView(view) => {
+            let buffer = view.get_viewed_array_buffer(agent, gc.nogc());
+            let byte_offset = view.byte_offset(agent);
+            let byte_length = view.byte_length(agent);
+            // 1. If IsArrayBufferViewOutOfBounds(value) is true, then throw a
+            //    "DataCloneError" DOMException.
+            if is_view_out_of_bounds(agent, buffer, byte_offset, byte_length) {
+                return Err(throw_data_clone_error(
+                    agent,
+                    "DataView is out of bounds and could not be cloned",
+                    gc.into_nogc(),
+                ));
+            }
+            // 2. Let buffer be the value of value's [[ViewedArrayBuffer]] internal slot.
+            // 3. Let bufferSerialized be ? StructuredSerializeInternal(buffer, forStorage, memory).
+            let buffer = serializer
+                .serialize_viewed_array_buffer(agent, buffer.unbind(), gc.nogc())
+                .unbind()?;
+            // 4. Assert: bufferSerialized.[[Type]] is "ArrayBuffer",
+            //    "ResizableArrayBuffer", "SharedArrayBuffer", or
+            //    "GrowableSharedArrayBuffer".
+            // 5. If value has a [[DataView]] internal slot, then set
+            //    serialized to { [[Type]]: "ArrayBufferView", [[Constructor]]:
+            //    "DataView", [[ArrayBufferSerialized]]: bufferSerialized,
+            //    [[ByteLength]]: value.[[ByteLength]], [[ByteOffset]]:
+            //    value.[[ByteOffset]] }.
+            SerializedObject::DataView {
+                buffer,
+                byte_offset,
+                byte_length,
+            }
+        }
+        #[cfg(feature = "array-buffer")]
+        Object::Int8Array(_)
+        | Object::Uint8Array(_)
+        | Object::Uint8ClampedArray(_)
+        | Object::Int16Array(_)
+        | Object::Uint16Array(_)
+        | Object::Int32Array(_)
+        | Object::Uint32Array(_)
+        | Object::BigInt64Array(_)
+        | Object::BigUint64Array(_)
+        | Object::Float32Array(_)
+        | Object::Float64Array(_) => {
+            let typed_array = TypedArray::try_from(object.into_value()).unwrap();
+            serialize_typed_array(agent, serializer, typed_array, gc.nogc()).unbind()?
+        }
+        #[cfg(feature = "proposal-float16array")]
+        Object::Float16Array(_) => {
+            let typed_array = TypedArray::try_from(object.into_value()).unwrap();
+            serialize_typed_array(agent, serializer, typed_array, gc.nogc()).unbind()?
+        }
+        // 16. Otherwise, if value has [[MapData]] internal slot, then:
+        //     1. Set serialized to { [[Type]]: "Map", [[MapData]]: a new empty List }.
+        //     2. Set deep to true.
+        Object::Map(_) => {
+            deep = true;
+            SerializedObject::Map(Vec::new())
+        }
+        // 17. Otherwise, if value has [[SetData]] internal slot, then:
+        //     1. Set serialized to { [[Type]]: "Set", [[SetData]]: a new empty List }.
+        //     2. Set deep to true.
+        #[cfg(feature = "set")]
+        Object::Set(_) => {
+            deep = true;
+            SerializedObject::Set(Vec::new())
+        }
+        // 18. Otherwise, if value has an [[ErrorData]] internal slot and value
+        //     is not a platform object, then:
+        Object::Error(_) => {
+            // 1. Let name be ? Get(value, "name").
+            let name = get(
+                agent,
+                object.unbind(),
+                BUILTIN_STRING_MEMORY.name.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // 2. If name is not one of "Error", "EvalError", "RangeError",
+            //    "ReferenceError", "SyntaxError", "TypeError", or "URIError",
+            //    then set name to "Error".
+            let name = match String::try_from(name) {
+                Ok(name) => match &*name.as_wtf8(agent).to_string_lossy() {
+                    "EvalError" => ExceptionType::EvalError,
+                    "RangeError" => ExceptionType::RangeError,
+                    "ReferenceError" => ExceptionType::ReferenceError,
+                    "SyntaxError" => ExceptionType::SyntaxError,
+                    "TypeError" => ExceptionType::TypeError,
+                    "URIError" => ExceptionType::UriError,
+                    _ => ExceptionType::Error,
+                },
+                Err(_) => ExceptionType::Error,
+            };
+            // 3. Let valueMessageDesc be ? value.[[GetOwnProperty]]("message").
+            let value_message_desc = scoped_object
+                .get(agent)
+                .internal_get_own_property(
+                    agent,
+                    BUILTIN_STRING_MEMORY.message.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+            // 4. Let message be undefined if IsDataDescriptor(valueMessageDesc)
+            //    is false, and ? ToString(valueMessageDesc.[[Value]]) otherwise.
+            let message = match value_message_desc {
+                Some(desc) if desc.is_data_descriptor() => {
+                    let value = desc.value.unwrap_or(Value::Undefined);
+                    let message = to_string(agent, value.unbind(), gc.reborrow())
+                        .unbind()?
+                        .bind(gc.nogc());
+                    Some(to_wtf8_buf(message.as_wtf8(agent)))
+                }
+                _ => None,
+            };
+            // 5. Set serialized to { [[Type]]: "Error", [[Name]]: name, [[Message]]: message }.
+            SerializedObject::Error { name, message }
+        }
+        // 19. Otherwise, if value is an Array exotic object, then:
+        Object::Array(array) => {
+            // 1. Let valueLenDescriptor be ? OrdinaryGetOwnProperty(value, "length").
+            // 2. Let valueLen be valueLenDescriptor.[[Value]].
+            let length = array.len(agent);
+            // 3. Set serialized to { [[Type]]: "Array", [[Length]]: valueLen,
+            //    [[Properties]]: a new empty List }.
+            // 4. Set deep to true.
+            deep = true;
+            SerializedObject::Array {
+                length,
+                properties: Vec::new(),
+            }
+        }
+        // NOTE: Raw JSON objects are frozen and only hold their rawJSON text,
+        //       which is serialized in place of their properties.
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        Object::RawJSON(raw_json) => {
+            let raw_json = unwrap_try(try_get(
+                agent,
+                raw_json,
+                BUILTIN_STRING_MEMORY.rawJSON.to_property_key(),
+                gc.nogc(),
+            ));
+            let raw_json = String::try_from(raw_json).unwrap();
+            SerializedObject::RawJSON(to_wtf8_buf(raw_json.as_wtf8(agent)))
+        }
+        // 25. Otherwise, set serialized to { [[Type]]: "Object",
+        //     [[Properties]]: a new empty List }, and set deep to true.
+        Object::Object(_) => {
+            deep = true;
+            SerializedObject::Object {
+                properties: Vec::new(),
+            }
+        }
+        _ => {
+            // 21. Otherwise, if value is a platform object, then throw a
+            //     "DataCloneError" DOMException.
+            // 22. Otherwise, if IsCallable(value) is true, then throw a
+            //     "DataCloneError" DOMException.
+            // 23. Otherwise, if value has any internal slot other than
+            //     [[Prototype]], [[Extensible]], or [[PrivateElements]],
+            //     then throw a "DataCloneError" DOMException.
+            // 24. Otherwise, if value is an exotic object and value is not
+            //     the %Object.prototype% intrinsic object associated with any
+            //     realm, then throw a "DataCloneError" DOMException.
+            let message = if is_callable(object, gc.nogc()).is_some() {
+                "Function could not be cloned"
+            } else {
+                "Object could not be cloned"
+            };
+            return Err(throw_data_clone_error(agent, message, gc.into_nogc()));
+        }
+    };
+    // 26. Set memory[value] to serialized.
+    let index = serializer.memorize(scoped_object.clone(), serialized);
+    // 27. If deep is true, then:
+    if deep {
+        match scoped_object.get(agent).bind(gc.nogc()) {
+            // 1. If value has a [[MapData]] internal slot, then:
+            Object::Map(map) => {
+                // 1. Let copiedList be a new empty List.
+                // 2. For each Record { [[Key]], [[Value]] } entry of
+                //    value.[[MapData]]:
+                //    1. Let copiedEntry be a new Record { [[Key]]:
+                //       entry.[[Key]], [[Value]]: entry.[[Value]] }.
+                //    2. If copiedEntry.[[Key]] is not the special value empty,
+                //       append copiedEntry to copiedList.
+                let map_data = &agent[map];
+                let copied_list = map_data
+                    .keys(gc.nogc())
+                    .iter()
+                    .zip(map_data.values(gc.nogc()))
+                    .filter_map(|(key, value)| {
+                        Some((key.as_ref()?.unbind(), value.unwrap().unbind()))
+                    })
+                    .collect::<Vec<_>>();
+                let copied_list = copied_list
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (key.scope(agent, gc.nogc()), value.scope(agent, gc.nogc()))
+                    })
+                    .collect::<Vec<_>>();
+                // 3. For each Record { [[Key]], [[Value]] } entry of copiedList:
+                let mut serialized_map_data = Vec::with_capacity(copied_list.len());
+                for (key, value) in copied_list {
+                    // 1. Let serializedKey be ? StructuredSerializeInternal(entry.[[Key]], forStorage, memory).
+                    let serialized_key = structured_serialize_internal(
+                        agent,
+                        serializer,
+                        key.get(agent),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    // 2. Let serializedValue be ? StructuredSerializeInternal(entry.[[Value]], forStorage, memory).
+                    let serialized_value = structured_serialize_internal(
+                        agent,
+                        serializer,
+                        value.get(agent),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    // 3. Append { [[Key]]: serializedKey, [[Value]]: serializedValue } to serialized.[[MapData]].
+                    serialized_map_data.push((serialized_key, serialized_value));
+                }
+                serializer.objects[index] = SerializedObject::Map(serialized_map_data);
+            }
+            // 2. Otherwise, if value has a [[SetData]] internal slot, then:
+            #[cfg(feature = "set")]
+            Object::Set(set) => {
+                // 1. Let copiedList be a new empty List.
+                // 2. For each entry of value.[[SetData]]:
+                //    1. If entry is not the special value empty, append entry
+                //       to copiedList.
+                let copied_list = agent[set]
+                    .values(gc.nogc())
+                    .iter()
+                    .filter_map(|entry| *entry)
+                    .map(|entry| entry.unbind())
+                    .collect::<Vec<_>>();
+                let copied_list = copied_list
+                    .into_iter()
+                    .map(|entry| entry.scope(agent, gc.nogc()))
+                    .collect::<Vec<_>>();
+                // 3. For each entry of copiedList:
+                let mut serialized_set_data = Vec::with_capacity(copied_list.len());
+                for entry in copied_list {
+                    // 1. Let serializedEntry be ? StructuredSerializeInternal(entry, forStorage, memory).
+                    let serialized_entry = structured_serialize_internal(
+                        agent,
+                        serializer,
+                        entry.get(agent),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    // 2. Append serializedEntry to serialized.[[SetData]].
+                    serialized_set_data.push(serialized_entry);
+                }
+                serializer.objects[index] = SerializedObject::Set(serialized_set_data);
+            }
+            // 3. Otherwise:
+            _ => serialize_properties(agent, serializer, index, scoped_object, gc.reborrow())
+                .unbind()?,
+        }
+    }
+    // 28. Return serialized.
+    Ok(Serialized::Object(index))
+}
+
+/// Serializes the enumerable own string-keyed properties of an Array or an
+/// ordinary object into the serialized object at `index`.
+///
+/// > Note: This performs step 27.3 of the StructuredSerializeInternal
+/// > abstract operation.
+fn serialize_properties<'a, 'scope>(
+    agent: &mut Agent,
+    serializer: &mut Serializer<'scope>,
+    index: usize,
+    value: Scoped<'scope, Object<'static>>,
+    mut gc: GcScope<'a, 'scope>,
+) -> JsResult<'a, ()> {
+    // 1. Let properties be ! EnumerableOwnProperties(value, key).
+    let properties = scoped_enumerable_own_keys(agent, value.clone(), gc.reborrow()).unbind()?;
+    let mut serialized_properties = Vec::with_capacity(properties.len());
+    // 2. For each key in properties:
+    for key in properties {
+        // 1. If ! HasOwnProperty(value, key) is true, then:
+        if !has_own_property(agent, value.get(agent), key.get(agent), gc.reborrow()).unbind()? {
+            continue;
+        }
+        // 1. Let inputValue be ? value.[[Get]](key, value).
+        let input_value = get(agent, value.get(agent), key.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 2. Let outputValue be ? StructuredSerializeInternal(inputValue, forStorage, memory).
+        let output_value =
+            structured_serialize_internal(agent, serializer, input_value.unbind(), gc.reborrow())
+                .unbind()?;
+        // 3. Append { [[Key]]: key, [[Value]]: outputValue } to serialized.[[Properties]].
+        let key = property_key_to_string(agent, key.get(agent));
+        serialized_properties.push((key, output_value));
+    }
+    match &mut serializer.objects[index] {
+        SerializedObject::Array { properties, .. } | SerializedObject::Object { properties } => {
+            *properties = serialized_properties;
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+/// Serializes a primitive value, throwing for Symbols.
+fn serialize_primitive<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Serialized> {
+    Ok(match value {
+        Value::Undefined => Serialized::Undefined,
+        Value::Null => Serialized::Null,
+        Value::Boolean(value) => Serialized::Boolean(value),
+        Value::String(_) | Value::SmallString(_) => {
+            let value = String::try_from(value).unwrap();
+            Serialized::String(to_wtf8_buf(value.as_wtf8(agent)))
+        }
+        Value::Number(_) | Value::Integer(_) | Value::SmallF64(_) => {
+            Serialized::Number(Number::try_from(value).unwrap().into_f64(agent))
+        }
+        Value::BigInt(value) => Serialized::BigInt(agent[value].data.clone()),
+        Value::SmallBigInt(value) => Serialized::BigInt(value.into_i64().into()),
+        Value::Symbol(_) => {
+            return Err(throw_data_clone_error(
+                agent,
+                "Symbol could not be cloned",
+                gc,
+            ));
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Serializes an ArrayBuffer by copying its data.
+///
+/// > Note: This performs step 14 of the StructuredSerializeInternal abstract
+/// > operation for ArrayBuffers that are not SharedArrayBuffers.
+#[cfg(feature = "array-buffer")]
+fn serialize_array_buffer<'a>(
+    agent: &mut Agent,
+    value: ArrayBuffer,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, SerializedObject> {
+    // 2. Otherwise:
+    //    1. If IsDetachedBuffer(value) is true, then throw a "DataCloneError" DOMException.
+    if value.is_detached(agent) {
+        return Err(throw_data_clone_error(
+            agent,
+            "ArrayBuffer is detached and could not be cloned",
+            gc,
+        ));
+    }
+    //    2. Let size be value.[[ArrayBufferByteLength]].
+    //    3. Let dataCopy be ? CreateByteDataBlock(size).
+    //    4. Perform CopyDataBlockBytes(dataCopy, 0, value.[[ArrayBufferData]], 0, size).
+    let data = value.as_slice(agent).to_vec();
+    //    5. If value has an [[ArrayBufferMaxByteLength]] internal slot, then
+    //       set serialized to { [[Type]]: "ResizableArrayBuffer",
+    //       [[ArrayBufferData]]: dataCopy, [[ByteLength]]: size,
+    //       [[ArrayBufferMaxByteLength]]: value.[[ArrayBufferMaxByteLength]] }.
+    //    6. Otherwise, set serialized to { [[Type]]: "ArrayBuffer",
+    //       [[ArrayBufferData]]: dataCopy, [[ByteLength]]: size }.
+    let max_byte_length = value
+        .is_resizable(agent)
+        .then(|| value.max_byte_length(agent));
+    Ok(SerializedObject::ArrayBuffer {
+        data,
+        max_byte_length,
+    })
+}
+
+/// Serializes a TypedArray and its \[\[ViewedArrayBuffer]].
+///
+/// > Note: This performs step 15 of the StructuredSerializeInternal abstract
+/// > operation for TypedArrays.
+#[cfg(feature = "array-buffer")]
+fn serialize_typed_array<'a, 'scope>(
+    agent: &mut Agent,
+    serializer: &mut Serializer<'scope>,
+    value: TypedArray,
+    gc: NoGcScope<'a, 'scope>,
+) -> JsResult<'a, SerializedObject> {
+    let value = value.bind(gc);
+    let buffer = value.get_viewed_array_buffer(agent, gc);
+    let byte_offset = value.byte_offset(agent);
+    let byte_length = value.byte_length(agent);
+    let array_length = value.array_length(agent);
+    // 1. If IsArrayBufferViewOutOfBounds(value) is true, then throw a
+    //    "DataCloneError" DOMException.
+    if is_view_out_of_bounds(agent, buffer, byte_offset, byte_length) {
+        return Err(throw_data_clone_error(
+            agent,
+            "TypedArray is out of bounds and could not be cloned",
+            gc,
+        ));
+    }
+    // 2. Let buffer be the value of value's [[ViewedArrayBuffer]] internal slot.
+    // 3. Let bufferSerialized be ? StructuredSerializeInternal(buffer, forStorage, memory).
+    let buffer = serializer.serialize_viewed_array_buffer(agent, buffer, gc)?;
+    // 6. Otherwise:
+    //    1. Assert: value has a [[TypedArrayName]] internal slot.
+    //    2. Let type be value.[[TypedArrayName]].
+    //    3. Set serialized to { [[Type]]: "ArrayBufferView", [[Constructor]]:
+    //       type, [[ArrayBufferSerialized]]: bufferSerialized, [[ByteLength]]:
+    //       value.[[ByteLength]], [[ByteOffset]]: value.[[ByteOffset]],
+    //       [[ArrayLength]]: value.[[ArrayLength]] }.
+    Ok(SerializedObject::TypedArray {
+        kind: TypedArrayKind::of(value),
+        buffer,
+        byte_offset,
+        byte_length,
+        array_length,
+    })
+}
+
+/// Returns true if the view's bytes are not within its detached or shrunk
+/// ArrayBuffer. A `None` byte length tracks the length of the ArrayBuffer.
+#[cfg(feature = "array-buffer")]
+fn is_view_out_of_bounds(
+    agent: &Agent,
+    buffer: ArrayBuffer,
+    byte_offset: usize,
+    byte_length: Option<usize>,
+) -> bool {
+    if buffer.is_detached(agent) {
+        return true;
+    }
+    let buffer_byte_length = buffer.byte_length(agent);
+    let byte_offset_end = byte_length.map_or(buffer_byte_length, |byte_length| {
+        byte_offset.saturating_add(byte_length)
+    });
+    byte_offset > buffer_byte_length || byte_offset_end > buffer_byte_length
+}
+
+/// ### [StructuredDeserialize ( serialized, targetRealm \[ , memory \] )](https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize)
+///
+/// Objects are created in the current Realm. All objects are created before
+/// any of their contents are deserialized so that references between them
+/// can be resolved by index.
+pub(crate) fn structured_deserialize<'a>(
+    agent: &mut Agent,
+    serialized: &SerializedValue,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Value<'a>> {
+    let SerializedValue { root, objects } = serialized;
+    let mut memory: Vec<Option<Object<'a>>> = vec![None; objects.len()];
+    // Note: ArrayBuffer views are created after all other objects so that
+    // their ArrayBuffers exist.
+    for (index, serialized) in objects.iter().enumerate() {
+        memory[index] = create_object(agent, serialized, gc)?;
+    }
+    #[cfg(feature = "array-buffer")]
+    for (index, serialized) in objects.iter().enumerate() {
+        if memory[index].is_none() {
+            memory[index] = Some(create_view(agent, &memory, serialized, gc)?);
+        }
+    }
+    let memory = memory.into_iter().map(Option::unwrap).collect::<Vec<_>>();
+    for (value, serialized) in memory.iter().zip(objects) {
+        match (*value, serialized) {
+            // 1. If serialized.[[Type]] is "Map", then:
+            //    For each Record { [[Key]], [[Value]] } entry of serialized.[[MapData]]:
+            //    1. Let deserializedKey be ? StructuredDeserialize(entry.[[Key]], targetRealm, memory).
+            //    2. Let deserializedValue be ? StructuredDeserialize(entry.[[Value]], targetRealm, memory).
+            //    3. Append { [[Key]]: deserializedKey, [[Value]]: deserializedValue } to value.[[MapData]].
+            (Object::Map(map), SerializedObject::Map(entries)) => {
+                for (key, value) in entries {
+                    let key = deserialize_value(agent, &memory, key, gc)?;
+                    let value = deserialize_value(agent, &memory, value, gc)?;
+                    map_data_set(agent, map, key, value);
+                }
+            }
+            // 2. Otherwise, if serialized.[[Type]] is "Set", then:
+            //    For each entry of serialized.[[SetData]]:
+            //    1. Let deserializedEntry be ? StructuredDeserialize(entry, targetRealm, memory).
+            //    2. Append deserializedEntry to value.[[SetData]].
+            #[cfg(feature = "set")]
+            (Object::Set(set), SerializedObject::Set(entries)) => {
+                for entry in entries {
+                    let entry = deserialize_value(agent, &memory, entry, gc)?;
+                    set_data_append(agent, set, entry);
+                }
+            }
+            // 3. Otherwise, if serialized.[[Type]] is "Array" or "Object", then:
+            //    For each Record { [[Key]], [[Value]] } entry of serialized.[[Properties]]:
+            //    1. Let deserializedValue be ? StructuredDeserialize(entry.[[Value]], targetRealm, memory).
+            //    2. Let result be ! CreateDataProperty(value, entry.[[Key]], deserializedValue).
+            //    3. Assert: result is true.
+            (
+                _,
+                SerializedObject::Array { properties, .. }
+                | SerializedObject::Object { properties },
+            ) => {
+                for (key, property_value) in properties {
+                    let property_value = deserialize_value(agent, &memory, property_value, gc)?;
+                    let key = match key.as_str() {
+                        Some(key) => PropertyKey::from_str(agent, key, gc),
+                        None => String::from_wtf8_buf(agent, key.clone(), gc).into(),
+                    };
+                    let result = unwrap_try(try_create_data_property(
+                        agent,
+                        *value,
+                        key,
+                        property_value,
+                        gc,
+                    ));
+                    debug_assert!(result);
+                }
+            }
+            _ => {}
+        }
+    }
+    deserialize_value(agent, &memory, root, gc)
+}
+
+/// Creates the object for a serialized object without its contents. Returns
+/// `None` for ArrayBuffer views, which are created by [`create_view`].
+fn create_object<'a>(
+    agent: &mut Agent,
+    serialized: &SerializedObject,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Option<Object<'a>>> {
+    let object = match serialized {
+        // 5. Otherwise, if serialized.[[Type]] is "Boolean", then set value to
+        //    a new Boolean object in targetRealm whose [[BooleanData]]
+        //    internal slot value is serialized.[[BooleanData]].
+        SerializedObject::Boolean(data) => agent
+            .heap
+            .create(PrimitiveObjectHeapData::new_boolean_object(*data))
+            .into_object(),
+        // 6. Otherwise, if serialized.[[Type]] is "Number", then set value to
+        //    a new Number object in targetRealm whose [[NumberData]] internal
+        //    slot value is serialized.[[NumberData]].
+        SerializedObject::Number(data) => {
+            let data = Number::from_f64(agent, *data, gc);
+            agent
+                .heap
+                .create(PrimitiveObjectHeapData::new_number_object(data))
+                .into_object()
+        }
+        // 7. Otherwise, if serialized.[[Type]] is "BigInt", then set value to
+        //    a new BigInt object in targetRealm whose [[BigIntData]] internal
+        //    slot value is serialized.[[BigIntData]].
+        SerializedObject::BigInt(data) => {
+            let data = BigInt::from_num_bigint(agent, data.clone());
+            agent
+                .heap
+                .create(PrimitiveObjectHeapData::new_big_int_object(data))
+                .into_object()
+        }
+        // 8. Otherwise, if serialized.[[Type]] is "String", then set value to
+        //    a new String object in targetRealm whose [[StringData]] internal
+        //    slot value is serialized.[[StringData]].
+        SerializedObject::String(data) => {
+            let data = String::from_wtf8_buf(agent, data.clone(), gc);
+            agent
+                .heap
+                .create(PrimitiveObjectHeapData::new_string_object(data))
+                .into_object()
+        }
+        // 9. Otherwise, if serialized.[[Type]] is "Date", then set value to a
+        //    new Date object in targetRealm whose [[DateValue]] internal slot
+        //    value is serialized.[[DateValue]].
+        #[cfg(feature = "date")]
+        SerializedObject::Date(data) => agent
+            .heap
+            .create(DateHeapData {
+                object_index: None,
+                date: time_clip(*data),
+            })
+            .into_object(),
+        // 10. Otherwise, if serialized.[[Type]] is "RegExp", then:
+        //     1. Let regExpResult be ! RegExpCreate(serialized.[[OriginalSource]],
+        //        serialized.[[OriginalFlags]]).
+        #[cfg(feature = "regexp")]
+        SerializedObject::RegExp { source, flags } => {
+            let mut original_flags = RegExpFlags::empty();
+            for flag in flags.chars() {
+                let Some((flag, _)) = REGEXP_FLAGS.iter().find(|(_, c)| *c == flag) else {
+                    return Err(throw_invalid_serialized_value(agent, gc));
+                };
+                if original_flags.contains(*flag) {
+                    return Err(throw_invalid_serialized_value(agent, gc));
+                }
+                original_flags |= *flag;
+            }
+            let source = String::from_wtf8_buf(agent, source.clone(), gc);
+            reg_exp_create_literal(agent, source, Some(original_flags), gc).into_object()
+        }
+        // 12. Otherwise, if serialized.[[Type]] is "ArrayBuffer", then set
+        //     value to a new ArrayBuffer object in targetRealm whose
+        //     [[ArrayBufferData]] internal slot value is
+        //     serialized.[[ArrayBufferData]], and whose
+        //     [[ArrayBufferByteLength]] internal slot value is
+        //     serialized.[[ArrayBufferByteLength]].
+        // 13. Otherwise, if serialized.[[Type]] is "ResizableArrayBuffer",
+        //     then set value to a new ArrayBuffer object in targetRealm whose
+        //     [[ArrayBufferData]] internal slot value is
+        //     serialized.[[ArrayBufferData]], whose [[ArrayBufferByteLength]]
+        //     internal slot value is serialized.[[ArrayBufferByteLength]], and
+        //     whose [[ArrayBufferMaxByteLength]] internal slot value is
+        //     serialized.[[ArrayBufferMaxByteLength]].
+        #[cfg(feature = "array-buffer")]
+        SerializedObject::ArrayBuffer {
+            data,
+            max_byte_length,
+        } => {
+            let array_buffer_constructor = agent.current_realm_record().intrinsics().array_buffer();
+            let buffer = allocate_array_buffer(
+                agent,
+                array_buffer_constructor.into_function(),
+                data.len() as u64,
+                max_byte_length.map(|max_byte_length| max_byte_length as u64),
+                gc,
+            )?;
+            buffer.as_mut_slice(agent).copy_from_slice(data);
+            buffer.into_object()
+        }
+        #[cfg(feature = "array-buffer")]
+        SerializedObject::DataView { .. } | SerializedObject::TypedArray { .. } => {
+            return Ok(None);
+        }
+        // 17. Otherwise, if serialized.[[Type]] is "Map", then set value to a
+        //     new Map object in targetRealm whose [[MapData]] internal slot
+        //     value is a new empty List.
+        SerializedObject::Map(_) => agent.heap.create(MapHeapData::default()).into_object(),
+        // 18. Otherwise, if serialized.[[Type]] is "Set", then set value to a
+        //     new Set object in targetRealm whose [[SetData]] internal slot
+        //     value is a new empty List.
+        #[cfg(feature = "set")]
+        SerializedObject::Set(_) => agent.heap.create(SetHeapData::default()).into_object(),
+        // 19. Otherwise, if serialized.[[Type]] is "Array", then:
+        //     1. Let outputProto be targetRealm.[[Intrinsics]].[[%Array.prototype%]].
+        //     2. Set value to ! ArrayCreate(serialized.[[Length]], outputProto).
+        SerializedObject::Array { length, .. } => {
+            let length = *length as usize;
+            check_array_elements_heap_limit(agent, length, gc)?;
+            array_create(agent, length, length, None, gc)?.into_object()
+        }
+        // 20. Otherwise, if serialized.[[Type]] is "Object", then set value to
+        //     a new Object in targetRealm.
+        SerializedObject::Object { .. } => {
+            OrdinaryObject::create_empty_object(agent, gc).into_object()
+        }
+        #[cfg(feature = "proposal-json-parse-with-source")]
+        SerializedObject::RawJSON(raw_json) => {
+            let raw_json = String::from_wtf8_buf(agent, raw_json.clone(), gc);
+            let Ok(object) = create_raw_json_object(agent, raw_json, gc) else {
+                return Err(throw_invalid_serialized_value(agent, gc));
+            };
+            object
+        }
+        // 21. Otherwise, if serialized.[[Type]] is "Error", then:
+        //     1. Let prototype be %Error.prototype%.
+        //     2. If serialized.[[Name]] is "EvalError", then set prototype to
+        //        %EvalError.prototype%.
+        //     ...
+        //     8. Let object be OrdinaryObjectCreate(prototype, « [[ErrorData]] »).
+        //     9. Let objectDescriptor be PropertyDescriptor{ [[Value]]: "message",
+        //        [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
+        //     10. If serialized.[[Message]] is not undefined, then perform
+        //         ! OrdinaryDefineOwnProperty(object, "message", objectDescriptor).
+        //     11. Set value to object.
+        SerializedObject::Error { name, message } => {
+            let message = message
+                .as_ref()
+                .map(|message| String::from_wtf8_buf(agent, message.clone(), gc));
+            agent
+                .heap
+                .create(ErrorHeapData::new(*name, message, None))
+                .into_object()
+        }
+    };
+    Ok(Some(object.bind(gc)))
+}
+
+/// Creates an ArrayBuffer view over an already deserialized ArrayBuffer.
+///
+/// > Note: This performs step 14 of the StructuredDeserialize abstract
+/// > operation.
+#[cfg(feature = "array-buffer")]
+fn create_view<'a>(
+    agent: &mut Agent,
+    memory: &[Option<Object<'a>>],
+    serialized: &SerializedObject,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    // 1. Let deserializedArrayBuffer be ? StructuredDeserialize(serialized.[[ArrayBufferSerialized]], targetRealm, memory).
+    let buffer_index = match serialized {
+        SerializedObject::DataView { buffer, .. } | SerializedObject::TypedArray { buffer, .. } => {
+            *buffer
+        }
+        _ => unreachable!(),
+    };
+    let Some(Some(Object::ArrayBuffer(buffer))) = memory.get(buffer_index) else {
+        return Err(throw_invalid_serialized_value(agent, gc));
+    };
+    let buffer = *buffer;
+    match *serialized {
+        // 2. If serialized.[[Constructor]] is "DataView", then set value to a
+        //    new DataView object in targetRealm whose [[ViewedArrayBuffer]]
+        //    internal slot value is deserializedArrayBuffer, whose
+        //    [[ByteLength]] internal slot value is serialized.[[ByteLength]],
+        //    and whose [[ByteOffset]] internal slot value is
+        //    serialized.[[ByteOffset]].
+        SerializedObject::DataView {
+            byte_offset,
+            byte_length,
+            ..
+        } => {
+            let view = agent.heap.create(DataViewHeapData {
+                object_index: None,
+                viewed_array_buffer: buffer.unbind(),
+                byte_length: byte_length.into(),
+                byte_offset: byte_offset.into(),
+            });
+            if agent[view].byte_length == ViewedArrayBufferByteLength::heap() {
+                agent
+                    .heap
+                    .data_view_byte_lengths
+                    .insert(view.unbind(), byte_length.unwrap());
+            }
+            if agent[view].byte_offset == ViewedArrayBufferByteOffset::heap() {
+                agent
+                    .heap
+                    .data_view_byte_offsets
+                    .insert(view.unbind(), byte_offset);
+            }
+            Ok(view.into_object().bind(gc))
+        }
+        // 3. Otherwise, set value to a new typed array object in targetRealm,
+        //    using the constructor given by serialized.[[Constructor]], whose
+        //    [[ViewedArrayBuffer]] internal slot value is
+        //    deserializedArrayBuffer, whose [[TypedArrayName]] internal slot
+        //    value is serialized.[[Constructor]], whose [[ByteLength]]
+        //    internal slot value is serialized.[[ByteLength]], whose
+        //    [[ByteOffset]] internal slot value is serialized.[[ByteOffset]],
+        //    and whose [[ArrayLength]] internal slot value is
+        //    serialized.[[ArrayLength]].
+        SerializedObject::TypedArray {
+            kind,
+            byte_offset,
+            byte_length,
+            array_length,
+            ..
+        } => {
+            let element_size = kind.element_size();
+            let is_valid = byte_offset % element_size == 0
+                && match (byte_length, array_length) {
+                    (Some(byte_length), Some(array_length)) => {
+                        array_length.checked_mul(element_size) == Some(byte_length)
+                    }
+                    (None, None) => true,
+                    _ => false,
+                };
+            if !is_valid {
+                return Err(throw_invalid_serialized_value(agent, gc));
+            }
+            let heap_data = TypedArrayHeapData {
+                object_index: None,
+                viewed_array_buffer: buffer.unbind(),
+                byte_length: byte_length.into(),
+                byte_offset: byte_offset.into(),
+                array_length: array_length.into(),
+            };
+            let is_heap_byte_length = heap_data.byte_length == ViewedArrayBufferByteLength::heap();
+            let is_heap_byte_offset = heap_data.byte_offset == ViewedArrayBufferByteOffset::heap();
+            let is_heap_array_length = heap_data.array_length == TypedArrayArrayLength::heap();
+            agent.heap.typed_arrays.push(Some(heap_data));
+            let typed_array = kind.typed_array(TypedArrayIndex::last(&agent.heap.typed_arrays));
+            if is_heap_byte_length {
+                agent
+                    .heap
+                    .typed_array_byte_lengths
+                    .insert(typed_array.unbind(), byte_length.unwrap());
+            }
+            if is_heap_byte_offset {
+                agent
+                    .heap
+                    .typed_array_byte_offsets
+                    .insert(typed_array.unbind(), byte_offset);
+            }
+            if is_heap_array_length {
+                agent
+                    .heap
+                    .typed_array_array_lengths
+                    .insert(typed_array.unbind(), array_length.unwrap());
+            }
+            Ok(typed_array.into_object().bind(gc))
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Deserializes a primitive value or looks up an already created object.
+fn deserialize_value<'a>(
+    agent: &mut Agent,
+    memory: &[Object<'a>],
+    serialized: &Serialized,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Value<'a>> {
+    // 4. If serialized.[[Type]] is "primitive", then set value to serialized.[[Value]].
+    Ok(match serialized {
+        Serialized::Undefined => Value::Undefined,
+        Serialized::Null => Value::Null,
+        Serialized::Boolean(value) => Value::Boolean(*value),
+        Serialized::Number(value) => Number::from_f64(agent, *value, gc).into_value(),
+        Serialized::BigInt(value) => BigInt::from_num_bigint(agent, value.clone())
+            .into_value()
+            .bind(gc),
+        Serialized::String(value) => String::from_wtf8_buf(agent, value.clone(), gc).into_value(),
+        // 2. If memory[serialized] exists, then return memory[serialized].
+        Serialized::Object(index) => match memory.get(*index) {
+            Some(object) => object.into_value(),
+            None => return Err(throw_invalid_serialized_value(agent, gc)),
+        },
+    })
+}
+
+fn property_key_to_string(agent: &Agent, key: PropertyKey) -> Wtf8Buf {
+    match key {
+        PropertyKey::Integer(integer) => Wtf8Buf::from_string(integer.into_i64().to_string()),
+        PropertyKey::SmallString(string) => Wtf8Buf::from_str(string.as_str()),
+        PropertyKey::String(string) => to_wtf8_buf(agent[string].as_wtf8()),
+        PropertyKey::Symbol(_) => unreachable!(),
+    }
+}
+
+fn to_wtf8_buf(wtf8: &Wtf8) -> Wtf8Buf {
+    let mut buf = Wtf8Buf::with_capacity(wtf8.len());
+    buf.push_wtf8(wtf8);
+    buf
+}
+
+/// Throws a "DataCloneError" as a TypeError.
+fn throw_data_clone_error<'a>(
+    agent: &mut Agent,
+    message: &'static str,
+    gc: NoGcScope<'a, '_>,
+) -> JsError<'a> {
+    agent.throw_exception_with_static_message(ExceptionType::TypeError, message, gc)
+}
+
+fn throw_invalid_serialized_value<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "Invalid serialized value",
+        gc,
+    )
+}
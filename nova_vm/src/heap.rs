@@ -31,10 +31,24 @@ use self::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::data::CollatorHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::data::DateTimeFormatHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::data::NumberFormatHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::data::PluralRulesHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::data::DurationHeapData, instant::data::InstantHeapData,
+    plain_date::data::PlainDateHeapData, plain_date_time::data::PlainDateTimeHeapData,
+    plain_time::data::PlainTimeHeapData, zoned_date_time::data::ZonedDateTimeHeapData,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{
     ArrayBufferHeapData,
@@ -57,6 +71,10 @@ use crate::{
             ArrayBuffer, ArrayHeapData,
             array_buffer::DetachKey,
             async_generator_objects::AsyncGeneratorHeapData,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelperHeapData,
+                wrap_for_valid_iterator::WrapForValidIteratorHeapData,
+            },
             control_abstraction_objects::{
                 async_function_objects::await_reaction::AwaitReaction,
                 generator_objects::GeneratorHeapData,
@@ -68,13 +86,16 @@ use crate::{
             embedder_object::data::EmbedderObjectHeapData,
             error::ErrorHeapData,
             finalization_registry::data::FinalizationRegistryHeapData,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
+            indexed_collections::array_objects::{
+                array_from_async::ArrayFromAsyncRecord,
+                array_iterator_objects::array_iterator::ArrayIteratorHeapData,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
             map::data::MapHeapData,
             module::{Module, data::ModuleHeapData},
             primitive_objects::PrimitiveObjectHeapData,
             promise::data::PromiseHeapData,
-            proxy::data::ProxyHeapData,
+            proxy::{data::ProxyHeapData, revoker_function::ProxyRevokerFunctionHeapData},
         },
         execution::{Environments, Realm, RealmRecord},
         scripts_and_modules::{
@@ -97,8 +118,8 @@ use crate::{
 #[cfg(feature = "array-buffer")]
 use ahash::AHashMap;
 use hashbrown::HashTable;
-pub(crate) use heap_bits::{CompactionLists, HeapMarkAndSweep, WorkQueues};
-use wtf8::Wtf8;
+pub(crate) use heap_bits::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues};
+use wtf8::{Wtf8, Wtf8Buf};
 
 #[derive(Debug)]
 pub struct Heap {
@@ -106,6 +127,7 @@ pub struct Heap {
     pub array_buffers: Vec<Option<ArrayBufferHeapData<'static>>>,
     #[cfg(feature = "array-buffer")]
     pub array_buffer_detach_keys: AHashMap<ArrayBuffer<'static>, DetachKey>,
+    pub(crate) array_from_async_records: Vec<Option<ArrayFromAsyncRecord<'static>>>,
     pub arrays: Vec<Option<ArrayHeapData<'static>>>,
     pub array_iterators: Vec<Option<ArrayIteratorHeapData<'static>>>,
     pub async_generators: Vec<Option<AsyncGeneratorHeapData<'static>>>,
@@ -122,12 +144,32 @@ pub struct Heap {
     pub data_view_byte_offsets: AHashMap<DataView<'static>, usize>,
     #[cfg(feature = "date")]
     pub dates: Vec<Option<DateHeapData<'static>>>,
+    #[cfg(feature = "intl")]
+    pub collators: Vec<Option<CollatorHeapData<'static>>>,
+    #[cfg(feature = "intl")]
+    pub date_time_formats: Vec<Option<DateTimeFormatHeapData<'static>>>,
+    #[cfg(feature = "intl")]
+    pub number_formats: Vec<Option<NumberFormatHeapData<'static>>>,
+    #[cfg(feature = "intl")]
+    pub plural_rules: Vec<Option<PluralRulesHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub durations: Vec<Option<DurationHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub instants: Vec<Option<InstantHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_dates: Vec<Option<PlainDateHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_date_times: Vec<Option<PlainDateTimeHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_times: Vec<Option<PlainTimeHeapData<'static>>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub zoned_date_times: Vec<Option<ZonedDateTimeHeapData<'static>>>,
     pub ecmascript_functions: Vec<Option<ECMAScriptFunctionHeapData<'static>>>,
     /// ElementsArrays is where all element arrays live;
     /// Element arrays are static arrays of Values plus
     /// a HashMap of possible property descriptors.
     pub elements: ElementArrays,
-    pub embedder_objects: Vec<Option<EmbedderObjectHeapData>>,
+    pub embedder_objects: Vec<Option<EmbedderObjectHeapData<'static>>>,
     pub environments: Environments,
     pub errors: Vec<Option<ErrorHeapData<'static>>>,
     /// Stores compiled bytecodes
@@ -137,12 +179,15 @@ pub struct Heap {
     pub(crate) globals: RefCell<Vec<Option<HeapRootData>>>,
     pub maps: Vec<Option<MapHeapData<'static>>>,
     pub map_iterators: Vec<Option<MapIteratorHeapData<'static>>>,
+    pub iterator_helpers: Vec<Option<IteratorHelperHeapData<'static>>>,
+    pub wrap_for_valid_iterators: Vec<Option<WrapForValidIteratorHeapData<'static>>>,
     pub numbers: Vec<Option<NumberHeapData>>,
     pub objects: Vec<Option<ObjectHeapData<'static>>>,
     pub primitive_objects: Vec<Option<PrimitiveObjectHeapData<'static>>>,
     pub promise_reaction_records: Vec<Option<PromiseReactionRecord<'static>>>,
     pub promise_resolving_functions: Vec<Option<PromiseResolvingFunctionHeapData<'static>>>,
     pub promises: Vec<Option<PromiseHeapData<'static>>>,
+    pub proxy_revoker_functions: Vec<Option<ProxyRevokerFunctionHeapData<'static>>>,
     pub proxys: Vec<Option<ProxyHeapData<'static>>>,
     pub realms: Vec<Option<RealmRecord<'static>>>,
     #[cfg(feature = "regexp")]
@@ -178,6 +223,10 @@ pub struct Heap {
     pub strings: Vec<Option<StringHeapData>>,
     pub string_lookup_table: HashTable<HeapString<'static>>,
     pub string_hasher: ahash::RandomState,
+    /// Number of bytes held outside of the heap vectors by string data, Data
+    /// Blocks, keyed collection entries, and bytecode. Allocations are counted
+    /// as they happen, and the count is recomputed after garbage collection.
+    pub(crate) external_bytes: usize,
     /// Counts allocations for garbage collection triggering.
     #[cfg(feature = "interleaved-gc")]
     pub(crate) alloc_counter: usize,
@@ -211,6 +260,17 @@ impl CreateHeapData<std::string::String, String<'static>> for Heap {
     }
 }
 
+impl CreateHeapData<Wtf8Buf, String<'static>> for Heap {
+    fn create(&mut self, data: Wtf8Buf) -> String<'static> {
+        match data.into_string() {
+            Ok(data) => self.create(data),
+            // SAFETY: SmallStrings are always valid UTF-8, so a string that
+            // isn't cannot be represented as a SmallString.
+            Err(data) => unsafe { self.alloc_wtf8_buf(data) },
+        }
+    }
+}
+
 impl Heap {
     pub fn new() -> Heap {
         let mut heap = Heap {
@@ -218,6 +278,7 @@ impl Heap {
             array_buffers: Vec::with_capacity(1024),
             #[cfg(feature = "array-buffer")]
             array_buffer_detach_keys: AHashMap::with_capacity(0),
+            array_from_async_records: Vec::with_capacity(0),
             arrays: Vec::with_capacity(1024),
             array_iterators: Vec::with_capacity(256),
             async_generators: Vec::with_capacity(0),
@@ -234,6 +295,26 @@ impl Heap {
             data_view_byte_offsets: AHashMap::with_capacity(0),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(1024),
+            #[cfg(feature = "intl")]
+            collators: Vec::with_capacity(1024),
+            #[cfg(feature = "intl")]
+            date_time_formats: Vec::with_capacity(1024),
+            #[cfg(feature = "intl")]
+            number_formats: Vec::with_capacity(1024),
+            #[cfg(feature = "intl")]
+            plural_rules: Vec::with_capacity(1024),
+            #[cfg(feature = "proposal-temporal")]
+            durations: Vec::with_capacity(0),
+            #[cfg(feature = "proposal-temporal")]
+            instants: Vec::with_capacity(0),
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates: Vec::with_capacity(0),
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times: Vec::with_capacity(0),
+            #[cfg(feature = "proposal-temporal")]
+            plain_times: Vec::with_capacity(0),
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times: Vec::with_capacity(0),
             ecmascript_functions: Vec::with_capacity(1024),
             elements: ElementArrays {
                 e2pow4: ElementArray2Pow4::with_capacity(1024),
@@ -255,6 +336,8 @@ impl Heap {
             globals: RefCell::new(Vec::with_capacity(1024)),
             maps: Vec::with_capacity(128),
             map_iterators: Vec::with_capacity(128),
+            iterator_helpers: Vec::with_capacity(0),
+            wrap_for_valid_iterators: Vec::with_capacity(0),
             modules: Vec::with_capacity(0),
             numbers: Vec::with_capacity(1024),
             objects: Vec::with_capacity(1024),
@@ -262,6 +345,7 @@ impl Heap {
             promise_reaction_records: Vec::with_capacity(0),
             promise_resolving_functions: Vec::with_capacity(0),
             promises: Vec::with_capacity(0),
+            proxy_revoker_functions: Vec::with_capacity(0),
             proxys: Vec::with_capacity(0),
             realms: Vec::with_capacity(1),
             #[cfg(feature = "regexp")]
@@ -276,6 +360,7 @@ impl Heap {
             strings: Vec::with_capacity(1024),
             string_lookup_table: HashTable::with_capacity(1024),
             string_hasher: ahash::RandomState::new(),
+            external_bytes: 0,
             symbols: Vec::with_capacity(1024),
             #[cfg(feature = "array-buffer")]
             typed_arrays: Vec::with_capacity(0),
@@ -350,7 +435,7 @@ impl Heap {
     /// comparison between heap allocated strings and SmallStrings can be
     /// guaranteed to never equal true.
     pub(crate) unsafe fn alloc_str(&mut self, message: &str) -> String<'static> {
-        let found = self.find_equal_string(message);
+        let found = self.find_equal_string(Wtf8::from_str(message));
         match found {
             Ok(string) => string,
             Err(hash) => {
@@ -373,7 +458,7 @@ impl Heap {
     /// comparison between heap allocated strings and SmallStrings can be
     /// guaranteed to never equal true.
     unsafe fn alloc_string(&mut self, message: std::string::String) -> String<'static> {
-        let found = self.find_equal_string(message.as_str());
+        let found = self.find_equal_string(Wtf8::from_str(message.as_str()));
         match found {
             Ok(string) => string,
             Err(hash) => {
@@ -383,6 +468,29 @@ impl Heap {
         }
     }
 
+    /// Allocate a WTF-8 string onto the Agent heap
+    ///
+    /// This method will currently iterate through all heap strings to look for
+    /// a possible matching string and if found will return its HeapString
+    /// instead of allocating a copy.
+    ///
+    /// # Safety
+    ///
+    /// The string being allocated must not be representable as a
+    /// SmallString. All SmallStrings must be kept on the stack to ensure that
+    /// comparison between heap allocated strings and SmallStrings can be
+    /// guaranteed to never equal true.
+    unsafe fn alloc_wtf8_buf(&mut self, message: Wtf8Buf) -> String<'static> {
+        let found = self.find_equal_string(&message);
+        match found {
+            Ok(string) => string,
+            Err(hash) => {
+                let data = StringHeapData::from_wtf8_buf(message);
+                self.create((data, hash))
+            }
+        }
+    }
+
     /// Allocate a static string onto the Agent heap
     ///
     /// This method will currently iterate through all heap strings to look for
@@ -396,7 +504,7 @@ impl Heap {
     /// comparison between heap allocated strings and SmallStrings can be
     /// guaranteed to never equal true.
     pub(crate) unsafe fn alloc_static_str(&mut self, message: &'static str) -> String<'static> {
-        let found = self.find_equal_string(message);
+        let found = self.find_equal_string(Wtf8::from_str(message));
         match found {
             Ok(string) => string,
             Err(hash) => {
@@ -407,9 +515,8 @@ impl Heap {
     }
 
     /// Find existing heap String or return the strings hash.
-    fn find_equal_string(&self, message: &str) -> Result<String<'static>, u64> {
-        debug_assert!(message.len() > 7);
-        let message = Wtf8::from_str(message);
+    fn find_equal_string(&self, message: &Wtf8) -> Result<String<'static>, u64> {
+        debug_assert!(message.len() > 7 || message.as_str().is_none());
         let hash = self.string_hasher.hash_one(message);
         self.string_lookup_table
             .find(hash, |heap_string| {
@@ -478,6 +585,259 @@ impl Heap {
         }
         ObjectIndex::last(&self.objects).into()
     }
+
+    /// Returns the approximate number of bytes used by the heap: the heap
+    /// vectors, element arrays, string data, Data Blocks, keyed collection
+    /// entries, and bytecode.
+    pub(crate) fn heap_size(&self) -> usize {
+        #[cfg(feature = "array-buffer")]
+        fn map_size<K, V>(map: &AHashMap<K, V>) -> usize {
+            map.len() * core::mem::size_of::<(K, V)>()
+        }
+
+        let Heap {
+            #[cfg(feature = "array-buffer")]
+            array_buffers,
+            #[cfg(feature = "array-buffer")]
+            array_buffer_detach_keys,
+            array_from_async_records,
+            arrays,
+            array_iterators,
+            async_generators,
+            await_reactions,
+            bigints,
+            bound_functions,
+            builtin_constructors,
+            builtin_functions,
+            #[cfg(feature = "array-buffer")]
+            data_views,
+            #[cfg(feature = "array-buffer")]
+            data_view_byte_lengths,
+            #[cfg(feature = "array-buffer")]
+            data_view_byte_offsets,
+            #[cfg(feature = "date")]
+            dates,
+            #[cfg(feature = "intl")]
+            collators,
+            #[cfg(feature = "intl")]
+            date_time_formats,
+            #[cfg(feature = "intl")]
+            number_formats,
+            #[cfg(feature = "intl")]
+            plural_rules,
+            #[cfg(feature = "proposal-temporal")]
+            durations,
+            #[cfg(feature = "proposal-temporal")]
+            instants,
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates,
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times,
+            #[cfg(feature = "proposal-temporal")]
+            plain_times,
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times,
+            ecmascript_functions,
+            elements,
+            embedder_objects,
+            environments,
+            errors,
+            executables,
+            finalization_registrys,
+            generators,
+            globals,
+            maps,
+            map_iterators,
+            iterator_helpers,
+            wrap_for_valid_iterators,
+            numbers,
+            objects,
+            primitive_objects,
+            promise_reaction_records,
+            promise_resolving_functions,
+            promises,
+            proxy_revoker_functions,
+            proxys,
+            realms,
+            #[cfg(feature = "regexp")]
+            regexps,
+            #[cfg(feature = "set")]
+            sets,
+            #[cfg(feature = "set")]
+            set_iterators,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_array_buffers,
+            symbols,
+            #[cfg(feature = "array-buffer")]
+            typed_arrays,
+            #[cfg(feature = "array-buffer")]
+            typed_array_byte_lengths,
+            #[cfg(feature = "array-buffer")]
+            typed_array_byte_offsets,
+            #[cfg(feature = "array-buffer")]
+            typed_array_array_lengths,
+            #[cfg(feature = "weak-refs")]
+            weak_maps,
+            #[cfg(feature = "weak-refs")]
+            weak_refs,
+            #[cfg(feature = "weak-refs")]
+            weak_sets,
+            modules,
+            scripts,
+            source_codes,
+            strings,
+            string_lookup_table,
+            string_hasher: _,
+            #[cfg(feature = "interleaved-gc")]
+                alloc_counter: _,
+            external_bytes,
+        } = self;
+        let mut size = *external_bytes + elements.heap_size() + environments.heap_size();
+        #[cfg(feature = "array-buffer")]
+        {
+            size += core::mem::size_of_val(array_buffers.as_slice());
+            size += map_size(array_buffer_detach_keys);
+            size += core::mem::size_of_val(data_views.as_slice());
+            size += map_size(data_view_byte_lengths);
+            size += map_size(data_view_byte_offsets);
+            size += core::mem::size_of_val(typed_arrays.as_slice());
+            size += map_size(typed_array_byte_lengths);
+            size += map_size(typed_array_byte_offsets);
+            size += map_size(typed_array_array_lengths);
+        }
+        size += core::mem::size_of_val(array_from_async_records.as_slice());
+        size += core::mem::size_of_val(arrays.as_slice());
+        size += core::mem::size_of_val(array_iterators.as_slice());
+        size += core::mem::size_of_val(async_generators.as_slice());
+        size += core::mem::size_of_val(await_reactions.as_slice());
+        size += core::mem::size_of_val(bigints.as_slice());
+        size += core::mem::size_of_val(bound_functions.as_slice());
+        size += core::mem::size_of_val(builtin_constructors.as_slice());
+        size += core::mem::size_of_val(builtin_functions.as_slice());
+        size += core::mem::size_of_val(ecmascript_functions.as_slice());
+        size += core::mem::size_of_val(embedder_objects.as_slice());
+        size += core::mem::size_of_val(errors.as_slice());
+        size += core::mem::size_of_val(executables.as_slice());
+        size += core::mem::size_of_val(finalization_registrys.as_slice());
+        size += core::mem::size_of_val(generators.as_slice());
+        size += core::mem::size_of_val(globals.borrow().as_slice());
+        size += core::mem::size_of_val(maps.as_slice());
+        size += core::mem::size_of_val(map_iterators.as_slice());
+        size += core::mem::size_of_val(iterator_helpers.as_slice());
+        size += core::mem::size_of_val(wrap_for_valid_iterators.as_slice());
+        size += core::mem::size_of_val(numbers.as_slice());
+        size += core::mem::size_of_val(objects.as_slice());
+        size += core::mem::size_of_val(primitive_objects.as_slice());
+        size += core::mem::size_of_val(promise_reaction_records.as_slice());
+        size += core::mem::size_of_val(promise_resolving_functions.as_slice());
+        size += core::mem::size_of_val(promises.as_slice());
+        size += core::mem::size_of_val(proxy_revoker_functions.as_slice());
+        size += core::mem::size_of_val(proxys.as_slice());
+        size += core::mem::size_of_val(realms.as_slice());
+        size += core::mem::size_of_val(symbols.as_slice());
+        size += core::mem::size_of_val(modules.as_slice());
+        size += core::mem::size_of_val(scripts.as_slice());
+        size += core::mem::size_of_val(source_codes.as_slice());
+        size += core::mem::size_of_val(strings.as_slice());
+        size += string_lookup_table.len() * core::mem::size_of::<HeapString>();
+        #[cfg(feature = "date")]
+        {
+            size += core::mem::size_of_val(dates.as_slice());
+        }
+        #[cfg(feature = "intl")]
+        {
+            size += core::mem::size_of_val(collators.as_slice());
+            size += core::mem::size_of_val(date_time_formats.as_slice());
+            size += core::mem::size_of_val(number_formats.as_slice());
+            size += core::mem::size_of_val(plural_rules.as_slice());
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            size += core::mem::size_of_val(durations.as_slice());
+            size += core::mem::size_of_val(instants.as_slice());
+            size += core::mem::size_of_val(plain_dates.as_slice());
+            size += core::mem::size_of_val(plain_date_times.as_slice());
+            size += core::mem::size_of_val(plain_times.as_slice());
+            size += core::mem::size_of_val(zoned_date_times.as_slice());
+        }
+        #[cfg(feature = "regexp")]
+        {
+            size += core::mem::size_of_val(regexps.as_slice());
+        }
+        #[cfg(feature = "set")]
+        {
+            size += core::mem::size_of_val(sets.as_slice());
+            size += core::mem::size_of_val(set_iterators.as_slice());
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            size += core::mem::size_of_val(shared_array_buffers.as_slice());
+        }
+        #[cfg(feature = "weak-refs")]
+        {
+            size += core::mem::size_of_val(weak_maps.as_slice());
+            size += core::mem::size_of_val(weak_refs.as_slice());
+            size += core::mem::size_of_val(weak_sets.as_slice());
+        }
+        size
+    }
+
+    /// Recount the number of bytes held by string data, Data Blocks, keyed
+    /// collection entries, and bytecode.
+    pub(crate) fn recount_external_bytes(&mut self) {
+        let string_bytes = self
+            .strings
+            .iter()
+            .flatten()
+            .map(StringHeapData::len)
+            .sum::<usize>();
+        #[cfg(feature = "array-buffer")]
+        let data_block_bytes = self
+            .array_buffers
+            .iter()
+            .flatten()
+            .map(ArrayBufferHeapData::byte_length)
+            .sum::<usize>();
+        #[cfg(not(feature = "array-buffer"))]
+        let data_block_bytes = 0;
+        let mut keyed_collection_bytes = self
+            .maps
+            .iter()
+            .flatten()
+            .map(MapHeapData::external_byte_size)
+            .sum::<usize>();
+        #[cfg(feature = "set")]
+        {
+            keyed_collection_bytes += self
+                .sets
+                .iter()
+                .flatten()
+                .map(SetHeapData::external_byte_size)
+                .sum::<usize>();
+        }
+        #[cfg(feature = "weak-refs")]
+        {
+            keyed_collection_bytes += self
+                .weak_maps
+                .iter()
+                .flatten()
+                .map(WeakMapHeapData::external_byte_size)
+                .sum::<usize>();
+            keyed_collection_bytes += self
+                .weak_sets
+                .iter()
+                .flatten()
+                .map(WeakSetHeapData::external_byte_size)
+                .sum::<usize>();
+        }
+        let bytecode_bytes = self
+            .executables
+            .iter()
+            .map(ExecutableHeapData::external_byte_size)
+            .sum::<usize>();
+        self.external_bytes =
+            string_bytes + data_block_bytes + keyed_collection_bytes + bytecode_bytes;
+    }
 }
 
 impl Default for Heap {
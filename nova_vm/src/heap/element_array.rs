@@ -58,6 +58,11 @@ impl ElementArrayKey {
             ElementArrayKey::E32 => u32::MAX,
         }
     }
+
+    /// Size in bytes of an element array of this size class.
+    pub(crate) fn byte_size(self) -> usize {
+        self.cap() as usize * core::mem::size_of::<Option<Value>>()
+    }
 }
 
 impl From<u32> for ElementArrayKey {
@@ -120,6 +125,16 @@ impl ElementsVector<'_> {
         self.cap.cap()
     }
 
+    /// Returns the number of bytes that reserving room for `new_len`
+    /// elements would allocate.
+    pub(crate) fn reserve_byte_size(&self, new_len: u32) -> usize {
+        if new_len <= self.cap() {
+            0
+        } else {
+            ElementArrayKey::from(new_len).byte_size()
+        }
+    }
+
     pub fn len(&self) -> u32 {
         self.len
     }
@@ -1862,6 +1877,28 @@ impl ElementArrays {
         elements_vector.elements_index = new_index;
     }
 
+    /// Returns the number of bytes used by element arrays.
+    pub(crate) fn heap_size(&self) -> usize {
+        let ElementArrays {
+            e2pow4,
+            e2pow6,
+            e2pow8,
+            e2pow10,
+            e2pow12,
+            e2pow16,
+            e2pow24,
+            e2pow32,
+        } = self;
+        core::mem::size_of_val(e2pow4.values.as_slice())
+            + core::mem::size_of_val(e2pow6.values.as_slice())
+            + core::mem::size_of_val(e2pow8.values.as_slice())
+            + core::mem::size_of_val(e2pow10.values.as_slice())
+            + core::mem::size_of_val(e2pow12.values.as_slice())
+            + core::mem::size_of_val(e2pow16.values.as_slice())
+            + core::mem::size_of_val(e2pow24.values.as_slice())
+            + core::mem::size_of_val(e2pow32.values.as_slice())
+    }
+
     pub fn allocate_elements_with_capacity(&mut self, capacity: usize) -> ElementsVector<'static> {
         let cap = ElementArrayKey::from(capacity);
         ElementsVector {
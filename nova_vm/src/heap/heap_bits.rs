@@ -15,10 +15,23 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::Duration, instant::Instant, plain_date::PlainDate, plain_date_time::PlainDateTime,
+    plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "set")]
@@ -32,6 +45,9 @@ use crate::ecmascript::{
         Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
         async_generator_objects::AsyncGenerator,
         bound_function::BoundFunction,
+        control_abstraction_objects::iteration::{
+            iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+        },
         control_abstraction_objects::{
             async_function_objects::await_reaction::AwaitReactionIdentifier,
             generator_objects::Generator,
@@ -43,13 +59,15 @@ use crate::ecmascript::{
         embedder_object::EmbedderObject,
         error::Error,
         finalization_registry::FinalizationRegistry,
-        indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+        indexed_collections::array_objects::{
+            array_from_async::ArrayFromAsync, array_iterator_objects::array_iterator::ArrayIterator,
+        },
         keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
         map::Map,
         module::Module,
         primitive_objects::PrimitiveObject,
         promise::Promise,
-        proxy::Proxy,
+        proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
     },
     execution::{
         DeclarativeEnvironment, FunctionEnvironment, GlobalEnvironment, ObjectEnvironment, Realm,
@@ -66,6 +84,7 @@ use crate::engine::Executable;
 pub struct HeapBits {
     #[cfg(feature = "array-buffer")]
     pub array_buffers: Box<[bool]>,
+    pub array_from_async_records: Box<[bool]>,
     pub arrays: Box<[bool]>,
     pub array_iterators: Box<[bool]>,
     pub async_generators: Box<[bool]>,
@@ -78,6 +97,26 @@ pub struct HeapBits {
     pub data_views: Box<[bool]>,
     #[cfg(feature = "date")]
     pub dates: Box<[bool]>,
+    #[cfg(feature = "intl")]
+    pub collators: Box<[bool]>,
+    #[cfg(feature = "intl")]
+    pub date_time_formats: Box<[bool]>,
+    #[cfg(feature = "intl")]
+    pub number_formats: Box<[bool]>,
+    #[cfg(feature = "intl")]
+    pub plural_rules: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub durations: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub instants: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_dates: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_date_times: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_times: Box<[bool]>,
+    #[cfg(feature = "proposal-temporal")]
+    pub zoned_date_times: Box<[bool]>,
     pub declarative_environments: Box<[bool]>,
     pub e_2_10: Box<[(bool, u16)]>,
     pub e_2_12: Box<[(bool, u16)]>,
@@ -98,6 +137,8 @@ pub struct HeapBits {
     pub global_environments: Box<[bool]>,
     pub maps: Box<[bool]>,
     pub map_iterators: Box<[bool]>,
+    pub iterator_helpers: Box<[bool]>,
+    pub wrap_for_valid_iterators: Box<[bool]>,
     pub modules: Box<[bool]>,
     pub numbers: Box<[bool]>,
     pub object_environments: Box<[bool]>,
@@ -106,6 +147,7 @@ pub struct HeapBits {
     pub promise_reaction_records: Box<[bool]>,
     pub promise_resolving_functions: Box<[bool]>,
     pub promises: Box<[bool]>,
+    pub proxy_revoker_functions: Box<[bool]>,
     pub proxys: Box<[bool]>,
     pub realms: Box<[bool]>,
     #[cfg(feature = "regexp")]
@@ -133,6 +175,7 @@ pub struct HeapBits {
 pub(crate) struct WorkQueues {
     #[cfg(feature = "array-buffer")]
     pub array_buffers: Vec<ArrayBuffer<'static>>,
+    pub array_from_async_records: Vec<ArrayFromAsync<'static>>,
     pub arrays: Vec<Array<'static>>,
     pub array_iterators: Vec<ArrayIterator<'static>>,
     pub async_generators: Vec<AsyncGenerator<'static>>,
@@ -145,6 +188,26 @@ pub(crate) struct WorkQueues {
     pub data_views: Vec<DataView<'static>>,
     #[cfg(feature = "date")]
     pub dates: Vec<Date<'static>>,
+    #[cfg(feature = "intl")]
+    pub collators: Vec<Collator<'static>>,
+    #[cfg(feature = "intl")]
+    pub date_time_formats: Vec<DateTimeFormat<'static>>,
+    #[cfg(feature = "intl")]
+    pub number_formats: Vec<NumberFormat<'static>>,
+    #[cfg(feature = "intl")]
+    pub plural_rules: Vec<PluralRules<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub durations: Vec<Duration<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub instants: Vec<Instant<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_dates: Vec<PlainDate<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_date_times: Vec<PlainDateTime<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_times: Vec<PlainTime<'static>>,
+    #[cfg(feature = "proposal-temporal")]
+    pub zoned_date_times: Vec<ZonedDateTime<'static>>,
     pub declarative_environments: Vec<DeclarativeEnvironment<'static>>,
     pub e_2_10: Vec<(ElementIndex<'static>, u32)>,
     pub e_2_12: Vec<(ElementIndex<'static>, u32)>,
@@ -165,6 +228,8 @@ pub(crate) struct WorkQueues {
     pub global_environments: Vec<GlobalEnvironment<'static>>,
     pub maps: Vec<Map<'static>>,
     pub map_iterators: Vec<MapIterator<'static>>,
+    pub iterator_helpers: Vec<IteratorHelper<'static>>,
+    pub wrap_for_valid_iterators: Vec<WrapForValidIterator<'static>>,
     pub modules: Vec<Module<'static>>,
     pub numbers: Vec<HeapNumber<'static>>,
     pub object_environments: Vec<ObjectEnvironment<'static>>,
@@ -173,6 +238,7 @@ pub(crate) struct WorkQueues {
     pub promises: Vec<Promise<'static>>,
     pub promise_reaction_records: Vec<PromiseReaction<'static>>,
     pub promise_resolving_functions: Vec<BuiltinPromiseResolvingFunction<'static>>,
+    pub proxy_revoker_functions: Vec<BuiltinProxyRevokerFunction<'static>>,
     pub proxys: Vec<Proxy<'static>>,
     pub realms: Vec<Realm<'static>>,
     #[cfg(feature = "regexp")]
@@ -200,6 +266,7 @@ impl HeapBits {
     pub fn new(heap: &Heap) -> Self {
         #[cfg(feature = "array-buffer")]
         let array_buffers = vec![false; heap.array_buffers.len()];
+        let array_from_async_records = vec![false; heap.array_from_async_records.len()];
         let arrays = vec![false; heap.arrays.len()];
         let array_iterators = vec![false; heap.array_iterators.len()];
         let async_generators = vec![false; heap.async_generators.len()];
@@ -212,6 +279,26 @@ impl HeapBits {
         let data_views = vec![false; heap.data_views.len()];
         #[cfg(feature = "date")]
         let dates = vec![false; heap.dates.len()];
+        #[cfg(feature = "intl")]
+        let collators = vec![false; heap.collators.len()];
+        #[cfg(feature = "intl")]
+        let date_time_formats = vec![false; heap.date_time_formats.len()];
+        #[cfg(feature = "intl")]
+        let number_formats = vec![false; heap.number_formats.len()];
+        #[cfg(feature = "intl")]
+        let plural_rules = vec![false; heap.plural_rules.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let durations = vec![false; heap.durations.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let instants = vec![false; heap.instants.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let plain_dates = vec![false; heap.plain_dates.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let plain_date_times = vec![false; heap.plain_date_times.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let plain_times = vec![false; heap.plain_times.len()];
+        #[cfg(feature = "proposal-temporal")]
+        let zoned_date_times = vec![false; heap.zoned_date_times.len()];
         let declarative_environments = vec![false; heap.environments.declarative.len()];
         let e_2_10 = vec![(false, 0u16); heap.elements.e2pow10.values.len()];
         let e_2_12 = vec![(false, 0u16); heap.elements.e2pow12.values.len()];
@@ -232,6 +319,8 @@ impl HeapBits {
         let global_environments = vec![false; heap.environments.global.len()];
         let maps = vec![false; heap.maps.len()];
         let map_iterators = vec![false; heap.map_iterators.len()];
+        let iterator_helpers = vec![false; heap.iterator_helpers.len()];
+        let wrap_for_valid_iterators = vec![false; heap.wrap_for_valid_iterators.len()];
         let modules = vec![false; heap.modules.len()];
         let numbers = vec![false; heap.numbers.len()];
         let object_environments = vec![false; heap.environments.object.len()];
@@ -240,6 +329,7 @@ impl HeapBits {
         let promise_reaction_records = vec![false; heap.promise_reaction_records.len()];
         let promise_resolving_functions = vec![false; heap.promise_resolving_functions.len()];
         let promises = vec![false; heap.promises.len()];
+        let proxy_revoker_functions = vec![false; heap.proxy_revoker_functions.len()];
         let proxys = vec![false; heap.proxys.len()];
         let realms = vec![false; heap.realms.len()];
         #[cfg(feature = "regexp")]
@@ -264,6 +354,7 @@ impl HeapBits {
         Self {
             #[cfg(feature = "array-buffer")]
             array_buffers: array_buffers.into_boxed_slice(),
+            array_from_async_records: array_from_async_records.into_boxed_slice(),
             arrays: arrays.into_boxed_slice(),
             array_iterators: array_iterators.into_boxed_slice(),
             async_generators: async_generators.into_boxed_slice(),
@@ -276,6 +367,26 @@ impl HeapBits {
             data_views: data_views.into_boxed_slice(),
             #[cfg(feature = "date")]
             dates: dates.into_boxed_slice(),
+            #[cfg(feature = "intl")]
+            collators: collators.into_boxed_slice(),
+            #[cfg(feature = "intl")]
+            date_time_formats: date_time_formats.into_boxed_slice(),
+            #[cfg(feature = "intl")]
+            number_formats: number_formats.into_boxed_slice(),
+            #[cfg(feature = "intl")]
+            plural_rules: plural_rules.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            durations: durations.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            instants: instants.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates: plain_dates.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times: plain_date_times.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            plain_times: plain_times.into_boxed_slice(),
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times: zoned_date_times.into_boxed_slice(),
             declarative_environments: declarative_environments.into_boxed_slice(),
             e_2_10: e_2_10.into_boxed_slice(),
             e_2_12: e_2_12.into_boxed_slice(),
@@ -296,6 +407,8 @@ impl HeapBits {
             global_environments: global_environments.into_boxed_slice(),
             maps: maps.into_boxed_slice(),
             map_iterators: map_iterators.into_boxed_slice(),
+            iterator_helpers: iterator_helpers.into_boxed_slice(),
+            wrap_for_valid_iterators: wrap_for_valid_iterators.into_boxed_slice(),
             modules: modules.into_boxed_slice(),
             numbers: numbers.into_boxed_slice(),
             object_environments: object_environments.into_boxed_slice(),
@@ -304,6 +417,7 @@ impl HeapBits {
             promise_reaction_records: promise_reaction_records.into_boxed_slice(),
             promise_resolving_functions: promise_resolving_functions.into_boxed_slice(),
             promises: promises.into_boxed_slice(),
+            proxy_revoker_functions: proxy_revoker_functions.into_boxed_slice(),
             proxys: proxys.into_boxed_slice(),
             realms: realms.into_boxed_slice(),
             #[cfg(feature = "regexp")]
@@ -327,6 +441,114 @@ impl HeapBits {
             weak_sets: weak_sets.into_boxed_slice(),
         }
     }
+
+    /// Returns true if the given Value has been marked as reachable.
+    ///
+    /// Stack values are always considered reachable. This is used to
+    /// implement weakly held references, eg. ephemeron marking of WeakMap
+    /// entries.
+    pub(crate) fn is_marked(&self, value: Value) -> bool {
+        match value {
+            Value::Undefined
+            | Value::Null
+            | Value::Boolean(_)
+            | Value::SmallString(_)
+            | Value::Integer(_)
+            | Value::SmallF64(_)
+            | Value::SmallBigInt(_) => true,
+            Value::String(data) => self.strings[data.get_index()],
+            Value::Symbol(data) => self.symbols[data.get_index()],
+            Value::Number(data) => self.numbers[data.get_index()],
+            Value::BigInt(data) => self.bigints[data.get_index()],
+            Value::Object(data) => self.objects[data.get_index()],
+            Value::Array(data) => self.arrays[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Value::ArrayBuffer(data) => self.array_buffers[data.get_index()],
+            #[cfg(feature = "date")]
+            Value::Date(data) => self.dates[data.get_index()],
+            #[cfg(feature = "intl")]
+            Value::Collator(data) => self.collators[data.get_index()],
+            #[cfg(feature = "intl")]
+            Value::DateTimeFormat(data) => self.date_time_formats[data.get_index()],
+            #[cfg(feature = "intl")]
+            Value::NumberFormat(data) => self.number_formats[data.get_index()],
+            #[cfg(feature = "intl")]
+            Value::PluralRules(data) => self.plural_rules[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::Duration(data) => self.durations[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::Instant(data) => self.instants[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDate(data) => self.plain_dates[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainDateTime(data) => self.plain_date_times[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::PlainTime(data) => self.plain_times[data.get_index()],
+            #[cfg(feature = "proposal-temporal")]
+            Value::ZonedDateTime(data) => self.zoned_date_times[data.get_index()],
+            Value::Error(data) => self.errors[data.get_index()],
+            Value::BoundFunction(data) => self.bound_functions[data.get_index()],
+            Value::BuiltinFunction(data) => self.builtin_functions[data.get_index()],
+            Value::ECMAScriptFunction(data) => self.ecmascript_functions[data.get_index()],
+            #[cfg(feature = "regexp")]
+            Value::RegExp(data) => self.regexps[data.get_index()],
+            Value::PrimitiveObject(data) => self.primitive_objects[data.get_index()],
+            Value::Arguments(data) => self.objects[data.get_index()],
+            #[cfg(feature = "proposal-json-parse-with-source")]
+            Value::RawJSON(data) => self.objects[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Value::DataView(data) => self.data_views[data.get_index()],
+            Value::FinalizationRegistry(data) => self.finalization_registrys[data.get_index()],
+            Value::Map(data) => self.maps[data.get_index()],
+            Value::Proxy(data) => self.proxys[data.get_index()],
+            Value::Promise(data) => self.promises[data.get_index()],
+            #[cfg(feature = "set")]
+            Value::Set(data) => self.sets[data.get_index()],
+            #[cfg(feature = "shared-array-buffer")]
+            Value::SharedArrayBuffer(data) => self.shared_array_buffers[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Value::WeakMap(data) => self.weak_maps[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Value::WeakRef(data) => self.weak_refs[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Value::WeakSet(data) => self.weak_sets[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Value::Int8Array(data)
+            | Value::Uint8Array(data)
+            | Value::Uint8ClampedArray(data)
+            | Value::Int16Array(data)
+            | Value::Uint16Array(data)
+            | Value::Int32Array(data)
+            | Value::Uint32Array(data)
+            | Value::BigInt64Array(data)
+            | Value::BigUint64Array(data)
+            | Value::Float32Array(data)
+            | Value::Float64Array(data) => self.typed_arrays[data.into_index()],
+            #[cfg(feature = "proposal-float16array")]
+            Value::Float16Array(data) => self.typed_arrays[data.into_index()],
+            Value::BuiltinConstructorFunction(data) => self.builtin_constructors[data.get_index()],
+            Value::BuiltinPromiseResolvingFunction(data) => {
+                self.promise_resolving_functions[data.get_index()]
+            }
+            Value::BuiltinProxyRevokerFunction(data) => {
+                self.proxy_revoker_functions[data.get_index()]
+            }
+            Value::AsyncGenerator(data) => self.async_generators[data.get_index()],
+            Value::ArrayIterator(data) => self.array_iterators[data.get_index()],
+            #[cfg(feature = "set")]
+            Value::SetIterator(data) => self.set_iterators[data.get_index()],
+            Value::MapIterator(data) => self.map_iterators[data.get_index()],
+            Value::IteratorHelper(data) => self.iterator_helpers[data.get_index()],
+            Value::WrapForValidIterator(data) => self.wrap_for_valid_iterators[data.get_index()],
+            Value::Generator(data) => self.generators[data.get_index()],
+            Value::Module(data) => self.modules[data.get_index()],
+            Value::EmbedderObject(data) => self.embedder_objects[data.get_index()],
+            Value::BuiltinGeneratorFunction
+            | Value::BuiltinPromiseCollectorFunction
+            | Value::AsyncFromSyncIterator
+            | Value::Iterator => true,
+        }
+    }
 }
 
 impl WorkQueues {
@@ -334,6 +556,7 @@ impl WorkQueues {
         Self {
             #[cfg(feature = "array-buffer")]
             array_buffers: Vec::with_capacity(heap.array_buffers.len() / 4),
+            array_from_async_records: Vec::with_capacity(heap.array_from_async_records.len() / 4),
             arrays: Vec::with_capacity(heap.arrays.len() / 4),
             array_iterators: Vec::with_capacity(heap.array_iterators.len() / 4),
             async_generators: Vec::with_capacity(heap.async_generators.len() / 4),
@@ -346,6 +569,26 @@ impl WorkQueues {
             data_views: Vec::with_capacity(heap.data_views.len() / 4),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(heap.dates.len() / 4),
+            #[cfg(feature = "intl")]
+            collators: Vec::with_capacity(heap.collators.len() / 4),
+            #[cfg(feature = "intl")]
+            date_time_formats: Vec::with_capacity(heap.date_time_formats.len() / 4),
+            #[cfg(feature = "intl")]
+            number_formats: Vec::with_capacity(heap.number_formats.len() / 4),
+            #[cfg(feature = "intl")]
+            plural_rules: Vec::with_capacity(heap.plural_rules.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            durations: Vec::with_capacity(heap.durations.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            instants: Vec::with_capacity(heap.instants.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates: Vec::with_capacity(heap.plain_dates.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times: Vec::with_capacity(heap.plain_date_times.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            plain_times: Vec::with_capacity(heap.plain_times.len() / 4),
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times: Vec::with_capacity(heap.zoned_date_times.len() / 4),
             declarative_environments: Vec::with_capacity(heap.environments.declarative.len() / 4),
             e_2_10: Vec::with_capacity(heap.elements.e2pow10.values.len() / 4),
             e_2_12: Vec::with_capacity(heap.elements.e2pow12.values.len() / 4),
@@ -366,6 +609,8 @@ impl WorkQueues {
             global_environments: Vec::with_capacity(heap.environments.global.len() / 4),
             maps: Vec::with_capacity(heap.maps.len() / 4),
             map_iterators: Vec::with_capacity(heap.map_iterators.len() / 4),
+            iterator_helpers: Vec::with_capacity(heap.iterator_helpers.len() / 4),
+            wrap_for_valid_iterators: Vec::with_capacity(heap.wrap_for_valid_iterators.len() / 4),
             modules: Vec::with_capacity(heap.modules.len() / 4),
             numbers: Vec::with_capacity(heap.numbers.len() / 4),
             object_environments: Vec::with_capacity(heap.environments.object.len() / 4),
@@ -376,6 +621,7 @@ impl WorkQueues {
                 heap.promise_resolving_functions.len() / 4,
             ),
             promises: Vec::with_capacity(heap.promises.len() / 4),
+            proxy_revoker_functions: Vec::with_capacity(heap.proxy_revoker_functions.len() / 4),
             proxys: Vec::with_capacity(heap.proxys.len() / 4),
             realms: Vec::with_capacity(heap.realms.len() / 4),
             #[cfg(feature = "regexp")]
@@ -404,6 +650,7 @@ impl WorkQueues {
         let Self {
             #[cfg(feature = "array-buffer")]
             array_buffers,
+            array_from_async_records,
             arrays,
             array_iterators,
             async_generators,
@@ -416,6 +663,26 @@ impl WorkQueues {
             data_views,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "intl")]
+            collators,
+            #[cfg(feature = "intl")]
+            date_time_formats,
+            #[cfg(feature = "intl")]
+            number_formats,
+            #[cfg(feature = "intl")]
+            plural_rules,
+            #[cfg(feature = "proposal-temporal")]
+            durations,
+            #[cfg(feature = "proposal-temporal")]
+            instants,
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates,
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times,
+            #[cfg(feature = "proposal-temporal")]
+            plain_times,
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times,
             declarative_environments,
             e_2_10,
             e_2_12,
@@ -436,6 +703,8 @@ impl WorkQueues {
             global_environments,
             maps,
             map_iterators,
+            iterator_helpers,
+            wrap_for_valid_iterators,
             modules,
             numbers,
             object_environments,
@@ -444,6 +713,7 @@ impl WorkQueues {
             promises,
             promise_reaction_records,
             promise_resolving_functions,
+            proxy_revoker_functions,
             proxys,
             realms,
             #[cfg(feature = "regexp")]
@@ -469,6 +739,26 @@ impl WorkQueues {
 
         #[cfg(not(feature = "date"))]
         let dates: &[bool; 0] = &[];
+        #[cfg(not(feature = "intl"))]
+        let collators: &[bool; 0] = &[];
+        #[cfg(not(feature = "intl"))]
+        let date_time_formats: &[bool; 0] = &[];
+        #[cfg(not(feature = "intl"))]
+        let number_formats: &[bool; 0] = &[];
+        #[cfg(not(feature = "intl"))]
+        let plural_rules: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let durations: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let instants: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let plain_dates: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let plain_date_times: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let plain_times: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-temporal"))]
+        let zoned_date_times: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
         let data_views: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
@@ -490,6 +780,7 @@ impl WorkQueues {
         #[cfg(not(feature = "set"))]
         let set_iterators: &[bool; 0] = &[];
         array_buffers.is_empty()
+            && array_from_async_records.is_empty()
             && arrays.is_empty()
             && array_iterators.is_empty()
             && async_generators.is_empty()
@@ -500,6 +791,16 @@ impl WorkQueues {
             && builtin_functions.is_empty()
             && data_views.is_empty()
             && dates.is_empty()
+            && collators.is_empty()
+            && date_time_formats.is_empty()
+            && number_formats.is_empty()
+            && plural_rules.is_empty()
+            && durations.is_empty()
+            && instants.is_empty()
+            && plain_dates.is_empty()
+            && plain_date_times.is_empty()
+            && plain_times.is_empty()
+            && zoned_date_times.is_empty()
             && declarative_environments.is_empty()
             && e_2_10.is_empty()
             && e_2_12.is_empty()
@@ -520,6 +821,8 @@ impl WorkQueues {
             && global_environments.is_empty()
             && maps.is_empty()
             && map_iterators.is_empty()
+            && iterator_helpers.is_empty()
+            && wrap_for_valid_iterators.is_empty()
             && modules.is_empty()
             && numbers.is_empty()
             && object_environments.is_empty()
@@ -528,6 +831,7 @@ impl WorkQueues {
             && promise_reaction_records.is_empty()
             && promise_resolving_functions.is_empty()
             && promises.is_empty()
+            && proxy_revoker_functions.is_empty()
             && proxys.is_empty()
             && realms.is_empty()
             && regexps.is_empty()
@@ -699,6 +1003,7 @@ impl Default for CompactionListBuilder {
 pub(crate) struct CompactionLists {
     #[cfg(feature = "array-buffer")]
     pub array_buffers: CompactionList,
+    pub array_from_async_records: CompactionList,
     pub arrays: CompactionList,
     pub array_iterators: CompactionList,
     pub async_generators: CompactionList,
@@ -711,6 +1016,26 @@ pub(crate) struct CompactionLists {
     pub data_views: CompactionList,
     #[cfg(feature = "date")]
     pub dates: CompactionList,
+    #[cfg(feature = "intl")]
+    pub collators: CompactionList,
+    #[cfg(feature = "intl")]
+    pub date_time_formats: CompactionList,
+    #[cfg(feature = "intl")]
+    pub number_formats: CompactionList,
+    #[cfg(feature = "intl")]
+    pub plural_rules: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub durations: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub instants: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_dates: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_date_times: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub plain_times: CompactionList,
+    #[cfg(feature = "proposal-temporal")]
+    pub zoned_date_times: CompactionList,
     pub declarative_environments: CompactionList,
     pub e_2_10: CompactionList,
     pub e_2_12: CompactionList,
@@ -731,6 +1056,8 @@ pub(crate) struct CompactionLists {
     pub global_environments: CompactionList,
     pub maps: CompactionList,
     pub map_iterators: CompactionList,
+    pub iterator_helpers: CompactionList,
+    pub wrap_for_valid_iterators: CompactionList,
     pub modules: CompactionList,
     pub numbers: CompactionList,
     pub object_environments: CompactionList,
@@ -739,6 +1066,7 @@ pub(crate) struct CompactionLists {
     pub promise_reaction_records: CompactionList,
     pub promise_resolving_functions: CompactionList,
     pub promises: CompactionList,
+    pub proxy_revoker_functions: CompactionList,
     pub proxys: CompactionList,
     pub realms: CompactionList,
     #[cfg(feature = "regexp")]
@@ -791,6 +1119,9 @@ impl CompactionLists {
             e_2_16: CompactionList::from_mark_u16s(&bits.e_2_16),
             e_2_24: CompactionList::from_mark_u32s(&bits.e_2_24),
             e_2_32: CompactionList::from_mark_u32s(&bits.e_2_32),
+            array_from_async_records: CompactionList::from_mark_bits(
+                &bits.array_from_async_records,
+            ),
             arrays: CompactionList::from_mark_bits(&bits.arrays),
             #[cfg(feature = "array-buffer")]
             array_buffers: CompactionList::from_mark_bits(&bits.array_buffers),
@@ -807,10 +1138,34 @@ impl CompactionLists {
             source_codes: CompactionList::from_mark_bits(&bits.source_codes),
             #[cfg(feature = "date")]
             dates: CompactionList::from_mark_bits(&bits.dates),
+            #[cfg(feature = "intl")]
+            collators: CompactionList::from_mark_bits(&bits.collators),
+            #[cfg(feature = "intl")]
+            date_time_formats: CompactionList::from_mark_bits(&bits.date_time_formats),
+            #[cfg(feature = "intl")]
+            number_formats: CompactionList::from_mark_bits(&bits.number_formats),
+            #[cfg(feature = "intl")]
+            plural_rules: CompactionList::from_mark_bits(&bits.plural_rules),
+            #[cfg(feature = "proposal-temporal")]
+            durations: CompactionList::from_mark_bits(&bits.durations),
+            #[cfg(feature = "proposal-temporal")]
+            instants: CompactionList::from_mark_bits(&bits.instants),
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates: CompactionList::from_mark_bits(&bits.plain_dates),
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times: CompactionList::from_mark_bits(&bits.plain_date_times),
+            #[cfg(feature = "proposal-temporal")]
+            plain_times: CompactionList::from_mark_bits(&bits.plain_times),
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times: CompactionList::from_mark_bits(&bits.zoned_date_times),
             errors: CompactionList::from_mark_bits(&bits.errors),
             executables: CompactionList::from_mark_bits(&bits.executables),
             maps: CompactionList::from_mark_bits(&bits.maps),
             map_iterators: CompactionList::from_mark_bits(&bits.map_iterators),
+            iterator_helpers: CompactionList::from_mark_bits(&bits.iterator_helpers),
+            wrap_for_valid_iterators: CompactionList::from_mark_bits(
+                &bits.wrap_for_valid_iterators,
+            ),
             numbers: CompactionList::from_mark_bits(&bits.numbers),
             objects: CompactionList::from_mark_bits(&bits.objects),
             promise_reaction_records: CompactionList::from_mark_bits(
@@ -834,6 +1189,7 @@ impl CompactionLists {
             #[cfg(feature = "array-buffer")]
             data_views: CompactionList::from_mark_bits(&bits.data_views),
             finalization_registrys: CompactionList::from_mark_bits(&bits.finalization_registrys),
+            proxy_revoker_functions: CompactionList::from_mark_bits(&bits.proxy_revoker_functions),
             proxys: CompactionList::from_mark_bits(&bits.proxys),
             #[cfg(feature = "weak-refs")]
             weak_maps: CompactionList::from_mark_bits(&bits.weak_maps),
@@ -87,6 +87,8 @@ pub(crate) enum IntrinsicObjectIndexes {
 
     // Control abstraction objects
     IteratorPrototype,
+    IteratorHelperPrototype,
+    WrapForValidIteratorPrototype,
     ArrayIteratorPrototype,
     // For-In Iterator objects are never directly accessible to ECMAScript code
     // ForInIteratorPrototype,
@@ -109,6 +111,36 @@ pub(crate) enum IntrinsicObjectIndexes {
     // Reflection
     ReflectObject,
 
+    // Internationalization
+    #[cfg(feature = "intl")]
+    IntlObject,
+    #[cfg(feature = "intl")]
+    CollatorPrototype,
+    #[cfg(feature = "intl")]
+    DateTimeFormatPrototype,
+    #[cfg(feature = "intl")]
+    NumberFormatPrototype,
+    #[cfg(feature = "intl")]
+    PluralRulesPrototype,
+
+    // Temporal
+    #[cfg(feature = "proposal-temporal")]
+    TemporalObject,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalNowObject,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalDurationPrototype,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalInstantPrototype,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDatePrototype,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDateTimePrototype,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainTimePrototype,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalZonedDateTimePrototype,
+
     // Errors subtypes
     AggregateErrorPrototype,
     EvalErrorPrototype,
@@ -218,6 +250,30 @@ pub(crate) enum IntrinsicConstructorIndexes {
     // Reflection
     Proxy,
 
+    // Internationalization
+    #[cfg(feature = "intl")]
+    Collator,
+    #[cfg(feature = "intl")]
+    DateTimeFormat,
+    #[cfg(feature = "intl")]
+    NumberFormat,
+    #[cfg(feature = "intl")]
+    PluralRules,
+
+    // Temporal
+    #[cfg(feature = "proposal-temporal")]
+    TemporalDuration,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalInstant,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDate,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainDateTime,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalPlainTime,
+    #[cfg(feature = "proposal-temporal")]
+    TemporalZonedDateTime,
+
     // Errors subtypes
     AggregateError,
     EvalError,
@@ -258,6 +314,8 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ParseInt,
     #[cfg(feature = "regexp")]
     RegExpPrototypeExec,
+    SetIteratorPrototypeNext,
+    SetPrototypeHas,
     SetPrototypeValues,
     StringPrototypeTrimEnd,
     StringPrototypeTrimStart,
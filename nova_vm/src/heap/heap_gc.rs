@@ -19,10 +19,23 @@ use super::{
 use super::{heap_bits::sweep_side_table_values, indexes::TypedArrayIndex};
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::NumberFormat;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::PluralRules;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::Duration, instant::Instant, plain_date::PlainDate, plain_date_time::PlainDateTime,
+    plain_time::PlainTime, zoned_date_time::ZonedDateTime,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "set")]
@@ -37,6 +50,9 @@ use crate::{
             Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelper, wrap_for_valid_iterator::WrapForValidIterator,
+            },
             control_abstraction_objects::{
                 async_function_objects::await_reaction::AwaitReactionIdentifier,
                 generator_objects::Generator,
@@ -47,14 +63,19 @@ use crate::{
             },
             embedder_object::EmbedderObject,
             error::Error,
-            finalization_registry::FinalizationRegistry,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+            finalization_registry::{
+                FinalizationRegistry, enqueue_finalization_registry_cleanup_jobs,
+            },
+            indexed_collections::array_objects::{
+                array_from_async::ArrayFromAsync,
+                array_iterator_objects::array_iterator::ArrayIterator,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
             module::Module,
             primitive_objects::PrimitiveObject,
             promise::Promise,
-            proxy::Proxy,
+            proxy::{Proxy, revoker_function::BuiltinProxyRevokerFunction},
         },
         execution::{
             Agent, DeclarativeEnvironment, Environments, FunctionEnvironment, GlobalEnvironment,
@@ -79,10 +100,18 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
         stack_refs,
         stack_ref_collections,
         vm_stack,
+        vm_frames,
+        remaining_execution_budget: _,
+        interrupt_requested: _,
+        termination: _,
+        native_stack_base: _,
+        heap_limit: _,
         options: _,
         symbol_id: _,
-        global_symbol_registry: _,
+        global_symbol_registry,
         host_hooks: _,
+        #[cfg(feature = "date")]
+            system_time_zone: _,
     } = agent;
     let mut bits = HeapBits::new(heap);
     let mut queues = WorkQueues::new(heap);
@@ -96,6 +125,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
     execution_context_stack.iter().for_each(|ctx| {
         ctx.mark_values(&mut queues);
     });
+    global_symbol_registry.mark_values(&mut queues);
     stack_refs
         .borrow()
         .iter()
@@ -107,6 +137,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
     vm_stack.iter().for_each(|vm_ptr| {
         unsafe { vm_ptr.as_ref() }.mark_values(&mut queues);
     });
+    vm_frames
+        .iter()
+        .for_each(|frame| frame.mark_values(&mut queues));
     let mut last_filled_global_value = None;
     heap.globals
         .borrow()
@@ -150,6 +183,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             array_buffers,
             #[cfg(feature = "array-buffer")]
                 array_buffer_detach_keys: _,
+            array_from_async_records,
             arrays,
             array_iterators,
             async_generators,
@@ -166,6 +200,26 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 data_view_byte_offsets: _,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "intl")]
+            collators,
+            #[cfg(feature = "intl")]
+            date_time_formats,
+            #[cfg(feature = "intl")]
+            number_formats,
+            #[cfg(feature = "intl")]
+            plural_rules,
+            #[cfg(feature = "proposal-temporal")]
+            durations,
+            #[cfg(feature = "proposal-temporal")]
+            instants,
+            #[cfg(feature = "proposal-temporal")]
+            plain_dates,
+            #[cfg(feature = "proposal-temporal")]
+            plain_date_times,
+            #[cfg(feature = "proposal-temporal")]
+            plain_times,
+            #[cfg(feature = "proposal-temporal")]
+            zoned_date_times,
             ecmascript_functions,
             elements,
             embedder_objects,
@@ -178,6 +232,8 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             globals: _,
             maps,
             map_iterators,
+            iterator_helpers,
+            wrap_for_valid_iterators,
             modules,
             numbers,
             objects,
@@ -185,6 +241,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             promise_reaction_records,
             promise_resolving_functions,
             promises,
+            proxy_revoker_functions,
             proxys,
             realms,
             #[cfg(feature = "regexp")]
@@ -199,6 +256,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             strings,
             string_lookup_table: _,
             string_hasher: _,
+            external_bytes: _,
             symbols,
             #[cfg(feature = "array-buffer")]
             typed_arrays,
@@ -337,6 +395,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             }
         });
 
+        let mut array_from_async_marks: Box<[ArrayFromAsync]> =
+            queues.array_from_async_records.drain(..).collect();
+        array_from_async_marks.sort();
+        array_from_async_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.array_from_async_records.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                array_from_async_records.get(index).mark_values(&mut queues);
+            }
+        });
         let mut array_marks: Box<[Array]> = queues.arrays.drain(..).collect();
         array_marks.sort();
         array_marks.iter().for_each(|&idx| {
@@ -549,6 +621,171 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
             });
         }
+        #[cfg(feature = "intl")]
+        {
+            let mut collator_marks: Box<[Collator]> = queues.collators.drain(..).collect();
+            collator_marks.sort();
+            collator_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.collators.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    collators.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "intl")]
+        {
+            let mut date_time_format_marks: Box<[DateTimeFormat]> =
+                queues.date_time_formats.drain(..).collect();
+            date_time_format_marks.sort();
+            date_time_format_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.date_time_formats.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    date_time_formats.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "intl")]
+        {
+            let mut number_format_marks: Box<[NumberFormat]> =
+                queues.number_formats.drain(..).collect();
+            number_format_marks.sort();
+            number_format_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.number_formats.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    number_formats.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "intl")]
+        {
+            let mut plural_rules_marks: Box<[PluralRules]> =
+                queues.plural_rules.drain(..).collect();
+            plural_rules_marks.sort();
+            plural_rules_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.plural_rules.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    plural_rules.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut duration_marks: Box<[Duration]> = queues.durations.drain(..).collect();
+            duration_marks.sort();
+            duration_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.durations.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    durations.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut instant_marks: Box<[Instant]> = queues.instants.drain(..).collect();
+            instant_marks.sort();
+            instant_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.instants.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    instants.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut plain_date_marks: Box<[PlainDate]> = queues.plain_dates.drain(..).collect();
+            plain_date_marks.sort();
+            plain_date_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.plain_dates.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    plain_dates.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut plain_date_time_marks: Box<[PlainDateTime]> =
+                queues.plain_date_times.drain(..).collect();
+            plain_date_time_marks.sort();
+            plain_date_time_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.plain_date_times.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    plain_date_times.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut plain_time_marks: Box<[PlainTime]> = queues.plain_times.drain(..).collect();
+            plain_time_marks.sort();
+            plain_time_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.plain_times.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    plain_times.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        {
+            let mut zoned_date_time_marks: Box<[ZonedDateTime]> =
+                queues.zoned_date_times.drain(..).collect();
+            zoned_date_time_marks.sort();
+            zoned_date_time_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.zoned_date_times.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    zoned_date_times.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         let mut embedder_object_marks: Box<[EmbedderObject]> =
             queues.embedder_objects.drain(..).collect();
         embedder_object_marks.sort();
@@ -646,6 +883,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                     .mark_values(&mut queues);
             }
         });
+        let mut proxy_revoker_function_marks: Box<[BuiltinProxyRevokerFunction]> =
+            queues.proxy_revoker_functions.drain(..).collect();
+        proxy_revoker_function_marks.sort();
+        proxy_revoker_function_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.proxy_revoker_functions.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                proxy_revoker_functions.get(index).mark_values(&mut queues);
+            }
+        });
         let mut proxy_marks: Box<[Proxy]> = queues.proxys.drain(..).collect();
         proxy_marks.sort();
         proxy_marks.iter().for_each(|&idx| {
@@ -685,6 +936,34 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 map_iterators.get(index).mark_values(&mut queues);
             }
         });
+        let mut iterator_helper_marks: Box<[IteratorHelper]> =
+            queues.iterator_helpers.drain(..).collect();
+        iterator_helper_marks.sort();
+        iterator_helper_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.iterator_helpers.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                iterator_helpers.get(index).mark_values(&mut queues);
+            }
+        });
+        let mut wrap_for_valid_iterator_marks: Box<[WrapForValidIterator]> =
+            queues.wrap_for_valid_iterators.drain(..).collect();
+        wrap_for_valid_iterator_marks.sort();
+        wrap_for_valid_iterator_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.wrap_for_valid_iterators.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                wrap_for_valid_iterators.get(index).mark_values(&mut queues);
+            }
+        });
         let mut number_marks: Box<[HeapNumber]> = queues.numbers.drain(..).collect();
         number_marks.sort();
         number_marks.iter().for_each(|&idx| {
@@ -1030,9 +1309,30 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
             }
         });
+
+        #[cfg(feature = "weak-refs")]
+        if queues.is_empty() {
+            // Ephemeron marking: WeakMap values are only reachable through
+            // the map if both the map and the entry's key are reachable. Once
+            // all strongly reachable values have been marked, mark the values
+            // of entries with marked keys. This may make new keys reachable,
+            // so we keep looping until no new values get marked.
+            weak_maps
+                .iter()
+                .zip(bits.weak_maps.iter())
+                .filter(|(_, marked)| **marked)
+                .for_each(|(weak_map, _)| {
+                    if let Some(weak_map) = weak_map {
+                        weak_map.mark_ephemeron_values(&bits, &mut queues);
+                    }
+                });
+        }
     }
 
     sweep(agent, &bits, root_realms, gc);
+    agent.heap.recount_external_bytes();
+
+    enqueue_finalization_registry_cleanup_jobs(agent);
 }
 
 fn sweep(
@@ -1041,6 +1341,55 @@ fn sweep(
     root_realms: &mut [Option<Realm<'static>>],
     _: GcScope,
 ) {
+    // Take the Rust closures of built-in functions that are about to be
+    // collected: they are dropped on this thread after sweeping.
+    let _collected_closures = agent
+        .heap
+        .builtin_functions
+        .iter_mut()
+        .zip(bits.builtin_functions.iter())
+        .filter(|(_, marked)| !**marked)
+        .filter_map(|(builtin_function, _)| builtin_function.as_mut()?.closure.take())
+        .collect::<Vec<_>>();
+    // Clear the targets of FinalizationRegistry cells that are about to be
+    // collected.
+    agent
+        .heap
+        .finalization_registrys
+        .iter_mut()
+        .zip(bits.finalization_registrys.iter())
+        .filter(|(_, marked)| **marked)
+        .for_each(|(finalization_registry, _)| {
+            if let Some(finalization_registry) = finalization_registry {
+                finalization_registry.clear_dead_cells(bits);
+            }
+        });
+    #[cfg(feature = "weak-refs")]
+    {
+        // Remove entries of weakly held keys that are about to be collected.
+        agent
+            .heap
+            .weak_maps
+            .iter_mut()
+            .zip(bits.weak_maps.iter())
+            .filter(|(_, marked)| **marked)
+            .for_each(|(weak_map, _)| {
+                if let Some(weak_map) = weak_map {
+                    weak_map.remove_dead_entries(bits);
+                }
+            });
+        agent
+            .heap
+            .weak_sets
+            .iter_mut()
+            .zip(bits.weak_sets.iter())
+            .filter(|(_, marked)| **marked)
+            .for_each(|(weak_set, _)| {
+                if let Some(weak_set) = weak_set {
+                    weak_set.remove_dead_entries(bits);
+                }
+            });
+    }
     let compactions = CompactionLists::create_from_bits(bits);
 
     for realm in root_realms {
@@ -1053,10 +1402,18 @@ fn sweep(
         stack_refs,
         stack_ref_collections,
         vm_stack,
+        vm_frames,
+        remaining_execution_budget: _,
+        interrupt_requested: _,
+        termination: _,
+        native_stack_base: _,
+        heap_limit: _,
         options: _,
         symbol_id: _,
-        global_symbol_registry: _,
+        global_symbol_registry,
         host_hooks: _,
+        #[cfg(feature = "date")]
+            system_time_zone: _,
     } = agent;
 
     let Heap {
@@ -1064,6 +1421,7 @@ fn sweep(
         array_buffers,
         #[cfg(feature = "array-buffer")]
         array_buffer_detach_keys,
+        array_from_async_records,
         arrays,
         array_iterators,
         async_generators,
@@ -1080,6 +1438,26 @@ fn sweep(
         data_view_byte_offsets,
         #[cfg(feature = "date")]
         dates,
+        #[cfg(feature = "intl")]
+        collators,
+        #[cfg(feature = "intl")]
+        date_time_formats,
+        #[cfg(feature = "intl")]
+        number_formats,
+        #[cfg(feature = "intl")]
+        plural_rules,
+        #[cfg(feature = "proposal-temporal")]
+        durations,
+        #[cfg(feature = "proposal-temporal")]
+        instants,
+        #[cfg(feature = "proposal-temporal")]
+        plain_dates,
+        #[cfg(feature = "proposal-temporal")]
+        plain_date_times,
+        #[cfg(feature = "proposal-temporal")]
+        plain_times,
+        #[cfg(feature = "proposal-temporal")]
+        zoned_date_times,
         ecmascript_functions,
         elements,
         embedder_objects,
@@ -1092,6 +1470,8 @@ fn sweep(
         globals,
         maps,
         map_iterators,
+        iterator_helpers,
+        wrap_for_valid_iterators,
         modules,
         numbers,
         objects,
@@ -1099,6 +1479,7 @@ fn sweep(
         promise_reaction_records,
         promise_resolving_functions,
         promises,
+        proxy_revoker_functions,
         proxys,
         realms,
         #[cfg(feature = "regexp")]
@@ -1113,6 +1494,7 @@ fn sweep(
         strings,
         string_lookup_table,
         string_hasher: _,
+        external_bytes: _,
         symbols,
         #[cfg(feature = "array-buffer")]
         typed_arrays,
@@ -1280,6 +1662,15 @@ fn sweep(
                 );
             });
         }
+        if !array_from_async_records.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    array_from_async_records,
+                    &compactions,
+                    &bits.array_from_async_records,
+                );
+            });
+        }
         if !arrays.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(arrays, &compactions, &bits.arrays);
@@ -1346,6 +1737,66 @@ fn sweep(
                 sweep_heap_vector_values(dates, &compactions, &bits.dates);
             });
         }
+        #[cfg(feature = "intl")]
+        if !collators.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(collators, &compactions, &bits.collators);
+            });
+        }
+        #[cfg(feature = "intl")]
+        if !date_time_formats.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(date_time_formats, &compactions, &bits.date_time_formats);
+            });
+        }
+        #[cfg(feature = "intl")]
+        if !number_formats.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(number_formats, &compactions, &bits.number_formats);
+            });
+        }
+        #[cfg(feature = "intl")]
+        if !plural_rules.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(plural_rules, &compactions, &bits.plural_rules);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !durations.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(durations, &compactions, &bits.durations);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !instants.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(instants, &compactions, &bits.instants);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !plain_dates.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(plain_dates, &compactions, &bits.plain_dates);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !plain_date_times.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(plain_date_times, &compactions, &bits.plain_date_times);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !plain_times.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(plain_times, &compactions, &bits.plain_times);
+            });
+        }
+        #[cfg(feature = "proposal-temporal")]
+        if !zoned_date_times.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(zoned_date_times, &compactions, &bits.zoned_date_times);
+            });
+        }
         if !declarative.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(declarative, &compactions, &bits.declarative_environments);
@@ -1361,9 +1812,9 @@ fn sweep(
             });
         }
         if !embedder_objects.is_empty() {
-            s.spawn(|| {
-                sweep_heap_vector_values(embedder_objects, &compactions, &bits.embedder_objects);
-            });
+            // NOTE: Host-defined data of embedder objects may only be accessed
+            // and dropped on this thread.
+            sweep_heap_vector_values(embedder_objects, &compactions, &bits.embedder_objects);
         }
         if !errors.is_empty() {
             s.spawn(|| {
@@ -1409,6 +1860,20 @@ fn sweep(
                 sweep_heap_vector_values(map_iterators, &compactions, &bits.map_iterators);
             });
         }
+        if !iterator_helpers.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(iterator_helpers, &compactions, &bits.iterator_helpers);
+            });
+        }
+        if !wrap_for_valid_iterators.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    wrap_for_valid_iterators,
+                    &compactions,
+                    &bits.wrap_for_valid_iterators,
+                );
+            });
+        }
         if !modules.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(modules, &compactions, &bits.modules);
@@ -1457,6 +1922,15 @@ fn sweep(
                 sweep_heap_vector_values(promises, &compactions, &bits.promises);
             });
         }
+        if !proxy_revoker_functions.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    proxy_revoker_functions,
+                    &compactions,
+                    &bits.proxy_revoker_functions,
+                );
+            });
+        }
         if !proxys.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(proxys, &compactions, &bits.proxys);
@@ -1562,6 +2036,7 @@ fn sweep(
                     .for_each(|entry| entry.sweep_values(&compactions));
             });
         }
+        global_symbol_registry.sweep_values(&compactions);
         if !stack_refs.borrow().is_empty() {
             stack_refs
                 .borrow_mut()
@@ -1579,6 +2054,9 @@ fn sweep(
                 .iter_mut()
                 .for_each(|entry| unsafe { entry.as_mut().sweep_values(&compactions) });
         }
+        vm_frames
+            .iter_mut()
+            .for_each(|frame| frame.sweep_values(&compactions));
     });
 }
 
@@ -4,10 +4,24 @@
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::collator::data::CollatorHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::date_time_format::data::DateTimeFormatHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::number_format::data::NumberFormatHeapData;
+#[cfg(feature = "intl")]
+use crate::ecmascript::builtins::intl::plural_rules::data::PluralRulesHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "proposal-temporal")]
+use crate::ecmascript::builtins::temporal::{
+    duration::data::DurationHeapData, instant::data::InstantHeapData,
+    plain_date::data::PlainDateHeapData, plain_date_time::data::PlainDateTimeHeapData,
+    plain_time::data::PlainTimeHeapData, zoned_date_time::data::ZonedDateTimeHeapData,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{
     ArrayBufferHeapData, data_view::data::DataViewHeapData, typed_array::data::TypedArrayHeapData,
@@ -25,14 +39,22 @@ use crate::ecmascript::builtins::{
 use crate::{
     ecmascript::{
         builtins::{
-            ArrayHeapData, async_generator_objects::AsyncGeneratorHeapData,
+            ArrayHeapData,
+            async_generator_objects::AsyncGeneratorHeapData,
             control_abstraction_objects::generator_objects::GeneratorHeapData,
-            embedder_object::data::EmbedderObjectHeapData, error::ErrorHeapData,
+            control_abstraction_objects::iteration::{
+                iterator_helper::IteratorHelperHeapData,
+                wrap_for_valid_iterator::WrapForValidIteratorHeapData,
+            },
+            embedder_object::data::EmbedderObjectHeapData,
+            error::ErrorHeapData,
             finalization_registry::data::FinalizationRegistryHeapData,
             indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
-            map::data::MapHeapData, primitive_objects::PrimitiveObjectHeapData,
-            promise::data::PromiseHeapData, proxy::data::ProxyHeapData,
+            map::data::MapHeapData,
+            primitive_objects::PrimitiveObjectHeapData,
+            promise::data::PromiseHeapData,
+            proxy::data::ProxyHeapData,
         },
         types::{
             BigIntHeapData, BoundFunctionHeapData, BuiltinConstructorHeapData,
@@ -200,14 +222,36 @@ pub type BuiltinConstructorIndex<'a> = BaseIndex<'a, BuiltinConstructorHeapData<
 pub type DataViewIndex<'a> = BaseIndex<'a, DataViewHeapData<'static>>;
 #[cfg(feature = "date")]
 pub type DateIndex<'a> = BaseIndex<'a, DateHeapData<'static>>;
+#[cfg(feature = "intl")]
+pub type CollatorIndex<'a> = BaseIndex<'a, CollatorHeapData<'static>>;
+#[cfg(feature = "intl")]
+pub type DateTimeFormatIndex<'a> = BaseIndex<'a, DateTimeFormatHeapData<'static>>;
+#[cfg(feature = "intl")]
+pub type NumberFormatIndex<'a> = BaseIndex<'a, NumberFormatHeapData<'static>>;
+#[cfg(feature = "intl")]
+pub type PluralRulesIndex<'a> = BaseIndex<'a, PluralRulesHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type DurationIndex<'a> = BaseIndex<'a, DurationHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type InstantIndex<'a> = BaseIndex<'a, InstantHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type PlainDateIndex<'a> = BaseIndex<'a, PlainDateHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type PlainDateTimeIndex<'a> = BaseIndex<'a, PlainDateTimeHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type PlainTimeIndex<'a> = BaseIndex<'a, PlainTimeHeapData<'static>>;
+#[cfg(feature = "proposal-temporal")]
+pub type ZonedDateTimeIndex<'a> = BaseIndex<'a, ZonedDateTimeHeapData<'static>>;
 pub type ECMAScriptFunctionIndex<'a> = BaseIndex<'a, ECMAScriptFunctionHeapData<'static>>;
 pub type ElementIndex<'a> = BaseIndex<'a, [Option<Value<'static>>]>;
-pub type EmbedderObjectIndex<'a> = BaseIndex<'a, EmbedderObjectHeapData>;
+pub type EmbedderObjectIndex<'a> = BaseIndex<'a, EmbedderObjectHeapData<'static>>;
 pub type ErrorIndex<'a> = BaseIndex<'a, ErrorHeapData<'static>>;
 pub type FinalizationRegistryIndex<'a> = BaseIndex<'a, FinalizationRegistryHeapData<'static>>;
 pub type GeneratorIndex<'a> = BaseIndex<'a, GeneratorHeapData<'static>>;
 pub type MapIndex<'a> = BaseIndex<'a, MapHeapData<'static>>;
 pub type MapIteratorIndex<'a> = BaseIndex<'a, MapIteratorHeapData<'static>>;
+pub type IteratorHelperIndex<'a> = BaseIndex<'a, IteratorHelperHeapData<'static>>;
+pub type WrapForValidIteratorIndex<'a> = BaseIndex<'a, WrapForValidIteratorHeapData<'static>>;
 pub type NumberIndex<'a> = BaseIndex<'a, NumberHeapData>;
 pub type ObjectIndex<'a> = BaseIndex<'a, ObjectHeapData<'static>>;
 pub type PrimitiveObjectIndex<'a> = BaseIndex<'a, PrimitiveObjectHeapData<'static>>;
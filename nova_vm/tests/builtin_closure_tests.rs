@@ -0,0 +1,99 @@
+use std::{cell::Cell, rc::Rc};
+
+use nova_vm::{
+    ecmascript::{
+        builtins::{BuiltinFunctionArgs, create_builtin_closure},
+        execution::{
+            DefaultHostHooks,
+            agent::{GcAgent, Options, RealmRoot},
+        },
+        types::{InternalMethods, IntoValue, PropertyDescriptor, PropertyKey, Value},
+    },
+    engine::context::Bindable,
+};
+
+mod common;
+use common::run;
+
+/// Define a global `increment` function that increments `counter` by its
+/// argument, or by `step` if no argument is given.
+fn define_increment(agent: &mut GcAgent, realm: &RealmRoot, counter: Rc<Cell<f64>>, step: f64) {
+    agent
+        .run_in_realm(realm, |agent, mut gc| {
+            let function = create_builtin_closure(
+                agent,
+                move |agent, _this, args, mut gc| {
+                    let amount = if args.get(0).is_undefined() {
+                        step
+                    } else {
+                        args.get(0).to_real(agent, gc.reborrow()).unbind()?
+                    };
+                    counter.set(counter.get() + amount);
+                    Ok(Value::from_f64(agent, counter.get(), gc.into_nogc()))
+                },
+                BuiltinFunctionArgs::new(1, "increment"),
+                gc.nogc(),
+            );
+            let global = agent.current_realm(gc.nogc()).global_object(agent).unbind();
+            let property_key = PropertyKey::from_static_str(agent, "increment", gc.nogc());
+            global
+                .internal_define_own_property(
+                    agent,
+                    property_key.unbind(),
+                    PropertyDescriptor {
+                        value: Some(function.into_value().unbind()),
+                        writable: Some(true),
+                        enumerable: Some(false),
+                        configurable: Some(true),
+                        ..Default::default()
+                    },
+                    gc.reborrow(),
+                )
+                .unwrap();
+        })
+        .unwrap();
+}
+
+#[test]
+fn builtin_closure_carries_rust_state() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let counter = Rc::new(Cell::new(0.0));
+    define_increment(&mut agent, &realm, counter.clone(), 2.0);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        if (increment() !== 2) throw new Error("Unexpected result");
+        if (increment(5) !== 7) throw new Error("Unexpected result");
+        if (increment.name !== "increment") throw new Error("Unexpected name");
+        if (increment.length !== 1) throw new Error("Unexpected length");
+        try {
+            new increment();
+            throw new Error("Closure should not be a constructor");
+        } catch (err) {
+            if (!(err instanceof TypeError)) throw err;
+        }
+        "#,
+    );
+    assert_eq!(counter.get(), 7.0);
+}
+
+#[test]
+fn builtin_closure_is_dropped_with_function() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let counter = Rc::new(Cell::new(0.0));
+    define_increment(&mut agent, &realm, counter.clone(), 1.0);
+    assert_eq!(Rc::strong_count(&counter), 2);
+    agent.gc();
+    assert_eq!(Rc::strong_count(&counter), 2);
+    run(
+        &mut agent,
+        &realm,
+        "increment(); delete globalThis.increment;",
+    );
+    agent.gc();
+    assert_eq!(Rc::strong_count(&counter), 1);
+    assert_eq!(counter.get(), 1.0);
+}
@@ -0,0 +1,83 @@
+use nova_vm::ecmascript::execution::{
+    DefaultHostHooks,
+    agent::{GcAgent, Options},
+};
+
+mod common;
+
+fn run(options: Options, source: &'static str) {
+    let mut agent = GcAgent::new(options, &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    common::run(&mut agent, &realm, source);
+}
+
+#[test]
+fn call_depth_limit_throws_range_error() {
+    run(
+        Options {
+            max_call_stack_depth: Some(50),
+            ..Default::default()
+        },
+        r#"
+        var depth = 0;
+        function recurse() {
+            depth++;
+            recurse();
+        }
+        try {
+            recurse();
+            throw new Error("Recursion did not throw");
+        } catch (err) {
+            if (!(err instanceof RangeError)) throw err;
+            if (err.message !== "Maximum call stack size exceeded") throw err;
+        }
+        if (depth === 0 || depth >= 50) throw new Error("Unexpected depth: " + depth);
+        "#,
+    );
+}
+
+#[test]
+fn native_stack_limit_throws_range_error() {
+    // Note: The call depth limit is lifted so that the default native stack
+    // limit is what stops the recursion, on a thread with the 2 MiB stack
+    // that spawned threads get by default.
+    let thread = std::thread::Builder::new()
+        .stack_size(2 * 1024 * 1024)
+        .spawn(|| {
+            run(
+                Options {
+                    max_call_stack_depth: Some(usize::MAX),
+                    ..Default::default()
+                },
+                r#"
+                function expectRangeError(f) {
+                    try {
+                        f();
+                    } catch (err) {
+                        if (err instanceof RangeError) return;
+                        throw err;
+                    }
+                    throw new Error("Expected a RangeError");
+                }
+
+                expectRangeError(function recurse() { recurse(); });
+                expectRangeError(() => {
+                    var getter = { get value() { return getter.value; } };
+                    getter.value;
+                });
+                expectRangeError(() => {
+                    var proxy = {};
+                    for (var i = 0; i < 100000; i++) proxy = new Proxy(proxy, {});
+                    proxy.value;
+                });
+                expectRangeError(() => {
+                    var nested = [];
+                    for (var i = 0; i < 100000; i++) nested = [nested];
+                    JSON.stringify(nested);
+                });
+                "#,
+            );
+        })
+        .unwrap();
+    thread.join().unwrap();
+}
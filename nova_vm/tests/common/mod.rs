@@ -0,0 +1,82 @@
+//! Helpers shared by the integration tests.
+
+// Not every test uses every helper.
+#![allow(dead_code)]
+
+use nova_vm::{
+    ecmascript::{
+        execution::{
+            Agent, DefaultHostHooks, JsResult,
+            agent::{GcAgent, Options, RealmRoot},
+        },
+        scripts_and_modules::script::{parse_script, script_evaluation},
+        types::{InternalMethods, PropertyDescriptor, PropertyKey, String, Value},
+    },
+    engine::context::{Bindable, GcScope},
+};
+
+/// Parse and evaluate `source` as a script in the current realm.
+pub fn evaluate<'gc>(
+    agent: &mut Agent,
+    source: &'static str,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let realm = agent.current_realm(gc.nogc());
+    let source_text = String::from_static_str(agent, source, gc.nogc());
+    let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+    script_evaluation(agent, script.unbind(), gc)
+}
+
+/// Evaluate `source` in `realm`, panicking if it throws.
+pub fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) {
+    agent
+        .run_in_realm(realm, |agent, mut gc| {
+            if let Err(err) = evaluate(agent, source, gc.reborrow()) {
+                panic!(
+                    "Script evaluation failed: {:?}",
+                    err.value().unbind().string_repr(agent, gc).as_str(agent)
+                )
+            }
+        })
+        .unwrap();
+}
+
+/// Define a writable, non-enumerable property `name` on the global object.
+pub fn set_global(agent: &mut Agent, name: &'static str, value: Value, gc: GcScope) {
+    let value = value.bind(gc.nogc());
+    let global = agent.current_realm(gc.nogc()).global_object(agent).unbind();
+    let property_key = PropertyKey::from_static_str(agent, name, gc.nogc());
+    global
+        .internal_define_own_property(
+            agent,
+            property_key.unbind(),
+            PropertyDescriptor {
+                value: Some(value.unbind()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc,
+        )
+        .unwrap();
+}
+
+/// Script defining `assertEq(actual, expected, message)`, which throws unless
+/// `Object.is(actual, expected)`.
+pub const ASSERT_EQ: &str = r#"
+    function assertEq(actual, expected, message) {
+        if (!Object.is(actual, expected)) {
+            throw new Error(message + ": expected " + expected + ", got " + actual);
+        }
+    }
+"#;
+
+/// Evaluate `source` in a new default realm where [`ASSERT_EQ`] has been run,
+/// panicking if it throws.
+pub fn run_with_assert(source: &'static str) {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    run(&mut agent, &realm, ASSERT_EQ);
+    run(&mut agent, &realm, source);
+}
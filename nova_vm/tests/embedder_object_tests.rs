@@ -0,0 +1,234 @@
+use std::{cell::Cell, rc::Rc};
+
+use nova_vm::{
+    ecmascript::{
+        builtins::{EmbedderClassBuilder, EmbedderData, EmbedderObject},
+        execution::{
+            Agent, DefaultHostHooks, JsResult,
+            agent::{ExceptionType, GcAgent, Options, RealmRoot},
+        },
+        types::{IntoValue, Value},
+    },
+    engine::{
+        context::{Bindable, NoGcScope},
+        rootable::Scopable,
+    },
+};
+
+mod common;
+use common::{run, set_global};
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl EmbedderData for Point {}
+
+fn this_point<'gc>(
+    agent: &mut Agent,
+    this: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, EmbedderObject<'gc>> {
+    match EmbedderObject::try_from(this.bind(gc)) {
+        Ok(point) if point.data::<Point>(agent).is_some() => Ok(point),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "this is not a Point",
+            gc,
+        )),
+    }
+}
+
+fn define_point_class(agent: &mut GcAgent, realm: &RealmRoot) {
+    agent
+        .run_in_realm(realm, |agent, mut gc| {
+            let class = EmbedderClassBuilder::new(
+                agent,
+                "Point",
+                2,
+                |agent, arguments, mut gc| {
+                    let y = arguments.get(1).scope(agent, gc.nogc());
+                    let x = arguments.get(0).to_real(agent, gc.reborrow()).unbind()?;
+                    let y = y.get(agent).to_real(agent, gc.reborrow()).unbind()?;
+                    Ok(Point { x, y })
+                },
+                gc.nogc(),
+            )
+            .with_method("norm", 0, |agent, this, _, gc| {
+                let gc = gc.into_nogc();
+                let point = this_point(agent, this, gc)?;
+                let Point { x, y } = *point.data::<Point>(agent).unwrap();
+                Ok(Value::from_f64(agent, x.hypot(y), gc))
+            })
+            .with_accessor(
+                "x",
+                |agent, this, _, gc| {
+                    let gc = gc.into_nogc();
+                    let point = this_point(agent, this, gc)?;
+                    let x = point.data::<Point>(agent).unwrap().x;
+                    Ok(Value::from_f64(agent, x, gc))
+                },
+                |agent, this, arguments, mut gc| {
+                    let point = this_point(agent, this, gc.nogc())
+                        .unbind()?
+                        .scope(agent, gc.nogc());
+                    let x = arguments.get(0).to_real(agent, gc.reborrow()).unbind()?;
+                    point.get(agent).data_mut::<Point>(agent).unwrap().x = x;
+                    Ok(Value::Undefined)
+                },
+            )
+            .with_getter("y", |agent, this, _, gc| {
+                let gc = gc.into_nogc();
+                let point = this_point(agent, this, gc)?;
+                let y = point.data::<Point>(agent).unwrap().y;
+                Ok(Value::from_f64(agent, y, gc))
+            })
+            .build();
+            set_global(agent, "Point", class.into_value().unbind(), gc.reborrow());
+        })
+        .unwrap();
+}
+
+#[test]
+fn embedder_class_instances_carry_host_data() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    define_point_class(&mut agent, &realm);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var point = new Point(3, 4);
+        if (!(point instanceof Point)) throw new Error("Not an instance");
+        if (point.constructor !== Point) throw new Error("Unexpected constructor");
+        if (point.norm() !== 5) throw new Error("Unexpected norm");
+        if (point.x !== 3 || point.y !== 4) throw new Error("Unexpected coordinates");
+        point.x = 6;
+        point.y = 0;
+        if (point.x !== 6 || point.y !== 4) throw new Error("Unexpected coordinates");
+        if (Object.prototype.toString.call(point) !== "[object Point]") {
+            throw new Error("Unexpected toStringTag");
+        }
+        point.label = "origin";
+        if (point.label !== "origin") throw new Error("Unexpected property");
+        if (Point.name !== "Point" || Point.length !== 2) throw new Error("Unexpected class");
+        var getter = Object.getOwnPropertyDescriptor(Point.prototype, "x").get;
+        if (getter.name !== "get x") throw new Error("Unexpected getter name");
+        try {
+            Point(1, 2);
+            throw new Error("Point should require new");
+        } catch (err) {
+            if (!(err instanceof TypeError)) throw err;
+        }
+        try {
+            Point.prototype.norm.call({});
+            throw new Error("norm should require a Point");
+        } catch (err) {
+            if (!(err instanceof TypeError)) throw err;
+        }
+        class Point3 extends Point {}
+        var point3 = new Point3(1, 0);
+        if (!(point3 instanceof Point3) || point3.norm() !== 1) throw new Error("Unexpected subclass");
+        "#,
+    );
+}
+
+struct Finalized(Rc<Cell<usize>>);
+
+impl EmbedderData for Finalized {}
+
+impl Drop for Finalized {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn embedder_data_is_dropped_with_object() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let drops = Rc::new(Cell::new(0));
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let object = EmbedderObject::new(agent, None, Finalized(drops.clone()), gc.nogc());
+            assert!(object.data::<Finalized>(agent).is_some());
+            assert!(object.data::<Point>(agent).is_none());
+            set_global(
+                agent,
+                "finalized",
+                object.into_value().unbind(),
+                gc.reborrow(),
+            );
+        })
+        .unwrap();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        if (Object.getPrototypeOf(finalized) !== Object.prototype) {
+            throw new Error("Unexpected prototype");
+        }
+        finalized.value = 1;
+        "#,
+    );
+    agent.gc();
+    assert_eq!(drops.get(), 0);
+    run(&mut agent, &realm, "delete globalThis.finalized;");
+    agent.gc();
+    assert_eq!(drops.get(), 1);
+}
+
+struct Holder(Value<'static>);
+
+impl EmbedderData for Holder {
+    fn mark_values(&self, mark: &mut dyn FnMut(Value<'static>)) {
+        mark(self.0);
+    }
+
+    fn sweep_values(&mut self, sweep: &mut dyn FnMut(&mut Value<'static>)) {
+        sweep(&mut self.0);
+    }
+}
+
+#[test]
+fn embedder_data_traces_values() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let class = EmbedderClassBuilder::new(
+                agent,
+                "Holder",
+                1,
+                |_, arguments, _| Ok(Holder(arguments.get(0).unbind())),
+                gc.nogc(),
+            )
+            .with_getter("value", |agent, this, _, gc| {
+                let holder = EmbedderObject::try_from(this).unwrap();
+                Ok(holder.data::<Holder>(agent).unwrap().0.bind(gc.into_nogc()))
+            })
+            .build();
+            set_global(agent, "Holder", class.into_value().unbind(), gc.reborrow());
+        })
+        .unwrap();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var garbage = [];
+        for (var i = 0; i < 100; i++) garbage.push({ i });
+        var holder = new Holder({ answer: 42 });
+        garbage = null;
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        if (holder.value.answer !== 42) throw new Error("Held value was not kept alive");
+        "#,
+    );
+}
@@ -0,0 +1,52 @@
+use nova_vm::ecmascript::execution::{
+    DefaultHostHooks,
+    agent::{ExecutionTerminated, GcAgent, Options, RealmRoot},
+};
+
+mod common;
+use common::evaluate;
+
+fn run(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &'static str,
+) -> Result<bool, ExecutionTerminated> {
+    agent.run_in_realm(realm, |agent, gc| evaluate(agent, source, gc).is_ok())
+}
+
+#[test]
+fn execution_budget_terminates_infinite_loop() {
+    let mut agent = GcAgent::new(
+        Options {
+            execution_budget: Some(10_000),
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    );
+    let realm = agent.create_default_realm();
+    // Termination cannot be caught by try-catch blocks.
+    let result = run(
+        &mut agent,
+        &realm,
+        "while (true) { try { while (true) {} } catch { continue; } }",
+    );
+    assert_eq!(result, Err(ExecutionTerminated::BudgetExhausted));
+    // The budget is reset for the next run.
+    assert_eq!(run(&mut agent, &realm, "1 + 1"), Ok(true));
+}
+
+#[test]
+fn interrupt_handle_terminates_execution() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let handle = agent.interrupt_handle();
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        handle.interrupt();
+    });
+    let result = run(&mut agent, &realm, "while (true) {}");
+    thread.join().unwrap();
+    assert_eq!(result, Err(ExecutionTerminated::Interrupted));
+    // The interrupt request is cleared after termination.
+    assert_eq!(run(&mut agent, &realm, "1 + 1"), Ok(true));
+}
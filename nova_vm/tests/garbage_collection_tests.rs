@@ -85,35 +85,204 @@ fn garbage_collection_tests() {
         create_global_this_value,
         Some(initialize_global_object),
     );
-    agent.run_in_realm(&realm, |agent, mut gc| {
-        let realm = agent.current_realm(gc.nogc());
-        let source_text = String::from_string(agent, header_contents, gc.nogc());
-        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
-        if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
-            panic!(
-                "Header evaluation failed: '{}' failed: {:?}",
-                d.display(),
-                err.value().unbind().string_repr(agent, gc).as_str(agent)
-            )
-        }
-    });
-    agent.gc();
-
-    for i in 0..2 {
-        agent.run_in_realm(&realm, |agent, mut gc| {
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
             let realm = agent.current_realm(gc.nogc());
-            let source_text = String::from_string(agent, call_contents.clone(), gc.nogc());
+            let source_text = String::from_string(agent, header_contents, gc.nogc());
             let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
             if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
-                println!("Error kind: {:?}", err.value());
                 panic!(
-                    "Loop index run {} '{}' failed: {:?}",
-                    i,
+                    "Header evaluation failed: '{}' failed: {:?}",
                     d.display(),
                     err.value().unbind().string_repr(agent, gc).as_str(agent)
                 )
             }
-        });
+        })
+        .unwrap();
+    agent.gc();
+
+    for i in 0..2 {
+        agent
+            .run_in_realm(&realm, |agent, mut gc| {
+                let realm = agent.current_realm(gc.nogc());
+                let source_text = String::from_string(agent, call_contents.clone(), gc.nogc());
+                let script =
+                    parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+                if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+                    println!("Error kind: {:?}", err.value());
+                    panic!(
+                        "Loop index run {} '{}' failed: {:?}",
+                        i,
+                        d.display(),
+                        err.value().unbind().string_repr(agent, gc).as_str(agent)
+                    )
+                }
+            })
+            .unwrap();
         agent.gc();
     }
 }
+
+#[test]
+#[cfg(feature = "weak-refs")]
+fn weak_collections_gc() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let create_global_object: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> = None;
+    let create_global_this_value: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> =
+        None;
+    let realm = agent.create_realm(
+        create_global_object,
+        create_global_this_value,
+        Some(initialize_global_object),
+    );
+    let run = |agent: &mut GcAgent, source: &'static str| {
+        agent
+            .run_in_realm(&realm, |agent, mut gc| {
+                let realm = agent.current_realm(gc.nogc());
+                let source_text = String::from_static_str(agent, source, gc.nogc());
+                let script =
+                    parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+                if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+                    panic!(
+                        "Script evaluation failed: {:?}",
+                        err.value().unbind().string_repr(agent, gc).as_str(agent)
+                    )
+                }
+            })
+            .unwrap();
+    };
+
+    run(
+        &mut agent,
+        r#"
+        var key = {};
+        var map = new WeakMap();
+        var set = new WeakSet([key]);
+        map.set(key, { value: 3 });
+        (function () {
+            // Entries with otherwise unreachable keys should be collected.
+            for (let i = 0; i < 10; i++) {
+                map.set({}, {});
+                set.add({});
+            }
+            // Values reachable only through their keys in a cycle.
+            var a = {};
+            var b = {};
+            map.set(a, b);
+            map.set(b, a);
+        })();
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        r#"
+        if (map.get(key).value !== 3) throw new Error("WeakMap value was collected");
+        if (!set.has(key)) throw new Error("WeakSet value was collected");
+        if (map.has({}) || set.has({})) throw new Error("Unexpected entry");
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        r#"
+        if (map.get(key).value !== 3) throw new Error("WeakMap value was collected");
+        key = undefined;
+        "#,
+    );
+    agent.gc();
+}
+
+#[test]
+fn finalization_registry_cleanup() {
+    use core::cell::RefCell;
+    use nova_vm::ecmascript::execution::agent::{HostHooks, Job};
+
+    #[derive(Default)]
+    struct TestHostHooks {
+        jobs: RefCell<Vec<Job>>,
+    }
+
+    impl core::fmt::Debug for TestHostHooks {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("TestHostHooks").finish()
+        }
+    }
+
+    impl HostHooks for TestHostHooks {
+        fn enqueue_promise_job(&self, job: Job) {
+            self.jobs.borrow_mut().push(job);
+        }
+
+        fn host_enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+            self.jobs.borrow_mut().push(job);
+        }
+    }
+
+    let host_hooks: &TestHostHooks = &*Box::leak(Box::default());
+    let mut agent = GcAgent::new(Options::default(), host_hooks);
+    let create_global_object: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> = None;
+    let create_global_this_value: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> =
+        None;
+    let realm = agent.create_realm(
+        create_global_object,
+        create_global_this_value,
+        Some(initialize_global_object),
+    );
+    let run = |agent: &mut GcAgent, source: &'static str| {
+        agent
+            .run_in_realm(&realm, |agent, mut gc| {
+                let realm = agent.current_realm(gc.nogc());
+                let source_text = String::from_static_str(agent, source, gc.nogc());
+                let script =
+                    parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+                if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+                    panic!(
+                        "Script evaluation failed: {:?}",
+                        err.value().unbind().string_repr(agent, gc).as_str(agent)
+                    )
+                }
+            })
+            .unwrap();
+    };
+
+    run(
+        &mut agent,
+        r#"
+        var cleanedUp = [];
+        var registry = new FinalizationRegistry((heldValue) => cleanedUp.push(heldValue));
+        var token = {};
+        var kept = {};
+        (function () {
+            registry.register({}, "collected");
+            registry.register({}, "unregistered", token);
+        })();
+        registry.register(kept, "kept");
+        if (!registry.unregister(token)) throw new Error("Cell was not unregistered");
+        "#,
+    );
+    agent.gc();
+    assert_eq!(host_hooks.jobs.borrow().len(), 1);
+    // A dropped cleanup job does not keep the registry from getting new ones.
+    drop(host_hooks.jobs.take());
+    agent.gc();
+    assert_eq!(host_hooks.jobs.borrow().len(), 1);
+    let jobs = host_hooks.jobs.take();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            for job in jobs {
+                job.run(agent, gc.reborrow()).unwrap();
+            }
+        })
+        .unwrap();
+    run(
+        &mut agent,
+        r#"
+        if (cleanedUp.length !== 1 || cleanedUp[0] !== "collected") {
+            throw new Error("Unexpected cleanup: " + cleanedUp);
+        }
+        "#,
+    );
+    agent.gc();
+    assert!(host_hooks.jobs.borrow().is_empty());
+}
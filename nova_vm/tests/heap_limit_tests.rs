@@ -0,0 +1,189 @@
+#![cfg(feature = "interleaved-gc")]
+
+use std::cell::{Cell, RefCell};
+
+use nova_vm::ecmascript::execution::{
+    DefaultHostHooks,
+    agent::{GcAgent, HostHooks, Job, Options, RealmRoot},
+};
+
+mod common;
+use common::run;
+
+const HEAP_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Create an Agent with the heap limit and a realm with an
+/// `expectRangeError` function.
+fn create_agent(host_hooks: &'static dyn HostHooks) -> (GcAgent, RealmRoot) {
+    let mut agent = GcAgent::new(
+        Options {
+            heap_limit: Some(HEAP_LIMIT),
+            ..Default::default()
+        },
+        host_hooks,
+    );
+    let realm = agent.create_default_realm();
+    run(&mut agent, &realm, EXPECT_RANGE_ERROR);
+    (agent, realm)
+}
+
+const EXPECT_RANGE_ERROR: &str = r#"
+function expectRangeError(f) {
+    try {
+        f();
+    } catch (err) {
+        if (err instanceof RangeError && err.message === "Out of memory") return;
+        throw err;
+    }
+    throw new Error("Expected a RangeError");
+}
+"#;
+
+#[test]
+fn heap_limit_throws_range_error() {
+    let (mut agent, realm) = create_agent(&DefaultHostHooks);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        expectRangeError(() => "x".repeat(32 * 1024 * 1024));
+        expectRangeError(() => "x".padEnd(32 * 1024 * 1024, "y"));
+        expectRangeError(() => new Array(4 * 1024 * 1024));
+        expectRangeError(() => {
+            var array = [];
+            array.length = 4 * 1024 * 1024;
+        });
+        expectRangeError(() => {
+            var array = [];
+            array[4 * 1024 * 1024] = 1;
+        });
+        // Execution continues normally after running out of memory.
+        var small = "x".repeat(1024);
+        if (small.length !== 1024) throw new Error("Unexpected length");
+        "#,
+    );
+}
+
+#[test]
+fn heap_limit_applies_to_small_allocations() {
+    let (mut agent, realm) = create_agent(&DefaultHostHooks);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        expectRangeError(() => {
+            var s = "x".repeat(1024 * 1024);
+            while (true) s = s + s;
+        });
+        "#,
+    );
+    // Garbage collection frees up the heap for further execution.
+    agent.gc();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        expectRangeError(() => {
+            var objects = [];
+            while (true) objects.push({});
+        });
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        expectRangeError(() => {
+            var map = new Map();
+            for (var i = 0; i < 3000000; i++) map.set(i, i);
+        });
+        expectRangeError(() => {
+            var weakMap = new WeakMap();
+            var keys = [];
+            for (var i = 0; i < 3000000; i++) {
+                var key = {};
+                keys.push(key);
+                weakMap.set(key, i);
+            }
+        });
+        "#,
+    );
+    agent.gc();
+    run(&mut agent, &realm, "var object = { value: [1, 2, 3] };");
+}
+
+#[test]
+fn heap_limit_collects_garbage_before_throwing() {
+    let (mut agent, realm) = create_agent(&DefaultHostHooks);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        for (var i = 0; i < 4096; i++) {
+            var garbage = "x".repeat(16 * 1024) + i;
+        }
+        "#,
+    );
+}
+
+#[cfg(feature = "array-buffer")]
+#[test]
+fn heap_limit_applies_to_array_buffers() {
+    let (mut agent, realm) = create_agent(&DefaultHostHooks);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        expectRangeError(() => new ArrayBuffer(32 * 1024 * 1024));
+        expectRangeError(() => {
+            var buffer = new ArrayBuffer(0, { maxByteLength: 32 * 1024 * 1024 });
+            buffer.resize(32 * 1024 * 1024);
+        });
+        if (new ArrayBuffer(1024).byteLength !== 1024) throw new Error("Unexpected length");
+        "#,
+    );
+}
+
+#[test]
+fn near_heap_limit_can_raise_limit() {
+    #[derive(Default)]
+    struct TestHostHooks {
+        jobs: RefCell<Vec<Job>>,
+        near_heap_limit_calls: Cell<usize>,
+    }
+
+    impl core::fmt::Debug for TestHostHooks {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("TestHostHooks").finish()
+        }
+    }
+
+    impl HostHooks for TestHostHooks {
+        fn enqueue_promise_job(&self, job: Job) {
+            self.jobs.borrow_mut().push(job);
+        }
+
+        fn host_enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+            self.jobs.borrow_mut().push(job);
+        }
+
+        fn near_heap_limit(&self, current_limit: usize, required: usize) -> usize {
+            self.near_heap_limit_calls
+                .set(self.near_heap_limit_calls.get() + 1);
+            current_limit.max(required)
+        }
+    }
+
+    let host_hooks: &TestHostHooks = &*Box::leak(Box::default());
+    let (mut agent, realm) = create_agent(host_hooks);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var large = "x".repeat(32 * 1024 * 1024);
+        if (large.length !== 32 * 1024 * 1024) throw new Error("Unexpected length");
+        "#,
+    );
+    assert!(host_hooks.near_heap_limit_calls.get() > 0);
+}
@@ -0,0 +1,212 @@
+#![cfg(feature = "serde")]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use nova_vm::{
+    ecmascript::execution::{
+        DefaultHostHooks,
+        agent::{GcAgent, Options},
+    },
+    engine::{
+        context::Bindable,
+        rootable::Scopable,
+        serde::{ValueDeserializer, from_value, to_value},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+mod common;
+use common::{evaluate, set_global};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: std::string::String,
+    servers: Vec<Server>,
+    retries: Option<u8>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Server {
+    host: std::string::String,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Fast,
+    Limited { rate: f64 },
+    Named(std::string::String),
+}
+
+#[test]
+fn serde_round_trip() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let config = Config {
+                name: "test".into(),
+                servers: vec![Server {
+                    host: "localhost".into(),
+                    port: 8080,
+                }],
+                retries: None,
+                mode: Mode::Limited { rate: 1.5 },
+            };
+            let value = to_value(agent, &config, gc.nogc()).unwrap();
+            set_global(agent, "config", value.unbind(), gc.reborrow());
+            let value = evaluate(
+                agent,
+                r#"
+                if (config.servers[0].port !== 8080) throw new Error("Unexpected port");
+                if (config.retries !== undefined) throw new Error("Unexpected retries");
+                if (config.mode.Limited.rate !== 1.5) throw new Error("Unexpected mode");
+                config.servers.push({ host: "example.com", port: 443 });
+                config.retries = 3;
+                config.mode = "Fast";
+                config
+                "#,
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let config: Config = from_value(agent, value, gc.reborrow()).unwrap();
+            assert_eq!(
+                config,
+                Config {
+                    name: "test".into(),
+                    servers: vec![
+                        Server {
+                            host: "localhost".into(),
+                            port: 8080,
+                        },
+                        Server {
+                            host: "example.com".into(),
+                            port: 443,
+                        },
+                    ],
+                    retries: Some(3),
+                    mode: Mode::Fast,
+                }
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+fn serde_deserializes_collections() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Collections {
+        map: BTreeMap<std::string::String, u32>,
+        set: BTreeSet<i32>,
+        bytes: Vec<u8>,
+        big: i128,
+        object: BTreeMap<u32, bool>,
+        named: Mode,
+    }
+
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let value = evaluate(
+                agent,
+                r#"
+                ({
+                    map: new Map([["a", 1], ["b", 2]]),
+                    set: new Set([3, -1, 2]),
+                    bytes: new Uint8Array([1, 2, 255]),
+                    big: 2n ** 100n,
+                    object: { 1: true, 2: false },
+                    named: { Named: "value" },
+                    ignored: "unknown keys are skipped",
+                })
+                "#,
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let collections: Collections = from_value(agent, value, gc.reborrow()).unwrap();
+            assert_eq!(
+                collections,
+                Collections {
+                    map: BTreeMap::from([("a".into(), 1), ("b".into(), 2)]),
+                    set: BTreeSet::from([-1, 2, 3]),
+                    bytes: vec![1, 2, 255],
+                    big: 1 << 100,
+                    object: BTreeMap::from([(1, true), (2, false)]),
+                    named: Mode::Named("value".into()),
+                }
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+fn serde_reports_error_paths() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let value = evaluate(
+                agent,
+                r#"({ name: "test", servers: [{ host: "localhost", port: "80" }], mode: "Fast" })"#,
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let error = from_value::<Config>(agent, value, gc.reborrow())
+                .unwrap_err()
+                .unbind();
+            let message = error.value().string_repr(agent, gc.reborrow());
+            assert_eq!(
+                message.as_str(agent),
+                "TypeError: invalid type: string \"80\", expected u16 at servers[0].port"
+            );
+
+            // Errors thrown by getters are rethrown as-is.
+            let value = evaluate(
+                agent,
+                r#"({ get name() { throw "getter error"; } })"#,
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let error = from_value::<Config>(agent, value, gc.reborrow())
+                .unwrap_err()
+                .unbind();
+            let message = error.value().string_repr(agent, gc.reborrow());
+            assert_eq!(message.as_str(agent), "getter error");
+        })
+        .unwrap();
+}
+
+#[test]
+fn serde_error_roots_thrown_value() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let error = agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let value = evaluate(
+                agent,
+                r#"({ get name() { throw new Error("getter error"); } })"#,
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind()
+            .scope(agent, gc.nogc());
+            Config::deserialize(ValueDeserializer::new(agent, value, gc)).unwrap_err()
+        })
+        .unwrap();
+    assert_eq!(error.to_string(), "JavaScript error thrown at name");
+    // The thrown Error object survives garbage collection.
+    agent.gc();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let error = error.into_js_error(agent, gc.nogc()).unbind();
+            let message = error.value().string_repr(agent, gc.reborrow());
+            assert_eq!(message.as_str(agent), "Error: getter error");
+        })
+        .unwrap();
+}
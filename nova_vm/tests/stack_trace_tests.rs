@@ -0,0 +1,19 @@
+mod common;
+use common::run_with_assert;
+
+#[test]
+fn stack_trace_line_and_column() {
+    run_with_assert(
+        r#"
+function thrower() {
+    return new Error("here");
+}
+var stack = thrower().stack.split("\n");
+assertEq(stack[0], "Error: here", "header");
+assertEq(stack[1], "    at thrower (<anonymous>:3:12)", "first line");
+assertEq(stack[2], "    at <anonymous>:5:13", "caller line");
+var s = "äö😀"; var e = new Error("unicode");
+assertEq(e.stack.split("\n")[1], "    at <anonymous>:9:25", "UTF-16 column");
+"#,
+    );
+}
@@ -0,0 +1,310 @@
+#![cfg(all(
+    feature = "array-buffer",
+    feature = "date",
+    feature = "regexp",
+    feature = "set"
+))]
+
+use nova_vm::{
+    ecmascript::{
+        execution::{
+            DefaultHostHooks,
+            agent::{GcAgent, Options},
+        },
+        types::Value,
+    },
+    engine::{
+        context::Bindable,
+        rootable::Scopable,
+        structured_clone::{Serialized, SerializedObject, SerializedValue},
+    },
+};
+use wtf8::Wtf8Buf;
+
+mod common;
+use common::{evaluate, set_global};
+
+const ORIGINAL: &str = r#"
+    const buffer = new ArrayBuffer(8);
+    new Uint8Array(buffer).set([1, 2, 3, 4, 5, 6, 7, 8]);
+    const error = new RangeError("out of range");
+    const key = String.fromCharCode(0xDC00);
+    const original = {
+        number: -0,
+        string: "text",
+        surrogates: [String.fromCharCode(0xD800), String.fromCharCode(0x61, 0xDFFF, 0x62)],
+        big: 2n ** 70n,
+        wrapped: [new Number(1), new String("s"), new Boolean(false), Object(3n)],
+        date: new Date(86400000),
+        regexp: /a+b/giu,
+        map: new Map([[1, "one"], ["two", { value: 2 }]]),
+        set: new Set([1, "a", null]),
+        buffer,
+        bytes: new Uint16Array(buffer, 2, 2),
+        view: new DataView(buffer, 4),
+        error,
+        sparse: [1, , 3],
+    };
+    original.self = original;
+    original[key] = key;
+    original.map.set(original.set, original);
+    original
+"#;
+
+const CHECK: &str = r#"
+    function assert(condition, message) {
+        if (!condition) throw new Error(message);
+    }
+    assert(clone !== original, "clone is a new object");
+    assert(clone.self === clone, "cycle");
+    assert(clone[String.fromCharCode(0xDC00)].charCodeAt(0) === 0xDC00, "lone surrogate key");
+    assert(Object.is(clone.number, -0), "number");
+    assert(clone.string === "text", "string");
+    assert(clone.surrogates[0] === original.surrogates[0] && clone.surrogates[0].charCodeAt(0) === 0xD800, "lone surrogate");
+    assert(clone.surrogates[1].length === 3 && clone.surrogates[1].charCodeAt(1) === 0xDFFF, "string with lone surrogate");
+    assert(clone.big === 2n ** 70n, "bigint");
+    assert(clone.wrapped[0] instanceof Number && clone.wrapped[0].valueOf() === 1, "Number object");
+    assert(clone.wrapped[1] instanceof String && clone.wrapped[1].valueOf() === "s", "String object");
+    assert(clone.wrapped[2] instanceof Boolean && clone.wrapped[2].valueOf() === false, "Boolean object");
+    assert(typeof clone.wrapped[3] === "object" && clone.wrapped[3].valueOf() === 3n, "BigInt object");
+    assert(clone.date instanceof Date && clone.date.getTime() === 86400000, "date");
+    assert(clone.regexp instanceof RegExp && clone.regexp !== original.regexp, "regexp");
+    assert(clone.map instanceof Map && clone.map.get(1) === "one", "map");
+    assert(clone.map.get("two").value === 2, "map object value");
+    assert(clone.map.get(clone.set) === clone, "map object key");
+    assert(clone.set instanceof Set && clone.set.size === 3 && clone.set.has(null), "set");
+    assert(clone.buffer instanceof ArrayBuffer && clone.buffer.byteLength === 8, "buffer");
+    assert(clone.bytes.buffer === clone.buffer, "typed array shares buffer");
+    assert(clone.bytes.byteOffset === 2 && clone.bytes.length === 2, "typed array bounds");
+    assert(clone.bytes[0] === new Uint16Array(original.buffer, 2, 2)[0], "typed array contents");
+    assert(clone.view.buffer === clone.buffer && clone.view.getUint8(0) === 5, "data view");
+    assert(clone.error instanceof RangeError && clone.error.message === "out of range", "error");
+    assert(Array.isArray(clone.sparse) && clone.sparse.length === 3 && !(1 in clone.sparse), "sparse array");
+    clone.bytes[0] = 0;
+    assert(original.bytes[0] !== 0, "buffer data is copied");
+"#;
+
+#[test]
+fn structured_clone_round_trip() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let original = evaluate(agent, ORIGINAL, gc.reborrow())
+                .unwrap()
+                .unbind()
+                .scope(agent, gc.nogc());
+            let serialized = agent
+                .structured_serialize(original.get(agent), gc.reborrow())
+                .unwrap();
+            let clone = agent
+                .structured_deserialize(&serialized, gc.nogc())
+                .unwrap()
+                .unbind();
+            let clone = clone.scope(agent, gc.nogc());
+            // The clone serializes exactly like the original, including
+            // RegExp sources and flags and strings with lone surrogates.
+            let reserialized = agent
+                .structured_serialize(clone.get(agent), gc.reborrow())
+                .unwrap();
+            assert_eq!(reserialized, serialized);
+            set_global(agent, "clone", clone.get(agent), gc.reborrow());
+            set_global(agent, "original", original.get(agent), gc.reborrow());
+            evaluate(agent, CHECK, gc.reborrow()).unwrap();
+        })
+        .unwrap();
+}
+
+#[test]
+fn structured_clone_between_agents() {
+    let mut source_agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let source_realm = source_agent.create_default_realm();
+    let serialized = source_agent
+        .run_in_realm(&source_realm, |agent, mut gc| {
+            let original = evaluate(agent, ORIGINAL, gc.reborrow()).unwrap().unbind();
+            agent.structured_serialize(original, gc).unwrap()
+        })
+        .unwrap();
+
+    let mut target_agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let target_realm = target_agent.create_default_realm();
+    target_agent
+        .run_in_realm(&target_realm, |agent, mut gc| {
+            let original = evaluate(agent, ORIGINAL, gc.reborrow()).unwrap().unbind();
+            set_global(agent, "original", original, gc.reborrow());
+            let clone = agent
+                .structured_deserialize(&serialized, gc.nogc())
+                .unwrap()
+                .unbind();
+            set_global(agent, "clone", clone, gc.reborrow());
+            evaluate(agent, CHECK, gc.reborrow()).unwrap();
+        })
+        .unwrap();
+}
+
+#[test]
+fn structured_serialize_is_heap_independent() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let value = evaluate(
+                agent,
+                "const inner = [true, 'x']; ({ a: 1.5, b: inner, c: inner })",
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let serialized = agent.structured_serialize(value, gc.reborrow()).unwrap();
+            assert_eq!(
+                serialized,
+                SerializedValue {
+                    root: Serialized::Object(0),
+                    objects: vec![
+                        SerializedObject::Object {
+                            properties: vec![
+                                (Wtf8Buf::from_str("a"), Serialized::Number(1.5)),
+                                (Wtf8Buf::from_str("b"), Serialized::Object(1)),
+                                (Wtf8Buf::from_str("c"), Serialized::Object(1)),
+                            ],
+                        },
+                        SerializedObject::Array {
+                            length: 2,
+                            properties: vec![
+                                (Wtf8Buf::from_str("0"), Serialized::Boolean(true)),
+                                (
+                                    Wtf8Buf::from_str("1"),
+                                    Serialized::String(Wtf8Buf::from_str("x"))
+                                ),
+                            ],
+                        },
+                    ],
+                }
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+fn structured_serialize_with_transfer_detaches_buffers() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let buffer = evaluate(
+                agent,
+                "const buffer = new ArrayBuffer(4, { maxByteLength: 16 }); new Uint8Array(buffer).set([1, 2, 3, 4]); buffer",
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind()
+            .scope(agent, gc.nogc());
+            let value = evaluate(agent, "({ view: new Uint8Array(buffer, 1) })", gc.reborrow())
+                .unwrap()
+                .unbind();
+            let serialized = agent
+                .structured_serialize_with_transfer(value, &[buffer.get(agent)], gc.reborrow())
+                .unwrap();
+            let clone = agent
+                .structured_deserialize(&serialized, gc.nogc())
+                .unwrap()
+                .unbind();
+            set_global(agent, "clone", clone, gc.reborrow());
+            let result = evaluate(
+                agent,
+                r#"
+                buffer.detached &&
+                clone.view.buffer.resizable &&
+                clone.view.buffer.maxByteLength === 16 &&
+                clone.view.join() === "2,3,4"
+                "#,
+                gc.reborrow(),
+            )
+            .unwrap();
+            assert_eq!(result, Value::Boolean(true));
+
+            // Detached buffers can be neither transferred nor cloned.
+            let error = agent
+                .structured_serialize_with_transfer(
+                    Value::Undefined,
+                    &[buffer.get(agent)],
+                    gc.reborrow(),
+                )
+                .unwrap_err()
+                .unbind();
+            let message = error.value().string_repr(agent, gc.reborrow());
+            assert_eq!(
+                message.as_str(agent),
+                "TypeError: ArrayBuffer is detached and could not be transferred"
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+fn structured_serialize_throws_for_uncloneable_values() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            for (source, expected) in [
+                ("({ f() {} })", "TypeError: Function could not be cloned"),
+                ("[Symbol.iterator]", "TypeError: Symbol could not be cloned"),
+                (
+                    "new Promise(() => {})",
+                    "TypeError: Object could not be cloned",
+                ),
+                (
+                    "({ get a() { throw new Error('getter') } })",
+                    "Error: getter",
+                ),
+            ] {
+                let value = evaluate(agent, source, gc.reborrow()).unwrap().unbind();
+                let error = agent
+                    .structured_serialize(value, gc.reborrow())
+                    .unwrap_err()
+                    .unbind();
+                let message = error.value().string_repr(agent, gc.reborrow());
+                assert_eq!(message.as_str(agent), expected);
+            }
+        })
+        .unwrap();
+}
+
+#[cfg(feature = "proposal-json-parse-with-source")]
+#[test]
+fn structured_clone_raw_json() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent
+        .run_in_realm(&realm, |agent, mut gc| {
+            let value = evaluate(agent, "[JSON.rawJSON('1e1000')]", gc.reborrow())
+                .unwrap()
+                .unbind();
+            let serialized = agent.structured_serialize(value, gc.reborrow()).unwrap();
+            assert_eq!(
+                serialized.objects[1],
+                SerializedObject::RawJSON(Wtf8Buf::from_str("1e1000"))
+            );
+            let clone = agent
+                .structured_deserialize(&serialized, gc.nogc())
+                .unwrap()
+                .unbind();
+            set_global(agent, "clone", clone, gc.reborrow());
+            let result = evaluate(
+                agent,
+                "JSON.isRawJSON(clone[0]) && Object.isFrozen(clone[0]) && JSON.stringify(clone) === '[1e1000]'",
+                gc.reborrow(),
+            )
+            .unwrap();
+            assert_eq!(result, Value::Boolean(true));
+
+            // Deserializing invalid raw JSON text fails.
+            let invalid = SerializedValue {
+                root: Serialized::Object(0),
+                objects: vec![SerializedObject::RawJSON(Wtf8Buf::from_str("{}"))],
+            };
+            assert!(agent.structured_deserialize(&invalid, gc.nogc()).is_err());
+        })
+        .unwrap();
+}
@@ -46,6 +46,14 @@ impl SmallString {
         panic!("Index out of bounds");
     }
 
+    /// Returns the UTF-16 code unit at the UTF-16 index `idx`.
+    pub fn utf16_code_unit(&self, idx: usize) -> u16 {
+        self.as_str()
+            .encode_utf16()
+            .nth(idx)
+            .expect("Index out of bounds")
+    }
+
     pub fn utf8_index(&self, utf16_idx: usize) -> Option<usize> {
         let mut current_utf16_index = 0;
         for (idx, ch) in self.as_str().char_indices() {